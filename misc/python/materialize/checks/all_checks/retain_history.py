@@ -167,10 +167,10 @@ class RetainHistoryOnMv(Check):
         content_validations = "\n".join(
             f"""
                 ! SELECT * FROM {mv_name} AS OF '${{time0}}'::TIMESTAMP; -- time0 (nothing exists)
-                contains: is not valid for all inputs
+                contains: is before the retained history
 
                 ! SELECT count(*) FROM {mv_name} AS OF '${{time1}}'::TIMESTAMP; -- time1 (table created)
-                contains: is not valid for all inputs
+                contains: is before the retained history
 
                 > SELECT * FROM {mv_name} AS OF '${{time2}}'::TIMESTAMP; -- time2 (table populated)
                 1 100
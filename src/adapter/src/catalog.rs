@@ -19,7 +19,7 @@ use std::sync::Arc;
 use futures::Future;
 use itertools::Itertools;
 use mz_adapter_types::connection::ConnectionId;
-use mz_audit_log::{EventType, FullNameV1, ObjectType, VersionedStorageUsage};
+use mz_audit_log::{EventType, FullNameV1, ObjectType, VersionedEvent, VersionedStorageUsage};
 use mz_build_info::DUMMY_BUILD_INFO;
 use mz_catalog::builtin::{
     BuiltinCluster, BuiltinLog, BuiltinSource, BuiltinTable, BUILTIN_PREFIXES,
@@ -53,8 +53,8 @@ use mz_secrets::InMemorySecretsController;
 use mz_sql::catalog::{
     CatalogCluster, CatalogClusterReplica, CatalogDatabase, CatalogError as SqlCatalogError,
     CatalogItem as SqlCatalogItem, CatalogItemType as SqlCatalogItemType, CatalogNetworkPolicy,
-    CatalogRole, CatalogSchema, DefaultPrivilegeAclItem, DefaultPrivilegeObject, EnvironmentId,
-    SessionCatalog, SystemObjectType,
+    CatalogRole, CatalogSchema, ColumnSecurity, DefaultPrivilegeAclItem, DefaultPrivilegeObject,
+    EnvironmentId, ResourceGroup, RowSecurity, SessionCatalog, SystemObjectType,
 };
 use mz_sql::names::{
     CommentObjectId, DatabaseId, FullItemName, FullSchemaName, ItemQualifiers, ObjectId,
@@ -383,6 +383,7 @@ pub struct ConnCatalog<'a> {
     database: Option<DatabaseId>,
     search_path: Vec<(ResolvedDatabaseSpecifier, SchemaSpecifier)>,
     role_id: RoleId,
+    is_superuser: bool,
     prepared_statements: Option<&'a BTreeMap<String, PreparedStatement>>,
     notices_tx: UnboundedSender<AdapterNotice>,
 }
@@ -620,6 +621,13 @@ impl Catalog {
             .err_into()
     }
 
+    /// Get all audit log events, sorted by ID.
+    ///
+    /// This reads the entire durable catalog, so it should only be called from a background task.
+    pub async fn get_audit_logs(&self) -> Result<Vec<VersionedEvent>, Error> {
+        self.storage().await.get_audit_logs().await.err_into()
+    }
+
     /// Get the next user item ID without allocating it.
     pub async fn get_next_user_item_id(&self) -> Result<u64, Error> {
         self.storage()
@@ -934,7 +942,7 @@ impl Catalog {
         self.state.object_dependents(object_ids, conn_id, &mut seen)
     }
 
-    fn full_name_detail(name: &FullItemName) -> FullNameV1 {
+    pub(crate) fn full_name_detail(name: &FullItemName) -> FullNameV1 {
         FullNameV1 {
             database: name.database.to_string(),
             schema: name.schema.clone(),
@@ -1079,6 +1087,10 @@ impl Catalog {
         self.state.get_network_policy(&network_policy_id)
     }
 
+    pub fn try_get_network_policy_by_name(&self, policy_name: &str) -> Option<&NetworkPolicy> {
+        self.state.try_get_network_policy_by_name(policy_name)
+    }
+
     pub fn clusters(&self) -> impl Iterator<Item = &Cluster> {
         self.state.clusters_by_id.values()
     }
@@ -1560,6 +1572,10 @@ impl SessionCatalog for ConnCatalog<'_> {
         &self.role_id
     }
 
+    fn is_superuser(&self) -> bool {
+        self.is_superuser
+    }
+
     fn get_prepared_statement_desc(&self, name: &str) -> Option<&StatementDesc> {
         self.prepared_statements
             .as_ref()
@@ -2016,6 +2032,22 @@ impl SessionCatalog for ConnCatalog<'_> {
         let comment_id = self.state.get_comment_id(ObjectId::Item(*id));
         self.state.comments.get_object_comments(comment_id)
     }
+
+    fn get_row_security(&self, id: &GlobalId) -> Option<&RowSecurity> {
+        self.state.row_security.get(id)
+    }
+
+    fn get_column_security(&self, id: &GlobalId) -> Option<&BTreeMap<usize, ColumnSecurity>> {
+        self.state.column_security.get(id)
+    }
+
+    fn get_resource_group(&self, name: &str) -> Option<&ResourceGroup> {
+        self.state.get_resource_group(name)
+    }
+
+    fn get_role_resource_group(&self, id: &RoleId) -> Option<(&str, &ResourceGroup)> {
+        self.state.get_role_resource_group(id)
+    }
 }
 
 #[cfg(test)]
@@ -238,6 +238,15 @@ impl CatalogState {
             StateUpdateKind::SourceReferences(source_reference) => {
                 self.apply_source_references_update(source_reference, diff, retractions);
             }
+            StateUpdateKind::RowSecurity(row_security) => {
+                self.apply_row_security_update(row_security, diff, retractions);
+            }
+            StateUpdateKind::ColumnSecurity(column_security) => {
+                self.apply_column_security_update(column_security, diff, retractions);
+            }
+            StateUpdateKind::ResourceGroup(resource_group) => {
+                self.apply_resource_group_update(resource_group, diff, retractions);
+            }
             StateUpdateKind::AuditLog(_audit_log) => {
                 // Audit logs are not stored in-memory.
             }
@@ -626,6 +635,7 @@ impl CatalogState {
                             },
                         ),
                         is_retained_metrics_object: table.is_retained_metrics_object,
+                        retention: None,
                         data_source: TableDataSource::TableWrites {
                             defaults: vec![Expr::null(); table.desc.arity()],
                         },
@@ -1047,6 +1057,101 @@ impl CatalogState {
         }
     }
 
+    #[instrument(level = "debug")]
+    fn apply_row_security_update(
+        &mut self,
+        row_security: mz_catalog::durable::RowSecurity,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        match diff {
+            StateDiff::Addition => {
+                let prev = self
+                    .row_security
+                    .insert(row_security.relation_id.to_global_id(), row_security.into());
+                assert!(
+                    prev.is_none(),
+                    "values must be explicitly retracted before inserting a new value: {prev:?}"
+                );
+            }
+            StateDiff::Retraction => {
+                let prev = self
+                    .row_security
+                    .remove(&row_security.relation_id.to_global_id());
+                assert!(
+                    prev.is_some(),
+                    "retraction for a non-existent existing value: {row_security:?}"
+                );
+            }
+        }
+    }
+
+    #[instrument(level = "debug")]
+    fn apply_column_security_update(
+        &mut self,
+        column_security: mz_catalog::durable::ColumnSecurity,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        let relation_id = column_security.relation_id.to_global_id();
+        let column_pos = column_security.column_pos;
+        match diff {
+            StateDiff::Addition => {
+                let prev = self
+                    .column_security
+                    .entry(relation_id)
+                    .or_default()
+                    .insert(column_pos, column_security.into());
+                assert!(
+                    prev.is_none(),
+                    "values must be explicitly retracted before inserting a new value: {prev:?}"
+                );
+            }
+            StateDiff::Retraction => {
+                let columns = self.column_security.get_mut(&relation_id);
+                let prev = columns.and_then(|columns| columns.remove(&column_pos));
+                assert!(
+                    prev.is_some(),
+                    "retraction for a non-existent existing value: {column_security:?}"
+                );
+                if self
+                    .column_security
+                    .get(&relation_id)
+                    .is_some_and(|columns| columns.is_empty())
+                {
+                    self.column_security.remove(&relation_id);
+                }
+            }
+        }
+    }
+
+    #[instrument(level = "debug")]
+    fn apply_resource_group_update(
+        &mut self,
+        resource_group: mz_catalog::durable::ResourceGroup,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        match diff {
+            StateDiff::Addition => {
+                let prev = self
+                    .resource_groups
+                    .insert(resource_group.name.clone(), resource_group.into());
+                assert!(
+                    prev.is_none(),
+                    "values must be explicitly retracted before inserting a new value: {prev:?}"
+                );
+            }
+            StateDiff::Retraction => {
+                let prev = self.resource_groups.remove(&resource_group.name);
+                assert!(
+                    prev.is_some(),
+                    "retraction for a non-existent existing value: {resource_group:?}"
+                );
+            }
+        }
+    }
+
     #[instrument(level = "debug")]
     fn apply_storage_collection_metadata_update(
         &mut self,
@@ -1184,7 +1289,14 @@ impl CatalogState {
             StateUpdateKind::NetworkPolicy(policy) => self
                 .pack_network_policy_update(&policy.id, diff)
                 .expect("could not pack audit log update"),
-            StateUpdateKind::StorageCollectionMetadata(_)
+            StateUpdateKind::ColumnSecurity(column_security) => {
+                self.pack_column_security_update(&column_security, diff)
+            }
+            StateUpdateKind::ResourceGroup(resource_group) => {
+                self.pack_resource_group_update(&resource_group, diff)
+            }
+            StateUpdateKind::RowSecurity(_)
+            | StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_) => Vec::new(),
         }
     }
@@ -1597,6 +1709,7 @@ impl CatalogState {
                 cluster_id,
                 is_retained_metrics_object: false,
                 custom_logical_compaction_window: None,
+                persisted: false,
             }),
             MZ_SYSTEM_ROLE_ID,
             PrivilegeMap::default(),
@@ -1704,6 +1817,9 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             ),
             StateUpdateKind::Comment(_)
             | StateUpdateKind::SourceReferences(_)
+            | StateUpdateKind::RowSecurity(_)
+            | StateUpdateKind::ColumnSecurity(_)
+            | StateUpdateKind::ResourceGroup(_)
             | StateUpdateKind::AuditLog(_)
             | StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_) => push_update(
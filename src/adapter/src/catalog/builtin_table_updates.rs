@@ -17,20 +17,22 @@ use mz_catalog::builtin::{
     BuiltinTable, MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_AWS_CONNECTIONS,
     MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES, MZ_CLUSTERS, MZ_CLUSTER_REPLICAS,
     MZ_CLUSTER_REPLICA_METRICS, MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICA_STATUSES,
-    MZ_CLUSTER_SCHEDULES, MZ_CLUSTER_WORKLOAD_CLASSES, MZ_COLUMNS, MZ_COMMENTS, MZ_CONNECTIONS,
-    MZ_CONTINUAL_TASKS, MZ_DATABASES, MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS,
-    MZ_HISTORY_RETENTION_STRATEGIES, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_INTERNAL_CLUSTER_REPLICAS,
-    MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_KAFKA_SOURCE_TABLES, MZ_LIST_TYPES,
-    MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES,
-    MZ_MYSQL_SOURCE_TABLES, MZ_NETWORK_POLICIES, MZ_NETWORK_POLICY_RULES, MZ_OBJECT_DEPENDENCIES,
-    MZ_OPERATORS, MZ_PENDING_CLUSTER_REPLICAS, MZ_POSTGRES_SOURCES, MZ_POSTGRES_SOURCE_TABLES,
-    MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_ROLE_PARAMETERS, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SOURCE_REFERENCES, MZ_SSH_TUNNEL_CONNECTIONS,
-    MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES,
-    MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
+    MZ_CLUSTER_SCHEDULES, MZ_CLUSTER_WORKLOAD_CLASSES, MZ_COLUMNS, MZ_COLUMN_MASKS,
+    MZ_COLUMN_PRIVILEGES, MZ_COMMENTS, MZ_CONNECTIONS, MZ_CONTINUAL_TASKS, MZ_DATABASES,
+    MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_HISTORY_RETENTION_STRATEGIES,
+    MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS,
+    MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_KAFKA_SOURCE_TABLES, MZ_LIST_TYPES, MZ_MAP_TYPES,
+    MZ_MATERIALIZED_VIEWS, MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES, MZ_MYSQL_SOURCE_TABLES,
+    MZ_NETWORK_POLICIES, MZ_NETWORK_POLICY_RULES, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS,
+    MZ_PENDING_CLUSTER_REPLICAS, MZ_POSTGRES_SOURCES, MZ_POSTGRES_SOURCE_TABLES, MZ_PSEUDO_TYPES,
+    MZ_RESOURCE_GROUPS, MZ_RESOURCE_GROUP_MEMBERS, MZ_RESOURCE_GROUP_STATUSES, MZ_ROLES,
+    MZ_ROLE_MEMBERS, MZ_ROLE_PARAMETERS, MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCES,
+    MZ_SOURCE_REFERENCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS,
+    MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TABLE_RETENTION_POLICIES, MZ_TABLE_RETENTION_STATUSES,
+    MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::config::AwsPrincipalContext;
-use mz_catalog::durable::SourceReferences;
+use mz_catalog::durable::{ColumnSecurity, ResourceGroup, SourceReferences};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogItem, ClusterReplicaProcessStatus, ClusterVariant, Connection, ContinualTask,
@@ -79,6 +81,7 @@ use mz_storage_types::sources::{
 // DO NOT add any more imports from `crate` outside of `crate::catalog`.
 use crate::active_compute_sink::ActiveSubscribe;
 use crate::catalog::CatalogState;
+use crate::coord::retention::TableRetentionStatus;
 use crate::coord::ConnMeta;
 
 /// An update to a built-in table.
@@ -869,7 +872,7 @@ impl CatalogState {
             None
         };
 
-        vec![BuiltinTableUpdate {
+        let mut updates = vec![BuiltinTableUpdate {
             id: &*MZ_TABLES,
             row: Row::pack_slice(&[
                 Datum::String(&id.to_string()),
@@ -895,7 +898,23 @@ impl CatalogState {
                 },
             ]),
             diff,
-        }]
+        }];
+
+        if let Some(retention) = &table.retention {
+            let period = Interval::from_duration(&retention.period)
+                .expect("planning ensured this convertible back to interval");
+            updates.push(BuiltinTableUpdate {
+                id: &*MZ_TABLE_RETENTION_POLICIES,
+                row: Row::pack_slice(&[
+                    Datum::String(&id.to_string()),
+                    Datum::String(table.desc.get_name(retention.column).as_str()),
+                    Datum::Interval(period),
+                ]),
+                diff,
+            });
+        }
+
+        updates
     }
 
     fn pack_source_update(
@@ -2198,6 +2217,122 @@ impl CatalogState {
         }
     }
 
+    pub fn pack_column_security_update(
+        &self,
+        column_security: &ColumnSecurity,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let id = column_security.relation_id.to_global_id().to_string();
+        // Positions are 1-based, so that they can be joined against `mz_columns`.
+        let position = u64::cast_from(column_security.column_pos + 1);
+        let mut updates = Vec::new();
+        if !column_security.privileges.is_empty() {
+            let privileges =
+                PrivilegeMap::from_mz_acl_items(column_security.privileges.iter().cloned());
+            let row = self.pack_privilege_array_row(&privileges);
+            updates.push(BuiltinTableUpdate {
+                id: &*MZ_COLUMN_PRIVILEGES,
+                row: Row::pack_slice(&[
+                    Datum::String(&id),
+                    Datum::UInt64(position),
+                    row.unpack_first(),
+                ]),
+                diff,
+            });
+        }
+        if let Some(mask) = &column_security.mask {
+            updates.push(BuiltinTableUpdate {
+                id: &*MZ_COLUMN_MASKS,
+                row: Row::pack_slice(&[
+                    Datum::String(&id),
+                    Datum::UInt64(position),
+                    Datum::String(mask),
+                ]),
+                diff,
+            });
+        }
+        updates
+    }
+
+    pub fn pack_resource_group_update(
+        &self,
+        resource_group: &ResourceGroup,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let name = resource_group.name.as_str();
+        let statement_timeout = resource_group.statement_timeout.map(|d| {
+            Interval::from_duration(&d).expect("planning ensured this convertible back to interval")
+        });
+        let mut updates = vec![BuiltinTableUpdate {
+            id: &*MZ_RESOURCE_GROUPS,
+            row: Row::pack_slice(&[
+                Datum::String(name),
+                resource_group.max_concurrent_peeks.into(),
+                resource_group.max_queued_peeks.into(),
+                statement_timeout.into(),
+                resource_group.max_result_size.into(),
+            ]),
+            diff,
+        }];
+        updates.extend(
+            resource_group
+                .roles
+                .iter()
+                .map(|role_id| BuiltinTableUpdate {
+                    id: &*MZ_RESOURCE_GROUP_MEMBERS,
+                    row: Row::pack_slice(&[
+                        Datum::String(name),
+                        Datum::String(&role_id.to_string()),
+                    ]),
+                    diff,
+                }),
+        );
+        updates
+    }
+
+    pub fn pack_resource_group_status_update(
+        &self,
+        name: &str,
+        queued_peeks: u64,
+        rejected_peeks: u64,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        BuiltinTableUpdate {
+            id: &*MZ_RESOURCE_GROUP_STATUSES,
+            row: Row::pack_slice(&[
+                Datum::String(name),
+                Datum::UInt64(queued_peeks),
+                Datum::UInt64(rejected_peeks),
+            ]),
+            diff,
+        }
+    }
+
+    pub fn pack_table_retention_status_update(
+        &self,
+        id: GlobalId,
+        status: &TableRetentionStatus,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        let last_run_duration = Interval::from_duration(&status.last_run_duration)
+            .expect("run durations are convertible to intervals");
+        BuiltinTableUpdate {
+            id: &*MZ_TABLE_RETENTION_STATUSES,
+            row: Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::TimestampTz(
+                    mz_ore::now::to_datetime(status.last_run_at)
+                        .try_into()
+                        .expect("must fit"),
+                ),
+                Datum::Interval(last_run_duration),
+                Datum::UInt64(status.retracted_rows),
+                Datum::from(status.error.as_deref()),
+            ]),
+            diff,
+        }
+    }
+
     pub fn pack_webhook_source_update(
         &self,
         source_id: GlobalId,
@@ -124,6 +124,29 @@ impl CatalogState {
             }
         }
 
+        for (relation_id, _row_security) in &self.row_security {
+            if !self.entry_by_id.contains_key(relation_id) {
+                inconsistencies.push(InternalFieldsInconsistency::RowSecurity(*relation_id));
+            }
+        }
+
+        for (relation_id, _column_security) in &self.column_security {
+            if !self.entry_by_id.contains_key(relation_id) {
+                inconsistencies.push(InternalFieldsInconsistency::ColumnSecurity(*relation_id));
+            }
+        }
+
+        for (name, resource_group) in &self.resource_groups {
+            for role_id in &resource_group.roles {
+                if !self.roles_by_id.contains_key(role_id) {
+                    inconsistencies.push(InternalFieldsInconsistency::ResourceGroup(
+                        name.clone(),
+                        *role_id,
+                    ));
+                }
+            }
+        }
+
         if inconsistencies.is_empty() {
             Ok(())
         } else {
@@ -618,6 +641,9 @@ enum InternalFieldsInconsistency {
     Cluster(String, ClusterId),
     Role(String, RoleId),
     SourceReferences(GlobalId),
+    RowSecurity(GlobalId),
+    ColumnSecurity(GlobalId),
+    ResourceGroup(String, RoleId),
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq)]
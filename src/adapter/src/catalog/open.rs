@@ -237,6 +237,9 @@ impl Catalog {
             system_privileges: PrivilegeMap::default(),
             comments: CommentsMap::default(),
             source_references: BTreeMap::new(),
+            row_security: BTreeMap::new(),
+            column_security: BTreeMap::new(),
+            resource_groups: BTreeMap::new(),
             storage_metadata: Default::default(),
             temporary_schemas: BTreeMap::new(),
             config: mz_sql::catalog::CatalogConfig {
@@ -370,6 +373,9 @@ impl Catalog {
                 | BootstrapStateUpdateKind::AuditLog(_)
                 | BootstrapStateUpdateKind::StorageCollectionMetadata(_)
                 | BootstrapStateUpdateKind::SourceReferences(_)
+                | BootstrapStateUpdateKind::RowSecurity(_)
+                | BootstrapStateUpdateKind::ColumnSecurity(_)
+                | BootstrapStateUpdateKind::ResourceGroup(_)
                 | BootstrapStateUpdateKind::UnfinalizedShard(_) => {
                     post_item_updates.push(StateUpdate {
                         kind: kind.into(),
@@ -1070,6 +1076,7 @@ fn add_new_builtin_clusters_migration(
                         schedule: Default::default(),
                     }),
                     workload_class: None,
+                    arrangement_spill: None,
                 },
                 &HashSet::new(),
             )?;
@@ -1415,6 +1422,7 @@ mod builtin_migration_tests {
                     resolved_ids: ResolvedIds(BTreeSet::new()),
                     custom_logical_compaction_window: None,
                     is_retained_metrics_object: false,
+                    retention: None,
                     data_source: TableDataSource::TableWrites {
                         defaults: vec![Expr::null(); 1],
                     },
@@ -1471,6 +1479,7 @@ mod builtin_migration_tests {
                         cluster_id: ClusterId::User(1),
                         custom_logical_compaction_window: None,
                         is_retained_metrics_object: false,
+                        persisted: false,
                     })
                 }
             };
@@ -57,8 +57,9 @@ use mz_sql::ast::Ident;
 use mz_sql::catalog::{
     BuiltinsConfig, CatalogCluster, CatalogClusterReplica, CatalogConfig, CatalogDatabase,
     CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem, CatalogItemType,
-    CatalogRecordField, CatalogRole, CatalogSchema, CatalogType, CatalogTypeDetails, EnvironmentId,
-    IdReference, NameReference, SessionCatalog, SystemObjectType, TypeReference,
+    CatalogRecordField, CatalogRole, CatalogSchema, CatalogType, CatalogTypeDetails,
+    ColumnSecurity, EnvironmentId, IdReference, NameReference, ResourceGroup, RowSecurity,
+    SessionCatalog, SystemObjectType, TypeReference,
 };
 use mz_sql::names::{
     CommentObjectId, DatabaseId, FullItemName, FullSchemaName, ObjectId, PartialItemName,
@@ -130,6 +131,11 @@ pub struct CatalogState {
     pub(super) comments: CommentsMap,
     #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
     pub(super) source_references: BTreeMap<GlobalId, SourceReferences>,
+    #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
+    pub(super) row_security: BTreeMap<GlobalId, RowSecurity>,
+    #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
+    pub(super) column_security: BTreeMap<GlobalId, BTreeMap<usize, ColumnSecurity>>,
+    pub(super) resource_groups: BTreeMap<String, ResourceGroup>,
     pub(super) storage_metadata: StorageMetadata,
 
     // Mutable state not derived from the durable catalog.
@@ -208,6 +214,9 @@ impl CatalogState {
             system_privileges: Default::default(),
             comments: Default::default(),
             source_references: Default::default(),
+            row_security: Default::default(),
+            column_security: Default::default(),
+            resource_groups: Default::default(),
             storage_metadata: Default::default(),
         }
     }
@@ -230,6 +239,7 @@ impl CatalogState {
             database,
             search_path,
             role_id: session.current_role_id().clone(),
+            is_superuser: session.vars().is_superuser(),
             prepared_statements: Some(session.prepared_statements()),
             notices_tx: session.retain_notice_transmitter(),
         }
@@ -252,6 +262,7 @@ impl CatalogState {
             // where catalog object names have not been normalized correctly.
             search_path: Vec::new(),
             role_id,
+            is_superuser: false,
             prepared_statements: None,
             notices_tx,
         }
@@ -737,6 +748,19 @@ impl CatalogState {
             .map(|id| &self.roles_by_id[id])
     }
 
+    pub fn get_resource_group(&self, name: &str) -> Option<&ResourceGroup> {
+        self.resource_groups.get(name)
+    }
+
+    /// Returns the name and configuration of the resource group that the role
+    /// with the given `id` is assigned to, if any.
+    pub(crate) fn get_role_resource_group(&self, id: &RoleId) -> Option<(&str, &ResourceGroup)> {
+        self.resource_groups
+            .iter()
+            .find(|(_, resource_group)| resource_group.roles.contains(id))
+            .map(|(name, resource_group)| (name.as_str(), resource_group))
+    }
+
     pub(super) fn try_get_network_policy_by_name(
         &self,
         policy_name: &str,
@@ -876,6 +900,7 @@ impl CatalogState {
                 custom_logical_compaction_window: custom_logical_compaction_window
                     .or(table.compaction_window),
                 is_retained_metrics_object,
+                retention: table.retention,
                 data_source: match table.data_source {
                     mz_sql::plan::TableDataSource::TableWrites { defaults } => {
                         TableDataSource::TableWrites { defaults }
@@ -1026,6 +1051,7 @@ impl CatalogState {
                 custom_logical_compaction_window: custom_logical_compaction_window
                     .or(index.compaction_window),
                 is_retained_metrics_object,
+                persisted: index.persisted,
             }),
             Plan::CreateSink(CreateSinkPlan {
                 sink,
@@ -2057,7 +2083,30 @@ impl CatalogState {
         details: EventDetails,
     ) -> Result<(), Error> {
         let user = session.map(|session| session.user().name.to_string());
+        Self::add_to_audit_log_for_user(
+            system_configuration,
+            oracle_write_ts,
+            user,
+            tx,
+            audit_events,
+            event_type,
+            object_type,
+            details,
+        )
+    }
 
+    /// Like [`CatalogState::add_to_audit_log`], but attributes the event to
+    /// `user` rather than to a session.
+    pub(crate) fn add_to_audit_log_for_user(
+        system_configuration: &SystemVars,
+        oracle_write_ts: mz_repr::Timestamp,
+        user: Option<String>,
+        tx: &mut mz_catalog::durable::Transaction,
+        audit_events: &mut Vec<VersionedEvent>,
+        event_type: EventType,
+        object_type: ObjectType,
+        details: EventDetails,
+    ) -> Result<(), Error> {
         // unsafe_mock_audit_event_timestamp can only be set to Some when running in unsafe mode.
 
         let occurred_at = match system_configuration.unsafe_mock_audit_event_timestamp() {
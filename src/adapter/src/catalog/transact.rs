@@ -41,19 +41,19 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{strconv, GlobalId};
 use mz_sql::catalog::{
     CatalogDatabase, CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem, CatalogRole,
-    CatalogSchema, DefaultPrivilegeAclItem, DefaultPrivilegeObject, RoleAttributes, RoleMembership,
-    RoleVars,
+    CatalogSchema, DefaultPrivilegeAclItem, DefaultPrivilegeObject, ResourceGroup, RoleAttributes,
+    RoleMembership, RoleVars, RowSecurityPolicy,
 };
 use mz_sql::names::{
     CommentObjectId, DatabaseId, FullItemName, ObjectId, QualifiedItemName,
     ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier, SystemObjectId,
 };
-use mz_sql::plan::{NetworkPolicyRule, PlanError};
+use mz_sql::plan::{NetworkPolicyRule, PlanError, RetentionPolicy};
 use mz_sql::session::user::{MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID};
 use mz_sql::session::vars::OwnedVarInput;
 use mz_sql::session::vars::{Value as VarValue, VarInput};
 use mz_sql::{rbac, DEFAULT_SCHEMA};
-use mz_sql_parser::ast::{QualifiedReplica, Value};
+use mz_sql_parser::ast::{QualifiedReplica, RetentionOptionValue, Value};
 use mz_storage_client::controller::StorageController;
 use tracing::{info, trace};
 
@@ -75,18 +75,36 @@ pub enum Op {
         value: Option<Value>,
         window: CompactionWindow,
     },
+    AlterRetention {
+        id: GlobalId,
+        value: Option<RetentionOptionValue>,
+        policy: Option<RetentionPolicy>,
+    },
     AlterRole {
         id: RoleId,
         name: String,
         attributes: RoleAttributes,
         vars: RoleVars,
     },
+    AlterRowSecurity {
+        id: GlobalId,
+        enabled: bool,
+    },
+    AlterColumnMask {
+        id: GlobalId,
+        column_pos: usize,
+        mask: Option<String>,
+    },
     AlterNetworkPolicy {
         id: NetworkPolicyId,
         rules: Vec<NetworkPolicyRule>,
         name: String,
         owner_id: RoleId,
     },
+    AlterResourceGroup {
+        name: String,
+        group: ResourceGroup,
+    },
     CreateDatabase {
         name: String,
         owner_id: RoleId,
@@ -125,17 +143,47 @@ pub enum Op {
         name: String,
         owner_id: RoleId,
     },
+    CreatePolicy {
+        relation_id: GlobalId,
+        name: String,
+        policy: RowSecurityPolicy,
+    },
+    CreateResourceGroup {
+        name: String,
+        group: ResourceGroup,
+    },
     Comment {
         object_id: CommentObjectId,
         sub_component: Option<usize>,
         comment: Option<String>,
     },
     DropObjects(Vec<DropObjectInfo>),
+    DropPolicy {
+        relation_id: GlobalId,
+        name: String,
+    },
+    DropResourceGroup {
+        name: String,
+    },
     GrantRole {
         role_id: RoleId,
         member_id: RoleId,
         grantor_id: RoleId,
     },
+    /// Records a session or access event, like a login, in the audit log.
+    ///
+    /// The event is attributed to `user` rather than the transaction's
+    /// session, as these events are buffered and recorded in batches.
+    LogAuditEvent {
+        user: Option<String>,
+        event_type: EventType,
+        object_type: ObjectType,
+        details: EventDetails,
+    },
+    /// Removes events that have outlived the audit log's retention period.
+    PruneAuditLog {
+        events: Vec<VersionedEvent>,
+    },
     RenameCluster {
         id: ClusterId,
         name: String,
@@ -168,6 +216,12 @@ pub enum Op {
         privilege: MzAclItem,
         variant: UpdatePrivilegeVariant,
     },
+    UpdateColumnPrivilege {
+        relation_id: GlobalId,
+        column_pos: usize,
+        privilege: MzAclItem,
+        variant: UpdatePrivilegeVariant,
+    },
     UpdateDefaultPrivilege {
         privilege_object: DefaultPrivilegeObject,
         privilege_acl_item: DefaultPrivilegeAclItem,
@@ -322,6 +376,21 @@ impl Catalog {
         !item.is_temporary()
     }
 
+    fn resource_group_detail(name: &str, group: &ResourceGroup) -> EventDetails {
+        EventDetails::ResourceGroupV1(mz_audit_log::ResourceGroupV1 {
+            name: name.to_string(),
+            roles: group
+                .roles
+                .iter()
+                .map(|role_id| role_id.to_string())
+                .collect(),
+            max_concurrent_peeks: group.max_concurrent_peeks,
+            max_queued_peeks: group.max_queued_peeks,
+            statement_timeout: group.statement_timeout.map(|timeout| timeout.format()),
+            max_result_size: group.max_result_size,
+        })
+    }
+
     /// Gets GlobalIds of temporary items to be created, checks for name collisions
     /// within a connection id.
     fn temporary_ids(
@@ -631,6 +700,47 @@ impl Catalog {
 
                 Self::log_update(state, &id);
             }
+            Op::AlterRetention { id, value, policy } => {
+                let entry = state.get_entry(&id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                if id.is_system() {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::ReadOnlyItem(
+                        full_name.to_string(),
+                    ))));
+                }
+
+                let mut new_entry = entry.clone();
+                new_entry
+                    .item
+                    .update_retention(value, policy)
+                    .map_err(|_| {
+                        AdapterError::Catalog(Error::new(ErrorKind::Internal(
+                            "planner should have rejected invalid alter retention item type"
+                                .to_string(),
+                        )))
+                    })?;
+
+                if Self::should_audit_log_item(new_entry.item()) {
+                    CatalogState::add_to_audit_log(
+                        &state.system_configuration,
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        audit_events,
+                        EventType::Alter,
+                        catalog_type_to_audit_object_type(new_entry.item().typ()),
+                        EventDetails::IdFullNameV1(IdFullNameV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&full_name),
+                        }),
+                    )?;
+                }
+
+                tx.update_item(id, new_entry.into())?;
+
+                Self::log_update(state, &id);
+            }
             Op::AlterRole {
                 id,
                 name,
@@ -660,6 +770,92 @@ impl Catalog {
 
                 info!("update role {name} ({id})");
             }
+            Op::AlterRowSecurity { id, enabled } => {
+                let entry = state.get_entry(&id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                if id.is_system() {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::ReadOnlyItem(
+                        full_name.to_string(),
+                    ))));
+                }
+
+                let policies = tx
+                    .get_row_security(id)
+                    .map(|row_security| row_security.policies)
+                    .unwrap_or_default();
+                tx.update_row_security(id, enabled, policies)?;
+
+                if Self::should_audit_log_item(entry.item()) {
+                    CatalogState::add_to_audit_log(
+                        &state.system_configuration,
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        audit_events,
+                        EventType::Alter,
+                        catalog_type_to_audit_object_type(entry.item().typ()),
+                        EventDetails::AlterRowSecurityV1(mz_audit_log::AlterRowSecurityV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&full_name),
+                            enabled,
+                        }),
+                    )?;
+                }
+
+                info!(
+                    "{} row-level security for {full_name} ({id})",
+                    if enabled { "enable" } else { "disable" }
+                );
+            }
+            Op::AlterColumnMask {
+                id,
+                column_pos,
+                mask,
+            } => {
+                let entry = state.get_entry(&id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                if id.is_system() {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::ReadOnlyItem(
+                        full_name.to_string(),
+                    ))));
+                }
+
+                let privileges = tx
+                    .get_column_security(id, column_pos)
+                    .map(|column_security| column_security.privileges)
+                    .unwrap_or_default();
+                tx.update_column_security(id, column_pos, privileges, mask.clone())?;
+
+                let column_name = entry
+                    .desc(&full_name)
+                    .expect("masks can only be set on relations")
+                    .get_name(column_pos)
+                    .to_string();
+                if Self::should_audit_log_item(entry.item()) {
+                    CatalogState::add_to_audit_log(
+                        &state.system_configuration,
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        audit_events,
+                        EventType::Alter,
+                        catalog_type_to_audit_object_type(entry.item().typ()),
+                        EventDetails::AlterColumnMaskV1(mz_audit_log::AlterColumnMaskV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&full_name),
+                            column_name: column_name.clone(),
+                            mask: mask.clone(),
+                        }),
+                    )?;
+                }
+
+                info!(
+                    "{} mask of column {column_name} of {full_name} ({id})",
+                    if mask.is_some() { "set" } else { "drop" }
+                );
+            }
             Op::AlterNetworkPolicy {
                 id,
                 rules,
@@ -1217,6 +1413,167 @@ impl Catalog {
 
                 info!("created network policy {name} ({id})");
             }
+            Op::CreateResourceGroup { name, group } => {
+                if state.resource_groups.contains_key(&name) {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::Unstructured(
+                        format!("resource group \"{name}\" already exists"),
+                    ))));
+                }
+                tx.upsert_resource_group(mz_catalog::durable::ResourceGroup {
+                    name: name.clone(),
+                    roles: group.roles.iter().copied().collect(),
+                    max_concurrent_peeks: group.max_concurrent_peeks,
+                    max_queued_peeks: group.max_queued_peeks,
+                    statement_timeout: group.statement_timeout,
+                    max_result_size: group.max_result_size,
+                })?;
+
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Create,
+                    ObjectType::ResourceGroup,
+                    Self::resource_group_detail(&name, &group),
+                )?;
+
+                info!("create resource group {name}");
+            }
+            Op::AlterResourceGroup { name, group } => {
+                if !state.resource_groups.contains_key(&name) {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::Unstructured(
+                        format!("resource group \"{name}\" does not exist"),
+                    ))));
+                }
+                tx.upsert_resource_group(mz_catalog::durable::ResourceGroup {
+                    name: name.clone(),
+                    roles: group.roles.iter().copied().collect(),
+                    max_concurrent_peeks: group.max_concurrent_peeks,
+                    max_queued_peeks: group.max_queued_peeks,
+                    statement_timeout: group.statement_timeout,
+                    max_result_size: group.max_result_size,
+                })?;
+
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Alter,
+                    ObjectType::ResourceGroup,
+                    Self::resource_group_detail(&name, &group),
+                )?;
+
+                info!("alter resource group {name}");
+            }
+            Op::DropResourceGroup { name } => {
+                let Some(group) = state.resource_groups.get(&name) else {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::Unstructured(
+                        format!("resource group \"{name}\" does not exist"),
+                    ))));
+                };
+                tx.remove_resource_group(&name)?;
+
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Drop,
+                    ObjectType::ResourceGroup,
+                    Self::resource_group_detail(&name, group),
+                )?;
+
+                info!("drop resource group {name}");
+            }
+            Op::CreatePolicy {
+                relation_id,
+                name,
+                policy,
+            } => {
+                let entry = state.get_entry(&relation_id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                let (enabled, mut policies) = match tx.get_row_security(relation_id) {
+                    Some(row_security) => (row_security.enabled, row_security.policies),
+                    None => (false, Vec::new()),
+                };
+                if policies.iter().any(|existing| existing.name == name) {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::Unstructured(
+                        format!("policy \"{name}\" for relation \"{full_name}\" already exists"),
+                    ))));
+                }
+                let roles: Vec<_> = policy.roles.into_iter().collect();
+                let details = EventDetails::PolicyV1(mz_audit_log::PolicyV1 {
+                    name: name.clone(),
+                    relation_id: relation_id.to_string(),
+                    relation_name: Self::full_name_detail(&full_name),
+                    roles: roles.iter().map(|role_id| role_id.to_string()).collect(),
+                    using: policy.using.clone(),
+                });
+                policies.push(mz_catalog::durable::RowSecurityPolicy {
+                    name: name.clone(),
+                    roles,
+                    using: policy.using,
+                });
+                tx.update_row_security(relation_id, enabled, policies)?;
+
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Create,
+                    ObjectType::Policy,
+                    details,
+                )?;
+
+                info!("create policy {name} on {full_name} ({relation_id})");
+            }
+            Op::DropPolicy { relation_id, name } => {
+                let entry = state.get_entry(&relation_id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                let (enabled, mut policies) = match tx.get_row_security(relation_id) {
+                    Some(row_security) => (row_security.enabled, row_security.policies),
+                    None => (false, Vec::new()),
+                };
+                let Some(idx) = policies.iter().position(|policy| policy.name == name) else {
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::Unstructured(
+                        format!("policy \"{name}\" for relation \"{full_name}\" does not exist"),
+                    ))));
+                };
+                let policy = policies.remove(idx);
+                tx.update_row_security(relation_id, enabled, policies)?;
+
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Drop,
+                    ObjectType::Policy,
+                    EventDetails::PolicyV1(mz_audit_log::PolicyV1 {
+                        name: name.clone(),
+                        relation_id: relation_id.to_string(),
+                        relation_name: Self::full_name_detail(&full_name),
+                        roles: policy
+                            .roles
+                            .iter()
+                            .map(|role_id| role_id.to_string())
+                            .collect(),
+                        using: policy.using,
+                    }),
+                )?;
+
+                info!("drop policy {name} on {full_name} ({relation_id})");
+            }
             Op::Comment {
                 object_id,
                 sub_component,
@@ -1270,6 +1627,12 @@ impl Catalog {
                 // Drop any associated comments.
                 tx.drop_comments(&delta.comments)?;
 
+                // Drop the row-level security configuration of any relations.
+                tx.drop_row_security(&delta.items.iter().copied().collect());
+
+                // Drop the column privileges and masks of any relations.
+                tx.drop_column_security(&delta.items.iter().copied().collect());
+
                 // Drop any items.
                 let (durable_items_to_drop, temporary_items_to_drop): (BTreeSet<_>, BTreeSet<_>) =
                     delta
@@ -1386,6 +1749,60 @@ impl Catalog {
                 // Drop any roles.
                 tx.remove_roles(&delta.roles)?;
 
+                // Remove the dropped roles from any row-level security policies, dropping the
+                // policies that no longer apply to any role.
+                if !delta.roles.is_empty() {
+                    for relation_id in state.row_security.keys() {
+                        let Some(row_security) = tx.get_row_security(*relation_id) else {
+                            continue;
+                        };
+                        let policies = row_security
+                            .policies
+                            .into_iter()
+                            .filter_map(|mut policy| {
+                                policy
+                                    .roles
+                                    .retain(|role_id| !delta.roles.contains(role_id));
+                                (!policy.roles.is_empty()).then_some(policy)
+                            })
+                            .collect();
+                        tx.update_row_security(*relation_id, row_security.enabled, policies)?;
+                    }
+
+                    // Likewise, revoke any column privileges granted to the dropped roles.
+                    for (relation_id, columns) in &state.column_security {
+                        for column_pos in columns.keys() {
+                            let Some(column_security) =
+                                tx.get_column_security(*relation_id, *column_pos)
+                            else {
+                                continue;
+                            };
+                            let privileges = column_security
+                                .privileges
+                                .into_iter()
+                                .filter(|privilege| !delta.roles.contains(&privilege.grantee))
+                                .collect();
+                            tx.update_column_security(
+                                *relation_id,
+                                *column_pos,
+                                privileges,
+                                column_security.mask,
+                            )?;
+                        }
+                    }
+
+                    // Remove the dropped roles from any resource groups.
+                    for mut resource_group in tx.get_resource_groups().collect::<Vec<_>>() {
+                        let len = resource_group.roles.len();
+                        resource_group
+                            .roles
+                            .retain(|role_id| !delta.roles.contains(role_id));
+                        if resource_group.roles.len() != len {
+                            tx.upsert_resource_group(resource_group)?;
+                        }
+                    }
+                }
+
                 for role_id in delta.roles {
                     let role = state
                         .roles_by_id
@@ -1646,6 +2063,55 @@ impl Catalog {
                     }),
                 )?;
             }
+            Op::UpdateColumnPrivilege {
+                relation_id,
+                column_pos,
+                privilege,
+                variant,
+            } => {
+                let entry = state.get_entry(&relation_id);
+                let full_name =
+                    state.resolve_full_name(entry.name(), session.map(|session| session.conn_id()));
+                let (mut privileges, mask) = match tx.get_column_security(relation_id, column_pos) {
+                    Some(column_security) => (
+                        PrivilegeMap::from_mz_acl_items(column_security.privileges),
+                        column_security.mask,
+                    ),
+                    None => (PrivilegeMap::new(), None),
+                };
+                match variant {
+                    UpdatePrivilegeVariant::Grant => privileges.grant(privilege),
+                    UpdatePrivilegeVariant::Revoke => privileges.revoke(&privilege),
+                }
+                tx.update_column_security(
+                    relation_id,
+                    column_pos,
+                    privileges.all_values_owned().collect(),
+                    mask,
+                )?;
+
+                let column_name = entry
+                    .desc(&full_name)
+                    .expect("column privileges can only be granted on relations")
+                    .get_name(column_pos)
+                    .to_string();
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    variant.into(),
+                    catalog_type_to_audit_object_type(entry.item().typ()),
+                    EventDetails::UpdateColumnPrivilegeV1(mz_audit_log::UpdateColumnPrivilegeV1 {
+                        object_id: relation_id.to_string(),
+                        column_name,
+                        grantee_id: privilege.grantee.to_string(),
+                        grantor_id: privilege.grantor.to_string(),
+                        privileges: privilege.acl_mode.to_string(),
+                    }),
+                )?;
+            }
             Op::UpdateDefaultPrivilege {
                 privilege_object,
                 privilege_acl_item,
@@ -2245,6 +2711,27 @@ impl Catalog {
                     EventDetails::ResetAllV1,
                 )?;
             }
+            Op::LogAuditEvent {
+                user,
+                event_type,
+                object_type,
+                details,
+            } => {
+                CatalogState::add_to_audit_log_for_user(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    user,
+                    tx,
+                    audit_events,
+                    event_type,
+                    object_type,
+                    details,
+                )?;
+            }
+            Op::PruneAuditLog { events } => {
+                info!("pruning {} audit log events", events.len());
+                tx.remove_audit_log_events(events);
+            }
             Op::WeirdStorageUsageUpdates {
                 object_id,
                 size_bytes,
@@ -11,6 +11,7 @@ use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::net::IpAddr;
 use std::pin;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -365,6 +366,7 @@ Issue a SQL query to get started. Need help?
             client_ip: None,
             external_metadata_rx: None,
             helm_chart_version: None,
+            client_certificate_subject: None,
         });
         let mut session_client = self.startup(session).await?;
 
@@ -403,6 +405,42 @@ Issue a SQL query to get started. Need help?
         to_datetime((self.now)())
     }
 
+    /// Returns the SCRAM-SHA-256 verifier of the named role's password, if the
+    /// role exists and has a password.
+    pub async fn role_password_verifier(&self, role_name: &str) -> Option<String> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::CatalogSnapshot { tx });
+        let CatalogSnapshot { catalog } = rx.await.expect("sender dropped");
+        catalog
+            .try_get_role_by_name(role_name)
+            .and_then(|role| role.attributes.password.clone())
+    }
+
+    /// Records a failed attempt to authenticate as `user` in the audit log, if
+    /// logins are being audited.
+    pub fn audit_login_failure(&self, user: &str, client_ip: Option<IpAddr>, reason: String) {
+        self.send(Command::AuditLoginFailure {
+            user: user.to_string(),
+            client_ip,
+            reason,
+        });
+    }
+
+    /// Records a webhook request on behalf of `user` that a network policy
+    /// denied in the audit log.
+    pub fn audit_network_policy_denied(
+        &self,
+        user: &str,
+        client_ip: Option<IpAddr>,
+        reason: String,
+    ) {
+        self.send(Command::AuditNetworkPolicyDenied {
+            user: user.to_string(),
+            client_ip,
+            reason,
+        });
+    }
+
     /// Get a metadata and a channel that can be used to append to a webhook source.
     pub async fn get_webhook_appender(
         &self,
@@ -875,7 +913,9 @@ impl SessionClient {
                 | Command::Terminate { .. }
                 | Command::RetireExecute { .. }
                 | Command::CheckConsistency { .. }
-                | Command::Dump { .. } => {}
+                | Command::Dump { .. }
+                | Command::AuditLoginFailure { .. }
+                | Command::AuditNetworkPolicyDenied { .. } => {}
             };
             cmd
         });
@@ -29,7 +29,10 @@ use mz_sql::catalog::ObjectType;
 use mz_sql::plan::{ExecuteTimeout, Plan, PlanKind};
 use mz_sql::session::user::User;
 use mz_sql::session::vars::{OwnedVarInput, Var};
-use mz_sql_parser::ast::{AlterObjectRenameStatement, AlterOwnerStatement, DropObjectsStatement};
+use mz_sql_parser::ast::{
+    AlterColumnMaskStatement, AlterObjectRenameStatement, AlterOwnerStatement,
+    AlterRetentionStatement, AlterRowSecurityStatement, DropObjectsStatement,
+};
 use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
@@ -133,6 +136,21 @@ pub enum Command {
     Dump {
         tx: oneshot::Sender<Result<serde_json::Value, anyhow::Error>>,
     },
+
+    /// Records a failed attempt to authenticate as `user` in the audit log.
+    AuditLoginFailure {
+        user: String,
+        client_ip: Option<IpAddr>,
+        reason: String,
+    },
+
+    /// Records a webhook request on behalf of `user` that a network policy
+    /// denied in the audit log.
+    AuditNetworkPolicyDenied {
+        user: String,
+        client_ip: Option<IpAddr>,
+        reason: String,
+    },
 }
 
 impl Command {
@@ -149,7 +167,9 @@ impl Command {
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
-            | Command::Dump { .. } => None,
+            | Command::Dump { .. }
+            | Command::AuditLoginFailure { .. }
+            | Command::AuditNetworkPolicyDenied { .. } => None,
         }
     }
 
@@ -166,7 +186,9 @@ impl Command {
             | Command::SetSystemVars { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
-            | Command::Dump { .. } => None,
+            | Command::Dump { .. }
+            | Command::AuditLoginFailure { .. }
+            | Command::AuditNetworkPolicyDenied { .. } => None,
         }
     }
 }
@@ -266,6 +288,8 @@ pub enum ExecuteResponse {
     AlteredDefaultPrivileges,
     /// The requested object was altered.
     AlteredObject(ObjectType),
+    /// The requested resource group was altered.
+    AlteredResourceGroup,
     /// The role was altered.
     AlteredRole,
     /// The system configuration was altered.
@@ -323,6 +347,10 @@ pub enum ExecuteResponse {
     CreatedType,
     /// The requested network policy was created.
     CreatedNetworkPolicy,
+    /// The requested row-level security policy was created.
+    CreatedPolicy,
+    /// The requested resource group was created.
+    CreatedResourceGroup,
     /// The requested prepared statement was removed.
     Deallocate { all: bool },
     /// The requested cursor was declared.
@@ -337,6 +365,10 @@ pub enum ExecuteResponse {
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested row-level security policy was dropped.
+    DroppedPolicy,
+    /// The requested resource group was dropped.
+    DroppedResourceGroup,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -435,7 +467,10 @@ impl TryFrom<&Statement<Raw>> for ExecuteResponse {
                 ExecuteResponse::DroppedObject((*object_type).into())
             }
             Statement::AlterObjectRename(AlterObjectRenameStatement { object_type, .. })
-            | Statement::AlterOwner(AlterOwnerStatement { object_type, .. }) => {
+            | Statement::AlterOwner(AlterOwnerStatement { object_type, .. })
+            | Statement::AlterRetention(AlterRetentionStatement { object_type, .. })
+            | Statement::AlterRowSecurity(AlterRowSecurityStatement { object_type, .. })
+            | Statement::AlterColumnMask(AlterColumnMaskStatement { object_type, .. }) => {
                 ExecuteResponse::AlteredObject((*object_type).into())
             }
             _ => return Err(()),
@@ -461,6 +496,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
                 Ok(ExecuteResponse::AlteredDefaultPrivileges)
             }
             ExecuteResponseKind::AlteredObject => Err(()),
+            ExecuteResponseKind::AlteredResourceGroup => Ok(ExecuteResponse::AlteredResourceGroup),
             ExecuteResponseKind::AlteredRole => Ok(ExecuteResponse::AlteredRole),
             ExecuteResponseKind::AlteredSystemConfiguration => {
                 Ok(ExecuteResponse::AlteredSystemConfiguration)
@@ -489,6 +525,8 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
                 Ok(ExecuteResponse::CreatedMaterializedView)
             }
             ExecuteResponseKind::CreatedNetworkPolicy => Ok(ExecuteResponse::CreatedNetworkPolicy),
+            ExecuteResponseKind::CreatedPolicy => Ok(ExecuteResponse::CreatedPolicy),
+            ExecuteResponseKind::CreatedResourceGroup => Ok(ExecuteResponse::CreatedResourceGroup),
             ExecuteResponseKind::CreatedContinualTask => Ok(ExecuteResponse::CreatedContinualTask),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
             ExecuteResponseKind::Deallocate => Err(()),
@@ -498,6 +536,8 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedPolicy => Ok(ExecuteResponse::DroppedPolicy),
+            ExecuteResponseKind::DroppedResourceGroup => Ok(ExecuteResponse::DroppedResourceGroup),
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
@@ -530,6 +570,7 @@ impl ExecuteResponse {
         match self {
             AlteredDefaultPrivileges => Some("ALTER DEFAULT PRIVILEGES".into()),
             AlteredObject(o) => Some(format!("ALTER {}", o)),
+            AlteredResourceGroup => Some("ALTER RESOURCE GROUP".into()),
             AlteredRole => Some("ALTER ROLE".into()),
             AlteredSystemConfiguration => Some("ALTER SYSTEM".into()),
             ClosedCursor => Some("CLOSE CURSOR".into()),
@@ -554,6 +595,8 @@ impl ExecuteResponse {
             CreatedContinualTask { .. } => Some("CREATE CONTINUAL TASK".into()),
             CreatedType => Some("CREATE TYPE".into()),
             CreatedNetworkPolicy => Some("CREATE NETWORKPOLICY".into()),
+            CreatedPolicy => Some("CREATE POLICY".into()),
+            CreatedResourceGroup => Some("CREATE RESOURCE GROUP".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
@@ -561,6 +604,8 @@ impl ExecuteResponse {
             DiscardedAll => Some("DISCARD ALL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedPolicy => Some("DROP POLICY".into()),
+            DroppedResourceGroup => Some("DROP RESOURCE GROUP".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -609,6 +654,7 @@ impl ExecuteResponse {
             | AlterOwner
             | AlterItemRename
             | AlterRetainHistory
+            | AlterRetention
             | AlterNoop
             | AlterSchemaRename
             | AlterSchemaSwap
@@ -617,9 +663,12 @@ impl ExecuteResponse {
             | AlterSource
             | AlterSink
             | AlterTableAddColumn
-            | AlterNetworkPolicy => &[AlteredObject],
+            | AlterNetworkPolicy
+            | AlterRowSecurity
+            | AlterColumnMask => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
             AlterSetCluster => &[AlteredObject],
+            AlterResourceGroup => &[AlteredResourceGroup],
             AlterRole => &[AlteredRole],
             AlterSystemSet | AlterSystemReset | AlterSystemResetAll => {
                 &[AlteredSystemConfiguration]
@@ -646,11 +695,15 @@ impl ExecuteResponse {
             CreateType => &[CreatedType],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
+            CreatePolicy => &[CreatedPolicy],
+            CreateResourceGroup => &[CreatedResourceGroup],
             Declare => &[DeclaredCursor],
             DiscardTemp => &[DiscardedTemp],
             DiscardAll => &[DiscardedAll],
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
+            DropPolicy => &[DroppedPolicy],
+            DropResourceGroup => &[DroppedResourceGroup],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
             | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
@@ -35,6 +35,7 @@ impl SystemParameterBackend {
             client_ip: None,
             external_metadata_rx: None,
             helm_chart_version: None,
+            client_certificate_subject: None,
         });
         let session_client = client.startup(session).await?;
         Ok(Self { session_client })
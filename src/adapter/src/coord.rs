@@ -72,6 +72,7 @@ use std::fmt;
 use std::net::IpAddr;
 use std::num::NonZeroI64;
 use std::ops::Neg;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
@@ -91,6 +92,7 @@ use itertools::{Either, Itertools};
 use mz_adapter_types::compaction::CompactionWindow;
 use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::WITH_0DT_DEPLOYMENT_CAUGHT_UP_CHECK_INTERVAL;
+use mz_audit_log::VersionedEvent;
 use mz_build_info::BuildInfo;
 use mz_catalog::builtin::{BUILTINS, BUILTINS_STATIC, MZ_STORAGE_USAGE_BY_SHARD};
 use mz_catalog::config::{AwsPrincipalContext, BuiltinItemMigrationConfig, ClusterReplicaSizeMap};
@@ -103,6 +105,7 @@ use mz_cloud_resources::{CloudResourceController, VpcEndpointConfig, VpcEndpoint
 use mz_compute_client::as_of_selection;
 use mz_compute_client::controller::error::InstanceMissing;
 use mz_compute_types::dataflows::DataflowDescription;
+use mz_compute_types::persisted_index;
 use mz_compute_types::plan::Plan;
 use mz_compute_types::ComputeInstanceId;
 use mz_controller::clusters::{ClusterConfig, ClusterEvent, ClusterStatus, ProcessId};
@@ -148,10 +151,7 @@ use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::read_holds::ReadHold;
 use mz_storage_types::sinks::S3SinkFormat;
 use mz_storage_types::sources::Timeline;
-use mz_timestamp_oracle::postgres_oracle::{
-    PostgresTimestampOracle, PostgresTimestampOracleConfig,
-};
-use mz_timestamp_oracle::WriteTimestamp;
+use mz_timestamp_oracle::{TimestampOracleConfig, WriteTimestamp};
 use mz_transform::dataflow::DataflowMetainfo;
 use opentelemetry::trace::TraceContextExt;
 use serde::Serialize;
@@ -173,11 +173,12 @@ use crate::config::{SynchronizedParameters, SystemParameterFrontend, SystemParam
 use crate::coord::appends::{
     BuiltinTableAppendNotify, DeferredWriteOp, GroupCommitPermit, PendingWriteTxn,
 };
+use crate::coord::audit_log::PendingAuditEvents;
 use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
-use crate::coord::peek::PendingPeek;
+use crate::coord::peek::{PendingPeek, ResourceGroupPeeks};
 use crate::coord::statement_logging::{StatementLogging, StatementLoggingId};
 use crate::coord::timeline::{TimelineContext, TimelineState};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
@@ -204,6 +205,7 @@ pub(crate) mod timeline;
 pub(crate) mod timestamp_selection;
 
 mod appends;
+mod audit_log;
 mod catalog_serving;
 mod caught_up;
 pub mod cluster_scheduling;
@@ -213,8 +215,10 @@ mod ddl;
 mod indexes;
 mod introspection;
 mod message_handler;
+mod persist_inspect;
 mod privatelink_status;
 pub mod read_policy;
+pub(crate) mod retention;
 mod sequencer;
 mod sql;
 mod validity;
@@ -248,11 +252,20 @@ pub enum Message {
     CancelPendingPeeks {
         conn_id: ConnectionId,
     },
+    /// The peek exceeded the statement timeout of its resource group.
+    PeekTimeout {
+        uuid: Uuid,
+    },
     LinearizeReads,
     StorageUsageSchedule,
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
     StorageUsagePrune(Vec<BuiltinTableUpdate>),
+    AuditLogFlush,
+    AuditLogPruneFetch,
+    AuditLogPrune(Vec<VersionedEvent>),
+    RetentionFetch,
+    RetentionApply(Vec<retention::RetentionRun>),
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     RetireExecute {
@@ -339,6 +352,8 @@ impl Message {
                 Command::RetireExecute { .. } => "command-retire_execute",
                 Command::CheckConsistency { .. } => "command-check_consistency",
                 Command::Dump { .. } => "command-dump",
+                Command::AuditLoginFailure { .. } => "command-audit_login_failure",
+                Command::AuditNetworkPolicyDenied { .. } => "command-audit_network_policy_denied",
             },
             Message::ControllerReady => "controller_ready",
             Message::PurifiedStatementReady(_) => "purified_statement_ready",
@@ -348,11 +363,17 @@ impl Message {
             Message::AdvanceTimelines => "advance_timelines",
             Message::ClusterEvent(_) => "cluster_event",
             Message::CancelPendingPeeks { .. } => "cancel_pending_peeks",
+            Message::PeekTimeout { .. } => "peek_timeout",
             Message::LinearizeReads => "linearize_reads",
             Message::StorageUsageSchedule => "storage_usage_schedule",
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
             Message::StorageUsagePrune(_) => "storage_usage_prune",
+            Message::AuditLogFlush => "audit_log_flush",
+            Message::AuditLogPruneFetch => "audit_log_prune_fetch",
+            Message::AuditLogPrune(_) => "audit_log_prune",
+            Message::RetentionFetch => "retention_fetch",
+            Message::RetentionApply(_) => "retention_apply",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
                 "execute_single_statement_transaction"
@@ -523,6 +544,8 @@ pub struct PeekStageFinish {
     insights_ctx: Option<Box<PlanInsightsContext>>,
     global_lir_plan: optimize::peek::GlobalLirPlan,
     optimization_finished_at: EpochMillis,
+    /// Whether the peek has already waited in the queue of its resource group.
+    resource_group_queued: bool,
 }
 
 #[derive(Debug)]
@@ -984,6 +1007,7 @@ pub struct Config {
     pub storage_usage_collection_interval: Duration,
     pub storage_usage_retention_period: Option<Duration>,
     pub segment_client: Option<mz_segment::Client>,
+    pub audit_log_export_path: Option<PathBuf>,
     pub egress_addresses: Vec<IpNet>,
     pub remote_system_parameters: Option<BTreeMap<String, String>>,
     pub aws_account_id: Option<String>,
@@ -1633,6 +1657,11 @@ pub struct Coordinator {
     pending_peeks: BTreeMap<Uuid, PendingPeek>,
     /// A map from client connection ids to a set of all pending peeks for that client.
     client_pending_peeks: BTreeMap<ConnectionId, BTreeMap<Uuid, ClusterId>>,
+    /// A map from resource group names to the peeks each resource group has admitted,
+    /// queued, and rejected.
+    resource_group_peeks: BTreeMap<String, ResourceGroupPeeks>,
+    /// The result of the most recent retention run on each table with a retention policy.
+    table_retention_statuses: BTreeMap<GlobalId, retention::TableRetentionStatus>,
 
     /// A map from client connection ids to pending linearize read transaction.
     pending_linearize_read_txns: BTreeMap<ConnectionId, PendingReadTxn>,
@@ -1701,6 +1730,11 @@ pub struct Coordinator {
     /// Segment analytics client.
     #[derivative(Debug = "ignore")]
     segment_client: Option<mz_segment::Client>,
+    /// Channel on which to send audit events to be appended to the audit log
+    /// export file, if one is configured.
+    audit_log_export_tx: Option<mpsc::UnboundedSender<VersionedEvent>>,
+    /// Session and access events waiting to be recorded in the audit log.
+    pending_audit_events: PendingAuditEvents,
 
     /// Coordinator metrics.
     metrics: Metrics,
@@ -1716,10 +1750,9 @@ pub struct Coordinator {
     /// Limit for how many concurrent webhook requests we allow.
     webhook_concurrency_limit: WebhookConcurrencyLimiter,
 
-    /// Optional config for the Postgres-backed timestamp oracle. This is
-    /// _required_ when `postgres` is configured using the `timestamp_oracle`
-    /// system variable.
-    pg_timestamp_oracle_config: Option<PostgresTimestampOracleConfig>,
+    /// Optional config for the timestamp oracle. This is _required_ when
+    /// `postgres` is configured using the `timestamp_oracle` system variable.
+    timestamp_oracle_config: Option<TimestampOracleConfig>,
 
     /// Periodically asks cluster scheduling policies to make their decisions.
     check_cluster_scheduling_policies_interval: Interval,
@@ -1847,6 +1880,7 @@ impl Coordinator {
                 ClusterConfig {
                     arranged_logs: instance.log_indexes.clone(),
                     workload_class: instance.config.workload_class.clone(),
+                    arrangement_spill: instance.config.arrangement_spill,
                 },
             )?;
             for replica in instance.replicas() {
@@ -1980,6 +2014,32 @@ impl Coordinator {
                             .entry(idx.cluster_id)
                             .or_insert_with(BTreeSet::new)
                             .insert(entry.id());
+                    } else if idx.persisted {
+                        // Persisted indexes are read through their storage collection, like
+                        // materialized views.
+                        policy_entry.storage_ids.insert(entry.id());
+
+                        let df_desc = self
+                            .catalog()
+                            .try_get_physical_plan(&entry.id())
+                            .expect("added in `bootstrap_dataflow_plans`")
+                            .clone();
+
+                        let df_meta = self
+                            .catalog()
+                            .try_get_dataflow_metainfo(&entry.id())
+                            .expect("added in `bootstrap_dataflow_plans`");
+
+                        if self.catalog().state().system_config().enable_mz_notices() {
+                            // Collect optimization hint updates.
+                            self.catalog().state().pack_optimizer_notices(
+                                &mut builtin_table_updates,
+                                df_meta.optimizer_notices.iter(),
+                                1,
+                            );
+                        }
+
+                        self.ship_dataflow(df_desc, idx.cluster_id, None).await;
                     } else {
                         let df_desc = self
                             .catalog()
@@ -2538,6 +2598,16 @@ impl Coordinator {
                     };
                     collections.push((id, collection_desc));
                 }
+                CatalogItem::Index(idx) if idx.persisted => {
+                    let collection_desc = CollectionDescription {
+                        desc: persisted_index::relation_desc(),
+                        data_source: DataSource::Other,
+                        since: None,
+                        status_collection_id: None,
+                        timeline: None,
+                    };
+                    collections.push((id, collection_desc));
+                }
                 CatalogItem::ContinualTask(ct) => {
                     let collection_desc = CollectionDescription {
                         desc: ct.desc.clone(),
@@ -2682,6 +2752,7 @@ impl Coordinator {
                             entry.name().clone(),
                             idx.on,
                             idx.keys.to_vec(),
+                            idx.persisted,
                         );
                         let global_mir_plan = optimizer.optimize(index_plan)?;
                         let optimized_plan = global_mir_plan.df_desc().clone();
@@ -2916,6 +2987,8 @@ impl Coordinator {
             });
 
             self.schedule_storage_usage_collection().await;
+            self.audit_log_prune_fetch();
+            self.schedule_retention_fetch();
             self.spawn_privatelink_vpc_endpoints_watch_task();
             self.spawn_statement_logging_task();
             flags::tracing_config(self.catalog.system_config()).apply(&self.tracing_handle);
@@ -3503,6 +3576,7 @@ pub fn serve(
         storage_usage_collection_interval,
         storage_usage_retention_period,
         segment_client,
+        audit_log_export_path,
         egress_addresses,
         aws_account_id,
         aws_privatelink_availability_zones,
@@ -3553,6 +3627,24 @@ pub fn serve(
         let aws_privatelink_availability_zones = aws_privatelink_availability_zones
             .map(|azs_vec| BTreeSet::from_iter(azs_vec.iter().cloned()));
 
+        let audit_log_export_tx = match audit_log_export_path {
+            Some(path) => {
+                let file = match std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                {
+                    Ok(file) => file,
+                    Err(e) => coord_bail!(
+                        "unable to open audit log export file {}: {e}",
+                        path.display()
+                    ),
+                };
+                Some(audit_log::spawn_audit_log_export_task(file))
+            }
+            None => None,
+        };
+
         info!(
             "startup: coordinator init: preamble complete in {:?}",
             coord_start.elapsed()
@@ -3560,10 +3652,12 @@ pub fn serve(
         let oracle_init_start = Instant::now();
         info!("startup: coordinator init: timestamp oracle init beginning");
 
-        let pg_timestamp_oracle_config = timestamp_oracle_url
-            .map(|pg_url| PostgresTimestampOracleConfig::new(&pg_url, &metrics_registry));
+        let timestamp_oracle_config = match timestamp_oracle_url {
+            Some(url) => Some(TimestampOracleConfig::from_url(&url, &metrics_registry).await?),
+            None => None,
+        };
         let mut initial_timestamps =
-            get_initial_oracle_timestamps(&pg_timestamp_oracle_config).await?;
+            get_initial_oracle_timestamps(&timestamp_oracle_config).await?;
 
         // Insert an entry for the `EpochMilliseconds` timeline if one doesn't exist,
         // which will ensure that the timeline is initialized since it's required
@@ -3577,7 +3671,7 @@ pub fn serve(
                 &timeline,
                 initial_timestamp,
                 now.clone(),
-                pg_timestamp_oracle_config.clone(),
+                timestamp_oracle_config.clone(),
                 &mut timestamp_oracles,
                 read_only_controllers,
             )
@@ -3720,7 +3814,10 @@ pub fn serve(
                 exclude_collections: new_builtins,
             });
 
-        if let Some(config) = pg_timestamp_oracle_config.as_ref() {
+        if let Some(config) = timestamp_oracle_config
+            .as_ref()
+            .and_then(|config| config.pg_config())
+        {
             // Apply settings from system vars as early as possible because some
             // of them are locked in right when an oracle is first opened!
             let pg_timestamp_oracle_params =
@@ -3764,6 +3861,8 @@ pub fn serve(
                     txn_read_holds: Default::default(),
                     pending_peeks: BTreeMap::new(),
                     client_pending_peeks: BTreeMap::new(),
+                    resource_group_peeks: BTreeMap::new(),
+                    table_retention_statuses: BTreeMap::new(),
                     pending_linearize_read_txns: BTreeMap::new(),
                     serialized_ddl: LockedVecDeque::new(),
                     active_compute_sinks: BTreeMap::new(),
@@ -3781,12 +3880,14 @@ pub fn serve(
                     storage_usage_client,
                     storage_usage_collection_interval,
                     segment_client,
+                    audit_log_export_tx,
+                    pending_audit_events: PendingAuditEvents::default(),
                     metrics,
                     optimizer_metrics,
                     tracing_handle,
                     statement_logging: StatementLogging::new(coord_now.clone()),
                     webhook_concurrency_limit,
-                    pg_timestamp_oracle_config,
+                    timestamp_oracle_config,
                     check_cluster_scheduling_policies_interval: check_scheduling_policies_interval,
                     cluster_scheduling_decisions: BTreeMap::new(),
                     caught_up_check_interval: clusters_caught_up_check_interval,
@@ -3885,27 +3986,25 @@ pub fn serve(
 // window (which is the only point where we should switch oracle
 // implementations).
 async fn get_initial_oracle_timestamps(
-    pg_timestamp_oracle_config: &Option<PostgresTimestampOracleConfig>,
+    timestamp_oracle_config: &Option<TimestampOracleConfig>,
 ) -> Result<BTreeMap<Timeline, Timestamp>, AdapterError> {
     let mut initial_timestamps = BTreeMap::new();
 
-    if let Some(pg_timestamp_oracle_config) = pg_timestamp_oracle_config {
-        let postgres_oracle_timestamps =
-            PostgresTimestampOracle::<NowFn>::get_all_timelines(pg_timestamp_oracle_config.clone())
-                .await?;
+    if let Some(timestamp_oracle_config) = timestamp_oracle_config {
+        let oracle_timestamps = timestamp_oracle_config.get_all_timelines().await?;
 
         let debug_msg = || {
-            postgres_oracle_timestamps
+            oracle_timestamps
                 .iter()
                 .map(|(timeline, ts)| format!("{:?} -> {}", timeline, ts))
                 .join(", ")
         };
         info!(
-            "current timestamps from the postgres-backed timestamp oracle: {}",
+            "current timestamps from the timestamp oracle: {}",
             debug_msg()
         );
 
-        for (timeline, ts) in postgres_oracle_timestamps {
+        for (timeline, ts) in oracle_timestamps {
             let entry = initial_timestamps
                 .entry(Timeline::from_str(&timeline).expect("could not parse timeline"));
 
@@ -3914,7 +4013,7 @@ async fn get_initial_oracle_timestamps(
                 .or_insert(ts);
         }
     } else {
-        info!("no postgres url for postgres-backed timestamp oracle configured!");
+        info!("no url for timestamp oracle configured!");
     };
 
     let debug_msg = || {
@@ -4106,12 +4205,14 @@ enum PlanStatement {
     },
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum NetworkPolicyError {
     #[error("Access denied for address {0}")]
     AddressDenied(IpAddr),
     #[error("Access denied missing IP address")]
     MissingIp,
+    #[error("Access denied by unknown network policy {0}")]
+    UnknownPolicy(String),
 }
 
 // TODO @jubrad this will be moved to a catalog resource in v1
@@ -4127,6 +4228,21 @@ impl NetworkPolicy {
         NetworkPolicy { allow_list }
     }
 
+    /// Builds a network policy that allows ingress from the addresses of
+    /// `rules`.
+    pub fn from_rules(rules: &[plan::NetworkPolicyRule]) -> Self {
+        let allow_list = rules
+            .iter()
+            .map(|rule| match (&rule.action, &rule.direction) {
+                (
+                    plan::NetworkPolicyRuleAction::Allow,
+                    plan::NetworkPolicyRuleDirection::Ingress,
+                ) => rule.address.0,
+            })
+            .collect();
+        NetworkPolicy { allow_list }
+    }
+
     /// Validate the provided IP is allowed by the network policy.
     pub fn validate(&self, ip: &IpAddr) -> Result<(), NetworkPolicyError> {
         match self.allow_list.iter().any(|net| net.contains(ip)) {
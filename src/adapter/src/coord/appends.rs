@@ -26,6 +26,7 @@ use mz_sql::names::ResolvedIds;
 use mz_sql::plan::Plan;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_storage_client::client::TimestamplessUpdate;
+use mz_storage_types::controller::StorageError;
 use mz_timestamp_oracle::WriteTimestamp;
 use tokio::sync::{oneshot, Notify, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
 use tracing::{debug_span, warn, Instrument, Span};
@@ -110,6 +111,7 @@ pub struct DeferredPlan {
 pub struct DeferredWrite {
     pub span: Span,
     pub writes: BTreeMap<GlobalId, Vec<(Row, i64)>>,
+    pub read_set: BTreeMap<GlobalId, Timestamp>,
     pub pending_txn: PendingTxn,
 }
 
@@ -132,6 +134,9 @@ pub(crate) enum PendingWriteTxn {
         writes: BTreeMap<GlobalId, Vec<(Row, Diff)>>,
         /// If they exist, should contain locks for each [`GlobalId`] in `writes`.
         write_locks: Option<WriteLocks>,
+        /// Tables read by a read/write transaction and the timestamp they were read at. The
+        /// writes are only applied if none of these tables were written after that timestamp.
+        read_set: BTreeMap<GlobalId, Timestamp>,
         /// Inner transaction.
         pending_txn: PendingTxn,
     },
@@ -152,6 +157,13 @@ impl PendingWriteTxn {
             _ => false,
         }
     }
+
+    fn has_read_set(&self) -> bool {
+        match self {
+            PendingWriteTxn::User { read_set, .. } => !read_set.is_empty(),
+            PendingWriteTxn::System { .. } => false,
+        }
+    }
 }
 
 impl Coordinator {
@@ -241,12 +253,14 @@ impl Coordinator {
             DeferredWriteOp::Write(DeferredWrite {
                 span,
                 writes,
+                read_set,
                 pending_txn,
             }) => {
                 self.submit_write(PendingWriteTxn::User {
                     span,
                     writes,
                     write_locks,
+                    read_set,
                     pending_txn,
                 });
             }
@@ -320,7 +334,28 @@ impl Coordinator {
 
         // TODO(parkmycar): Refactor away this allocation. Currently `drain(..)` requires holding
         // a mutable borrow on the Coordinator and so does trying to grant a write lock.
-        let pending_writes: Vec<_> = self.pending_writes.drain(..).collect();
+        let mut pending_writes: Vec<_> = self.pending_writes.drain(..).collect();
+
+        // Writes with a read set are validated as a whole, so a conflict aborts everything in the
+        // group. Commit them on their own, after any internal system writes whose callers are
+        // waiting on this group commit.
+        if pending_writes.iter().any(|write| write.has_read_set()) {
+            let (read_write, others): (Vec<_>, Vec<_>) = pending_writes
+                .into_iter()
+                .partition(|write| write.has_read_set());
+            if others.iter().any(|write| write.is_internal_system()) {
+                pending_writes = others;
+                self.pending_writes.extend(read_write);
+            } else {
+                let mut read_write = read_write.into_iter();
+                pending_writes = read_write.next().into_iter().collect();
+                self.pending_writes.extend(read_write);
+                self.pending_writes.extend(others);
+            }
+            if !self.pending_writes.is_empty() {
+                self.trigger_group_commit();
+            }
+        }
 
         // Validate, merge, and possibly acquire write locks for as many pending writes as possible.
         for pending_write in pending_writes {
@@ -332,6 +367,7 @@ impl Coordinator {
                     span,
                     write_locks: Some(write_locks),
                     writes,
+                    read_set,
                     pending_txn,
                 } => match write_locks.validate(writes.keys().copied()) {
                     Ok(validated_locks) => {
@@ -343,6 +379,7 @@ impl Coordinator {
                             span,
                             writes,
                             write_locks: None,
+                            read_set,
                             pending_txn,
                         };
                         validated_writes.push(validated_write);
@@ -366,6 +403,7 @@ impl Coordinator {
                     span,
                     writes,
                     write_locks: None,
+                    read_set,
                     pending_txn,
                 } => {
                     let missing = group_write_locks.missing_locks(writes.keys().copied());
@@ -376,6 +414,7 @@ impl Coordinator {
                             span,
                             writes,
                             write_locks: None,
+                            read_set,
                             pending_txn,
                         };
                         validated_writes.push(validated_write);
@@ -397,6 +436,7 @@ impl Coordinator {
                                     span,
                                     writes,
                                     write_locks: None,
+                                    read_set,
                                     pending_txn,
                                 };
                                 validated_writes.push(validated_write);
@@ -407,6 +447,7 @@ impl Coordinator {
                                 let write = DeferredWrite {
                                     span,
                                     writes,
+                                    read_set,
                                     pending_txn,
                                 };
                                 deferred_writes.push((acquire_future, write));
@@ -448,6 +489,7 @@ impl Coordinator {
             .unwrap_or_terminate("unable to confirm leadership");
 
         let mut appends: BTreeMap<GlobalId, Vec<(Row, Diff)>> = BTreeMap::new();
+        let mut read_set = BTreeMap::new();
         let mut responses = Vec::with_capacity(validated_writes.len());
         let mut notifies = Vec::new();
 
@@ -457,6 +499,7 @@ impl Coordinator {
                     span: _,
                     writes,
                     write_locks,
+                    read_set: txn_read_set,
                     pending_txn:
                         PendingTxn {
                             ctx,
@@ -475,6 +518,7 @@ impl Coordinator {
                             appends.entry(id).or_default().extend(rows);
                         }
                     }
+                    read_set.extend(txn_read_set);
                     if let Some(id) = ctx.extra().contents() {
                         self.set_statement_execution_timestamp(id, timestamp);
                     }
@@ -513,6 +557,19 @@ impl Coordinator {
                 (id, updates)
             })
             .collect();
+        let read_names: BTreeMap<_, _> = read_set
+            .keys()
+            .map(|id| {
+                let name = match self.catalog().try_get_entry(id) {
+                    Some(entry) => self
+                        .catalog()
+                        .resolve_full_name(entry.name(), None)
+                        .to_string(),
+                    None => id.to_string(),
+                };
+                (*id, name)
+            })
+            .collect();
 
         // Instrument our table writes since they can block the coordinator.
         let histogram = self
@@ -522,7 +579,7 @@ impl Coordinator {
         let append_fut = self
             .controller
             .storage
-            .append_table(timestamp, advance_to, appends)
+            .append_table_validated(timestamp, advance_to, appends, read_set)
             .expect("invalid updates")
             .wall_time()
             .observe(histogram);
@@ -541,6 +598,15 @@ impl Coordinator {
                     .instrument(debug_span!("group_commit_apply::append_fut"))
                     .await
                 {
+                    Ok(Err(StorageError::ReadConflict(id))) => {
+                        // The tables were still advanced, only the writes were dropped.
+                        let name = read_names[&id].clone();
+                        for response in &mut responses {
+                            response.set_error(AdapterError::ReadWriteTransactionConflict(
+                                name.clone(),
+                            ));
+                        }
+                    }
                     Ok(append_result) => {
                         append_result.unwrap_or_terminate("cannot fail to apply appends")
                     }
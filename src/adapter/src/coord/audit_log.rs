@@ -0,0 +1,428 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Recording of session and access events in the audit log, and the
+//! maintenance of the audit log itself: pruning expired events and exporting
+//! new events to an external file.
+
+use std::collections::BTreeSet;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_audit_log::{
+    EventDetails, EventType, LoginFailedV1, LoginV1, ObjectType, SelectV1, VersionedEvent,
+};
+use mz_ore::task;
+use mz_repr::GlobalId;
+use mz_sql::catalog::CatalogItem;
+use rand::distributions::{Bernoulli, Distribution};
+use rand::thread_rng;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+use crate::catalog::{self, catalog_type_to_audit_object_type, Catalog};
+use crate::coord::{Coordinator, Message};
+use crate::session::Session;
+
+/// How often the audit log is checked for events that have outlived
+/// `audit_log_retention_period`.
+const AUDIT_LOG_PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// The window over which failed logins are deduplicated and rate limited.
+const FAILED_LOGIN_WINDOW: Duration = Duration::from_secs(60);
+
+/// The maximum number of failed logins recorded per [`FAILED_LOGIN_WINDOW`].
+const MAX_FAILED_LOGINS_PER_WINDOW: usize = 100;
+
+/// Session and access events waiting to be recorded in the audit log.
+///
+/// Recording an event requires a durable catalog transaction, and some events,
+/// like failed logins, can be triggered by clients that haven't authenticated.
+/// Events are therefore buffered and recorded in a single transaction once the
+/// coordinator gets to [`Message::AuditLogFlush`], and failed logins are
+/// deduplicated and capped per [`FAILED_LOGIN_WINDOW`].
+#[derive(Debug, Default)]
+pub(crate) struct PendingAuditEvents {
+    /// Buffered events, along with the name of the user that caused them.
+    events: Vec<(Option<String>, EventType, ObjectType, EventDetails)>,
+    /// Whether a [`Message::AuditLogFlush`] is outstanding.
+    flush_scheduled: bool,
+    /// When the current failed login window started.
+    failed_login_window: Option<Instant>,
+    /// The role name, client IP and reason of the failed logins recorded in
+    /// the current window.
+    failed_logins: BTreeSet<(String, Option<String>, String)>,
+    /// The number of failed logins dropped in the current window.
+    failed_logins_dropped: u64,
+}
+
+impl PendingAuditEvents {
+    /// Reports whether the failed login described by `details` should be
+    /// recorded, or the reason it should be dropped.
+    fn admit_failed_login(
+        &mut self,
+        details: &LoginFailedV1,
+        now: Instant,
+    ) -> Result<(), &'static str> {
+        let expired = self.failed_login_window.map_or(true, |start| {
+            now.duration_since(start) >= FAILED_LOGIN_WINDOW
+        });
+        if expired {
+            if self.failed_logins_dropped > 0 {
+                warn!(
+                    "dropped {} failed login audit events in the last {FAILED_LOGIN_WINDOW:?}",
+                    self.failed_logins_dropped
+                );
+            }
+            self.failed_login_window = Some(now);
+            self.failed_logins.clear();
+            self.failed_logins_dropped = 0;
+        }
+
+        let key = (
+            details.role_name.clone(),
+            details.client_ip.clone(),
+            details.reason.clone(),
+        );
+        let res = if self.failed_logins.contains(&key) {
+            Err("duplicate")
+        } else if self.failed_logins.len() >= MAX_FAILED_LOGINS_PER_WINDOW {
+            Err("rate_limited")
+        } else {
+            self.failed_logins.insert(key);
+            Ok(())
+        };
+        if res.is_err() {
+            self.failed_logins_dropped += 1;
+        }
+        res
+    }
+}
+
+impl Coordinator {
+    /// Buffers `events` of type `event_type` to be appended to the audit log.
+    ///
+    /// Session and access events are best effort: failing to record them is
+    /// logged but does not fail the operation that produced them.
+    pub(crate) fn log_audit_events(
+        &mut self,
+        conn_id: Option<&ConnectionId>,
+        event_type: EventType,
+        events: Vec<(ObjectType, EventDetails)>,
+    ) {
+        if events.is_empty() || self.controller.read_only() {
+            return;
+        }
+        let user = conn_id
+            .and_then(|conn_id| self.active_conns.get(conn_id))
+            .map(|conn| conn.user().name.clone());
+        let now = Instant::now();
+        for (object_type, details) in events {
+            if let EventDetails::LoginFailedV1(failure) = &details {
+                if let Err(reason) = self.pending_audit_events.admit_failed_login(failure, now) {
+                    self.metrics
+                        .audit_log_failed_logins_dropped
+                        .with_label_values(&[reason])
+                        .inc();
+                    continue;
+                }
+            }
+            self.pending_audit_events.events.push((
+                user.clone(),
+                event_type.clone(),
+                object_type,
+                details,
+            ));
+        }
+
+        if !self.pending_audit_events.events.is_empty()
+            && !self.pending_audit_events.flush_scheduled
+        {
+            self.pending_audit_events.flush_scheduled = true;
+            // If sending fails, the main thread has shutdown.
+            let _ = self.internal_cmd_tx.send(Message::AuditLogFlush);
+        }
+    }
+
+    /// Records all buffered session and access events in the audit log in a
+    /// single catalog transaction.
+    #[mz_ore::instrument(level = "debug")]
+    pub(crate) async fn audit_log_flush(&mut self) {
+        self.pending_audit_events.flush_scheduled = false;
+        let events = std::mem::take(&mut self.pending_audit_events.events);
+        if events.is_empty() || self.controller.read_only() {
+            return;
+        }
+        let count = events.len();
+        let ops = events
+            .into_iter()
+            .map(
+                |(user, event_type, object_type, details)| catalog::Op::LogAuditEvent {
+                    user,
+                    event_type,
+                    object_type,
+                    details,
+                },
+            )
+            .collect();
+        if let Err(e) = self.catalog_transact_inner(None, ops).await {
+            warn!("unable to record {count} audit events: {e}");
+        }
+    }
+
+    /// Records a successful login by the connection `conn_id`, if logins are
+    /// being audited.
+    pub(crate) fn audit_login(&mut self, conn_id: &ConnectionId) {
+        if !self.catalog().system_config().audit_log_logins() {
+            return;
+        }
+        let conn = &self.active_conns[conn_id];
+        let details = EventDetails::LoginV1(LoginV1 {
+            role_name: conn.user().name.clone(),
+            session_id: conn.uuid().to_string(),
+            application_name: conn.application_name().to_string(),
+            client_ip: conn.client_ip().map(|ip| ip.to_string()),
+        });
+        self.log_audit_events(
+            Some(conn_id),
+            EventType::Login,
+            vec![(ObjectType::Role, details)],
+        );
+    }
+
+    /// Records a failed attempt to log in as `role_name`, if logins are being
+    /// audited.
+    pub(crate) fn audit_login_failure(
+        &mut self,
+        role_name: String,
+        client_ip: Option<IpAddr>,
+        reason: String,
+    ) {
+        if !self.catalog().system_config().audit_log_logins() {
+            return;
+        }
+        let details = EventDetails::LoginFailedV1(LoginFailedV1 {
+            role_name,
+            client_ip: client_ip.map(|ip| ip.to_string()),
+            reason,
+        });
+        self.log_audit_events(
+            None,
+            EventType::LoginFailed,
+            vec![(ObjectType::Role, details)],
+        );
+    }
+
+    /// Records a connection attempt by `role_name` that a network policy
+    /// denied.
+    ///
+    /// Unlike other failed logins, denials are recorded whether or not logins
+    /// are being audited, though they are subject to the same deduplication
+    /// and rate limit. Every denial is counted in the
+    /// `mz_network_policy_denials_total` metric, labeled with `interface`.
+    pub(crate) fn audit_network_policy_denied(
+        &mut self,
+        interface: &'static str,
+        role_name: String,
+        client_ip: Option<IpAddr>,
+        reason: String,
+    ) {
+        self.metrics
+            .network_policy_denials
+            .with_label_values(&[interface])
+            .inc();
+        debug!(%interface, %role_name, ?client_ip, "network policy denied connection");
+        let details = EventDetails::LoginFailedV1(LoginFailedV1 {
+            role_name,
+            client_ip: client_ip.map(|ip| ip.to_string()),
+            reason,
+        });
+        self.log_audit_events(
+            None,
+            EventType::LoginFailed,
+            vec![(ObjectType::Role, details)],
+        );
+    }
+
+    /// Returns the audit events to record for a read of `ids` by `session`.
+    ///
+    /// An object is audited if it, or any object it transitively depends on,
+    /// is listed in `audit_log_select_objects`, subject to that entry's sample
+    /// rate.
+    pub(crate) fn select_audit_events(
+        &self,
+        session: &Session,
+        ids: &BTreeSet<GlobalId>,
+    ) -> Vec<(ObjectType, EventDetails)> {
+        let audited = self.catalog().system_config().audit_log_select_objects();
+        if audited.is_empty() {
+            return Vec::new();
+        }
+
+        let state = self.catalog().state();
+        let conn_id = session.conn_id();
+        let used: BTreeSet<_> = ids
+            .iter()
+            .flat_map(|id| state.transitive_uses(*id))
+            .collect();
+
+        let mut events = Vec::new();
+        for id in used {
+            let entry = state.get_entry(&id);
+            let name = state.resolve_full_name(entry.name(), Some(conn_id));
+            let Some(audited) = audited.iter().find(|o| o.name == name.to_string()) else {
+                continue;
+            };
+            let distribution =
+                Bernoulli::new(audited.sample_rate).expect("rate must be in range [0, 1]");
+            if !distribution.sample(&mut thread_rng()) {
+                continue;
+            }
+            events.push((
+                catalog_type_to_audit_object_type(entry.item_type()),
+                EventDetails::SelectV1(SelectV1 {
+                    id: id.to_string(),
+                    name: Catalog::full_name_detail(&name),
+                    session_id: session.uuid().to_string(),
+                }),
+            ));
+        }
+        events
+    }
+
+    /// Determines which audit events have outlived `audit_log_retention_period`
+    /// in a background task, which reports them back with
+    /// [`Message::AuditLogPrune`].
+    pub(crate) fn audit_log_prune_fetch(&self) {
+        let retention_period = self.catalog().system_config().audit_log_retention_period();
+        if retention_period.is_zero() || self.controller.read_only() {
+            self.schedule_audit_log_prune();
+            return;
+        }
+
+        let cutoff = u128::from(self.now()).saturating_sub(retention_period.as_millis());
+        let catalog = self.owned_catalog();
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "audit_log_prune_fetch", async move {
+            let expired = match catalog.get_audit_logs().await {
+                Ok(events) => events
+                    .into_iter()
+                    .filter(|event| match event {
+                        VersionedEvent::V1(event) => u128::from(event.occurred_at) < cutoff,
+                    })
+                    .collect(),
+                Err(e) => {
+                    warn!("unable to read audit log for pruning: {e}");
+                    Vec::new()
+                }
+            };
+            // If sending fails, the main thread has shutdown.
+            let _ = internal_cmd_tx.send(Message::AuditLogPrune(expired));
+        });
+    }
+
+    /// Removes the `expired` events from the audit log and schedules the next
+    /// pruning.
+    #[mz_ore::instrument(level = "debug")]
+    pub(crate) async fn audit_log_prune(&mut self, expired: Vec<VersionedEvent>) {
+        if !expired.is_empty() {
+            let ops = vec![catalog::Op::PruneAuditLog { events: expired }];
+            if let Err(e) = self.catalog_transact_inner(None, ops).await {
+                warn!("unable to prune audit log: {e}");
+            }
+        }
+        self.schedule_audit_log_prune();
+    }
+
+    fn schedule_audit_log_prune(&self) {
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "audit_log_prune_schedule", async move {
+            tokio::time::sleep(AUDIT_LOG_PRUNE_INTERVAL).await;
+            // If sending fails, the main thread has shutdown.
+            let _ = internal_cmd_tx.send(Message::AuditLogPruneFetch);
+        });
+    }
+}
+
+/// Spawns a task that appends every audit event sent to the returned channel
+/// to `file`, one JSON object per line.
+pub(crate) fn spawn_audit_log_export_task(
+    file: std::fs::File,
+) -> mpsc::UnboundedSender<VersionedEvent> {
+    let (tx, mut rx) = mpsc::unbounded_channel::<VersionedEvent>();
+    let mut file = tokio::fs::File::from_std(file);
+    task::spawn(|| "audit_log_export", async move {
+        info!("exporting audit log events");
+        while let Some(event) = rx.recv().await {
+            let mut line = event.serialize();
+            line.push(b'\n');
+            let res = async {
+                file.write_all(&line).await?;
+                file.flush().await
+            }
+            .await;
+            if let Err(e) = res {
+                warn!("unable to export audit log event: {e}");
+            }
+        }
+    });
+    tx
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use mz_audit_log::LoginFailedV1;
+
+    use super::{PendingAuditEvents, FAILED_LOGIN_WINDOW, MAX_FAILED_LOGINS_PER_WINDOW};
+
+    fn failure(role_name: &str) -> LoginFailedV1 {
+        LoginFailedV1 {
+            role_name: role_name.into(),
+            client_ip: Some("10.0.0.1".into()),
+            reason: "invalid password".into(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_failed_login_rate_limit() {
+        let mut pending = PendingAuditEvents::default();
+        let start = Instant::now();
+
+        // Repeated failures are recorded once per window.
+        assert_eq!(pending.admit_failed_login(&failure("a"), start), Ok(()));
+        assert_eq!(
+            pending.admit_failed_login(&failure("a"), start),
+            Err("duplicate")
+        );
+
+        // Distinct failures are capped per window.
+        for i in 1..MAX_FAILED_LOGINS_PER_WINDOW {
+            let role_name = format!("r{i}");
+            assert_eq!(
+                pending.admit_failed_login(&failure(&role_name), start),
+                Ok(())
+            );
+        }
+        assert_eq!(
+            pending.admit_failed_login(&failure("b"), start),
+            Err("rate_limited")
+        );
+        assert_eq!(pending.failed_logins_dropped, 2);
+
+        // A new window starts afresh.
+        let next = start + FAILED_LOGIN_WINDOW + Duration::from_secs(1);
+        assert_eq!(pending.admit_failed_login(&failure("a"), next), Ok(()));
+        assert_eq!(pending.admit_failed_login(&failure("b"), next), Ok(()));
+        assert_eq!(pending.failed_logins_dropped, 0);
+    }
+}
@@ -118,6 +118,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterSetCluster(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
+        | Plan::AlterRetention(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
@@ -142,6 +143,13 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::RevokePrivileges(_)
         | Plan::AlterDefaultPrivileges(_)
         | Plan::ReassignOwned(_)
+        | Plan::AlterRowSecurity(_)
+        | Plan::AlterColumnMask(_)
+        | Plan::CreatePolicy(_)
+        | Plan::DropPolicy(_)
+        | Plan::CreateResourceGroup(_)
+        | Plan::AlterResourceGroup(_)
+        | Plan::DropResourceGroup(_)
         | Plan::ValidateConnection(_)
         | Plan::SideEffectingFunc(_) => return TargetCluster::Active,
     };
@@ -42,9 +42,9 @@ use mz_sql::pure::{
 };
 use mz_sql::rbac;
 use mz_sql::rbac::CREATE_ITEM_USAGE;
-use mz_sql::session::user::User;
+use mz_sql::session::user::{User, MZ_EXTERNAL_ROLE_SYNC_ROLE_ID};
 use mz_sql::session::vars::{
-    EndTransactionAction, OwnedVarInput, Value, Var, STATEMENT_LOGGING_SAMPLE_RATE,
+    EndTransactionAction, OwnedVarInput, Value, Var, NETWORK_POLICY, STATEMENT_LOGGING_SAMPLE_RATE,
 };
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
@@ -54,7 +54,7 @@ use mz_sql_parser::ast::{
 use mz_storage_types::sources::Timeline;
 use opentelemetry::trace::TraceContextExt;
 use tokio::sync::{mpsc, oneshot};
-use tracing::{debug_span, warn, Instrument};
+use tracing::{debug, debug_span, warn, Instrument};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 use crate::command::{
@@ -62,8 +62,8 @@ use crate::command::{
 };
 use crate::coord::appends::PendingWriteTxn;
 use crate::coord::{
-    ConnMeta, Coordinator, DeferredPlanStatement, Message, NetworkPolicy, PendingTxn,
-    PlanStatement, PlanValidity, PurifiedStatementReady,
+    ConnMeta, Coordinator, DeferredPlanStatement, Message, NetworkPolicy, NetworkPolicyError,
+    PendingTxn, PlanStatement, PlanValidity, PurifiedStatementReady,
 };
 use crate::error::AdapterError;
 use crate::notice::AdapterNotice;
@@ -237,6 +237,22 @@ impl Coordinator {
                 Command::Dump { tx } => {
                     let _ = tx.send(self.dump().await);
                 }
+
+                Command::AuditLoginFailure {
+                    user,
+                    client_ip,
+                    reason,
+                } => {
+                    self.audit_login_failure(user, client_ip, reason);
+                }
+
+                Command::AuditNetworkPolicyDenied {
+                    user,
+                    client_ip,
+                    reason,
+                } => {
+                    self.audit_network_policy_denied("webhook", user, client_ip, reason);
+                }
             }
         }
         .instrument(debug_span!("handle_command"))
@@ -308,6 +324,7 @@ impl Coordinator {
                 let update = self.catalog().state().resolve_builtin_table_update(update);
                 self.begin_session_for_statement_logging(&conn);
                 self.active_conns.insert(conn_id.clone(), conn);
+                self.audit_login(&conn_id);
 
                 // Note: Do NOT await the notify here, we pass this back to
                 // whatever requested the startup to prevent blocking the
@@ -333,6 +350,15 @@ impl Coordinator {
                 self.catalog_mut()
                     .drop_temporary_schema(&conn_id)
                     .unwrap_or_terminate("unable to drop temporary schema");
+                match &e {
+                    AdapterError::NetworkPolicyDenied(err) => self.audit_network_policy_denied(
+                        "session",
+                        user.name,
+                        client_ip,
+                        err.to_string(),
+                    ),
+                    _ => self.audit_login_failure(user.name, client_ip, e.to_string()),
+                }
 
                 // Communicate the error back to the client. No need to
                 // handle failures to send the error back; we've already
@@ -342,6 +368,38 @@ impl Coordinator {
         }
     }
 
+    /// Returns the network policy that applies to connections by the role
+    /// named `role_name`.
+    ///
+    /// The role's own `network_policy` default takes precedence over the
+    /// system default. If neither names a policy, connections are validated
+    /// against `default_network_policy_allow_list`. Naming a policy that does
+    /// not exist denies all connections.
+    pub(crate) fn network_policy_for_role(
+        &self,
+        role_name: &str,
+    ) -> Result<NetworkPolicy, NetworkPolicyError> {
+        let catalog = self.catalog();
+        let system_config = catalog.system_config();
+        let role_policy = catalog.try_get_role_by_name(role_name).and_then(|role| {
+            role.vars()
+                .find(|(name, _)| *name == NETWORK_POLICY.name())
+                .map(|(_, value)| match value {
+                    OwnedVarInput::Flat(name) => name.clone(),
+                    OwnedVarInput::SqlSet(names) => names.join(", "),
+                })
+        });
+        let policy_name =
+            role_policy.unwrap_or_else(|| system_config.default_network_policy_name());
+        if policy_name.is_empty() {
+            return Ok(NetworkPolicy::new(system_config.default_network_policy()));
+        }
+        match catalog.try_get_network_policy_by_name(&policy_name) {
+            Some(policy) => Ok(NetworkPolicy::from_rules(&policy.rules)),
+            None => Err(NetworkPolicyError::UnknownPolicy(policy_name)),
+        }
+    }
+
     // Failible startup work that needs to be cleaned up on error.
     async fn handle_startup_inner(
         &mut self,
@@ -353,11 +411,12 @@ impl Coordinator {
         // can only connect on the internal interfaces (internal HTTP/
         // pgwire). It is up to the person deploying the system to
         // ensure these internal interfaces are well secured.
-        let system_config = self.catalog().state().system_config();
         if !user.is_internal() {
-            let default_policy = NetworkPolicy::new(system_config.default_network_policy());
+            let policy = self
+                .network_policy_for_role(&user.name)
+                .map_err(AdapterError::NetworkPolicyDenied)?;
             if let Some(ip) = client_ip {
-                match default_policy.validate(ip) {
+                match policy.validate(ip) {
                     Ok(_) => {}
                     Err(e) => return Err(AdapterError::NetworkPolicyDenied(e)),
                 }
@@ -366,7 +425,7 @@ impl Coordinator {
                 // should be missing a client_ip. These sessions should not be
                 // making requests or going through handle_startup.
                 return Err(AdapterError::NetworkPolicyDenied(
-                    super::NetworkPolicyError::MissingIp,
+                    NetworkPolicyError::MissingIp,
                 ));
             }
         }
@@ -393,11 +452,79 @@ impl Coordinator {
             return Err(AdapterError::UserSessionsDisallowed);
         }
 
+        if let Some(roles) = user
+            .external_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.roles.as_ref())
+        {
+            self.sync_external_role_membership(role_id, roles).await?;
+        }
+
         self.catalog_mut()
             .create_temporary_schema(conn_id, role_id)?;
         Ok(role_id)
     }
 
+    /// Synchronizes the role membership of `member_id` with the roles that an
+    /// external identity provider reports it to be a member of.
+    ///
+    /// Memberships granted by this synchronization are recorded with the
+    /// dedicated `mz_external_role_sync` grantor, and are revoked once the
+    /// identity provider no longer reports them. Memberships granted by any
+    /// other role, including `mz_system`, are left alone. Roles that do not
+    /// exist, that cannot be granted, or that would introduce a membership
+    /// cycle are ignored.
+    async fn sync_external_role_membership(
+        &mut self,
+        member_id: RoleId,
+        roles: &[String],
+    ) -> Result<(), AdapterError> {
+        let catalog = self.catalog();
+        let mut desired = BTreeSet::new();
+        for name in roles {
+            let Some(role) = catalog.try_get_role_by_name(name) else {
+                debug!(%name, "ignoring unknown role from external identity provider");
+                continue;
+            };
+            if role.id == member_id
+                || catalog.ensure_grantable_role(&role.id).is_err()
+                || catalog
+                    .state()
+                    .collect_role_membership(&role.id)
+                    .contains(&member_id)
+            {
+                debug!(%name, "ignoring ungrantable role from external identity provider");
+                continue;
+            }
+            desired.insert(role.id);
+        }
+
+        let membership = &catalog.get_role(&member_id).membership.map;
+        let grants = desired
+            .iter()
+            .filter(|role_id| !membership.contains_key(role_id))
+            .map(|role_id| catalog::Op::GrantRole {
+                role_id: *role_id,
+                member_id,
+                grantor_id: MZ_EXTERNAL_ROLE_SYNC_ROLE_ID,
+            });
+        let revokes = membership
+            .iter()
+            .filter(|(role_id, grantor_id)| {
+                **grantor_id == MZ_EXTERNAL_ROLE_SYNC_ROLE_ID && !desired.contains(*role_id)
+            })
+            .map(|(role_id, grantor_id)| catalog::Op::RevokeRole {
+                role_id: *role_id,
+                member_id,
+                grantor_id: *grantor_id,
+            });
+        let ops: Vec<_> = grants.chain(revokes).collect();
+        if ops.is_empty() {
+            return Ok(());
+        }
+        self.catalog_transact(None, ops).await
+    }
+
     /// Handles an execute command.
     #[instrument(name = "coord::handle_execute", fields(session = session.uuid().to_string()))]
     pub(crate) async fn handle_execute(
@@ -626,6 +753,15 @@ impl Coordinator {
                         // any cluster (no RETURNING) is always safe.
                     }
 
+                    // Writes that follow reads in an explicit transaction are safe if the
+                    // transaction can be validated against those reads at commit.
+                    Statement::Insert(_) | Statement::Update(_) | Statement::Delete(_)
+                        if self
+                            .catalog()
+                            .system_config()
+                            .enable_read_write_transactions()
+                            && ctx.session().transaction().can_upgrade_to_read_write() => {}
+
                     // These statements must be kept in-sync with `must_serialize_ddl()`.
                     Statement::AlterObjectRename(_) | Statement::AlterObjectSwap(_) => {
                         let state = self.catalog().for_session(ctx.session()).state().clone();
@@ -651,7 +787,11 @@ impl Coordinator {
                     | Statement::AlterSetCluster(_)
                     | Statement::AlterOwner(_)
                     | Statement::AlterRetainHistory(_)
+                    | Statement::AlterRetention(_)
+                    | Statement::AlterResourceGroup(_)
                     | Statement::AlterRole(_)
+                    | Statement::AlterRowSecurity(_)
+                    | Statement::AlterColumnMask(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
@@ -679,9 +819,13 @@ impl Coordinator {
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::CreateNetworkPolicy(_)
+                    | Statement::CreatePolicy(_)
+                    | Statement::CreateResourceGroup(_)
                     | Statement::Delete(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropPolicy(_)
+                    | Statement::DropResourceGroup(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
@@ -1354,11 +1498,17 @@ impl Coordinator {
                 .or_insert_with(WebhookAppenderInvalidator::new);
             let tx = WebhookAppender::new(row_tx, invalidator.guard(), stats);
 
+            // Requests are subject to the network policy of the source's owner.
+            let owner = coord.catalog().get_role(entry.owner_id()).name.clone();
+            let network_policy = coord.network_policy_for_role(&owner);
+
             Ok(AppendWebhookResponse {
                 tx,
                 body_format,
                 header_tys,
                 validator,
+                owner,
+                network_policy,
             })
         }
 
@@ -19,7 +19,7 @@ use futures::Future;
 use maplit::{btreemap, btreeset};
 use mz_adapter_types::compaction::SINCE_GRANULARITY;
 use mz_adapter_types::connection::ConnectionId;
-use mz_audit_log::VersionedEvent;
+use mz_audit_log::{EventType, VersionedEvent};
 use mz_catalog::memory::objects::{
     CatalogItem, Connection, ContinualTask, DataSourceDesc, Index, MaterializedView, Sink,
 };
@@ -219,6 +219,8 @@ impl Coordinator {
         let mut update_cluster_scheduling_config = false;
         let mut update_arrangement_exert_proportionality = false;
         let mut update_http_config = false;
+        // Webhook appenders cache the network policy of their source's owner.
+        let mut update_network_policies = false;
 
         for op in &ops {
             match op {
@@ -254,6 +256,15 @@ impl Coordinator {
                                     CatalogItem::Sink(Sink { .. }) => {
                                         storage_sinks_to_drop.push(*id);
                                     }
+                                    CatalogItem::Index(Index {
+                                        cluster_id,
+                                        persisted: true,
+                                        ..
+                                    }) => {
+                                        // Like materialized views, persisted indexes consist of a
+                                        // compute sink and a storage collection.
+                                        materialized_views_to_drop.push((*cluster_id, *id));
+                                    }
                                     CatalogItem::Index(Index { cluster_id, .. }) => {
                                         indexes_to_drop.push((*cluster_id, *id));
                                     }
@@ -301,6 +312,9 @@ impl Coordinator {
                                 // Drop the cluster replica itself.
                                 cluster_replicas_to_drop.push((*cluster_id, *replica_id));
                             }
+                            catalog::DropObjectInfo::NetworkPolicy(_) => {
+                                update_network_policies = true;
+                            }
                             _ => (),
                         }
                     }
@@ -326,6 +340,8 @@ impl Coordinator {
                     update_arrangement_exert_proportionality |=
                         name == vars::ARRANGEMENT_EXERT_PROPORTIONALITY.name();
                     update_http_config |= vars::is_http_config_var(name);
+                    update_network_policies |= name == vars::NETWORK_POLICY.name()
+                        || name == vars::DEFAULT_NETWORK_POLICY_ALLOW_LIST.name();
                 }
                 catalog::Op::ResetAllSystemConfiguration => {
                     // Assume they all need to be updated.
@@ -340,6 +356,13 @@ impl Coordinator {
                     update_cluster_scheduling_config = true;
                     update_arrangement_exert_proportionality = true;
                     update_http_config = true;
+                    update_network_policies = true;
+                }
+                catalog::Op::CreateNetworkPolicy { .. }
+                | catalog::Op::AlterNetworkPolicy { .. }
+                | catalog::Op::AlterRole { .. }
+                | catalog::Op::UpdateOwner { .. } => {
+                    update_network_policies = true;
                 }
                 catalog::Op::RenameItem { id, .. } => {
                     let item = self.catalog().get_entry(id);
@@ -388,6 +411,10 @@ impl Coordinator {
             }
         }
 
+        if update_network_policies {
+            webhook_sources_to_restart.extend(self.active_webhooks.keys().copied());
+        }
+
         let relations_to_drop: BTreeSet<_> = sources_to_drop
             .iter()
             .chain(tables_to_drop.iter())
@@ -789,9 +816,24 @@ impl Coordinator {
         .instrument(info_span!("coord::catalog_transact_with::finalize"))
         .await;
 
+        if let Some(audit_log_export_tx) = &self.audit_log_export_tx {
+            for event in &audit_events {
+                // The export task only shuts down with the coordinator.
+                let _ = audit_log_export_tx.send(event.clone());
+            }
+        }
+
         let conn = conn_id.and_then(|id| self.active_conns.get(id));
         if let Some(segment_client) = &self.segment_client {
             for VersionedEvent::V1(event) in audit_events {
+                // Session and access events are only of interest to the
+                // environment's own auditors.
+                if matches!(
+                    event.event_type,
+                    EventType::Login | EventType::LoginFailed | EventType::Select
+                ) {
+                    continue;
+                }
                 let event_type = format!(
                     "{} {}",
                     event.object_type.as_title_case(),
@@ -1202,7 +1244,11 @@ impl Coordinator {
 
     fn update_pg_timestamp_oracle_config(&self) {
         let config_params = flags::pg_timstamp_oracle_config(self.catalog().system_config());
-        if let Some(config) = self.pg_timestamp_oracle_config.as_ref() {
+        if let Some(config) = self
+            .timestamp_oracle_config
+            .as_ref()
+            .and_then(|config| config.pg_config())
+        {
             config_params.apply(config)
         }
     }
@@ -1551,8 +1597,15 @@ impl Coordinator {
                 },
                 Op::AlterRole { .. }
                 | Op::AlterRetainHistory { .. }
+                | Op::AlterRetention { .. }
+                | Op::AlterRowSecurity { .. }
+                | Op::AlterColumnMask { .. }
                 | Op::AlterNetworkPolicy { .. }
+                | Op::AlterResourceGroup { .. }
+                | Op::CreateResourceGroup { .. }
+                | Op::DropResourceGroup { .. }
                 | Op::UpdatePrivilege { .. }
+                | Op::UpdateColumnPrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
                 | Op::RenameCluster { .. }
@@ -1568,6 +1621,10 @@ impl Coordinator {
                 | Op::ResetSystemConfiguration { .. }
                 | Op::ResetAllSystemConfiguration { .. }
                 | Op::Comment { .. }
+                | Op::CreatePolicy { .. }
+                | Op::DropPolicy { .. }
+                | Op::LogAuditEvent { .. }
+                | Op::PruneAuditLog { .. }
                 | Op::WeirdStorageUsageUpdates { .. }
                 | Op::TransactionDryRun => {}
             }
@@ -48,6 +48,8 @@ impl DataflowBuilder<'_> {
                     .entry(self.compute.instance_id())
                     .or_default()
                     .extend(available_indexes);
+            } else if let Some((index_id, _)) = self.persisted_index_on(id) {
+                id_bundle.storage_ids.insert(index_id);
             } else {
                 match self.catalog.get_entry(&id).item() {
                     // Unmaterialized view. Search its dependencies.
@@ -86,9 +88,31 @@ impl DataflowBuilder<'_> {
     pub fn indexes_on(&self, id: GlobalId) -> impl Iterator<Item = (GlobalId, &Index)> {
         self.catalog
             .get_indexes_on(id, self.compute.instance_id())
+            .filter(|(_idx_id, idx)| !idx.persisted)
             .filter(|(idx_id, _idx)| self.compute.contains_collection(idx_id))
             .filter(|(idx_id, _idx)| self.replan.map_or(true, |id| idx_id < &id))
     }
+
+    /// Returns the persisted index on `id` that dataflows import in the absence of an index on
+    /// the compute instance, if any.
+    ///
+    /// Persisted indexes are written to storage collections, so unlike [`Self::indexes_on`],
+    /// this considers indexes on all clusters. If there are several, the oldest one is chosen.
+    pub fn persisted_index_on(&self, id: GlobalId) -> Option<(GlobalId, &Index)> {
+        if self.ignore_persisted_indexes_on == Some(id) {
+            return None;
+        }
+        self.catalog
+            .get_entry(&id)
+            .used_by()
+            .iter()
+            .filter_map(|idx_id| match self.catalog.get_entry(idx_id).item() {
+                CatalogItem::Index(idx) if idx.persisted && idx.on == id => Some((*idx_id, idx)),
+                _ => None,
+            })
+            .filter(|(idx_id, _idx)| self.replan.map_or(true, |id| idx_id < &id))
+            .min_by_key(|(idx_id, _idx)| *idx_id)
+    }
 }
 
 impl IndexOracle for DataflowBuilder<'_> {
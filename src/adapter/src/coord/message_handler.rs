@@ -110,6 +110,9 @@ impl Coordinator {
             Message::CancelPendingPeeks { conn_id } => {
                 self.cancel_pending_peeks(&conn_id);
             }
+            Message::PeekTimeout { uuid } => {
+                self.handle_peek_timeout(uuid);
+            }
             Message::LinearizeReads => {
                 self.message_linearize_reads().boxed_local().await;
             }
@@ -125,6 +128,21 @@ impl Coordinator {
             Message::StorageUsagePrune(expired) => {
                 self.storage_usage_prune(expired).boxed_local().await;
             }
+            Message::AuditLogFlush => {
+                self.audit_log_flush().boxed_local().await;
+            }
+            Message::AuditLogPruneFetch => {
+                self.audit_log_prune_fetch();
+            }
+            Message::AuditLogPrune(expired) => {
+                self.audit_log_prune(expired).boxed_local().await;
+            }
+            Message::RetentionFetch => {
+                self.retention_fetch().boxed_local().await;
+            }
+            Message::RetentionApply(runs) => {
+                self.retention_apply(runs);
+            }
             Message::RetireExecute {
                 otel_ctx,
                 data,
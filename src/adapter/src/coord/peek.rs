@@ -12,9 +12,10 @@
 //! This module determines if a dataflow can be short-cut, by returning constant values
 //! or by reading out of existing arrangements, and implements the appropriate plan.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt;
 use std::num::NonZeroUsize;
+use std::time::Duration;
 
 use differential_dataflow::consolidation::consolidate;
 use futures::TryFutureExt;
@@ -38,12 +39,16 @@ use mz_ore::tracing::OpenTelemetryContext;
 use mz_repr::explain::text::DisplayText;
 use mz_repr::explain::{CompactScalars, IndexUsageType, PlanRenderingContext, UsedIndexes};
 use mz_repr::{Diff, GlobalId, IntoRowIterator, RelationType, Row, RowCollection, RowIterator};
+use mz_sql::session::metadata::SessionMetadata;
 use serde::{Deserialize, Serialize};
 use timely::progress::Timestamp;
+use tokio::sync::oneshot;
 use uuid::Uuid;
 
 use crate::coord::timestamp_selection::TimestampDetermination;
+use crate::coord::Message;
 use crate::optimize::OptimizerError;
+use crate::session::Session;
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::util::ResultExt;
 use crate::{AdapterError, ExecuteContextExtra, ExecuteResponse};
@@ -62,6 +67,35 @@ pub(crate) struct PendingPeek {
     pub(crate) ctx_extra: ExecuteContextExtra,
     /// Is this a fast-path peek, i.e. one that doesn't require a dataflow?
     pub(crate) is_fast_path: bool,
+    /// The resource group that the peek counts against, if any.
+    pub(crate) resource_group: Option<String>,
+}
+
+/// The peeks that a resource group has admitted, queued, and rejected.
+#[derive(Debug, Default)]
+pub(crate) struct ResourceGroupPeeks {
+    /// The number of admitted peeks that have not yet completed.
+    active: u32,
+    /// Peeks waiting for an admitted peek to complete before retrying admission.
+    queued: VecDeque<oneshot::Sender<()>>,
+    /// The number of peeks rejected because the queue was full.
+    rejected: u64,
+    /// The queue depth and rejection count last written to
+    /// `mz_resource_group_statuses`, if any.
+    status: Option<(u64, u64)>,
+}
+
+/// The outcome of admitting a peek to the resource group of its role.
+#[derive(Debug)]
+pub(crate) enum ResourceGroupAdmission {
+    /// The peek may run, counting against the named resource group, if any.
+    Admitted(Option<String>),
+    /// The peek must retry admission once `rx` fires, and must give up if that
+    /// takes longer than `timeout`.
+    Queued {
+        rx: oneshot::Receiver<()>,
+        timeout: Option<Duration>,
+    },
 }
 
 /// The response from a `Peek`, with row multiplicities represented in unary.
@@ -375,7 +409,11 @@ pub fn create_fast_path_plan<T: Timestamp>(
                             )));
                         }
                     }
-                    // If there is no arrangement, consider peeking the persist shard directly
+                    // If there is no arrangement, consider peeking the persist shard directly,
+                    // unless the collection is read from a persisted index.
+                    if !dataflow_plan.source_imports.contains_key(get_id) {
+                        return Ok(None);
+                    }
                     let safe_mfp = mfp_to_safe_plan(mfp)?;
                     let (_m, filters, _p) = safe_mfp.as_map_filter_project();
                     let small_finish = match &finishing {
@@ -446,15 +484,22 @@ impl crate::coord::Coordinator {
         target_replica: Option<ReplicaId>,
         max_result_size: u64,
         max_returned_query_size: Option<u64>,
+        resource_group: Option<String>,
     ) -> Result<crate::ExecuteResponse, AdapterError> {
         let PlannedPeek {
             plan: fast_path,
             determination,
             conn_id,
             source_arity,
-            source_ids,
+            mut source_ids,
         } = plan;
 
+        // Peeks also depend on the persisted indexes they read, so that they are canceled when
+        // one of those is dropped.
+        if let PeekPlan::SlowPath(plan) = &fast_path {
+            source_ids.extend(plan.desc.persisted_index_imports().map(|(id, _)| *id));
+        }
+
         // If the dataflow optimizes to a constant expression, we can immediately return the result.
         if let PeekPlan::FastPath(FastPathPlan::Constant(rows, _)) = fast_path {
             let mut rows = match rows {
@@ -615,6 +660,7 @@ impl crate::coord::Coordinator {
                 depends_on: source_ids,
                 ctx_extra: std::mem::take(ctx_extra),
                 is_fast_path,
+                resource_group: resource_group.clone(),
             },
         );
         self.client_pending_peeks
@@ -637,6 +683,22 @@ impl crate::coord::Coordinator {
                 rows_tx,
             )
             .unwrap_or_terminate("cannot fail to peek");
+
+        // Resource group timeouts cannot be overridden by the session, so we enforce them here
+        // rather than relying on the client to cancel the statement.
+        if let Some(timeout) = resource_group
+            .as_ref()
+            .and_then(|name| self.catalog().state().get_resource_group(name))
+            .and_then(|group| group.statement_timeout)
+        {
+            let internal_cmd_tx = self.internal_cmd_tx.clone();
+            mz_ore::task::spawn(|| "resource group peek timeout", async move {
+                tokio::time::sleep(timeout).await;
+                // It is not an error for this task to be running after `internal_cmd_rx` is dropped.
+                let _ = internal_cmd_tx.send(Message::PeekTimeout { uuid });
+            });
+        }
+
         let duration_histogram = self.metrics.row_set_finishing_seconds();
 
         // Prepare the receiver to return as a response.
@@ -704,6 +766,9 @@ impl crate::coord::Coordinator {
                 .filter_map(|(uuid, _)| self.pending_peeks.remove(uuid))
                 .collect::<Vec<_>>();
             for peek in peeks {
+                if let Some(resource_group) = &peek.resource_group {
+                    self.release_resource_group_peek(resource_group);
+                }
                 self.retire_execution(StatementEndedExecutionReason::Canceled, peek.ctx_extra);
             }
         }
@@ -725,6 +790,7 @@ impl crate::coord::Coordinator {
             depends_on: _,
             ctx_extra,
             is_fast_path,
+            resource_group: _,
         }) = self.remove_pending_peek(&uuid)
         {
             let reason = match notification {
@@ -761,10 +827,130 @@ impl crate::coord::Coordinator {
             if uuids.is_empty() {
                 self.client_pending_peeks.remove(&pending_peek.conn_id);
             }
+            if let Some(resource_group) = &pending_peek.resource_group {
+                self.release_resource_group_peek(resource_group);
+            }
         }
         pending_peek
     }
 
+    /// Cancels the peek `uuid` because it exceeded the statement timeout of its resource
+    /// group. Does nothing for already-removed peeks.
+    pub(crate) fn handle_peek_timeout(&mut self, uuid: Uuid) {
+        if let Some(pending_peek) = self.pending_peeks.get(&uuid) {
+            // The cluster may have been dropped while the peek was in progress, in which case
+            // there is nothing left to cancel.
+            let _ = self.controller.compute.cancel_peek(
+                pending_peek.cluster_id,
+                uuid,
+                PeekResponse::Error(AdapterError::StatementTimeout.to_string()),
+            );
+        }
+    }
+
+    /// Admits a peek of the current role of `session` to the role's resource group.
+    ///
+    /// If the resource group is already running its maximum number of concurrent peeks, the
+    /// peek is queued, or rejected if the queue is full. `requeued` indicates that the peek has
+    /// already waited in the queue, in which case it retains its place at the head of the queue.
+    pub(crate) fn admit_resource_group_peek(
+        &mut self,
+        session: &Session,
+        requeued: bool,
+    ) -> Result<ResourceGroupAdmission, AdapterError> {
+        let Some((name, group)) = self
+            .catalog()
+            .state()
+            .get_role_resource_group(session.current_role_id())
+        else {
+            return Ok(ResourceGroupAdmission::Admitted(None));
+        };
+        let name = name.to_string();
+        let max_concurrent_peeks = group.max_concurrent_peeks;
+        let max_queued_peeks = group.max_queued_peeks.unwrap_or(0);
+        let timeout = group.statement_timeout;
+
+        let peeks = self.resource_group_peeks.entry(name.clone()).or_default();
+        // Peeks that were canceled or timed out while queued no longer hold a place in the queue.
+        peeks.queued.retain(|tx| !tx.is_closed());
+        let admission = match max_concurrent_peeks {
+            Some(max_concurrent_peeks) if peeks.active >= max_concurrent_peeks => {
+                if requeued || peeks.queued.len() < usize::cast_from(max_queued_peeks) {
+                    let (tx, rx) = oneshot::channel();
+                    if requeued {
+                        peeks.queued.push_front(tx);
+                    } else {
+                        peeks.queued.push_back(tx);
+                    }
+                    Ok(ResourceGroupAdmission::Queued { rx, timeout })
+                } else {
+                    peeks.rejected += 1;
+                    Err(AdapterError::ResourceGroupQueueFull {
+                        name: name.clone(),
+                        limit: max_queued_peeks,
+                    })
+                }
+            }
+            _ => {
+                peeks.active += 1;
+                Ok(ResourceGroupAdmission::Admitted(Some(name.clone())))
+            }
+        };
+        self.update_resource_group_status(&name);
+        admission
+    }
+
+    /// Releases a completed peek from resource group `name`, waking the next queued peek.
+    pub(crate) fn release_resource_group_peek(&mut self, name: &str) {
+        let Some(peeks) = self.resource_group_peeks.get_mut(name) else {
+            return;
+        };
+        peeks.active = peeks.active.saturating_sub(1);
+        while let Some(tx) = peeks.queued.pop_front() {
+            if tx.send(()).is_ok() {
+                break;
+            }
+        }
+        self.update_resource_group_status(name);
+    }
+
+    /// Forgets the peeks of the dropped resource group `name`, retracting its status.
+    ///
+    /// Queued peeks are woken and retry admission without the resource group.
+    pub(crate) fn drop_resource_group_status(&mut self, name: &str) {
+        let Some(peeks) = self.resource_group_peeks.remove(name) else {
+            return;
+        };
+        if let Some((queued, rejected)) = peeks.status {
+            let state = self.catalog().state();
+            let update = state.pack_resource_group_status_update(name, queued, rejected, -1);
+            let update = state.resolve_builtin_table_update(update);
+            self.builtin_table_update().background(vec![update]);
+        }
+    }
+
+    /// Writes the current queue depth and rejection count of resource group `name` to
+    /// `mz_resource_group_statuses`, if they changed.
+    fn update_resource_group_status(&mut self, name: &str) {
+        let Some(peeks) = self.resource_group_peeks.get_mut(name) else {
+            return;
+        };
+        let status = (u64::cast_from(peeks.queued.len()), peeks.rejected);
+        if peeks.status == Some(status) {
+            return;
+        }
+        let prev = peeks.status.replace(status);
+
+        let state = self.catalog().state();
+        let mut updates = Vec::new();
+        if let Some((queued, rejected)) = prev {
+            updates.push(state.pack_resource_group_status_update(name, queued, rejected, -1));
+        }
+        updates.push(state.pack_resource_group_status_update(name, status.0, status.1, 1));
+        let updates = state.resolve_builtin_table_updates(updates);
+        self.builtin_table_update().background(updates);
+    }
+
     /// Constructs an [`ExecuteResponse`] that that will send some rows to the
     /// client immediately, as opposed to asking the dataflow layer to send along
     /// the rows after some computation.
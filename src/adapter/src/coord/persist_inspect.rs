@@ -0,0 +1,223 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Evaluation of the persist shard inspection table functions.
+//!
+//! `mz_internal.mz_persist_shard_parts` and `mz_internal.mz_persist_shard_state`
+//! report on the current state of the persist shard backing a collection.
+//! Reading that state requires the storage controller, so, like
+//! unmaterializable functions, calls to them are replaced with the rows they
+//! return before a one-off `SELECT` is optimized.
+
+use std::collections::BTreeMap;
+
+use mz_expr::visit::Visit;
+use mz_expr::TableFunc;
+use mz_ore::cast::CastFrom;
+use mz_ore::str::StrExt;
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::{Datum, GlobalId, Row, RowPacker, Timestamp};
+use mz_sql::plan::{HirRelationExpr, HirScalarExpr};
+use mz_sql::rbac;
+use mz_sql::session::metadata::SessionMetadata;
+use serde_json::Value;
+
+use crate::coord::Coordinator;
+use crate::error::AdapterError;
+use crate::session::Session;
+
+impl Coordinator {
+    /// Replaces every call to a persist shard inspection function in `expr`
+    /// with a constant holding the rows it returns.
+    pub(crate) async fn resolve_persist_inspections(
+        &self,
+        session: &Session,
+        expr: &mut HirRelationExpr,
+    ) -> Result<(), AdapterError> {
+        let mut ids = Vec::new();
+        expr.try_visit_post(&mut |expr: &HirRelationExpr| {
+            if let Some(id) = inspected_collection(expr)? {
+                ids.extend(id);
+            }
+            Ok::<_, AdapterError>(())
+        })?;
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        if !session.is_superuser() {
+            return Err(AdapterError::Unauthorized(
+                rbac::UnauthorizedError::Superuser {
+                    action: "inspect persist shards".into(),
+                },
+            ));
+        }
+
+        let mut states = BTreeMap::new();
+        for id in ids {
+            if !states.contains_key(&id) {
+                if self.catalog().try_get_entry(&id).is_none() {
+                    coord_bail!("unknown catalog item '{}'", id);
+                }
+                let state = self.controller.storage.inspect_persist_state(id).await?;
+                states.insert(id, state);
+            }
+        }
+
+        expr.try_visit_mut_post(&mut |expr: &mut HirRelationExpr| {
+            let Some(id) = inspected_collection(expr)? else {
+                return Ok(());
+            };
+            let HirRelationExpr::CallTable { func, .. } = expr else {
+                unreachable!("checked by inspected_collection");
+            };
+            let typ = func.output_type();
+            let rows = match (id, &*func) {
+                (None, _) => vec![],
+                (Some(id), TableFunc::MzPersistShardParts) => shard_parts_rows(&states[&id])?,
+                (Some(id), TableFunc::MzPersistShardState) => {
+                    vec![shard_state_row(&states[&id])?]
+                }
+                _ => unreachable!("checked by inspected_collection"),
+            };
+            *expr = HirRelationExpr::Constant { rows, typ };
+            Ok::<_, AdapterError>(())
+        })
+    }
+}
+
+/// If `expr` is a call to a persist shard inspection function, returns the
+/// collection it inspects, or `None` if the argument is `NULL`.
+fn inspected_collection(expr: &HirRelationExpr) -> Result<Option<Option<GlobalId>>, AdapterError> {
+    let HirRelationExpr::CallTable {
+        func: func @ (TableFunc::MzPersistShardParts | TableFunc::MzPersistShardState),
+        exprs,
+    } = expr
+    else {
+        return Ok(None);
+    };
+    let [HirScalarExpr::Literal(row, _)] = exprs.as_slice() else {
+        coord_bail!("the argument to {} must be a literal object ID", func);
+    };
+    match row.unpack_first() {
+        Datum::Null => Ok(Some(None)),
+        Datum::String(s) => match s.parse() {
+            Ok(id) => Ok(Some(Some(id))),
+            Err(_) => coord_bail!("invalid object ID: {}", s.quoted()),
+        },
+        d => unreachable!("{func} takes a string, got {d:?}"),
+    }
+}
+
+/// Returns one row per part of each batch in the serialized shard `state`.
+fn shard_parts_rows(state: &Value) -> Result<Vec<Row>, AdapterError> {
+    let mut rows = Vec::new();
+    for (batch_idx, batch) in array(&state["batches"]).iter().enumerate() {
+        for (run_idx, run) in array(&batch["part_runs"]).iter().enumerate() {
+            // Each run is serialized as a `(RunMeta, Vec<RunPart>)` pair.
+            for (part_idx, part) in array(&run[1]).iter().enumerate() {
+                let (part_type, encoded_size_bytes) = match part["type"].as_str() {
+                    Some("Hollow") => ("hollow", part["encoded_size_bytes"].as_u64()),
+                    Some("Inline") => ("inline", None),
+                    // A `HollowRunRef` to a run of parts stored in blob.
+                    _ => ("run", part["hollow_bytes"].as_u64()),
+                };
+                let mut row = Row::default();
+                let mut packer = row.packer();
+                packer.extend([
+                    Datum::UInt64(u64::cast_from(batch_idx)),
+                    frontier(&batch["lower"]),
+                    frontier(&batch["upper"]),
+                    frontier(&batch["since"]),
+                    Datum::UInt64(u64::cast_from(run_idx)),
+                    Datum::UInt64(u64::cast_from(part_idx)),
+                    Datum::String(part_type),
+                    part["key"].as_str().map_or(Datum::Null, Datum::String),
+                    encoded_size_bytes.map_or(Datum::Null, Datum::UInt64),
+                    part["diffs_sum"].as_i64().map_or(Datum::Null, Datum::Int64),
+                    frontier(&part["ts_rewrite"]),
+                ]);
+                pack_jsonb(&mut packer, &part["stats"])?;
+                rows.push(row);
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// Returns a summary of the serialized shard `state`.
+fn shard_state_row(state: &Value) -> Result<Row, AdapterError> {
+    let batches = array(&state["batches"]);
+    let parts = || {
+        batches
+            .iter()
+            .flat_map(|batch| array(&batch["part_runs"]))
+            .flat_map(|run| array(&run[1]))
+    };
+    let part_count = parts().count();
+    let encoded_size_bytes: u64 = parts()
+        .filter_map(|part| {
+            part["encoded_size_bytes"]
+                .as_u64()
+                .or_else(|| part["hollow_bytes"].as_u64())
+        })
+        .sum();
+    let update_count: u64 = batches
+        .iter()
+        .filter_map(|batch| batch["len"].as_u64())
+        .sum();
+    let object_len = |value: &Value| value.as_object().map_or(0, |o| o.len());
+    let reader_count =
+        object_len(&state["leased_readers"]) + object_len(&state["critical_readers"]);
+
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    packer.extend([
+        Datum::String(state["shard_id"].as_str().unwrap_or_default()),
+        Datum::UInt64(state["seqno"].as_u64().unwrap_or_default()),
+        frontier(&state["since"]),
+        frontier(&state["upper"]),
+        Datum::UInt64(u64::cast_from(batches.len())),
+        Datum::UInt64(u64::cast_from(part_count)),
+        Datum::UInt64(encoded_size_bytes),
+        Datum::UInt64(update_count),
+        Datum::UInt64(u64::cast_from(reader_count)),
+        Datum::UInt64(u64::cast_from(object_len(&state["writers"]))),
+    ]);
+    pack_jsonb(&mut packer, state)?;
+    Ok(row)
+}
+
+fn array(value: &Value) -> &[Value] {
+    value.as_array().map_or(&[], |a| a.as_slice())
+}
+
+/// Converts a serialized frontier, either a bare list of elements or an
+/// `Antichain`, into a timestamp, or `NULL` if it is empty or missing.
+fn frontier<'a>(value: &Value) -> Datum<'a> {
+    let elements = match value {
+        Value::Object(antichain) => antichain.get("elements").unwrap_or(&Value::Null),
+        value => value,
+    };
+    match array(elements).first().and_then(|ts| ts.as_u64()) {
+        Some(ts) => Datum::MzTimestamp(Timestamp::from(ts)),
+        None => Datum::Null,
+    }
+}
+
+fn pack_jsonb(packer: &mut RowPacker, value: &Value) -> Result<(), AdapterError> {
+    if value.is_null() {
+        packer.push(Datum::Null);
+    } else {
+        JsonbPacker::new(packer)
+            .pack_serde_json(value.clone())
+            .map_err(anyhow::Error::from)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,380 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Enforcement of table retention policies.
+//!
+//! Every `retention_check_interval`, each table with a `RETENTION` policy is
+//! read at the latest timestamp and the rows whose timestamp column has fallen
+//! more than the retention period behind the current time are retracted. The
+//! retractions are ordinary table writes: they commit through group commit,
+//! and so through txn-wal, like any `DELETE`.
+//!
+//! Runs don't read the whole table. Persist's statistics on the timestamp
+//! column are used to skip the parts of the table that can't contain expired
+//! rows, so a run only reads the parts with rows that are about to be
+//! retracted, or that were retracted but not yet compacted away.
+
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+use differential_dataflow::consolidation::consolidate;
+use futures::future::{self, FutureExt};
+use mz_catalog::memory::objects::{CatalogItem, Table};
+use mz_expr::{BinaryFunc, MapFilterProject, MirScalarExpr, ResultSpec};
+use mz_ore::now::{to_datetime, EpochMillis};
+use mz_ore::task;
+use mz_repr::adt::date::Date;
+use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType};
+use mz_sql::plan::RetentionPolicy;
+use mz_storage_client::controller::PartFilter;
+use mz_storage_types::stats::RelationPartStats;
+use tokio::sync::OwnedMutexGuard;
+use tracing::{debug, warn};
+
+use crate::catalog::BuiltinTableUpdate;
+use crate::coord::{Coordinator, Message};
+
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// The result of the most recent retention run on a table, as reported in
+/// `mz_internal.mz_table_retention_statuses`.
+#[derive(Debug, Clone)]
+pub struct TableRetentionStatus {
+    /// When the run started.
+    pub last_run_at: EpochMillis,
+    /// How long the run took, from reading the table to submitting the
+    /// retractions.
+    pub last_run_duration: Duration,
+    /// The number of rows the run retracted.
+    pub retracted_rows: u64,
+    /// Why the run failed, if it did.
+    pub error: Option<String>,
+}
+
+/// A retention run on a single table whose expired rows have been determined
+/// but not yet retracted.
+#[derive(Debug)]
+pub struct RetentionRun {
+    id: GlobalId,
+    /// The policy the expired rows were determined with.
+    policy: RetentionPolicy,
+    /// The arity of the table when it was read.
+    arity: usize,
+    started_at: EpochMillis,
+    start: Instant,
+    /// The retractions of the expired rows, or why they could not be
+    /// determined.
+    retractions: Result<Vec<(Row, Diff)>, String>,
+    /// The table's write lock, which is held from before the table is read
+    /// until the retractions are committed, so that no other write can land in
+    /// between.
+    write_lock: OwnedMutexGuard<()>,
+}
+
+impl Coordinator {
+    /// Reads every table with a retention policy and determines its expired
+    /// rows in a background task, which reports them back with
+    /// [`Message::RetentionApply`].
+    ///
+    /// Tables that are being written to are skipped until the next run.
+    pub(crate) async fn retention_fetch(&mut self) {
+        if self.controller.read_only() {
+            self.schedule_retention_fetch();
+            return;
+        }
+
+        let tables: Vec<_> = self
+            .catalog()
+            .entries()
+            .filter_map(|entry| match entry.item() {
+                CatalogItem::Table(Table {
+                    retention: Some(policy),
+                    desc,
+                    ..
+                }) => {
+                    let typ = desc.typ().column_types[policy.column].scalar_type.clone();
+                    Some((entry.id(), policy.clone(), desc.arity(), typ))
+                }
+                _ => None,
+            })
+            .collect();
+        let mut locked = Vec::with_capacity(tables.len());
+        for (id, policy, arity, typ) in tables {
+            match self.try_grant_object_write_lock(id) {
+                Some(write_lock) => locked.push((id, policy, arity, typ, write_lock)),
+                None => debug!(%id, "table is locked, skipping retention run"),
+            }
+        }
+        if locked.is_empty() {
+            // Still report back, so that statuses for dropped tables are retracted.
+            let _ = self
+                .internal_cmd_tx
+                .send(Message::RetentionApply(Vec::new()));
+            return;
+        }
+
+        let read_ts = self.get_local_read_ts().await;
+        let started_at = self.now();
+        let start = Instant::now();
+        let reads: Vec<_> = locked
+            .into_iter()
+            .map(|(id, policy, arity, typ, write_lock)| {
+                let filter = expired_part_filter(&policy, arity, &typ, started_at);
+                let snapshot = self
+                    .controller
+                    .storage
+                    .snapshot_filtered(id, read_ts, filter);
+                (id, policy, arity, write_lock, snapshot)
+            })
+            .collect();
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "retention_fetch", async move {
+            let mut runs = Vec::with_capacity(reads.len());
+            for (id, policy, arity, write_lock, snapshot) in reads {
+                let retractions = match snapshot.await {
+                    Ok(contents) => Ok(expired_retractions(&policy, started_at, contents)),
+                    Err(e) => {
+                        warn!(%id, "unable to read table for retention: {e}");
+                        Err(e.to_string())
+                    }
+                };
+                runs.push(RetentionRun {
+                    id,
+                    policy,
+                    arity,
+                    started_at,
+                    start,
+                    retractions,
+                    write_lock,
+                });
+            }
+            // If sending fails, the main thread has shutdown.
+            let _ = internal_cmd_tx.send(Message::RetentionApply(runs));
+        });
+    }
+
+    /// Retracts the expired rows found by `runs`, records the runs in
+    /// `mz_internal.mz_table_retention_statuses`, and schedules the next run.
+    pub(crate) fn retention_apply(&mut self, runs: Vec<RetentionRun>) {
+        // Borrow only the catalog, so that the statuses can be updated alongside.
+        let state = self.catalog.state();
+        let mut retractions = Vec::new();
+        let mut status_updates = Vec::new();
+        let mut write_locks = Vec::with_capacity(runs.len());
+        let mut statuses = Vec::with_capacity(runs.len());
+        for run in runs {
+            // The table may have been dropped or altered while it was being read.
+            let unchanged = match state.try_get_entry(&run.id).map(|entry| entry.item()) {
+                Some(CatalogItem::Table(table)) => {
+                    table.retention.as_ref() == Some(&run.policy) && table.desc.arity() == run.arity
+                }
+                _ => false,
+            };
+            if !unchanged {
+                continue;
+            }
+
+            let (retracted_rows, error) = match run.retractions {
+                Ok(expired) => {
+                    let retracted_rows: u64 =
+                        expired.iter().map(|(_, diff)| diff.unsigned_abs()).sum();
+                    retractions.extend(expired.into_iter().map(|(row, diff)| BuiltinTableUpdate {
+                        id: run.id,
+                        row,
+                        diff,
+                    }));
+                    (retracted_rows, None)
+                }
+                Err(e) => (0, Some(e)),
+            };
+            let status = TableRetentionStatus {
+                last_run_at: run.started_at,
+                last_run_duration: run.start.elapsed(),
+                retracted_rows,
+                error,
+            };
+            if let Some(prev) = self.table_retention_statuses.get(&run.id) {
+                status_updates.push(state.pack_table_retention_status_update(run.id, prev, -1));
+            }
+            status_updates.push(state.pack_table_retention_status_update(run.id, &status, 1));
+            statuses.push((run.id, status));
+            write_locks.push(run.write_lock);
+        }
+
+        // Retract the statuses of tables that no longer have a retention policy.
+        let policies: BTreeSet<_> = self
+            .catalog
+            .entries()
+            .filter_map(|entry| match entry.item() {
+                CatalogItem::Table(Table {
+                    retention: Some(_), ..
+                }) => Some(entry.id()),
+                _ => None,
+            })
+            .collect();
+        let stale: Vec<_> = self
+            .table_retention_statuses
+            .keys()
+            .filter(|id| !policies.contains(id))
+            .copied()
+            .collect();
+        for id in stale {
+            let prev = self
+                .table_retention_statuses
+                .remove(&id)
+                .expect("known to exist");
+            status_updates.push(state.pack_table_retention_status_update(id, &prev, -1));
+        }
+
+        let mut updates = retractions;
+        updates.extend(state.resolve_builtin_table_updates(status_updates));
+        self.table_retention_statuses.extend(statuses);
+
+        let notify = if updates.is_empty() {
+            future::ready(()).boxed()
+        } else {
+            self.builtin_table_update().defer(updates)
+        };
+        let interval = self.catalog().system_config().retention_check_interval();
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "retention_apply", async move {
+            notify.await;
+            drop(write_locks);
+            tokio::time::sleep(interval).await;
+            // If sending fails, the main thread has shutdown.
+            let _ = internal_cmd_tx.send(Message::RetentionFetch);
+        });
+    }
+
+    pub(crate) fn schedule_retention_fetch(&self) {
+        let interval = self.catalog().system_config().retention_check_interval();
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "retention_schedule", async move {
+            tokio::time::sleep(interval).await;
+            // If sending fails, the main thread has shutdown.
+            let _ = internal_cmd_tx.send(Message::RetentionFetch);
+        });
+    }
+}
+
+/// Returns the time, in milliseconds since the Unix epoch, before which
+/// `policy` considers rows expired at `now`.
+fn expiry_cutoff(policy: &RetentionPolicy, now: EpochMillis) -> i64 {
+    let now = i64::try_from(now).unwrap_or(i64::MAX);
+    let period = i64::try_from(policy.period.as_millis()).unwrap_or(i64::MAX);
+    now.saturating_sub(period)
+}
+
+/// Returns a filter for the parts of a table that may contain rows that
+/// `policy` considers expired at `now`.
+///
+/// `arity` is the arity of the table and `typ` the type of the policy's
+/// timestamp column. The filter may admit parts without expired rows, but
+/// never skips a part with one.
+fn expired_part_filter(
+    policy: &RetentionPolicy,
+    arity: usize,
+    typ: &ScalarType,
+    now: EpochMillis,
+) -> PartFilter {
+    let cutoff = expiry_cutoff(policy, now);
+    let bound = u64::try_from(cutoff).ok().and_then(|millis| {
+        let dt = to_datetime(millis);
+        let bound = match typ {
+            ScalarType::TimestampTz { .. } => {
+                Datum::TimestampTz(CheckedTimestamp::from_timestamplike(dt).ok()?)
+            }
+            ScalarType::Timestamp { .. } => {
+                Datum::Timestamp(CheckedTimestamp::from_timestamplike(dt.naive_utc()).ok()?)
+            }
+            // Dates expire at midnight, so round up to keep every expired date.
+            ScalarType::Date => {
+                let days = i32::try_from(cutoff / MILLIS_PER_DAY + 1).ok()?;
+                Datum::Date(Date::from_unix_epoch(days).ok()?)
+            }
+            _ => return None,
+        };
+        Some(MirScalarExpr::literal_ok(bound, typ.clone()))
+    });
+    match bound {
+        Some(bound) => {
+            let expired = MirScalarExpr::column(policy.column).call_binary(bound, BinaryFunc::Lt);
+            let mfp = MapFilterProject::new(arity).filter([expired]);
+            Box::new(move |stats: &RelationPartStats| {
+                stats.may_match_mfp(ResultSpec::anything(), &mfp)
+            })
+        }
+        // Cutoffs before the Unix epoch or out of range for the column are
+        // rare enough not to bother with.
+        None => Box::new(|_: &RelationPartStats| true),
+    }
+}
+
+/// Returns the retractions of the rows in `contents` that `policy` considers
+/// expired at `now`.
+///
+/// Rows whose timestamp column is `NULL` never expire.
+fn expired_retractions(
+    policy: &RetentionPolicy,
+    now: EpochMillis,
+    mut contents: Vec<(Row, Diff)>,
+) -> Vec<(Row, Diff)> {
+    consolidate(&mut contents);
+    let cutoff = expiry_cutoff(policy, now);
+    contents
+        .into_iter()
+        .filter(|(row, diff)| {
+            let expires_at = match row.iter().nth(policy.column) {
+                Some(Datum::Timestamp(ts)) => ts.and_utc().timestamp_millis(),
+                Some(Datum::TimestampTz(ts)) => ts.timestamp_millis(),
+                Some(Datum::Date(date)) => i64::from(date.unix_epoch_days()) * MILLIS_PER_DAY,
+                _ => return false,
+            };
+            *diff > 0 && expires_at < cutoff
+        })
+        .map(|(row, diff)| (row, -diff))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::adt::timestamp::CheckedTimestamp;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_expired_retractions() {
+        let ts = |millis: i64| {
+            let dt = mz_ore::now::to_datetime(u64::try_from(millis).expect("positive"));
+            Datum::TimestampTz(CheckedTimestamp::from_timestamplike(dt).expect("in range"))
+        };
+        let row = |id: i32, datum: Datum| Row::pack_slice(&[Datum::Int32(id), datum]);
+        let policy = RetentionPolicy {
+            period: Duration::from_secs(10),
+            column: 1,
+        };
+        let now = 100_000;
+        let contents = vec![
+            // Expired, including duplicates.
+            (row(1, ts(50_000)), 2),
+            // Exactly at the cutoff, so retained.
+            (row(2, ts(90_000)), 1),
+            (row(3, ts(95_000)), 1),
+            // NULLs never expire.
+            (row(4, Datum::Null), 1),
+            // Expired but already retracted.
+            (row(5, ts(10_000)), 1),
+            (row(5, ts(10_000)), -1),
+        ];
+        let expected = vec![(row(1, ts(50_000)), -2)];
+        assert_eq!(expired_retractions(&policy, now, contents), expected);
+    }
+}
@@ -426,6 +426,10 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::AlterRetention(plan) => {
+                    let result = self.sequence_alter_retention(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::AlterItemRename(plan) => {
                     let result = self
                         .sequence_alter_item_rename(ctx.session_mut(), plan)
@@ -612,6 +616,38 @@ impl Coordinator {
                     let result = self.sequence_reassign_owned(ctx.session_mut(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterRowSecurity(plan) => {
+                    let result = self.sequence_alter_row_security(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::AlterColumnMask(plan) => {
+                    let result = self.sequence_alter_column_mask(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::CreatePolicy(plan) => {
+                    let result = self.sequence_create_policy(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::DropPolicy(plan) => {
+                    let result = self.sequence_drop_policy(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::CreateResourceGroup(plan) => {
+                    let result = self
+                        .sequence_create_resource_group(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::AlterResourceGroup(plan) => {
+                    let result = self
+                        .sequence_alter_resource_group(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::DropResourceGroup(plan) => {
+                    let result = self.sequence_drop_resource_group(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::ValidateConnection(plan) => {
                     let connection = plan
                         .connection
@@ -767,7 +803,7 @@ impl Coordinator {
                     returning: Vec::new(),
                     max_result_size: catalog.system_config().max_result_size(),
                 };
-                Self::send_diffs(session, diffs_plan)
+                Self::send_diffs(catalog, session, diffs_plan)
             }
             None => panic!(
                 "tried using sequence_insert_constant on non-constant MirRelationExpr {:?}",
@@ -778,6 +814,7 @@ impl Coordinator {
 
     #[mz_ore::instrument(level = "debug")]
     pub(crate) fn send_diffs(
+        catalog: &Catalog,
         session: &mut Session,
         mut plan: plan::SendDiffsPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
@@ -822,6 +859,11 @@ impl Coordinator {
             returning = plan.returning.len(),
         );
 
+        // Writes that follow reads in an explicit transaction are validated
+        // against those reads at commit, if permitted.
+        if catalog.system_config().enable_read_write_transactions() {
+            session.transaction_mut().upgrade_to_read_write();
+        }
         session.add_transaction_ops(TransactionOps::Writes(vec![WriteOp {
             id: plan.id,
             rows: plan.updates,
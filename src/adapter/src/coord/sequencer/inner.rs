@@ -27,11 +27,12 @@ use mz_expr::{
     CollectionPlan, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
 };
 use mz_ore::collections::{CollectionExt, HashSet};
+use mz_ore::str::StrExt;
 use mz_ore::task::{self, spawn, JoinHandle};
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::vec::VecExt;
 use mz_repr::adt::jsonb::Jsonb;
-use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::json::json_string;
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::role_id::RoleId;
@@ -81,11 +82,13 @@ use mz_sql_parser::ast::{
     WithOptionValue,
 };
 use mz_ssh_util::keys::SshKeyPairSet;
+use mz_storage_client::client::TimestamplessUpdate;
 use mz_storage_client::controller::{CollectionDescription, DataSource, ExportDescription};
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::controller::StorageError;
 use mz_storage_types::stats::RelationPartStats;
 use mz_storage_types::AlterCompatible;
+use mz_timestamp_oracle::WriteTimestamp;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::notice::{OptimizerNoticeApi, OptimizerNoticeKind, RawOptimizerNotice};
 use mz_transform::EmptyStatisticsOracle;
@@ -98,6 +101,7 @@ use crate::command::{ExecuteResponse, Response};
 use crate::coord::appends::{
     BuiltinTableAppendNotify, DeferredPlan, DeferredWriteOp, PendingWriteTxn,
 };
+use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::{
     AlterConnectionValidationReady, AlterSinkReadyContext, Coordinator,
     CreateConnectionValidationReady, DeferredPlanStatement, ExecuteContext, ExplainContext,
@@ -152,6 +156,16 @@ struct CreateSourceInner {
     if_not_exists_ids: BTreeMap<GlobalId, QualifiedItemName>,
 }
 
+/// The relation cloned by a `CREATE TABLE ... CLONE`, once the time at which
+/// it is cloned has been determined.
+#[derive(Debug)]
+struct TableCloneSource {
+    id: GlobalId,
+    as_of: Timestamp,
+    /// Holds back the compaction of `id` until it has been read at `as_of`.
+    read_holds: ReadHolds<Timestamp>,
+}
+
 impl Coordinator {
     /// Sequences the next staged of a [Staged] plan. This is designed for use with plans that
     /// execute both on and off of the coordinator thread. Stages can either produce another stage
@@ -955,8 +969,24 @@ impl Coordinator {
             name,
             table,
             if_not_exists,
+            clone_of,
         } = plan;
 
+        let (clone_of, resolved_ids) = match clone_of {
+            Some(clone_of) => {
+                let clone_of = self
+                    .resolve_table_clone_source(ctx.session(), clone_of)
+                    .await?;
+                // The cloned relation was named in the statement, but the
+                // table doesn't depend on it.
+                let stmt = mz_sql::parse::parse(&table.create_sql)?.into_element().ast;
+                let catalog = self.catalog().for_session(ctx.session());
+                let (_, resolved_ids) = mz_sql::names::resolve(&catalog, stmt)?;
+                (Some(clone_of), resolved_ids)
+            }
+            None => (None, resolved_ids),
+        };
+
         let conn_id = if table.temporary {
             Some(ctx.session().conn_id())
         } else {
@@ -997,6 +1027,7 @@ impl Coordinator {
             resolved_ids,
             custom_logical_compaction_window: table.compaction_window,
             is_retained_metrics_object: false,
+            retention: table.retention,
             data_source,
         };
         let ops = vec![catalog::Op::CreateItem {
@@ -1034,6 +1065,27 @@ impl Coordinator {
 
                         let collection_desc = CollectionDescription::for_table(table.desc.clone());
                         let storage_metadata = coord.catalog.state().storage_metadata();
+                        if let Some(clone_of) = &clone_of {
+                            // Share the parts of the cloned relation rather
+                            // than copying them. If that isn't possible, the
+                            // correction below copies them instead.
+                            if let Err(err) = coord
+                                .controller
+                                .storage
+                                .fork_collection(
+                                    storage_metadata,
+                                    clone_of.id,
+                                    table_id,
+                                    register_ts,
+                                )
+                                .await
+                            {
+                                warn!(
+                                    source = %clone_of.id, table = %table_id,
+                                    "unable to fork collection for table clone: {err}"
+                                );
+                            }
+                        }
                         coord
                             .controller
                             .storage
@@ -1045,6 +1097,11 @@ impl Coordinator {
                             .await
                             .unwrap_or_terminate("cannot fail to create collections");
                         coord.apply_local_write(register_ts).await;
+                        if let Some(clone_of) = clone_of {
+                            coord
+                                .write_table_clone_correction(table_id, register_ts, clone_of)
+                                .await;
+                        }
 
                         coord
                             .initialize_storage_read_policies(
@@ -1134,6 +1191,129 @@ impl Coordinator {
         }
     }
 
+    /// Determines the time at which the relation cloned by a `CREATE TABLE
+    /// ... CLONE` is cloned, and holds back its compaction until it has been.
+    async fn resolve_table_clone_source(
+        &mut self,
+        session: &Session,
+        clone_of: plan::TableCloneSource,
+    ) -> Result<TableCloneSource, AdapterError> {
+        let plan::TableCloneSource { id, as_of } = clone_of;
+        let entry = self.catalog().get_entry(&id);
+        let name = self
+            .catalog()
+            .resolve_full_name(entry.name(), Some(session.conn_id()))
+            .to_string();
+        // Tables that are written by this environment (rather than by a
+        // source) are readable at the current read timestamp.
+        let is_table = matches!(
+            entry.item(),
+            CatalogItem::Table(Table {
+                data_source: TableDataSource::TableWrites { .. },
+                ..
+            })
+        );
+
+        let id_bundle = CollectionIdBundle {
+            storage_ids: btreeset![id],
+            compute_ids: BTreeMap::new(),
+        };
+        let read_holds = self.acquire_read_holds(&id_bundle);
+        let since = read_holds.least_valid_read();
+
+        // The latest time at which the relation can be read without waiting:
+        // relations other than tables are only readable up to their write
+        // frontier.
+        let readable = if is_table {
+            Some(self.get_local_read_ts().await)
+        } else {
+            let (_since, upper) = self.controller.storage.collection_frontiers(id)?;
+            upper.into_option().and_then(|upper| upper.step_back())
+        };
+        let as_of = match as_of {
+            Some(as_of) => Coordinator::evaluate_as_of(self.catalog().state(), as_of, session)?,
+            None => match readable {
+                Some(readable) => readable,
+                None => coord_bail!("{} is not yet readable", name.quoted()),
+            },
+        };
+        if !since.less_equal(&as_of) {
+            return Err(AdapterError::AsOfBeforeRetainedHistory {
+                as_of,
+                inputs: vec![(name, since)],
+            });
+        }
+        if readable.map_or(true, |readable| readable < as_of) {
+            coord_bail!(
+                "AS OF timestamp {} is not yet readable from {}",
+                as_of,
+                name.quoted()
+            );
+        }
+
+        Ok(TableCloneSource {
+            id,
+            as_of,
+            read_holds,
+        })
+    }
+
+    /// Makes the contents of the table `id`, a clone created at `register_ts`,
+    /// equal to those of the cloned relation at its `as_of`.
+    ///
+    /// The table starts out as a fork of the cloned relation, which has its
+    /// contents as of some earlier time, or as an empty table, if it couldn't
+    /// be forked. The difference is written before anything can read the
+    /// table at a later time than `register_ts`.
+    async fn write_table_clone_correction(
+        &mut self,
+        id: GlobalId,
+        register_ts: Timestamp,
+        clone_of: TableCloneSource,
+    ) {
+        let TableCloneSource {
+            id: source,
+            as_of,
+            read_holds,
+        } = clone_of;
+        let (desired, forked) = future::join(
+            self.controller.storage.snapshot(source, as_of),
+            self.controller.storage.snapshot(id, register_ts),
+        )
+        .await;
+        drop(read_holds);
+        let mut correction = desired.unwrap_or_terminate("cannot fail to read cloned relation");
+        correction.extend(
+            forked
+                .unwrap_or_terminate("cannot fail to read table clone")
+                .into_iter()
+                .map(|(row, diff)| (row, -diff)),
+        );
+        differential_dataflow::consolidation::consolidate(&mut correction);
+        if correction.is_empty() {
+            return;
+        }
+
+        let WriteTimestamp {
+            timestamp,
+            advance_to,
+        } = self.get_local_write_ts().await;
+        let updates = correction
+            .into_iter()
+            .map(|(row, diff)| TimestamplessUpdate { row, diff })
+            .collect();
+        let append = self
+            .controller
+            .storage
+            .append_table(timestamp, advance_to, vec![(id, updates)])
+            .expect("invalid updates");
+        match append.await {
+            Ok(result) => result.unwrap_or_terminate("cannot fail to apply appends"),
+            Err(_) => warn!("Writer terminated with writes in indefinite state"),
+        }
+        self.apply_local_write(timestamp).await;
+    }
+
     #[instrument]
     pub(super) async fn sequence_create_sink(
         &mut self,
@@ -1702,6 +1882,34 @@ impl Coordinator {
                                 dependant_objects: dependants,
                             });
                         }
+                        // Dataflows on other clusters read a persisted index from storage, which
+                        // the compute controller doesn't track. Dropping the index would stall
+                        // them, so refuse while any of them is still installed.
+                        if index.persisted {
+                            let humanizer = self.catalog().for_session(session);
+                            let dependants = self
+                                .catalog()
+                                .entries()
+                                .filter(|entry| !ids_set.contains(&ObjectId::Item(entry.id())))
+                                .filter(|entry| {
+                                    self.catalog()
+                                        .try_get_physical_plan(&entry.id())
+                                        .map_or(false, |plan| plan.source_imports.contains_key(id))
+                                })
+                                .map(|entry| {
+                                    humanizer
+                                        .humanize_id(entry.id())
+                                        .unwrap_or_else(|| entry.id().to_string())
+                                })
+                                .collect_vec();
+                            if !dependants.is_empty() {
+                                coord_bail!(
+                                    "cannot drop persisted index {}: still used by {}",
+                                    humanizer.humanize_id(*id).unwrap_or_else(|| id.to_string()),
+                                    dependants.join(", ")
+                                );
+                            }
+                        }
                     }
                 }
                 _ => {}
@@ -2093,7 +2301,34 @@ impl Coordinator {
             Ok((Some(TransactionOps::Writes(writes)), _)) if writes.is_empty() => {
                 (response, action)
             }
-            Ok((Some(TransactionOps::Writes(writes)), write_lock_guards)) => {
+            Ok((
+                Some(ops @ (TransactionOps::Writes(_) | TransactionOps::ReadWrite { .. })),
+                write_lock_guards,
+            )) => {
+                // A read/write transaction is submitted even without writes, so that its
+                // reads get validated.
+                let (writes, read_set) = match ops {
+                    TransactionOps::Writes(writes) => (writes, BTreeMap::new()),
+                    TransactionOps::ReadWrite {
+                        determination,
+                        read_ids,
+                        writes,
+                        ..
+                    } => {
+                        let read_ts = *determination
+                            .timestamp_context
+                            .timestamp()
+                            .expect("read/write transactions have a timestamp");
+                        let read_set = self
+                            .read_write_transaction_tables(read_ids)
+                            .into_iter()
+                            .map(|id| (id, read_ts))
+                            .collect();
+                        (writes, read_set)
+                    }
+                    _ => unreachable!("matched above"),
+                };
+
                 // Make sure we have the correct set of write locks for this transaction.
                 // Aggressively dropping partial sets of locks to prevent deadlocking separate
                 // transactions.
@@ -2118,6 +2353,7 @@ impl Coordinator {
                     span: Span::current(),
                     writes: collected_writes,
                     write_locks: validated_locks,
+                    read_set,
                     pending_txn: PendingTxn {
                         ctx,
                         response,
@@ -2207,7 +2443,7 @@ impl Coordinator {
         if let EndTransactionAction::Commit = action {
             if let (Some(mut ops), write_lock_guards) = txn.into_ops_and_lock_guard() {
                 match &mut ops {
-                    TransactionOps::Writes(writes) => {
+                    TransactionOps::Writes(writes) | TransactionOps::ReadWrite { writes, .. } => {
                         for WriteOp { id, .. } in &mut writes.iter() {
                             // Re-verify this id exists.
                             let _ = self.catalog().try_get_entry(id).ok_or_else(|| {
@@ -2244,6 +2480,35 @@ impl Coordinator {
         Ok((None, None))
     }
 
+    /// Returns the tables whose contents the reads of a read/write transaction
+    /// depended on, i.e. the tables written by this environment that `ids`
+    /// transitively depend on. Items that have been dropped since they were
+    /// read are included, so that the transaction fails to validate.
+    fn read_write_transaction_tables(&self, ids: BTreeSet<GlobalId>) -> BTreeSet<GlobalId> {
+        let mut tables = BTreeSet::new();
+        let mut seen = BTreeSet::new();
+        let mut queue: VecDeque<_> = ids.into_iter().collect();
+        while let Some(id) = queue.pop_front() {
+            if !id.is_user() || !seen.insert(id) {
+                continue;
+            }
+            let Some(entry) = self.catalog().try_get_entry(&id) else {
+                tables.insert(id);
+                continue;
+            };
+            match entry.item() {
+                CatalogItem::Table(Table {
+                    data_source: TableDataSource::TableWrites { .. },
+                    ..
+                }) => {
+                    tables.insert(id);
+                }
+                _ => queue.extend(entry.uses()),
+            }
+        }
+        tables
+    }
+
     pub(super) async fn sequence_side_effecting_func(
         &mut self,
         ctx: ExecuteContext,
@@ -2633,8 +2898,17 @@ impl Coordinator {
         let mut source_ids = plan.selection.depends_on();
         source_ids.insert(plan.id);
 
+        // In a read-write transaction, the read happens at the transaction's
+        // timestamp and is validated at commit, instead of being linearized
+        // with the write. Validation takes the place of the write locks.
+        let read_write = self
+            .catalog()
+            .system_config()
+            .enable_read_write_transactions()
+            && ctx.session().transaction().can_upgrade_to_read_write();
+
         // If the transaction doesn't already have write locks, acquire them.
-        if ctx.session().transaction().write_locks().is_none() {
+        if !read_write && ctx.session().transaction().write_locks().is_none() {
             // Pre-define all of the locks we need.
             let mut write_locks = WriteLocks::builder(source_ids.iter().copied());
 
@@ -2796,6 +3070,11 @@ impl Coordinator {
         // It's debatable whether this makes sense conceptually,
         // because the inner fragment here is not actually a
         // "statement" in its own right.
+        // The reads of a read-write transaction must all happen on the same cluster.
+        let target_cluster = match session.transaction().cluster() {
+            Some(cluster_id) if read_write => TargetCluster::Transaction(cluster_id),
+            _ => TargetCluster::Active,
+        };
         let peek_ctx = ExecuteContext::from_parts(
             peek_client_tx,
             self.internal_cmd_tx.clone(),
@@ -2807,11 +3086,15 @@ impl Coordinator {
             plan::SelectPlan {
                 select: None,
                 source: selection,
-                when: QueryWhen::FreshestTableWrite,
+                when: if read_write {
+                    QueryWhen::Immediately
+                } else {
+                    QueryWhen::FreshestTableWrite
+                },
                 finishing,
                 copy_to: None,
             },
-            TargetCluster::Active,
+            target_cluster,
             None,
         )
         .await;
@@ -2987,8 +3270,13 @@ impl Coordinator {
             };
 
             // We need to clear out the timestamp context so the write doesn't fail due to a
-            // read only transaction.
-            let timestamp_context = ctx.session_mut().take_transaction_timestamp_context();
+            // read only transaction. Read-write transactions instead keep it around, to be
+            // validated at commit.
+            let timestamp_context = if read_write {
+                None
+            } else {
+                ctx.session_mut().take_transaction_timestamp_context()
+            };
             // No matter what isolation level the client is using, we must linearize this
             // read. The write will be performed right after this, as part of a single
             // transaction, so the write must have a timestamp greater than or equal to the
@@ -3038,6 +3326,7 @@ impl Coordinator {
             match diffs {
                 Ok(diffs) => {
                     let result = Self::send_diffs(
+                        &catalog,
                         ctx.session_mut(),
                         plan::SendDiffsPlan {
                             id,
@@ -3119,6 +3408,17 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(plan.object_type))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_retention(
+        &mut self,
+        session: &Session,
+        plan::AlterRetentionPlan { id, value, policy }: plan::AlterRetentionPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterRetention { id, value, policy };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_schema_rename(
         &mut self,
@@ -3205,6 +3505,9 @@ impl Coordinator {
                 if let Some(inherit) = attrs.inherit {
                     attributes.inherit = inherit;
                 }
+                if let Some(password) = attrs.password {
+                    attributes.password = password;
+                }
 
                 if let Some(notice) = self.should_emit_rbac_notice(session) {
                     notices.push(notice);
@@ -4219,6 +4522,7 @@ impl Coordinator {
         for UpdatePrivilege {
             acl_mode,
             target_id,
+            column_positions,
             grantor,
         } in update_privileges
         {
@@ -4243,6 +4547,49 @@ impl Coordinator {
                     .ensure_not_reserved_object(object_id, session.conn_id())?;
             }
 
+            if !column_positions.is_empty() {
+                let SystemObjectId::Object(ObjectId::Item(relation_id)) = target_id else {
+                    // Should be unreachable since the planner only produces column privileges
+                    // for tables.
+                    return Err(AdapterError::Unsupported(
+                        "column privileges on objects other than tables",
+                    ));
+                };
+                let column_security = catalog.get_column_security(&relation_id);
+                for column_pos in column_positions {
+                    let privileges = column_security
+                        .and_then(|columns| columns.get(&column_pos))
+                        .map(|security| &security.privileges);
+                    for grantee in &grantees {
+                        self.catalog().ensure_not_system_role(grantee)?;
+                        self.catalog().ensure_not_predefined_role(grantee)?;
+                        let existing_mode = privileges
+                            .and_then(|privileges| privileges.get_acl_item(grantee, &grantor))
+                            .map(|item| item.acl_mode)
+                            .unwrap_or_else(AclMode::empty);
+                        let changed = match variant {
+                            UpdatePrivilegeVariant::Grant => !existing_mode.contains(acl_mode),
+                            UpdatePrivilegeVariant::Revoke => {
+                                !existing_mode.intersection(acl_mode).is_empty()
+                            }
+                        };
+                        if changed {
+                            ops.push(catalog::Op::UpdateColumnPrivilege {
+                                relation_id,
+                                column_pos,
+                                privilege: MzAclItem {
+                                    grantee: *grantee,
+                                    grantor,
+                                    acl_mode,
+                                },
+                                variant,
+                            });
+                        }
+                    }
+                }
+                continue;
+            }
+
             let privileges = self
                 .catalog()
                 .get_privileges(&target_id, session.conn_id())
@@ -4551,6 +4898,114 @@ impl Coordinator {
             .map(|_| ExecuteResponse::ReassignOwned)
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_row_security(
+        &mut self,
+        session: &Session,
+        plan::AlterRowSecurityPlan {
+            id,
+            enabled,
+            object_type,
+        }: plan::AlterRowSecurityPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterRowSecurity { id, enabled };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::AlteredObject(object_type))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_column_mask(
+        &mut self,
+        session: &Session,
+        plan::AlterColumnMaskPlan {
+            id,
+            column_pos,
+            mask,
+            object_type,
+        }: plan::AlterColumnMaskPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterColumnMask {
+            id,
+            column_pos,
+            mask,
+        };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::AlteredObject(object_type))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_policy(
+        &mut self,
+        session: &Session,
+        plan::CreatePolicyPlan {
+            relation_id,
+            name,
+            policy,
+        }: plan::CreatePolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::CreatePolicy {
+            relation_id,
+            name,
+            policy,
+        };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::CreatedPolicy)
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_drop_policy(
+        &mut self,
+        session: &Session,
+        plan::DropPolicyPlan { policy }: plan::DropPolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        if let Some((relation_id, name)) = policy {
+            let op = catalog::Op::DropPolicy { relation_id, name };
+            self.catalog_transact(Some(session), vec![op]).await?;
+        }
+        Ok(ExecuteResponse::DroppedPolicy)
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_resource_group(
+        &mut self,
+        session: &Session,
+        plan::CreateResourceGroupPlan { name, group }: plan::CreateResourceGroupPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::CreateResourceGroup { name, group };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::CreatedResourceGroup)
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_resource_group(
+        &mut self,
+        session: &Session,
+        plan::AlterResourceGroupPlan { name, group }: plan::AlterResourceGroupPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterResourceGroup { name, group };
+        self.catalog_transact(Some(session), vec![op])
+            .await
+            .map(|_| ExecuteResponse::AlteredResourceGroup)
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_drop_resource_group(
+        &mut self,
+        session: &Session,
+        plan::DropResourceGroupPlan { name }: plan::DropResourceGroupPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        if let Some(name) = name {
+            let op = catalog::Op::DropResourceGroup { name: name.clone() };
+            self.catalog_transact(Some(session), vec![op]).await?;
+            self.drop_resource_group_status(&name);
+        }
+        Ok(ExecuteResponse::DroppedResourceGroup)
+    }
+
     #[instrument]
     pub(crate) async fn handle_deferred_statement(&mut self) {
         // It is possible Message::DeferredStatementReady was sent but then a session cancellation
@@ -263,6 +263,12 @@ impl Coordinator {
             Unchanged => {}
         }
 
+        match &options.arrangement_spill {
+            Set(spill) => new_config.arrangement_spill = *spill,
+            Reset => new_config.arrangement_spill = None,
+            Unchanged => {}
+        }
+
         if new_config == config {
             return Ok(StageResult::Response(ExecuteResponse::AlteredObject(
                 ObjectType::Cluster,
@@ -270,6 +276,7 @@ impl Coordinator {
         }
 
         let new_workload_class = new_config.workload_class.clone();
+        let new_arrangement_spill = new_config.arrangement_spill;
         match (&config.variant, &new_config.variant) {
             (Managed(_), Managed(new_config_managed)) => {
                 let alter_followup = self
@@ -358,6 +365,8 @@ impl Coordinator {
 
         self.controller
             .update_cluster_workload_class(cluster_id, new_workload_class)?;
+        self.controller
+            .update_cluster_arrangement_spill(cluster_id, new_arrangement_spill)?;
 
         Ok(StageResult::Response(ExecuteResponse::AlteredObject(
             ObjectType::Cluster,
@@ -473,6 +482,7 @@ impl Coordinator {
             config: ClusterConfig {
                 variant: ClusterVariant::Managed(new_config),
                 workload_class: cluster.config.workload_class.clone(),
+                arrangement_spill: cluster.config.arrangement_spill,
             },
         });
         self.catalog_transact(Some(session), ops).await?;
@@ -592,6 +602,7 @@ impl Coordinator {
             name,
             variant,
             workload_class,
+            arrangement_spill,
         }: CreateClusterPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         tracing::debug!("sequence_create_cluster");
@@ -627,6 +638,7 @@ impl Coordinator {
         let config = ClusterConfig {
             variant: cluster_variant,
             workload_class,
+            arrangement_spill,
         };
         let ops = vec![catalog::Op::CreateCluster {
             id,
@@ -920,6 +932,7 @@ impl Coordinator {
                 mz_controller::clusters::ClusterConfig {
                     arranged_logs: cluster.log_indexes.clone(),
                     workload_class: cluster.config.workload_class.clone(),
+                    arrangement_spill: cluster.config.arrangement_spill,
                 },
             )
             .expect("creating cluster must not fail");
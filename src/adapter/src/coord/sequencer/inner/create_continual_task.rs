@@ -70,6 +70,7 @@ impl Coordinator {
                     resolved_ids: resolved_ids.clone(),
                     custom_logical_compaction_window: None,
                     is_retained_metrics_object: false,
+                    retention: None,
                     data_source: TableDataSource::TableWrites {
                         defaults: Vec::new(),
                     },
@@ -9,8 +9,9 @@
 
 use std::collections::BTreeMap;
 
-use maplit::btreemap;
+use maplit::{btreemap, btreeset};
 use mz_catalog::memory::objects::{CatalogItem, Index};
+use mz_compute_types::persisted_index;
 use mz_ore::instrument;
 use mz_repr::explain::{ExprHumanizerExt, TransientItem};
 use mz_repr::optimize::{OptimizerFeatures, OverrideFrom};
@@ -19,6 +20,7 @@ use mz_sql::ast::ExplainStage;
 use mz_sql::catalog::CatalogError;
 use mz_sql::names::ResolvedIds;
 use mz_sql::plan;
+use mz_storage_client::controller::{CollectionDescription, DataSource};
 use tracing::Span;
 
 use crate::command::ExecuteResponse;
@@ -33,6 +35,7 @@ use crate::explain::optimizer_trace::OptimizerTrace;
 use crate::optimize::dataflows::dataflow_import_id_bundle;
 use crate::optimize::{self, Optimize};
 use crate::session::Session;
+use crate::util::ResultExt;
 use crate::{catalog, AdapterNotice, ExecuteContext, TimestampProvider};
 
 impl Staged for CreateIndexStage {
@@ -331,8 +334,12 @@ impl Coordinator {
                 ), AdapterError> {
                     let _dispatch_guard = explain_ctx.dispatch_guard();
 
-                    let index_plan =
-                        optimize::index::Index::new(plan.name.clone(), plan.index.on, plan.index.keys.clone());
+                    let index_plan = optimize::index::Index::new(
+                        plan.name.clone(),
+                        plan.index.on,
+                        plan.index.keys.clone(),
+                        plan.index.persisted,
+                    );
 
                     // MIR ⇒ MIR optimization (global)
                     let global_mir_plan = optimizer.catch_unwind_optimize(index_plan)?;
@@ -412,6 +419,7 @@ impl Coordinator {
                             keys,
                             cluster_id,
                             compaction_window,
+                            persisted,
                         },
                     if_not_exists,
                 },
@@ -435,6 +443,7 @@ impl Coordinator {
                 cluster_id,
                 is_retained_metrics_object: false,
                 custom_logical_compaction_window: compaction_window,
+                persisted,
             }),
             owner_id: *self.catalog().get_entry(&on).owner_id(),
         }];
@@ -469,7 +478,38 @@ impl Coordinator {
                 // hold on to them and downgrade when possible?
                 let read_holds = coord.acquire_read_holds(&id_bundle);
                 let since = coord.least_valid_read(&read_holds);
-                df_desc.set_as_of(since);
+                df_desc.set_as_of(since.clone());
+
+                if persisted {
+                    // Announce the storage collection the persisted index is written to.
+                    let storage_metadata = coord.catalog.state().storage_metadata();
+                    coord
+                        .controller
+                        .storage
+                        .create_collections(
+                            storage_metadata,
+                            None,
+                            vec![(
+                                exported_index_id,
+                                CollectionDescription {
+                                    desc: persisted_index::relation_desc(),
+                                    data_source: DataSource::Other,
+                                    since: Some(since),
+                                    status_collection_id: None,
+                                    timeline: None,
+                                },
+                            )],
+                        )
+                        .await
+                        .unwrap_or_terminate("cannot fail to append");
+
+                    coord
+                        .initialize_storage_read_policies(
+                            btreeset![exported_index_id],
+                            compaction_window.unwrap_or_default(),
+                        )
+                        .await;
+                }
 
                 coord
                     .ship_dataflow_and_notice_builtin_table_updates(
@@ -483,11 +523,15 @@ impl Coordinator {
                 // point compute will have put in its own read holds.
                 drop(read_holds);
 
-                coord.update_compute_read_policy(
-                    cluster_id,
-                    exported_index_id,
-                    compaction_window.unwrap_or_default().into(),
-                );
+                // Persisted indexes are read through their storage collection, whose read policy
+                // is installed above.
+                if !persisted {
+                    coord.update_compute_read_policy(
+                        cluster_id,
+                        exported_index_id,
+                        compaction_window.unwrap_or_default().into(),
+                    );
+                }
             })
             .await;
 
@@ -14,6 +14,7 @@ use std::sync::Arc;
 use http::Uri;
 use itertools::Either;
 use maplit::btreemap;
+use mz_audit_log::EventType;
 use mz_controller_types::ClusterId;
 use mz_expr::{CollectionPlan, ResultSpec};
 use mz_ore::cast::CastFrom;
@@ -36,7 +37,9 @@ use tracing::{Instrument, Span};
 use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyTo};
 use crate::command::ExecuteResponse;
 use crate::coord::id_bundle::CollectionIdBundle;
-use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::peek::{
+    self, FastPathPlan, PeekDataflowPlan, PeekPlan, PlannedPeek, ResourceGroupAdmission,
+};
 use crate::coord::sequencer::inner::{check_log_reads, return_if_err};
 use crate::coord::timeline::TimelineContext;
 use crate::coord::timestamp_selection::{
@@ -54,7 +57,9 @@ use crate::explain::optimizer_trace::OptimizerTrace;
 use crate::notice::AdapterNotice;
 use crate::optimize::dataflows::{prep_scalar_expr, EvalTime, ExprPrepStyle};
 use crate::optimize::{self, Optimize};
-use crate::session::{RequireLinearization, Session, TransactionOps, TransactionStatus};
+use crate::session::{
+    RequireLinearization, Session, Transaction, TransactionOps, TransactionStatus,
+};
 use crate::statement_logging::StatementLifecycleEvent;
 
 impl Staged for PeekStage {
@@ -122,10 +127,16 @@ impl Coordinator {
     pub(crate) async fn sequence_peek(
         &mut self,
         ctx: ExecuteContext,
-        plan: plan::SelectPlan,
+        mut plan: plan::SelectPlan,
         target_cluster: TargetCluster,
         max_query_result_size: Option<u64>,
     ) {
+        return_if_err!(
+            self.resolve_persist_inspections(ctx.session(), &mut plan.source)
+                .await,
+            ctx
+        );
+
         let explain_ctx = if ctx.session().vars().emit_plan_insights_notice() {
             let optimizer_trace = OptimizerTrace::new(ExplainStage::PlanInsights.paths());
             ExplainContext::PlanInsightsNotice(optimizer_trace)
@@ -640,6 +651,7 @@ impl Coordinator {
                                         global_lir_plan,
                                         optimization_finished_at,
                                         insights_ctx,
+                                        resource_group_queued: false,
                                     })
                                 }
                                 ExplainContext::None => PeekStage::Finish(PeekStageFinish {
@@ -656,6 +668,7 @@ impl Coordinator {
                                     global_lir_plan,
                                     optimization_finished_at,
                                     insights_ctx,
+                                    resource_group_queued: false,
                                 }),
                                 ExplainContext::Pushdown => {
                                     let (plan, _, _) = global_lir_plan.unapply();
@@ -792,7 +805,44 @@ impl Coordinator {
     async fn peek_finish(
         &mut self,
         ctx: &mut ExecuteContext,
-        PeekStageFinish {
+        mut stage: PeekStageFinish,
+    ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
+        // Constant peeks do not occupy the cluster, so they are not subject to the concurrency
+        // limits of resource groups.
+        let resource_group = if matches!(
+            stage.global_lir_plan.peek_plan(),
+            PeekPlan::FastPath(FastPathPlan::Constant(..))
+        ) {
+            None
+        } else {
+            match self.admit_resource_group_peek(ctx.session(), stage.resource_group_queued)? {
+                ResourceGroupAdmission::Admitted(resource_group) => resource_group,
+                ResourceGroupAdmission::Queued { rx, timeout } => {
+                    stage.resource_group_queued = true;
+                    let span = Span::current();
+                    return Ok(StageResult::Handle(mz_ore::task::spawn(
+                        || "peek resource group queue",
+                        async move {
+                            // The sender is dropped along with its resource group, in which
+                            // case the peek retries admission just the same.
+                            match timeout {
+                                Some(timeout) => {
+                                    let _ = tokio::time::timeout(timeout, rx)
+                                        .await
+                                        .map_err(|_| AdapterError::StatementTimeout)?;
+                                }
+                                None => {
+                                    let _ = rx.await;
+                                }
+                            }
+                            Ok(Box::new(PeekStage::Finish(stage)))
+                        }
+                        .instrument(span),
+                    )));
+                }
+            }
+        };
+        let PeekStageFinish {
             validity: _,
             plan,
             max_query_result_size,
@@ -806,8 +856,9 @@ impl Coordinator {
             global_lir_plan,
             optimization_finished_at,
             insights_ctx,
-        }: PeekStageFinish,
-    ) -> Result<StageResult<Box<PeekStage>>, AdapterError> {
+            resource_group_queued: _,
+        } = stage;
+
         if let Some(id) = ctx.extra.contents() {
             self.record_statement_lifecycle_event(
                 &id,
@@ -839,10 +890,20 @@ impl Coordinator {
                     df_meta,
                     insights_ctx,
                 )
-                .await?;
-            session.add_notice(AdapterNotice::PlanInsights(insights));
+                .await;
+            match insights {
+                Ok(insights) => session.add_notice(AdapterNotice::PlanInsights(insights)),
+                Err(e) => {
+                    if let Some(resource_group) = &resource_group {
+                        self.release_resource_group_peek(resource_group);
+                    }
+                    return Err(e);
+                }
+            }
         }
 
+        let select_audit_events = self.select_audit_events(session, &source_ids);
+
         let planned_peek = PlannedPeek {
             plan: peek_plan,
             determination: determination.clone(),
@@ -898,7 +959,19 @@ impl Coordinator {
             )
         }
 
-        let max_result_size = self.catalog().system_config().max_result_size();
+        let mut max_result_size = self.catalog().system_config().max_result_size();
+        let mut max_query_result_size = max_query_result_size;
+        // Unlike the session variables, the result size limit of a resource group cannot be
+        // raised by its members.
+        if let Some(limit) = resource_group
+            .as_ref()
+            .and_then(|name| self.catalog().state().get_resource_group(name))
+            .and_then(|group| group.max_result_size)
+        {
+            max_result_size = std::cmp::min(max_result_size, limit);
+            max_query_result_size =
+                Some(max_query_result_size.map_or(limit, |size| size.min(limit)));
+        }
 
         // Implement the peek, and capture the response.
         let resp = self
@@ -910,8 +983,23 @@ impl Coordinator {
                 target_replica,
                 max_result_size,
                 max_query_result_size,
+                resource_group.clone(),
             )
-            .await?;
+            .await;
+        let resp = match resp {
+            Ok(resp) => resp,
+            Err(e) => {
+                if let Some(resource_group) = &resource_group {
+                    self.release_resource_group_peek(resource_group);
+                }
+                return Err(e);
+            }
+        };
+        self.log_audit_events(
+            Some(ctx.session().conn_id()),
+            EventType::Select,
+            select_audit_events,
+        );
 
         if ctx.session().vars().emit_timestamp_notice() {
             let explanation =
@@ -1185,10 +1273,23 @@ impl Coordinator {
         // depend on whether or not reads have occurred in the txn.
         let mut transaction_determination = determination.clone();
         if when.is_transactional() {
+            // Reads in a read-write transaction happen at the transaction's timestamp, so they
+            // would not observe the transaction's own writes.
+            if let TransactionStatus::InTransaction(Transaction {
+                ops: TransactionOps::ReadWrite { writes, .. },
+                ..
+            }) = session.transaction()
+            {
+                let read_tables = self.read_write_transaction_tables(source_ids.clone());
+                if writes.iter().any(|write| read_tables.contains(&write.id)) {
+                    return Err(AdapterError::WriteOnlyTransaction);
+                }
+            }
             session.add_transaction_ops(TransactionOps::Peeks {
                 determination: transaction_determination,
                 cluster_id,
                 requires_linearization,
+                read_ids: source_ids.clone(),
             })?;
         } else if matches!(session.transaction(), &TransactionStatus::InTransaction(_)) {
             // If the query uses AS OF, then ignore the timestamp.
@@ -1197,6 +1298,7 @@ impl Coordinator {
                 determination: transaction_determination,
                 cluster_id,
                 requires_linearization,
+                read_ids: BTreeSet::new(),
             })?;
         };
 
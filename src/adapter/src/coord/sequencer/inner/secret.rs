@@ -15,6 +15,7 @@ use mz_expr::MirScalarExpr;
 use mz_ore::collections::CollectionExt;
 use mz_ore::instrument;
 use mz_repr::{Datum, GlobalId, RowArena};
+use mz_secrets::external::ExternalSecretReference;
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{ConnectionOption, ConnectionOptionName, Statement, Value, WithOptionValue};
 use mz_sql::catalog::{CatalogError, ObjectType};
@@ -119,7 +120,8 @@ impl Coordinator {
     ) -> Result<StageResult<Box<SecretStage>>, AdapterError> {
         let id = self.catalog_mut().allocate_user_id().await?;
         let secrets_controller = Arc::clone(&self.secrets_controller);
-        let payload = self.extract_secret(session, &mut plan.secret.secret_as)?;
+        let payload =
+            self.extract_secret(session, &mut plan.secret.secret_as, plan.secret.external)?;
         let span = Span::current();
         Ok(StageResult::Handle(mz_ore::task::spawn(
             || "create secret ensure",
@@ -132,10 +134,15 @@ impl Coordinator {
         )))
     }
 
+    /// Evaluates `secret_as` to the contents of a secret.
+    ///
+    /// Unless `external` is set, the contents are a user-provided value, which
+    /// must not be mistaken for a reference to an external secret.
     fn extract_secret(
         &self,
         session: &Session,
         secret_as: &mut MirScalarExpr,
+        external: bool,
     ) -> Result<Vec<u8>, AdapterError> {
         let temp_storage = RowArena::new();
         prep_scalar_expr(
@@ -179,6 +186,13 @@ impl Coordinator {
             coord_bail!("secret value must be valid UTF-8");
         }
 
+        // Otherwise, any user could create a reference to an arbitrary
+        // external secret without `CREATE SECRET ... FROM VAULT`, bypassing
+        // its feature flag.
+        if !external && ExternalSecretReference::is_encoded(payload) {
+            coord_bail!("secret value is reserved for references to external secrets");
+        }
+
         Ok(Vec::from(payload))
     }
 
@@ -262,7 +276,7 @@ impl Coordinator {
     ) -> Result<StageResult<Box<SecretStage>>, AdapterError> {
         let plan::AlterSecretPlan { id, mut secret_as } = plan;
         let secrets_controller = Arc::clone(&self.secrets_controller);
-        let payload = self.extract_secret(session, &mut secret_as)?;
+        let payload = self.extract_secret(session, &mut secret_as, false)?;
         let span = Span::current();
         Ok(StageResult::HandleRetire(mz_ore::task::spawn(
             || "alter secret ensure",
@@ -190,7 +190,7 @@ impl Coordinator {
         let conn_id = session.conn_id().clone();
         let up_to = up_to
             .as_ref()
-            .map(|expr| Coordinator::evaluate_when(self.catalog().state(), expr.clone(), session))
+            .map(|expr| Coordinator::evaluate_up_to(self.catalog().state(), expr.clone(), session))
             .transpose()?;
         let debug_name = format!("subscribe-{}", sink_id);
         let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config())
@@ -341,7 +341,17 @@ impl Coordinator {
                 .expect("set to Some in an earlier stage"),
             arity: global_lir_plan.sink_desc().from_desc.arity(),
             cluster_id,
-            depends_on: dependency_ids,
+            // Subscribes also depend on the persisted indexes they read, so that they are
+            // canceled when one of those is dropped.
+            depends_on: dependency_ids
+                .into_iter()
+                .chain(
+                    global_lir_plan
+                        .df_desc()
+                        .persisted_index_imports()
+                        .map(|(id, _)| *id),
+                )
+                .collect(),
             start_time: self.now(),
             output,
         };
@@ -87,9 +87,12 @@ impl PreparedStatementLoggingInfo {
         let kind = stmt.map(StatementKind::from);
         let sql = match kind {
             // We __always__ want to redact SQL statements that might contain secret values.
-            Some(StatementKind::CreateSecret | StatementKind::AlterSecret) => {
-                stmt.map(|s| s.to_ast_string_redacted()).unwrap_or_default()
-            }
+            Some(
+                StatementKind::CreateSecret
+                | StatementKind::AlterSecret
+                | StatementKind::CreateRole
+                | StatementKind::AlterRole,
+            ) => stmt.map(|s| s.to_ast_string_redacted()).unwrap_or_default(),
             _ => raw_sql,
         };
 
@@ -527,6 +530,7 @@ impl Coordinator {
             connected_at,
             application_name,
             authenticated_user,
+            client_certificate_subject,
         } = event;
         Row::pack_slice(&[
             Datum::Uuid(*id),
@@ -537,6 +541,7 @@ impl Coordinator {
             ),
             Datum::String(&*application_name),
             Datum::String(&*authenticated_user),
+            Datum::from(client_certificate_subject.as_deref()),
         ])
     }
 
@@ -792,6 +797,7 @@ impl Coordinator {
             connected_at: session.connected_at(),
             application_name: session.application_name().to_owned(),
             authenticated_user: self.catalog.get_role(session_role).name.clone(),
+            client_certificate_subject: session.user().client_certificate_subject.clone(),
         };
         self.statement_logging.unlogged_sessions.insert(id, event);
     }
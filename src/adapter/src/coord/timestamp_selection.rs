@@ -20,6 +20,7 @@ use mz_expr::MirScalarExpr;
 use mz_ore::cast::CastLossy;
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::{GlobalId, RowArena, ScalarType, Timestamp, TimestampManipulation};
+use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::QueryWhen;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::vars::IsolationLevel;
@@ -304,7 +305,7 @@ pub trait TimestampProvider {
 
         if let Some(timestamp) = when.advance_to_timestamp() {
             let catalog_state = self.catalog_state();
-            let ts = Coordinator::evaluate_when(catalog_state, timestamp, session)?;
+            let ts = Coordinator::evaluate_as_of(catalog_state, timestamp, session)?;
             candidate.join_assign(&ts);
         }
 
@@ -390,6 +391,21 @@ pub trait TimestampProvider {
                 timestamp = format!("{candidate}")
             );
             candidate
+        } else if let QueryWhen::AtTimestamp(_) = when {
+            // The user asked for this exact time, so explain which inputs no
+            // longer retain enough history to answer it.
+            let catalog = self.catalog_state().for_session(session);
+            let inputs = invalid_inputs(id_bundle, compute_instance, &read_holds, candidate)
+                .into_iter()
+                .map(|(id, since)| {
+                    let name = catalog.resolve_full_name(catalog.get_item(&id).name());
+                    (name.to_string(), since)
+                })
+                .collect();
+            return Err(AdapterError::AsOfBeforeRetainedHistory {
+                as_of: candidate,
+                inputs,
+            });
         } else {
             coord_bail!(generate_timestamp_not_valid_error_msg(
                 id_bundle,
@@ -472,6 +488,21 @@ fn generate_timestamp_not_valid_error_msg(
     read_holds: &ReadHolds<mz_repr::Timestamp>,
     candidate: mz_repr::Timestamp,
 ) -> String {
+    let invalid = invalid_inputs(id_bundle, compute_instance, read_holds, candidate);
+    format!(
+        "Timestamp ({}) is not valid for all inputs: {:?}",
+        candidate, invalid,
+    )
+}
+
+/// Returns the inputs that can't be read at `candidate`, along with their
+/// sinces.
+fn invalid_inputs(
+    id_bundle: &CollectionIdBundle,
+    compute_instance: ComputeInstanceId,
+    read_holds: &ReadHolds<mz_repr::Timestamp>,
+    candidate: mz_repr::Timestamp,
+) -> Vec<(GlobalId, Antichain<mz_repr::Timestamp>)> {
     let mut invalid = Vec::new();
 
     if let Some(compute_ids) = id_bundle.compute_ids.get(&compute_instance) {
@@ -490,10 +521,7 @@ fn generate_timestamp_not_valid_error_msg(
         }
     }
 
-    format!(
-        "Timestamp ({}) is not valid for all inputs: {:?}",
-        candidate, invalid,
-    )
+    invalid
 }
 
 impl Coordinator {
@@ -613,13 +641,38 @@ impl Coordinator {
         }
     }
 
-    pub(crate) fn evaluate_when(
+    /// Evaluates the timestamp of an AS OF clause, which may be relative to
+    /// `now()` or an interval before it.
+    pub(crate) fn evaluate_as_of(
+        catalog: &CatalogState,
+        timestamp: MirScalarExpr,
+        session: &Session,
+    ) -> Result<mz_repr::Timestamp, AdapterError> {
+        // Like `now()` elsewhere, this is the start of the transaction.
+        let now = match session.transaction().inner() {
+            Some(inner) => inner.pcx.wall_time,
+            None => mz_ore::now::to_datetime((catalog.config().now)()),
+        };
+        Self::evaluate_when(catalog, timestamp, session, Some(now))
+    }
+
+    /// Evaluates the timestamp of an UP TO clause.
+    pub(crate) fn evaluate_up_to(
+        catalog: &CatalogState,
+        timestamp: MirScalarExpr,
+        session: &Session,
+    ) -> Result<mz_repr::Timestamp, AdapterError> {
+        Self::evaluate_when(catalog, timestamp, session, None)
+    }
+
+    fn evaluate_when(
         catalog: &CatalogState,
         mut timestamp: MirScalarExpr,
         session: &Session,
+        now: Option<DateTime<Utc>>,
     ) -> Result<mz_repr::Timestamp, AdapterError> {
         let temp_storage = RowArena::new();
-        prep_scalar_expr(&mut timestamp, ExprPrepStyle::AsOfUpTo)?;
+        prep_scalar_expr(&mut timestamp, ExprPrepStyle::AsOfUpTo { now })?;
         let evaled = timestamp.eval(&[], &temp_storage)?;
         if evaled.is_null() {
             coord_bail!("can't use {} as a mz_timestamp for AS OF or UP TO", evaled);
@@ -645,6 +698,18 @@ impl Coordinator {
                 .and_utc()
                 .timestamp_millis()
                 .try_into()?,
+            // An interval is a duration before now, which only AS OF has.
+            ScalarType::Interval => {
+                let Some(now) = now else {
+                    coord_bail!("can't use interval as a mz_timestamp for UP TO");
+                };
+                let interval = evaled.unwrap_interval();
+                if interval.is_negative() {
+                    coord_bail!("can't use negative interval {} for AS OF", interval);
+                }
+                let ago = i64::try_from(interval.as_milliseconds())?;
+                now.timestamp_millis().saturating_sub(ago).try_into()?
+            }
             _ => coord_bail!(
                 "can't use {} as a mz_timestamp for AS OF or UP TO",
                 catalog.for_session(session).humanize_column_type(&ty)
@@ -223,6 +223,7 @@ mod tests {
                     client_ip: None,
                     external_metadata_rx: None,
                     helm_chart_version: None,
+                    client_certificate_subject: None,
                 },
                 metrics.session_metrics(),
             );
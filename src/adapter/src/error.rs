@@ -45,6 +45,14 @@ pub enum AdapterError {
         as_of: mz_repr::Timestamp,
         up_to: mz_repr::Timestamp,
     },
+    /// A query requested an `AS OF` timestamp that precedes the retained history
+    /// of some of its inputs.
+    AsOfBeforeRetainedHistory {
+        as_of: mz_repr::Timestamp,
+        /// The names of the inputs that can't be read at `as_of`, along with
+        /// the earliest times at which they can be.
+        inputs: Vec<(String, Antichain<mz_repr::Timestamp>)>,
+    },
     /// Attempted to use a potentially ambiguous column reference expression with a system table.
     // We don't allow this until https://github.com/MaterializeInc/database-issues/issues/4824 is
     // resolved because it prevents us from adding columns to system tables.
@@ -132,6 +140,11 @@ pub enum AdapterError {
         limit: String,
         current: String,
     },
+    /// A peek was rejected because the queue of its resource group is full.
+    ResourceGroupQueueFull {
+        name: String,
+        limit: u32,
+    },
     /// Result size of a query is too large.
     ResultSize(String),
     /// The specified feature is not permitted in safe mode.
@@ -187,6 +200,9 @@ pub enum AdapterError {
     DDLOnlyTransaction,
     /// Another session modified the Catalog while this transaction was open.
     DDLTransactionRace,
+    /// A table read by a read/write transaction was written by another session before the
+    /// transaction committed.
+    ReadWriteTransactionConflict(String),
     /// Used to prevent us from durably committing state while a DDL transaction is open, should
     /// never be returned to the user.
     TransactionDryRun {
@@ -258,6 +274,16 @@ impl AdapterError {
             AdapterError::AmbiguousSystemColumnReference => {
                 Some("This is a current limitation in Materialize".into())
             },
+            AdapterError::AsOfBeforeRetainedHistory { inputs, .. } => Some(
+                inputs
+                    .iter()
+                    .map(|(name, since)| match since.as_option() {
+                        Some(since) => format!("{} retains history from {}", name.quoted(), since),
+                        None => format!("{} can no longer be read", name.quoted()),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
             AdapterError::Catalog(c) => c.detail(),
             AdapterError::Eval(e) => e.detail(),
             AdapterError::RelationOutsideTimeDomain { relations, names } => Some(format!(
@@ -344,6 +370,7 @@ impl AdapterError {
             AdapterError::RtrDropFailure(name) => Some(format!("{name} dropped before ingesting data to the real-time recency point")),
             AdapterError::UserSessionsDisallowed => Some("Your organization has been blocked. Please contact support.".to_string()),
             AdapterError::NetworkPolicyDenied(reason)=> Some(format!("{reason}.")),
+            AdapterError::ReadWriteTransactionConflict(name) => Some(format!("Table {name} was written after this transaction read it.")),
             _ => None,
         }
     }
@@ -356,6 +383,9 @@ impl AdapterError {
                 convert all NATURAL JOINs to USING joins."
                     .to_string(),
             ),
+            AdapterError::AsOfBeforeRetainedHistory { .. } => Some(
+                "Query a later time, or increase the RETAIN HISTORY of the inputs.".into(),
+            ),
             AdapterError::Catalog(c) => c.hint(),
             AdapterError::Eval(e) => e.hint(),
             AdapterError::InvalidClusterReplicaAz { expected, az: _ } => {
@@ -390,6 +420,9 @@ impl AdapterError {
             AdapterError::ResourceExhaustion { resource_type, .. } => Some(format!(
                 "Drop an existing {resource_type} or contact support to request a limit increase."
             )),
+            AdapterError::ResourceGroupQueueFull { .. } => Some(
+                "Retry the query once some of the role's running queries have completed.".into(),
+            ),
             AdapterError::StatementTimeout => Some(
                 "Consider increasing the maximum allowed statement duration for this session by \
                  setting the statement_timeout session variable. For example, `SET \
@@ -414,6 +447,9 @@ impl AdapterError {
             AdapterError::AlterClusterTimeout => Some(
                 "Consider increasing the timeout duration in the alter cluster statement.".into(),
             ),
+            AdapterError::ReadWriteTransactionConflict(_) => {
+                Some("The transaction might succeed if retried.".into())
+            }
             _ => None,
         }
     }
@@ -427,6 +463,7 @@ impl AdapterError {
             // DATA_EXCEPTION to match what Postgres returns for degenerate
             // range bounds
             AdapterError::AbsurdSubscribeBounds { .. } => SqlState::DATA_EXCEPTION,
+            AdapterError::AsOfBeforeRetainedHistory { .. } => SqlState::DATA_EXCEPTION,
             AdapterError::AmbiguousSystemColumnReference => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::Catalog(e) => match &e.kind {
                 mz_catalog::memory::error::ErrorKind::VarError(e) => match e {
@@ -475,6 +512,9 @@ impl AdapterError {
             AdapterError::PlanError(PlanError::ColumnAlreadyExists { .. }) => {
                 SqlState::DUPLICATE_COLUMN
             }
+            AdapterError::PlanError(PlanError::ColumnPermissionDenied { .. }) => {
+                SqlState::INSUFFICIENT_PRIVILEGE
+            }
             AdapterError::PlanError(_) => SqlState::INTERNAL_ERROR,
             AdapterError::PreparedStatementExists(_) => SqlState::DUPLICATE_PSTATEMENT,
             AdapterError::ReadOnlyTransaction => SqlState::READ_ONLY_SQL_TRANSACTION,
@@ -489,6 +529,7 @@ impl AdapterError {
             AdapterError::RecursionLimit(_) => SqlState::INTERNAL_ERROR,
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
+            AdapterError::ResourceGroupQueueFull { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
             AdapterError::SubscribeOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
@@ -525,6 +566,7 @@ impl AdapterError {
             AdapterError::Unstructured(_) => SqlState::INTERNAL_ERROR,
             AdapterError::UntargetedLogRead { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::DDLTransactionRace => SqlState::T_R_SERIALIZATION_FAILURE,
+            AdapterError::ReadWriteTransactionConflict(_) => SqlState::T_R_SERIALIZATION_FAILURE,
             AdapterError::TransactionDryRun { .. } => SqlState::T_R_SERIALIZATION_FAILURE,
             // It's not immediately clear which error code to use here because a
             // "write-only transaction", "single table write transaction", or "ddl only
@@ -569,6 +611,18 @@ impl fmt::Display for AdapterError {
                     up_to, as_of
                 )
             }
+            AdapterError::AsOfBeforeRetainedHistory { as_of, inputs } => {
+                write!(
+                    f,
+                    "AS OF timestamp {} is before the retained history of {}",
+                    as_of,
+                    inputs
+                        .iter()
+                        .map(|(name, _)| name.quoted().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
             AdapterError::AmbiguousSystemColumnReference => {
                 write!(
                     f,
@@ -677,6 +731,12 @@ impl fmt::Display for AdapterError {
                     "creating {resource_type} would violate {limit_name} limit (desired: {desired}, limit: {limit}, current: {current})"
                 )
             }
+            AdapterError::ResourceGroupQueueFull { name, limit } => {
+                write!(
+                    f,
+                    "resource group \"{name}\" has reached its limit of {limit} queued queries"
+                )
+            }
             AdapterError::ResultSize(e) => write!(f, "{e}"),
             AdapterError::SafeModeViolation(feature) => {
                 write!(f, "cannot create {} in safe mode", feature)
@@ -732,6 +792,9 @@ impl fmt::Display for AdapterError {
             AdapterError::DDLTransactionRace => {
                 f.write_str("object state changed while transaction was in progress")
             }
+            AdapterError::ReadWriteTransactionConflict(_) => {
+                f.write_str("could not serialize access due to concurrent update")
+            }
             AdapterError::TransactionDryRun { .. } => f.write_str("transaction dry run"),
             AdapterError::Storage(e) => e.fmt(f),
             AdapterError::Compute(e) => e.fmt(f),
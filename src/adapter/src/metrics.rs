@@ -42,6 +42,8 @@ pub struct Metrics {
     pub check_scheduling_policies_seconds: HistogramVec,
     pub handle_scheduling_decisions_seconds: HistogramVec,
     pub row_set_finishing_seconds: HistogramVec,
+    pub audit_log_failed_logins_dropped: IntCounterVec,
+    pub network_policy_denials: IntCounterVec,
 }
 
 impl Metrics {
@@ -174,6 +176,16 @@ impl Metrics {
                 help: "The time it takes to run RowSetFinishing::finish.",
                 buckets: histogram_seconds_buckets(0.000_128, 16.0),
             )),
+            audit_log_failed_logins_dropped: registry.register(metric!(
+                name: "mz_audit_log_failed_logins_dropped_total",
+                help: "The number of failed logins not recorded in the audit log.",
+                var_labels: ["reason"],
+            )),
+            network_policy_denials: registry.register(metric!(
+                name: "mz_network_policy_denials_total",
+                help: "The number of connections and requests denied by a network policy.",
+                var_labels: ["interface"],
+            )),
         }
     }
 
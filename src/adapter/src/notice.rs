@@ -190,6 +190,8 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::PolicyDoesNotExist { .. } => Severity::Notice,
+                PlanNotice::ResourceGroupDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
             },
             AdapterNotice::UnknownSessionDatabase(_) => Severity::Notice,
@@ -291,6 +293,8 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::PolicyDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
+                PlanNotice::ResourceGroupDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
             },
             AdapterNotice::UnknownSessionDatabase(_) => SqlState::from_code("MZ004"),
@@ -102,6 +102,11 @@ pub struct DataflowBuilder<'a> {
     ///
     /// Bound from [`OptimizerConfig::replan`].
     pub replan: Option<GlobalId>,
+    /// If set, persisted indexes on the given [`GlobalId`] are not imported.
+    ///
+    /// Set when building the dataflow of an index, which must compute the collection it indexes
+    /// rather than read it back from another index on the same collection.
+    pub ignore_persisted_indexes_on: Option<GlobalId>,
     /// A guard for recursive operations in this [`DataflowBuilder`] instance.
     recursion_guard: RecursionGuard,
 }
@@ -119,7 +124,11 @@ pub enum ExprPrepStyle<'a> {
         catalog_state: &'a CatalogState,
     },
     /// The expression is being prepared for evaluation in an AS OF or UP TO clause.
-    AsOfUpTo,
+    AsOfUpTo {
+        /// Time at which this expression is being evaluated, if it may call
+        /// `now()`, which is only the case for AS OF.
+        now: Option<DateTime<Utc>>,
+    },
     /// The expression is being prepared for evaluation in a CHECK expression of a webhook source.
     WebhookValidation {
         /// Time at which this expression is being evaluated.
@@ -155,6 +164,7 @@ impl<'a> DataflowBuilder<'a> {
             catalog,
             compute,
             replan: None,
+            ignore_persisted_indexes_on: None,
             recursion_guard: RecursionGuard::with_limit(RECURSION_LIMIT),
         }
     }
@@ -168,6 +178,12 @@ impl<'a> DataflowBuilder<'a> {
         self
     }
 
+    /// Prevents the builder from importing persisted indexes on `id`.
+    pub(super) fn ignore_persisted_indexes_on(mut self, id: GlobalId) -> Self {
+        self.ignore_persisted_indexes_on = Some(id);
+        self
+    }
+
     /// Imports the view, source, or table with `id` into the provided
     /// dataflow description. [`OptimizerFeatures`] is used while running
     /// the [`Monotonic`] analysis.
@@ -206,6 +222,27 @@ impl<'a> DataflowBuilder<'a> {
                         .expect("indexes can only be built on items with descs");
                     dataflow.import_index(index_id, index_desc, desc.typ().clone(), monotonic);
                 }
+            } else if let Some((index_id, idx)) = self.persisted_index_on(*id) {
+                // Without a local index, a persisted index on any cluster provides the
+                // collection already arranged.
+                let index_desc = IndexDesc {
+                    on_id: *id,
+                    key: idx.keys.to_vec(),
+                };
+                let entry = self.catalog.get_entry(id);
+                let desc = entry
+                    .desc(
+                        &self
+                            .catalog
+                            .resolve_full_name(entry.name(), entry.conn_id()),
+                    )
+                    .expect("indexes can only be built on items with descs");
+                dataflow.import_persisted_index(
+                    index_id,
+                    index_desc,
+                    desc.typ().clone(),
+                    monotonic,
+                );
             } else {
                 drop(valid_indexes);
                 let entry = self.catalog.get_entry(id);
@@ -447,7 +484,7 @@ pub fn prep_relation_expr(
             })
         }
         ExprPrepStyle::OneShot { .. }
-        | ExprPrepStyle::AsOfUpTo
+        | ExprPrepStyle::AsOfUpTo { .. }
         | ExprPrepStyle::WebhookValidation { .. } => expr
             .0
             .try_visit_scalars_mut(&mut |s| prep_scalar_expr(s, style)),
@@ -461,7 +498,9 @@ pub fn prep_relation_expr(
 ///
 /// * `OneShot`: Calls to all unmaterializable functions are replaced.
 /// * `Index`: An error is produced if a call to an unmaterializable function is encountered.
-/// * `AsOfUpTo`: An error is produced if a call to an unmaterializable function is encountered.
+/// * `AsOfUpTo`: Calls to `UnmaterializableFunc::CurrentTimestamp` are replaced if `now` is
+///   given, and an error is produced if a call to any other unmaterializable function is
+///   encountered.
 /// * `WebhookValidation`: Only calls to `UnmaterializableFunc::CurrentTimestamp` are replaced,
 ///   others are left untouched.
 ///
@@ -484,7 +523,21 @@ pub fn prep_scalar_expr(
         }),
 
         // Reject the query if it contains any unmaterializable function calls.
-        ExprPrepStyle::Index | ExprPrepStyle::AsOfUpTo => {
+        ExprPrepStyle::Index | ExprPrepStyle::AsOfUpTo { .. } => {
+            // `now()` is well defined when evaluating an AS OF, so that past
+            // times can be expressed relative to it.
+            if let ExprPrepStyle::AsOfUpTo { now: Some(now) } = style {
+                expr.try_visit_mut_post(&mut |e| {
+                    if let MirScalarExpr::CallUnmaterializable(
+                        f @ UnmaterializableFunc::CurrentTimestamp,
+                    ) = e
+                    {
+                        let now: Datum = now.try_into()?;
+                        *e = MirScalarExpr::literal_ok(now, f.output_type().scalar_type);
+                    }
+                    Ok::<_, OptimizerError>(())
+                })?;
+            }
             let mut last_observed_unmaterializable_func = None;
             expr.visit_mut_post(&mut |e| {
                 if let MirScalarExpr::CallUnmaterializable(f) = e {
@@ -495,7 +548,7 @@ pub fn prep_scalar_expr(
             if let Some(f) = last_observed_unmaterializable_func {
                 let err = match style {
                     ExprPrepStyle::Index => OptimizerError::UnmaterializableFunction(f),
-                    ExprPrepStyle::AsOfUpTo => OptimizerError::UncallableFunction {
+                    ExprPrepStyle::AsOfUpTo { .. } => OptimizerError::UncallableFunction {
                         func: f,
                         context: "AS OF or UP TO",
                     },
@@ -30,6 +30,9 @@ use std::time::{Duration, Instant};
 
 use mz_compute_types::dataflows::IndexDesc;
 use mz_compute_types::plan::Plan;
+use mz_compute_types::sinks::{
+    ComputeSinkConnection, ComputeSinkDesc, PersistedIndexSinkConnection,
+};
 use mz_repr::explain::trace_plan;
 use mz_repr::GlobalId;
 use mz_sql::names::QualifiedItemName;
@@ -39,6 +42,7 @@ use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::notice::{IndexAlreadyExists, IndexKeyEmpty};
 use mz_transform::typecheck::{empty_context, SharedContext as TypecheckContext};
 use mz_transform::TransformCtx;
+use timely::progress::Antichain;
 
 use crate::optimize::dataflows::{
     prep_relation_expr, prep_scalar_expr, ComputeInstanceSnapshot, DataflowBuilder, ExprPrepStyle,
@@ -90,12 +94,23 @@ pub struct Index {
     name: QualifiedItemName,
     on: GlobalId,
     keys: Vec<mz_expr::MirScalarExpr>,
+    persisted: bool,
 }
 
 impl Index {
     /// Construct a new [`Index`]. Arguments are recorded as-is.
-    pub fn new(name: QualifiedItemName, on: GlobalId, keys: Vec<mz_expr::MirScalarExpr>) -> Self {
-        Self { name, on, keys }
+    pub fn new(
+        name: QualifiedItemName,
+        on: GlobalId,
+        keys: Vec<mz_expr::MirScalarExpr>,
+        persisted: bool,
+    ) -> Self {
+        Self {
+            name,
+            on,
+            keys,
+            persisted,
+        }
     }
 }
 
@@ -150,18 +165,38 @@ impl Optimize<Index> for Optimizer {
 
         let mut df_builder = {
             let compute = self.compute_instance.clone();
-            DataflowBuilder::new(&*self.catalog, compute).with_config(&self.config)
+            DataflowBuilder::new(&*self.catalog, compute)
+                .with_config(&self.config)
+                .ignore_persisted_indexes_on(index.on)
         };
         let mut df_desc = MirDataflowDescription::new(full_name.to_string());
 
         df_builder.import_into_dataflow(&index.on, &mut df_desc, &self.config.features)?;
         df_builder.maybe_reoptimize_imported_views(&mut df_desc, &self.config)?;
 
-        let index_desc = IndexDesc {
-            on_id: index.on,
-            key: index.keys.clone(),
-        };
-        df_desc.export_index(self.exported_index_id, index_desc, on_desc.typ().clone());
+        if index.persisted {
+            // Persisted indexes are written to persist by a sink, rather than exported as an
+            // arrangement of the dataflow.
+            let sink_description = ComputeSinkDesc {
+                from: index.on,
+                from_desc: on_desc.into_owned(),
+                connection: ComputeSinkConnection::PersistedIndex(PersistedIndexSinkConnection {
+                    key: index.keys.clone(),
+                    storage_metadata: (),
+                }),
+                with_snapshot: true,
+                up_to: Antichain::default(),
+                non_null_assertions: Vec::new(),
+                refresh_schedule: None,
+            };
+            df_desc.export_sink(self.exported_index_id, sink_description);
+        } else {
+            let index_desc = IndexDesc {
+                on_id: index.on,
+                key: index.keys.clone(),
+            };
+            df_desc.export_index(self.exported_index_id, index_desc, on_desc.typ().clone());
+        }
 
         // Prepare expressions in the assembled dataflow.
         let style = ExprPrepStyle::Index;
@@ -445,4 +445,9 @@ impl GlobalLirPlan {
     pub fn unapply(self) -> (PeekPlan, DataflowMetainfo, RelationType) {
         (self.peek_plan, self.df_meta, self.typ)
     }
+
+    /// Returns the peek plan produced by the optimization pipeline.
+    pub fn peek_plan(&self) -> &PeekPlan {
+        &self.peek_plan
+    }
 }
@@ -200,6 +200,9 @@ pub struct SessionConfig {
     pub external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
     /// Helm chart version
     pub helm_chart_version: Option<String>,
+    /// The subject of the TLS client certificate that authenticated the user,
+    /// if any.
+    pub client_certificate_subject: Option<String>,
 }
 
 impl<T: TimestampManipulation> Session<T> {
@@ -282,6 +285,7 @@ impl<T: TimestampManipulation> Session<T> {
                 client_ip: None,
                 external_metadata_rx: None,
                 helm_chart_version: None,
+                client_certificate_subject: None,
             },
             metrics,
         );
@@ -298,6 +302,7 @@ impl<T: TimestampManipulation> Session<T> {
             client_ip,
             mut external_metadata_rx,
             helm_chart_version,
+            client_certificate_subject,
         }: SessionConfig,
         metrics: SessionMetrics,
     ) -> Session<T> {
@@ -308,6 +313,7 @@ impl<T: TimestampManipulation> Session<T> {
             external_metadata: external_metadata_rx
                 .as_mut()
                 .map(|rx| rx.borrow_and_update().clone()),
+            client_certificate_subject,
         };
         let mut vars = SessionVars::new_unchecked(build_info, user, helm_chart_version);
         if let Some(default_cluster) = default_cluster {
@@ -365,6 +371,7 @@ impl<T: TimestampManipulation> Session<T> {
                 }
                 TransactionOps::None
                 | TransactionOps::Writes(_)
+                | TransactionOps::ReadWrite { .. }
                 | TransactionOps::SingleStatement { .. }
                 | TransactionOps::DDL { .. } => false,
             };
@@ -579,12 +586,14 @@ impl<T: TimestampManipulation> Session<T> {
     /// Returns the transaction's read timestamp determination, if set.
     ///
     /// Returns `None` if there is no active transaction, or if the active
-    /// transaction is not a read transaction.
+    /// transaction is not a read or read-write transaction.
     pub fn get_transaction_timestamp_determination(&self) -> Option<TimestampDetermination<T>> {
         match self.transaction.inner() {
             Some(Transaction {
                 pcx: _,
-                ops: TransactionOps::Peeks { determination, .. },
+                ops:
+                    TransactionOps::Peeks { determination, .. }
+                    | TransactionOps::ReadWrite { determination, .. },
                 write_lock_guards: _,
                 access: _,
                 id: _,
@@ -1056,6 +1065,62 @@ impl<T: TimestampManipulation> TransactionStatus<T> {
         self.is_in_multi_statement_transaction() && when == &QueryWhen::Immediately
     }
 
+    /// Whether this is an explicit transaction that has so far only done reads
+    /// with a timestamp, and thus may be upgraded to a read-write transaction
+    /// with [`Self::upgrade_to_read_write`], or that already is a read-write
+    /// transaction.
+    pub fn can_upgrade_to_read_write(&self) -> bool {
+        match self {
+            TransactionStatus::InTransaction(Transaction {
+                ops: TransactionOps::Peeks { determination, .. },
+                access,
+                ..
+            }) => {
+                determination.timestamp_context.contains_timestamp()
+                    && !matches!(access, Some(TransactionAccessMode::ReadOnly))
+            }
+            TransactionStatus::InTransaction(Transaction {
+                ops: TransactionOps::ReadWrite { .. },
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Turns a transaction that has so far only done reads with a timestamp
+    /// into a read-write transaction, permitting it to write. Does nothing
+    /// otherwise.
+    pub fn upgrade_to_read_write(&mut self) {
+        if !self.can_upgrade_to_read_write() {
+            return;
+        }
+        if let TransactionStatus::InTransaction(Transaction {
+            ops: TransactionOps::ReadWrite { .. },
+            ..
+        }) = self
+        {
+            return;
+        }
+        let TransactionStatus::InTransaction(Transaction { ops, .. }) = self else {
+            unreachable!("checked above");
+        };
+        let TransactionOps::Peeks {
+            determination,
+            cluster_id,
+            read_ids,
+            ..
+        } = std::mem::take(ops)
+        else {
+            unreachable!("checked above");
+        };
+        *ops = TransactionOps::ReadWrite {
+            determination,
+            cluster_id,
+            read_ids,
+            writes: Vec::new(),
+        };
+    }
+
     /// Grants the writes lock to the inner transaction, returning an error if the transaction
     /// has already been granted write locks.
     ///
@@ -1156,13 +1221,16 @@ impl<T: TimestampManipulation> TransactionStatus<T> {
                         determination,
                         cluster_id,
                         requires_linearization,
+                        read_ids,
                     } => match add_ops {
                         TransactionOps::Peeks {
                             determination: add_timestamp_determination,
                             cluster_id: add_cluster_id,
                             requires_linearization: add_requires_linearization,
+                            read_ids: add_read_ids,
                         } => {
                             assert_eq!(*cluster_id, add_cluster_id);
+                            read_ids.extend(add_read_ids);
                             match (
                                 &determination.timestamp_context,
                                 &add_timestamp_determination.timestamp_context,
@@ -1224,6 +1292,43 @@ impl<T: TimestampManipulation> TransactionStatus<T> {
                             return Err(AdapterError::WriteOnlyTransaction);
                         }
                     },
+                    TransactionOps::ReadWrite {
+                        determination,
+                        cluster_id,
+                        read_ids,
+                        writes: txn_writes,
+                    } => match add_ops {
+                        TransactionOps::Writes(mut add_writes) => {
+                            txn_writes.append(&mut add_writes);
+                        }
+                        // Further reads happen at the transaction's timestamp and are
+                        // validated at commit like the earlier ones. The coordinator
+                        // rejects reads of tables the transaction has written, which
+                        // would not observe those writes.
+                        TransactionOps::Peeks {
+                            determination: add_timestamp_determination,
+                            cluster_id: add_cluster_id,
+                            read_ids: add_read_ids,
+                            ..
+                        } => {
+                            if add_timestamp_determination
+                                .timestamp_context
+                                .contains_timestamp()
+                            {
+                                assert_eq!(*cluster_id, add_cluster_id);
+                                assert_eq!(
+                                    determination.timestamp_context.timeline_timestamp(),
+                                    add_timestamp_determination
+                                        .timestamp_context
+                                        .timeline_timestamp()
+                                );
+                                read_ids.extend(add_read_ids);
+                            }
+                        }
+                        _ => {
+                            return Err(AdapterError::WriteOnlyTransaction);
+                        }
+                    },
                     TransactionOps::SingleStatement { .. } => {
                         return Err(AdapterError::SingleStatementTransaction)
                     }
@@ -1308,11 +1413,20 @@ impl<T> Transaction<T> {
                         ..
                     },
                 ..
+            }
+            | TransactionOps::ReadWrite {
+                determination:
+                    TimestampDetermination {
+                        timestamp_context: TimestampContext::TimelineTimestamp { timeline, .. },
+                        ..
+                    },
+                ..
             } => Some(timeline.clone()),
             TransactionOps::Peeks { .. }
             | TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
+            | TransactionOps::ReadWrite { .. }
             | TransactionOps::SingleStatement { .. }
             | TransactionOps::DDL { .. } => None,
         }
@@ -1321,7 +1435,8 @@ impl<T> Transaction<T> {
     /// The cluster of the transaction, if one exists.
     pub fn cluster(&self) -> Option<ClusterId> {
         match &self.ops {
-            TransactionOps::Peeks { cluster_id, .. } => Some(cluster_id.clone()),
+            TransactionOps::Peeks { cluster_id, .. }
+            | TransactionOps::ReadWrite { cluster_id, .. } => Some(cluster_id.clone()),
             TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
@@ -1397,12 +1512,32 @@ pub enum TransactionOps<T> {
         cluster_id: ClusterId,
         /// Whether this peek needs to be linearized.
         requires_linearization: RequireLinearization,
+        /// The collections read by the peeks that have a timestamp. These are
+        /// validated at commit if the transaction goes on to write (see
+        /// [`TransactionOps::ReadWrite`]).
+        read_ids: BTreeSet<GlobalId>,
     },
     /// This transaction has done a `SUBSCRIBE` and must do nothing else.
     Subscribe,
     /// This transaction has had a write (`INSERT`, `UPDATE`, `DELETE`) and must
     /// only do other writes, or reads whose timestamp is None (i.e. constants).
     Writes(Vec<WriteOp>),
+    /// This explicit transaction has had peeks with a timestamp followed by a
+    /// write, which is only possible when `enable_read_write_transactions` is
+    /// on. It may do further writes, and further reads at the same timestamp
+    /// of tables it has not written. At commit, the writes are only applied if
+    /// none of the tables that were read have been written since the peek
+    /// timestamp; otherwise the transaction fails with a serialization failure.
+    ReadWrite {
+        /// The timestamp and timestamp related metadata for the peeks.
+        determination: TimestampDetermination<T>,
+        /// The cluster used to execute peeks.
+        cluster_id: ClusterId,
+        /// The collections read by the peeks.
+        read_ids: BTreeSet<GlobalId>,
+        /// The write operations.
+        writes: Vec<WriteOp>,
+    },
     /// This transaction has a prospective statement that will execute during commit.
     SingleStatement {
         /// The prospective statement.
@@ -1426,7 +1561,8 @@ pub enum TransactionOps<T> {
 impl<T> TransactionOps<T> {
     fn timestamp_determination(self) -> Option<TimestampDetermination<T>> {
         match self {
-            TransactionOps::Peeks { determination, .. } => Some(determination),
+            TransactionOps::Peeks { determination, .. }
+            | TransactionOps::ReadWrite { determination, .. } => Some(determination),
             TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
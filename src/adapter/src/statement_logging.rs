@@ -132,6 +132,7 @@ pub struct SessionHistoryEvent {
     pub connected_at: EpochMillis,
     pub application_name: String,
     pub authenticated_user: String,
+    pub client_certificate_subject: Option<String>,
 }
 
 impl From<&Result<ExecuteResponse, AdapterError>> for StatementEndedExecutionReason {
@@ -187,6 +188,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
 
             ExecuteResponse::AlteredDefaultPrivileges
             | ExecuteResponse::AlteredObject(_)
+            | ExecuteResponse::AlteredResourceGroup
             | ExecuteResponse::AlteredRole
             | ExecuteResponse::AlteredSystemConfiguration
             | ExecuteResponse::ClosedCursor
@@ -210,6 +212,8 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedContinualTask
             | ExecuteResponse::CreatedType
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPolicy
+            | ExecuteResponse::CreatedResourceGroup
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::DeclaredCursor
             | ExecuteResponse::Deleted(_)
@@ -217,6 +221,8 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPolicy
+            | ExecuteResponse::DroppedResourceGroup
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
@@ -166,6 +166,12 @@ impl CompletedClientTransmitter {
         }
     }
 
+    /// Replaces the response with `err`, rolling back the transaction.
+    pub fn set_error(&mut self, err: AdapterError) {
+        self.response = Err(err);
+        self.action = EndTransactionAction::Rollback;
+    }
+
     /// Returns the execute context to be finalized, and the result to send it.
     pub fn finalize(mut self) -> (ExecuteContext, Result<ExecuteResponse, AdapterError>) {
         let changed = self
@@ -379,6 +385,7 @@ impl<T> ShouldTerminateGracefully for StorageError<T> {
             | StorageError::ExportInstanceMissing { .. }
             | StorageError::Generic(_)
             | StorageError::ReadOnly
+            | StorageError::ReadConflict(_)
             | StorageError::DataflowError(_)
             | StorageError::InvalidAlter { .. }
             | StorageError::ShuttingDown(_)
@@ -398,7 +405,8 @@ impl ShouldTerminateGracefully for DataflowCreationError {
             | DataflowCreationError::ReplicaMissing(_)
             | DataflowCreationError::MissingAsOf
             | DataflowCreationError::EmptyAsOfForSubscribe
-            | DataflowCreationError::EmptyAsOfForCopyTo => false,
+            | DataflowCreationError::EmptyAsOfForCopyTo
+            | DataflowCreationError::UpToForPersistedIndex(_) => false,
         }
     }
 }
@@ -24,6 +24,7 @@ use mz_storage_client::statistics::WebhookStatistics;
 use mz_storage_types::controller::StorageError;
 use tokio::sync::Semaphore;
 
+use crate::coord::{NetworkPolicy, NetworkPolicyError};
 use crate::optimize::dataflows::{prep_scalar_expr, ExprPrepStyle};
 
 /// Errors returns when attempting to append to a webhook.
@@ -44,6 +45,8 @@ pub enum AppendWebhookError {
     },
     #[error("failed to validate the request")]
     ValidationFailed,
+    #[error("request denied: {0}")]
+    NetworkPolicyDenied(NetworkPolicyError),
     // Note: we should _NEVER_ add more detail to this error, including the actual error we got
     // when running validation. This is because the error messages might contain info about the
     // arguments provided to the validation expression, we could contains user SECRETs. So by
@@ -241,6 +244,10 @@ pub struct AppendWebhookResponse {
     /// Expression used to validate a webhook request.
     #[derivative(Debug = "ignore")]
     pub validator: Option<AppendWebhookValidator>,
+    /// Name of the role that owns the webhook source.
+    pub owner: String,
+    /// Network policy of the role that owns the webhook source, which requests must satisfy.
+    pub network_policy: Result<NetworkPolicy, NetworkPolicyError>,
 }
 
 /// A wrapper around [`MonotonicAppender`] that can get closed by the `Coordinator` if the webhook
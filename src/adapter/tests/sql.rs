@@ -99,6 +99,7 @@ async fn datadriven() {
                                             resolved_ids: ResolvedIds(BTreeSet::new()),
                                             custom_logical_compaction_window: None,
                                             is_retained_metrics_object: false,
+                                            retention: None,
                                             data_source: TableDataSource::TableWrites {
                                                 defaults: vec![],
                                             },
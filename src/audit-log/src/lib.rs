@@ -83,6 +83,9 @@ pub enum EventType {
     Grant,
     Revoke,
     Comment,
+    Login,
+    LoginFailed,
+    Select,
 }
 
 impl EventType {
@@ -94,6 +97,9 @@ impl EventType {
             EventType::Grant => "Granted",
             EventType::Revoke => "Revoked",
             EventType::Comment => "Comment",
+            EventType::Login => "Logged In",
+            EventType::LoginFailed => "Login Failed",
+            EventType::Select => "Selected",
         }
     }
 }
@@ -114,6 +120,8 @@ pub enum ObjectType {
     Index,
     MaterializedView,
     NetworkPolicy,
+    Policy,
+    ResourceGroup,
     Role,
     Secret,
     Schema,
@@ -137,6 +145,8 @@ impl ObjectType {
             ObjectType::Index => "Index",
             ObjectType::MaterializedView => "Materialized View",
             ObjectType::NetworkPolicy => "Network Policy",
+            ObjectType::Policy => "Policy",
+            ObjectType::ResourceGroup => "Resource Group",
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
@@ -185,6 +195,14 @@ pub enum EventDetails {
     UpdateItemV1(UpdateItemV1),
     RenameSchemaV1(RenameSchemaV1),
     AlterRetainHistoryV1(AlterRetainHistoryV1),
+    AlterRowSecurityV1(AlterRowSecurityV1),
+    PolicyV1(PolicyV1),
+    UpdateColumnPrivilegeV1(UpdateColumnPrivilegeV1),
+    AlterColumnMaskV1(AlterColumnMaskV1),
+    ResourceGroupV1(ResourceGroupV1),
+    LoginV1(LoginV1),
+    LoginFailedV1(LoginFailedV1),
+    SelectV1(SelectV1),
     ToNewIdV1(ToNewIdV1),
     FromPreviousIdV1(FromPreviousIdV1),
     SetV1(SetV1),
@@ -492,6 +510,74 @@ pub struct AlterRetainHistoryV1 {
     pub new_history: Option<String>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct AlterRowSecurityV1 {
+    pub id: String,
+    #[serde(flatten)]
+    pub name: FullNameV1,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct PolicyV1 {
+    pub name: String,
+    pub relation_id: String,
+    pub relation_name: FullNameV1,
+    pub roles: Vec<String>,
+    pub using: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct UpdateColumnPrivilegeV1 {
+    pub object_id: String,
+    pub column_name: String,
+    pub grantee_id: String,
+    pub grantor_id: String,
+    pub privileges: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct AlterColumnMaskV1 {
+    pub id: String,
+    #[serde(flatten)]
+    pub name: FullNameV1,
+    pub column_name: String,
+    pub mask: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ResourceGroupV1 {
+    pub name: String,
+    pub roles: Vec<String>,
+    pub max_concurrent_peeks: Option<u32>,
+    pub max_queued_peeks: Option<u32>,
+    pub statement_timeout: Option<String>,
+    pub max_result_size: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct LoginV1 {
+    pub role_name: String,
+    pub session_id: String,
+    pub application_name: String,
+    pub client_ip: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct LoginFailedV1 {
+    pub role_name: String,
+    pub client_ip: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct SelectV1 {
+    pub id: String,
+    #[serde(flatten)]
+    pub name: FullNameV1,
+    pub session_id: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct UpdateItemV1 {
     pub id: String,
@@ -559,6 +645,16 @@ impl EventDetails {
             EventDetails::AlterRetainHistoryV1(v) => {
                 serde_json::to_value(v).expect("must serialize")
             }
+            EventDetails::AlterRowSecurityV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::PolicyV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::UpdateColumnPrivilegeV1(v) => {
+                serde_json::to_value(v).expect("must serialize")
+            }
+            EventDetails::AlterColumnMaskV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::ResourceGroupV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::LoginV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::LoginFailedV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::SelectV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::ToNewIdV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::FromPreviousIdV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::SetV1(v) => serde_json::to_value(v).expect("must serialize"),
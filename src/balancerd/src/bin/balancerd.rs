@@ -228,6 +228,10 @@ pub async fn run(args: ServiceArgs, tracing_handle: TracingHandle) -> Result<(),
             "exactly one of --static-resolver-addr or --frontegg-resolver-template must be present"
         ),
     };
+    let tls = args.tls.into_config()?;
+    if tls.as_ref().is_some_and(|tls| tls.client_auth.is_some()) {
+        anyhow::bail!("balancerd does not support --tls-client-ca");
+    }
     let config = BalancerConfig::new(
         &BUILD_INFO,
         args.internal_http_listen_addr,
@@ -236,7 +240,7 @@ pub async fn run(args: ServiceArgs, tracing_handle: TracingHandle) -> Result<(),
         args.cancellation_resolver_dir,
         resolver,
         args.https_resolver_template,
-        args.tls.into_config()?,
+        tls,
         metrics_registry,
         mz_server_core::default_cert_reload_ticker(),
         args.launchdarkly_sdk_key,
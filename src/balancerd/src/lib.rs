@@ -266,6 +266,7 @@ impl BalancerService {
                     Some(ReloadingTlsConfig {
                         context: context.clone(),
                         mode: TlsMode::Require,
+                        client_auth: None,
                     }),
                     Some(context),
                 )
@@ -27,9 +27,10 @@ use mz_build_info::{build_info, BuildInfo};
 use mz_catalog::config::{BuiltinItemMigrationConfig, ClusterReplicaSizeMap, StateConfig};
 use mz_catalog::durable::debug::{
     AuditLogCollection, ClusterCollection, ClusterIntrospectionSourceIndexCollection,
-    ClusterReplicaCollection, Collection, CollectionTrace, CollectionType, CommentCollection,
-    ConfigCollection, DatabaseCollection, DebugCatalogState, DefaultPrivilegeCollection,
-    IdAllocatorCollection, ItemCollection, NetworkPolicyCollection, RoleCollection,
+    ClusterReplicaCollection, Collection, CollectionTrace, CollectionType,
+    ColumnSecurityCollection, CommentCollection, ConfigCollection, DatabaseCollection,
+    DebugCatalogState, DefaultPrivilegeCollection, IdAllocatorCollection, ItemCollection,
+    NetworkPolicyCollection, ResourceGroupCollection, RoleCollection, RowSecurityCollection,
     SchemaCollection, SettingCollection, SourceReferencesCollection,
     StorageCollectionMetadataCollection, SystemConfigurationCollection,
     SystemItemMappingCollection, SystemPrivilegeCollection, Trace, TxnWalShardCollection,
@@ -279,6 +280,9 @@ macro_rules! for_collection {
             CollectionType::Schema => $fn::<SchemaCollection>($($arg),*).await?,
             CollectionType::Setting => $fn::<SettingCollection>($($arg),*).await?,
             CollectionType::SourceReferences => $fn::<SourceReferencesCollection>($($arg),*).await?,
+            CollectionType::RowSecurity => $fn::<RowSecurityCollection>($($arg),*).await?,
+            CollectionType::ColumnSecurity => $fn::<ColumnSecurityCollection>($($arg),*).await?,
+            CollectionType::ResourceGroup => $fn::<ResourceGroupCollection>($($arg),*).await?,
             CollectionType::SystemConfiguration => $fn::<SystemConfigurationCollection>($($arg),*).await?,
             CollectionType::SystemGidMapping => $fn::<SystemItemMappingCollection>($($arg),*).await?,
             CollectionType::SystemPrivileges => $fn::<SystemPrivilegeCollection>($($arg),*).await?,
@@ -416,6 +420,9 @@ async fn dump(
         schemas,
         settings,
         source_references,
+        row_security,
+        column_security,
+        resource_groups,
         system_object_mappings,
         system_configurations,
         system_privileges,
@@ -475,6 +482,9 @@ async fn dump(
         stats_only,
         consolidate,
     );
+    dump_col(&mut data, row_security, &ignore, stats_only, consolidate);
+    dump_col(&mut data, column_security, &ignore, stats_only, consolidate);
+    dump_col(&mut data, resource_groups, &ignore, stats_only, consolidate);
     dump_col(
         &mut data,
         system_configurations,
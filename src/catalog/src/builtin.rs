@@ -46,8 +46,9 @@ use mz_sql::catalog::{
 };
 use mz_sql::rbac;
 use mz_sql::session::user::{
-    ANALYTICS_USER_NAME, MZ_ANALYTICS_ROLE_ID, MZ_MONITOR_REDACTED_ROLE_ID, MZ_MONITOR_ROLE_ID,
-    MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID, SUPPORT_USER_NAME, SYSTEM_USER_NAME,
+    ANALYTICS_USER_NAME, MZ_ANALYTICS_ROLE_ID, MZ_EXTERNAL_ROLE_SYNC_ROLE_ID,
+    MZ_MONITOR_REDACTED_ROLE_ID, MZ_MONITOR_ROLE_ID, MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID,
+    SUPPORT_USER_NAME, SYSTEM_USER_NAME,
 };
 use mz_storage_client::controller::IntrospectionType;
 use mz_storage_client::healthcheck::{
@@ -1954,6 +1955,15 @@ pub static MZ_COMPUTE_HYDRATION_TIMES_PER_WORKER: LazyLock<BuiltinLog> =
         access: vec![PUBLIC_SELECT],
     });
 
+pub static MZ_COMPUTE_ARRANGEMENT_CHECKPOINTS_PER_WORKER: LazyLock<BuiltinLog> =
+    LazyLock::new(|| BuiltinLog {
+        name: "mz_compute_arrangement_checkpoints_per_worker",
+        schema: MZ_INTROSPECTION_SCHEMA,
+        oid: oid::LOG_MZ_COMPUTE_ARRANGEMENT_CHECKPOINTS_PER_WORKER_OID,
+        variant: LogVariant::Compute(ComputeLog::ArrangementCheckpoints),
+        access: vec![PUBLIC_SELECT],
+    });
+
 pub static MZ_ACTIVE_PEEKS_PER_WORKER: LazyLock<BuiltinLog> = LazyLock::new(|| BuiltinLog {
     name: "mz_active_peeks_per_worker",
     schema: MZ_INTROSPECTION_SCHEMA,
@@ -2182,6 +2192,23 @@ pub static MZ_COMPUTE_OPERATOR_HYDRATION_STATUSES_PER_WORKER: LazyLock<BuiltinSo
         is_retained_metrics_object: false,
         access: vec![PUBLIC_SELECT],
     });
+pub static MZ_COMPUTE_DATAFLOW_MEMORY_LIMIT_STATUSES_PER_WORKER: LazyLock<BuiltinSource> =
+    LazyLock::new(|| BuiltinSource {
+        name: "mz_compute_dataflow_memory_limit_statuses_per_worker",
+        schema: MZ_INTERNAL_SCHEMA,
+        oid: oid::SOURCE_MZ_COMPUTE_DATAFLOW_MEMORY_LIMIT_STATUSES_PER_WORKER_OID,
+        data_source: IntrospectionType::ComputeDataflowMemoryLimitStatus,
+        desc: RelationDesc::builder()
+            .with_column("object_id", ScalarType::String.nullable(false))
+            .with_column("replica_id", ScalarType::String.nullable(false))
+            .with_column("worker_id", ScalarType::UInt64.nullable(false))
+            .with_column("heap_size", ScalarType::UInt64.nullable(false))
+            .with_column("memory_limit", ScalarType::UInt64.nullable(false))
+            .with_column("error", ScalarType::String.nullable(false))
+            .finish(),
+        is_retained_metrics_object: false,
+        access: vec![PUBLIC_SELECT],
+    });
 
 pub static MZ_DATABASES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     name: "mz_databases",
@@ -3674,6 +3701,111 @@ pub static MZ_COMMENTS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     access: vec![PUBLIC_SELECT],
 });
 
+pub static MZ_COLUMN_PRIVILEGES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_column_privileges",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_COLUMN_PRIVILEGES_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("position", ScalarType::UInt64.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::MzAclItem)).nullable(false),
+        )
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_COLUMN_MASKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_column_masks",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_COLUMN_MASKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("position", ScalarType::UInt64.nullable(false))
+        .with_column("mask", ScalarType::String.nullable(false))
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_RESOURCE_GROUPS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_resource_groups",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_RESOURCE_GROUPS_OID,
+    desc: RelationDesc::builder()
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("max_concurrent_peeks", ScalarType::UInt32.nullable(true))
+        .with_column("max_queued_peeks", ScalarType::UInt32.nullable(true))
+        .with_column("statement_timeout", ScalarType::Interval.nullable(true))
+        .with_column("max_result_size", ScalarType::UInt64.nullable(true))
+        .with_key(vec![0])
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_RESOURCE_GROUP_MEMBERS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_resource_group_members",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_RESOURCE_GROUP_MEMBERS_OID,
+    desc: RelationDesc::builder()
+        .with_column("resource_group", ScalarType::String.nullable(false))
+        .with_column("role_id", ScalarType::String.nullable(false))
+        .with_key(vec![1])
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_RESOURCE_GROUP_STATUSES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_resource_group_statuses",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_RESOURCE_GROUP_STATUSES_OID,
+    desc: RelationDesc::builder()
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("queued_peeks", ScalarType::UInt64.nullable(false))
+        .with_column("rejected_peeks", ScalarType::UInt64.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_TABLE_RETENTION_POLICIES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_table_retention_policies",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_TABLE_RETENTION_POLICIES_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("column_name", ScalarType::String.nullable(false))
+        .with_column("retention_period", ScalarType::Interval.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_TABLE_RETENTION_STATUSES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_table_retention_statuses",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_TABLE_RETENTION_STATUSES_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column(
+            "last_run_at",
+            ScalarType::TimestampTz { precision: None }.nullable(false),
+        )
+        .with_column("last_run_duration", ScalarType::Interval.nullable(false))
+        .with_column("retracted_rows", ScalarType::UInt64.nullable(false))
+        .with_column("error", ScalarType::String.nullable(true))
+        .with_key(vec![0])
+        .finish(),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
 pub static MZ_SOURCE_REFERENCES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     name: "mz_source_references",
     schema: MZ_INTERNAL_SCHEMA,
@@ -8917,6 +9049,16 @@ pub const MZ_MONITOR_REDACTED: BuiltinRole = BuiltinRole {
     attributes: RoleAttributes::new(),
 };
 
+/// This role is the grantor of role memberships synchronized from an external
+/// identity provider, which distinguishes them from memberships granted by
+/// other roles.
+pub const MZ_EXTERNAL_ROLE_SYNC_ROLE: BuiltinRole = BuiltinRole {
+    id: MZ_EXTERNAL_ROLE_SYNC_ROLE_ID,
+    name: "mz_external_role_sync",
+    oid: oid::ROLE_MZ_EXTERNAL_ROLE_SYNC_OID,
+    attributes: RoleAttributes::new(),
+};
+
 pub const MZ_SYSTEM_CLUSTER: BuiltinCluster = BuiltinCluster {
     name: SYSTEM_USER_NAME,
     owner_id: &MZ_SYSTEM_ROLE_ID,
@@ -9154,6 +9296,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Log(&MZ_COMPUTE_IMPORT_FRONTIERS_PER_WORKER),
         Builtin::Log(&MZ_COMPUTE_ERROR_COUNTS_RAW),
         Builtin::Log(&MZ_COMPUTE_HYDRATION_TIMES_PER_WORKER),
+        Builtin::Log(&MZ_COMPUTE_ARRANGEMENT_CHECKPOINTS_PER_WORKER),
         Builtin::Table(&MZ_KAFKA_SINKS),
         Builtin::Table(&MZ_KAFKA_CONNECTIONS),
         Builtin::Table(&MZ_KAFKA_SOURCES),
@@ -9211,6 +9354,13 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Table(&MZ_DEFAULT_PRIVILEGES),
         Builtin::Table(&MZ_SYSTEM_PRIVILEGES),
         Builtin::Table(&MZ_COMMENTS),
+        Builtin::Table(&MZ_COLUMN_PRIVILEGES),
+        Builtin::Table(&MZ_COLUMN_MASKS),
+        Builtin::Table(&MZ_RESOURCE_GROUPS),
+        Builtin::Table(&MZ_RESOURCE_GROUP_MEMBERS),
+        Builtin::Table(&MZ_RESOURCE_GROUP_STATUSES),
+        Builtin::Table(&MZ_TABLE_RETENTION_POLICIES),
+        Builtin::Table(&MZ_TABLE_RETENTION_STATUSES),
         Builtin::Table(&MZ_WEBHOOKS_SOURCES),
         Builtin::Table(&MZ_HISTORY_RETENTION_STRATEGIES),
         Builtin::Table(&MZ_CONTINUAL_TASKS),
@@ -9405,6 +9555,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Source(&MZ_MATERIALIZED_VIEW_REFRESHES),
         Builtin::Source(&MZ_COMPUTE_DEPENDENCIES),
         Builtin::Source(&MZ_COMPUTE_OPERATOR_HYDRATION_STATUSES_PER_WORKER),
+        Builtin::Source(&MZ_COMPUTE_DATAFLOW_MEMORY_LIMIT_STATUSES_PER_WORKER),
         Builtin::View(&MZ_MATERIALIZATION_DEPENDENCIES),
         Builtin::View(&MZ_MATERIALIZATION_LAG),
         Builtin::View(&MZ_CONSOLE_CLUSTER_UTILIZATION_OVERVIEW),
@@ -9494,6 +9645,7 @@ pub const BUILTIN_ROLES: &[&BuiltinRole] = &[
     &MZ_ANALYTICS_ROLE,
     &MZ_MONITOR_ROLE,
     &MZ_MONITOR_REDACTED,
+    &MZ_EXTERNAL_ROLE_SYNC_ROLE,
 ];
 pub const BUILTIN_CLUSTERS: &[&BuiltinCluster] = &[
     &MZ_SYSTEM_CLUSTER,
@@ -31,10 +31,11 @@ pub use crate::durable::metrics::Metrics;
 pub use crate::durable::objects::state_update::StateUpdate;
 use crate::durable::objects::Snapshot;
 pub use crate::durable::objects::{
-    Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, Comment,
-    Database, DefaultPrivilege, IntrospectionSourceIndex, Item, NetworkPolicy, ReplicaConfig,
-    ReplicaLocation, Role, Schema, SourceReference, SourceReferences, StorageCollectionMetadata,
-    SystemConfiguration, SystemObjectDescription, SystemObjectMapping, UnfinalizedShard,
+    Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, ColumnSecurity,
+    Comment, Database, DefaultPrivilege, IntrospectionSourceIndex, Item, NetworkPolicy,
+    ReplicaConfig, ReplicaLocation, ResourceGroup, Role, RowSecurity, RowSecurityPolicy, Schema,
+    SourceReference, SourceReferences, StorageCollectionMetadata, SystemConfiguration,
+    SystemObjectDescription, SystemObjectMapping, UnfinalizedShard,
 };
 pub use crate::durable::persist::{builtin_migration_shard_id, expression_cache_shard_id};
 use crate::durable::persist::{Timestamp, UnopenedPersistCatalogState};
@@ -203,7 +204,8 @@ pub trait ReadOnlyDurableCatalogState: Debug + Send {
     ///
     /// Results are guaranteed to be sorted by ID.
     ///
-    /// WARNING: This is meant for use in integration tests and has bad performance.
+    /// WARNING: This reads the entire catalog and has bad performance. It is meant for use in
+    /// integration tests and in infrequent background tasks, like pruning the audit log.
     async fn get_audit_logs(&mut self) -> Result<Vec<VersionedEvent>, CatalogError>;
 
     /// Get the next ID of `id_type`, without allocating it.
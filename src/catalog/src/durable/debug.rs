@@ -68,6 +68,9 @@ pub enum CollectionType {
     Schema,
     Setting,
     SourceReferences,
+    RowSecurity,
+    ColumnSecurity,
+    ResourceGroup,
     SystemConfiguration,
     SystemGidMapping,
     SystemPrivileges,
@@ -239,6 +242,30 @@ collection_impl!({
     trace_field: source_references,
     update: StateUpdateKind::SourceReferences,
 });
+collection_impl!({
+    name: RowSecurityCollection,
+    key: proto::RowSecurityKey,
+    value: proto::RowSecurityValue,
+    collection_type: CollectionType::RowSecurity,
+    trace_field: row_security,
+    update: StateUpdateKind::RowSecurity,
+});
+collection_impl!({
+    name: ColumnSecurityCollection,
+    key: proto::ColumnSecurityKey,
+    value: proto::ColumnSecurityValue,
+    collection_type: CollectionType::ColumnSecurity,
+    trace_field: column_security,
+    update: StateUpdateKind::ColumnSecurity,
+});
+collection_impl!({
+    name: ResourceGroupCollection,
+    key: proto::ResourceGroupKey,
+    value: proto::ResourceGroupValue,
+    collection_type: CollectionType::ResourceGroup,
+    trace_field: resource_groups,
+    update: StateUpdateKind::ResourceGroup,
+});
 collection_impl!({
     name: SystemConfigurationCollection,
     key: proto::ServerConfigurationKey,
@@ -322,6 +349,9 @@ pub struct Trace {
     pub schemas: CollectionTrace<SchemaCollection>,
     pub settings: CollectionTrace<SettingCollection>,
     pub source_references: CollectionTrace<SourceReferencesCollection>,
+    pub row_security: CollectionTrace<RowSecurityCollection>,
+    pub column_security: CollectionTrace<ColumnSecurityCollection>,
+    pub resource_groups: CollectionTrace<ResourceGroupCollection>,
     pub system_object_mappings: CollectionTrace<SystemItemMappingCollection>,
     pub system_configurations: CollectionTrace<SystemConfigurationCollection>,
     pub system_privileges: CollectionTrace<SystemPrivilegeCollection>,
@@ -348,6 +378,9 @@ impl Trace {
             schemas: CollectionTrace::new(),
             settings: CollectionTrace::new(),
             source_references: CollectionTrace::new(),
+            row_security: CollectionTrace::new(),
+            column_security: CollectionTrace::new(),
+            resource_groups: CollectionTrace::new(),
             system_object_mappings: CollectionTrace::new(),
             system_configurations: CollectionTrace::new(),
             system_privileges: CollectionTrace::new(),
@@ -703,6 +703,7 @@ fn default_cluster_config(args: &BootstrapArgs) -> ClusterConfig {
             schedule: Default::default(),
         }),
         workload_class: None,
+        arrangement_spill: None,
     }
 }
 
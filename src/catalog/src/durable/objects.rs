@@ -30,6 +30,7 @@ pub(crate) mod state_update;
 
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use mz_audit_log::VersionedEvent;
 use mz_controller::clusters::ReplicaLogging;
@@ -296,6 +297,7 @@ impl DurableType for Cluster {
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: Option<bool>,
 }
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
@@ -593,6 +595,138 @@ impl DurableType for SourceReferences {
     }
 }
 
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct RowSecurity {
+    pub relation_id: CatalogItemId,
+    pub enabled: bool,
+    pub policies: Vec<RowSecurityPolicy>,
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq, Arbitrary)]
+pub struct RowSecurityPolicy {
+    pub name: String,
+    pub roles: Vec<RoleId>,
+    pub using: String,
+}
+
+impl DurableType for RowSecurity {
+    type Key = RowSecurityKey;
+    type Value = RowSecurityValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            RowSecurityKey {
+                relation_id: self.relation_id,
+            },
+            RowSecurityValue {
+                enabled: self.enabled,
+                policies: self.policies,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            relation_id: key.relation_id,
+            enabled: value.enabled,
+            policies: value.policies,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        RowSecurityKey {
+            relation_id: self.relation_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct ColumnSecurity {
+    pub relation_id: CatalogItemId,
+    pub column_pos: usize,
+    pub privileges: Vec<MzAclItem>,
+    pub mask: Option<String>,
+}
+
+impl DurableType for ColumnSecurity {
+    type Key = ColumnSecurityKey;
+    type Value = ColumnSecurityValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            ColumnSecurityKey {
+                relation_id: self.relation_id,
+                column_pos: self.column_pos,
+            },
+            ColumnSecurityValue {
+                privileges: self.privileges,
+                mask: self.mask,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            relation_id: key.relation_id,
+            column_pos: key.column_pos,
+            privileges: value.privileges,
+            mask: value.mask,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        ColumnSecurityKey {
+            relation_id: self.relation_id,
+            column_pos: self.column_pos,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct ResourceGroup {
+    pub name: String,
+    pub roles: Vec<RoleId>,
+    pub max_concurrent_peeks: Option<u32>,
+    pub max_queued_peeks: Option<u32>,
+    pub statement_timeout: Option<Duration>,
+    pub max_result_size: Option<u64>,
+}
+
+impl DurableType for ResourceGroup {
+    type Key = ResourceGroupKey;
+    type Value = ResourceGroupValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            ResourceGroupKey { name: self.name },
+            ResourceGroupValue {
+                roles: self.roles,
+                max_concurrent_peeks: self.max_concurrent_peeks,
+                max_queued_peeks: self.max_queued_peeks,
+                statement_timeout: self.statement_timeout,
+                max_result_size: self.max_result_size,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            name: key.name,
+            roles: value.roles,
+            max_concurrent_peeks: value.max_concurrent_peeks,
+            max_queued_peeks: value.max_queued_peeks,
+            statement_timeout: value.statement_timeout,
+            max_result_size: value.max_result_size,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        ResourceGroupKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
 /// A newtype wrapper for [`CatalogItemId`] that is only for the "system" namespace.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, PartialEq, Eq)]
 pub struct SystemCatalogItemId(u64);
@@ -1072,6 +1206,9 @@ pub struct Snapshot {
         BTreeMap<proto::ServerConfigurationKey, proto::ServerConfigurationValue>,
     pub default_privileges: BTreeMap<proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue>,
     pub source_references: BTreeMap<proto::SourceReferencesKey, proto::SourceReferencesValue>,
+    pub row_security: BTreeMap<proto::RowSecurityKey, proto::RowSecurityValue>,
+    pub column_security: BTreeMap<proto::ColumnSecurityKey, proto::ColumnSecurityValue>,
+    pub resource_groups: BTreeMap<proto::ResourceGroupKey, proto::ResourceGroupValue>,
     pub system_privileges: BTreeMap<proto::SystemPrivilegesKey, proto::SystemPrivilegesValue>,
     pub storage_collection_metadata:
         BTreeMap<proto::StorageCollectionMetadataKey, proto::StorageCollectionMetadataValue>,
@@ -1206,6 +1343,43 @@ pub struct SourceReferencesValue {
     pub(crate) updated_at: u64,
 }
 
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct RowSecurityKey {
+    pub(crate) relation_id: CatalogItemId,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct RowSecurityValue {
+    pub(crate) enabled: bool,
+    pub(crate) policies: Vec<RowSecurityPolicy>,
+}
+
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ColumnSecurityKey {
+    pub(crate) relation_id: CatalogItemId,
+    pub(crate) column_pos: usize,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ColumnSecurityValue {
+    pub(crate) privileges: Vec<MzAclItem>,
+    pub(crate) mask: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ResourceGroupKey {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ResourceGroupValue {
+    pub(crate) roles: Vec<RoleId>,
+    pub(crate) max_concurrent_peeks: Option<u32>,
+    pub(crate) max_queued_peeks: Option<u32>,
+    pub(crate) statement_timeout: Option<Duration>,
+    pub(crate) max_result_size: Option<u64>,
+}
+
 #[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct SchemaKey {
     pub(crate) id: SchemaId,
@@ -12,15 +12,17 @@
 use std::time::Duration;
 
 use mz_audit_log::{
-    AlterDefaultPrivilegeV1, AlterRetainHistoryV1, AlterSetClusterV1, AlterSourceSinkV1,
-    CreateClusterReplicaV1, CreateClusterReplicaV2, CreateIndexV1, CreateMaterializedViewV1,
-    CreateOrDropClusterReplicaReasonV1, CreateSourceSinkV1, CreateSourceSinkV2, CreateSourceSinkV3,
-    CreateSourceSinkV4, DropClusterReplicaV1, DropClusterReplicaV2, EventDetails, EventType,
-    EventV1, FromPreviousIdV1, FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1,
+    AlterColumnMaskV1, AlterDefaultPrivilegeV1, AlterRetainHistoryV1, AlterRowSecurityV1,
+    AlterSetClusterV1, AlterSourceSinkV1, CreateClusterReplicaV1, CreateClusterReplicaV2,
+    CreateIndexV1, CreateMaterializedViewV1, CreateOrDropClusterReplicaReasonV1,
+    CreateSourceSinkV1, CreateSourceSinkV2, CreateSourceSinkV3, CreateSourceSinkV4,
+    DropClusterReplicaV1, DropClusterReplicaV2, EventDetails, EventType, EventV1, FromPreviousIdV1,
+    FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1, LoginFailedV1, LoginV1, PolicyV1,
     RefreshDecisionWithReasonV1, RenameClusterReplicaV1, RenameClusterV1, RenameItemV1,
-    RenameSchemaV1, RevokeRoleV1, RevokeRoleV2, RotateKeysV1, SchedulingDecisionV1,
-    SchedulingDecisionsWithReasonsV1, SchemaV1, SchemaV2, SetV1, ToNewIdV1, UpdateItemV1,
-    UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
+    RenameSchemaV1, ResourceGroupV1, RevokeRoleV1, RevokeRoleV2, RotateKeysV1,
+    SchedulingDecisionV1, SchedulingDecisionsWithReasonsV1, SchemaV1, SchemaV2, SelectV1, SetV1,
+    ToNewIdV1, UpdateColumnPrivilegeV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1,
+    VersionedEvent,
 };
 use mz_compute_client::controller::ComputeReplicaLogging;
 use mz_controller_types::ReplicaId;
@@ -47,14 +49,16 @@ use crate::durable::objects::serialization::proto::{
 use crate::durable::objects::state_update::StateUpdateKindJson;
 use crate::durable::objects::{
     AuditLogKey, ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue,
-    ClusterKey, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, CommentKey, CommentValue,
-    ConfigKey, ConfigValue, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
-    DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue, ItemKey,
-    ItemValue, NetworkPolicyKey, NetworkPolicyValue, RoleKey, RoleValue, SchemaKey, SchemaValue,
-    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
-    SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
-    StorageCollectionMetadataValue, SystemCatalogItemId, SystemGlobalId, SystemPrivilegesKey,
-    SystemPrivilegesValue, TxnWalShardValue, UnfinalizedShardKey,
+    ClusterKey, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, ColumnSecurityKey,
+    ColumnSecurityValue, CommentKey, CommentValue, ConfigKey, ConfigValue, DatabaseKey,
+    DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue, GidMappingKey, GidMappingValue,
+    IdAllocKey, IdAllocValue, ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue,
+    ResourceGroupKey, ResourceGroupValue, RoleKey, RoleValue, RowSecurityKey, RowSecurityPolicy,
+    RowSecurityValue, SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue,
+    SettingKey, SettingValue, SourceReference, SourceReferencesKey, SourceReferencesValue,
+    StorageCollectionMetadataKey, StorageCollectionMetadataValue, SystemCatalogItemId,
+    SystemGlobalId, SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue,
+    UnfinalizedShardKey,
 };
 use crate::durable::{
     ClusterConfig, ClusterVariant, ClusterVariantManaged, ReplicaConfig, ReplicaLocation,
@@ -127,6 +131,7 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
         proto::ClusterConfig {
             variant: Some(self.variant.into_proto()),
             workload_class: self.workload_class.clone(),
+            arrangement_spill: self.arrangement_spill,
         }
     }
 
@@ -134,6 +139,7 @@ impl RustType<proto::ClusterConfig> for ClusterConfig {
         Ok(Self {
             variant: proto.variant.into_rust_if_some("ClusterConfig::variant")?,
             workload_class: proto.workload_class,
+            arrangement_spill: proto.arrangement_spill,
         })
     }
 }
@@ -959,6 +965,129 @@ impl RustType<proto::SourceReference> for SourceReference {
     }
 }
 
+impl RustType<proto::RowSecurityKey> for RowSecurityKey {
+    fn into_proto(&self) -> proto::RowSecurityKey {
+        proto::RowSecurityKey {
+            relation: Some(self.relation_id.into_proto()),
+        }
+    }
+    fn from_proto(proto: proto::RowSecurityKey) -> Result<Self, TryFromProtoError> {
+        Ok(RowSecurityKey {
+            relation_id: proto
+                .relation
+                .into_rust_if_some("RowSecurityKey::relation_id")?,
+        })
+    }
+}
+
+impl RustType<proto::RowSecurityValue> for RowSecurityValue {
+    fn into_proto(&self) -> proto::RowSecurityValue {
+        proto::RowSecurityValue {
+            enabled: self.enabled,
+            policies: self
+                .policies
+                .iter()
+                .map(|policy| policy.into_proto())
+                .collect(),
+        }
+    }
+    fn from_proto(proto: proto::RowSecurityValue) -> Result<Self, TryFromProtoError> {
+        Ok(RowSecurityValue {
+            enabled: proto.enabled,
+            policies: proto
+                .policies
+                .into_iter()
+                .map(|policy| policy.into_rust())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl RustType<proto::RowSecurityPolicy> for RowSecurityPolicy {
+    fn into_proto(&self) -> proto::RowSecurityPolicy {
+        proto::RowSecurityPolicy {
+            name: self.name.clone(),
+            roles: self.roles.iter().map(|role| role.into_proto()).collect(),
+            using: self.using.clone(),
+        }
+    }
+    fn from_proto(proto: proto::RowSecurityPolicy) -> Result<Self, TryFromProtoError> {
+        Ok(RowSecurityPolicy {
+            name: proto.name,
+            roles: proto
+                .roles
+                .into_iter()
+                .map(|role| role.into_rust())
+                .collect::<Result<_, _>>()?,
+            using: proto.using,
+        })
+    }
+}
+
+impl RustType<proto::ColumnSecurityKey> for ColumnSecurityKey {
+    fn into_proto(&self) -> proto::ColumnSecurityKey {
+        proto::ColumnSecurityKey {
+            relation: Some(self.relation_id.into_proto()),
+            column_pos: CastFrom::cast_from(self.column_pos),
+        }
+    }
+    fn from_proto(proto: proto::ColumnSecurityKey) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnSecurityKey {
+            relation_id: proto
+                .relation
+                .into_rust_if_some("ColumnSecurityKey::relation_id")?,
+            column_pos: CastFrom::cast_from(proto.column_pos),
+        })
+    }
+}
+
+impl RustType<proto::ColumnSecurityValue> for ColumnSecurityValue {
+    fn into_proto(&self) -> proto::ColumnSecurityValue {
+        proto::ColumnSecurityValue {
+            privileges: self.privileges.into_proto(),
+            mask: self.mask.clone(),
+        }
+    }
+    fn from_proto(proto: proto::ColumnSecurityValue) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnSecurityValue {
+            privileges: proto.privileges.into_rust()?,
+            mask: proto.mask,
+        })
+    }
+}
+
+impl RustType<proto::ResourceGroupKey> for ResourceGroupKey {
+    fn into_proto(&self) -> proto::ResourceGroupKey {
+        proto::ResourceGroupKey {
+            name: self.name.clone(),
+        }
+    }
+    fn from_proto(proto: proto::ResourceGroupKey) -> Result<Self, TryFromProtoError> {
+        Ok(ResourceGroupKey { name: proto.name })
+    }
+}
+
+impl RustType<proto::ResourceGroupValue> for ResourceGroupValue {
+    fn into_proto(&self) -> proto::ResourceGroupValue {
+        proto::ResourceGroupValue {
+            roles: self.roles.into_proto(),
+            max_concurrent_peeks: self.max_concurrent_peeks,
+            max_queued_peeks: self.max_queued_peeks,
+            statement_timeout: self.statement_timeout.into_proto(),
+            max_result_size: self.max_result_size,
+        }
+    }
+    fn from_proto(proto: proto::ResourceGroupValue) -> Result<Self, TryFromProtoError> {
+        Ok(ResourceGroupValue {
+            roles: proto.roles.into_rust()?,
+            max_concurrent_peeks: proto.max_concurrent_peeks,
+            max_queued_peeks: proto.max_queued_peeks,
+            statement_timeout: proto.statement_timeout.into_rust()?,
+            max_result_size: proto.max_result_size,
+        })
+    }
+}
+
 impl RustType<proto::DefaultPrivilegesKey> for DefaultPrivilegesKey {
     fn into_proto(&self) -> proto::DefaultPrivilegesKey {
         proto::DefaultPrivilegesKey {
@@ -1160,6 +1289,7 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
     fn into_proto(&self) -> proto::RoleAttributes {
         proto::RoleAttributes {
             inherit: self.inherit,
+            password: self.password.clone(),
         }
     }
 
@@ -1167,6 +1297,7 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
         let mut attributes = RoleAttributes::new();
 
         attributes.inherit = proto.inherit;
+        attributes.password = proto.password;
 
         Ok(attributes)
     }
@@ -1701,6 +1832,9 @@ impl RustType<proto::audit_log_event_v1::EventType> for EventType {
             EventType::Grant => proto::audit_log_event_v1::EventType::Grant,
             EventType::Revoke => proto::audit_log_event_v1::EventType::Revoke,
             EventType::Comment => proto::audit_log_event_v1::EventType::Comment,
+            EventType::Login => proto::audit_log_event_v1::EventType::Login,
+            EventType::LoginFailed => proto::audit_log_event_v1::EventType::LoginFailed,
+            EventType::Select => proto::audit_log_event_v1::EventType::Select,
         }
     }
 
@@ -1712,6 +1846,9 @@ impl RustType<proto::audit_log_event_v1::EventType> for EventType {
             proto::audit_log_event_v1::EventType::Grant => Ok(EventType::Grant),
             proto::audit_log_event_v1::EventType::Revoke => Ok(EventType::Revoke),
             proto::audit_log_event_v1::EventType::Comment => Ok(EventType::Comment),
+            proto::audit_log_event_v1::EventType::Login => Ok(EventType::Login),
+            proto::audit_log_event_v1::EventType::LoginFailed => Ok(EventType::LoginFailed),
+            proto::audit_log_event_v1::EventType::Select => Ok(EventType::Select),
             proto::audit_log_event_v1::EventType::Unknown => Err(
                 TryFromProtoError::unknown_enum_variant("EventType::Unknown"),
             ),
@@ -1754,6 +1891,10 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             mz_audit_log::ObjectType::NetworkPolicy => {
                 proto::audit_log_event_v1::ObjectType::NetworkPolicy
             }
+            mz_audit_log::ObjectType::Policy => proto::audit_log_event_v1::ObjectType::Policy,
+            mz_audit_log::ObjectType::ResourceGroup => {
+                proto::audit_log_event_v1::ObjectType::ResourceGroup
+            }
             mz_audit_log::ObjectType::Role => proto::audit_log_event_v1::ObjectType::Role,
             mz_audit_log::ObjectType::Secret => proto::audit_log_event_v1::ObjectType::Secret,
             mz_audit_log::ObjectType::Schema => proto::audit_log_event_v1::ObjectType::Schema,
@@ -1789,6 +1930,10 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             proto::audit_log_event_v1::ObjectType::NetworkPolicy => {
                 Ok(mz_audit_log::ObjectType::NetworkPolicy)
             }
+            proto::audit_log_event_v1::ObjectType::Policy => Ok(mz_audit_log::ObjectType::Policy),
+            proto::audit_log_event_v1::ObjectType::ResourceGroup => {
+                Ok(mz_audit_log::ObjectType::ResourceGroup)
+            }
             proto::audit_log_event_v1::ObjectType::Role => Ok(mz_audit_log::ObjectType::Role),
             proto::audit_log_event_v1::ObjectType::Secret => Ok(mz_audit_log::ObjectType::Secret),
             proto::audit_log_event_v1::ObjectType::Schema => Ok(mz_audit_log::ObjectType::Schema),
@@ -2569,6 +2714,180 @@ impl RustType<proto::audit_log_event_v1::AlterRetainHistoryV1> for AlterRetainHi
     }
 }
 
+impl RustType<proto::audit_log_event_v1::AlterRowSecurityV1> for AlterRowSecurityV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::AlterRowSecurityV1 {
+        proto::audit_log_event_v1::AlterRowSecurityV1 {
+            id: self.id.to_string(),
+            name: Some(self.name.into_proto()),
+            enabled: self.enabled,
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::AlterRowSecurityV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(AlterRowSecurityV1 {
+            id: proto.id,
+            name: proto.name.into_rust_if_some("AlterRowSecurityV1::name")?,
+            enabled: proto.enabled,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::PolicyV1> for PolicyV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::PolicyV1 {
+        proto::audit_log_event_v1::PolicyV1 {
+            name: self.name.clone(),
+            relation_id: self.relation_id.to_string(),
+            relation_name: Some(self.relation_name.into_proto()),
+            roles: self.roles.clone(),
+            using: self.using.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::audit_log_event_v1::PolicyV1) -> Result<Self, TryFromProtoError> {
+        Ok(PolicyV1 {
+            name: proto.name,
+            relation_id: proto.relation_id,
+            relation_name: proto
+                .relation_name
+                .into_rust_if_some("PolicyV1::relation_name")?,
+            roles: proto.roles,
+            using: proto.using,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::UpdateColumnPrivilegeV1> for UpdateColumnPrivilegeV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+        proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+            object_id: self.object_id.to_string(),
+            column_name: self.column_name.to_string(),
+            grantee_id: self.grantee_id.to_string(),
+            grantor_id: self.grantor_id.to_string(),
+            privileges: self.privileges.to_string(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::UpdateColumnPrivilegeV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(UpdateColumnPrivilegeV1 {
+            object_id: proto.object_id,
+            column_name: proto.column_name,
+            grantee_id: proto.grantee_id,
+            grantor_id: proto.grantor_id,
+            privileges: proto.privileges,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::AlterColumnMaskV1> for AlterColumnMaskV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::AlterColumnMaskV1 {
+        proto::audit_log_event_v1::AlterColumnMaskV1 {
+            id: self.id.to_string(),
+            name: Some(self.name.into_proto()),
+            column_name: self.column_name.to_string(),
+            mask: self.mask.clone(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::AlterColumnMaskV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(AlterColumnMaskV1 {
+            id: proto.id,
+            name: proto.name.into_rust_if_some("AlterColumnMaskV1::name")?,
+            column_name: proto.column_name,
+            mask: proto.mask,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::ResourceGroupV1> for ResourceGroupV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::ResourceGroupV1 {
+        proto::audit_log_event_v1::ResourceGroupV1 {
+            name: self.name.clone(),
+            roles: self.roles.clone(),
+            max_concurrent_peeks: self.max_concurrent_peeks,
+            max_queued_peeks: self.max_queued_peeks,
+            statement_timeout: self.statement_timeout.clone(),
+            max_result_size: self.max_result_size,
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::ResourceGroupV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(ResourceGroupV1 {
+            name: proto.name,
+            roles: proto.roles,
+            max_concurrent_peeks: proto.max_concurrent_peeks,
+            max_queued_peeks: proto.max_queued_peeks,
+            statement_timeout: proto.statement_timeout,
+            max_result_size: proto.max_result_size,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::LoginV1> for LoginV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::LoginV1 {
+        proto::audit_log_event_v1::LoginV1 {
+            role_name: self.role_name.clone(),
+            session_id: self.session_id.clone(),
+            application_name: self.application_name.clone(),
+            client_ip: self.client_ip.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::audit_log_event_v1::LoginV1) -> Result<Self, TryFromProtoError> {
+        Ok(LoginV1 {
+            role_name: proto.role_name,
+            session_id: proto.session_id,
+            application_name: proto.application_name,
+            client_ip: proto.client_ip,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::LoginFailedV1> for LoginFailedV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::LoginFailedV1 {
+        proto::audit_log_event_v1::LoginFailedV1 {
+            role_name: self.role_name.clone(),
+            client_ip: self.client_ip.clone(),
+            reason: self.reason.clone(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::LoginFailedV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(LoginFailedV1 {
+            role_name: proto.role_name,
+            client_ip: proto.client_ip,
+            reason: proto.reason,
+        })
+    }
+}
+
+impl RustType<proto::audit_log_event_v1::SelectV1> for SelectV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::SelectV1 {
+        proto::audit_log_event_v1::SelectV1 {
+            id: self.id.to_string(),
+            name: Some(self.name.into_proto()),
+            session_id: self.session_id.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::audit_log_event_v1::SelectV1) -> Result<Self, TryFromProtoError> {
+        Ok(SelectV1 {
+            id: proto.id,
+            name: proto.name.into_rust_if_some("SelectV1::name")?,
+            session_id: proto.session_id,
+        })
+    }
+}
+
 impl RustType<proto::audit_log_event_v1::ToNewIdV1> for ToNewIdV1 {
     fn into_proto(&self) -> proto::audit_log_event_v1::ToNewIdV1 {
         proto::audit_log_event_v1::ToNewIdV1 {
@@ -2687,6 +3006,16 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             EventDetails::AlterRetainHistoryV1(details) => {
                 AlterRetainHistoryV1(details.into_proto())
             }
+            EventDetails::AlterRowSecurityV1(details) => AlterRowSecurityV1(details.into_proto()),
+            EventDetails::PolicyV1(details) => PolicyV1(details.into_proto()),
+            EventDetails::UpdateColumnPrivilegeV1(details) => {
+                UpdateColumnPrivilegeV1(details.into_proto())
+            }
+            EventDetails::AlterColumnMaskV1(details) => AlterColumnMaskV1(details.into_proto()),
+            EventDetails::ResourceGroupV1(details) => ResourceGroupV1(details.into_proto()),
+            EventDetails::LoginV1(details) => LoginV1(details.into_proto()),
+            EventDetails::LoginFailedV1(details) => LoginFailedV1(details.into_proto()),
+            EventDetails::SelectV1(details) => SelectV1(details.into_proto()),
             EventDetails::ToNewIdV1(details) => ToNewIdV1(details.into_proto()),
             EventDetails::FromPreviousIdV1(details) => FromPreviousIdV1(details.into_proto()),
             EventDetails::SetV1(details) => SetV1(details.into_proto()),
@@ -2752,6 +3081,18 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             AlterRetainHistoryV1(details) => {
                 Ok(EventDetails::AlterRetainHistoryV1(details.into_rust()?))
             }
+            AlterRowSecurityV1(details) => {
+                Ok(EventDetails::AlterRowSecurityV1(details.into_rust()?))
+            }
+            PolicyV1(details) => Ok(EventDetails::PolicyV1(details.into_rust()?)),
+            UpdateColumnPrivilegeV1(details) => {
+                Ok(EventDetails::UpdateColumnPrivilegeV1(details.into_rust()?))
+            }
+            AlterColumnMaskV1(details) => Ok(EventDetails::AlterColumnMaskV1(details.into_rust()?)),
+            ResourceGroupV1(details) => Ok(EventDetails::ResourceGroupV1(details.into_rust()?)),
+            LoginV1(details) => Ok(EventDetails::LoginV1(details.into_rust()?)),
+            LoginFailedV1(details) => Ok(EventDetails::LoginFailedV1(details.into_rust()?)),
+            SelectV1(details) => Ok(EventDetails::SelectV1(details.into_rust()?)),
             ToNewIdV1(details) => Ok(EventDetails::ToNewIdV1(details.into_rust()?)),
             FromPreviousIdV1(details) => Ok(EventDetails::FromPreviousIdV1(details.into_rust()?)),
             SetV1(details) => Ok(EventDetails::SetV1(details.into_rust()?)),
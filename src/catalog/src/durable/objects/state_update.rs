@@ -143,6 +143,9 @@ impl StateUpdate {
             configs,
             settings,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_gid_mapping,
             system_configurations,
             default_privileges,
@@ -174,6 +177,9 @@ impl StateUpdate {
             from_batch(system_configurations, StateUpdateKind::SystemConfiguration);
         let default_privileges = from_batch(default_privileges, StateUpdateKind::DefaultPrivilege);
         let source_references = from_batch(source_references, StateUpdateKind::SourceReferences);
+        let row_security = from_batch(row_security, StateUpdateKind::RowSecurity);
+        let column_security = from_batch(column_security, StateUpdateKind::ColumnSecurity);
+        let resource_groups = from_batch(resource_groups, StateUpdateKind::ResourceGroup);
         let system_privileges = from_batch(system_privileges, StateUpdateKind::SystemPrivilege);
         let storage_collection_metadata = from_batch(
             storage_collection_metadata,
@@ -196,6 +202,9 @@ impl StateUpdate {
             .chain(configs)
             .chain(settings)
             .chain(source_references)
+            .chain(row_security)
+            .chain(column_security)
+            .chain(resource_groups)
             .chain(system_object_mappings)
             .chain(system_configurations)
             .chain(default_privileges)
@@ -216,6 +225,7 @@ pub enum StateUpdateKind {
     AuditLog(proto::AuditLogKey, ()),
     Cluster(proto::ClusterKey, proto::ClusterValue),
     ClusterReplica(proto::ClusterReplicaKey, proto::ClusterReplicaValue),
+    ColumnSecurity(proto::ColumnSecurityKey, proto::ColumnSecurityValue),
     Comment(proto::CommentKey, proto::CommentValue),
     Config(proto::ConfigKey, proto::ConfigValue),
     Database(proto::DatabaseKey, proto::DatabaseValue),
@@ -228,7 +238,9 @@ pub enum StateUpdateKind {
     ),
     Item(proto::ItemKey, proto::ItemValue),
     NetworkPolicy(proto::NetworkPolicyKey, proto::NetworkPolicyValue),
+    ResourceGroup(proto::ResourceGroupKey, proto::ResourceGroupValue),
     Role(proto::RoleKey, proto::RoleValue),
+    RowSecurity(proto::RowSecurityKey, proto::RowSecurityValue),
     Schema(proto::SchemaKey, proto::SchemaValue),
     Setting(proto::SettingKey, proto::SettingValue),
     SourceReferences(proto::SourceReferencesKey, proto::SourceReferencesValue),
@@ -264,6 +276,9 @@ impl StateUpdateKind {
             StateUpdateKind::Item(_, _) => Some(CollectionType::Item),
             StateUpdateKind::NetworkPolicy(_, _) => Some(CollectionType::NetworkPolicy),
             StateUpdateKind::Role(_, _) => Some(CollectionType::Role),
+            StateUpdateKind::RowSecurity(_, _) => Some(CollectionType::RowSecurity),
+            StateUpdateKind::ColumnSecurity(_, _) => Some(CollectionType::ColumnSecurity),
+            StateUpdateKind::ResourceGroup(_, _) => Some(CollectionType::ResourceGroup),
             StateUpdateKind::Schema(_, _) => Some(CollectionType::Schema),
             StateUpdateKind::Setting(_, _) => Some(CollectionType::Setting),
             StateUpdateKind::SourceReferences(_, _) => Some(CollectionType::SourceReferences),
@@ -429,6 +444,22 @@ impl TryFrom<&StateUpdateKind> for Option<memory::objects::StateUpdateKind> {
                 let role = into_durable(key, value)?;
                 Some(memory::objects::StateUpdateKind::Role(role))
             }
+            StateUpdateKind::RowSecurity(key, value) => {
+                let row_security = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::RowSecurity(row_security))
+            }
+            StateUpdateKind::ColumnSecurity(key, value) => {
+                let column_security = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::ColumnSecurity(
+                    column_security,
+                ))
+            }
+            StateUpdateKind::ResourceGroup(key, value) => {
+                let resource_group = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::ResourceGroup(
+                    resource_group,
+                ))
+            }
             StateUpdateKind::Schema(key, value) => {
                 let schema = into_durable(key, value)?;
                 Some(memory::objects::StateUpdateKind::Schema(schema))
@@ -620,6 +651,30 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         value: Some(value),
                     })
                 }
+                StateUpdateKind::RowSecurity(key, value) => {
+                    proto::state_update_kind::Kind::RowSecurity(
+                        proto::state_update_kind::RowSecurity {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
+                StateUpdateKind::ColumnSecurity(key, value) => {
+                    proto::state_update_kind::Kind::ColumnSecurity(
+                        proto::state_update_kind::ColumnSecurity {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
+                StateUpdateKind::ResourceGroup(key, value) => {
+                    proto::state_update_kind::Kind::ResourceGroup(
+                        proto::state_update_kind::ResourceGroup {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
                 StateUpdateKind::Schema(key, value) => {
                     proto::state_update_kind::Kind::Schema(proto::state_update_kind::Schema {
                         key: Some(key),
@@ -928,6 +983,36 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         )
                     })?,
                 ),
+                proto::state_update_kind::Kind::RowSecurity(
+                    proto::state_update_kind::RowSecurity { key, value },
+                ) => StateUpdateKind::RowSecurity(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::RowSecurity::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::RowSecurity::value")
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ColumnSecurity(
+                    proto::state_update_kind::ColumnSecurity { key, value },
+                ) => StateUpdateKind::ColumnSecurity(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ColumnSecurity::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ColumnSecurity::value")
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ResourceGroup(
+                    proto::state_update_kind::ResourceGroup { key, value },
+                ) => StateUpdateKind::ResourceGroup(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ResourceGroup::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ResourceGroup::value")
+                    })?,
+                ),
                 proto::state_update_kind::Kind::NetworkPolicy(
                     proto::state_update_kind::NetworkPolicy { key, value },
                 ) => StateUpdateKind::NetworkPolicy(
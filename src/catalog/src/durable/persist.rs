@@ -815,6 +815,15 @@ impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
                     StateUpdateKind::Role(key, value) => {
                         apply(&mut snapshot.roles, key, value, diff);
                     }
+                    StateUpdateKind::RowSecurity(key, value) => {
+                        apply(&mut snapshot.row_security, key, value, diff);
+                    }
+                    StateUpdateKind::ColumnSecurity(key, value) => {
+                        apply(&mut snapshot.column_security, key, value, diff);
+                    }
+                    StateUpdateKind::ResourceGroup(key, value) => {
+                        apply(&mut snapshot.resource_groups, key, value, diff);
+                    }
                     StateUpdateKind::Schema(key, value) => {
                         apply(&mut snapshot.schemas, key, value, diff);
                     }
@@ -1880,6 +1889,15 @@ impl Trace {
                     trace.network_policies.values.push(((k, v), ts, diff))
                 }
                 StateUpdateKind::Role(k, v) => trace.roles.values.push(((k, v), ts, diff)),
+                StateUpdateKind::RowSecurity(k, v) => {
+                    trace.row_security.values.push(((k, v), ts, diff))
+                }
+                StateUpdateKind::ColumnSecurity(k, v) => {
+                    trace.column_security.values.push(((k, v), ts, diff))
+                }
+                StateUpdateKind::ResourceGroup(k, v) => {
+                    trace.resource_groups.values.push(((k, v), ts, diff))
+                }
                 StateUpdateKind::Schema(k, v) => trace.schemas.values.push(((k, v), ts, diff)),
                 StateUpdateKind::Setting(k, v) => trace.settings.values.push(((k, v), ts, diff)),
                 StateUpdateKind::SourceReferences(k, v) => {
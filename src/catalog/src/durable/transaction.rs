@@ -46,15 +46,17 @@ use crate::durable::objects::serialization::proto;
 use crate::durable::objects::{
     AuditLogKey, Cluster, ClusterConfig, ClusterIntrospectionSourceIndexKey,
     ClusterIntrospectionSourceIndexValue, ClusterKey, ClusterReplica, ClusterReplicaKey,
-    ClusterReplicaValue, ClusterValue, CommentKey, CommentValue, Config, ConfigKey, ConfigValue,
-    Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
-    DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
-    IntrospectionSourceIndex, Item, ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue,
-    ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue,
-    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
-    SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
-    StorageCollectionMetadataValue, SystemObjectDescription, SystemObjectMapping,
-    SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue, UnfinalizedShardKey,
+    ClusterReplicaValue, ClusterValue, ColumnSecurity, ColumnSecurityKey, ColumnSecurityValue,
+    CommentKey, CommentValue, Config, ConfigKey, ConfigValue, Database, DatabaseKey, DatabaseValue,
+    DefaultPrivilegesKey, DefaultPrivilegesValue, DurableType, GidMappingKey, GidMappingValue,
+    IdAllocKey, IdAllocValue, IntrospectionSourceIndex, Item, ItemKey, ItemValue, NetworkPolicyKey,
+    NetworkPolicyValue, ReplicaConfig, ResourceGroup, ResourceGroupKey, ResourceGroupValue, Role,
+    RoleKey, RoleValue, RowSecurity, RowSecurityKey, RowSecurityPolicy, RowSecurityValue, Schema,
+    SchemaKey, SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey,
+    SettingValue, SourceReference, SourceReferencesKey, SourceReferencesValue,
+    StorageCollectionMetadataKey, StorageCollectionMetadataValue, SystemObjectDescription,
+    SystemObjectMapping, SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue,
+    UnfinalizedShardKey,
 };
 use crate::durable::{
     CatalogError, DefaultPrivilege, DurableCatalogError, DurableCatalogState, NetworkPolicy,
@@ -91,6 +93,9 @@ pub struct Transaction<'a> {
     system_configurations: TableTransaction<ServerConfigurationKey, ServerConfigurationValue>,
     default_privileges: TableTransaction<DefaultPrivilegesKey, DefaultPrivilegesValue>,
     source_references: TableTransaction<SourceReferencesKey, SourceReferencesValue>,
+    row_security: TableTransaction<RowSecurityKey, RowSecurityValue>,
+    column_security: TableTransaction<ColumnSecurityKey, ColumnSecurityValue>,
+    resource_groups: TableTransaction<ResourceGroupKey, ResourceGroupValue>,
     system_privileges: TableTransaction<SystemPrivilegesKey, SystemPrivilegesValue>,
     network_policies: TableTransaction<NetworkPolicyKey, NetworkPolicyValue>,
     storage_collection_metadata:
@@ -123,6 +128,9 @@ impl<'a> Transaction<'a> {
             configs,
             settings,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_object_mappings,
             system_configurations,
             default_privileges,
@@ -171,6 +179,9 @@ impl<'a> Transaction<'a> {
             configs: TableTransaction::new(configs)?,
             settings: TableTransaction::new(settings)?,
             source_references: TableTransaction::new(source_references)?,
+            row_security: TableTransaction::new(row_security)?,
+            column_security: TableTransaction::new(column_security)?,
+            resource_groups: TableTransaction::new(resource_groups)?,
             system_gid_mapping: TableTransaction::new(system_object_mappings)?,
             system_configurations: TableTransaction::new(system_configurations)?,
             default_privileges: TableTransaction::new(default_privileges)?,
@@ -219,6 +230,17 @@ impl<'a> Transaction<'a> {
         self.audit_log_updates.extend(events);
     }
 
+    /// Removes `events` from the audit log.
+    ///
+    /// The events must have previously been inserted, e.g. as returned by
+    /// [`DurableCatalogState::get_audit_logs`].
+    pub fn remove_audit_log_events(&mut self, events: impl IntoIterator<Item = VersionedEvent>) {
+        let events = events
+            .into_iter()
+            .map(|event| (AuditLogKey { event }, -1, self.op_id));
+        self.audit_log_updates.extend(events);
+    }
+
     pub fn insert_user_database(
         &mut self,
         database_name: &str,
@@ -1876,6 +1898,89 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Sets the row-level security configuration of `relation_id` to `enabled` and `policies`,
+    /// removing it entirely if it carries no information.
+    pub fn update_row_security(
+        &mut self,
+        relation_id: GlobalId,
+        enabled: bool,
+        policies: Vec<RowSecurityPolicy>,
+    ) -> Result<(), CatalogError> {
+        let key = RowSecurityKey {
+            relation_id: relation_id.to_item_id(),
+        };
+        let value = if enabled || !policies.is_empty() {
+            Some(RowSecurityValue { enabled, policies })
+        } else {
+            None
+        };
+        self.row_security.set(key, value, self.op_id)?;
+        Ok(())
+    }
+
+    pub fn drop_row_security(&mut self, relation_ids: &BTreeSet<GlobalId>) {
+        if relation_ids.is_empty() {
+            return;
+        }
+
+        self.row_security.delete(
+            |k, _v| relation_ids.contains(&k.relation_id.to_global_id()),
+            self.op_id,
+        );
+    }
+
+    /// Sets the privileges and mask of the column at `column_pos` of `relation_id`, removing them
+    /// entirely if they carry no information.
+    pub fn update_column_security(
+        &mut self,
+        relation_id: GlobalId,
+        column_pos: usize,
+        privileges: Vec<MzAclItem>,
+        mask: Option<String>,
+    ) -> Result<(), CatalogError> {
+        let key = ColumnSecurityKey {
+            relation_id: relation_id.to_item_id(),
+            column_pos,
+        };
+        let value = if !privileges.is_empty() || mask.is_some() {
+            Some(ColumnSecurityValue { privileges, mask })
+        } else {
+            None
+        };
+        self.column_security.set(key, value, self.op_id)?;
+        Ok(())
+    }
+
+    pub fn drop_column_security(&mut self, relation_ids: &BTreeSet<GlobalId>) {
+        if relation_ids.is_empty() {
+            return;
+        }
+
+        self.column_security.delete(
+            |k, _v| relation_ids.contains(&k.relation_id.to_global_id()),
+            self.op_id,
+        );
+    }
+
+    /// Creates or replaces the resource group `resource_group.name`.
+    pub fn upsert_resource_group(
+        &mut self,
+        resource_group: ResourceGroup,
+    ) -> Result<(), CatalogError> {
+        let (key, value) = resource_group.into_key_value();
+        self.resource_groups.set(key, Some(value), self.op_id)?;
+        Ok(())
+    }
+
+    /// Removes the resource group `name`, if it exists.
+    pub fn remove_resource_group(&mut self, name: &str) -> Result<(), CatalogError> {
+        let key = ResourceGroupKey {
+            name: name.to_string(),
+        };
+        self.resource_groups.set(key, None, self.op_id)?;
+        Ok(())
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), CatalogError> {
         let key = ServerConfigurationKey {
@@ -1945,6 +2050,36 @@ impl<'a> Transaction<'a> {
             .map(|(k, v)| DurableType::from_key_value(k, v))
     }
 
+    pub fn get_row_security(&self, relation_id: GlobalId) -> Option<RowSecurity> {
+        let key = RowSecurityKey {
+            relation_id: relation_id.to_item_id(),
+        };
+        self.row_security
+            .get(&key)
+            .map(|v| DurableType::from_key_value(key, v))
+    }
+
+    pub fn get_column_security(
+        &self,
+        relation_id: GlobalId,
+        column_pos: usize,
+    ) -> Option<ColumnSecurity> {
+        let key = ColumnSecurityKey {
+            relation_id: relation_id.to_item_id(),
+            column_pos,
+        };
+        self.column_security
+            .get(&key)
+            .map(|v| DurableType::from_key_value(key, v))
+    }
+
+    pub fn get_resource_groups(&self) -> impl Iterator<Item = ResourceGroup> {
+        self.resource_groups
+            .items()
+            .into_iter()
+            .map(|(k, v)| DurableType::from_key_value(k, v))
+    }
+
     pub fn get_system_object_mappings(&self) -> impl Iterator<Item = SystemObjectMapping> {
         self.system_gid_mapping
             .items()
@@ -2059,6 +2194,9 @@ impl<'a> Transaction<'a> {
             system_configurations,
             default_privileges,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_privileges,
             audit_log_updates,
             storage_collection_metadata,
@@ -2143,6 +2281,21 @@ impl<'a> Transaction<'a> {
                 StateUpdateKind::SourceReferences,
                 self.op_id,
             ))
+            .chain(get_collection_op_updates(
+                row_security,
+                StateUpdateKind::RowSecurity,
+                self.op_id,
+            ))
+            .chain(get_collection_op_updates(
+                column_security,
+                StateUpdateKind::ColumnSecurity,
+                self.op_id,
+            ))
+            .chain(get_collection_op_updates(
+                resource_groups,
+                StateUpdateKind::ResourceGroup,
+                self.op_id,
+            ))
             .chain(get_collection_op_updates(
                 storage_collection_metadata,
                 StateUpdateKind::StorageCollectionMetadata,
@@ -2204,6 +2357,9 @@ impl<'a> Transaction<'a> {
             id_allocator: self.id_allocator.pending(),
             configs: self.configs.pending(),
             source_references: self.source_references.pending(),
+            row_security: self.row_security.pending(),
+            column_security: self.column_security.pending(),
+            resource_groups: self.resource_groups.pending(),
             settings: self.settings.pending(),
             system_gid_mapping: self.system_gid_mapping.pending(),
             system_configurations: self.system_configurations.pending(),
@@ -2242,6 +2398,9 @@ impl<'a> Transaction<'a> {
             id_allocator,
             configs,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             settings,
             system_gid_mapping,
             system_configurations,
@@ -2268,6 +2427,9 @@ impl<'a> Transaction<'a> {
         differential_dataflow::consolidation::consolidate_updates(configs);
         differential_dataflow::consolidation::consolidate_updates(settings);
         differential_dataflow::consolidation::consolidate_updates(source_references);
+        differential_dataflow::consolidation::consolidate_updates(row_security);
+        differential_dataflow::consolidation::consolidate_updates(column_security);
+        differential_dataflow::consolidation::consolidate_updates(resource_groups);
         differential_dataflow::consolidation::consolidate_updates(system_gid_mapping);
         differential_dataflow::consolidation::consolidate_updates(system_configurations);
         differential_dataflow::consolidation::consolidate_updates(default_privileges);
@@ -2467,6 +2629,9 @@ pub struct TransactionBatch {
         proto::SourceReferencesValue,
         Diff,
     )>,
+    pub(crate) row_security: Vec<(proto::RowSecurityKey, proto::RowSecurityValue, Diff)>,
+    pub(crate) column_security: Vec<(proto::ColumnSecurityKey, proto::ColumnSecurityValue, Diff)>,
+    pub(crate) resource_groups: Vec<(proto::ResourceGroupKey, proto::ResourceGroupValue, Diff)>,
     pub(crate) system_privileges: Vec<(
         proto::SystemPrivilegesKey,
         proto::SystemPrivilegesValue,
@@ -2500,6 +2665,9 @@ impl TransactionBatch {
             configs,
             settings,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_gid_mapping,
             system_configurations,
             default_privileges,
@@ -2523,6 +2691,9 @@ impl TransactionBatch {
             && configs.is_empty()
             && settings.is_empty()
             && source_references.is_empty()
+            && row_security.is_empty()
+            && column_security.is_empty()
+            && resource_groups.is_empty()
             && system_gid_mapping.is_empty()
             && system_configurations.is_empty()
             && default_privileges.is_empty()
@@ -180,14 +180,14 @@ macro_rules! objects {
     }
 }
 
-objects!(v67, v68, v69);
+objects!(v67, v68, v69, v70, v71, v72, v73, v74, v75);
 
 /// The current version of the `Catalog`.
 ///
 /// We will initialize new `Catalog`es with this version, and migrate existing `Catalog`es to this
 /// version. Whenever the `Catalog` changes, e.g. the protobufs we serialize in the `Catalog`
 /// change, we need to bump this version.
-pub const CATALOG_VERSION: u64 = 69;
+pub const CATALOG_VERSION: u64 = 75;
 
 /// The minimum `Catalog` version number that we support migrating from.
 ///
@@ -201,6 +201,12 @@ const FUTURE_VERSION: u64 = CATALOG_VERSION + 1;
 
 mod v67_to_v68;
 mod v68_to_v69;
+mod v69_to_v70;
+mod v70_to_v71;
+mod v71_to_v72;
+mod v72_to_v73;
+mod v73_to_v74;
+mod v74_to_v75;
 
 /// Describes a single action to take during a migration from `V1` to `V2`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -281,6 +287,12 @@ async fn run_upgrade(
 
         67 => run_versioned_upgrade(unopened_catalog_state, version, v67_to_v68::upgrade).await,
         68 => run_versioned_upgrade(unopened_catalog_state, version, v68_to_v69::upgrade).await,
+        69 => run_versioned_upgrade(unopened_catalog_state, version, v69_to_v70::upgrade).await,
+        70 => run_versioned_upgrade(unopened_catalog_state, version, v70_to_v71::upgrade).await,
+        71 => run_versioned_upgrade(unopened_catalog_state, version, v71_to_v72::upgrade).await,
+        72 => run_versioned_upgrade(unopened_catalog_state, version, v72_to_v73::upgrade).await,
+        73 => run_versioned_upgrade(unopened_catalog_state, version, v73_to_v74::upgrade).await,
+        74 => run_versioned_upgrade(unopened_catalog_state, version, v74_to_v75::upgrade).await,
 
         // Up-to-date, no migration needed!
         CATALOG_VERSION => Ok(CATALOG_VERSION),
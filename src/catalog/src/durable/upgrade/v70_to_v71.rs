@@ -0,0 +1,19 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v70 as v70, objects_v71 as v71};
+
+/// In v71, we add a collection for row-level security configurations. No existing relation has
+/// row-level security configured, so no data needs to be rewritten.
+pub fn upgrade(
+    _snapshot: Vec<v70::StateUpdateKind>,
+) -> Vec<MigrationAction<v70::StateUpdateKind, v71::StateUpdateKind>> {
+    Vec::new()
+}
@@ -0,0 +1,19 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v71 as v71, objects_v72 as v72};
+
+/// In v72, we add a collection for column privileges and masks. No existing column has any
+/// privileges or mask configured, so no data needs to be rewritten.
+pub fn upgrade(
+    _snapshot: Vec<v71::StateUpdateKind>,
+) -> Vec<MigrationAction<v71::StateUpdateKind, v72::StateUpdateKind>> {
+    Vec::new()
+}
@@ -0,0 +1,19 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v72 as v72, objects_v73 as v73};
+
+/// In v73, we add a collection for resource groups. No resource groups exist yet, so no data needs
+/// to be rewritten.
+pub fn upgrade(
+    _snapshot: Vec<v72::StateUpdateKind>,
+) -> Vec<MigrationAction<v72::StateUpdateKind, v73::StateUpdateKind>> {
+    Vec::new()
+}
@@ -31,7 +31,10 @@ use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::role_id::RoleId;
 use mz_repr::{Diff, GlobalId, RelationDesc, Timestamp};
 use mz_sql::ast::display::AstDisplay;
-use mz_sql::ast::{Expr, Raw, Statement, UnresolvedItemName, Value, WithOptionValue};
+use mz_sql::ast::{
+    Expr, Raw, RetentionOptionValue, Statement, TableOption, TableOptionName, UnresolvedItemName,
+    Value, WithOptionValue,
+};
 use mz_sql::catalog::{
     CatalogClusterReplica, CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem,
     CatalogItemType as SqlCatalogItemType, CatalogItemType, CatalogSchema, CatalogTypeDetails,
@@ -45,8 +48,8 @@ use mz_sql::names::{
 use mz_sql::plan::{
     ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant, CreateSourcePlan,
-    HirRelationExpr, Ingestion as PlanIngestion, NetworkPolicyRule, PlanError, WebhookBodyFormat,
-    WebhookHeaders, WebhookValidation,
+    HirRelationExpr, Ingestion as PlanIngestion, NetworkPolicyRule, PlanError, RetentionPolicy,
+    WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -396,10 +399,12 @@ impl Cluster {
             }
         };
         let workload_class = self.config.workload_class.clone();
+        let arrangement_spill = self.config.arrangement_spill;
         Ok(CreateClusterPlan {
             name,
             variant,
             workload_class,
+            arrangement_spill,
         })
     }
 }
@@ -486,6 +491,47 @@ pub struct ClusterReplicaProcessStatus {
     pub time: DateTime<Utc>,
 }
 
+impl From<durable::RowSecurity> for mz_sql::catalog::RowSecurity {
+    fn from(row_security: durable::RowSecurity) -> mz_sql::catalog::RowSecurity {
+        mz_sql::catalog::RowSecurity {
+            enabled: row_security.enabled,
+            policies: row_security
+                .policies
+                .into_iter()
+                .map(|policy| {
+                    let durable::RowSecurityPolicy { name, roles, using } = policy;
+                    let policy = mz_sql::catalog::RowSecurityPolicy {
+                        roles: roles.into_iter().collect(),
+                        using,
+                    };
+                    (name, policy)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<durable::ColumnSecurity> for mz_sql::catalog::ColumnSecurity {
+    fn from(column_security: durable::ColumnSecurity) -> mz_sql::catalog::ColumnSecurity {
+        mz_sql::catalog::ColumnSecurity {
+            privileges: PrivilegeMap::from_mz_acl_items(column_security.privileges),
+            mask: column_security.mask,
+        }
+    }
+}
+
+impl From<durable::ResourceGroup> for mz_sql::catalog::ResourceGroup {
+    fn from(resource_group: durable::ResourceGroup) -> mz_sql::catalog::ResourceGroup {
+        mz_sql::catalog::ResourceGroup {
+            roles: resource_group.roles.into_iter().collect(),
+            max_concurrent_peeks: resource_group.max_concurrent_peeks,
+            max_queued_peeks: resource_group.max_queued_peeks,
+            statement_timeout: resource_group.statement_timeout,
+            max_result_size: resource_group.max_result_size,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Clone, PartialEq)]
 pub struct SourceReferences {
     pub updated_at: u64,
@@ -649,6 +695,8 @@ pub struct Table {
     /// Whether the table's logical compaction window is controlled by
     /// METRICS_RETENTION
     pub is_retained_metrics_object: bool,
+    /// The policy by which the table's expired rows are retracted, if any.
+    pub retention: Option<RetentionPolicy>,
     pub data_source: TableDataSource,
 }
 
@@ -1045,6 +1093,9 @@ pub struct Index {
     pub cluster_id: ClusterId,
     pub custom_logical_compaction_window: Option<CompactionWindow>,
     pub is_retained_metrics_object: bool,
+    /// Whether the index is written to a storage collection, from which dataflows on any
+    /// cluster can import it.
+    pub persisted: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1183,10 +1234,10 @@ impl CatalogItem {
             | CatalogItem::Source(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::ContinualTask(_) => true,
+            CatalogItem::Index(idx) => idx.persisted,
             CatalogItem::Log(_)
             | CatalogItem::Sink(_)
             | CatalogItem::View(_)
-            | CatalogItem::Index(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -1554,6 +1605,52 @@ impl CatalogItem {
         Ok(res)
     }
 
+    /// Updates the retention policy for a table. Returns the previous retention value. Returns an
+    /// error if this item does not support retention.
+    pub fn update_retention(
+        &mut self,
+        value: Option<RetentionOptionValue>,
+        policy: Option<RetentionPolicy>,
+    ) -> Result<Option<WithOptionValue<Raw>>, ()> {
+        let update = |ast: &mut Statement<Raw>| {
+            let Statement::CreateTable(stmt) = ast else {
+                return Err(());
+            };
+            // Replace or add the option.
+            let pos = stmt
+                .with_options
+                .iter()
+                .rposition(|o| o.name == TableOptionName::Retention);
+            let previous = match (value, pos) {
+                (Some(value), Some(idx)) => {
+                    let previous = stmt.with_options[idx].value.take();
+                    stmt.with_options[idx].value = Some(WithOptionValue::Retention(value));
+                    previous
+                }
+                (Some(value), None) => {
+                    stmt.with_options.push(TableOption {
+                        name: TableOptionName::Retention,
+                        value: Some(WithOptionValue::Retention(value)),
+                    });
+                    None
+                }
+                (None, Some(idx)) => stmt.with_options.swap_remove(idx).value,
+                (None, None) => None,
+            };
+            Ok(previous)
+        };
+
+        let CatalogItem::Table(_) = self else {
+            return Err(());
+        };
+        let res = self.update_sql(update)?;
+        let CatalogItem::Table(table) = self else {
+            unreachable!("checked above");
+        };
+        table.retention = policy;
+        Ok(res)
+    }
+
     /// Updates the create_sql field of this item. Returns an error if this is a builtin item,
     /// otherwise returns f's result.
     pub fn update_sql<F, T>(&mut self, f: F) -> Result<T, ()>
@@ -2354,6 +2451,7 @@ impl DefaultPrivileges {
 pub struct ClusterConfig {
     pub variant: ClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: Option<bool>,
 }
 
 impl ClusterConfig {
@@ -2370,6 +2468,7 @@ impl From<ClusterConfig> for durable::ClusterConfig {
         Self {
             variant: config.variant.into(),
             workload_class: config.workload_class,
+            arrangement_spill: config.arrangement_spill,
         }
     }
 }
@@ -2379,6 +2478,7 @@ impl From<durable::ClusterConfig> for ClusterConfig {
         Self {
             variant: config.variant.into(),
             workload_class: config.workload_class,
+            arrangement_spill: config.arrangement_spill,
         }
     }
 }
@@ -2808,6 +2908,9 @@ pub enum StateUpdateKind {
     TemporaryItem(TemporaryItem),
     Item(durable::objects::Item),
     Comment(durable::objects::Comment),
+    RowSecurity(durable::objects::RowSecurity),
+    ColumnSecurity(durable::objects::ColumnSecurity),
+    ResourceGroup(durable::objects::ResourceGroup),
     AuditLog(durable::objects::AuditLog),
     // Storage updates.
     StorageCollectionMetadata(durable::objects::StorageCollectionMetadata),
@@ -2882,6 +2985,9 @@ pub enum BootstrapStateUpdateKind {
     SystemObjectMapping(durable::objects::SystemObjectMapping),
     Item(durable::objects::Item),
     Comment(durable::objects::Comment),
+    RowSecurity(durable::objects::RowSecurity),
+    ColumnSecurity(durable::objects::ColumnSecurity),
+    ResourceGroup(durable::objects::ResourceGroup),
     AuditLog(durable::objects::AuditLog),
     // Storage updates.
     StorageCollectionMetadata(durable::objects::StorageCollectionMetadata),
@@ -2917,6 +3023,9 @@ impl From<BootstrapStateUpdateKind> for StateUpdateKind {
             }
             BootstrapStateUpdateKind::Item(kind) => StateUpdateKind::Item(kind),
             BootstrapStateUpdateKind::Comment(kind) => StateUpdateKind::Comment(kind),
+            BootstrapStateUpdateKind::RowSecurity(kind) => StateUpdateKind::RowSecurity(kind),
+            BootstrapStateUpdateKind::ColumnSecurity(kind) => StateUpdateKind::ColumnSecurity(kind),
+            BootstrapStateUpdateKind::ResourceGroup(kind) => StateUpdateKind::ResourceGroup(kind),
             BootstrapStateUpdateKind::AuditLog(kind) => StateUpdateKind::AuditLog(kind),
             BootstrapStateUpdateKind::StorageCollectionMetadata(kind) => {
                 StateUpdateKind::StorageCollectionMetadata(kind)
@@ -2964,6 +3073,13 @@ impl TryFrom<StateUpdateKind> for BootstrapStateUpdateKind {
             StateUpdateKind::TemporaryItem(kind) => Err(kind),
             StateUpdateKind::Item(kind) => Ok(BootstrapStateUpdateKind::Item(kind)),
             StateUpdateKind::Comment(kind) => Ok(BootstrapStateUpdateKind::Comment(kind)),
+            StateUpdateKind::RowSecurity(kind) => Ok(BootstrapStateUpdateKind::RowSecurity(kind)),
+            StateUpdateKind::ColumnSecurity(kind) => {
+                Ok(BootstrapStateUpdateKind::ColumnSecurity(kind))
+            }
+            StateUpdateKind::ResourceGroup(kind) => {
+                Ok(BootstrapStateUpdateKind::ResourceGroup(kind))
+            }
             StateUpdateKind::AuditLog(kind) => Ok(BootstrapStateUpdateKind::AuditLog(kind)),
             StateUpdateKind::StorageCollectionMetadata(kind) => {
                 Ok(BootstrapStateUpdateKind::StorageCollectionMetadata(kind))
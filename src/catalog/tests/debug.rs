@@ -61,6 +61,9 @@ impl Debug for HiddenUserVersionTrace<'_> {
             schemas,
             settings,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_object_mappings,
             system_configurations,
             system_privileges,
@@ -92,6 +95,9 @@ impl Debug for HiddenUserVersionTrace<'_> {
             .field("schemas", schemas)
             .field("settings", settings)
             .field("source_references", source_references)
+            .field("row_security", row_security)
+            .field("column_security", column_security)
+            .field("resource_groups", resource_groups)
             .field("system_object_mappings", system_object_mappings)
             .field("system_configurations", system_configurations)
             .field("system_privileges", system_privileges)
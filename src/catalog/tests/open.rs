@@ -61,6 +61,9 @@ impl Debug for HiddenUserVersionSnapshot<'_> {
             configs,
             settings,
             source_references,
+            row_security,
+            column_security,
+            resource_groups,
             system_object_mappings,
             system_configurations,
             default_privileges,
@@ -85,6 +88,9 @@ impl Debug for HiddenUserVersionSnapshot<'_> {
             .field("configs", &configs)
             .field("settings", settings)
             .field("source_references", source_references)
+            .field("row_security", row_security)
+            .field("column_security", column_security)
+            .field("resource_groups", resource_groups)
             .field("system_object_mappings", system_object_mappings)
             .field("system_configurations", system_configurations)
             .field("default_privileges", default_privileges)
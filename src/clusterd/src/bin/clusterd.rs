@@ -92,6 +92,16 @@ struct Args {
         default_value = "http://localhost:6879"
     )]
     persist_pubsub_url: String,
+    /// A local directory in which to cache blobs read from persist's blob
+    /// storage, so that they needn't be fetched again, e.g. after a restart.
+    ///
+    /// Disabled if not provided.
+    #[clap(long, env = "PERSIST_BLOB_CACHE_DIRECTORY", value_name = "PATH")]
+    persist_blob_cache_directory: Option<PathBuf>,
+    /// The maximum number of bytes to cache in
+    /// `--persist-blob-cache-directory`.
+    #[clap(long, env = "PERSIST_BLOB_CACHE_DISK_LIMIT_BYTES", value_name = "N")]
+    persist_blob_cache_disk_limit_bytes: Option<usize>,
 
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
@@ -271,6 +281,10 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), mz_dyncfgs::all_dyncfgs());
     persist_cfg.is_cc_active = args.is_cluster_size_v2;
     persist_cfg.announce_memory_limit = args.announce_memory_limit;
+    persist_cfg.blob_cache_disk_dir = args.persist_blob_cache_directory;
+    if let Some(limit_bytes) = args.persist_blob_cache_disk_limit_bytes {
+        persist_cfg.blob_cache_disk_limit_bytes = limit_bytes;
+    }
     let persist_clients = Arc::new(PersistClientCache::new(
         persist_cfg,
         &metrics_registry,
@@ -984,6 +984,7 @@ mod tests {
                 let desc = SourceInstanceDesc {
                     arguments: SourceInstanceArguments {
                         operators: Default::default(),
+                        persisted_index: Default::default(),
                     },
                     storage_metadata: Default::default(),
                     typ: RelationType::empty(),
@@ -40,7 +40,8 @@ use mz_build_info::BuildInfo;
 use mz_cluster_client::client::ClusterReplicaLocation;
 use mz_cluster_client::{ReplicaId, WallclockLagFn};
 use mz_compute_types::dataflows::DataflowDescription;
-use mz_compute_types::dyncfgs::COMPUTE_REPLICA_EXPIRATION_OFFSET;
+use mz_compute_types::dyncfgs::{COMPUTE_REPLICA_EXPIRATION_OFFSET, ENABLE_ARRANGEMENT_SPILL};
+use mz_compute_types::sinks::ComputeSinkConnection;
 use mz_compute_types::ComputeInstanceId;
 use mz_dyncfg::ConfigSet;
 use mz_expr::RowSetFinishing;
@@ -183,6 +184,9 @@ pub struct ComputeController<T: ComputeControllerTimestamp> {
     /// class of the workload that compute instance is running (e.g.,
     /// `production` or `staging`).
     instance_workload_classes: Arc<Mutex<BTreeMap<ComputeInstanceId, Option<String>>>>,
+    /// A map from an instance ID to the instance's override of the global
+    /// `enable_compute_arrangement_spill` flag, if any.
+    instance_arrangement_spill: BTreeMap<ComputeInstanceId, Option<bool>>,
     build_info: &'static BuildInfo,
     /// A handle providing access to storage collections.
     storage_collections: StorageCollections<T>,
@@ -291,6 +295,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         Self {
             instances: BTreeMap::new(),
             instance_workload_classes,
+            instance_arrangement_spill: BTreeMap::new(),
             build_info,
             storage_collections,
             initialized: false,
@@ -473,6 +478,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         let Self {
             instances,
             instance_workload_classes,
+            instance_arrangement_spill,
             build_info: _,
             storage_collections: _,
             initialized,
@@ -505,6 +511,10 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
             .iter()
             .map(|(id, wc)| (id.to_string(), format!("{wc:?}")))
             .collect();
+        let instance_arrangement_spill: BTreeMap<_, _> = instance_arrangement_spill
+            .iter()
+            .map(|(id, spill)| (id.to_string(), format!("{spill:?}")))
+            .collect();
 
         fn field(
             key: &str,
@@ -517,6 +527,7 @@ impl<T: ComputeControllerTimestamp> ComputeController<T> {
         let map = serde_json::Map::from_iter([
             field("instances", instances_dump)?,
             field("instance_workload_classes", instance_workload_classes)?,
+            field("instance_arrangement_spill", instance_arrangement_spill)?,
             field("initialized", initialized)?,
             field("read_only", read_only)?,
             field(
@@ -542,6 +553,7 @@ where
         id: ComputeInstanceId,
         arranged_logs: BTreeMap<LogVariant, GlobalId>,
         workload_class: Option<String>,
+        arrangement_spill: Option<bool>,
     ) -> Result<(), InstanceExists> {
         if self.instances.contains_key(&id) {
             return Err(InstanceExists(id));
@@ -581,6 +593,8 @@ where
             .lock()
             .expect("lock poisoned")
             .insert(id, workload_class.clone());
+        self.instance_arrangement_spill
+            .insert(id, arrangement_spill);
 
         let instance = self.instances.get_mut(&id).expect("instance just added");
         if self.initialized {
@@ -593,6 +607,10 @@ where
 
         let mut config_params = self.config.clone();
         config_params.workload_class = Some(workload_class);
+        config_params.dyncfg_updates.add(
+            &ENABLE_ARRANGEMENT_SPILL,
+            arrangement_spill.unwrap_or_else(|| ENABLE_ARRANGEMENT_SPILL.get(&self.dyncfg)),
+        );
         instance.call(|i| i.update_configuration(config_params));
 
         Ok(())
@@ -618,6 +636,27 @@ where
         Ok(())
     }
 
+    /// Updates a compute instance's override of the global arrangement spill
+    /// flag.
+    ///
+    /// `None` makes the instance follow the global flag again.
+    pub fn update_instance_arrangement_spill(
+        &mut self,
+        id: ComputeInstanceId,
+        arrangement_spill: Option<bool>,
+    ) -> Result<(), InstanceMissing> {
+        // Ensure that the instance exists first.
+        let _ = self.instance(id)?;
+
+        self.instance_arrangement_spill
+            .insert(id, arrangement_spill);
+
+        // Cause a config update to notify the instance about its new setting.
+        self.update_configuration(Default::default());
+
+        Ok(())
+    }
+
     /// Remove a compute instance.
     ///
     /// # Panics
@@ -632,6 +671,7 @@ where
             .lock()
             .expect("lock poisoned")
             .remove(&id);
+        self.instance_arrangement_spill.remove(&id);
     }
 
     /// Returns the compute controller's config set.
@@ -650,10 +690,15 @@ where
             .expect("lock poisoned");
 
         // Forward updates to existing clusters.
-        // Workload classes are cluster-specific, so we need to overwrite them here.
+        // Workload classes and arrangement spill overrides are cluster-specific, so we need to
+        // overwrite them here. The spill flag is always sent, so that resetting an override
+        // restores the global value.
+        let global_spill = ENABLE_ARRANGEMENT_SPILL.get(&self.dyncfg);
         for (id, instance) in self.instances.iter_mut() {
             let mut params = config_params.clone();
             params.workload_class = Some(instance_workload_classes[id].clone());
+            let spill = self.instance_arrangement_spill[id].unwrap_or(global_spill);
+            params.dyncfg_updates.add(&ENABLE_ARRANGEMENT_SPILL, spill);
             instance.call(|i| i.update_configuration(params));
         }
 
@@ -766,7 +811,10 @@ where
 
         instance.replicas.remove(&replica_id);
 
-        instance.call(move |i| i.remove_replica(replica_id).expect("validated"));
+        instance.call(move |i| {
+            i.remove_replica(replica_id).expect("validated");
+            i.release_checkpoint_read_holds(replica_id);
+        });
 
         Ok(())
     }
@@ -802,6 +850,15 @@ where
             return Err(EmptyAsOfForCopyTo);
         }
 
+        // Validation: persisted indexes are maintained without an upper bound
+        for (id, sink) in &dataflow.sink_exports {
+            let persisted_index =
+                matches!(sink.connection, ComputeSinkConnection::PersistedIndex(_));
+            if persisted_index && !sink.up_to.is_empty() {
+                return Err(UpToForPersistedIndex(*id));
+            }
+        }
+
         // Validation: input collections
         let storage_ids = dataflow.imported_source_ids().collect();
         let mut import_read_holds = self.storage_collections.acquire_read_holds(storage_ids)?;
@@ -1071,6 +1128,7 @@ where
                 | ReplicaFrontiers
                 | ComputeDependencies
                 | ComputeOperatorHydrationStatus
+                | ComputeDataflowMemoryLimitStatus
                 | ComputeMaterializedViewRefreshes => {
                     let op = StorageWriteOp::Append { updates };
                     storage.update_introspection_collection(type_, op);
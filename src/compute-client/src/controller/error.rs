@@ -131,6 +131,9 @@ pub enum DataflowCreationError {
     /// because it should always have an external side effect.
     #[error("copy to dataflow has an empty as_of")]
     EmptyAsOfForCopyTo,
+    /// Persisted indexes are maintained indefinitely, and cannot have an `up_to`.
+    #[error("persisted index dataflow has an up_to: {0}")]
+    UpToForPersistedIndex(GlobalId),
 }
 
 impl From<InstanceMissing> for DataflowCreationError {
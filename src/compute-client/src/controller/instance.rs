@@ -15,6 +15,7 @@ use std::num::NonZeroI64;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use differential_dataflow::lattice::Lattice;
 use mz_build_info::BuildInfo;
 use mz_cluster_client::client::{ClusterStartupEpoch, TimelyConfig};
 use mz_cluster_client::WallclockLagFn;
@@ -22,7 +23,8 @@ use mz_compute_types::dataflows::{BuildDesc, DataflowDescription};
 use mz_compute_types::plan::flat_plan::FlatPlan;
 use mz_compute_types::plan::LirId;
 use mz_compute_types::sinks::{
-    ComputeSinkConnection, ComputeSinkDesc, ContinualTaskConnection, MaterializedViewSinkConnection,
+    ComputeSinkConnection, ComputeSinkDesc, ContinualTaskConnection,
+    MaterializedViewSinkConnection, PersistedIndexSinkConnection,
 };
 use mz_compute_types::sources::SourceInstanceDesc;
 use mz_compute_types::ComputeInstanceId;
@@ -64,8 +66,8 @@ use crate::protocol::command::{
 };
 use crate::protocol::history::ComputeCommandHistory;
 use crate::protocol::response::{
-    ComputeResponse, CopyToResponse, FrontiersResponse, OperatorHydrationStatus, PeekResponse,
-    StatusResponse, SubscribeBatch, SubscribeResponse,
+    ComputeResponse, CopyToResponse, DataflowMemoryLimitStatus, FrontiersResponse,
+    OperatorHydrationStatus, PeekResponse, StatusResponse, SubscribeBatch, SubscribeResponse,
 };
 use crate::service::{ComputeClient, ComputeGrpcClient};
 
@@ -625,6 +627,166 @@ impl<T: ComputeControllerTimestamp> Instance<T> {
         );
     }
 
+    /// Record that a collection's dataflow was paused on a replica for exceeding its memory limit.
+    fn update_dataflow_memory_limit_status(
+        &mut self,
+        replica_id: ReplicaId,
+        status: DataflowMemoryLimitStatus,
+    ) {
+        let Some(replica) = self.replicas.get_mut(&replica_id) else {
+            tracing::error!(
+                %replica_id, ?status,
+                "status update for an unknown replica"
+            );
+            return;
+        };
+        let Some(collection) = replica.collections.get_mut(&status.collection_id) else {
+            tracing::error!(
+                %replica_id, ?status,
+                "status update for an unknown collection"
+            );
+            return;
+        };
+
+        tracing::warn!(
+            %replica_id, ?status,
+            "dataflow paused for exceeding its memory limit"
+        );
+        collection.introspection.memory_limit_exceeded(
+            status.worker_id,
+            status.heap_size,
+            status.limit,
+        );
+        collection.memory_limit_status.get_or_insert(status);
+
+        self.fail_paused_dependents();
+    }
+
+    /// Returns the first pause report for the given collection or its transitive compute
+    /// dependencies on the given replica.
+    fn paused_dependency<'a>(
+        &self,
+        replica: &'a ReplicaState<T>,
+        id: GlobalId,
+    ) -> Option<&'a DataflowMemoryLimitStatus> {
+        let mut todo = vec![id];
+        let mut visited = BTreeSet::new();
+        while let Some(id) = todo.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let replica_collection = replica.collections.get(&id);
+            if let Some(status) = replica_collection.and_then(|c| c.memory_limit_status.as_ref()) {
+                return Some(status);
+            }
+            if let Some(collection) = self.collections.get(&id) {
+                todo.extend(collection.compute_dependency_ids());
+            }
+        }
+        None
+    }
+
+    /// Returns an error if reading the given collection cannot make progress on any replica that
+    /// could serve the read, because a dataflow it depends on was paused for exceeding its
+    /// memory limit.
+    ///
+    /// If a read `time` is given, replicas whose write frontier is already beyond that time are
+    /// still able to serve the read.
+    fn paused_dependency_error(
+        &self,
+        target_replica: Option<ReplicaId>,
+        id: GlobalId,
+        time: Option<&Antichain<T>>,
+    ) -> Option<String> {
+        let mut error = None;
+        for (&replica_id, replica) in &self.replicas {
+            if target_replica.is_some_and(|target| target != replica_id) {
+                continue;
+            }
+            if let (Some(time), Some(collection)) = (time, replica.collections.get(&id)) {
+                if PartialOrder::less_than(time, &collection.write_frontier) {
+                    return None;
+                }
+            }
+            let status = self.paused_dependency(replica, id)?;
+            error = Some(format!(
+                "dataflow maintaining {} was paused on replica {replica_id} for exceeding its \
+                 memory limit: arrangement heap size of {} bytes exceeds the per-worker limit \
+                 of {} bytes",
+                status.collection_id, status.heap_size, status.limit,
+            ));
+        }
+        error
+    }
+
+    /// Fails peeks, subscribes, and COPY TOs that depend on a dataflow paused for exceeding its
+    /// memory limit.
+    ///
+    /// A paused dataflow discards its inputs and never resumes, so these would otherwise wait
+    /// forever. We only fail them once none of the replicas that could serve them is able to.
+    fn fail_paused_dependents(&mut self) {
+        let any_paused = self
+            .replicas
+            .values()
+            .flat_map(|replica| replica.collections.values())
+            .any(|collection| collection.memory_limit_status.is_some());
+        if !any_paused {
+            return;
+        }
+
+        let mut peek_errors = Vec::new();
+        for (uuid, peek) in &self.peeks {
+            let id = peek.read_hold.id();
+            let time = peek.read_hold.since();
+            if let Some(error) = self.paused_dependency_error(peek.target_replica, id, Some(time)) {
+                peek_errors.push((*uuid, error));
+            }
+        }
+        for (uuid, error) in peek_errors {
+            let otel_ctx = self.peeks[&uuid].otel_ctx.clone();
+            self.deliver_response(ComputeControllerResponse::PeekNotification(
+                uuid,
+                PeekNotification::Error(error.clone()),
+                otel_ctx,
+            ));
+            self.finish_peek(uuid, PeekResponse::Error(error));
+        }
+
+        let mut subscribe_errors = Vec::new();
+        for (subscribe_id, subscribe) in &self.subscribes {
+            let target_replica = subscribe.target_replica;
+            if let Some(error) = self.paused_dependency_error(target_replica, *subscribe_id, None) {
+                subscribe_errors.push((*subscribe_id, error));
+            }
+        }
+        for (subscribe_id, error) in subscribe_errors {
+            let subscribe = self.subscribes.remove(&subscribe_id).expect("tracked");
+            let response = ComputeControllerResponse::SubscribeResponse(
+                subscribe_id,
+                SubscribeBatch {
+                    lower: subscribe.frontier.clone(),
+                    upper: subscribe.frontier,
+                    updates: Err(error),
+                },
+            );
+            self.deliver_response(response);
+        }
+
+        let mut copy_to_errors = Vec::new();
+        for copy_to_id in &self.copy_tos {
+            if let Some(error) = self.paused_dependency_error(None, *copy_to_id, None) {
+                copy_to_errors.push((*copy_to_id, error));
+            }
+        }
+        for (copy_to_id, error) in copy_to_errors {
+            self.copy_tos.remove(&copy_to_id);
+            let result = Err(anyhow::anyhow!(error));
+            self.deliver_response(ComputeControllerResponse::CopyToResponse(
+                copy_to_id, result,
+            ));
+        }
+    }
+
     /// Returns `true` if the given collection is hydrated on at least one
     /// replica.
     ///
@@ -932,6 +1094,7 @@ where
         self.send(ComputeCommand::CreateInstance(InstanceConfig {
             logging: dummy_logging_config,
             expiration_offset: None,
+            replica_id: None,
         }));
 
         loop {
@@ -1295,6 +1458,18 @@ where
                 ComputeSinkConnection::CopyToS3Oneshot(conn) => {
                     ComputeSinkConnection::CopyToS3Oneshot(conn)
                 }
+                ComputeSinkConnection::PersistedIndex(conn) => {
+                    let metadata = self
+                        .storage_collections
+                        .collection_metadata(id)
+                        .map_err(|_| CollectionMissing(id))?
+                        .clone();
+                    let conn = PersistedIndexSinkConnection {
+                        key: conn.key,
+                        storage_metadata: metadata,
+                    };
+                    ComputeSinkConnection::PersistedIndex(conn)
+                }
             };
             let desc = ComputeSinkDesc {
                 from: se.from,
@@ -1836,6 +2011,29 @@ where
             replica_collection.update_write_frontier(new_frontier.clone());
             self.maybe_update_global_write_frontier(id, new_frontier);
         }
+        if let Some(new_frontier) = frontiers.checkpoint_frontier {
+            let collection = self.collections.get_mut(&id).expect("checked above");
+            collection
+                .checkpoint_frontiers
+                .insert(replica_id, new_frontier);
+            collection.update_checkpoint_read_holds();
+        }
+    }
+
+    /// Releases the read holds kept for arrangement checkpoints of the given replica.
+    ///
+    /// Not called when a replica is merely restarted, as the new incarnation can still hydrate
+    /// from the checkpoints written by the previous one.
+    pub fn release_checkpoint_read_holds(&mut self, replica_id: ReplicaId) {
+        for collection in self.collections.values_mut() {
+            if collection
+                .checkpoint_frontiers
+                .remove(&replica_id)
+                .is_some()
+            {
+                collection.update_checkpoint_read_holds();
+            }
+        }
     }
 
     #[mz_ore::instrument(level = "debug")]
@@ -2016,6 +2214,9 @@ where
             StatusResponse::OperatorHydration(status) => {
                 self.update_operator_hydration_status(replica_id, status)
             }
+            StatusResponse::DataflowMemoryLimit(status) => {
+                self.update_dataflow_memory_limit_status(replica_id, status)
+            }
         }
     }
 
@@ -2091,6 +2292,7 @@ where
         self.update_frontier_introspection();
         self.refresh_state_metrics();
         self.refresh_wallclock_lag();
+        self.fail_paused_dependents();
     }
 }
 
@@ -2146,6 +2348,18 @@ struct CollectionState<T: ComputeControllerTimestamp> {
     /// requires on them.
     compute_dependencies: BTreeMap<GlobalId, ReadHold<T>>,
 
+    /// The checkpoint frontiers reported by replicas checkpointing the arrangement of this
+    /// collection.
+    ///
+    /// See [`FrontiersResponse::checkpoint_frontier`].
+    checkpoint_frontiers: BTreeMap<ReplicaId, Antichain<T>>,
+    /// Read holds on the storage inputs of this collection at the meet of the
+    /// `checkpoint_frontiers`.
+    ///
+    /// These are kept separately from the per-replica input read holds, which only cover times
+    /// beyond the `as_of` of the replica's current incarnation of the dataflow.
+    checkpoint_read_holds: Vec<ReadHold<T>>,
+
     /// Introspection state associated with this collection.
     introspection: CollectionIntrospection<T>,
 }
@@ -2191,6 +2405,8 @@ impl<T: ComputeControllerTimestamp> CollectionState<T> {
             read_policy: Some(ReadPolicy::ValidFrom(since)),
             storage_dependencies,
             compute_dependencies,
+            checkpoint_frontiers: Default::default(),
+            checkpoint_read_holds: Default::default(),
             introspection,
         }
     }
@@ -2244,6 +2460,31 @@ impl<T: ComputeControllerTimestamp> CollectionState<T> {
         self.compute_dependency_ids()
             .chain(self.storage_dependency_ids())
     }
+
+    /// Moves the checkpoint read holds to the meet of the replica checkpoint frontiers.
+    fn update_checkpoint_read_holds(&mut self) {
+        if self.checkpoint_frontiers.is_empty() {
+            self.checkpoint_read_holds.clear();
+            return;
+        }
+
+        let mut frontier = Antichain::new();
+        for replica_frontier in self.checkpoint_frontiers.values() {
+            frontier.extend(replica_frontier.iter().cloned());
+        }
+
+        // The first report can arrive after the storage inputs have already been allowed to
+        // compact beyond it, in which case we hold them where they are.
+        if self.checkpoint_read_holds.is_empty() {
+            self.checkpoint_read_holds = self.storage_dependencies.values().cloned().collect();
+        }
+        for read_hold in &mut self.checkpoint_read_holds {
+            let since = read_hold.since().join(&frontier);
+            read_hold
+                .try_downgrade(since)
+                .expect("joined with the current since");
+        }
+    }
 }
 
 /// Collection state shared with the `ComputeController`.
@@ -2791,6 +3032,11 @@ struct ReplicaCollectionState<T: ComputeControllerTimestamp> {
     hydrated: bool,
     /// Tracks introspection state for this collection.
     introspection: ReplicaCollectionIntrospection<T>,
+    /// The first report of the collection's dataflow exceeding its memory limit, if any.
+    ///
+    /// Once set, the dataflow is paused on the replica and the collection does not advance
+    /// anymore.
+    memory_limit_status: Option<DataflowMemoryLimitStatus>,
     /// Read holds on storage inputs to this collection.
     ///
     /// These read holds are kept to ensure that the replica is able to read from storage inputs at
@@ -2818,6 +3064,7 @@ impl<T: ComputeControllerTimestamp> ReplicaCollectionState<T> {
             as_of,
             hydrated: false,
             introspection,
+            memory_limit_status: None,
             input_read_holds,
             wallclock_lag_max: Default::default(),
         }
@@ -2911,6 +3158,9 @@ struct ReplicaCollectionIntrospection<T: ComputeControllerTimestamp> {
     /// Operator-level hydration state.
     /// (lir_id, worker_id) -> hydrated
     operators: BTreeMap<(LirId, usize), bool>,
+    /// Workers on which the collection's dataflow was paused for exceeding its memory limit.
+    /// worker_id -> (heap_size, limit)
+    memory_limits: BTreeMap<usize, (u64, u64)>,
     /// The collection's reported replica write frontier.
     write_frontier: Antichain<T>,
     /// A channel through which introspection updates are delivered.
@@ -2929,6 +3179,7 @@ impl<T: ComputeControllerTimestamp> ReplicaCollectionIntrospection<T> {
             replica_id,
             collection_id,
             operators: Default::default(),
+            memory_limits: Default::default(),
             write_frontier: as_of,
             introspection_tx,
         };
@@ -2977,6 +3228,47 @@ impl<T: ComputeControllerTimestamp> ReplicaCollectionIntrospection<T> {
         })
     }
 
+    /// Record that the collection's dataflow exceeded its memory limit on the given worker.
+    fn memory_limit_exceeded(&mut self, worker_id: usize, heap_size: u64, limit: u64) {
+        let retraction = self.memory_limit_row(worker_id);
+        self.memory_limits.insert(worker_id, (heap_size, limit));
+        let insertion = self.memory_limit_row(worker_id);
+
+        if retraction == insertion {
+            return; // no change
+        }
+
+        let updates = retraction
+            .map(|r| (r, -1))
+            .into_iter()
+            .chain(insertion.map(|r| (r, 1)))
+            .collect();
+        self.send(IntrospectionType::ComputeDataflowMemoryLimitStatus, updates);
+    }
+
+    /// Return a `Row` reflecting the memory limit status of the collection's dataflow on the
+    /// given worker.
+    ///
+    /// Returns `None` if the dataflow did not exceed its memory limit on the worker.
+    fn memory_limit_row(&self, worker_id: usize) -> Option<Row> {
+        self.memory_limits
+            .get(&worker_id)
+            .map(|(heap_size, limit)| {
+                let error = format!(
+                    "dataflow paused: arrangement heap size of {heap_size} bytes \
+                 exceeds the per-worker memory limit of {limit} bytes"
+                );
+                Row::pack_slice(&[
+                    Datum::String(&self.collection_id.to_string()),
+                    Datum::String(&self.replica_id.to_string()),
+                    Datum::UInt64(u64::cast_from(worker_id)),
+                    Datum::UInt64(*heap_size),
+                    Datum::UInt64(*limit),
+                    Datum::String(&error),
+                ])
+            })
+    }
+
     /// Observe the given current write frontier and update the introspection state as necessary.
     fn observe_frontier(&mut self, write_frontier: &Antichain<T>) {
         if self.write_frontier == *write_frontier {
@@ -3024,6 +3316,17 @@ impl<T: ComputeControllerTimestamp> Drop for ReplicaCollectionIntrospection<T> {
             self.send(IntrospectionType::ComputeOperatorHydrationStatus, updates);
         }
 
+        // Retract memory limit status.
+        let updates: Vec<_> = self
+            .memory_limits
+            .keys()
+            .flat_map(|worker_id| self.memory_limit_row(*worker_id))
+            .map(|r| (r, -1))
+            .collect();
+        if !updates.is_empty() {
+            self.send(IntrospectionType::ComputeDataflowMemoryLimitStatus, updates);
+        }
+
         // Retract the write frontier.
         let row = self.write_frontier_row();
         let updates = vec![(row, -1)];
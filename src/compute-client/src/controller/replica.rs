@@ -273,8 +273,10 @@ where
             ComputeCommand::CreateInstance(InstanceConfig {
                 logging,
                 expiration_offset,
+                replica_id,
             }) => {
                 *logging = self.config.logging.clone();
+                *replica_id = Some(self.replica_id);
                 if ENABLE_COMPUTE_REPLICA_EXPIRATION.get(&self.dyncfg) {
                     *expiration_offset = self.config.expiration_offset;
                 }
@@ -303,6 +303,8 @@ pub enum ComputeLog {
     ErrorCount,
     /// Hydration times of exported collections.
     HydrationTime,
+    /// Times of the latest arrangement checkpoints of exported collections.
+    ArrangementCheckpoints,
     /// Mappings from `GlobalId`/`LirId`` pairs to dataflow addresses.
     LirMapping,
     /// Mappings from dataflows to `GlobalId`s.
@@ -325,6 +327,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
                 ComputeLog::ShutdownDuration => ShutdownDuration(()),
                 ComputeLog::ErrorCount => ErrorCount(()),
                 ComputeLog::HydrationTime => HydrationTime(()),
+                ComputeLog::ArrangementCheckpoints => ArrangementCheckpoints(()),
                 ComputeLog::LirMapping => LirMapping(()),
                 ComputeLog::DataflowGlobal => DataflowGlobal(()),
             }),
@@ -345,6 +348,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
             Some(ShutdownDuration(())) => Ok(ComputeLog::ShutdownDuration),
             Some(ErrorCount(())) => Ok(ComputeLog::ErrorCount),
             Some(HydrationTime(())) => Ok(ComputeLog::HydrationTime),
+            Some(ArrangementCheckpoints(())) => Ok(ComputeLog::ArrangementCheckpoints),
             Some(LirMapping(())) => Ok(ComputeLog::LirMapping),
             Some(DataflowGlobal(())) => Ok(ComputeLog::DataflowGlobal),
             None => Err(TryFromProtoError::missing_field("ProtoComputeLog::kind")),
@@ -534,6 +538,13 @@ impl LogVariant {
                 .with_key(vec![0, 1])
                 .finish(),
 
+            LogVariant::Compute(ComputeLog::ArrangementCheckpoints) => RelationDesc::builder()
+                .with_column("export_id", ScalarType::String.nullable(false))
+                .with_column("worker_id", ScalarType::UInt64.nullable(false))
+                .with_column("time", ScalarType::MzTimestamp.nullable(false))
+                .with_key(vec![0, 1])
+                .finish(),
+
             LogVariant::Compute(ComputeLog::LirMapping) => RelationDesc::builder()
                 .with_column("global_id", ScalarType::String.nullable(false))
                 .with_column("lir_id", ScalarType::UInt64.nullable(false))
@@ -12,6 +12,7 @@
 use std::time::Duration;
 
 use mz_cluster_client::client::{ClusterStartupEpoch, TimelyConfig, TryIntoTimelyConfig};
+use mz_cluster_client::ReplicaId;
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_compute_types::plan::flat_plan::FlatPlan;
 use mz_dyncfg::ConfigUpdates;
@@ -374,6 +375,11 @@ pub struct InstanceConfig {
     pub logging: LoggingConfig,
     /// The offset relative to the replica startup at which it should expire. None disables feature.
     pub expiration_offset: Option<Duration>,
+    /// The ID of the replica.
+    ///
+    /// Used to scope durable state written by the replica, such as arrangement checkpoints.
+    #[proptest(strategy = "proptest::option::of(any::<u64>().prop_map(ReplicaId::User))")]
+    pub replica_id: Option<ReplicaId>,
 }
 
 impl RustType<ProtoInstanceConfig> for InstanceConfig {
@@ -381,6 +387,7 @@ impl RustType<ProtoInstanceConfig> for InstanceConfig {
         ProtoInstanceConfig {
             logging: Some(self.logging.into_proto()),
             expiration_offset: self.expiration_offset.into_proto(),
+            replica_id: self.replica_id.map(|id| id.to_string()),
         }
     }
 
@@ -390,6 +397,11 @@ impl RustType<ProtoInstanceConfig> for InstanceConfig {
                 .logging
                 .into_rust_if_some("ProtoCreateInstance::logging")?,
             expiration_offset: proto.expiration_offset.into_rust()?,
+            replica_id: proto
+                .replica_id
+                .map(|id| id.parse())
+                .transpose()
+                .map_err(|e| TryFromProtoError::InvalidFieldError(format!("{e}")))?,
         })
     }
 }
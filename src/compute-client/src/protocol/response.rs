@@ -271,6 +271,13 @@ pub struct FrontiersResponse<T = mz_repr::Timestamp> {
     ///  * In a multi-replica cluster, slower replicas observe and report the write frontier of the
     ///    fastest replica, by witnessing advancements of the target persist shard's `upper`.
     pub output_frontier: Option<Antichain<T>>,
+    /// The collection's new checkpoint frontier, if any.
+    ///
+    /// Only reported for indexes whose arrangements the replica checkpoints. Upon receiving an
+    /// updated `checkpoint_frontier`, the controller holds back the collection's storage inputs
+    /// at that frontier, so that a restarted replica can hydrate the arrangement from its
+    /// checkpoints by reading the input changes since.
+    pub checkpoint_frontier: Option<Antichain<T>>,
 }
 
 impl<T> FrontiersResponse<T> {
@@ -279,6 +286,7 @@ impl<T> FrontiersResponse<T> {
         self.write_frontier.is_some()
             || self.input_frontier.is_some()
             || self.output_frontier.is_some()
+            || self.checkpoint_frontier.is_some()
     }
 }
 
@@ -288,6 +296,7 @@ impl RustType<ProtoFrontiersResponse> for FrontiersResponse {
             write_frontier: self.write_frontier.into_proto(),
             input_frontier: self.input_frontier.into_proto(),
             output_frontier: self.output_frontier.into_proto(),
+            checkpoint_frontier: self.checkpoint_frontier.into_proto(),
         }
     }
 
@@ -296,6 +305,7 @@ impl RustType<ProtoFrontiersResponse> for FrontiersResponse {
             write_frontier: proto.write_frontier.into_rust()?,
             input_frontier: proto.input_frontier.into_rust()?,
             output_frontier: proto.output_frontier.into_rust()?,
+            checkpoint_frontier: proto.checkpoint_frontier.into_rust()?,
         })
     }
 }
@@ -305,11 +315,17 @@ impl Arbitrary for FrontiersResponse {
     type Parameters = ();
 
     fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-        (any_antichain(), any_antichain(), any_antichain())
-            .prop_map(|(write, input, compute)| Self {
+        (
+            any_antichain(),
+            any_antichain(),
+            any_antichain(),
+            any_antichain(),
+        )
+            .prop_map(|(write, input, compute, checkpoint)| Self {
                 write_frontier: Some(write),
                 input_frontier: Some(input),
                 output_frontier: Some(compute),
+                checkpoint_frontier: Some(checkpoint),
             })
             .boxed()
     }
@@ -588,6 +604,8 @@ impl Arbitrary for SubscribeBatch<mz_repr::Timestamp> {
 pub enum StatusResponse {
     /// Reports the hydration status of dataflow operators.
     OperatorHydration(OperatorHydrationStatus),
+    /// Reports that a dataflow was paused for exceeding its memory limit.
+    DataflowMemoryLimit(DataflowMemoryLimitStatus),
 }
 
 impl RustType<ProtoStatusResponse> for StatusResponse {
@@ -596,6 +614,7 @@ impl RustType<ProtoStatusResponse> for StatusResponse {
 
         let kind = match self {
             Self::OperatorHydration(status) => Kind::OperatorHydration(status.into_proto()),
+            Self::DataflowMemoryLimit(status) => Kind::DataflowMemoryLimit(status.into_proto()),
         };
         ProtoStatusResponse { kind: Some(kind) }
     }
@@ -607,6 +626,9 @@ impl RustType<ProtoStatusResponse> for StatusResponse {
             Some(Kind::OperatorHydration(status)) => {
                 Ok(Self::OperatorHydration(status.into_rust()?))
             }
+            Some(Kind::DataflowMemoryLimit(status)) => {
+                Ok(Self::DataflowMemoryLimit(status.into_rust()?))
+            }
             None => Err(TryFromProtoError::missing_field(
                 "ProtoStatusResponse::kind",
             )),
@@ -649,6 +671,42 @@ impl RustType<ProtoOperatorHydrationStatus> for OperatorHydrationStatus {
     }
 }
 
+/// An update about a dataflow that was paused because its arrangements exceeded the configured
+/// per-dataflow memory limit on a worker.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct DataflowMemoryLimitStatus {
+    /// The ID of the compute collection exported by the paused dataflow.
+    pub collection_id: GlobalId,
+    /// The ID of the worker on which the dataflow exceeded its memory limit.
+    pub worker_id: usize,
+    /// The arrangement heap size of the dataflow on the worker, in bytes.
+    pub heap_size: u64,
+    /// The memory limit of the dataflow on the worker, in bytes.
+    pub limit: u64,
+}
+
+impl RustType<ProtoDataflowMemoryLimitStatus> for DataflowMemoryLimitStatus {
+    fn into_proto(&self) -> ProtoDataflowMemoryLimitStatus {
+        ProtoDataflowMemoryLimitStatus {
+            collection_id: Some(self.collection_id.into_proto()),
+            worker_id: self.worker_id.into_proto(),
+            heap_size: self.heap_size,
+            limit: self.limit,
+        }
+    }
+
+    fn from_proto(proto: ProtoDataflowMemoryLimitStatus) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
+            collection_id: proto
+                .collection_id
+                .into_rust_if_some("ProtoDataflowMemoryLimitStatus::collection_id")?,
+            worker_id: proto.worker_id.into_rust()?,
+            heap_size: proto.heap_size,
+            limit: proto.limit,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_ok;
@@ -291,11 +291,15 @@ where
                 let output_frontier = frontiers
                     .output_frontier
                     .and_then(|f| tracked.update_output_frontier(shard_id, &f));
+                let checkpoint_frontier = frontiers
+                    .checkpoint_frontier
+                    .and_then(|f| tracked.update_checkpoint_frontier(shard_id, &f));
 
                 let frontiers = FrontiersResponse {
                     write_frontier,
                     input_frontier,
                     output_frontier,
+                    checkpoint_frontier,
                 };
                 let result = frontiers
                     .has_updates()
@@ -492,6 +496,11 @@ struct TrackedFrontiers<T> {
     input_frontier: (MutableAntichain<T>, Vec<Antichain<T>>),
     /// The tracked output frontier.
     output_frontier: (MutableAntichain<T>, Vec<Antichain<T>>),
+    /// The tracked checkpoint frontier.
+    ///
+    /// A checkpoint is only complete once all partitions have written their parts of it, so the
+    /// checkpoint frontier only advances once all partitions have reported it.
+    checkpoint_frontier: (MutableAntichain<T>, Vec<Antichain<T>>),
 }
 
 impl<T> TrackedFrontiers<T>
@@ -512,11 +521,14 @@ where
         Self {
             write_frontier: frontier_entry.clone(),
             input_frontier: frontier_entry.clone(),
-            output_frontier: frontier_entry,
+            output_frontier: frontier_entry.clone(),
+            checkpoint_frontier: frontier_entry,
         }
     }
 
     /// Returns whether all tracked frontiers have advanced to the empty frontier.
+    ///
+    /// The checkpoint frontier is not considered, as it is not reported for all collections.
     fn all_empty(&self) -> bool {
         self.write_frontier.0.frontier().is_empty()
             && self.input_frontier.0.frontier().is_empty()
@@ -556,6 +568,18 @@ where
         Self::update_frontier(&mut self.output_frontier, shard_id, new_shard_frontier)
     }
 
+    /// Updates checkpoint frontier tracking with a new shard frontier.
+    ///
+    /// If this causes the global checkpoint frontier to advance, the advanced frontier is
+    /// returned.
+    fn update_checkpoint_frontier(
+        &mut self,
+        shard_id: usize,
+        new_shard_frontier: &Antichain<T>,
+    ) -> Option<Antichain<T>> {
+        Self::update_frontier(&mut self.checkpoint_frontier, shard_id, new_shard_frontier)
+    }
+
     /// Updates the provided frontier entry with a new shard frontier.
     fn update_frontier(
         entry: &mut (MutableAntichain<T>, Vec<Antichain<T>>),
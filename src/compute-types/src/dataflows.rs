@@ -181,7 +181,35 @@ impl<T> DataflowDescription<OptimizedMirRelationExpr, (), T> {
             (
                 SourceInstanceDesc {
                     storage_metadata: (),
-                    arguments: SourceInstanceArguments { operators: None },
+                    arguments: SourceInstanceArguments {
+                        operators: None,
+                        persisted_index: None,
+                    },
+                    typ,
+                },
+                monotonic,
+            ),
+        );
+    }
+
+    /// Imports the persisted index `id`, making the collection it is on available arranged by
+    /// the index key.
+    pub fn import_persisted_index(
+        &mut self,
+        id: GlobalId,
+        desc: IndexDesc,
+        typ: RelationType,
+        monotonic: bool,
+    ) {
+        self.source_imports.insert(
+            id,
+            (
+                SourceInstanceDesc {
+                    storage_metadata: (),
+                    arguments: SourceInstanceArguments {
+                        operators: None,
+                        persisted_index: Some(desc),
+                    },
                     typ,
                 },
                 monotonic,
@@ -224,12 +252,19 @@ impl<T> DataflowDescription<OptimizedMirRelationExpr, (), T> {
         self.objects_to_build.iter().any(|bd| &bd.id == id)
             || self.index_imports.keys().any(|i| i == id)
             || self.source_imports.keys().any(|i| i == id)
+            || self
+                .persisted_index_imports()
+                .any(|(_, desc)| &desc.on_id == id)
     }
 
     /// The number of columns associated with an identifier in the dataflow.
     pub fn arity_of(&self, id: &GlobalId) -> usize {
         for (source_id, (source, _monotonic)) in self.source_imports.iter() {
-            if source_id == id {
+            let imported_id = match &source.arguments.persisted_index {
+                Some(desc) => &desc.on_id,
+                None => source_id,
+            };
+            if imported_id == id {
                 return source.typ.arity();
             }
         }
@@ -271,6 +306,19 @@ impl<T> DataflowDescription<OptimizedMirRelationExpr, (), T> {
 }
 
 impl<P, S, T> DataflowDescription<P, S, T> {
+    /// Iterates over the persisted indexes imported by the dataflow, and their descriptions.
+    pub fn persisted_index_imports(&self) -> impl Iterator<Item = (&GlobalId, &IndexDesc)> {
+        self.source_imports
+            .iter()
+            .filter_map(|(id, (source, _monotonic))| {
+                source
+                    .arguments
+                    .persisted_index
+                    .as_ref()
+                    .map(|desc| (id, desc))
+            })
+    }
+
     /// Sets the `as_of` frontier to the supplied argument.
     ///
     /// This method allows the dataflow to indicate a frontier up through
@@ -340,6 +388,7 @@ impl<P, S, T> DataflowDescription<P, S, T> {
             .filter_map(|(id, desc)| match desc.connection {
                 ComputeSinkConnection::MaterializedView(_) => Some(*id),
                 ComputeSinkConnection::ContinualTask(_) => Some(*id),
+                ComputeSinkConnection::PersistedIndex(_) => Some(*id),
                 _ => None,
             })
     }
@@ -435,6 +484,15 @@ where
             out.insert(collection_id);
             return;
         }
+        if let Some((index_id, _)) = self
+            .persisted_index_imports()
+            .find(|(_, desc)| desc.on_id == collection_id)
+        {
+            // The collection is provided by an imported persisted index. Report the
+            // dependency on the index.
+            out.insert(*index_id);
+            return;
+        }
 
         // NOTE(benesch): we're not smart enough here to know *which* index
         // for the collection will be used, if one exists, so we have to report
@@ -45,6 +45,27 @@ pub const ENABLE_LGALLOC_EAGER_RECLAMATION: Config<bool> = Config::new(
     "Enable lgalloc's eager return behavior.",
 );
 
+/// Enable spilling large arrangement batches to the scratch directory.
+pub const ENABLE_ARRANGEMENT_SPILL: Config<bool> = Config::new(
+    "enable_compute_arrangement_spill",
+    false,
+    "Enable spilling large arrangement batches to local disk.",
+);
+
+/// The minimum size of a merged arrangement batch to spill it to disk.
+pub const ARRANGEMENT_SPILL_THRESHOLD_BYTES: Config<usize> = Config::new(
+    "compute_arrangement_spill_threshold_bytes",
+    64 << 20,
+    "The minimum size in bytes of a merged arrangement batch to spill it to local disk.",
+);
+
+/// The number of spilled arrangement bytes each worker keeps resident in memory.
+pub const ARRANGEMENT_SPILL_CACHE_BYTES: Config<usize> = Config::new(
+    "compute_arrangement_spill_cache_bytes",
+    256 << 20,
+    "The number of spilled arrangement bytes each worker keeps resident in memory.",
+);
+
 /// Enable the chunked stack implementation.
 pub const ENABLE_CHUNKED_STACK: Config<bool> = Config::new(
     "enable_compute_chunked_stack",
@@ -78,6 +99,17 @@ pub const DATAFLOW_MAX_INFLIGHT_BYTES_CC: Config<Option<usize>> = Config::new(
      compute dataflows in cc clusters.",
 );
 
+/// Maximum arrangement heap size of a single dataflow on a replica.
+///
+/// The limit is split evenly across the workers of a replica. A dataflow whose arrangements
+/// exceed their share on any worker is paused, and queries depending on it fail.
+pub const DATAFLOW_MAX_HEAP_SIZE: Config<Option<usize>> = Config::new(
+    "compute_dataflow_max_heap_size",
+    None,
+    "The maximum number of bytes the arrangements of a single compute dataflow may occupy \
+     on a replica before the dataflow is paused.",
+);
+
 /// The interval at which the background thread wakes.
 pub const LGALLOC_BACKGROUND_INTERVAL: Config<Duration> = Config::new(
     "lgalloc_background_interval",
@@ -142,6 +174,24 @@ pub const COMPUTE_REPLICA_EXPIRATION_OFFSET: Config<Duration> = Config::new(
     "The expiration time offset for replicas. Zero disables expiration.",
 );
 
+/// Enable periodic checkpointing of index arrangements to blob storage, and hydration of
+/// replicas from these checkpoints.
+pub const ENABLE_ARRANGEMENT_CHECKPOINTS: Config<bool> = Config::new(
+    "enable_compute_arrangement_checkpoints",
+    false,
+    "Enable checkpointing index arrangements to blob storage and hydrating from checkpoints.",
+);
+
+/// The interval at which index arrangements are checkpointed.
+///
+/// Checkpoint times are aligned to multiples of this interval, so all workers and replicas
+/// checkpoint an index at the same times.
+pub const ARRANGEMENT_CHECKPOINT_INTERVAL: Config<Duration> = Config::new(
+    "compute_arrangement_checkpoint_interval",
+    Duration::from_secs(5 * 60),
+    "The interval at which index arrangements are checkpointed to blob storage.",
+);
+
 /// Adds the full set of all compute `Config`s.
 pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
     configs
@@ -149,10 +199,14 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&LINEAR_JOIN_YIELDING)
         .add(&ENABLE_COLUMNATION_LGALLOC)
         .add(&ENABLE_LGALLOC_EAGER_RECLAMATION)
+        .add(&ENABLE_ARRANGEMENT_SPILL)
+        .add(&ARRANGEMENT_SPILL_THRESHOLD_BYTES)
+        .add(&ARRANGEMENT_SPILL_CACHE_BYTES)
         .add(&ENABLE_CHUNKED_STACK)
         .add(&COMPUTE_SERVER_MAINTENANCE_INTERVAL)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES_CC)
+        .add(&DATAFLOW_MAX_HEAP_SIZE)
         .add(&LGALLOC_BACKGROUND_INTERVAL)
         .add(&LGALLOC_SLOW_CLEAR_BYTES)
         .add(&HYDRATION_CONCURRENCY)
@@ -161,4 +215,6 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&COPY_TO_S3_MULTIPART_PART_SIZE_BYTES)
         .add(&ENABLE_COMPUTE_REPLICA_EXPIRATION)
         .add(&COMPUTE_REPLICA_EXPIRATION_OFFSET)
+        .add(&ENABLE_ARRANGEMENT_CHECKPOINTS)
+        .add(&ARRANGEMENT_CHECKPOINT_INTERVAL)
 }
@@ -365,6 +365,24 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for Plan {
                             write!(f, " offset={}", plan.offset)?;
                         }
                     }
+                    TopKPlan::Bounded(plan) => {
+                        write!(f, "{}TopK::Bounded", ctx.indent)?;
+                        if plan.group_key.len() > 0 {
+                            let group_by = mode.seq(&plan.group_key, None);
+                            let group_by = CompactScalars(group_by);
+                            write!(f, " group_by=[{}]", group_by)?;
+                        }
+                        if plan.order_key.len() > 0 {
+                            let order_by = mode.seq(&plan.order_key, None);
+                            let order_by = separated(", ", order_by);
+                            write!(f, " order_by=[{}]", order_by)?;
+                        }
+                        write!(f, " limit={}", plan.limit)?;
+                        if &plan.offset > &0 {
+                            write!(f, " offset={}", plan.offset)?;
+                        }
+                        write!(f, " buffer={}", plan.buffer)?;
+                    }
                 }
                 writeln!(f, "{}", annotations)?;
                 ctx.indented(|ctx| input.fmt_text(f, ctx))?;
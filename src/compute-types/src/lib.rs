@@ -16,6 +16,7 @@ use std::time::Duration;
 pub mod dataflows;
 pub mod dyncfgs;
 pub mod explain;
+pub mod persisted_index;
 pub mod plan;
 pub mod sinks;
 pub mod sources;
@@ -820,7 +820,14 @@ impl<T: timely::progress::Timestamp> Plan<T> {
             let monotonic_ids = dataflow
                 .source_imports
                 .iter()
-                .filter_map(|(id, (_, monotonic))| if *monotonic { Some(id) } else { None })
+                .filter(|(_id, (_source, monotonic))| *monotonic)
+                .map(|(id, (source, _monotonic))| {
+                    // Persisted indexes provide the collection they are on.
+                    match &source.arguments.persisted_index {
+                        Some(desc) => &desc.on_id,
+                        None => id,
+                    }
+                })
                 .chain(
                     dataflow
                         .index_imports
@@ -878,6 +885,10 @@ impl<T: timely::progress::Timestamp> Plan<T> {
         // Extract MFPs from Get operators for sources, and extract what we can for the source.
         // For each source, we want to find `&mut MapFilterProject` for each `Get` expression.
         for (source_id, (source, _monotonic)) in dataflow.source_imports.iter_mut() {
+            // Persisted indexes are read as arrangements, to which no MFP can be pushed.
+            if source.arguments.persisted_index.is_some() {
+                continue;
+            }
             let mut identity_present = false;
             let mut mfps = Vec::new();
             for build_desc in dataflow.objects_to_build.iter_mut() {
@@ -676,6 +676,58 @@ impl<T> FlatPlan<T> {
         }
     }
 
+    /// Return whether the plan is linear.
+    ///
+    /// The output of a linear plan at any time is determined by the changes of its inputs at that
+    /// time: it contains only stateless operators, no constants, and no temporal expressions.
+    /// Arrangements in a linear plan do not contribute to its output other than by passing on
+    /// the changes of their inputs.
+    pub fn is_linear(&self) -> bool {
+        fn is_temporal(mfp: &MapFilterProject) -> bool {
+            mfp.expressions.iter().any(|e| e.contains_temporal())
+                || mfp.predicates.iter().any(|(_, p)| p.contains_temporal())
+        }
+
+        self.steps.values().all(|step| match &step.node {
+            FlatPlanNode::Get { plan, .. } => match plan {
+                GetPlan::PassArrangements => true,
+                GetPlan::Arrangement(_, _, mfp) | GetPlan::Collection(mfp) => !is_temporal(mfp),
+            },
+            FlatPlanNode::Let { .. } | FlatPlanNode::Negate { .. } | FlatPlanNode::Union { .. } => {
+                true
+            }
+            FlatPlanNode::Mfp { mfp, .. } => !is_temporal(mfp),
+            FlatPlanNode::FlatMap {
+                exprs, mfp_after, ..
+            } => !exprs.iter().any(|e| e.contains_temporal()) && !is_temporal(mfp_after),
+            FlatPlanNode::ArrangeBy { input_mfp, .. } => !is_temporal(input_mfp),
+            FlatPlanNode::Constant { .. }
+            | FlatPlanNode::LetRec { .. }
+            | FlatPlanNode::Join { .. }
+            | FlatPlanNode::Reduce { .. }
+            | FlatPlanNode::TopK { .. }
+            | FlatPlanNode::Threshold { .. } => false,
+        })
+    }
+
+    /// Removes the arrangement by `key` from the forms of the root node, if it is an `ArrangeBy`
+    /// that produces such an arrangement, and requests the unarranged form instead.
+    ///
+    /// Returns whether the arrangement was removed.
+    pub fn take_root_arrangement(&mut self, key: &[MirScalarExpr]) -> bool {
+        let root = &mut self.steps.get_mut(&self.root).expect("invariant (1)").node;
+        let FlatPlanNode::ArrangeBy { forms, .. } = root else {
+            return false;
+        };
+        let len = forms.arranged.len();
+        forms.arranged.retain(|(k, _, _)| k != key);
+        if forms.arranged.len() == len {
+            return false;
+        }
+        forms.raw = true;
+        true
+    }
+
     /// Enumerate all identifiers referenced in `Get` operators.
     pub fn depends(&self) -> BTreeSet<Id> {
         self.steps
@@ -983,6 +1035,7 @@ impl<'a, T> std::fmt::Display for FlatPlanNodeHumanizer<'a, T> {
                         write!(f, "MonotonicTopK")?
                     }
                     TopKPlan::Basic(..) => write!(f, "Basic")?,
+                    TopKPlan::Bounded(..) => write!(f, "Bounded")?,
                 };
                 write!(f, " {input}")
             }
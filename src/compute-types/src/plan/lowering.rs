@@ -39,6 +39,8 @@ pub(super) struct Context {
     enable_reduce_mfp_fusion: bool,
     /// Whether to fuse `Reduce` with `FlatMap UnnestList` for better window function performance.
     enable_reduce_unnest_list_fusion: bool,
+    /// The buffer to use for bounded TopK plans, or `None` if they are disabled.
+    bounded_top_k_buffer: Option<usize>,
 }
 
 impl Context {
@@ -52,6 +54,9 @@ impl Context {
             },
             enable_reduce_mfp_fusion: features.enable_reduce_mfp_fusion,
             enable_reduce_unnest_list_fusion: features.enable_reduce_unnest_list_fusion,
+            bounded_top_k_buffer: features
+                .enable_bounded_top_k
+                .then_some(features.bounded_top_k_buffer),
         }
     }
 
@@ -95,7 +100,19 @@ impl Context {
             index_keys.arranged.push((key, permutation, thinning));
             index_keys.types = Some(typ.column_types.clone());
         }
-        for id in desc.source_imports.keys() {
+        for (id, (source, _monotonic)) in desc.source_imports.iter() {
+            // Persisted indexes provide arranged forms of the collection they are on.
+            if let Some(index_desc) = &source.arguments.persisted_index {
+                let key = index_desc.key.clone();
+                let (permutation, thinning) = permutation_for_arrangement(&key, source.typ.arity());
+                let index_keys = self
+                    .arrangements
+                    .entry(Id::Global(index_desc.on_id))
+                    .or_insert_with(AvailableCollections::default);
+                index_keys.arranged.push((key, permutation, thinning));
+                index_keys.types = Some(source.typ.column_types.clone());
+                continue;
+            }
             self.arrangements
                 .entry(Id::Global(*id))
                 .or_insert_with(AvailableCollections::new_raw);
@@ -734,6 +751,7 @@ This is not expected to cause incorrect results, but could indicate a performanc
                     arity,
                     *monotonic,
                     *expected_group_size,
+                    self.bounded_top_k_buffer,
                 );
 
                 // We don't have an MFP here -- install an operator to permute the
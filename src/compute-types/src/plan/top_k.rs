@@ -16,9 +16,12 @@
 //! * A [MonotonicTop1Plan] maintains a single row per key and is suitable for monotonic inputs.
 //! * A [MonotonicTopKPlan] maintains up to K rows per key and is suitable for monotonic inputs.
 //! * A [BasicTopKPlan] maintains up to K rows per key and can handle retractions.
+//! * A [BoundedTopKPlan] maintains up to K plus a buffer of rows per key and can handle
+//!   retractions, recomputing from its input when the buffer underflows.
 
-use mz_expr::ColumnOrder;
+use mz_expr::{ColumnOrder, MirScalarExpr};
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
+use mz_repr::{Datum, ScalarType};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +38,8 @@ pub enum TopKPlan {
     MonotonicTopK(MonotonicTopKPlan),
     /// A plan for generic TopK operations.
     Basic(BasicTopKPlan),
+    /// A plan for TopK operations with a literal limit that bounds the state kept per group.
+    Bounded(BoundedTopKPlan),
 }
 
 impl TopKPlan {
@@ -48,6 +53,8 @@ impl TopKPlan {
     /// * `arity` - The number of columns in the input and output.
     /// * `monotonic` - `true` if the input is monotonic.
     /// * `expected_group_size` - A hint about how many rows will have the same group key.
+    /// * `bounded_buffer` - If set, the number of rows beyond `offset + limit` to retain per
+    ///   group when rendering a [BoundedTopKPlan]. Only applies to literal limits.
     pub(crate) fn create_from(
         group_key: Vec<usize>,
         order_key: Vec<ColumnOrder>,
@@ -56,6 +63,7 @@ impl TopKPlan {
         arity: usize,
         monotonic: bool,
         expected_group_size: Option<u64>,
+        bounded_buffer: Option<usize>,
     ) -> Self {
         // Capture whether the limit is a literal integer first.
         let limit_as_int64 = limit.as_ref().and_then(|l| l.as_literal_int64());
@@ -82,6 +90,21 @@ impl TopKPlan {
                 arity,
                 must_consolidate: false,
             })
+        } else if let (Some(buffer), Some(limit)) = (
+            bounded_buffer,
+            limit_as_int64.and_then(|l| usize::try_from(l).ok()),
+        ) {
+            // With a literal limit, we can bound the number of rows maintained per group, at
+            // the expense of recomputing groups from the input when retractions exhaust the
+            // buffer.
+            TopKPlan::Bounded(BoundedTopKPlan {
+                group_key,
+                order_key,
+                offset,
+                limit,
+                arity,
+                buffer,
+            })
         } else {
             // A plan for all other inputs
             TopKPlan::Basic(BasicTopKPlan {
@@ -121,6 +144,29 @@ impl TopKPlan {
             TopKPlan::MonotonicTop1(plan) => {
                 plan.must_consolidate = must_consolidate;
             }
+            TopKPlan::Bounded(plan) => {
+                if plan.offset == 0 {
+                    *self = if plan.limit == 1 {
+                        TopKPlan::MonotonicTop1(MonotonicTop1Plan {
+                            group_key: plan.group_key.clone(),
+                            order_key: plan.order_key.clone(),
+                            must_consolidate,
+                        })
+                    } else {
+                        let limit = i64::try_from(plan.limit).expect("limit originates from i64");
+                        TopKPlan::MonotonicTopK(MonotonicTopKPlan {
+                            group_key: plan.group_key.clone(),
+                            order_key: plan.order_key.clone(),
+                            limit: Some(MirScalarExpr::literal_ok(
+                                Datum::Int64(limit),
+                                ScalarType::Int64,
+                            )),
+                            arity: plan.arity,
+                            must_consolidate,
+                        })
+                    }
+                }
+            }
             TopKPlan::MonotonicTopK(plan) => {
                 plan.must_consolidate = must_consolidate;
             }
@@ -137,6 +183,7 @@ impl RustType<ProtoTopKPlan> for TopKPlan {
                 TopKPlan::Basic(plan) => Some(Basic(plan.into_proto())),
                 TopKPlan::MonotonicTop1(plan) => Some(MonotonicTop1(plan.into_proto())),
                 TopKPlan::MonotonicTopK(plan) => Some(MonotonicTopK(plan.into_proto())),
+                TopKPlan::Bounded(plan) => Some(Bounded(plan.into_proto())),
             },
         }
     }
@@ -148,6 +195,7 @@ impl RustType<ProtoTopKPlan> for TopKPlan {
             Some(Basic(plan)) => Ok(TopKPlan::Basic(plan.into_rust()?)),
             Some(MonotonicTop1(plan)) => Ok(TopKPlan::MonotonicTop1(plan.into_rust()?)),
             Some(MonotonicTopK(plan)) => Ok(TopKPlan::MonotonicTopK(plan.into_rust()?)),
+            Some(Bounded(plan)) => Ok(TopKPlan::Bounded(plan.into_rust()?)),
             None => Err(TryFromProtoError::missing_field("ProtoTopKPlan::kind")),
         }
     }
@@ -291,6 +339,72 @@ impl RustType<ProtoBasicTopKPlan> for BasicTopKPlan {
     }
 }
 
+/// A plan for TopKs with a literal limit that keep a bounded number of rows per group.
+///
+/// Rather than maintaining the full input of each group in a hierarchy of reductions, the
+/// rendered operator keeps the first `offset + limit + buffer` rows of each group, in order.
+/// Insertions beyond the buffered rows are discarded, and retractions that leave fewer than
+/// `offset + limit` buffered rows of a group that has discarded rows cause the group to be
+/// recomputed from the arranged input.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BoundedTopKPlan {
+    /// The columns that form the key for each group.
+    pub group_key: Vec<usize>,
+    /// Ordering that is used within each group.
+    pub order_key: Vec<mz_expr::ColumnOrder>,
+    /// A lower bound on the per-group ordinal position of the records to
+    /// produce from each group.
+    pub offset: usize,
+    /// An upper bound on the number of records to produce from each group.
+    pub limit: usize,
+    /// The number of columns in the input and output.
+    pub arity: usize,
+    /// The number of records beyond `offset + limit` to retain per group.
+    pub buffer: usize,
+}
+
+impl BoundedTopKPlan {
+    /// Returns an equivalent [BasicTopKPlan], for contexts that cannot render bounded TopKs.
+    pub fn as_basic(&self) -> BasicTopKPlan {
+        let limit = i64::try_from(self.limit).expect("limit originates from i64");
+        BasicTopKPlan {
+            group_key: self.group_key.clone(),
+            order_key: self.order_key.clone(),
+            limit: Some(MirScalarExpr::literal_ok(
+                Datum::Int64(limit),
+                ScalarType::Int64,
+            )),
+            offset: self.offset,
+            arity: self.arity,
+            buckets: bucketing_of_expected_group_size(None),
+        }
+    }
+}
+
+impl RustType<ProtoBoundedTopKPlan> for BoundedTopKPlan {
+    fn into_proto(&self) -> ProtoBoundedTopKPlan {
+        ProtoBoundedTopKPlan {
+            group_key: self.group_key.into_proto(),
+            order_key: self.order_key.into_proto(),
+            offset: self.offset.into_proto(),
+            limit: self.limit.into_proto(),
+            arity: self.arity.into_proto(),
+            buffer: self.buffer.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoBoundedTopKPlan) -> Result<Self, TryFromProtoError> {
+        Ok(BoundedTopKPlan {
+            group_key: proto.group_key.into_rust()?,
+            order_key: proto.order_key.into_rust()?,
+            offset: proto.offset.into_rust()?,
+            limit: proto.limit.into_rust()?,
+            arity: proto.arity.into_rust()?,
+            buffer: proto.buffer.into_rust()?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_ok;
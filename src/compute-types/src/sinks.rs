@@ -9,6 +9,7 @@
 
 //! Types for describing dataflow sinks.
 
+use mz_expr::MirScalarExpr;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{GlobalId, RelationDesc, Timestamp};
@@ -124,6 +125,8 @@ pub enum ComputeSinkConnection<S: 'static = ()> {
     ContinualTask(ContinualTaskConnection<S>),
     /// A compute sink to do a oneshot copy to s3.
     CopyToS3Oneshot(CopyToS3OneshotSinkConnection),
+    /// A compute sink writing an arrangement to persist, for import by other dataflows.
+    PersistedIndex(PersistedIndexSinkConnection<S>),
 }
 
 impl<S> ComputeSinkConnection<S> {
@@ -134,6 +137,7 @@ impl<S> ComputeSinkConnection<S> {
             ComputeSinkConnection::MaterializedView(_) => "materialized_view",
             ComputeSinkConnection::ContinualTask(_) => "continual_task",
             ComputeSinkConnection::CopyToS3Oneshot(_) => "copy_to_s3_oneshot",
+            ComputeSinkConnection::PersistedIndex(_) => "persisted_index",
         }
     }
 
@@ -162,6 +166,9 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
                 ComputeSinkConnection::CopyToS3Oneshot(s3) => {
                     Kind::CopyToS3Oneshot(s3.into_proto())
                 }
+                ComputeSinkConnection::PersistedIndex(persisted_index) => {
+                    Kind::PersistedIndex(persisted_index.into_proto())
+                }
             }),
         }
     }
@@ -180,6 +187,9 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
                 ComputeSinkConnection::ContinualTask(continual_task.into_rust()?)
             }
             Kind::CopyToS3Oneshot(s3) => ComputeSinkConnection::CopyToS3Oneshot(s3.into_rust()?),
+            Kind::PersistedIndex(persisted_index) => {
+                ComputeSinkConnection::PersistedIndex(persisted_index.into_rust()?)
+            }
         })
     }
 }
@@ -260,6 +270,39 @@ impl RustType<ProtoMaterializedViewSinkConnection>
     }
 }
 
+/// Information necessary for rendering a persisted index sink.
+///
+/// The sink writes the arrangement of its input by `key` to the collection described by
+/// `storage_metadata`, in the layout described by [`crate::persisted_index`].
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PersistedIndexSinkConnection<S> {
+    /// The expressions by which the input is arranged.
+    #[proptest(strategy = "proptest::collection::vec(any::<MirScalarExpr>(), 0..3)")]
+    pub key: Vec<MirScalarExpr>,
+    /// The necessary storage information for writing to the output collection.
+    pub storage_metadata: S,
+}
+
+impl RustType<ProtoPersistedIndexSinkConnection>
+    for PersistedIndexSinkConnection<CollectionMetadata>
+{
+    fn into_proto(&self) -> ProtoPersistedIndexSinkConnection {
+        ProtoPersistedIndexSinkConnection {
+            key: self.key.into_proto(),
+            storage_metadata: Some(self.storage_metadata.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoPersistedIndexSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PersistedIndexSinkConnection {
+            key: proto.key.into_rust()?,
+            storage_metadata: proto
+                .storage_metadata
+                .into_rust_if_some("ProtoPersistedIndexSinkConnection::storage_metadata")?,
+        })
+    }
+}
+
 /// ContinualTask-specific information necessary for rendering a ContinualTask
 /// sink. (Shared-sink information is instead stored on ComputeSinkConnection.)
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
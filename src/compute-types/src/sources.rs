@@ -12,9 +12,12 @@
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::RelationType;
 use mz_storage_types::controller::CollectionMetadata;
+use proptest::prelude::any;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
+use crate::dataflows::IndexDesc;
+
 include!(concat!(env!("OUT_DIR"), "/mz_compute_types.sources.rs"));
 
 /// A description of an instantiation of a source.
@@ -61,18 +64,41 @@ impl RustType<ProtoSourceInstanceDesc> for SourceInstanceDesc<CollectionMetadata
 pub struct SourceInstanceArguments {
     /// Linear operators to be applied record-by-record.
     pub operators: Option<mz_expr::MapFilterProject>,
+    /// If set, the source is a persisted index, and is imported as an arrangement of the indexed
+    /// collection instead of as a collection of its own.
+    ///
+    /// Persisted indexes are written by [`crate::sinks::PersistedIndexSinkConnection`], in the
+    /// layout described by [`crate::persisted_index`].
+    #[proptest(strategy = "proptest::option::of(any::<IndexDesc>())")]
+    pub persisted_index: Option<IndexDesc>,
 }
 
 impl RustType<ProtoSourceInstanceArguments> for SourceInstanceArguments {
     fn into_proto(&self) -> ProtoSourceInstanceArguments {
         ProtoSourceInstanceArguments {
             operators: self.operators.into_proto(),
+            persisted_index: self
+                .persisted_index
+                .as_ref()
+                .map(|desc| ProtoPersistedIndex {
+                    on_id: Some(desc.on_id.into_proto()),
+                    key: desc.key.into_proto(),
+                }),
         }
     }
 
     fn from_proto(proto: ProtoSourceInstanceArguments) -> Result<Self, TryFromProtoError> {
         Ok(SourceInstanceArguments {
             operators: proto.operators.into_rust()?,
+            persisted_index: proto
+                .persisted_index
+                .map(|desc| {
+                    Ok::<_, TryFromProtoError>(IndexDesc {
+                        on_id: desc.on_id.into_rust_if_some("ProtoPersistedIndex::on_id")?,
+                        key: desc.key.into_rust()?,
+                    })
+                })
+                .transpose()?,
         })
     }
 }
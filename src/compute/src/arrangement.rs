@@ -9,4 +9,6 @@
 
 //! Types related to the arrangement and management of collections.
 
+pub mod checkpoint;
 pub mod manager;
+pub mod spill;
@@ -0,0 +1,587 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Checkpoints of index arrangements in blob storage.
+//!
+//! Hydrating an index requires reading the full contents of its inputs and recomputing the
+//! arrangement. For indexes whose dataflows are linear, i.e., whose output changes at a time are
+//! determined by the input changes at that time, a replica can instead start from a copy of the
+//! arrangement at some earlier time and replay only the input changes since. This module writes
+//! such copies, and keeps the input changes since the latest one readable.
+//!
+//! Checkpoints are written at times aligned to multiples of the checkpoint interval. Each worker
+//! writes the contents of its part of the arrangement at the checkpoint time to a separate blob.
+//! Once all workers have written their parts, a designated worker writes a marker declaring the
+//! checkpoint complete and deletes older checkpoints. Checkpoints are scoped to the replica that
+//! wrote them, so replicas of the same cluster don't interfere with each other. The keys are laid
+//! out as follows:
+//!
+//! ```text
+//! compute-arrangement-checkpoints/{index_id}/{replica_id}/{time}/{peers}/part-{worker}
+//! compute-arrangement-checkpoints/{index_id}/{replica_id}/{time}/{peers}/complete
+//! ```
+//!
+//! The input changes since the latest checkpoint are kept readable by the controller: each worker
+//! reports the time of the latest checkpoint it has written as the collection's checkpoint
+//! frontier, and the controller holds back the dataflow inputs at the meet of the checkpoint
+//! frontiers of all workers and replicas.
+//!
+//! The restoring side lives in `render::checkpoint`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use differential_dataflow::trace::cursor::IntoOwned;
+use differential_dataflow::trace::{Cursor, TraceReader};
+use differential_dataflow::Hashable;
+use mz_cluster_client::ReplicaId;
+use mz_ore::cast::CastFrom;
+use mz_ore::task::AbortOnDropHandle;
+use mz_ore::url::SensitiveUrl;
+use mz_persist::location::{Blob, ExternalError};
+use mz_persist_client::cache::PersistClientCache;
+use mz_repr::{Diff, GlobalId, Row, RowRef, Timestamp};
+use timely::progress::Antichain;
+use timely::PartialOrder;
+use tokio::sync::oneshot::error::TryRecvError;
+use tokio::sync::{oneshot, watch};
+use tracing::{debug, warn};
+
+use crate::typedefs::{ErrAgent, RowRowAgent};
+
+/// The prefix of all blob keys of arrangement checkpoints.
+const KEY_PREFIX: &str = "compute-arrangement-checkpoints";
+
+/// The version of the checkpoint part format.
+const FORMAT_VERSION: u8 = 1;
+
+/// The interval at which the designated worker looks for newly written checkpoints.
+const COMPLETE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Identifies the checkpoints of an index written by a replica.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CheckpointScope {
+    pub index_id: GlobalId,
+    pub replica_id: ReplicaId,
+}
+
+impl CheckpointScope {
+    /// Returns the prefix of the blob keys of checkpoints of the index, written by any replica.
+    fn index_prefix(&self) -> String {
+        format!("{KEY_PREFIX}/{}/", self.index_id)
+    }
+
+    /// Returns the prefix of the blob keys of checkpoints in this scope.
+    fn prefix(&self) -> String {
+        format!("{}{}/", self.index_prefix(), self.replica_id)
+    }
+
+    /// Returns the blob key of the part written by `worker` for a checkpoint at `time`.
+    pub fn part_key(&self, time: Timestamp, peers: usize, worker: usize) -> String {
+        format!("{}{}/{peers}/part-{worker}", self.prefix(), u64::from(time))
+    }
+
+    /// Returns the blob key of the marker of a complete checkpoint at `time`.
+    fn marker_key(&self, time: Timestamp, peers: usize) -> String {
+        format!("{}{}/{peers}/complete", self.prefix(), u64::from(time))
+    }
+}
+
+/// The blobs of a checkpoint, as found in a listing.
+#[derive(Debug, Default)]
+pub(crate) struct CheckpointListing {
+    /// The workers that have written their parts.
+    pub parts: BTreeSet<usize>,
+    /// Whether the checkpoint has been marked complete.
+    pub complete: bool,
+}
+
+/// Lists the checkpoints in the given scope, keyed by time and number of workers.
+pub(crate) async fn list_checkpoints(
+    blob: &dyn Blob,
+    scope: CheckpointScope,
+) -> Result<BTreeMap<(Timestamp, usize), CheckpointListing>, ExternalError> {
+    let index_id = scope.index_id;
+    let prefix = scope.prefix();
+    let mut keys = Vec::new();
+    blob.list_keys_and_metadata(&prefix, &mut |metadata| {
+        keys.push(metadata.key.to_owned());
+    })
+    .await?;
+
+    let mut checkpoints = BTreeMap::<_, CheckpointListing>::new();
+    for key in keys {
+        let Some(rest) = key.strip_prefix(&prefix) else {
+            continue;
+        };
+        let parsed = || -> Option<(Timestamp, usize, Option<usize>)> {
+            let mut parts = rest.split('/');
+            let time = Timestamp::from(parts.next()?.parse::<u64>().ok()?);
+            let peers = parts.next()?.parse().ok()?;
+            let worker = match parts.next()? {
+                "complete" => None,
+                name => Some(name.strip_prefix("part-")?.parse().ok()?),
+            };
+            parts.next().is_none().then_some((time, peers, worker))
+        };
+        match parsed() {
+            Some((time, peers, Some(worker))) => {
+                let listing = checkpoints.entry((time, peers)).or_default();
+                listing.parts.insert(worker);
+            }
+            Some((time, peers, None)) => {
+                checkpoints.entry((time, peers)).or_default().complete = true;
+            }
+            None => debug!(%index_id, key, "ignoring unexpected checkpoint key"),
+        }
+    }
+    Ok(checkpoints)
+}
+
+/// Encodes the updates of a checkpoint part.
+///
+/// A part consists of a version byte, followed by a sequence of updates. Each update is encoded
+/// as its length-prefixed key and value rows, followed by its diff, with all integers encoded as
+/// big-endian.
+#[derive(Debug)]
+pub(crate) struct PartEncoder {
+    buf: Vec<u8>,
+}
+
+impl PartEncoder {
+    pub fn new() -> Self {
+        Self {
+            buf: vec![FORMAT_VERSION],
+        }
+    }
+
+    pub fn push(&mut self, key: &Row, val: &Row, diff: Diff) {
+        for row in [key, val] {
+            let len = u64::cast_from(row.data().len());
+            self.buf.extend_from_slice(&len.to_be_bytes());
+            self.buf.extend_from_slice(row.data());
+        }
+        self.buf.extend_from_slice(&diff.to_be_bytes());
+    }
+
+    pub fn finish(self) -> Bytes {
+        Bytes::from(self.buf)
+    }
+}
+
+/// Decodes a checkpoint part produced by [`PartEncoder`].
+pub(crate) fn decode_part(mut bytes: &[u8]) -> Result<Vec<((Row, Row), Diff)>, String> {
+    fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+        if bytes.len() < len {
+            return Err(format!(
+                "truncated checkpoint part: expected {len} bytes, found {}",
+                bytes.len()
+            ));
+        }
+        let (head, tail) = bytes.split_at(len);
+        *bytes = tail;
+        Ok(head)
+    }
+    fn take_u64(bytes: &mut &[u8]) -> Result<u64, String> {
+        let head = take(bytes, 8)?;
+        Ok(u64::from_be_bytes(head.try_into().expect("8 bytes")))
+    }
+    fn take_row(bytes: &mut &[u8]) -> Result<Row, String> {
+        let len = take_u64(bytes)?;
+        let len = usize::try_from(len).map_err(|_| format!("invalid row length: {len}"))?;
+        Ok(RowRef::from_slice(take(bytes, len)?).to_owned())
+    }
+
+    match take(&mut bytes, 1)? {
+        [FORMAT_VERSION] => {}
+        [version] => return Err(format!("unknown checkpoint format version: {version}")),
+        _ => unreachable!("took one byte"),
+    }
+
+    let mut updates = Vec::new();
+    while !bytes.is_empty() {
+        let key = take_row(&mut bytes)?;
+        let val = take_row(&mut bytes)?;
+        let diff = Diff::from_be_bytes(take(&mut bytes, 8)?.try_into().expect("8 bytes"));
+        updates.push(((key, val), diff));
+    }
+    Ok(updates)
+}
+
+/// Returns the smallest checkpoint time greater or equal to `time`.
+fn checkpoint_time(time: Timestamp, interval: Duration) -> Timestamp {
+    let interval = u64::try_from(interval.as_millis())
+        .unwrap_or(u64::MAX)
+        .max(1);
+    let time = u64::from(time);
+    let aligned = time.div_ceil(interval).saturating_mul(interval);
+    Timestamp::from(aligned)
+}
+
+/// Periodically writes the contents of an index arrangement maintained by the local worker to
+/// blob storage.
+pub struct ArrangementCheckpointer {
+    scope: CheckpointScope,
+    worker: usize,
+    peers: usize,
+    persist_clients: Arc<PersistClientCache>,
+    blob_uri: SensitiveUrl,
+    /// Handles to the arrangement traces.
+    ///
+    /// Their logical compaction is held at `next`, so the arrangement contents at that time
+    /// remain accessible.
+    oks: RowRowAgent<Timestamp, Diff>,
+    errs: ErrAgent<Timestamp, Diff>,
+    /// The time of the next checkpoint.
+    next: Timestamp,
+    /// The time and result of a checkpoint write in progress.
+    in_flight: Option<(Timestamp, oneshot::Receiver<Result<(), String>>)>,
+    /// The time from which the input changes must remain readable.
+    ///
+    /// This is the time of the latest checkpoint written by this worker, or of the first
+    /// checkpoint it is going to write.
+    hold: Timestamp,
+    /// The `hold` last reported to the controller.
+    reported_hold: Option<Timestamp>,
+    /// The task marking checkpoints complete, on the designated worker.
+    completer: Option<AbortOnDropHandle<()>>,
+}
+
+impl ArrangementCheckpointer {
+    /// Creates a checkpointer for the given index arrangement, which must have been hydrated from
+    /// `as_of`, and starts marking checkpoints complete on the designated worker.
+    pub fn new(
+        scope: CheckpointScope,
+        worker: usize,
+        peers: usize,
+        persist_clients: Arc<PersistClientCache>,
+        blob_uri: SensitiveUrl,
+        mut oks: RowRowAgent<Timestamp, Diff>,
+        mut errs: ErrAgent<Timestamp, Diff>,
+        as_of: Timestamp,
+        interval: Duration,
+        read_only_rx: watch::Receiver<bool>,
+    ) -> Self {
+        let next = checkpoint_time(as_of, interval);
+        oks.set_physical_compaction(Antichain::new().borrow());
+        oks.set_logical_compaction(Antichain::from_elem(next).borrow());
+        errs.set_physical_compaction(Antichain::new().borrow());
+        errs.set_logical_compaction(Antichain::from_elem(next).borrow());
+
+        let index_id = scope.index_id;
+        let completer = (usize::cast_from(index_id.hashed()) % peers == worker).then(|| {
+            let persist_clients = Arc::clone(&persist_clients);
+            let blob_uri = blob_uri.clone();
+            mz_ore::task::spawn(
+                || format!("arrangement_checkpoint_complete({index_id})"),
+                complete_checkpoints(scope, peers, persist_clients, blob_uri, read_only_rx),
+            )
+            .abort_on_drop()
+        });
+
+        Self {
+            scope,
+            worker,
+            peers,
+            persist_clients,
+            blob_uri,
+            oks,
+            errs,
+            next,
+            in_flight: None,
+            hold: next,
+            reported_hold: None,
+            completer,
+        }
+    }
+
+    /// Writes a checkpoint if the arrangement has advanced past the next checkpoint time.
+    ///
+    /// Returns the time of a checkpoint whose write has completed since the last call, if any.
+    pub fn maintain(&mut self, interval: Duration, read_only: bool) -> Option<Timestamp> {
+        let index_id = self.scope.index_id;
+        let mut written = None;
+        if let Some((time, result)) = &mut self.in_flight {
+            match result.try_recv() {
+                Ok(Ok(())) => written = Some(*time),
+                Ok(Err(error)) => {
+                    warn!(%index_id, %time, "failed to write checkpoint: {error}");
+                }
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Closed) => {}
+            }
+            // Release the input changes before the checkpoint, even if we failed to write it.
+            // Otherwise persistent failures would hold back the inputs indefinitely.
+            self.hold = *time;
+            self.in_flight = None;
+        }
+
+        let mut upper = Antichain::new();
+        self.oks.read_upper(&mut upper);
+        // An empty upper means the index will not change anymore, so there is no point in
+        // checkpointing it.
+        let Some(&upper_time) = upper.as_option() else {
+            return written;
+        };
+        if upper_time <= self.next {
+            return written;
+        }
+
+        let time = self.next;
+        if !read_only && self.errors_empty_at(time) {
+            let part = self.encode_at(time);
+            let (tx, rx) = oneshot::channel();
+            let persist_clients = Arc::clone(&self.persist_clients);
+            let blob_uri = self.blob_uri.clone();
+            let key = self.scope.part_key(time, self.peers, self.worker);
+            mz_ore::task::spawn(
+                || format!("arrangement_checkpoint_write({index_id})"),
+                async move {
+                    let result = async {
+                        let blob = persist_clients.open_blob(blob_uri).await?;
+                        blob.set(&key, part).await
+                    };
+                    let _ = tx.send(result.await.map_err(|error| error.to_string()));
+                },
+            );
+            self.in_flight = Some((time, rx));
+        } else {
+            self.hold = time;
+        }
+
+        self.next = checkpoint_time(upper_time, interval);
+        let since = Antichain::from_elem(self.next);
+        self.oks.set_logical_compaction(since.borrow());
+        self.errs.set_logical_compaction(since.borrow());
+
+        written
+    }
+
+    /// Returns the checkpoint frontier to report to the controller, if it has changed since the
+    /// last call.
+    ///
+    /// Read-only replicas don't write checkpoints, so they don't hold back the inputs either.
+    pub fn checkpoint_frontier_update(&mut self, read_only: bool) -> Option<Antichain<Timestamp>> {
+        if read_only || self.reported_hold == Some(self.hold) {
+            return None;
+        }
+        self.reported_hold = Some(self.hold);
+        Some(Antichain::from_elem(self.hold))
+    }
+
+    /// Returns whether the error trace is empty at the given time.
+    ///
+    /// Checkpoints only contain the `oks` arrangement, so we only write them when there are no
+    /// errors to restore.
+    fn errors_empty_at(&mut self, time: Timestamp) -> bool {
+        let (mut cursor, storage) = self.errs.cursor();
+        while cursor.key_valid(&storage) {
+            while cursor.val_valid(&storage) {
+                let mut sum = 0;
+                cursor.map_times(&storage, |t, d| {
+                    if t.less_equal(&time) {
+                        sum += d.into_owned();
+                    }
+                });
+                if sum != 0 {
+                    return false;
+                }
+                cursor.step_val(&storage);
+            }
+            cursor.step_key(&storage);
+        }
+        true
+    }
+
+    /// Encodes the contents of the arrangement at the given time.
+    fn encode_at(&mut self, time: Timestamp) -> Bytes {
+        let mut encoder = PartEncoder::new();
+        let (mut cursor, storage) = self.oks.cursor();
+        while let Some(key) = cursor.get_key(&storage) {
+            let key = key.into_owned();
+            while let Some(val) = cursor.get_val(&storage) {
+                let mut sum = 0;
+                cursor.map_times(&storage, |t, d| {
+                    if t.less_equal(&time) {
+                        sum += d.into_owned();
+                    }
+                });
+                if sum != 0 {
+                    encoder.push(&key, &val.into_owned(), sum);
+                }
+                cursor.step_val(&storage);
+            }
+            cursor.step_key(&storage);
+        }
+        encoder.finish()
+    }
+
+    /// Deletes all checkpoints of the index, in the background.
+    ///
+    /// Called when the index is dropped. Only the designated worker deletes checkpoints. This
+    /// includes checkpoints written by other replicas, which would otherwise be leaked once the
+    /// replica that wrote them is dropped.
+    pub fn delete_checkpoints(self) {
+        if self.completer.is_none() {
+            return;
+        }
+        let index_id = self.scope.index_id;
+        let prefix = self.scope.index_prefix();
+        let persist_clients = Arc::clone(&self.persist_clients);
+        let blob_uri = self.blob_uri.clone();
+        mz_ore::task::spawn(
+            || format!("arrangement_checkpoint_delete({index_id})"),
+            async move {
+                let result = async {
+                    let blob = persist_clients.open_blob(blob_uri).await?;
+                    let mut keys = Vec::new();
+                    blob.list_keys_and_metadata(&prefix, &mut |metadata| {
+                        keys.push(metadata.key.to_owned());
+                    })
+                    .await?;
+                    for key in keys {
+                        blob.delete(&key).await?;
+                    }
+                    Ok::<_, ExternalError>(())
+                };
+                if let Err(error) = result.await {
+                    warn!(%index_id, "failed to delete checkpoints: {error}");
+                }
+            },
+        );
+    }
+}
+
+/// Marks checkpoints in the given scope complete once all workers have written their parts, and
+/// deletes older checkpoints.
+async fn complete_checkpoints(
+    scope: CheckpointScope,
+    peers: usize,
+    persist_clients: Arc<PersistClientCache>,
+    blob_uri: SensitiveUrl,
+    mut read_only_rx: watch::Receiver<bool>,
+) {
+    let index_id = scope.index_id;
+
+    // Read-only replicas must not modify durable state.
+    if read_only_rx.wait_for(|read_only| !read_only).await.is_err() {
+        return;
+    }
+
+    let blob = match persist_clients.open_blob(blob_uri).await {
+        Ok(blob) => blob,
+        Err(error) => {
+            warn!(%index_id, "failed to open checkpoint blob: {error}");
+            return;
+        }
+    };
+
+    let mut completed = None;
+    let mut interval = tokio::time::interval(COMPLETE_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        let checkpoints = match list_checkpoints(&*blob, scope).await {
+            Ok(checkpoints) => checkpoints,
+            Err(error) => {
+                warn!(%index_id, "failed to list checkpoints: {error}");
+                continue;
+            }
+        };
+
+        // The latest checkpoint written by all workers of this replica.
+        let latest = checkpoints
+            .iter()
+            .rev()
+            .find(|((_, p), listing)| *p == peers && listing.parts.len() == peers)
+            .map(|((time, _), listing)| (*time, listing.complete));
+
+        let Some((time, complete)) = latest else {
+            continue;
+        };
+        if completed >= Some(time) {
+            continue;
+        }
+
+        if !complete {
+            if let Err(error) = blob.set(&scope.marker_key(time, peers), Bytes::new()).await {
+                warn!(%index_id, %time, "failed to mark checkpoint complete: {error}");
+                continue;
+            }
+        }
+        completed = Some(time);
+
+        // Delete older checkpoints of this replica size, marker first so that they are not
+        // considered complete anymore.
+        for ((old_time, old_peers), listing) in &checkpoints {
+            if *old_peers != peers || *old_time >= time {
+                continue;
+            }
+            let markers = listing.complete.then(|| scope.marker_key(*old_time, peers));
+            let parts = listing
+                .parts
+                .iter()
+                .map(|worker| scope.part_key(*old_time, peers, *worker));
+            for key in markers.into_iter().chain(parts) {
+                if let Err(error) = blob.delete(&key).await {
+                    warn!(%index_id, key, "failed to delete checkpoint: {error}");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::Datum;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_part_roundtrip() {
+        let updates = vec![
+            ((Row::default(), Row::default()), 1),
+            (
+                (
+                    Row::pack_slice(&[Datum::Int64(7)]),
+                    Row::pack_slice(&[Datum::String("seven"), Datum::Null]),
+                ),
+                -3,
+            ),
+            (
+                (Row::pack_slice(&[Datum::String("abc")]), Row::default()),
+                i64::MAX,
+            ),
+        ];
+
+        let mut encoder = PartEncoder::new();
+        for ((key, val), diff) in &updates {
+            encoder.push(key, val, *diff);
+        }
+        let bytes = encoder.finish();
+        assert_eq!(decode_part(&bytes), Ok(updates));
+
+        // Truncated parts and unknown versions are rejected.
+        assert!(decode_part(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode_part(&[FORMAT_VERSION + 1]).is_err());
+        assert_eq!(decode_part(&[FORMAT_VERSION]), Ok(vec![]));
+    }
+
+    #[mz_ore::test]
+    fn test_checkpoint_time() {
+        let interval = Duration::from_millis(1000);
+        assert_eq!(checkpoint_time(0.into(), interval), 0.into());
+        assert_eq!(checkpoint_time(1.into(), interval), 1000.into());
+        assert_eq!(checkpoint_time(1000.into(), interval), 1000.into());
+        assert_eq!(checkpoint_time(1001.into(), interval), 2000.into());
+        assert_eq!(checkpoint_time(5.into(), Duration::ZERO), 5.into());
+    }
+}
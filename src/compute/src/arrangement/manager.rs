@@ -65,6 +65,8 @@ impl TraceManager {
             bundle.errs.set_physical_compaction(antichain.borrow());
         }
 
+        crate::arrangement::spill::report_metrics(&self.metrics);
+
         let duration = start.elapsed().as_secs_f64();
         self.metrics.maintenance_seconds_total.inc_by(duration);
         self.metrics.maintenance_active_info.set(0);
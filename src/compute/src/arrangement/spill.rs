@@ -0,0 +1,377 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Spilling of large arrangement batches to local disk.
+//!
+//! Arrangements maintain their contents as a logarithmic number of batches, most of which are
+//! large, old, and rarely accessed. When spilling is enabled, containers produced by merging
+//! batches whose combined size exceeds a threshold place their bytes in a memory-mapped file in
+//! the scratch directory instead of on the heap.
+//!
+//! Each worker tracks the spilled segments it has touched recently in a `SpillCache`. When the
+//! bytes of resident segments exceed the configured cache size, the least recently used segments
+//! are released with `MADV_DONTNEED`. Released segments stay mapped, and the kernel transparently
+//! reads them back from disk the next time they are accessed. Residency is tracked at the
+//! granularity of whole segments and once per maintenance epoch, which keeps the cost on the
+//! read path to a single comparison in the common case.
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use memmap2::MmapMut;
+use tracing::{debug, warn};
+
+use crate::metrics::TraceMetrics;
+
+thread_local! {
+    /// The spill state of the current worker.
+    static SPILL_STATE: Rc<SpillState> = Rc::new(SpillState::default());
+}
+
+/// Configuration for spilling arrangement batches.
+#[derive(Clone, Debug)]
+pub struct SpillConfig {
+    /// The directory in which to create spill files.
+    pub path: PathBuf,
+    /// The minimum size in bytes of a merged container to spill it to disk.
+    pub threshold_bytes: usize,
+    /// The number of spilled bytes a worker keeps resident before releasing segments.
+    pub cache_bytes: usize,
+}
+
+/// Configure spilling for the current worker.
+///
+/// Passing `None` disables spilling of new batches. Batches that have already been spilled stay
+/// on disk until they are dropped, and continue to be managed by the cache.
+pub fn configure(config: Option<SpillConfig>) {
+    SPILL_STATE.with(|state| {
+        let mut cache = state.cache.borrow_mut();
+        if let Some(config) = &config {
+            cache.capacity_bytes = config.cache_bytes;
+            cache.shrink_to_capacity(None);
+        }
+        *state.config.borrow_mut() = config;
+    });
+}
+
+/// Report the spill statistics of the current worker to `metrics` and start a new epoch.
+pub fn report_metrics(metrics: &TraceMetrics) {
+    SPILL_STATE.with(|state| {
+        state.epoch.set(state.epoch.get() + 1);
+        let mut cache = state.cache.borrow_mut();
+        let stats = std::mem::take(&mut cache.stats);
+        metrics.spill_cache_hits_total.inc_by(stats.hits);
+        metrics.spill_cache_misses_total.inc_by(stats.misses);
+        metrics.spill_cache_evictions_total.inc_by(stats.evictions);
+        metrics
+            .spill_resident_bytes
+            .set(u64::try_from(cache.resident_bytes).expect("must fit"));
+        metrics
+            .spill_bytes
+            .set(u64::try_from(cache.spilled_bytes).expect("must fit"));
+    });
+}
+
+/// Per-worker spill state.
+#[derive(Default)]
+struct SpillState {
+    /// The current spill configuration, or `None` if spilling is disabled.
+    config: RefCell<Option<SpillConfig>>,
+    /// The current epoch, advanced on each maintenance round.
+    epoch: Cell<u64>,
+    /// Residency information about spilled segments.
+    cache: RefCell<SpillCache>,
+}
+
+/// Counters accumulated between two calls to [`report_metrics`].
+#[derive(Debug, Default)]
+struct SpillStats {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+/// Tracks which spilled segments are resident in memory.
+#[derive(Debug, Default)]
+struct SpillCache {
+    /// The number of resident bytes above which segments are released.
+    capacity_bytes: usize,
+    /// The number of bytes in resident segments.
+    resident_bytes: usize,
+    /// The number of bytes in all live segments.
+    spilled_bytes: usize,
+    /// The identifier to assign to the next segment.
+    next_id: u64,
+    /// Resident segments, by identifier.
+    resident: BTreeMap<u64, ResidentSegment>,
+    /// Statistics not yet reported.
+    stats: SpillStats,
+}
+
+/// Information about a resident segment, sufficient to release it.
+#[derive(Debug)]
+struct ResidentSegment {
+    /// The start address of the segment's mapping.
+    ///
+    /// Only valid while the segment is registered in the cache. Segments remove themselves
+    /// from the cache before unmapping.
+    addr: usize,
+    /// The length of the segment's mapping.
+    len: usize,
+    /// The epoch in which the segment was last accessed.
+    last_epoch: u64,
+}
+
+impl SpillCache {
+    /// Record an access to segment `id` in `epoch`, admitting it if not resident.
+    fn touch(&mut self, id: u64, addr: usize, len: usize, epoch: u64) {
+        if let Some(segment) = self.resident.get_mut(&id) {
+            segment.last_epoch = epoch;
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+            self.resident.insert(
+                id,
+                ResidentSegment {
+                    addr,
+                    len,
+                    last_epoch: epoch,
+                },
+            );
+            self.resident_bytes += len;
+            self.shrink_to_capacity(Some(id));
+        }
+    }
+
+    /// Release least recently used segments until the resident bytes fit the capacity.
+    ///
+    /// The segment identified by `keep` is never released.
+    fn shrink_to_capacity(&mut self, keep: Option<u64>) {
+        while self.resident_bytes > self.capacity_bytes {
+            let victim = self
+                .resident
+                .iter()
+                .filter(|(id, _)| Some(**id) != keep)
+                .min_by_key(|(_, segment)| segment.last_epoch)
+                .map(|(id, _)| *id);
+            let Some(victim) = victim else {
+                break;
+            };
+            let segment = self.resident.remove(&victim).expect("must exist");
+            self.resident_bytes -= segment.len;
+            self.stats.evictions += 1;
+            // SAFETY: The segment is still mapped, as segments deregister before unmapping.
+            // `MADV_DONTNEED` on a shared file mapping only drops the pages from our address
+            // space; the contents remain in the file and are faulted back in on access.
+            let ret = unsafe {
+                libc::madvise(
+                    segment.addr as *mut libc::c_void,
+                    segment.len,
+                    libc::MADV_DONTNEED,
+                )
+            };
+            if ret != 0 {
+                debug!(
+                    "failed to release spilled segment: {}",
+                    std::io::Error::last_os_error()
+                );
+            }
+        }
+    }
+}
+
+/// A fixed-capacity byte region backed by a memory-mapped file.
+///
+/// The backing file is unlinked on creation, so its space is reclaimed once the region is dropped.
+pub struct SpillRegion {
+    /// The mapping holding the region's bytes.
+    map: MmapMut,
+    /// The number of bytes written.
+    len: usize,
+    /// The identifier of this region in the cache.
+    id: u64,
+    /// The epoch in which this region was last registered as accessed.
+    last_epoch: Cell<u64>,
+    /// The spill state of the worker that created the region.
+    state: Rc<SpillState>,
+}
+
+impl SpillRegion {
+    /// Allocate a region of `capacity` bytes, if spilling is enabled and `capacity` exceeds the
+    /// configured threshold.
+    ///
+    /// Returns `None` if the region should be allocated on the heap instead, including when
+    /// creating the spill file fails.
+    pub fn try_allocate(capacity: usize) -> Option<Self> {
+        let state = SPILL_STATE.with(Rc::clone);
+        let path = {
+            let config = state.config.borrow();
+            let config = config.as_ref()?;
+            if capacity == 0 || capacity < config.threshold_bytes {
+                return None;
+            }
+            config.path.clone()
+        };
+
+        let map = match Self::map_file(&path, capacity) {
+            Ok(map) => map,
+            Err(err) => {
+                warn!(
+                    ?path,
+                    capacity, "failed to create arrangement spill file: {err}"
+                );
+                return None;
+            }
+        };
+
+        let id = {
+            let mut cache = state.cache.borrow_mut();
+            let id = cache.next_id;
+            cache.next_id += 1;
+            cache.spilled_bytes += map.len();
+            id
+        };
+
+        Some(Self {
+            map,
+            len: 0,
+            id,
+            // Force registration on first access.
+            last_epoch: Cell::new(u64::MAX),
+            state,
+        })
+    }
+
+    fn map_file(path: &std::path::Path, capacity: usize) -> std::io::Result<MmapMut> {
+        let file = tempfile::tempfile_in(path)?;
+        file.set_len(u64::try_from(capacity).expect("must fit"))?;
+        // SAFETY: The file is unlinked and private to this process, so nobody else can modify or
+        // truncate it while mapped.
+        unsafe { MmapMut::map_mut(&file) }
+    }
+
+    /// The number of bytes written to the region.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no bytes have been written to the region.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes the region can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Append `slice` to the region.
+    ///
+    /// Panics if the region does not have sufficient capacity.
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[u8]) {
+        let end = self.len + slice.len();
+        self.touch();
+        self.map[self.len..end].copy_from_slice(slice);
+        self.len = end;
+    }
+
+    /// The bytes written to the region.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.touch();
+        &self.map[..self.len]
+    }
+
+    /// Register an access with the cache, once per epoch.
+    #[inline]
+    fn touch(&self) {
+        let epoch = self.state.epoch.get();
+        if self.last_epoch.get() != epoch {
+            self.last_epoch.set(epoch);
+            self.state.cache.borrow_mut().touch(
+                self.id,
+                self.map.as_ptr() as usize,
+                self.map.len(),
+                epoch,
+            );
+        }
+    }
+}
+
+impl Drop for SpillRegion {
+    fn drop(&mut self) {
+        let mut cache = self.state.cache.borrow_mut();
+        if let Some(segment) = cache.resident.remove(&self.id) {
+            cache.resident_bytes -= segment.len;
+        }
+        cache.spilled_bytes -= self.map.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `mmap`
+    fn test_spill_region_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        configure(Some(SpillConfig {
+            path: dir.path().to_path_buf(),
+            threshold_bytes: 1024,
+            cache_bytes: 8192,
+        }));
+
+        // Below the threshold, regions are allocated on the heap.
+        assert!(SpillRegion::try_allocate(512).is_none());
+
+        let mut a = SpillRegion::try_allocate(8192).expect("spilling enabled");
+        a.extend_from_slice(b"hello");
+        a.extend_from_slice(b" world");
+        assert_eq!(a.as_slice(), b"hello world");
+
+        // Admitting a second segment exceeds the cache capacity and releases `a`.
+        let mut b = SpillRegion::try_allocate(8192).expect("spilling enabled");
+        b.extend_from_slice(b"other");
+        SPILL_STATE.with(|state| {
+            let cache = state.cache.borrow();
+            assert_eq!(cache.resident.len(), 1);
+            assert_eq!(cache.stats.misses, 2);
+            assert_eq!(cache.stats.evictions, 1);
+        });
+
+        // Released segments are read back from disk.
+        SPILL_STATE.with(|state| state.epoch.set(state.epoch.get() + 1));
+        assert_eq!(a.as_slice(), b"hello world");
+        assert_eq!(b.as_slice(), b"other");
+        SPILL_STATE.with(|state| {
+            let cache = state.cache.borrow();
+            assert_eq!(cache.stats.misses, 4);
+            assert_eq!(cache.stats.evictions, 3);
+        });
+
+        drop(a);
+        drop(b);
+        SPILL_STATE.with(|state| {
+            let cache = state.cache.borrow();
+            assert_eq!(cache.resident_bytes, 0);
+            assert_eq!(cache.spilled_bytes, 0);
+        });
+
+        // Disabling spilling stops new regions from being spilled.
+        configure(None);
+        assert!(SpillRegion::try_allocate(8192).is_none());
+    }
+}
@@ -19,16 +19,18 @@ use differential_dataflow::lattice::Lattice;
 use differential_dataflow::trace::cursor::IntoOwned;
 use differential_dataflow::trace::{Cursor, TraceReader};
 use differential_dataflow::Hashable;
+use mz_cluster_client::ReplicaId;
 use mz_compute_client::logging::LoggingConfig;
 use mz_compute_client::protocol::command::{
     ComputeCommand, ComputeParameters, InstanceConfig, Peek, PeekTarget,
 };
 use mz_compute_client::protocol::history::ComputeCommandHistory;
 use mz_compute_client::protocol::response::{
-    ComputeResponse, CopyToResponse, FrontiersResponse, OperatorHydrationStatus, PeekResponse,
-    StatusResponse, SubscribeResponse,
+    ComputeResponse, CopyToResponse, DataflowMemoryLimitStatus, FrontiersResponse,
+    OperatorHydrationStatus, PeekResponse, StatusResponse, SubscribeResponse,
 };
 use mz_compute_types::dataflows::DataflowDescription;
+use mz_compute_types::dyncfgs::{ARRANGEMENT_CHECKPOINT_INTERVAL, DATAFLOW_MAX_HEAP_SIZE};
 use mz_compute_types::plan::flat_plan::FlatPlan;
 use mz_compute_types::plan::LirId;
 use mz_dyncfg::ConfigSet;
@@ -61,12 +63,15 @@ use tokio::sync::{oneshot, watch};
 use tracing::{debug, error, info, span, warn, Level};
 use uuid::Uuid;
 
+use crate::arrangement::checkpoint::ArrangementCheckpointer;
 use crate::arrangement::manager::{SpecializedTraceHandle, TraceBundle, TraceManager};
+use crate::arrangement::spill::SpillConfig;
 use crate::logging;
 use crate::logging::compute::{CollectionLogging, ComputeEvent};
+use crate::logging::DataflowHeapSizes;
 use crate::metrics::ComputeMetrics;
 use crate::metrics::WorkerMetrics;
-use crate::render::{LinearJoinSpec, StartSignal};
+use crate::render::{LinearJoinSpec, PauseSignal, StartSignal};
 use crate::server::{ComputeInstanceContext, ResponseSender};
 
 /// Worker-local state that is maintained across dataflows.
@@ -140,6 +145,11 @@ pub struct ComputeState {
     /// Copies of this sender are passed to the hydration logging operators.
     pub hydration_tx: mpsc::Sender<HydrationEvent>,
 
+    /// Arrangement heap sizes of the dataflows on this worker.
+    ///
+    /// Maintained by the compute logging dataflow and used to enforce per-dataflow memory limits.
+    pub dataflow_heap_sizes: DataflowHeapSizes,
+
     /// Collections awaiting schedule instruction by the controller.
     ///
     /// Each entry stores a reference to a token that can be dropped to unsuspend the collection's
@@ -175,6 +185,8 @@ pub struct ComputeState {
     /// replica can drop diffs associated with timestamps beyond the replica expiration.
     /// The replica will panic if such dataflows are not dropped before the replica has expired.
     pub replica_expiration: Antichain<Timestamp>,
+    /// The ID of this replica, if the controller provided it.
+    pub replica_id: Option<ReplicaId>,
 }
 
 impl ComputeState {
@@ -217,12 +229,14 @@ impl ComputeState {
             worker_config: mz_dyncfgs::all_dyncfgs(),
             hydration_rx,
             hydration_tx,
+            dataflow_heap_sizes: Default::default(),
             suspended_collections: Default::default(),
             read_only_tx,
             read_only_rx,
             server_maintenance_interval: Duration::ZERO,
             init_system_time: mz_ore::now::SYSTEM_TIME(),
             replica_expiration: Antichain::default(),
+            replica_id: None,
         }
     }
 
@@ -302,6 +316,23 @@ impl ComputeState {
             lgalloc::lgalloc_set_config(lgalloc::LgAlloc::new().disable());
         }
 
+        if ENABLE_ARRANGEMENT_SPILL.get(config) {
+            if let Some(path) = &self.context.scratch_directory {
+                let spill_config = SpillConfig {
+                    path: path.clone(),
+                    threshold_bytes: ARRANGEMENT_SPILL_THRESHOLD_BYTES.get(config),
+                    cache_bytes: ARRANGEMENT_SPILL_CACHE_BYTES.get(config),
+                };
+                info!(?spill_config, "enabling arrangement spilling");
+                crate::arrangement::spill::configure(Some(spill_config));
+            } else {
+                debug!("not enabling arrangement spilling, scratch directory not specified");
+                crate::arrangement::spill::configure(None);
+            }
+        } else {
+            crate::arrangement::spill::configure(None);
+        }
+
         let chunked_stack = ENABLE_CHUNKED_STACK.get(config);
         info!("using chunked stack: {chunked_stack}");
         mz_timely_util::containers::stack::use_chunked_stack(chunked_stack);
@@ -421,6 +452,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         if let Some(offset) = config.expiration_offset {
             self.compute_state.apply_expiration_offset(offset);
         }
+        self.compute_state.replica_id = config.replica_id;
 
         self.initialize_logging(config.logging);
     }
@@ -507,10 +539,16 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
             .chain(dataflow.copy_to_ids())
             .collect();
 
+        // All collections exported by the dataflow share the signal that pauses it when it
+        // exceeds its memory limit.
+        let pause_signal = PauseSignal::default();
+
         // Initialize compute and logging state for each object.
         for object_id in dataflow.export_ids() {
             let is_subscribe_or_copy = subscribe_copy_ids.contains(&object_id);
             let mut collection = CollectionState::new(is_subscribe_or_copy, as_of.clone());
+            collection.memory_limit =
+                Some(MemoryLimitState::new(dataflow_index, pause_signal.clone()));
 
             if let Some(logger) = self.compute_state.compute_logger.clone() {
                 let logging = CollectionLogging::new(
@@ -547,6 +585,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
             self.compute_state,
             dataflow,
             start_signal,
+            pause_signal,
             until,
             dataflow_expiration,
         );
@@ -632,6 +671,13 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
 
         // If this collection is an index, remove its trace.
         self.compute_state.traces.remove(&id);
+        // If the index is checkpointed, its checkpoints are not needed anymore. Read-only
+        // replicas must not modify durable state.
+        if let Some(checkpointer) = collection.checkpointer {
+            if !*self.compute_state.read_only_rx.borrow() {
+                checkpointer.delete_checkpoints();
+            }
+        }
         // If the collection is unscheduled, remove it from the list of waiting collections.
         self.compute_state.suspended_collections.remove(&id);
 
@@ -649,7 +695,11 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
             panic!("dataflow server has already initialized logging");
         }
 
-        let (logger, traces) = logging::initialize(self.timely_worker, &config);
+        let (logger, traces) = logging::initialize(
+            self.timely_worker,
+            &config,
+            Rc::clone(&self.compute_state.dataflow_heap_sizes),
+        );
 
         let mut log_index_ids = config.index_logs;
         for (log, (trace, dataflow_index)) in traces {
@@ -689,6 +739,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
     /// Send progress information to the controller.
     pub fn report_frontiers(&mut self) {
         let mut responses = Vec::new();
+        let read_only = *self.compute_state.read_only_rx.borrow();
 
         // Maintain a single allocation for `new_frontier` to avoid allocating on every iteration.
         let mut new_frontier = Antichain::new();
@@ -760,10 +811,18 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
                     .set_reported_output_frontier(ReportedFrontier::Reported(frontier.clone()));
             }
 
+            // Collect the checkpoint frontier of a checkpointed index, for the controller to hold
+            // back the inputs.
+            let new_checkpoint_frontier = collection
+                .checkpointer
+                .as_mut()
+                .and_then(|checkpointer| checkpointer.checkpoint_frontier_update(read_only));
+
             let response = FrontiersResponse {
                 write_frontier: new_write_frontier,
                 input_frontier: new_input_frontier,
                 output_frontier: new_output_frontier,
+                checkpoint_frontier: new_checkpoint_frontier,
             };
             if response.has_updates() {
                 responses.push((id, response));
@@ -798,6 +857,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
                 write_frontier,
                 input_frontier,
                 output_frontier,
+                checkpoint_frontier: None,
             };
             if frontiers.has_updates() {
                 self.send_compute_response(ComputeResponse::Frontiers(id, frontiers));
@@ -949,6 +1009,87 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
         let _ = self.response_tx.send(response);
     }
 
+    /// Writes checkpoints of index arrangements that have advanced past their next checkpoint
+    /// time.
+    pub(crate) fn checkpoint_arrangements(&mut self) {
+        let interval = ARRANGEMENT_CHECKPOINT_INTERVAL.get(&self.compute_state.worker_config);
+        let read_only = *self.compute_state.read_only_rx.borrow();
+        for collection in self.compute_state.collections.values_mut() {
+            let Some(checkpointer) = &mut collection.checkpointer else {
+                continue;
+            };
+            if let Some(time) = checkpointer.maintain(interval, read_only) {
+                if let Some(logging) = &mut collection.logging {
+                    logging.set_checkpoint(Some(time));
+                }
+            }
+        }
+    }
+
+    /// Pauses dataflows whose arrangements exceed the configured per-dataflow memory limit, and
+    /// reports the collections they export to the controller.
+    ///
+    /// The limit is split evenly across workers, and each worker enforces its share
+    /// independently. Heap sizes are attributed to dataflows through arrangement heap size
+    /// logging, as allocator statistics are only available for the process as a whole. As a
+    /// result, memory held outside of arrangements is not accounted for, and no limits are
+    /// enforced if introspection logging is disabled.
+    pub(crate) fn enforce_dataflow_memory_limits(&mut self) {
+        let Some(limit) = DATAFLOW_MAX_HEAP_SIZE.get(&self.compute_state.worker_config) else {
+            return;
+        };
+        let worker_limit = limit / self.timely_worker.peers();
+        let worker_id = self.timely_worker.index();
+
+        let heap_sizes = self.compute_state.dataflow_heap_sizes.borrow();
+        let mut responses = Vec::new();
+        for (&id, collection) in self.compute_state.collections.iter_mut() {
+            let Some(state) = &mut collection.memory_limit else {
+                continue;
+            };
+            if state.reported {
+                continue;
+            }
+
+            let heap_size = heap_sizes.get(&state.dataflow_index).copied().unwrap_or(0);
+            let heap_size = usize::try_from(heap_size).unwrap_or(0);
+            // Another collection exported by the same dataflow might have paused it already.
+            let paused = state.pause_signal.is_paused();
+            if !paused && heap_size <= worker_limit {
+                continue;
+            }
+
+            if !paused {
+                warn!(
+                    %id, dataflow = state.dataflow_index, heap_size, limit = worker_limit,
+                    "pausing dataflow exceeding its memory limit",
+                );
+                state.pause_signal.pause();
+            }
+            state.reported = true;
+
+            // The compute protocol forbids reporting `Status` about collections that have
+            // advanced to the empty frontier.
+            if collection.reported_frontiers().all_empty() {
+                continue;
+            }
+
+            let status = DataflowMemoryLimitStatus {
+                collection_id: id,
+                worker_id,
+                heap_size: u64::cast_from(heap_size),
+                limit: u64::cast_from(worker_limit),
+            };
+            let response = ComputeResponse::Status(StatusResponse::DataflowMemoryLimit(status));
+            responses.push(response);
+        }
+        drop(heap_sizes);
+
+        for response in responses {
+            self.send_compute_response(response);
+        }
+    }
+
     /// Checks for dataflow expiration. Panics if we're past the replica expiration time.
     pub(crate) fn check_expiration(&self) {
         let now = mz_ore::now::SYSTEM_TIME();
@@ -1641,6 +1782,14 @@ pub struct CollectionState {
     pub compute_probe: Option<probe::Handle<Timestamp>>,
     /// Logging state maintained for this collection.
     logging: Option<CollectionLogging>,
+    /// Writes checkpoints of the collection's arrangement.
+    ///
+    /// Only `Some` if the collection is an index with checkpoints enabled.
+    pub checkpointer: Option<ArrangementCheckpointer>,
+    /// State for enforcing the memory limit of the collection's dataflow.
+    ///
+    /// `None` for logging collections, which are not subject to memory limits.
+    pub memory_limit: Option<MemoryLimitState>,
 }
 
 impl CollectionState {
@@ -1654,6 +1803,8 @@ impl CollectionState {
             input_probes: Default::default(),
             compute_probe: None,
             logging: None,
+            checkpointer: None,
+            memory_limit: None,
         }
     }
 
@@ -1742,3 +1893,23 @@ pub struct HydrationEvent {
     /// Whether the node is hydrated.
     pub hydrated: bool,
 }
+
+/// State for enforcing the memory limit of a collection's dataflow.
+pub struct MemoryLimitState {
+    /// The index of the dataflow maintaining the collection.
+    dataflow_index: usize,
+    /// Signal that pauses the dataflow.
+    pause_signal: PauseSignal,
+    /// Whether the dataflow was paused and this has been reported for the collection.
+    reported: bool,
+}
+
+impl MemoryLimitState {
+    fn new(dataflow_index: usize, pause_signal: PauseSignal) -> Self {
+        Self {
+            dataflow_index,
+            pause_signal,
+            reported: false,
+        }
+    }
+}
@@ -18,6 +18,7 @@ mod timely;
 use ::timely::container::{CapacityContainerBuilder, ContainerBuilder, PushInto, SizableContainer};
 use ::timely::Container;
 use std::any::Any;
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::time::Duration;
@@ -35,6 +36,12 @@ use crate::typedefs::RowRowAgent;
 
 pub use crate::logging::initialize::initialize;
 
+/// Arrangement heap sizes of the dataflows on a worker, in bytes, keyed by dataflow index.
+///
+/// Maintained by the compute logging dataflow from `ArrangementHeapSize` events, and read by the
+/// compute state to enforce per-dataflow memory limits.
+pub type DataflowHeapSizes = Rc<RefCell<BTreeMap<usize, isize>>>;
+
 /// Logs events as a timely stream, with progress statements.
 struct BatchLogger<CB, P>
 where
@@ -159,6 +166,8 @@ impl<C> EventQueue<C> {
 struct SharedLoggingState {
     /// Activators for arrangement heap size operators.
     arrangement_size_activators: BTreeMap<usize, Activator>,
+    /// Arrangement heap sizes per dataflow.
+    dataflow_heap_sizes: DataflowHeapSizes,
     /// Shared compute logger.
     compute_logger: Option<ComputeLogger>,
 }
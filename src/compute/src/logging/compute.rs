@@ -10,6 +10,7 @@
 //! Logging dataflows for events generated by clusterd.
 
 use std::cell::RefCell;
+use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Write};
 use std::rc::Rc;
@@ -131,6 +132,12 @@ pub enum ComputeEvent {
     },
     /// A dataflow export was hydrated.
     Hydration { export_id: GlobalId },
+    /// Checkpoint information for dataflow exports.
+    ArrangementCheckpoint {
+        export_id: GlobalId,
+        time: Timestamp,
+        diff: i8,
+    },
     /// An LIR operator was mapped to some particular dataflow operator.
     ///
     /// Cf. `ComputeLog::LirMaping`
@@ -268,6 +275,7 @@ pub(super) fn construct<A: Allocate + 'static>(
             demux.new_output();
         let (mut error_count_out, error_count) = demux.new_output();
         let (mut hydration_time_out, hydration_time) = demux.new_output();
+        let (mut arrangement_checkpoint_out, arrangement_checkpoint) = demux.new_output();
         let (mut lir_mapping_out, lir_mapping) = demux.new_output();
         let (mut dataflow_global_ids_out, dataflow_global_ids) = demux.new_output();
 
@@ -286,6 +294,7 @@ pub(super) fn construct<A: Allocate + 'static>(
                 let mut arrangement_heap_allocations = arrangement_heap_allocations_out.activate();
                 let mut error_count = error_count_out.activate();
                 let mut hydration_time = hydration_time_out.activate();
+                let mut arrangement_checkpoint = arrangement_checkpoint_out.activate();
                 let mut lir_mapping = lir_mapping_out.activate();
                 let mut dataflow_global_ids = dataflow_global_ids_out.activate();
 
@@ -304,6 +313,7 @@ pub(super) fn construct<A: Allocate + 'static>(
                         arrangement_heap_allocations: arrangement_heap_allocations.session(&cap),
                         error_count: error_count.session(&cap),
                         hydration_time: hydration_time.session(&cap),
+                        arrangement_checkpoint: arrangement_checkpoint.session(&cap),
                         lir_mapping: lir_mapping.session(&cap),
                         dataflow_global_ids: dataflow_global_ids.session(&cap),
                     };
@@ -442,6 +452,18 @@ pub(super) fn construct<A: Allocate + 'static>(
             }
         });
 
+        let packer = PermutedRowPacker::new(ComputeLog::ArrangementCheckpoints);
+        let arrangement_checkpoints = arrangement_checkpoint.as_collection().map({
+            let mut scratch = String::new();
+            move |datum| {
+                packer.pack_slice(&[
+                    make_string_datum(datum.export_id, &mut scratch),
+                    Datum::UInt64(u64::cast_from(worker_id)),
+                    Datum::MzTimestamp(datum.time),
+                ])
+            }
+        });
+
         let packer = PermutedRowPacker::new(ComputeLog::LirMapping);
         let lir_mapping = lir_mapping.as_collection().map({
             let mut scratch1 = String::new();
@@ -488,6 +510,7 @@ pub(super) fn construct<A: Allocate + 'static>(
             (ArrangementHeapAllocations, arrangement_heap_allocations),
             (ErrorCount, error_count),
             (HydrationTime, hydration_time),
+            (ArrangementCheckpoints, arrangement_checkpoints),
             (LirMapping, lir_mapping),
             (DataflowGlobal, dataflow_global_ids),
         ];
@@ -594,6 +617,8 @@ impl ExportState {
 /// State for tracking arrangement sizes.
 #[derive(Default)]
 struct ArrangementSizeState {
+    /// The index of the dataflow containing the arrangement.
+    dataflow_index: usize,
     size: isize,
     capacity: isize,
     count: isize,
@@ -616,6 +641,7 @@ struct DemuxOutput<'a> {
     arrangement_heap_capacity: OutputSession<'a, ArrangementHeapDatum>,
     arrangement_heap_allocations: OutputSession<'a, ArrangementHeapDatum>,
     hydration_time: OutputSession<'a, HydrationTimeDatum>,
+    arrangement_checkpoint: OutputSession<'a, ArrangementCheckpointDatum>,
     error_count: OutputSession<'a, ErrorCountDatum>,
     lir_mapping: OutputSession<'a, LirMappingDatum>,
     dataflow_global_ids: OutputSession<'a, DataflowGlobalDatum>,
@@ -663,6 +689,12 @@ struct HydrationTimeDatum {
     time_ns: Option<u64>,
 }
 
+#[derive(Clone)]
+struct ArrangementCheckpointDatum {
+    export_id: GlobalId,
+    time: Timestamp,
+}
+
 #[derive(Clone)]
 struct ErrorCountDatum {
     export_id: GlobalId,
@@ -757,6 +789,11 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
             DataflowShutdown { dataflow_index } => self.handle_dataflow_shutdown(dataflow_index),
             ErrorCount { export_id, diff } => self.handle_error_count(export_id, diff),
             Hydration { export_id } => self.handle_hydration(export_id),
+            ArrangementCheckpoint {
+                export_id,
+                time,
+                diff,
+            } => self.handle_arrangement_checkpoint(export_id, time, diff),
             LirMapping { global_id, mapping } => self.handle_lir_mapping(global_id, mapping),
             DataflowGlobal { id, global_id } => self.handle_dataflow_global(id, global_id),
         }
@@ -999,6 +1036,14 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
         self.output.frontier.give((datum, ts, diff));
     }
 
+    fn handle_arrangement_checkpoint(&mut self, export_id: GlobalId, time: Timestamp, diff: i8) {
+        let ts = self.ts();
+        let datum = ArrangementCheckpointDatum { export_id, time };
+        self.output
+            .arrangement_checkpoint
+            .give((datum, ts, diff.into()));
+    }
+
     fn handle_import_frontier(
         &mut self,
         import_id: GlobalId,
@@ -1028,6 +1073,9 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
             .give((datum, ts, Diff::cast_from(size)));
 
         state.size += size;
+
+        let mut dataflow_heap_sizes = self.shared_state.dataflow_heap_sizes.borrow_mut();
+        *dataflow_heap_sizes.entry(state.dataflow_index).or_default() += size;
     }
 
     /// Update the allocation capacity for an arrangement.
@@ -1062,11 +1110,13 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
 
     /// Indicate that a new arrangement exists, start maintaining the heap size state.
     fn handle_arrangement_heap_size_operator(&mut self, operator_id: usize, address: Rc<[usize]>) {
+        let dataflow_index = address[0];
         let activator = self.state.worker.activator_for(address);
-        let existing = self
-            .state
-            .arrangement_size
-            .insert(operator_id, Default::default());
+        let state = ArrangementSizeState {
+            dataflow_index,
+            ..Default::default()
+        };
+        let existing = self.state.arrangement_size.insert(operator_id, state);
         if existing.is_some() {
             error!(%operator_id, "arrangement size operator already registered");
         }
@@ -1099,6 +1149,14 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
                 ts,
                 -Diff::cast_from(state.count),
             ));
+
+            let mut dataflow_heap_sizes = self.shared_state.dataflow_heap_sizes.borrow_mut();
+            if let Entry::Occupied(mut entry) = dataflow_heap_sizes.entry(state.dataflow_index) {
+                *entry.get_mut() -= state.size;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
         }
         self.shared_state
             .arrangement_size_activators
@@ -1166,6 +1224,7 @@ pub struct CollectionLogging {
 
     logged_frontier: Option<Timestamp>,
     logged_import_frontiers: BTreeMap<GlobalId, Timestamp>,
+    logged_checkpoint: Option<Timestamp>,
 }
 
 impl CollectionLogging {
@@ -1183,6 +1242,7 @@ impl CollectionLogging {
             logger,
             logged_frontier: None,
             logged_import_frontiers: Default::default(),
+            logged_checkpoint: None,
         };
 
         // Initialize frontier logging.
@@ -1239,12 +1299,36 @@ impl CollectionLogging {
         self.logger
             .log(ComputeEvent::Hydration { export_id: self.id });
     }
+
+    /// Set the time of the latest complete arrangement checkpoint to the given new time and emit
+    /// corresponding logging events.
+    pub fn set_checkpoint(&mut self, new_time: Option<Timestamp>) {
+        let old_time = self.logged_checkpoint;
+        self.logged_checkpoint = new_time;
+
+        if old_time != new_time {
+            let export_id = self.id;
+            let retraction = old_time.map(|time| ComputeEvent::ArrangementCheckpoint {
+                export_id,
+                time,
+                diff: -1,
+            });
+            let insertion = new_time.map(|time| ComputeEvent::ArrangementCheckpoint {
+                export_id,
+                time,
+                diff: 1,
+            });
+            let events = retraction.into_iter().chain(insertion);
+            self.logger.log_many(events);
+        }
+    }
 }
 
 impl Drop for CollectionLogging {
     fn drop(&mut self) {
         // Emit retraction events to clean up events previously logged.
         self.set_frontier(None);
+        self.set_checkpoint(None);
 
         let import_ids: Vec<_> = self.logged_import_frontiers.keys().copied().collect();
         for id in import_ids {
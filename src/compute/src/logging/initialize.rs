@@ -29,15 +29,19 @@ use timely::progress::reachability::logging::TrackerEvent;
 use crate::arrangement::manager::TraceBundle;
 use crate::extensions::arrange::{KeyCollection, MzArrange};
 use crate::logging::compute::ComputeEvent;
-use crate::logging::{BatchLogger, EventQueue, SharedLoggingState};
+use crate::logging::{BatchLogger, DataflowHeapSizes, EventQueue, SharedLoggingState};
 
 /// Initialize logging dataflows.
 ///
 /// Returns a logger for compute events, and for each `LogVariant` a trace bundle usable for
 /// retrieving logged records as well as the index of the exporting dataflow.
+///
+/// The compute logging dataflow keeps `dataflow_heap_sizes` up to date with the arrangement heap
+/// sizes of all dataflows on the worker.
 pub fn initialize<A: Allocate + 'static>(
     worker: &mut timely::worker::Worker<A>,
     config: &LoggingConfig,
+    dataflow_heap_sizes: DataflowHeapSizes,
 ) -> (
     super::compute::Logger,
     BTreeMap<LogVariant, (TraceBundle, usize)>,
@@ -62,7 +66,10 @@ pub fn initialize<A: Allocate + 'static>(
         r_event_queue: EventQueue::new("r"),
         d_event_queue: EventQueue::new("d"),
         c_event_queue: EventQueue::new("c"),
-        shared_state: Default::default(),
+        shared_state: Rc::new(RefCell::new(SharedLoggingState {
+            dataflow_heap_sizes,
+            ..Default::default()
+        })),
     };
 
     // Depending on whether we should log the creation of the logging dataflows, we register the
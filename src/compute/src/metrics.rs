@@ -12,7 +12,7 @@ use std::sync::{Arc, Mutex};
 use mz_compute_client::metrics::{CommandMetrics, HistoryMetrics};
 use mz_ore::cast::CastFrom;
 use mz_ore::metric;
-use mz_ore::metrics::{raw, MetricsRegistry, UIntGauge};
+use mz_ore::metrics::{raw, IntCounter, MetricsRegistry, UIntGauge};
 use mz_repr::SharedRow;
 use prometheus::core::{AtomicF64, GenericCounter};
 use prometheus::proto::LabelPair;
@@ -43,6 +43,11 @@ pub struct ComputeMetrics {
     // arrangements
     arrangement_maintenance_seconds_total: raw::CounterVec,
     arrangement_maintenance_active_info: raw::UIntGaugeVec,
+    arrangement_spill_cache_hits_total: raw::IntCounterVec,
+    arrangement_spill_cache_misses_total: raw::IntCounterVec,
+    arrangement_spill_cache_evictions_total: raw::IntCounterVec,
+    arrangement_spill_resident_bytes: raw::UIntGaugeVec,
+    arrangement_spill_bytes: raw::UIntGaugeVec,
 
     // timely step timings
     //
@@ -69,6 +74,9 @@ pub struct ComputeMetrics {
 
     /// Remaining seconds until replica expiration.
     pub(crate) replica_expiration_remaining_seconds: raw::GaugeVec,
+
+    /// The number of group recomputations performed by bounded TopK operators.
+    pub(crate) bounded_top_k_recomputations_total: raw::IntCounterVec,
 }
 
 /// Per-worker metrics.
@@ -79,6 +87,8 @@ pub struct WorkerMetrics {
     pub(crate) replica_expiration_timestamp_seconds: UIntGauge,
     /// Remaining seconds until replica expiration.
     pub(crate) replica_expiration_remaining_seconds: raw::Gauge,
+    /// The number of group recomputations performed by bounded TopK operators.
+    pub(crate) bounded_top_k_recomputations_total: IntCounter,
 }
 
 impl WorkerMetrics {
@@ -99,10 +109,15 @@ impl WorkerMetrics {
             .replica_expiration_remaining_seconds
             .with_label_values(&[&worker]);
 
+        let bounded_top_k_recomputations_total = metrics
+            .bounded_top_k_recomputations_total
+            .with_label_values(&[&worker]);
+
         Self {
             handle_command_duration_seconds,
             replica_expiration_timestamp_seconds,
             replica_expiration_remaining_seconds,
+            bounded_top_k_recomputations_total,
         }
     }
 }
@@ -167,6 +182,31 @@ impl ComputeMetrics {
                 help: "Whether maintenance is currently occuring.",
                 var_labels: ["worker_id"],
             )),
+            arrangement_spill_cache_hits_total: registry.register(metric!(
+                name: "mz_arrangement_spill_cache_hits_total",
+                help: "The number of accesses to spilled arrangement segments that were resident.",
+                var_labels: ["worker_id"],
+            )),
+            arrangement_spill_cache_misses_total: registry.register(metric!(
+                name: "mz_arrangement_spill_cache_misses_total",
+                help: "The number of accesses to spilled arrangement segments that had to be read from disk.",
+                var_labels: ["worker_id"],
+            )),
+            arrangement_spill_cache_evictions_total: registry.register(metric!(
+                name: "mz_arrangement_spill_cache_evictions_total",
+                help: "The number of spilled arrangement segments released from memory.",
+                var_labels: ["worker_id"],
+            )),
+            arrangement_spill_resident_bytes: registry.register(metric!(
+                name: "mz_arrangement_spill_resident_bytes",
+                help: "The number of bytes in spilled arrangement segments considered resident.",
+                var_labels: ["worker_id"],
+            )),
+            arrangement_spill_bytes: registry.register(metric!(
+                name: "mz_arrangement_spill_bytes",
+                help: "The number of bytes in arrangement segments spilled to disk.",
+                var_labels: ["worker_id"],
+            )),
             timely_step_duration_seconds: registry.register(metric!(
                 name: "mz_timely_step_duration_seconds",
                 help: "The time spent in each compute step_or_park call",
@@ -200,6 +240,11 @@ impl ComputeMetrics {
                 help: "The remaining seconds until replica expiration. Can go negative, can lag behind.",
                 var_labels: ["worker_id"],
             )),
+            bounded_top_k_recomputations_total: registry.register(metric!(
+                name: "mz_compute_bounded_top_k_recomputations_total",
+                help: "The number of TopK groups recomputed from their input due to buffer underflow.",
+                var_labels: ["worker_id"],
+            )),
         }
     }
 
@@ -229,6 +274,19 @@ impl ComputeMetrics {
         TraceMetrics {
             maintenance_seconds_total,
             maintenance_active_info,
+            spill_cache_hits_total: self
+                .arrangement_spill_cache_hits_total
+                .with_label_values(&[&worker]),
+            spill_cache_misses_total: self
+                .arrangement_spill_cache_misses_total
+                .with_label_values(&[&worker]),
+            spill_cache_evictions_total: self
+                .arrangement_spill_cache_evictions_total
+                .with_label_values(&[&worker]),
+            spill_resident_bytes: self
+                .arrangement_spill_resident_bytes
+                .with_label_values(&[&worker]),
+            spill_bytes: self.arrangement_spill_bytes.with_label_values(&[&worker]),
         }
     }
 
@@ -295,4 +353,14 @@ pub struct TraceMetrics {
     /// to gain a sense that Materialize is stuck on maintenance before the
     /// maintenance completes
     pub maintenance_active_info: UIntGauge,
+    /// Accesses to spilled arrangement segments that were resident.
+    pub spill_cache_hits_total: IntCounter,
+    /// Accesses to spilled arrangement segments that were not resident.
+    pub spill_cache_misses_total: IntCounter,
+    /// Spilled arrangement segments released from memory.
+    pub spill_cache_evictions_total: IntCounter,
+    /// Bytes in resident spilled arrangement segments.
+    pub spill_resident_bytes: UIntGauge,
+    /// Bytes in all spilled arrangement segments.
+    pub spill_bytes: UIntGauge,
 }
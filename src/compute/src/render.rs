@@ -101,7 +101,7 @@
 //! if/when the errors are retracted.
 
 use std::any::Any;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 use std::future::Future;
@@ -124,7 +124,7 @@ use mz_compute_types::plan::LirId;
 use mz_expr::{EvalError, Id};
 use mz_persist_client::operators::shard_source::SnapshotMode;
 use mz_repr::explain::DummyHumanizer;
-use mz_repr::{Datum, GlobalId, Row, SharedRow};
+use mz_repr::{Datum, Diff, GlobalId, Row, SharedRow};
 use mz_storage_operators::persist_source;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
@@ -133,7 +133,7 @@ use timely::communication::Allocate;
 use timely::container::columnation::Columnation;
 use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::operators::to_stream::ToStream;
-use timely::dataflow::operators::{probe, BranchWhen, Operator, Probe};
+use timely::dataflow::operators::{probe, BranchWhen, CapabilitySet, Enter, Operator, Probe};
 use timely::dataflow::scopes::Child;
 use timely::dataflow::{Scope, Stream};
 use timely::order::Product;
@@ -148,17 +148,20 @@ use crate::compute_state::ComputeState;
 use crate::extensions::arrange::{KeyCollection, MzArrange};
 use crate::extensions::reduce::MzReduce;
 use crate::logging::compute::{ComputeEvent, LirMetadata, LogDataflowErrors};
+use crate::render::checkpoint::{CheckpointPlan, HydrationGate};
 use crate::render::context::{
     ArrangementFlavor, Context, MzArrangement, MzArrangementImport, ShutdownToken,
 };
 use crate::render::continual_task::ContinualTaskCtx;
-use crate::typedefs::{ErrSpine, KeyBatcher};
+use crate::typedefs::{ErrAgent, ErrSpine, KeyBatcher, RowRowAgent};
 
+mod checkpoint;
 pub mod context;
 pub(crate) mod continual_task;
 mod errors;
 mod flat_map;
 mod join;
+mod persisted_index;
 mod reduce;
 pub mod sinks;
 mod threshold;
@@ -172,14 +175,22 @@ pub use join::LinearJoinSpec;
 /// This method imports sources from provided assets, and then builds the remaining
 /// dataflow using "compute-local" assets like shared arrangements, and producing
 /// both arrangements and sinks.
+///
+/// All inputs of the dataflow are gated by the given `pause_signal`.
 pub fn build_compute_dataflow<A: Allocate>(
     timely_worker: &mut TimelyWorker<A>,
     compute_state: &mut ComputeState,
-    dataflow: DataflowDescription<FlatPlan, CollectionMetadata>,
+    mut dataflow: DataflowDescription<FlatPlan, CollectionMetadata>,
     start_signal: StartSignal,
+    pause_signal: PauseSignal,
     until: Antichain<mz_repr::Timestamp>,
     dataflow_expiration: Antichain<mz_repr::Timestamp>,
 ) {
+    // Determine whether the exported index is checkpointed. This removes its arrangement from
+    // the plan, as it is rendered separately.
+    let checkpointing = CheckpointPlan::for_dataflow(&mut dataflow, compute_state, &until)
+        .map(|plan| (plan, HydrationGate::new()));
+
     // Mutually recursive view definitions require special handling.
     let recursive = dataflow
         .objects_to_build
@@ -216,11 +227,53 @@ pub fn build_compute_dataflow<A: Allocate>(
         // so that other similar uses (e.g. with iterative scopes) do not require weird
         // alternate type signatures.
         let mut imported_sources = Vec::new();
+        let mut imported_persisted_indexes = Vec::new();
         let mut tokens = BTreeMap::new();
         scope.clone().region_named(&input_name, |region| {
+            // Decide how to hydrate a checkpointed index, and read the input changes required to
+            // hydrate it from a checkpoint.
+            let mut checkpoint_suffixes = match &checkpointing {
+                Some((plan, gate)) => checkpoint::render_coordinator(
+                    region,
+                    plan,
+                    Arc::clone(&compute_state.persist_clients),
+                    compute_state.txns_ctx.clone(),
+                    start_signal.clone(),
+                    gate,
+                ),
+                None => BTreeMap::new(),
+            };
+
             // Import declared sources into the rendering context.
             for (source_id, (source, _monotonic)) in dataflow.source_imports.iter() {
                 region.region_named(&format!("Source({:?})", source_id), |inner| {
+                    // Persisted indexes are imported as arrangements rather than collections.
+                    if let Some(index_desc) = &source.arguments.persisted_index {
+                        let input_probe =
+                            compute_state.input_probe_for(*source_id, dataflow.export_ids());
+                        let (oks, errs, token) = persisted_index::persisted_index_source(
+                            inner,
+                            *source_id,
+                            Arc::clone(&compute_state.persist_clients),
+                            source.storage_metadata.clone(),
+                            dataflow
+                                .as_of
+                                .clone()
+                                .unwrap_or_else(|| Antichain::from_elem(Timestamp::minimum())),
+                            until.clone(),
+                            start_signal.clone(),
+                            input_probe,
+                        );
+                        imported_persisted_indexes.push((
+                            *source_id,
+                            index_desc.clone(),
+                            oks,
+                            errs,
+                        ));
+                        tokens.insert(*source_id, token);
+                        return;
+                    }
+
                     let mut mfp = source.arguments.operators.clone().map(|ops| {
                         mz_expr::MfpPlan::create_from(ops)
                             .expect("Linear operators should always be valid")
@@ -237,25 +290,48 @@ pub fn build_compute_dataflow<A: Allocate>(
 
                     // Note: For correctness, we require that sources only emit times advanced by
                     // `dataflow.as_of`. `persist_source` is documented to provide this guarantee.
-                    let (mut ok_stream, err_stream, token) = persist_source::persist_source(
-                        inner,
-                        *source_id,
-                        Arc::clone(&compute_state.persist_clients),
-                        &compute_state.txns_ctx,
-                        &compute_state.worker_config,
-                        source.storage_metadata.clone(),
-                        dataflow.as_of.clone(),
-                        snapshot_mode,
-                        until.clone(),
-                        mfp.as_mut(),
-                        compute_state.dataflow_max_inflight_bytes(),
-                        start_signal.clone(),
-                        |error| panic!("compute_import: {error}"),
-                    );
-
-                    // If `mfp` is non-identity, we need to apply what remains.
-                    // For the moment, assert that it is either trivial or `None`.
-                    assert!(mfp.map(|x| x.is_identity()).unwrap_or(true));
+                    let (mut ok_stream, err_stream, token): (_, _, Rc<dyn Any>) =
+                        match &checkpointing {
+                            Some((plan, gate)) => {
+                                let (suffix_oks, suffix_errs) = checkpoint_suffixes
+                                    .remove(source_id)
+                                    .expect("all sources are checkpoint inputs");
+                                checkpoint::render_source(
+                                    inner,
+                                    *source_id,
+                                    compute_state,
+                                    source.storage_metadata.clone(),
+                                    mfp,
+                                    plan.as_of,
+                                    start_signal.clone(),
+                                    gate,
+                                    (suffix_oks.enter(inner), suffix_errs.enter(inner)),
+                                )
+                            }
+                            None => {
+                                let (ok_stream, err_stream, token) = persist_source::persist_source(
+                                    inner,
+                                    *source_id,
+                                    Arc::clone(&compute_state.persist_clients),
+                                    &compute_state.txns_ctx,
+                                    &compute_state.worker_config,
+                                    source.storage_metadata.clone(),
+                                    dataflow.as_of.clone(),
+                                    snapshot_mode,
+                                    until.clone(),
+                                    mfp.as_mut(),
+                                    compute_state.dataflow_max_inflight_bytes(),
+                                    start_signal.clone(),
+                                    |error| panic!("compute_import: {error}"),
+                                );
+
+                                // If `mfp` is non-identity, we need to apply what remains.
+                                // For the moment, assert that it is either trivial or `None`.
+                                assert!(mfp.map(|x| x.is_identity()).unwrap_or(true));
+
+                                (ok_stream, err_stream, Rc::new(token))
+                            }
+                        };
 
                     // To avoid a memory spike during arrangement hydration (database-issues#6368), need to
                     // ensure that the first frontier we report into the dataflow is beyond the
@@ -269,6 +345,12 @@ pub fn build_compute_dataflow<A: Allocate>(
                         compute_state.input_probe_for(*source_id, dataflow.export_ids());
                     ok_stream = ok_stream.probe_with(&input_probe);
 
+                    // Gate the input, so the dataflow can be paused when it exceeds its memory
+                    // limit. This needs to happen after the input probe, which should keep
+                    // reporting the frontier of the source.
+                    let ok_stream = ok_stream.with_pause_signal(pause_signal.clone());
+                    let err_stream = err_stream.with_pause_signal(pause_signal.clone());
+
                     // The `suppress_early_progress` operator and the input
                     // probe both want to work on the untransformed ct input,
                     // make sure this stays after them.
@@ -292,7 +374,6 @@ pub fn build_compute_dataflow<A: Allocate>(
                     imported_sources.push((mz_expr::Id::Global(*source_id), (oks, errs)));
 
                     // Associate returned tokens with the source identifier.
-                    let token: Rc<dyn Any> = Rc::new(token);
                     tokens.insert(*source_id, token);
                 });
             }
@@ -319,6 +400,18 @@ pub fn build_compute_dataflow<A: Allocate>(
                     context.insert_id(id, bundle);
                 }
 
+                for (source_id, index_desc, oks, errs) in imported_persisted_indexes.into_iter() {
+                    context.import_persisted_index(
+                        &mut tokens,
+                        source_id,
+                        index_desc,
+                        oks,
+                        errs,
+                        start_signal.clone(),
+                        pause_signal.clone(),
+                    );
+                }
+
                 // Import declared indexes into the rendering context.
                 for (idx_id, idx) in &dataflow.index_imports {
                     let input_probe = compute_state.input_probe_for(*idx_id, dataflow.export_ids());
@@ -329,6 +422,7 @@ pub fn build_compute_dataflow<A: Allocate>(
                         *idx_id,
                         &idx.desc,
                         start_signal.clone(),
+                        pause_signal.clone(),
                     );
                 }
 
@@ -404,6 +498,18 @@ pub fn build_compute_dataflow<A: Allocate>(
                     context.insert_id(id, bundle);
                 }
 
+                for (source_id, index_desc, oks, errs) in imported_persisted_indexes.into_iter() {
+                    context.import_persisted_index(
+                        &mut tokens,
+                        source_id,
+                        index_desc,
+                        oks,
+                        errs,
+                        start_signal.clone(),
+                        pause_signal.clone(),
+                    );
+                }
+
                 // Import declared indexes into the rendering context.
                 for (idx_id, idx) in &dataflow.index_imports {
                     let input_probe = compute_state.input_probe_for(*idx_id, dataflow.export_ids());
@@ -414,6 +520,7 @@ pub fn build_compute_dataflow<A: Allocate>(
                         *idx_id,
                         &idx.desc,
                         start_signal.clone(),
+                        pause_signal.clone(),
                     );
                 }
 
@@ -445,11 +552,42 @@ pub fn build_compute_dataflow<A: Allocate>(
                     context.insert_id(Id::Global(object.id), bundle);
                 }
 
+                // Arrange a checkpointed index, which may be hydrated from a checkpoint.
+                let mut checkpoint_traces = None;
+                if let Some((plan, gate)) = &checkpointing {
+                    let id = Id::Global(plan.index_id);
+                    let bundle = context.lookup_id(id).expect("index object must be built");
+                    let (oks, errs) = bundle.collection.expect("unarranged form requested");
+                    let (oks, errs) = checkpoint::render_arrangement(
+                        plan,
+                        Arc::clone(&compute_state.persist_clients),
+                        gate,
+                        oks,
+                        errs,
+                    );
+                    checkpoint_traces = Some((oks.trace.clone(), errs.trace.clone()));
+                    let arrangement = ArrangementFlavor::Local(MzArrangement::RowRow(oks), errs);
+                    context.update_id(
+                        id,
+                        CollectionBundle::from_expressions(plan.key.clone(), arrangement),
+                    );
+                }
+
                 // Export declared indexes.
                 for (idx_id, dependencies, idx) in indexes {
                     context.export_index(compute_state, &tokens, dependencies, idx_id, &idx);
                 }
 
+                if let (Some((plan, _gate)), Some((oks, errs))) =
+                    (&checkpointing, checkpoint_traces)
+                {
+                    let checkpointer =
+                        plan.checkpointer(compute_state, region.index(), region.peers(), oks, errs);
+                    compute_state
+                        .expect_collection_mut(plan.index_id)
+                        .checkpointer = Some(checkpointer);
+                }
+
                 // Export declared sinks.
                 for (sink_id, dependencies, sink) in sinks {
                     context.export_sink(
@@ -482,6 +620,7 @@ where
         idx_id: GlobalId,
         idx: &IndexDesc,
         start_signal: StartSignal,
+        pause_signal: PauseSignal,
     ) {
         if let Some(traces) = compute_state.traces.get_mut(&idx_id) {
             assert!(
@@ -510,8 +649,12 @@ where
             );
             let err_arranged = err_arranged.enter(&self.scope);
 
-            let ok_arranged = ok_arranged.with_start_signal(start_signal.clone());
-            let err_arranged = err_arranged.with_start_signal(start_signal);
+            let ok_arranged = ok_arranged
+                .with_start_signal(start_signal.clone())
+                .with_pause_signal(pause_signal.clone());
+            let err_arranged = err_arranged
+                .with_start_signal(start_signal)
+                .with_pause_signal(pause_signal);
 
             self.update_id(
                 Id::Global(idx.on_id),
@@ -531,6 +674,59 @@ where
             );
         }
     }
+
+    /// Imports the arrangement of a persisted index into the rendering context, as an
+    /// arrangement of the collection the index is on.
+    ///
+    /// The traces are produced by [`persisted_index::persisted_index_source`], which also
+    /// attaches the input probe.
+    pub(crate) fn import_persisted_index(
+        &mut self,
+        tokens: &mut BTreeMap<GlobalId, Rc<dyn std::any::Any>>,
+        source_id: GlobalId,
+        index_desc: IndexDesc,
+        mut oks: RowRowAgent<mz_repr::Timestamp, Diff>,
+        mut errs: ErrAgent<mz_repr::Timestamp, Diff>,
+        start_signal: StartSignal,
+        pause_signal: PauseSignal,
+    ) {
+        let (ok_arranged, ok_button) = oks.import_frontier_core(
+            &self.scope.parent,
+            &format!("PersistedIndex({}, {:?})", source_id, index_desc.key),
+            self.as_of_frontier.clone(),
+            self.until.clone(),
+        );
+        let ok_arranged = MzArrangementImport::RowRow(ok_arranged.enter(&self.scope));
+        let (err_arranged, err_button) = errs.import_frontier_core(
+            &self.scope.parent,
+            &format!("ErrPersistedIndex({}, {:?})", source_id, index_desc.key),
+            self.as_of_frontier.clone(),
+            self.until.clone(),
+        );
+        let err_arranged = err_arranged.enter(&self.scope);
+
+        let ok_arranged = ok_arranged
+            .with_start_signal(start_signal.clone())
+            .with_pause_signal(pause_signal.clone());
+        let err_arranged = err_arranged
+            .with_start_signal(start_signal)
+            .with_pause_signal(pause_signal);
+
+        self.insert_id(
+            Id::Global(index_desc.on_id),
+            CollectionBundle::from_expressions(
+                index_desc.key,
+                ArrangementFlavor::Trace(source_id, ok_arranged, err_arranged),
+            ),
+        );
+
+        // Keep the read operators alive for as long as the imports.
+        let token = tokens.remove(&source_id);
+        tokens.insert(
+            source_id,
+            Rc::new((ok_button.press_on_drop(), err_button.press_on_drop(), token)),
+        );
+    }
 }
 
 // This implementation block requires the scopes have the same timestamp as the trace manager.
@@ -1267,6 +1463,9 @@ pub trait RenderTimestamp: Timestamp + Lattice + Refines<mz_repr::Timestamp> + C
     /// Steps the timestamp back so that logical compaction to the output will
     /// not conflate `self` with any historical times.
     fn step_back(&self) -> Self;
+    /// Whether the timestamp is totally ordered, i.e., whether its `Ord` implementation agrees
+    /// with its `PartialOrder` implementation.
+    fn is_totally_ordered() -> bool;
 }
 
 impl RenderTimestamp for mz_repr::Timestamp {
@@ -1288,6 +1487,9 @@ impl RenderTimestamp for mz_repr::Timestamp {
     fn step_back(&self) -> Self {
         self.saturating_sub(1)
     }
+    fn is_totally_ordered() -> bool {
+        true
+    }
 }
 
 impl RenderTimestamp for Product<mz_repr::Timestamp, PointStamp<u64>> {
@@ -1317,6 +1519,9 @@ impl RenderTimestamp for Product<mz_repr::Timestamp, PointStamp<u64>> {
         }
         Product::new(self.outer.saturating_sub(1), PointStamp::new(vec))
     }
+    fn is_totally_ordered() -> bool {
+        false
+    }
 }
 
 /// A signal that can be awaited by operators to suspend them prior to startup.
@@ -1449,6 +1654,94 @@ where
     }
 }
 
+/// A signal that pauses a dataflow, used to stop it from growing beyond its memory limit.
+///
+/// Pausing is implemented by gating the inputs of the dataflow: Once the signal is set, the gate
+/// operators discard all further input data and hold back their output frontiers at the times
+/// they had reached. Downstream operators therefore never observe progress beyond the pause
+/// point, so the dataflow's outputs remain correct, if stale. Because input data is discarded, a
+/// paused dataflow cannot be resumed; it stays paused until it is dropped. The compute controller
+/// fails queries that depend on a paused dataflow, instead of letting them wait forever.
+#[derive(Clone, Default)]
+pub(crate) struct PauseSignal {
+    paused: Rc<Cell<bool>>,
+}
+
+impl PauseSignal {
+    /// Pause the dataflow.
+    pub fn pause(&self) {
+        self.paused.set(true);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+}
+
+/// Extension trait to attach a `PauseSignal` to operator outputs.
+pub(crate) trait WithPauseSignal {
+    /// Passes data and progress updates through until the pause signal is set, and discards
+    /// data and holds back progress afterwards.
+    fn with_pause_signal(self, signal: PauseSignal) -> Self;
+}
+
+impl<S> WithPauseSignal for MzArrangementImport<S>
+where
+    S: Scope,
+    S::Timestamp: RenderTimestamp,
+{
+    fn with_pause_signal(self, signal: PauseSignal) -> Self {
+        match self {
+            MzArrangementImport::RowRow(arr) => {
+                MzArrangementImport::RowRow(arr.with_pause_signal(signal))
+            }
+        }
+    }
+}
+
+impl<S, Tr> WithPauseSignal for Arranged<S, Tr>
+where
+    S: Scope,
+    S::Timestamp: RenderTimestamp,
+    Tr: TraceReader + Clone,
+{
+    fn with_pause_signal(self, signal: PauseSignal) -> Self {
+        Arranged {
+            stream: self.stream.with_pause_signal(signal),
+            trace: self.trace,
+        }
+    }
+}
+
+impl<S, D> WithPauseSignal for Stream<S, D>
+where
+    S: Scope,
+    D: timely::Data,
+{
+    fn with_pause_signal(self, signal: PauseSignal) -> Self {
+        self.unary_frontier(Pipeline, "PauseSignal", |cap, _info| {
+            // Capabilities tracking the input frontier, which we stop downgrading once paused.
+            let mut held_caps = CapabilitySet::from_elem(cap);
+            let mut buffer = Vec::new();
+
+            move |input, output| {
+                // Discard all input once paused, without releasing the held capabilities.
+                if signal.is_paused() {
+                    input.for_each(|_cap, _data| {});
+                    return;
+                }
+
+                input.for_each(|cap, data| {
+                    data.swap(&mut buffer);
+                    output.session(&cap).give_container(&mut buffer);
+                });
+
+                held_caps.downgrade(input.frontier().frontier().iter());
+            }
+        })
+    }
+}
+
 /// Suppress progress messages for times before the given `as_of`.
 ///
 /// This operator exists specifically to work around a memory spike we'd otherwise see when
@@ -1534,3 +1827,45 @@ impl Pairer {
         (first, second)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::Timestamp;
+    use timely::dataflow::operators::{Input, Inspect};
+    use timely::dataflow::ProbeHandle;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn pause_signal() {
+        timely::execute_directly(|worker| {
+            let signal = PauseSignal::default();
+            let output = Rc::new(RefCell::new(Vec::new()));
+            let mut probe = ProbeHandle::<Timestamp>::new();
+            let mut input = worker.dataflow::<Timestamp, _, _>(|scope| {
+                let (handle, stream) = scope.new_input::<u64>();
+                let output = Rc::clone(&output);
+                stream
+                    .with_pause_signal(signal.clone())
+                    .inspect(move |x| output.borrow_mut().push(*x))
+                    .probe_with(&mut probe);
+                handle
+            });
+
+            input.send(1);
+            input.advance_to(Timestamp::new(1));
+            worker.step_while(|| probe.less_than(&Timestamp::new(1)));
+            assert_eq!(*output.borrow(), [1]);
+
+            // Once paused, input data is discarded and the output frontier is held back.
+            signal.pause();
+            input.send(2);
+            input.advance_to(Timestamp::new(2));
+            for _ in 0..10 {
+                worker.step();
+            }
+            assert_eq!(*output.borrow(), [1]);
+            assert!(probe.less_than(&Timestamp::new(2)));
+        });
+    }
+}
@@ -0,0 +1,697 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Hydration of index arrangements from checkpoints.
+//!
+//! Checkpoints are written as described in [`crate::arrangement::checkpoint`]. A dataflow
+//! exporting a single index through a linear plan can hydrate its arrangement from the latest
+//! complete checkpoint at a time `C` not beyond the dataflow `as_of` `A`, instead of reading the
+//! full contents of its inputs at `A`:
+//!
+//!  * Each worker loads the checkpoint contents for the keys it owns, at `A`.
+//!  * A designated worker reads the input changes in `(C, A]` and feeds them through the
+//!    dataflow at `A`. The plan being linear, they result in the changes of the arrangement in
+//!    the same interval. For inputs in the txn-wal system, like tables, reads at `A` are first
+//!    unblocked through the txns shard.
+//!  * All workers read the input changes beyond `A`, as they would otherwise.
+//!
+//! Whether a checkpoint is usable is only known once the dataflow is running, so the dataflow is
+//! rendered for both modes of hydration. The designated worker makes the decision and
+//! broadcasts it, and the operators rendered only for the mode not chosen are shut down.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+
+use differential_dataflow::consolidation::consolidate;
+use differential_dataflow::hashable::Hashable;
+use differential_dataflow::operators::arrange::Arranged;
+use differential_dataflow::Collection;
+use mz_compute_types::dataflows::DataflowDescription;
+use mz_compute_types::dyncfgs::{ARRANGEMENT_CHECKPOINT_INTERVAL, ENABLE_ARRANGEMENT_CHECKPOINTS};
+use mz_compute_types::plan::flat_plan::FlatPlan;
+use mz_expr::{permutation_for_arrangement, EvalError, MfpPlan, MirScalarExpr, SafeMfpPlan};
+use mz_ore::cast::CastFrom;
+use mz_ore::retry::Retry;
+use mz_ore::url::SensitiveUrl;
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::cfg::USE_CRITICAL_SINCE_SNAPSHOT;
+use mz_persist_client::operators::shard_source::SnapshotMode;
+use mz_persist_client::read::{Listen, ListenEvent};
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{DatumVec, Diff, GlobalId, Row, RowArena, Timestamp};
+use mz_storage_operators::persist_source;
+use mz_storage_types::controller::{CollectionMetadata, TxnsCodecRow};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::builder_async::{OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton};
+use mz_timely_util::operator::CollectionExt;
+use mz_txn_wal::operator::TxnsContext;
+use serde::{Deserialize, Serialize};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Broadcast, Concat, Inspect, Operator};
+use timely::dataflow::{Scope, Stream};
+use timely::progress::Antichain;
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::arrangement::checkpoint::{
+    decode_part, list_checkpoints, ArrangementCheckpointer, CheckpointScope,
+};
+use crate::compute_state::ComputeState;
+use crate::extensions::arrange::{KeyCollection, MzArrange};
+use crate::render::persisted_index::{arrange_persisted_index, ReadEvent};
+use crate::render::StartSignal;
+use crate::typedefs::{ErrAgent, ErrSpine, RowRowAgent};
+
+/// A stream of updates of a dataflow input.
+type UpdateStreams<G> = (
+    Stream<G, (Row, Timestamp, Diff)>,
+    Stream<G, (DataflowError, Timestamp, Diff)>,
+);
+
+/// The plan for hydrating and checkpointing the index exported by a dataflow.
+pub(crate) struct CheckpointPlan {
+    /// The ID of the exported index.
+    pub index_id: GlobalId,
+    /// The scope of the checkpoints written and read by this replica.
+    scope: CheckpointScope,
+    /// The key of the exported index.
+    pub key: Vec<MirScalarExpr>,
+    /// The arity of the indexed collection.
+    arity: usize,
+    /// The blob storage holding checkpoints.
+    blob_uri: SensitiveUrl,
+    /// The dataflow inputs, with the operators applied to them on import.
+    inputs: BTreeMap<GlobalId, (CollectionMetadata, Option<SafeMfpPlan>)>,
+    /// The `as_of` of the dataflow.
+    pub as_of: Timestamp,
+}
+
+impl CheckpointPlan {
+    /// Returns a plan for the given dataflow, if checkpoints are enabled and the dataflow
+    /// supports them.
+    ///
+    /// The dataflow must export a single index, built by a linear plan from persist inputs, and
+    /// the replica must know its ID, to scope the checkpoints. If a plan is returned, the
+    /// arrangement of the index is removed from the dataflow, to be rendered by
+    /// [`render_arrangement`] instead.
+    pub fn for_dataflow(
+        dataflow: &mut DataflowDescription<FlatPlan, CollectionMetadata>,
+        compute_state: &ComputeState,
+        until: &Antichain<Timestamp>,
+    ) -> Option<Self> {
+        if !ENABLE_ARRANGEMENT_CHECKPOINTS.get(&compute_state.worker_config)
+            || dataflow.is_transient()
+            || !until.is_empty()
+            || dataflow.index_exports.len() != 1
+            || !dataflow.sink_exports.is_empty()
+            || !dataflow.index_imports.is_empty()
+            || dataflow.source_imports.is_empty()
+        {
+            return None;
+        }
+        let replica_id = compute_state.replica_id?;
+
+        let as_of = *dataflow.as_of.as_ref()?.as_option()?;
+        let (&index_id, (index, typ)) = dataflow.index_exports.first_key_value()?;
+        if dataflow.objects_to_build.last()?.id != index_id
+            || !dataflow.objects_to_build.iter().all(|o| o.plan.is_linear())
+        {
+            return None;
+        }
+
+        let mut inputs = BTreeMap::new();
+        for (id, (source, _monotonic)) in &dataflow.source_imports {
+            let metadata = &source.storage_metadata;
+            if source.arguments.persisted_index.is_some() {
+                return None;
+            }
+            let mfp = match &source.arguments.operators {
+                Some(operators) => {
+                    let plan = MfpPlan::create_from(operators.clone()).ok()?;
+                    Some(plan.into_nontemporal().ok()?)
+                }
+                None => None,
+            };
+            inputs.insert(*id, (metadata.clone(), mfp));
+        }
+        let blob_uri = inputs.values().next()?.0.persist_location.blob_uri.clone();
+
+        let key = index.key.clone();
+        let arity = typ.arity();
+        let object = dataflow.objects_to_build.last_mut()?;
+        if !object.plan.take_root_arrangement(&key) {
+            return None;
+        }
+
+        Some(Self {
+            index_id,
+            scope: CheckpointScope {
+                index_id,
+                replica_id,
+            },
+            key,
+            arity,
+            blob_uri,
+            inputs,
+            as_of,
+        })
+    }
+
+    /// Returns a checkpointer for the arrangement rendered by [`render_arrangement`].
+    pub fn checkpointer(
+        &self,
+        compute_state: &ComputeState,
+        worker: usize,
+        peers: usize,
+        oks: RowRowAgent<Timestamp, Diff>,
+        errs: ErrAgent<Timestamp, Diff>,
+    ) -> ArrangementCheckpointer {
+        ArrangementCheckpointer::new(
+            self.scope,
+            worker,
+            peers,
+            Arc::clone(&compute_state.persist_clients),
+            self.blob_uri.clone(),
+            oks,
+            errs,
+            self.as_of,
+            ARRANGEMENT_CHECKPOINT_INTERVAL.get(&compute_state.worker_config),
+            compute_state.read_only_rx.clone(),
+        )
+    }
+}
+
+/// The way an index arrangement is hydrated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Hydration {
+    /// From the checkpoint at `time`, written by `peers` workers.
+    Checkpoint { time: Timestamp, peers: usize },
+    /// From the full contents of the inputs at the `as_of`.
+    Full,
+}
+
+/// Distributes the hydration decision to the operators of a worker.
+///
+/// Dropping the gate shuts down all operators registered with it.
+pub(crate) struct HydrationGate {
+    decision: watch::Sender<Option<Hydration>>,
+    /// Tokens of operators only required for full hydration.
+    full_only: RefCell<Vec<Rc<dyn Any>>>,
+    /// Tokens of operators required for either mode of hydration.
+    retained: RefCell<Vec<Rc<dyn Any>>>,
+}
+
+impl HydrationGate {
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self {
+            decision: watch::channel(None).0,
+            full_only: Default::default(),
+            retained: Default::default(),
+        })
+    }
+
+    fn decide(&self, hydration: Hydration) {
+        self.decision.send_replace(Some(hydration));
+        if hydration != Hydration::Full {
+            // Drop outside of the borrow, in case dropping activates other operators.
+            let tokens = std::mem::take(&mut *self.full_only.borrow_mut());
+            drop(tokens);
+        }
+    }
+
+    /// Returns a future resolving to the decision, once it is made.
+    fn decision(&self) -> impl std::future::Future<Output = Hydration> + 'static {
+        let mut rx = self.decision.subscribe();
+        async move {
+            match rx.wait_for(Option::is_some).await {
+                Ok(decision) => (*decision).expect("waited for decision"),
+                // The gate was dropped, so the dataflow is shutting down.
+                Err(_) => std::future::pending().await,
+            }
+        }
+    }
+
+    /// Returns a future resolving once full hydration has been decided on.
+    fn full(&self) -> impl std::future::Future<Output = ()> + 'static {
+        let decision = self.decision();
+        async move {
+            if decision.await != Hydration::Full {
+                std::future::pending().await
+            }
+        }
+    }
+}
+
+/// Renders the operator deciding how the index is hydrated.
+///
+/// The decision is made by a designated worker. If it finds a usable checkpoint, it also reads
+/// the input changes between the checkpoint time and the `as_of`, which are returned per input,
+/// at the `as_of`.
+pub(crate) fn render_coordinator<G>(
+    scope: &G,
+    plan: &CheckpointPlan,
+    persist_clients: Arc<PersistClientCache>,
+    txns_ctx: TxnsContext,
+    start_signal: StartSignal,
+    gate: &Rc<HydrationGate>,
+) -> BTreeMap<GlobalId, UpdateStreams<G>>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let index_id = plan.index_id;
+    let checkpoint_scope = plan.scope;
+    let as_of = plan.as_of;
+    let blob_uri = plan.blob_uri.clone();
+    let inputs = plan.inputs.clone();
+
+    let mut op =
+        AsyncOperatorBuilder::new(format!("CheckpointHydration({index_id})"), scope.clone());
+    let (decision_output, decisions) = op.new_output::<CapacityContainerBuilder<_>>();
+    let mut outputs = Vec::new();
+    let mut streams = BTreeMap::new();
+    for id in inputs.keys() {
+        let (oks_output, oks) = op.new_output::<CapacityContainerBuilder<_>>();
+        let (errs_output, errs) = op.new_output::<CapacityContainerBuilder<_>>();
+        outputs.push((oks_output, errs_output));
+        streams.insert(*id, (oks, errs));
+    }
+
+    let peers = scope.peers();
+    let designated = usize::cast_from(index_id.hashed()) % peers == scope.index();
+
+    let button = op.build(move |capabilities| async move {
+        if !designated {
+            return;
+        }
+        let mut capabilities = capabilities.into_iter().map(|cap| cap.delayed(&as_of));
+        let decision_cap = capabilities.next().expect("one capability per output");
+        let input_caps: Vec<_> = capabilities.collect();
+
+        start_signal.await;
+
+        let checkpoint = find_checkpoint(
+            checkpoint_scope,
+            as_of,
+            peers,
+            &persist_clients,
+            &txns_ctx,
+            blob_uri,
+            &inputs,
+        )
+        .await;
+        let Some((time, written_peers, listens)) = checkpoint else {
+            decision_output.give(&decision_cap, Hydration::Full);
+            return;
+        };
+        info!(%index_id, %time, %as_of, "hydrating index from checkpoint");
+        let hydration = Hydration::Checkpoint {
+            time,
+            peers: written_peers,
+        };
+        decision_output.give(&decision_cap, hydration);
+        drop(decision_cap);
+
+        // Read the input changes in `(time, as_of]`.
+        let mut datum_vec = DatumVec::new();
+        let mut row_builder = Row::default();
+        let caps = input_caps.chunks(2);
+        let mfps = inputs.values().map(|(_metadata, mfp)| mfp);
+        for (((mut listen, (oks_output, errs_output)), caps), mfp) in
+            listens.into_iter().zip(&outputs).zip(caps).zip(mfps)
+        {
+            let (oks_cap, errs_cap) = (&caps[0], &caps[1]);
+            let mut done = false;
+            while !done {
+                for event in listen.fetch_next().await {
+                    let updates = match event {
+                        ListenEvent::Updates(updates) => updates,
+                        ListenEvent::Progress(frontier) => {
+                            done = !frontier.less_equal(&as_of);
+                            continue;
+                        }
+                    };
+                    for ((data, _unit), time, diff) in updates {
+                        if time > as_of {
+                            continue;
+                        }
+                        let row = match data {
+                            Ok(SourceData(row)) => row,
+                            Err(err) => Err(EvalError::Internal(err.into()).into()),
+                        };
+                        let row = match (row, mfp) {
+                            (Ok(row), Some(mfp)) => {
+                                let arena = RowArena::new();
+                                let mut datums = datum_vec.borrow_with(&row);
+                                mfp.evaluate_into(&mut datums, &arena, &mut row_builder)
+                                    .map_err(DataflowError::from)
+                            }
+                            (row, _) => row.map(Some),
+                        };
+                        match row {
+                            Ok(Some(row)) => oks_output.give(oks_cap, (row, as_of, diff)),
+                            Ok(None) => {}
+                            Err(err) => errs_output.give(errs_cap, (err, as_of, diff)),
+                        }
+                    }
+                }
+            }
+            listen.expire().await;
+        }
+    });
+
+    // Share the decision with all workers.
+    let weak_gate = Rc::downgrade(gate);
+    decisions.broadcast().inspect(move |hydration| {
+        if let Some(gate) = Weak::upgrade(&weak_gate) {
+            gate.decide(*hydration);
+        }
+    });
+    gate.retained
+        .borrow_mut()
+        .push(Rc::new(button.press_on_drop()));
+
+    streams
+}
+
+/// Looks for the latest complete checkpoint of the index not beyond the `as_of`, whose input
+/// changes are still readable.
+///
+/// Returns the checkpoint time and number of workers, together with listens on the inputs, in
+/// order, from the checkpoint time. The listens are guaranteed to progress past the `as_of`.
+async fn find_checkpoint(
+    scope: CheckpointScope,
+    as_of: Timestamp,
+    peers: usize,
+    persist_clients: &PersistClientCache,
+    txns_ctx: &TxnsContext,
+    blob_uri: SensitiveUrl,
+    inputs: &BTreeMap<GlobalId, (CollectionMetadata, Option<SafeMfpPlan>)>,
+) -> Option<(
+    Timestamp,
+    usize,
+    Vec<Listen<SourceData, (), Timestamp, Diff>>,
+)> {
+    let index_id = scope.index_id;
+    let checkpoints = async {
+        let blob = persist_clients.open_blob(blob_uri).await?;
+        list_checkpoints(&*blob, scope).await
+    };
+    let checkpoints = match checkpoints.await {
+        Ok(checkpoints) => checkpoints,
+        Err(error) => {
+            warn!(%index_id, "failed to list checkpoints: {error}");
+            return None;
+        }
+    };
+
+    // Prefer checkpoints written by as many workers as this replica has, which can be loaded
+    // without filtering.
+    let (time, written_peers) = checkpoints
+        .into_iter()
+        .filter(|((time, _), listing)| listing.complete && *time <= as_of)
+        .map(|(key, _listing)| key)
+        .max_by_key(|(time, written_peers)| (*time, *written_peers == peers))?;
+
+    let mut listens = Vec::new();
+    for (id, (metadata, _mfp)) in inputs {
+        let client = persist_clients
+            .open(metadata.persist_location.clone())
+            .await
+            .expect("location must be valid");
+        let reader = client
+            .open_leased_reader::<SourceData, (), Timestamp, Diff>(
+                metadata.data_shard,
+                Arc::new(metadata.relation_desc.clone()),
+                Arc::new(UnitSchema),
+                Diagnostics {
+                    shard_name: id.to_string(),
+                    handle_purpose: format!("arrangement checkpoint hydration {index_id}"),
+                },
+                USE_CRITICAL_SINCE_SNAPSHOT.get(client.dyncfgs()),
+            )
+            .await
+            .expect("codecs must match");
+        // Writes to txn-wal data shards are only applied lazily, so their physical upper can be
+        // behind the `as_of`. Unblock reading the shard at the `as_of` before listening.
+        let listen = match metadata.txns_shard {
+            Some(txns_shard) => {
+                let txns_read = txns_ctx
+                    .get_or_init::<Timestamp, TxnsCodecRow>(&client, txns_shard)
+                    .await;
+                txns_read.update_gt(as_of).await;
+                let snapshot = txns_read.data_snapshot(metadata.data_shard, as_of).await;
+                snapshot.listen(reader, Antichain::from_elem(time)).await
+            }
+            None => reader.listen(Antichain::from_elem(time)).await,
+        };
+        match listen {
+            Ok(listen) => listens.push(listen),
+            Err(since) => {
+                debug!(%index_id, %time, ?since, "input {id} compacted beyond checkpoint");
+                return None;
+            }
+        }
+    }
+
+    Some((time, written_peers, listens))
+}
+
+/// Renders the import of a dataflow input that supports hydration from a checkpoint.
+///
+/// `suffix` are the updates read by the coordinator for this input. Returns the streams of
+/// updates and a token keeping the import alive.
+pub(crate) fn render_source<G>(
+    scope: &mut G,
+    source_id: GlobalId,
+    compute_state: &ComputeState,
+    metadata: CollectionMetadata,
+    mut mfp: Option<MfpPlan>,
+    as_of: Timestamp,
+    start_signal: StartSignal,
+    gate: &Rc<HydrationGate>,
+    suffix: UpdateStreams<G>,
+) -> (
+    Stream<G, (Row, Timestamp, Diff)>,
+    Stream<G, (DataflowError, Timestamp, Diff)>,
+    Rc<dyn Any>,
+)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    // The snapshot at the `as_of` is only read for full hydration.
+    let snapshot_start = {
+        let start_signal = start_signal.clone();
+        let full = gate.full();
+        async move {
+            start_signal.await;
+            full.await;
+        }
+    };
+    let mut snapshot_mfp = mfp.clone();
+    let (snapshot_oks, snapshot_errs, snapshot_token) = persist_source::persist_source(
+        scope,
+        source_id,
+        Arc::clone(&compute_state.persist_clients),
+        &compute_state.txns_ctx,
+        &compute_state.worker_config,
+        metadata.clone(),
+        Some(Antichain::from_elem(as_of)),
+        SnapshotMode::Include,
+        Antichain::from_elem(as_of.step_forward()),
+        snapshot_mfp.as_mut(),
+        compute_state.dataflow_max_inflight_bytes(),
+        snapshot_start,
+        |error| panic!("compute_import: {error}"),
+    );
+    assert!(snapshot_mfp.map(|x| x.is_identity()).unwrap_or(true));
+
+    // Changes beyond the `as_of` are read in either case.
+    let (oks, errs, token) = persist_source::persist_source(
+        scope,
+        source_id,
+        Arc::clone(&compute_state.persist_clients),
+        &compute_state.txns_ctx,
+        &compute_state.worker_config,
+        metadata,
+        Some(Antichain::from_elem(as_of)),
+        SnapshotMode::Exclude,
+        Antichain::new(),
+        mfp.as_mut(),
+        compute_state.dataflow_max_inflight_bytes(),
+        start_signal,
+        |error| panic!("compute_import: {error}"),
+    );
+    assert!(mfp.map(|x| x.is_identity()).unwrap_or(true));
+
+    gate.full_only.borrow_mut().push(Rc::new(snapshot_token));
+
+    let (suffix_oks, suffix_errs) = suffix;
+    let oks = oks.concat(&snapshot_oks).concat(&suffix_oks);
+    let errs = errs.concat(&snapshot_errs).concat(&suffix_errs);
+
+    (oks, errs, Rc::new((token, Rc::clone(gate))))
+}
+
+/// Renders the arrangement of the index, from the collection produced by the dataflow.
+///
+/// For hydration from a checkpoint, the arrangement starts out with the checkpoint contents.
+pub(crate) fn render_arrangement<G>(
+    plan: &CheckpointPlan,
+    persist_clients: Arc<PersistClientCache>,
+    gate: &Rc<HydrationGate>,
+    oks: Collection<G, Row, Diff>,
+    errs: Collection<G, DataflowError, Diff>,
+) -> (
+    Arranged<G, RowRowAgent<Timestamp, Diff>>,
+    Arranged<G, ErrAgent<Timestamp, Diff>>,
+)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let index_id = plan.index_id;
+    let as_of = plan.as_of;
+    let scope = oks.scope();
+
+    // Form keys and values as the arrangement of the index would.
+    let key = plan.key.clone();
+    let (_permutation, thinning) = permutation_for_arrangement(&key, plan.arity);
+    let mut datums = DatumVec::new();
+    type CB<C> = CapacityContainerBuilder<C>;
+    let (oks, key_errs) =
+        oks.map_fallible::<CB<_>, CB<_>, _, _, _>("CheckpointFormArrangementKey", move |row| {
+            let datums = datums.borrow_with(&row);
+            let temp_storage = RowArena::new();
+            let mut key_row = Row::default();
+            let mut packer = key_row.packer();
+            for expr in &key {
+                packer.push(expr.eval(&datums, &temp_storage)?);
+            }
+            let val_row = Row::pack(thinning.iter().map(|c| datums[*c]));
+            Ok::<_, DataflowError>((key_row, val_row))
+        });
+    let updates = oks.inner.unary(
+        Exchange::new(
+            |((key, _val), _time, _diff): &((Row, Row), Timestamp, Diff)| key.hashed().into(),
+        ),
+        "CheckpointExchange",
+        |_capability, _info| {
+            let mut buffer = Vec::new();
+            move |input, output| {
+                input.for_each(|cap, data| {
+                    data.swap(&mut buffer);
+                    let updates = std::mem::take(&mut buffer);
+                    output.session(&cap).give(ReadEvent::Updates(updates));
+                });
+            }
+        },
+    );
+
+    let (snapshot, button) = load_checkpoint(
+        &scope,
+        plan.scope,
+        as_of,
+        persist_clients,
+        plan.blob_uri.clone(),
+        gate,
+    );
+    gate.retained.borrow_mut().push(Rc::new(button));
+
+    let oks = arrange_persisted_index(
+        &format!("CheckpointArrange({index_id})"),
+        updates.concat(&snapshot),
+        Antichain::from_elem(as_of),
+    );
+
+    let errs: KeyCollection<_, _, _> = errs.concat(&key_errs).into();
+    let errs = errs.mz_arrange::<ErrSpine<_, _>>(&format!("CheckpointErrors({index_id})"));
+
+    (oks, errs)
+}
+
+/// Renders an operator that loads the checkpoint contents owned by the local worker, if the
+/// index is hydrated from a checkpoint.
+fn load_checkpoint<G>(
+    scope: &G,
+    checkpoint_scope: CheckpointScope,
+    as_of: Timestamp,
+    persist_clients: Arc<PersistClientCache>,
+    blob_uri: SensitiveUrl,
+    gate: &HydrationGate,
+) -> (Stream<G, ReadEvent>, PressOnDropButton)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let index_id = checkpoint_scope.index_id;
+    let mut op = AsyncOperatorBuilder::new(format!("CheckpointLoad({index_id})"), scope.clone());
+    let (output, stream) = op.new_output::<CapacityContainerBuilder<_>>();
+
+    let peers = scope.peers();
+    let worker = scope.index();
+    let owns_key = move |key: &Row| usize::cast_from(key.hashed()) % peers == worker;
+    let decision = gate.decision();
+
+    let button = op.build(move |capabilities| async move {
+        let [cap]: [_; 1] = capabilities.try_into().expect("one capability per output");
+        let cap = cap.delayed(&as_of);
+
+        let Hydration::Checkpoint {
+            time,
+            peers: written_peers,
+        } = decision.await
+        else {
+            return;
+        };
+
+        let blob = Retry::default()
+            .retry_async(|_| persist_clients.open_blob(blob_uri.clone()))
+            .await
+            .expect("retries forever");
+
+        // With a different number of workers, keys are distributed differently, so every worker
+        // reads all parts and retains the keys it owns.
+        let workers = if written_peers == peers {
+            worker..worker + 1
+        } else {
+            0..written_peers
+        };
+        let mut snapshot = Vec::new();
+        for part_worker in workers {
+            let key = checkpoint_scope.part_key(time, written_peers, part_worker);
+            let part = Retry::default()
+                .retry_async(|_| blob.get(&key))
+                .await
+                .expect("retries forever");
+            // Parts of complete checkpoints are only deleted once a later checkpoint is complete,
+            // in which case a restart hydrates from that.
+            let Some(part) = part else {
+                panic!("checkpoint part {key} of index {index_id} missing");
+            };
+            let updates = decode_part(&part.into_contiguous())
+                .unwrap_or_else(|error| panic!("invalid checkpoint part {key}: {error}"));
+            if written_peers == peers {
+                snapshot = updates;
+            } else {
+                snapshot.extend(updates.into_iter().filter(|((key, _), _)| owns_key(key)));
+            }
+        }
+        if written_peers != peers {
+            consolidate(&mut snapshot);
+        }
+
+        output.give(&cap, ReadEvent::Snapshot(snapshot));
+    });
+
+    (stream, button.press_on_drop())
+}
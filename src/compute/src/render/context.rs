@@ -23,6 +23,7 @@ use differential_dataflow::{Collection, Data};
 use mz_compute_types::dataflows::DataflowDescription;
 use mz_compute_types::plan::{AvailableCollections, LirId};
 use mz_expr::{Id, MapFilterProject, MirScalarExpr};
+use mz_ore::metrics::IntCounter;
 use mz_repr::fixed_length::{FromDatumIter, ToDatumIter};
 use mz_repr::{DatumVec, DatumVecBorrow, Diff, GlobalId, Row, RowArena, SharedRow};
 use mz_storage_types::controller::CollectionMetadata;
@@ -89,6 +90,8 @@ where
     pub(super) compute_logger: Option<crate::logging::compute::Logger>,
     /// Specification for rendering linear joins.
     pub(super) linear_join_spec: LinearJoinSpec,
+    /// Counter of group recomputations performed by bounded TopK operators.
+    pub(super) bounded_top_k_recomputations: IntCounter,
     /// The expiration time for dataflows in this context. The output's frontier should never advance
     /// past this frontier, except the empty frontier.
     pub dataflow_expiration: Antichain<T>,
@@ -141,6 +144,10 @@ where
             hydration_logger,
             compute_logger,
             linear_join_spec: compute_state.linear_join_spec,
+            bounded_top_k_recomputations: compute_state
+                .worker_metrics
+                .bounded_top_k_recomputations_total
+                .clone(),
             dataflow_expiration,
         }
     }
@@ -0,0 +1,403 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Import of persisted indexes as arrangements.
+//!
+//! A persisted index stores an arrangement in a persist collection, in the layout described in
+//! [`mz_compute_types::persisted_index`]. Instead of reading the collection through
+//! `persist_source` and arranging it, each worker reads a consolidated snapshot, retains the keys
+//! it is responsible for, and assembles them into a single arrangement batch. Persist delivers
+//! consolidated snapshots in the order arrangements store their data, so forming this batch does
+//! not require sorting. Updates beyond the snapshot are batched as the shard's upper advances.
+//!
+//! Every worker reads the whole shard and discards the keys owned by other workers. This trades
+//! additional reads from blob storage for avoiding an exchange of the snapshot between workers.
+
+use std::any::Any;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use differential_dataflow::consolidation::consolidate_updates;
+use differential_dataflow::hashable::Hashable;
+use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
+use differential_dataflow::trace::{Builder, Description, Trace, TraceReader};
+use differential_dataflow::AsCollection;
+use mz_compute_types::persisted_index;
+use mz_expr::EvalError;
+use mz_ore::cast::CastFrom;
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::cfg::USE_CRITICAL_SINCE_SNAPSHOT;
+use mz_persist_client::read::ListenEvent;
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::builder_async::OperatorBuilder as AsyncOperatorBuilder;
+use timely::container::columnation::TimelyStack;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::{probe, Operator, Probe};
+use timely::dataflow::Scope;
+use timely::progress::{Antichain, Timestamp as TimelyTimestamp};
+use timely::PartialOrder;
+use tracing::warn;
+
+use crate::extensions::arrange::{KeyCollection, MzArrange};
+use crate::render::StartSignal;
+use crate::typedefs::{ErrAgent, ErrSpine, RowRowAgent, RowRowSpine};
+
+/// The spine of imported persisted indexes.
+type Spine = RowRowSpine<Timestamp, Diff>;
+
+/// The number of updates to buffer before pushing them into a batch builder.
+const BUILDER_CHUNK_SIZE: usize = 1024;
+
+/// Data produced by the persisted index reader of a worker.
+#[derive(Clone, Debug)]
+pub(super) enum ReadEvent {
+    /// The snapshot of the keys owned by the worker at the `as_of`, consolidated and sorted.
+    Snapshot(Vec<((Row, Row), Diff)>),
+    /// Updates beyond the `as_of`.
+    Updates(Vec<((Row, Row), Timestamp, Diff)>),
+}
+
+/// Imports the persisted index stored in the collection described by `metadata`.
+///
+/// Returns handles to the traces of the arranged data and errors, and a token that keeps the
+/// import alive.
+pub(crate) fn persisted_index_source<G>(
+    scope: &G,
+    source_id: GlobalId,
+    persist_clients: Arc<PersistClientCache>,
+    metadata: CollectionMetadata,
+    as_of: Antichain<Timestamp>,
+    until: Antichain<Timestamp>,
+    start_signal: StartSignal,
+    input_probe: probe::Handle<Timestamp>,
+) -> (
+    RowRowAgent<Timestamp, Diff>,
+    ErrAgent<Timestamp, Diff>,
+    Rc<dyn Any>,
+)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let (events, errs, token) = read_persisted_index(
+        scope,
+        source_id,
+        persist_clients,
+        metadata,
+        as_of.clone(),
+        until,
+        start_signal,
+    );
+
+    let oks = arrange_persisted_index(
+        &format!("PersistedIndexArrange({source_id})"),
+        events,
+        as_of,
+    );
+    oks.stream.probe_with(&input_probe);
+
+    let errs: KeyCollection<_, _, _> = errs.as_collection().into();
+    let errs = errs.mz_arrange::<ErrSpine<_, _>>(&format!("PersistedIndexErrors({source_id})"));
+
+    (oks.trace, errs.trace, token)
+}
+
+/// Renders an operator that reads the contents of a persisted index owned by the local worker.
+fn read_persisted_index<G>(
+    scope: &G,
+    source_id: GlobalId,
+    persist_clients: Arc<PersistClientCache>,
+    metadata: CollectionMetadata,
+    as_of: Antichain<Timestamp>,
+    until: Antichain<Timestamp>,
+    start_signal: StartSignal,
+) -> (
+    timely::dataflow::Stream<G, ReadEvent>,
+    timely::dataflow::Stream<G, (DataflowError, Timestamp, Diff)>,
+    Rc<dyn Any>,
+)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let mut read_op =
+        AsyncOperatorBuilder::new(format!("PersistedIndexRead({source_id})"), scope.clone());
+    let (events_output, events) = read_op.new_output::<CapacityContainerBuilder<_>>();
+    let (errs_output, errs) = read_op.new_output::<CapacityContainerBuilder<_>>();
+
+    let peers = scope.peers();
+    let worker_index = scope.index();
+    // Errors are not partitioned by key, so a single worker reports them.
+    let error_worker = usize::cast_from(source_id.hashed()) % peers == worker_index;
+    let owns_key = move |key: &Row| usize::cast_from(key.hashed()) % peers == worker_index;
+
+    let button = read_op.build(move |capabilities| async move {
+        let [events_cap, errs_cap]: [_; 2] =
+            capabilities.try_into().expect("one capability per output");
+
+        let Some(&as_of_ts) = as_of.as_option() else {
+            // The dataflow will never produce output.
+            return;
+        };
+
+        start_signal.await;
+
+        let client = persist_clients
+            .open(metadata.persist_location.clone())
+            .await
+            .expect("location must be valid");
+        let mut reader = client
+            .open_leased_reader::<SourceData, (), Timestamp, Diff>(
+                metadata.data_shard,
+                Arc::new(metadata.relation_desc.clone()),
+                Arc::new(UnitSchema),
+                Diagnostics {
+                    shard_name: source_id.to_string(),
+                    handle_purpose: format!("persisted index import {source_id}"),
+                },
+                USE_CRITICAL_SINCE_SNAPSHOT.get(client.dyncfgs()),
+            )
+            .await
+            .expect("codecs must match");
+
+        // Decodes an update, returning `None` for keys owned by other workers.
+        let decode =
+            |data: Result<SourceData, String>| -> Result<Option<(Row, Row)>, DataflowError> {
+                let row = data.map_err(|err| EvalError::Internal(err.into()))?.0?;
+                let (key, val) =
+                    persisted_index::decode(&row).map_err(|err| EvalError::Internal(err.into()))?;
+                Ok(owns_key(&key).then_some((key, val)))
+            };
+
+        // Read the snapshot at the `as_of`.
+        let mut snapshot = Vec::new();
+        let mut snapshot_errs = Vec::new();
+        {
+            let mut cursor = match reader.snapshot_cursor(as_of.clone(), |_| true).await {
+                Ok(cursor) => cursor,
+                Err(since) => panic!(
+                    "persisted index {source_id}: as_of {as_of:?} not beyond since {since:?}"
+                ),
+            };
+            while let Some(updates) = cursor.next().await {
+                for ((data, _unit), _time, diff) in updates {
+                    match decode(data) {
+                        Ok(Some(update)) => snapshot.push((update, diff)),
+                        Ok(None) => {}
+                        Err(err) if error_worker => snapshot_errs.push((err, diff)),
+                        Err(_) => {}
+                    }
+                }
+            }
+        }
+
+        // Persist only delivers snapshots in arrangement order if it sorts consolidated data by
+        // its structured representation. Fall back to sorting if that is not the case.
+        if !snapshot.windows(2).all(|w| w[0].0 < w[1].0) {
+            warn!(%source_id, "persisted index snapshot not in arrangement order");
+            snapshot.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        let events_cap = events_cap.delayed(&as_of_ts);
+        let errs_cap = errs_cap.delayed(&as_of_ts);
+        events_output.give(&events_cap, ReadEvent::Snapshot(snapshot));
+        for (err, diff) in snapshot_errs {
+            errs_output.give(&errs_cap, (err, as_of_ts, diff));
+        }
+
+        // Follow updates beyond the snapshot, until the `until`.
+        let mut listen = match reader.listen(as_of.clone()).await {
+            Ok(listen) => listen,
+            Err(since) => {
+                panic!("persisted index {source_id}: as_of {as_of:?} not beyond since {since:?}")
+            }
+        };
+        let mut events_cap = events_cap;
+        let mut errs_cap = errs_cap;
+        loop {
+            for event in listen.fetch_next().await {
+                match event {
+                    ListenEvent::Updates(updates) => {
+                        let mut owned = Vec::new();
+                        let mut errs = Vec::new();
+                        for ((data, _unit), time, diff) in updates {
+                            match decode(data) {
+                                Ok(Some(update)) => owned.push((update, time, diff)),
+                                Ok(None) => {}
+                                Err(err) if error_worker => errs.push((err, time, diff)),
+                                Err(_) => {}
+                            }
+                        }
+                        if !owned.is_empty() {
+                            events_output.give(&events_cap, ReadEvent::Updates(owned));
+                        }
+                        for err in errs {
+                            errs_output.give(&errs_cap, err);
+                        }
+                    }
+                    ListenEvent::Progress(frontier) => {
+                        if PartialOrder::less_equal(&until, &frontier) {
+                            return;
+                        }
+                        match frontier.into_option() {
+                            Some(ts) => {
+                                events_cap.downgrade(&ts);
+                                errs_cap.downgrade(&ts);
+                            }
+                            None => return,
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    (events, errs, Rc::new(button.press_on_drop()))
+}
+
+/// Renders an operator maintaining an arrangement of the events produced by
+/// [`read_persisted_index`].
+///
+/// Updates at times not beyond the `as_of` are merged into the snapshot batch. The events of each
+/// key must be delivered to the worker owning it.
+pub(super) fn arrange_persisted_index<G>(
+    name: &str,
+    events: timely::dataflow::Stream<G, ReadEvent>,
+    as_of: Antichain<Timestamp>,
+) -> Arranged<G, RowRowAgent<Timestamp, Diff>>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let mut reader = None;
+    let reader_ref = &mut reader;
+
+    let batches = events.unary_frontier(Pipeline, name, move |capability, info| {
+        let empty = Spine::new(info.clone(), None, None);
+        let (reader_local, mut writer) = TraceAgent::new(empty, info, None);
+        *reader_ref = Some(reader_local);
+
+        let mut capability = Some(capability);
+        // The upper of the batches inserted into the trace so far.
+        let mut upper = Antichain::from_elem(Timestamp::minimum());
+        let mut snapshot = None;
+        let mut pending = Vec::new();
+        let mut buffer = Vec::new();
+
+        move |input, output| {
+            while let Some((_cap, data)) = input.next() {
+                data.swap(&mut buffer);
+                for event in buffer.drain(..) {
+                    match event {
+                        ReadEvent::Snapshot(updates) => snapshot = Some(updates),
+                        ReadEvent::Updates(updates) => pending.extend(updates),
+                    }
+                }
+            }
+
+            let frontier = input.frontier().frontier().to_owned();
+            let Some(cap) = capability.as_mut() else {
+                return;
+            };
+
+            // Once the snapshot is complete, insert it as a single batch ending just after
+            // the `as_of`. The snapshot is already sorted, so unless there are updates to merge
+            // into it, it can be pushed into the builder directly.
+            if let Some(&as_of_ts) = as_of.as_option() {
+                if PartialOrder::less_equal(&upper.borrow(), &as_of.borrow())
+                    && !frontier.less_equal(&as_of_ts)
+                {
+                    let new_upper = Antichain::from_elem(as_of_ts.step_forward());
+                    let updates = snapshot.take().unwrap_or_default();
+                    let mut updates: Vec<_> = updates
+                        .into_iter()
+                        .map(|(kv, diff)| (kv, as_of_ts, diff))
+                        .collect();
+                    let (early, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut pending)
+                        .into_iter()
+                        .partition(|(_, time, _)| time.less_equal(&as_of_ts));
+                    pending = rest;
+                    if !early.is_empty() {
+                        let early = early.into_iter().map(|(kv, _, diff)| (kv, as_of_ts, diff));
+                        updates.extend(early);
+                        consolidate_updates(&mut updates);
+                    }
+                    let batch = build_batch(updates, upper.clone(), new_upper.clone());
+                    output.session(&cap.delayed(&as_of_ts)).give(batch.clone());
+                    writer.insert(batch, Some(as_of_ts));
+                    upper = new_upper;
+                }
+            }
+
+            // Insert updates beyond the snapshot as the frontier advances.
+            if PartialOrder::less_equal(&as_of.borrow(), &upper.borrow())
+                && PartialOrder::less_than(&upper.borrow(), &frontier.borrow())
+            {
+                let (mut ready, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut pending)
+                    .into_iter()
+                    .partition(|(_, time, _)| !frontier.less_equal(time));
+                pending = rest;
+                consolidate_updates(&mut ready);
+
+                let lower = upper.clone();
+                let batch = build_batch(ready, lower.clone(), frontier.clone());
+                if let Some(&time) = lower.as_option() {
+                    output.session(&cap.delayed(&time)).give(batch.clone());
+                    writer.insert(batch, Some(time));
+                } else {
+                    writer.seal(frontier.clone());
+                }
+                upper = frontier.clone();
+            }
+
+            match frontier.as_option() {
+                Some(time) => cap.downgrade(time),
+                None => {
+                    capability = None;
+                    if !upper.is_empty() {
+                        writer.seal(Antichain::new());
+                    }
+                }
+            }
+        }
+    });
+    Arranged {
+        stream: batches,
+        trace: reader.expect("operator constructed"),
+    }
+}
+
+/// Builds a batch from sorted and consolidated `updates`.
+fn build_batch(
+    updates: impl IntoIterator<Item = ((Row, Row), Timestamp, Diff)>,
+    lower: Antichain<Timestamp>,
+    upper: Antichain<Timestamp>,
+) -> <Spine as TraceReader>::Batch {
+    let mut builder = <Spine as Trace>::Builder::with_capacity(0, 0, 0);
+    let mut chunk = TimelyStack::default();
+    for update in updates {
+        chunk.copy(&update);
+        if chunk.len() >= BUILDER_CHUNK_SIZE {
+            builder.push(&mut chunk);
+            chunk.clear();
+        }
+    }
+    if !chunk.is_empty() {
+        builder.push(&mut chunk);
+        chunk.clear();
+    }
+    builder.done(Description::new(
+        lower,
+        upper,
+        Antichain::from_elem(Timestamp::minimum()),
+    ))
+}
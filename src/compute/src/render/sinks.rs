@@ -149,6 +149,9 @@ where
             ComputeSinkConnection::CopyToS3Oneshot(_) => {
                 format!("CopyToS3OneshotSink({:?})", sink_id)
             }
+            ComputeSinkConnection::PersistedIndex(_) => {
+                format!("PersistedIndexSink({:?})", sink_id)
+            }
         };
         self.scope
             .parent
@@ -208,5 +211,6 @@ where
         ComputeSinkConnection::MaterializedView(connection) => Box::new(connection.clone()),
         ComputeSinkConnection::ContinualTask(connection) => Box::new(connection.clone()),
         ComputeSinkConnection::CopyToS3Oneshot(connection) => Box::new(connection.clone()),
+        ComputeSinkConnection::PersistedIndex(connection) => Box::new(connection.clone()),
     }
 }
@@ -19,10 +19,10 @@ use differential_dataflow::hashable::Hashable;
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
 use differential_dataflow::trace::cursor::IntoOwned;
-use differential_dataflow::trace::{Batch, Builder, Trace, TraceReader};
+use differential_dataflow::trace::{Batch, BatchReader, Builder, Cursor, Trace, TraceReader};
 use differential_dataflow::{AsCollection, Collection};
 use mz_compute_types::plan::top_k::{
-    BasicTopKPlan, MonotonicTop1Plan, MonotonicTopKPlan, TopKPlan,
+    BasicTopKPlan, BoundedTopKPlan, MonotonicTop1Plan, MonotonicTopKPlan, TopKPlan,
 };
 use mz_expr::func::CastUint64ToInt64;
 use mz_expr::{BinaryFunc, EvalError, MirScalarExpr, UnaryFunc};
@@ -36,6 +36,7 @@ use timely::container::CapacityContainerBuilder;
 use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::operators::Operator;
 use timely::dataflow::Scope;
+use timely::PartialOrder;
 
 use crate::extensions::arrange::{ArrangementSize, KeyCollection, MzArrange};
 use crate::extensions::reduce::MzReduce;
@@ -58,6 +59,16 @@ where
     ) -> CollectionBundle<G> {
         let (ok_input, err_input) = input.as_specific_collection(None);
 
+        // Bounded TopK processes updates in time order, which requires totally ordered times.
+        let top_k_plan = match top_k_plan {
+            TopKPlan::Bounded(plan)
+                if !<G::Timestamp as crate::render::RenderTimestamp>::is_totally_ordered() =>
+            {
+                TopKPlan::Basic(plan.as_basic())
+            }
+            plan => plan,
+        };
+
         // We create a new region to compartmentalize the topk logic.
         let (ok_result, err_collection) = ok_input.scope().region_named("TopK", |inner| {
             let ok_input = ok_input.enter_region(inner);
@@ -73,6 +84,7 @@ where
                 TopKPlan::MonotonicTop1(MonotonicTop1Plan { .. }) => None,
                 TopKPlan::MonotonicTopK(MonotonicTopKPlan { limit, .. }) => Some(limit),
                 TopKPlan::Basic(BasicTopKPlan { limit, .. }) => Some(limit),
+                TopKPlan::Bounded(BoundedTopKPlan { .. }) => None,
             };
             if let Some(limit) = limit_err {
                 if let Some(expr) = limit {
@@ -234,6 +246,11 @@ where
                     err_collection = err_collection.concat(&errs);
                     oks
                 }
+                TopKPlan::Bounded(plan) => {
+                    let (oks, errs) = self.build_bounded_topk(ok_input, plan);
+                    err_collection = err_collection.concat(&errs);
+                    oks
+                }
             };
 
             // Extract the results from the region.
@@ -243,6 +260,159 @@ where
         CollectionBundle::from_collections(ok_result, err_collection)
     }
 
+    /// Constructs a bounded TopK dataflow subgraph.
+    ///
+    /// The input is arranged by group key, and a single operator maintains the first
+    /// `offset + limit + buffer` rows of each group. Updates are applied to the buffered rows
+    /// in time order, and a group is only recomputed from the arrangement when retractions leave
+    /// too few buffered rows to produce its output.
+    fn build_bounded_topk<S>(
+        &self,
+        collection: Collection<S, Row, Diff>,
+        BoundedTopKPlan {
+            group_key,
+            order_key,
+            offset,
+            limit,
+            arity: _,
+            buffer,
+        }: BoundedTopKPlan,
+    ) -> (Collection<S, Row, Diff>, Collection<S, DataflowError, Diff>)
+    where
+        S: Scope<Timestamp = G::Timestamp>,
+    {
+        let mut datum_vec = mz_repr::DatumVec::new();
+        let arranged = collection
+            .map(move |row| {
+                let group_row = {
+                    let datums = datum_vec.borrow_with(&row);
+                    SharedRow::pack(group_key.iter().map(|i| datums[*i]))
+                };
+                (group_row, row)
+            })
+            .mz_arrange::<RowRowSpine<_, _>>("Arranged BoundedTopK input");
+
+        let to_diff = |n: usize| Diff::try_from(n).unwrap_or(Diff::MAX);
+        let required = to_diff(offset.saturating_add(limit));
+        let capacity = to_diff(offset.saturating_add(limit).saturating_add(buffer));
+        let (offset, limit) = (to_diff(offset), to_diff(limit));
+
+        let recomputations = self.bounded_top_k_recomputations.clone();
+        let mut trace = arranged.trace.clone();
+        let stage = arranged
+            .stream
+            .unary_frontier(Pipeline, "BoundedTopK", move |_cap, _info| {
+                let mut groups: BTreeMap<Row, bounded::BoundedGroup> = BTreeMap::new();
+                let mut order = bounded::RowOrder::new(order_key);
+                let mut buffer = Vec::new();
+                let mut updates = Vec::new();
+                move |input, output| {
+                    while let Some((cap, data)) = input.next() {
+                        data.swap(&mut buffer);
+                        for batch in buffer.drain(..) {
+                            let mut cursor = batch.cursor();
+                            while let Some(key) = cursor.get_key(&batch) {
+                                while let Some(val) = cursor.get_val(&batch) {
+                                    cursor.map_times(&batch, |time, diff| {
+                                        updates.push((
+                                            time.into_owned(),
+                                            key.into_owned(),
+                                            val.into_owned(),
+                                            diff.into_owned(),
+                                        ));
+                                    });
+                                    cursor.step_val(&batch);
+                                }
+                                cursor.step_key(&batch);
+                            }
+                        }
+
+                        // Apply updates by time, and within each time by group.
+                        updates.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+                        let mut updates = updates.drain(..).peekable();
+                        while let Some((time, key, val, diff)) = updates.next() {
+                            let group = groups.entry(key.clone()).or_default();
+                            let before = group.output(offset, limit);
+                            group.update(val, diff, capacity, &mut order);
+                            while let Some((_, _, val, diff)) =
+                                updates.next_if(|(t, k, _, _)| t == &time && k == &key)
+                            {
+                                group.update(val, diff, capacity, &mut order);
+                            }
+
+                            if group.needs_recompute(required) {
+                                recomputations.inc();
+                                let (mut cursor, storage) = trace.cursor();
+                                let mut rows = Vec::new();
+                                cursor.seek_key(&storage, IntoOwned::borrow_as(&key));
+                                if cursor
+                                    .get_key(&storage)
+                                    .map(|k| k == IntoOwned::borrow_as(&key))
+                                    == Some(true)
+                                {
+                                    while let Some(val) = cursor.get_val(&storage) {
+                                        let mut sum = 0;
+                                        cursor.map_times(&storage, |t, d| {
+                                            if t.less_equal(&time) {
+                                                sum += d.into_owned();
+                                            }
+                                        });
+                                        if sum != 0 {
+                                            rows.push((val.into_owned(), sum));
+                                        }
+                                        cursor.step_val(&storage);
+                                    }
+                                }
+                                group.recompute(rows, capacity, &mut order);
+                            }
+
+                            let after = group.output(offset, limit);
+                            if before != after {
+                                let mut session = output.session(&cap.delayed(&time));
+                                let mut emit =
+                                    |result: Result<Vec<(Row, Diff)>, ()>, sign| match result {
+                                        Ok(rows) => {
+                                            for (row, diff) in rows {
+                                                session.give((Ok(row), time.clone(), sign * diff));
+                                            }
+                                        }
+                                        Err(()) => {
+                                            session.give((Err(key.clone()), time.clone(), sign));
+                                        }
+                                    };
+                                emit(before, -1);
+                                emit(after, 1);
+                            }
+                            if group.is_empty() {
+                                groups.remove(&key);
+                            }
+                        }
+                    }
+
+                    // Allow the input trace to compact up to the times we have processed.
+                    trace.set_logical_compaction(input.frontier().frontier());
+                    trace.set_physical_compaction(input.frontier().frontier());
+                }
+            })
+            .as_collection();
+
+        // Demux oks and errors.
+        let error_logger = self.error_logger();
+        type CB<C> = CapacityContainerBuilder<C>;
+        let (oks, errs) =
+            stage.map_fallible::<CB<_>, CB<_>, _, _, _>("Demuxing Errors", move |result| {
+                match result {
+                    Ok(row) => Ok(row),
+                    Err(k) => {
+                        let message = "Negative multiplicities in TopK";
+                        error_logger.log(message, &format!("k={k:?}"));
+                        Err(EvalError::Internal(message.into()).into())
+                    }
+                }
+            });
+        (oks, errs)
+    }
+
     /// Constructs a TopK dataflow subgraph.
     fn build_topk<S>(
         &self,
@@ -1017,3 +1187,212 @@ pub mod monoids {
         }
     }
 }
+
+/// Per-group state for bounded TopK operators.
+pub mod bounded {
+    use std::cmp::Ordering;
+
+    use mz_expr::ColumnOrder;
+    use mz_repr::{DatumVec, Diff, Row};
+
+    /// Compares rows by an ordering key, reusing allocations across comparisons.
+    #[derive(Debug)]
+    pub struct RowOrder {
+        order_key: Vec<ColumnOrder>,
+        left: DatumVec,
+        right: DatumVec,
+    }
+
+    impl RowOrder {
+        pub fn new(order_key: Vec<ColumnOrder>) -> Self {
+            Self {
+                order_key,
+                left: DatumVec::new(),
+                right: DatumVec::new(),
+            }
+        }
+
+        /// Compares `a` and `b` by the ordering key, breaking ties by the full row.
+        pub fn compare(&mut self, a: &Row, b: &Row) -> Ordering {
+            let left = self.left.borrow_with(a);
+            let right = self.right.borrow_with(b);
+            mz_expr::compare_columns(&self.order_key, &left, &right, || left.cmp(&right))
+        }
+    }
+
+    /// The first rows of a TopK group, in order.
+    ///
+    /// The buffered rows always form a prefix of the group's rows in order. If `truncated` is set,
+    /// the group contains further rows that are not buffered, and the last buffered row may have
+    /// additional copies beyond the buffer.
+    #[derive(Debug, Default)]
+    pub struct BoundedGroup {
+        /// The buffered rows and their multiplicities, sorted by the ordering key.
+        rows: Vec<(Row, Diff)>,
+        /// The sum of the multiplicities in `rows`.
+        count: Diff,
+        /// Whether the group contains rows beyond `rows`.
+        truncated: bool,
+        /// Whether `rows` does not describe the group, either because an update could not be
+        /// applied or because the group has negative multiplicities.
+        invalid: bool,
+    }
+
+    impl BoundedGroup {
+        /// Applies an update to the group, retaining at most `capacity` rows.
+        pub fn update(&mut self, row: Row, diff: Diff, capacity: Diff, order: &mut RowOrder) {
+            if self.invalid {
+                return;
+            }
+            match self.rows.binary_search_by(|(r, _)| order.compare(r, &row)) {
+                Ok(index) => {
+                    let last = index + 1 == self.rows.len();
+                    let entry = &mut self.rows[index];
+                    entry.1 += diff;
+                    self.count += diff;
+                    if entry.1 < 0 {
+                        if self.truncated && last {
+                            // The retracted copies extend beyond the buffer.
+                            self.count -= entry.1;
+                            self.rows.pop();
+                        } else {
+                            self.invalid = true;
+                        }
+                    } else if entry.1 == 0 {
+                        self.rows.remove(index);
+                    }
+                }
+                Err(index) => {
+                    if self.truncated && index == self.rows.len() {
+                        // The row orders after all buffered rows and is not tracked.
+                    } else if diff > 0 {
+                        self.rows.insert(index, (row, diff));
+                        self.count += diff;
+                    } else {
+                        self.invalid = true;
+                    }
+                }
+            }
+            self.trim(capacity);
+        }
+
+        /// Discards rows beyond `capacity`.
+        fn trim(&mut self, capacity: Diff) {
+            while self.count > capacity {
+                let excess = self.count - capacity;
+                let last = self.rows.last_mut().expect("count is positive");
+                if last.1 <= excess {
+                    self.count -= last.1;
+                    self.rows.pop();
+                } else {
+                    last.1 -= excess;
+                    self.count -= excess;
+                }
+                self.truncated = true;
+            }
+        }
+
+        /// Whether the group must be recomputed from its input to produce `required` rows.
+        pub fn needs_recompute(&self, required: Diff) -> bool {
+            self.invalid || (self.truncated && self.count < required)
+        }
+
+        /// Replaces the contents of the group with `rows`, retaining at most `capacity` rows.
+        ///
+        /// The rows must be consolidated. If any multiplicity is negative, the group is left
+        /// invalid and reports an error until it is next recomputed.
+        pub fn recompute(
+            &mut self,
+            mut rows: Vec<(Row, Diff)>,
+            capacity: Diff,
+            order: &mut RowOrder,
+        ) {
+            self.truncated = false;
+            if rows.iter().any(|(_, diff)| *diff < 0) {
+                self.rows.clear();
+                self.count = 0;
+                self.invalid = true;
+                return;
+            }
+            rows.sort_by(|(a, _), (b, _)| order.compare(a, b));
+            self.count = rows.iter().map(|(_, diff)| diff).sum();
+            self.rows = rows;
+            self.invalid = false;
+            self.trim(capacity);
+        }
+
+        /// The output of the group after skipping `offset` rows and limiting to `limit` rows, or
+        /// `Err(())` if the group has negative multiplicities.
+        pub fn output(&self, offset: Diff, limit: Diff) -> Result<Vec<(Row, Diff)>, ()> {
+            if self.invalid {
+                return Err(());
+            }
+            let mut skip = offset;
+            let mut take = limit;
+            let mut output = Vec::new();
+            for (row, diff) in self.rows.iter() {
+                if take == 0 {
+                    break;
+                }
+                let available = diff - std::cmp::min(*diff, skip);
+                skip -= diff - available;
+                let copies = std::cmp::min(available, take);
+                if copies > 0 {
+                    output.push((row.clone(), copies));
+                    take -= copies;
+                }
+            }
+            Ok(output)
+        }
+
+        /// Whether the group holds no state.
+        pub fn is_empty(&self) -> bool {
+            self.rows.is_empty() && !self.truncated && !self.invalid
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use mz_expr::ColumnOrder;
+        use mz_repr::{Datum, Row};
+
+        use super::*;
+
+        fn row(i: i64) -> Row {
+            Row::pack_slice(&[Datum::Int64(i)])
+        }
+
+        #[mz_ore::test]
+        fn test_bounded_group() {
+            let mut order = RowOrder::new(vec![ColumnOrder {
+                column: 0,
+                desc: false,
+                nulls_last: true,
+            }]);
+            let mut group = BoundedGroup::default();
+            for i in [5, 3, 1, 4, 2] {
+                group.update(row(i), 1, 3, &mut order);
+            }
+            // Only the three smallest rows are buffered.
+            assert_eq!(group.output(0, 2), Ok(vec![(row(1), 1), (row(2), 1)]));
+            assert!(!group.needs_recompute(2));
+
+            // Retracting a buffered row leaves enough rows to produce the output.
+            group.update(row(1), -1, 3, &mut order);
+            assert_eq!(group.output(0, 2), Ok(vec![(row(2), 1), (row(3), 1)]));
+            assert!(!group.needs_recompute(2));
+
+            // Retracting another buffered row requires a recomputation.
+            group.update(row(2), -1, 3, &mut order);
+            assert!(group.needs_recompute(2));
+            group.recompute(vec![(row(3), 1), (row(4), 1), (row(5), 1)], 3, &mut order);
+            assert_eq!(group.output(1, 2), Ok(vec![(row(4), 1), (row(5), 1)]));
+
+            // Retracting rows that were never inserted invalidates the group.
+            group.update(row(0), -1, 3, &mut order);
+            assert!(group.needs_recompute(2));
+            group.recompute(vec![(row(0), -1)], 3, &mut order);
+            assert_eq!(group.output(0, 2), Err(()));
+        }
+    }
+}
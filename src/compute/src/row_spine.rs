@@ -106,6 +106,8 @@ mod container {
     use mz_repr::{read_datum, Datum, Row, RowPacker};
     use timely::container::PushInto;
 
+    use crate::arrangement::spill::SpillRegion;
+
     /// A slice container with four bytes overhead per slice.
     pub struct DatumContainer {
         batches: Vec<DatumBatch>,
@@ -122,7 +124,10 @@ mod container {
             );
             for batch in self.batches.iter() {
                 batch.offsets.heap_size(&mut callback);
-                callback(batch.storage.len(), batch.storage.capacity());
+                // Spilled storage lives in a file mapping and does not count towards the heap.
+                if let DatumStorage::Heap(storage) = &batch.storage {
+                    callback(storage.len(), storage.capacity());
+                }
             }
         }
     }
@@ -149,7 +154,7 @@ mod container {
                 byte_cap += batch.storage.len();
             }
             Self {
-                batches: vec![DatumBatch::with_capacities(item_cap, byte_cap)],
+                batches: vec![DatumBatch::with_capacities_spillable(item_cap, byte_cap)],
             }
         }
 
@@ -212,7 +217,49 @@ mod container {
     /// The backing storage for this batch will not be resized.
     pub struct DatumBatch {
         offsets: crate::row_spine::OffsetOptimized,
-        storage: Region<u8>,
+        storage: DatumStorage,
+    }
+
+    /// The backing storage of a [`DatumBatch`].
+    enum DatumStorage {
+        /// Bytes held in a heap or lgalloc region.
+        Heap(Region<u8>),
+        /// Bytes spilled to a file on local disk.
+        Spilled(SpillRegion),
+    }
+
+    impl DatumStorage {
+        #[inline]
+        fn len(&self) -> usize {
+            match self {
+                DatumStorage::Heap(storage) => storage.len(),
+                DatumStorage::Spilled(storage) => storage.len(),
+            }
+        }
+
+        #[inline]
+        fn capacity(&self) -> usize {
+            match self {
+                DatumStorage::Heap(storage) => storage.capacity(),
+                DatumStorage::Spilled(storage) => storage.capacity(),
+            }
+        }
+
+        #[inline]
+        fn extend_from_slice(&mut self, slice: &[u8]) {
+            match self {
+                DatumStorage::Heap(storage) => storage.extend_from_slice(slice),
+                DatumStorage::Spilled(storage) => storage.extend_from_slice(slice),
+            }
+        }
+
+        #[inline]
+        fn as_slice(&self) -> &[u8] {
+            match self {
+                DatumStorage::Heap(storage) => &storage[..],
+                DatumStorage::Spilled(storage) => storage.as_slice(),
+            }
+        }
     }
 
     impl DatumBatch {
@@ -230,7 +277,7 @@ mod container {
         fn index(&self, index: usize) -> &[u8] {
             let lower = self.offsets.index(index);
             let upper = self.offsets.index(index + 1);
-            &self.storage[lower..upper]
+            &self.storage.as_slice()[lower..upper]
         }
         fn len(&self) -> usize {
             self.offsets.len() - 1
@@ -242,7 +289,27 @@ mod container {
             offsets.push(0);
             Self {
                 offsets,
-                storage: Region::new_auto(byte_cap.next_power_of_two()),
+                storage: DatumStorage::Heap(Region::new_auto(byte_cap.next_power_of_two())),
+            }
+        }
+
+        /// Like [`DatumBatch::with_capacities`], but places the bytes on disk if spilling is
+        /// enabled and `byte_cap` exceeds the spill threshold.
+        ///
+        /// Only used for the results of merges, which hold the bulk of an arrangement's data
+        /// and are accessed less frequently than recently formed batches.
+        fn with_capacities_spillable(item_cap: usize, byte_cap: usize) -> Self {
+            match SpillRegion::try_allocate(byte_cap) {
+                Some(storage) => {
+                    let mut offsets =
+                        crate::row_spine::OffsetOptimized::with_capacity(item_cap + 1);
+                    offsets.push(0);
+                    Self {
+                        offsets,
+                        storage: DatumStorage::Spilled(storage),
+                    }
+                }
+                None => Self::with_capacities(item_cap, byte_cap),
             }
         }
     }
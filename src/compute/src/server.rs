@@ -471,6 +471,8 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
                     compute_state.report_frontiers();
                     compute_state.report_dropped_collections();
                     compute_state.report_metrics();
+                    compute_state.checkpoint_arrangements();
+                    compute_state.enforce_dataflow_memory_limits();
                     compute_state.check_expiration();
                 }
 
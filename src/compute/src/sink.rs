@@ -10,6 +10,7 @@
 mod copy_to_s3_oneshot;
 mod correction;
 mod materialized_view;
+mod persisted_index;
 mod refresh;
 mod subscribe;
 
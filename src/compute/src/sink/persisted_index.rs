@@ -0,0 +1,109 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink writing arrangements to persist, as persisted indexes.
+//!
+//! The layout of the written data is described in [`mz_compute_types::persisted_index`].
+
+use std::any::Any;
+use std::rc::Rc;
+
+use differential_dataflow::Collection;
+use mz_compute_types::persisted_index;
+use mz_compute_types::sinks::{ComputeSinkDesc, PersistedIndexSinkConnection};
+use mz_expr::permutation_for_arrangement;
+use mz_ore::soft_panic_or_log;
+use mz_repr::{DatumVec, Diff, GlobalId, Row, RowArena, Timestamp};
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_timely_util::operator::CollectionExt;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::probe;
+use timely::dataflow::Scope;
+use timely::progress::Antichain;
+
+use crate::compute_state::ComputeState;
+use crate::render::sinks::SinkRender;
+use crate::render::StartSignal;
+use crate::sink::materialized_view::persist_sink;
+use crate::sink::refresh::apply_refresh;
+
+impl<G> SinkRender<G> for PersistedIndexSinkConnection<CollectionMetadata>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn render_sink(
+        &self,
+        compute_state: &mut ComputeState,
+        sink: &ComputeSinkDesc<CollectionMetadata>,
+        sink_id: GlobalId,
+        as_of: Antichain<Timestamp>,
+        start_signal: StartSignal,
+        mut ok_collection: Collection<G, Row, Diff>,
+        mut err_collection: Collection<G, DataflowError, Diff>,
+        _ct_times: Option<Collection<G, (), Diff>>,
+    ) -> Option<Rc<dyn Any>> {
+        // Attach a probe reporting the compute frontier, before any rounding of timestamps.
+        let mut probe = probe::Handle::default();
+        ok_collection = ok_collection.probe_with(&mut probe);
+        let collection_state = compute_state.expect_collection_mut(sink_id);
+        collection_state.compute_probe = Some(probe);
+
+        if let Some(refresh_schedule) = &sink.refresh_schedule {
+            ok_collection = apply_refresh(ok_collection, refresh_schedule.clone());
+            err_collection = apply_refresh(err_collection, refresh_schedule.clone());
+        }
+
+        // The controller rejects persisted index dataflows with an UP TO. Should one still reach
+        // us, we keep maintaining the index past it, which only writes more than was asked for.
+        if !sink.up_to.is_empty() {
+            soft_panic_or_log!(
+                "persisted index sink {sink_id} has an UP TO: {:?}",
+                sink.up_to
+            );
+        }
+
+        // Form the arrangement keys and values, using the same thinning as dataflow-local
+        // arrangements, so importing dataflows can interpret values as they would those of an
+        // index.
+        let key = self.key.clone();
+        let (_permutation, thinning) = permutation_for_arrangement(&key, sink.from_desc.arity());
+        let mut datums = DatumVec::new();
+        let mut key_row = Row::default();
+        let mut val_row = Row::default();
+        let mut buf = Vec::new();
+        type CB<C> = CapacityContainerBuilder<C>;
+        let (ok_collection, key_errs) = ok_collection.map_fallible::<CB<_>, CB<_>, _, _, _>(
+            "PersistedIndexEncode",
+            move |row| {
+                let datums = datums.borrow_with(&row);
+                let temp_storage = RowArena::new();
+                let mut packer = key_row.packer();
+                for expr in &key {
+                    packer.push(expr.eval(&datums, &temp_storage)?);
+                }
+                val_row.packer().extend(thinning.iter().map(|c| datums[*c]));
+                let mut encoded = Row::default();
+                persisted_index::encode(&key_row, &val_row, &mut buf, &mut encoded);
+                Ok::<_, DataflowError>(encoded)
+            },
+        );
+        let err_collection = err_collection.concat(&key_errs);
+
+        persist_sink(
+            sink_id,
+            &self.storage_metadata,
+            ok_collection,
+            err_collection,
+            as_of,
+            compute_state,
+            start_signal,
+        )
+    }
+}
@@ -54,6 +54,9 @@ pub struct ClusterConfig {
     /// An optional arbitrary string that describes the class of the workload
     /// this cluster is running (e.g., `production` or `staging`).
     pub workload_class: Option<String>,
+    /// Whether the cluster's replicas may spill arrangements to disk,
+    /// overriding the `enable_compute_arrangement_spill` system flag.
+    pub arrangement_spill: Option<bool>,
 }
 
 /// The status of a cluster.
@@ -344,8 +347,12 @@ where
         config: ClusterConfig,
     ) -> Result<(), anyhow::Error> {
         self.storage.create_instance(id);
-        self.compute
-            .create_instance(id, config.arranged_logs, config.workload_class)?;
+        self.compute.create_instance(
+            id,
+            config.arranged_logs,
+            config.workload_class,
+            config.arrangement_spill,
+        )?;
         Ok(())
     }
 
@@ -360,6 +367,17 @@ where
         Ok(())
     }
 
+    /// Updates the arrangement spill override for a cluster.
+    pub fn update_cluster_arrangement_spill(
+        &mut self,
+        id: ClusterId,
+        arrangement_spill: Option<bool>,
+    ) -> Result<(), anyhow::Error> {
+        self.compute
+            .update_instance_arrangement_spill(id, arrangement_spill)?;
+        Ok(())
+    }
+
     /// Drops the specified cluster.
     ///
     /// # Panics
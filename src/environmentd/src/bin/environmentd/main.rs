@@ -38,6 +38,7 @@ use mz_cloud_resources::{AwsExternalIdPrefix, CloudResourceController};
 use mz_controller::ControllerConfig;
 use mz_environmentd::{CatalogConfig, Listeners, ListenersConfig, BUILD_INFO};
 use mz_frontegg_auth::{Authenticator, FronteggCliArgs};
+use mz_oidc_auth::OidcCliArgs;
 use mz_orchestrator::Orchestrator;
 use mz_orchestrator_kubernetes::{
     KubernetesImagePullPolicy, KubernetesOrchestrator, KubernetesOrchestratorConfig,
@@ -59,6 +60,7 @@ use mz_persist_client::rpc::{
     MetricsSameProcessPubSubSender, PersistGrpcPubSubServer, PubSubClientConnection, PubSubSender,
 };
 use mz_persist_client::PersistLocation;
+use mz_secrets::external::ResolvingSecretsController;
 use mz_secrets::SecretsController;
 use mz_server_core::TlsCliArgs;
 use mz_service::emit_boot_diagnostics;
@@ -221,6 +223,9 @@ pub struct Args {
     /// Frontegg arguments.
     #[clap(flatten)]
     frontegg: FronteggCliArgs,
+    /// OIDC arguments.
+    #[clap(flatten)]
+    oidc: OidcCliArgs,
 
     // === Orchestrator options. ===
     /// The service orchestrator implementation to use.
@@ -353,6 +358,29 @@ pub struct Args {
         required_if_eq("secrets-controller", "aws-secrets-manager")
     )]
     aws_secrets_controller_tags: Vec<KeyValueArg<String, String>>,
+    /// The address of the HashiCorp Vault server from which to read secrets
+    /// created with `CREATE SECRET ... FROM VAULT`.
+    #[clap(
+        long,
+        env = "SECRETS_VAULT_ADDRESS",
+        requires_all = &["secrets-vault-mount", "secrets-vault-token-file"]
+    )]
+    secrets_vault_address: Option<Url>,
+    /// The mount of the KV secrets engine that secrets created with `CREATE
+    /// SECRET ... FROM VAULT` may refer to. Secrets in other mounts can't be
+    /// read.
+    #[clap(long, env = "SECRETS_VAULT_MOUNT", requires = "secrets-vault-address")]
+    secrets_vault_mount: Option<String>,
+    /// The file containing the token with which to authenticate to the
+    /// HashiCorp Vault server. The file is reread for every request, so it can
+    /// be kept up to date by a Vault agent.
+    #[clap(
+        long,
+        env = "SECRETS_VAULT_TOKEN_FILE",
+        requires = "secrets-vault-address",
+        value_name = "PATH"
+    )]
+    secrets_vault_token_file: Option<PathBuf>,
     /// The clusterd image reference to use.
     #[structopt(
         long,
@@ -423,8 +451,13 @@ pub struct Args {
     storage_usage_retention_period: Option<Duration>,
 
     // === Adapter options. ===
-    /// The PostgreSQL URL for the Postgres-backed timestamp oracle.
-    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
+    /// The URL for the timestamp oracle.
+    ///
+    /// A PostgreSQL URL selects the Postgres-backed timestamp oracle. A URL of
+    /// the form `persist+<consensus url>`, e.g. `persist+postgres://...`,
+    /// selects the timestamp oracle backed by the persist consensus at
+    /// `<consensus url>`.
+    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "URL")]
     timestamp_oracle_url: Option<SensitiveUrl>,
     /// Availability zones in which storage and compute resources may be
     /// deployed.
@@ -447,6 +480,10 @@ pub struct Args {
     /// which the event was sent.
     #[clap(long, env = "SEGMENT_CLIENT_SIDE")]
     segment_client_side: bool,
+    /// A file to which to append audit events as they occur, one JSON object
+    /// per line.
+    #[clap(long, env = "AUDIT_LOG_EXPORT_PATH", value_name = "PATH")]
+    audit_log_export_path: Option<PathBuf>,
     /// An SDK key for LaunchDarkly.
     ///
     /// Setting this in combination with [`Self::config_sync_loop_interval`]
@@ -705,6 +742,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     // Configure connections.
     let tls = args.tls.into_config()?;
     let frontegg = Authenticator::from_args(args.frontegg, &metrics_registry)?;
+    let oidc = mz_oidc_auth::Authenticator::from_args(args.oidc)?;
+    if frontegg.is_some() && oidc.is_some() {
+        bail!("Frontegg and OIDC authentication cannot both be enabled");
+    }
 
     // Configure CORS.
     let allowed_origins = if !args.cors_allowed_origin.is_empty() {
@@ -863,6 +904,25 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         }
     };
     drop(entered);
+
+    // When serialized to args in the controller, only the relevant flags will be passed
+    // through, so we just set all of them
+    let secrets_args = SecretsReaderCliArgs {
+        secrets_reader: args.secrets_controller,
+        secrets_reader_local_file_dir: args.orchestrator_process_secrets_directory,
+        secrets_reader_kubernetes_context: Some(args.orchestrator_kubernetes_context),
+        secrets_reader_aws_prefix: Some(aws_secrets_controller_prefix(&args.environment_id)),
+        secrets_reader_vault_address: args.secrets_vault_address,
+        secrets_reader_vault_mount: args.secrets_vault_mount,
+        secrets_reader_vault_token_file: args.secrets_vault_token_file,
+    };
+    // Secrets created with `CREATE SECRET ... FROM VAULT` are resolved by the
+    // readers of every process, including this one.
+    let secrets_controller: Arc<dyn SecretsController> = Arc::new(ResolvingSecretsController::new(
+        secrets_controller,
+        secrets_args.external_reader(),
+    ));
+
     let cloud_resource_reader = cloud_resource_controller.as_ref().map(|c| c.reader());
     let secrets_reader = secrets_controller.reader();
     let now = SYSTEM_TIME.clone();
@@ -971,14 +1031,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         metrics_registry: metrics_registry.clone(),
         persist_pubsub_url: args.persist_pubsub_url,
         connection_context,
-        // When serialized to args in the controller, only the relevant flags will be passed
-        // through, so we just set all of them
-        secrets_args: SecretsReaderCliArgs {
-            secrets_reader: args.secrets_controller,
-            secrets_reader_local_file_dir: args.orchestrator_process_secrets_directory,
-            secrets_reader_kubernetes_context: Some(args.orchestrator_kubernetes_context),
-            secrets_reader_aws_prefix: Some(aws_secrets_controller_prefix(&args.environment_id)),
-        },
+        secrets_args,
     };
 
     let cluster_replica_sizes: ClusterReplicaSizeMap = match args.cluster_replica_sizes {
@@ -1019,6 +1072,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                 tls,
                 tls_reload_certs: mz_server_core::default_cert_reload_ticker(),
                 frontegg,
+                oidc,
                 cors_allowed_origin,
                 egress_addresses: args.announce_egress_address,
                 http_host_name: args.http_host_name,
@@ -1037,6 +1091,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                 timestamp_oracle_url,
                 segment_api_key: args.segment_api_key,
                 segment_client_side: args.segment_client_side,
+                audit_log_export_path: args.audit_log_export_path,
                 launchdarkly_sdk_key: args.launchdarkly_sdk_key,
                 launchdarkly_key_map: args
                     .launchdarkly_key_map
@@ -44,11 +44,16 @@ use mz_adapter::session::{Session, SessionConfig};
 use mz_adapter::{AdapterError, AdapterNotice, Client, SessionClient, WebhookAppenderCache};
 use mz_frontegg_auth::{Authenticator as FronteggAuthentication, Error as FronteggError};
 use mz_http_util::DynamicFilterTarget;
+use mz_oidc_auth::{Authenticator as OidcAuthentication, Error as OidcError};
 use mz_ore::cast::u64_to_usize;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::str::StrExt;
+use mz_pgwire_common::scram::ScramVerifier;
 use mz_repr::user::ExternalUserMetadata;
-use mz_server_core::{Connection, ConnectionHandler, ReloadingSslContext, Server};
+use mz_server_core::{
+    ClientCertRoleSource, ClientCertificate, Connection, ConnectionHandler, ReloadingSslContext,
+    Server,
+};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SUPPORT_USER_NAME, SYSTEM_USER_NAME};
 use mz_sql::session::vars::{
@@ -90,6 +95,7 @@ pub struct HttpConfig {
     pub source: &'static str,
     pub tls: Option<ReloadingTlsConfig>,
     pub frontegg: Option<FronteggAuthentication>,
+    pub oidc: Option<OidcAuthentication>,
     pub adapter_client: mz_adapter::Client,
     pub allowed_origin: AllowOrigin,
     pub active_connection_count: Arc<Mutex<ConnectionCounter>>,
@@ -102,6 +108,7 @@ pub struct HttpConfig {
 pub struct ReloadingTlsConfig {
     pub context: ReloadingSslContext,
     pub mode: TlsMode,
+    pub client_auth: Option<ClientCertRoleSource>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -112,7 +119,7 @@ pub enum TlsMode {
 
 #[derive(Clone)]
 pub struct WsState {
-    frontegg: Arc<Option<FronteggAuthentication>>,
+    external_auth: Arc<Option<ExternalAuthentication>>,
     adapter_client_rx: Delayed<mz_adapter::Client>,
     active_connection_count: SharedConnectionCounter,
     helm_chart_version: Option<String>,
@@ -136,6 +143,7 @@ impl HttpServer {
             source,
             tls,
             frontegg,
+            oidc,
             adapter_client,
             allowed_origin,
             active_connection_count,
@@ -145,8 +153,9 @@ impl HttpServer {
         }: HttpConfig,
     ) -> HttpServer {
         let tls_mode = tls.as_ref().map(|tls| tls.mode).unwrap_or(TlsMode::Disable);
-        let frontegg = Arc::new(frontegg);
-        let base_frontegg = Arc::clone(&frontegg);
+        let external_auth = Arc::new(ExternalAuthentication::new(frontegg, oidc));
+        let base_external_auth = Arc::clone(&external_auth);
+        let base_adapter_client = adapter_client.clone();
         let (adapter_client_tx, adapter_client_rx) = oneshot::channel();
         adapter_client_tx
             .send(adapter_client.clone())
@@ -156,8 +165,18 @@ impl HttpServer {
 
         let base_router = base_router(BaseRouterConfig { profiling: false })
             .layer(middleware::from_fn(move |req, next| {
-                let base_frontegg = Arc::clone(&base_frontegg);
-                async move { http_auth(req, next, tls_mode, base_frontegg.as_ref().as_ref()).await }
+                let base_external_auth = Arc::clone(&base_external_auth);
+                let adapter_client = base_adapter_client.clone();
+                async move {
+                    http_auth(
+                        req,
+                        next,
+                        tls_mode,
+                        base_external_auth.as_ref().as_ref(),
+                        &adapter_client,
+                    )
+                    .await
+                }
             }))
             .layer(Extension(adapter_client_rx.clone()))
             .layer(Extension(Arc::clone(&active_connection_count)))
@@ -177,7 +196,7 @@ impl HttpServer {
         let ws_router = Router::new()
             .route("/api/experimental/sql", routing::get(sql::handle_sql_ws))
             .with_state(WsState {
-                frontegg,
+                external_auth,
                 adapter_client_rx,
                 active_connection_count,
                 helm_chart_version,
@@ -241,7 +260,14 @@ impl Server for HttpServer {
                         let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
                         return Err(e.into());
                     }
-                    (MaybeHttpsStream::Https(ssl_stream), ConnProtocol::Https)
+                    let client_certificate = tls_config
+                        .client_auth
+                        .map(|role_source| role_source.client_certificate(ssl_stream.ssl()))
+                        .transpose()?;
+                    (
+                        MaybeHttpsStream::Https(ssl_stream),
+                        ConnProtocol::Https { client_certificate },
+                    )
                 }
                 _ => (MaybeHttpsStream::Http(conn), ConnProtocol::Http),
             };
@@ -417,7 +443,7 @@ impl InternalHttpServer {
             // attach the x-materialize-user header to all requests it proxies to this api.
             .layer(middleware::from_fn(internal_http_auth))
             .with_state(WsState {
-                frontegg: Arc::new(None),
+                external_auth: Arc::new(None),
                 adapter_client_rx,
                 active_connection_count,
                 helm_chart_version: helm_chart_version.clone(),
@@ -458,6 +484,7 @@ async fn internal_http_auth(mut req: Request, next: Next) -> impl IntoResponse {
     req.extensions_mut().insert(AuthedUser {
         name: user_name,
         external_metadata_rx: None,
+        client_certificate_subject: None,
     });
     Ok(next.run(req).await)
 }
@@ -495,13 +522,18 @@ type SharedConnectionCounter = Arc<Mutex<ConnectionCounter>>;
 #[derive(Clone)]
 enum ConnProtocol {
     Http,
-    Https,
+    Https {
+        /// The identity established by the client's TLS certificate, if the
+        /// server requires client certificates.
+        client_certificate: Option<ClientCertificate>,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub struct AuthedUser {
     name: String,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
+    client_certificate_subject: Option<String>,
 }
 
 pub struct AuthedClient {
@@ -530,6 +562,7 @@ impl AuthedClient {
             client_ip: Some(peer_addr),
             external_metadata_rx: user.external_metadata_rx,
             helm_chart_version,
+            client_certificate_subject: user.client_certificate_subject,
         });
         let drop_connection =
             DropConnection::new_connection(session.user(), active_connection_count)?;
@@ -646,8 +679,12 @@ enum AuthError {
     InvalidLogin(String),
     #[error("{0}")]
     Frontegg(#[from] FronteggError),
+    #[error("{0}")]
+    Oidc(#[from] OidcError),
     #[error("missing authorization header")]
     MissingHttpAuthentication,
+    #[error("invalid password for user {0}")]
+    InvalidPassword(String),
     #[error("{0}")]
     MismatchedUser(String),
     #[error("unexpected credentials")]
@@ -676,7 +713,8 @@ async fn http_auth(
     mut req: Request,
     next: Next,
     tls_mode: TlsMode,
-    frontegg: Option<&FronteggAuthentication>,
+    external_auth: Option<&ExternalAuthentication>,
+    adapter_client: &Client,
 ) -> impl IntoResponse {
     // First, extract the username from the certificate, validating that the
     // connection matches the TLS configuration along the way.
@@ -687,9 +725,29 @@ async fn http_auth(
         (TlsMode::Require, ConnProtocol::Http) => return Err(AuthError::HttpsRequired),
         (TlsMode::Require, ConnProtocol::Https { .. }) => {}
     }
-    let creds = match frontegg {
-        // If no Frontegg authentication, use the default HTTP user.
-        None => Credentials::DefaultUser,
+    // A verified client certificate authenticates the client in place of any
+    // other credentials.
+    if let ConnProtocol::Https {
+        client_certificate: Some(certificate),
+    } = conn_protocol
+    {
+        let basic = req.headers().typed_get::<Authorization<Basic>>();
+        let user = certificate_auth(certificate, basic.as_ref().map(|basic| basic.username()))?;
+        req.extensions_mut().insert(user);
+        return Ok(next.run(req).await);
+    }
+    let creds = match external_auth {
+        // If no external authentication, use the default HTTP user, unless HTTP
+        // Basic authentication names a role with a password.
+        None => match req.headers().typed_get::<Authorization<Basic>>() {
+            Some(basic)
+                if check_role_password(adapter_client, basic.username(), basic.password())
+                    .await? =>
+            {
+                Credentials::User(basic.username().to_string())
+            }
+            _ => Credentials::DefaultUser,
+        },
         Some(_) => {
             if let Some(basic) = req.headers().typed_get::<Authorization<Basic>>() {
                 Credentials::Password {
@@ -706,7 +764,7 @@ async fn http_auth(
         }
     };
 
-    let user = auth(frontegg, creds).await?;
+    let user = auth(external_auth, creds).await?;
 
     // Add the authenticated user as an extension so downstream handlers can
     // inspect it if necessary.
@@ -718,7 +776,7 @@ async fn http_auth(
 
 async fn init_ws(
     WsState {
-        frontegg,
+        external_auth,
         adapter_client_rx,
         active_connection_count,
         helm_chart_version,
@@ -746,8 +804,9 @@ async fn init_ws(
             }
         }
     };
-    let (user, options) = match (frontegg.as_ref(), existing_user, ws_auth) {
-        (Some(frontegg), None, ws_auth) => {
+    let adapter_client = adapter_client_rx.clone().await?;
+    let (user, options) = match (external_auth.as_ref(), existing_user, ws_auth) {
+        (Some(external_auth), None, ws_auth) => {
             let (creds, options) = match ws_auth {
                 WebSocketAuth::Basic {
                     user,
@@ -768,37 +827,46 @@ async fn init_ws(
                     anyhow::bail!("expected auth information");
                 }
             };
-            (auth(Some(frontegg), creds).await?, options)
+            (auth(Some(external_auth), creds).await?, options)
         }
         (
             None,
             None,
             WebSocketAuth::Basic {
                 user,
-                password: _,
+                password,
                 options,
             },
-        ) => (auth(None, Credentials::User(user)).await?, options),
-        // No frontegg, specified existing user, we only accept options only.
+        ) => {
+            check_role_password(&adapter_client, &user, &password).await?;
+            (auth(None, Credentials::User(user)).await?, options)
+        }
+        // A user authenticated by a client certificate only specifies options.
+        (_, Some(existing_user), WebSocketAuth::OptionsOnly { options })
+            if existing_user.client_certificate_subject.is_some() =>
+        {
+            (existing_user, options)
+        }
+        // No external auth, specified existing user, we only accept options only.
         (None, Some(existing_user), WebSocketAuth::OptionsOnly { options }) => {
             (existing_user, options)
         }
-        // No frontegg, specified existing user, we do not expect basic or bearer auth.
+        // No external auth, specified existing user, we do not expect basic or bearer auth.
         (None, Some(_), WebSocketAuth::Basic { .. } | WebSocketAuth::Bearer { .. }) => {
             warn!("Unexpected bearer or basic auth provided when using user header");
             anyhow::bail!("unexpected")
         }
-        // Specifying both frontegg and an existing user should not be possible.
+        // Specifying both external auth and an existing user should not be possible.
         (Some(_), Some(_), _) => anyhow::bail!("unexpected"),
-        // No frontegg, no existing user, and no passed username.
+        // No external auth, no existing user, and no passed username.
         (None, None, WebSocketAuth::Bearer { .. } | WebSocketAuth::OptionsOnly { .. }) => {
-            warn!("Unexpected auth type when not using frontegg or user header");
+            warn!("Unexpected auth type when not using external auth or user header");
             anyhow::bail!("unexpected")
         }
     };
 
     let client = AuthedClient::new(
-        &adapter_client_rx.clone().await?,
+        &adapter_client,
         user,
         peer_addr,
         Arc::clone(active_connection_count),
@@ -811,6 +879,45 @@ async fn init_ws(
     Ok(client)
 }
 
+/// Verifies `password` against the password of the role named `username`, for
+/// use when external authentication is disabled.
+///
+/// Returns whether the role has a password. Roles without a password are
+/// trusted, as they are in pgwire, so their password is not checked.
+async fn check_role_password(
+    adapter_client: &Client,
+    username: &str,
+    password: &str,
+) -> Result<bool, AuthError> {
+    let Some(verifier) = adapter_client.role_password_verifier(username).await else {
+        return Ok(false);
+    };
+    match ScramVerifier::parse(&verifier) {
+        Some(verifier) if verifier.verify_password(password) => Ok(true),
+        _ => Err(AuthError::InvalidPassword(username.to_string())),
+    }
+}
+
+/// An external identity provider with which users authenticate.
+#[derive(Debug)]
+enum ExternalAuthentication {
+    Frontegg(FronteggAuthentication),
+    Oidc(OidcAuthentication),
+}
+
+impl ExternalAuthentication {
+    fn new(
+        frontegg: Option<FronteggAuthentication>,
+        oidc: Option<OidcAuthentication>,
+    ) -> Option<ExternalAuthentication> {
+        match (frontegg, oidc) {
+            (Some(frontegg), _) => Some(ExternalAuthentication::Frontegg(frontegg)),
+            (None, Some(oidc)) => Some(ExternalAuthentication::Oidc(oidc)),
+            (None, None) => None,
+        }
+    }
+}
+
 enum Credentials {
     User(String),
     DefaultUser,
@@ -819,32 +926,32 @@ enum Credentials {
 }
 
 async fn auth(
-    frontegg: Option<&FronteggAuthentication>,
+    external_auth: Option<&ExternalAuthentication>,
     creds: Credentials,
 ) -> Result<AuthedUser, AuthError> {
     // There are three places a username may be specified:
     //
     //   - certificate common name
     //   - HTTP Basic authentication
-    //   - JWT email address
+    //   - JWT email address or user claim
     //
     // We verify that if any of these are present, they must match any other
     // that is also present.
 
-    // Then, handle Frontegg authentication if required.
-    let (name, external_metadata_rx) = match (frontegg, creds) {
-        // If no Frontegg authentication, allow the default user.
+    // Then, handle external authentication if required.
+    let (name, external_metadata_rx) = match (external_auth, creds) {
+        // If no external authentication, allow the default user.
         (None, Credentials::DefaultUser) => (HTTP_DEFAULT_USER.name.to_string(), None),
-        // If no Frontegg authentication, allow a protocol-specified user.
+        // If no external authentication, allow a protocol-specified user.
         (None, Credentials::User(name)) => (name, None),
-        // With frontegg disabled, specifying credentials is an error.
+        // With external authentication disabled, specifying credentials is an error.
         (None, _) => return Err(AuthError::UnexpectedCredentials),
         // If we require Frontegg auth, fetch credentials from the HTTP auth
         // header. Basic auth comes with a username/password, where the password
         // is the client+secret pair. Bearer auth is an existing JWT that must
         // be validated. In either case, if a username was specified in the
         // client cert, it must match that of the JWT.
-        (Some(frontegg), creds) => match creds {
+        (Some(ExternalAuthentication::Frontegg(frontegg)), creds) => match creds {
             Credentials::Password { username, password } => {
                 let auth_session = frontegg.authenticate(&username, &password).await?;
                 let user = auth_session.user().into();
@@ -856,6 +963,7 @@ async fn auth(
                 let (_, external_metadata_rx) = watch::channel(ExternalUserMetadata {
                     user_id: claims.user_id,
                     admin: claims.is_admin,
+                    roles: None,
                 });
                 (claims.user, Some(external_metadata_rx))
             }
@@ -863,6 +971,25 @@ async fn auth(
                 return Err(AuthError::MissingHttpAuthentication)
             }
         },
+        // With OIDC, Basic auth comes with a username/password, where the
+        // password is a JWT issued by the identity provider, as in pgwire.
+        // Bearer auth is the JWT itself.
+        (Some(ExternalAuthentication::Oidc(oidc)), creds) => match creds {
+            Credentials::Password { username, password } => {
+                let auth_session = oidc.authenticate(&username, &password).await?;
+                let user = auth_session.user().into();
+                let external_metadata_rx = Some(auth_session.external_metadata_rx());
+                (user, external_metadata_rx)
+            }
+            Credentials::Token { token } => {
+                let claims = oidc.validate_access_token(&token, None).await?;
+                let (_, external_metadata_rx) = watch::channel(claims.to_external_user_metadata());
+                (claims.user, Some(external_metadata_rx))
+            }
+            Credentials::DefaultUser | Credentials::User(_) => {
+                return Err(AuthError::MissingHttpAuthentication)
+            }
+        },
     };
 
     if mz_adapter::catalog::is_reserved_role_name(name.as_str()) {
@@ -871,6 +998,33 @@ async fn auth(
     Ok(AuthedUser {
         name,
         external_metadata_rx,
+        client_certificate_subject: None,
+    })
+}
+
+/// Authenticates a client by its verified TLS client certificate.
+///
+/// If the client also names a user, it must be the role that the certificate
+/// authenticates.
+fn certificate_auth(
+    certificate: &ClientCertificate,
+    username: Option<&str>,
+) -> Result<AuthedUser, AuthError> {
+    if let Some(username) = username {
+        if username != certificate.role {
+            return Err(AuthError::MismatchedUser(format!(
+                "user {username} does not match role {} of client certificate {}",
+                certificate.role, certificate.subject
+            )));
+        }
+    }
+    if mz_adapter::catalog::is_reserved_role_name(&certificate.role) {
+        return Err(AuthError::InvalidLogin(certificate.role.clone()));
+    }
+    Ok(AuthedUser {
+        name: certificate.role.clone(),
+        external_metadata_rx: None,
+        client_certificate_subject: Some(certificate.subject.clone()),
     })
 }
 
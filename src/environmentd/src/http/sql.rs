@@ -50,7 +50,9 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::debug;
 use tungstenite::protocol::frame::coding::CloseCode;
 
-use crate::http::{init_ws, AuthedClient, AuthedUser, WsState, MAX_REQUEST_SIZE};
+use crate::http::{
+    certificate_auth, init_ws, AuthedClient, AuthedUser, ConnProtocol, WsState, MAX_REQUEST_SIZE,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -119,11 +121,24 @@ pub async fn handle_sql(
 pub async fn handle_sql_ws(
     State(state): State<WsState>,
     existing_user: Option<Extension<AuthedUser>>,
+    conn_protocol: Option<Extension<ConnProtocol>>,
     ws: WebSocketUpgrade,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
 ) -> impl IntoResponse {
     // An upstream middleware may have already provided the user for us
-    let user = existing_user.and_then(|Extension(user)| Some(user));
+    let mut user = existing_user.and_then(|Extension(user)| Some(user));
+    // Otherwise, a verified client certificate authenticates the user.
+    if let Some(Extension(ConnProtocol::Https {
+        client_certificate: Some(certificate),
+    })) = conn_protocol
+    {
+        if user.is_none() {
+            match certificate_auth(&certificate, None) {
+                Ok(authed) => user = Some(authed),
+                Err(e) => return e.into_response(),
+            }
+        }
+    }
     let addr = Box::new(addr.ip());
     ws.max_message_size(MAX_REQUEST_SIZE)
         .on_upgrade(|ws| async move { run_ws(&state, user, *addr, ws).await })
@@ -1225,12 +1240,16 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedContinualTask { .. }
         | ExecuteResponse::CreatedType
         | ExecuteResponse::CreatedNetworkPolicy
+        | ExecuteResponse::CreatedPolicy
+        | ExecuteResponse::CreatedResourceGroup
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedPolicy
+        | ExecuteResponse::DroppedResourceGroup
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
@@ -1244,6 +1263,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::StartedTransaction { .. }
         | ExecuteResponse::Updated(_)
         | ExecuteResponse::AlteredObject(_)
+        | ExecuteResponse::AlteredResourceGroup
         | ExecuteResponse::AlteredRole
         | ExecuteResponse::AlteredSystemConfiguration
         | ExecuteResponse::Deallocate { .. }
@@ -10,6 +10,7 @@
 //! Helpers for handling events from a Webhook source.
 
 use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
 use mz_adapter::{AppendWebhookError, AppendWebhookResponse, WebhookAppenderCache};
@@ -21,7 +22,7 @@ use mz_repr::{Datum, Row, RowPacker, ScalarType, Timestamp};
 use mz_sql::plan::{WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders};
 use mz_storage_types::controller::StorageError;
 
-use axum::extract::{Path, State};
+use axum::extract::{ConnectInfo, Path, State};
 use axum::response::IntoResponse;
 use bytes::Bytes;
 use http::StatusCode;
@@ -35,6 +36,7 @@ pub async fn handle_webhook(
         webhook_cache,
     }): State<WebhookState>,
     Path((database, schema, name)): Path<(String, String, String)>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: http::HeaderMap,
     body: Bytes,
 ) -> impl IntoResponse {
@@ -62,6 +64,7 @@ pub async fn handle_webhook(
                 &database,
                 &schema,
                 &name,
+                addr.ip(),
                 &body,
                 &headers,
             )
@@ -81,13 +84,14 @@ pub async fn handle_webhook(
 }
 
 /// Append the provided `body` and `headers` to the webhook source identified via `database`,
-/// `schema`, and `name`.
+/// `schema`, and `name`, on behalf of a client at `client_ip`.
 async fn append_webhook(
     adapter_client: &mz_adapter::Client,
     webhook_cache: &WebhookAppenderCache,
     database: &str,
     schema: &str,
     name: &str,
+    client_ip: IpAddr,
     body: &Bytes,
     headers: &Arc<BTreeMap<String, String>>,
 ) -> Result<(), AppendWebhookError> {
@@ -103,6 +107,8 @@ async fn append_webhook(
         body_format,
         header_tys,
         validator,
+        owner,
+        network_policy,
     } = async {
         let mut guard = webhook_cache.entries.lock().await;
 
@@ -132,6 +138,12 @@ async fn append_webhook(
     }
     .await?;
 
+    // Requests must satisfy the network policy of the source's owner.
+    if let Err(e) = network_policy.and_then(|policy| policy.validate(&client_ip)) {
+        adapter_client.audit_network_policy_denied(&owner, Some(client_ip), e.to_string());
+        return Err(AppendWebhookError::NetworkPolicyDenied(e));
+    }
+
     // These must happen before validation as we do not know if validation or
     // packing will succeed and appending will begin
     tx.increment_messages_received(1);
@@ -322,6 +334,8 @@ pub enum WebhookError {
     InvalidBody { ty: ScalarType, msg: String },
     #[error("failed to validate the request")]
     ValidationFailed,
+    #[error("request denied by network policy")]
+    NetworkPolicyDenied,
     #[error("error occurred while running validation")]
     ValidationError,
     #[error("service unavailable")]
@@ -351,6 +365,7 @@ impl From<AppendWebhookError> for WebhookError {
                 name,
             } => WebhookError::NotFound(format!("'{database}.{schema}.{name}'")),
             AppendWebhookError::ValidationFailed => WebhookError::ValidationFailed,
+            AppendWebhookError::NetworkPolicyDenied(_) => WebhookError::NetworkPolicyDenied,
             AppendWebhookError::ChannelClosed => {
                 WebhookError::Internal(anyhow::anyhow!("channel closed"))
             }
@@ -383,6 +398,9 @@ impl IntoResponse for WebhookError {
             e @ WebhookError::InvalidHeaders(_) => {
                 (StatusCode::UNAUTHORIZED, e.to_string()).into_response()
             }
+            e @ WebhookError::NetworkPolicyDenied => {
+                (StatusCode::FORBIDDEN, e.to_string()).into_response()
+            }
             e @ WebhookError::Unavailable => {
                 (StatusCode::SERVICE_UNAVAILABLE, e.to_string()).into_response()
             }
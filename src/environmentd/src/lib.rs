@@ -16,6 +16,7 @@
 use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -36,6 +37,7 @@ use mz_catalog::durable::BootstrapArgs;
 use mz_cloud_resources::CloudResourceController;
 use mz_controller::ControllerConfig;
 use mz_frontegg_auth::Authenticator as FronteggAuthentication;
+use mz_oidc_auth::Authenticator as OidcAuthentication;
 use mz_ore::future::OreFutureExt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::NowFn;
@@ -88,6 +90,8 @@ pub struct Config {
     pub tls_reload_certs: ReloadTrigger,
     /// Frontegg JWT authentication configuration.
     pub frontegg: Option<FronteggAuthentication>,
+    /// OIDC JWT authentication configuration.
+    pub oidc: Option<OidcAuthentication>,
     /// Origins for which cross-origin resource sharing (CORS) for HTTP requests
     /// is permitted.
     pub cors_allowed_origin: AllowOrigin,
@@ -126,13 +130,17 @@ pub struct Config {
     pub availability_zones: Vec<String>,
     /// A map from size name to resource allocations for cluster replicas.
     pub cluster_replica_sizes: ClusterReplicaSizeMap,
-    /// The PostgreSQL URL for the Postgres-backed timestamp oracle.
+    /// The URL for the timestamp oracle, either a PostgreSQL URL or a
+    /// `persist+<consensus url>`.
     pub timestamp_oracle_url: Option<SensitiveUrl>,
     /// An API key for Segment. Enables export of audit events to Segment.
     pub segment_api_key: Option<String>,
     /// Whether the Segment client is being used on the client side
     /// (rather than the server side).
     pub segment_client_side: bool,
+    /// A file to which to append audit events as they occur, one JSON object
+    /// per line.
+    pub audit_log_export_path: Option<PathBuf>,
     /// An SDK key for LaunchDarkly. Enables system parameter synchronization
     /// with LaunchDarkly.
     pub launchdarkly_sdk_key: Option<String>,
@@ -298,13 +306,19 @@ impl Listeners {
             None => (None, None),
             Some(tls_config) => {
                 let context = tls_config.reloading_context(config.tls_reload_certs)?;
+                let client_auth = tls_config
+                    .client_auth
+                    .as_ref()
+                    .map(|client_auth| client_auth.role_source);
                 let pgwire_tls = mz_server_core::ReloadingTlsConfig {
                     context: context.clone(),
                     mode: mz_server_core::TlsMode::Require,
+                    client_auth,
                 };
                 let http_tls = http::ReloadingTlsConfig {
                     context,
                     mode: http::TlsMode::Require,
+                    client_auth,
                 };
                 (Some(pgwire_tls), Some(http_tls))
             }
@@ -647,6 +661,7 @@ impl Listeners {
             storage_usage_collection_interval: config.storage_usage_collection_interval,
             storage_usage_retention_period: config.storage_usage_retention_period,
             segment_client: segment_client.clone(),
+            audit_log_export_path: config.audit_log_export_path,
             egress_addresses: config.egress_addresses,
             remote_system_parameters,
             aws_account_id: config.aws_account_id,
@@ -684,6 +699,7 @@ impl Listeners {
                 tls: pgwire_tls.clone(),
                 adapter_client: adapter_client.clone(),
                 frontegg: config.frontegg.clone(),
+                oidc: config.oidc.clone(),
                 metrics: metrics.clone(),
                 internal: false,
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -714,6 +730,7 @@ impl Listeners {
                 }),
                 adapter_client: adapter_client.clone(),
                 frontegg: None,
+                oidc: None,
                 metrics: metrics.clone(),
                 internal: true,
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -735,6 +752,7 @@ impl Listeners {
                 source: "external",
                 tls: http_tls,
                 frontegg: config.frontegg.clone(),
+                oidc: config.oidc.clone(),
                 adapter_client: adapter_client.clone(),
                 allowed_origin: config.cors_allowed_origin.clone(),
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -758,6 +776,7 @@ impl Listeners {
                 // TODO(Alex): implement self-signed TLS for all internal connections
                 tls: None,
                 frontegg: config.frontegg.clone(),
+                oidc: config.oidc.clone(),
                 adapter_client: adapter_client.clone(),
                 allowed_origin: config.cors_allowed_origin,
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -781,6 +800,7 @@ impl Listeners {
                 tls: None,
                 adapter_client: adapter_client.clone(),
                 frontegg: config.frontegg.clone(),
+                oidc: config.oidc.clone(),
                 metrics,
                 internal: false,
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -41,7 +41,7 @@ use mz_persist_client::cfg::PersistConfig;
 use mz_persist_client::rpc::PersistGrpcPubSubServer;
 use mz_persist_client::PersistLocation;
 use mz_secrets::SecretsController;
-use mz_server_core::{ReloadTrigger, TlsCertConfig};
+use mz_server_core::{ClientCertRoleSource, ReloadTrigger, TlsCertConfig, TlsClientAuthConfig};
 use mz_sql::catalog::EnvironmentId;
 use mz_storage_types::connections::ConnectionContext;
 use mz_tracing::CloneableEnvFilter;
@@ -73,7 +73,9 @@ use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Message, WebSocket};
 use url::Url;
 
-use crate::{CatalogConfig, FronteggAuthentication, WebSocketAuth, WebSocketResponse};
+use crate::{
+    CatalogConfig, FronteggAuthentication, OidcAuthentication, WebSocketAuth, WebSocketResponse,
+};
 
 pub static KAFKA_ADDRS: LazyLock<String> =
     LazyLock::new(|| env::var("KAFKA_ADDRS").unwrap_or_else(|_| "localhost:9092".into()));
@@ -84,6 +86,7 @@ pub struct TestHarness {
     data_directory: Option<PathBuf>,
     tls: Option<TlsCertConfig>,
     frontegg: Option<FronteggAuthentication>,
+    oidc: Option<OidcAuthentication>,
     unsafe_mode: bool,
     workers: usize,
     now: NowFn,
@@ -117,6 +120,7 @@ impl Default for TestHarness {
             data_directory: None,
             tls: None,
             frontegg: None,
+            oidc: None,
             unsafe_mode: false,
             workers: 1,
             now: SYSTEM_TIME.clone(),
@@ -201,6 +205,25 @@ impl TestHarness {
         self.tls = Some(TlsCertConfig {
             cert: cert_path.into(),
             key: key_path.into(),
+            client_auth: None,
+        });
+        self
+    }
+
+    /// Requires clients to authenticate with a certificate signed by the CA
+    /// at `ca_path`. Must be called after [`TestHarness::with_tls`].
+    pub fn with_tls_client_auth(
+        mut self,
+        ca_path: impl Into<PathBuf>,
+        role_source: ClientCertRoleSource,
+    ) -> Self {
+        let tls = self
+            .tls
+            .as_mut()
+            .expect("client authentication requires TLS");
+        tls.client_auth = Some(TlsClientAuthConfig {
+            ca: ca_path.into(),
+            role_source,
         });
         self
     }
@@ -220,6 +243,11 @@ impl TestHarness {
         self
     }
 
+    pub fn with_oidc(mut self, oidc: &OidcAuthentication) -> Self {
+        self.oidc = Some(oidc.clone());
+        self
+    }
+
     pub fn with_now(mut self, now: NowFn) -> Self {
         self.now = now;
         self
@@ -495,6 +523,9 @@ impl Listeners {
                         secrets_reader_local_file_dir: Some(data_directory.join("secrets")),
                         secrets_reader_kubernetes_context: None,
                         secrets_reader_aws_prefix: None,
+                        secrets_reader_vault_address: None,
+                        secrets_reader_vault_mount: None,
+                        secrets_reader_vault_token_file: None,
                     },
                     connection_context,
                 },
@@ -502,6 +533,7 @@ impl Listeners {
                 cloud_resource_controller: None,
                 tls: config.tls,
                 frontegg: config.frontegg,
+                oidc: config.oidc,
                 unsafe_mode: config.unsafe_mode,
                 all_features: false,
                 metrics_registry: metrics_registry.clone(),
@@ -527,6 +559,7 @@ impl Listeners {
                 storage_usage_retention_period: config.storage_usage_retention_period,
                 segment_api_key: None,
                 segment_client_side: false,
+                audit_log_export_path: None,
                 egress_addresses: vec![],
                 aws_account_id: None,
                 aws_privatelink_availability_zones: None,
@@ -865,6 +898,11 @@ impl TestServerWithRuntime {
         &self.server.inner
     }
 
+    /// Returns the [`MetricsRegistry`] of the running `environmentd` server.
+    pub fn metrics_registry(&self) -> &MetricsRegistry {
+        &self.server.metrics_registry
+    }
+
     /// Connect to the __public__ SQL port of the running `environmentd` server.
     pub fn connect<T>(&self, tls: T) -> Result<postgres::Client, postgres::Error>
     where
@@ -15,6 +15,7 @@ use std::future::IntoFuture;
 use std::io::{Read, Write};
 use std::net::{IpAddr, Ipv4Addr, TcpStream};
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -40,14 +41,21 @@ use mz_frontegg_auth::{
 use mz_frontegg_mock::{
     models::ApiToken, models::TenantApiTokenConfig, models::UserConfig, FronteggMockServer,
 };
+use mz_oidc_auth::{
+    Authenticator as OidcAuthentication, AuthenticatorConfig as OidcConfig, JwksSource,
+};
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{NowFn, SYSTEM_TIME};
 use mz_ore::retry::Retry;
 use mz_ore::{assert_contains, assert_err, assert_none, assert_ok};
+use mz_server_core::ClientCertRoleSource;
 use mz_sql::names::PUBLIC_ROLE_NAME;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SYSTEM_USER};
+use openssl::bn::BigNumRef;
 use openssl::error::ErrorStack;
-use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions, SslVerifyMode};
+use openssl::ssl::{
+    SslConnector, SslConnectorBuilder, SslFiletype, SslMethod, SslOptions, SslVerifyMode,
+};
 use postgres::config::SslMode;
 use postgres::error::SqlState;
 use serde::Deserialize;
@@ -1447,6 +1455,397 @@ async fn test_auth_base_require_tls() {
     .await;
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_password() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .start()
+        .await;
+    let sys_client = server.connect().internal().await.unwrap();
+    sys_client
+        .batch_execute("CREATE ROLE alice LOGIN PASSWORD 'correct horse'")
+        .await
+        .unwrap();
+
+    let no_headers = HeaderMap::new();
+    let alice_header_basic = make_header(Authorization::basic("alice", "correct horse"));
+    let alice_header_bad_basic = make_header(Authorization::basic("alice", "battery staple"));
+
+    run_tests(
+        "Password",
+        &server,
+        &[
+            // The client negotiates SCRAM-SHA-256-PLUS, binding to the TLS channel.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("correct horse"),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("battery staple"),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                    assert_eq!(
+                        err.message(),
+                        "password authentication failed for user \"alice\""
+                    );
+                })),
+            },
+            // Roles without a password are trusted.
+            TestCase::Pgwire {
+                user_to_auth_as: "materialize",
+                user_reported_by_system: "materialize",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &alice_header_basic,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &alice_header_bad_basic,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: &*HTTP_DEFAULT_USER.name,
+                user_reported_by_system: &*HTTP_DEFAULT_USER.name,
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Ws {
+                auth: &WebSocketAuth::Basic {
+                    user: "alice".to_string(),
+                    password: "correct horse".to_string(),
+                    options: BTreeMap::default(),
+                },
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Ws {
+                auth: &WebSocketAuth::Basic {
+                    user: "alice".to_string(),
+                    password: "battery staple".to_string(),
+                    options: BTreeMap::default(),
+                },
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, CloseCode::Protocol);
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    )
+    .await;
+
+    // Once the password is removed, the role is trusted again.
+    sys_client
+        .batch_execute("ALTER ROLE alice PASSWORD NULL")
+        .await
+        .unwrap();
+    run_tests(
+        "Password removed",
+        &server,
+        &[TestCase::Pgwire {
+            user_to_auth_as: "alice",
+            user_reported_by_system: "alice",
+            password: None,
+            ssl_mode: SslMode::Require,
+            configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+            assert: Assert::Success,
+        }],
+    )
+    .await;
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_oidc() {
+    const ISSUER: &str = "https://idp.example.com";
+
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+
+    // Sign tokens with the CA's key, and publish its public half as a JWK.
+    let rsa = ca.pkey.rsa().unwrap();
+    let b64 = |n: &BigNumRef| base64::encode_config(n.to_vec(), base64::URL_SAFE_NO_PAD);
+    let jwks = serde_json::from_value(json!({
+        "keys": [{
+            "kty": "RSA",
+            "kid": "test",
+            "n": b64(rsa.n()),
+            "e": b64(rsa.e()),
+        }],
+    }))
+    .unwrap();
+    let encoding_key =
+        EncodingKey::from_rsa_pem(&ca.pkey.private_key_to_pem_pkcs8().unwrap()).unwrap();
+    let make_token = |email: &str, groups: &[&str], exp: i64| {
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = Some("test".into());
+        let claims = json!({
+            "iss": ISSUER,
+            "sub": Uuid::new_v4(),
+            "exp": exp,
+            "email": email,
+            "groups": groups,
+        });
+        jsonwebtoken::encode(&header, &claims, &encoding_key).unwrap()
+    };
+
+    let oidc = OidcAuthentication::new(OidcConfig {
+        issuer: ISSUER.into(),
+        audience: None,
+        jwks: JwksSource::Static(jwks),
+        user_claim: "email".into(),
+        role_claim: Some("groups".into()),
+        admin_role: Some("mzadmin".into()),
+        now: SYSTEM_TIME.clone(),
+    });
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_oidc(&oidc)
+        .start()
+        .await;
+    server
+        .connect()
+        .internal()
+        .await
+        .unwrap()
+        .batch_execute("CREATE ROLE analysts; CREATE ROLE auditors; CREATE ROLE operators")
+        .await
+        .unwrap();
+
+    let exp = SYSTEM_TIME.as_secs() + 3600;
+    let alice_token = make_token("alice@example.com", &["analysts"], exp);
+    let expired_token = make_token("alice@example.com", &["analysts"], exp - 7200);
+    let no_headers = HeaderMap::new();
+    let alice_header_bearer = make_header(Authorization::bearer(&alice_token).unwrap());
+    let alice_header_basic = make_header(Authorization::basic("alice@example.com", &alice_token));
+    let expired_header_bearer = make_header(Authorization::bearer(&expired_token).unwrap());
+
+    run_tests(
+        "OIDC",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                password: Some(&alice_token),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                password: Some(&expired_token),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                    assert_eq!(err.message(), "invalid password");
+                })),
+            },
+            // The token must belong to the user.
+            TestCase::Pgwire {
+                user_to_auth_as: "bob@example.com",
+                user_reported_by_system: "bob@example.com",
+                password: Some(&alice_token),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_contains!(err.to_string(), "password missing");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                scheme: Scheme::HTTPS,
+                headers: &alice_header_bearer,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                scheme: Scheme::HTTPS,
+                headers: &alice_header_basic,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice@example.com",
+                user_reported_by_system: "alice@example.com",
+                scheme: Scheme::HTTPS,
+                headers: &expired_header_bearer,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: assert_http_rejected(),
+            },
+            TestCase::Http {
+                user_to_auth_as: &*HTTP_DEFAULT_USER.name,
+                user_reported_by_system: &*HTTP_DEFAULT_USER.name,
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: assert_http_rejected(),
+            },
+            TestCase::Ws {
+                auth: &WebSocketAuth::Bearer {
+                    token: alice_token.clone(),
+                    options: BTreeMap::default(),
+                },
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Success,
+            },
+            TestCase::Ws {
+                auth: &WebSocketAuth::Bearer {
+                    token: expired_token.clone(),
+                    options: BTreeMap::default(),
+                },
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, CloseCode::Protocol);
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    )
+    .await;
+
+    let connect = |token: String| {
+        let server = &server;
+        async move {
+            server
+                .connect()
+                .with_tls(make_pg_tls(|b| Ok(b.set_verify(SslVerifyMode::NONE))))
+                .ssl_mode(SslMode::Require)
+                .user("alice@example.com")
+                .password(&token)
+                .await
+                .unwrap()
+        }
+    };
+    let memberships = |client: tokio_postgres::Client| async move {
+        client
+            .query(
+                "SELECT r.name FROM mz_role_members m
+                 JOIN mz_roles r ON m.role_id = r.id
+                 JOIN mz_roles u ON m.member = u.id
+                 WHERE u.name = current_user
+                 ORDER BY r.name",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| row.get::<_, String>(0))
+            .collect::<Vec<_>>()
+    };
+
+    // Role membership follows the role claim every time the user connects.
+    let client = connect(alice_token.clone()).await;
+    assert_eq!(memberships(client).await, ["analysts"]);
+    let client = connect(make_token(
+        "alice@example.com",
+        &["auditors", "unknown"],
+        exp,
+    ))
+    .await;
+    assert_eq!(memberships(client).await, ["auditors"]);
+
+    // Memberships granted outside of the synchronization are left alone, even when granted by
+    // mz_system.
+    let internal = server.connect().internal().await.unwrap();
+    internal
+        .batch_execute(r#"GRANT operators TO "alice@example.com""#)
+        .await
+        .unwrap();
+    let client = connect(make_token("alice@example.com", &["auditors"], exp)).await;
+    assert_eq!(memberships(client).await, ["auditors", "operators"]);
+    let grantors: Vec<(String, String)> = internal
+        .query(
+            "SELECT r.name, g.name FROM mz_role_members m
+             JOIN mz_roles r ON m.role_id = r.id
+             JOIN mz_roles g ON m.grantor = g.id
+             JOIN mz_roles u ON m.member = u.id
+             WHERE u.name = 'alice@example.com'
+             ORDER BY r.name",
+            &[],
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| (row.get(0), row.get(1)))
+        .collect();
+    assert_eq!(
+        grantors,
+        [
+            ("auditors".into(), "mz_external_role_sync".into()),
+            ("operators".into(), "mz_system".into()),
+        ]
+    );
+
+    // The admin role makes the user a superuser.
+    let client = connect(make_token("alice@example.com", &["mzadmin"], exp)).await;
+    let is_superuser: String = client
+        .query_one("SHOW is_superuser", &[])
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(is_superuser, "on");
+    assert_eq!(memberships(client).await, ["operators"]);
+
+    // The session ends when the token expires.
+    let client = connect(make_token(
+        "alice@example.com",
+        &[],
+        SYSTEM_TIME.as_secs() + 2,
+    ))
+    .await;
+    sleep(Duration::from_secs(4)).await;
+    assert_err!(client.query_one("SELECT 1", &[]).await);
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 async fn test_auth_intermediate_ca_no_intermediary() {
@@ -1556,6 +1955,143 @@ async fn test_auth_intermediate_ca() {
     .await;
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_client_certificate() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let (client_cert, client_key) = ca.request_client_cert("alice").unwrap();
+    let (system_cert, system_key) = ca.request_client_cert(&SYSTEM_USER.name).unwrap();
+
+    // A certificate signed by an untrusted CA must be rejected.
+    let untrusted_ca = Ca::new_root("untrusted ca").unwrap();
+    let (untrusted_cert, untrusted_key) = untrusted_ca.request_client_cert("alice").unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_tls_client_auth(ca.ca_cert_path(), ClientCertRoleSource::CommonName)
+        .start()
+        .await;
+
+    let configure_cert = |cert: &PathBuf, key: &PathBuf| {
+        let cert = cert.clone();
+        let key = key.clone();
+        let ca_path = ca.ca_cert_path();
+        Box::new(move |b: &mut SslConnectorBuilder| {
+            b.set_ca_file(&ca_path)?;
+            b.set_certificate_file(&cert, SslFiletype::PEM)?;
+            b.set_private_key_file(&key, SslFiletype::PEM)
+        })
+    };
+    let basic_alice = make_header(Authorization::basic("alice", ""));
+    let basic_bob = make_header(Authorization::basic("bob", ""));
+
+    run_tests(
+        "TlsMode::Require, client certificates",
+        &server,
+        &[
+            // The certificate authenticates the role that it names.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: configure_cert(&client_cert, &client_key),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &HeaderMap::new(),
+                configure: configure_cert(&client_cert, &client_key),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &basic_alice,
+                configure: configure_cert(&client_cert, &client_key),
+                assert: Assert::Success,
+            },
+            // The certificate does not authenticate other roles.
+            TestCase::Pgwire {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: configure_cert(&client_cert, &client_key),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                    assert_eq!(
+                        err.message(),
+                        "certificate authentication failed for user \"bob\""
+                    );
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                scheme: Scheme::HTTPS,
+                headers: &basic_bob,
+                configure: configure_cert(&client_cert, &client_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Certificates cannot authenticate system users.
+            TestCase::Http {
+                user_to_auth_as: &*SYSTEM_USER.name,
+                user_reported_by_system: &*SYSTEM_USER.name,
+                scheme: Scheme::HTTPS,
+                headers: &HeaderMap::new(),
+                configure: configure_cert(&system_cert, &system_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Connections without a trusted certificate fail the handshake.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_contains!(err.to_string(), "handshake failure");
+                })),
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: configure_cert(&untrusted_cert, &untrusted_key),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_contains!(err.to_string(), "unknown ca");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &HeaderMap::new(),
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_none!(code);
+                    assert_contains!(message, "handshake failure");
+                })),
+            },
+        ],
+    )
+    .await;
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 async fn test_auth_admin_non_superuser() {
@@ -2924,6 +2924,148 @@ fn test_webhook_duplicate_headers() {
     assert_eq!(resp.status().as_u16(), 401);
 }
 
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_network_policy_per_role() {
+    let server = test_util::TestHarness::default().start_blocking();
+
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    let mut mz_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    let http_client = Client::new();
+
+    mz_client
+        .batch_execute("ALTER SYSTEM SET enable_network_policies = on")
+        .unwrap();
+    mz_client
+        .batch_execute(
+            "CREATE NETWORK POLICY closed (RULES (r1 (address='192.0.2.0/24', action='allow', direction='ingress')))",
+        )
+        .unwrap();
+    mz_client
+        .batch_execute(
+            "CREATE ROLE restricted; ALTER ROLE restricted SET network_policy = 'closed'",
+        )
+        .unwrap();
+
+    // Roles without a policy of their own are validated against the default allow list.
+    server
+        .pg_config()
+        .user("unrestricted")
+        .connect(postgres::NoTls)
+        .unwrap();
+    let err = server
+        .pg_config()
+        .user("restricted")
+        .connect(postgres::NoTls)
+        .unwrap_err();
+    assert_contains!(err.to_string(), "session denied");
+
+    // A policy that does not exist denies all connections.
+    mz_client
+        .batch_execute("ALTER ROLE restricted SET network_policy = 'missing'")
+        .unwrap();
+    server
+        .pg_config()
+        .user("restricted")
+        .connect(postgres::NoTls)
+        .unwrap_err();
+
+    // Denied connections are recorded even though logins are not audited.
+    let denied: i64 = mz_client
+        .query_one(
+            "SELECT count(*) FROM mz_audit_events WHERE event_type = 'login-failed' AND details->>'role_name' = 'restricted'",
+            &[],
+        )
+        .unwrap()
+        .get(0);
+    assert_eq!(denied, 2);
+
+    // Webhook requests are validated against the policy of the source's owner.
+    client
+        .batch_execute("CREATE CLUSTER webhook_cluster REPLICAS (r1 (SIZE '1'))")
+        .unwrap();
+    client
+        .batch_execute(
+            "CREATE SOURCE webhook_text IN CLUSTER webhook_cluster FROM WEBHOOK BODY FORMAT TEXT",
+        )
+        .unwrap();
+    let webhook_url = format!(
+        "http://{}/api/webhook/materialize/public/webhook_text",
+        server.inner().http_local_addr()
+    );
+    let resp = http_client
+        .post(&webhook_url)
+        .body("allowed")
+        .send()
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    mz_client
+        .batch_execute("ALTER ROLE materialize SET network_policy = 'closed'")
+        .unwrap();
+    for _ in 0..3 {
+        let resp = http_client
+            .post(&webhook_url)
+            .body("denied")
+            .send()
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    mz_client
+        .batch_execute("ALTER ROLE materialize RESET network_policy")
+        .unwrap();
+    let resp = http_client
+        .post(&webhook_url)
+        .body("allowed")
+        .send()
+        .unwrap();
+    assert!(resp.status().is_success());
+
+    Retry::default()
+        .retry(|_| {
+            let denied: i64 = mz_client
+                .query_one(
+                    "SELECT count(*) FROM mz_audit_events WHERE event_type = 'login-failed' AND details->>'role_name' = 'materialize'",
+                    &[],
+                )
+                .unwrap()
+                .get(0);
+            if denied == 1 {
+                Ok(())
+            } else {
+                Err(format!("expected 1 denied webhook request, found {denied}"))
+            }
+        })
+        .unwrap();
+
+    // Repeated denials are recorded in the audit log once, but every denial is
+    // counted.
+    let denials: BTreeMap<_, _> = server
+        .metrics_registry()
+        .gather()
+        .into_iter()
+        .find(|m| m.get_name() == "mz_network_policy_denials_total")
+        .unwrap()
+        .get_metric()
+        .iter()
+        .map(|m| {
+            (
+                m.get_label()[0].get_value().to_string(),
+                m.get_counter().get_value(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        denials,
+        BTreeMap::from([("session".to_string(), 2.0), ("webhook".to_string(), 3.0)])
+    );
+}
+
 // Test that websockets observe cancellation and leave the transaction in an idle state.
 #[mz_ore::test]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `epoll_wait` on OS `linux`
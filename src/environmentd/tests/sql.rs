@@ -634,7 +634,7 @@ fn test_subscribe_basic() {
     assert!(err
         .unwrap_db_error()
         .message()
-        .starts_with("Timestamp (1) is not valid for all inputs"));
+        .starts_with("AS OF timestamp 1 is before the retained history"));
 }
 
 /// Test the done messages by sending inserting a single row and waiting to
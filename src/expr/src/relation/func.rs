@@ -3639,6 +3639,16 @@ pub enum TableFunc {
         name: String,
         relation: RelationType,
     },
+    /// The batches and parts of the persist shard backing a collection.
+    ///
+    /// Like unmaterializable functions, this cannot be evaluated in a dataflow
+    /// and must be replaced by the coordinator before optimization.
+    MzPersistShardParts,
+    /// A summary of the state of the persist shard backing a collection.
+    ///
+    /// Like unmaterializable functions, this cannot be evaluated in a dataflow
+    /// and must be replaced by the coordinator before optimization.
+    MzPersistShardState,
 }
 
 impl RustType<ProtoTableFunc> for TableFunc {
@@ -3673,6 +3683,8 @@ impl RustType<ProtoTableFunc> for TableFunc {
                         relation: Some(relation.into_proto()),
                     })
                 }
+                TableFunc::MzPersistShardParts => Kind::MzPersistShardParts(()),
+                TableFunc::MzPersistShardState => Kind::MzPersistShardState(()),
             }),
         }
     }
@@ -3717,6 +3729,8 @@ impl RustType<ProtoTableFunc> for TableFunc {
                     .relation
                     .into_rust_if_some("ProtoTabletizedScalar::relation")?,
             },
+            Kind::MzPersistShardParts(()) => TableFunc::MzPersistShardParts,
+            Kind::MzPersistShardState(()) => TableFunc::MzPersistShardState,
         })
     }
 }
@@ -3796,6 +3810,12 @@ impl TableFunc {
                 let r = Row::pack_slice(datums);
                 Ok(Box::new(std::iter::once((r, 1))))
             }
+            TableFunc::MzPersistShardParts | TableFunc::MzPersistShardState => {
+                Err(EvalError::Unsupported {
+                    feature: format!("calling {self} outside of a one-off SELECT").into(),
+                    discussion_no: None,
+                })
+            }
         }
     }
 
@@ -3924,6 +3944,41 @@ impl TableFunc {
             TableFunc::TabletizedScalar { relation, .. } => {
                 return relation.clone();
             }
+            TableFunc::MzPersistShardParts => {
+                let column_types = vec![
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::String.nullable(false),
+                    ScalarType::String.nullable(true),
+                    ScalarType::UInt64.nullable(true),
+                    ScalarType::Int64.nullable(true),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::Jsonb.nullable(true),
+                ];
+                let keys = vec![vec![0, 4, 5]];
+                (column_types, keys)
+            }
+            TableFunc::MzPersistShardState => {
+                let column_types = vec![
+                    ScalarType::String.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::MzTimestamp.nullable(true),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::UInt64.nullable(false),
+                    ScalarType::Jsonb.nullable(false),
+                ];
+                let keys = vec![vec![]];
+                (column_types, keys)
+            }
         };
 
         if !keys.is_empty() {
@@ -3953,6 +4008,8 @@ impl TableFunc {
             TableFunc::UnnestMap { .. } => 2,
             TableFunc::Wrap { width, .. } => *width,
             TableFunc::TabletizedScalar { relation, .. } => relation.column_types.len(),
+            TableFunc::MzPersistShardParts => 12,
+            TableFunc::MzPersistShardState => 11,
         }
     }
 
@@ -3973,7 +4030,9 @@ impl TableFunc {
             | TableFunc::Repeat
             | TableFunc::UnnestArray { .. }
             | TableFunc::UnnestList { .. }
-            | TableFunc::UnnestMap { .. } => true,
+            | TableFunc::UnnestMap { .. }
+            | TableFunc::MzPersistShardParts
+            | TableFunc::MzPersistShardState => true,
             TableFunc::Wrap { .. } => false,
             TableFunc::TabletizedScalar { .. } => false,
         }
@@ -4002,6 +4061,8 @@ impl TableFunc {
             TableFunc::UnnestMap { .. } => true,
             TableFunc::Wrap { .. } => true,
             TableFunc::TabletizedScalar { .. } => true,
+            TableFunc::MzPersistShardParts => false,
+            TableFunc::MzPersistShardState => false,
         }
     }
 }
@@ -4027,6 +4088,8 @@ impl fmt::Display for TableFunc {
             TableFunc::UnnestMap { .. } => f.write_str("unnest_map"),
             TableFunc::Wrap { width, .. } => write!(f, "wrap{}", width),
             TableFunc::TabletizedScalar { name, .. } => f.write_str(name),
+            TableFunc::MzPersistShardParts => f.write_str("mz_persist_shard_parts"),
+            TableFunc::MzPersistShardState => f.write_str("mz_persist_shard_state"),
         }
     }
 }
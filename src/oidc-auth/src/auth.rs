@@ -0,0 +1,676 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Context as _;
+use derivative::Derivative;
+use jsonwebtoken::jwk::{AlgorithmParameters, Jwk, JwkSet};
+use jsonwebtoken::{DecodingKey, Validation};
+use mz_ore::now::NowFn;
+use mz_repr::user::ExternalUserMetadata;
+use serde_json::Value;
+use tokio::sync::watch;
+use tokio::time;
+use uuid::Uuid;
+
+use crate::{Error, OidcCliArgs};
+
+/// The claim that contains the name of the user, if not otherwise configured.
+pub const DEFAULT_USER_CLAIM: &str = "sub";
+
+/// The maximum length of a user name.
+pub const MAX_USER_NAME_LENGTH: usize = 255;
+
+/// The minimum amount of time between fetches of a [`JwksSource::Url`].
+///
+/// Tokens signed by an unknown key trigger a refetch, as the identity provider
+/// may have rotated its keys. This bounds how often clients presenting bogus
+/// tokens can make us contact the identity provider.
+const JWKS_MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Where an [`Authenticator`] gets the keys used to validate JWTs.
+#[derive(Clone, Debug)]
+pub enum JwksSource {
+    /// A fixed set of keys, e.g., loaded from a local file.
+    Static(JwkSet),
+    /// A URL from which the set of keys is fetched, e.g., the `jwks_uri` of an
+    /// OpenID Connect provider.
+    Url(String),
+}
+
+/// Configures an [`Authenticator`].
+#[derive(Clone, Debug)]
+pub struct AuthenticatorConfig {
+    /// The expected value of the `iss` claim.
+    pub issuer: String,
+    /// The expected value of the `aud` claim, if any.
+    pub audience: Option<String>,
+    /// The keys used to validate JWTs.
+    pub jwks: JwksSource,
+    /// The claim that contains the name of the user.
+    pub user_claim: String,
+    /// The claim that contains the names of the roles the user is a member of,
+    /// if role membership is managed by the identity provider.
+    pub role_claim: Option<String>,
+    /// The role in the role claim that makes a user an administrator.
+    pub admin_role: Option<String>,
+    /// Function to provide system time to validate exp (expires at) field of JWTs.
+    pub now: NowFn,
+}
+
+/// Facilitates authenticating users with JWTs issued by an OpenID Connect
+/// provider.
+#[derive(Clone, Debug)]
+pub struct Authenticator {
+    inner: Arc<AuthenticatorInner>,
+}
+
+impl Authenticator {
+    /// Creates a new authenticator.
+    pub fn new(config: AuthenticatorConfig) -> Self {
+        let (keys, jwks_url) = match config.jwks {
+            JwksSource::Static(set) => (public_keys(set), None),
+            JwksSource::Url(url) => (JwkSet { keys: vec![] }, Some(url)),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("must build Client");
+
+        Authenticator {
+            inner: Arc::new(AuthenticatorInner {
+                issuer: config.issuer,
+                audience: config.audience,
+                user_claim: config.user_claim,
+                role_claim: config.role_claim,
+                admin_role: config.admin_role,
+                now: config.now,
+                jwks_url,
+                client,
+                keys: Mutex::new(Keys {
+                    set: keys,
+                    last_fetch: None,
+                }),
+            }),
+        }
+    }
+
+    /// Create an [`Authenticator`] from [`OidcCliArgs`].
+    pub fn from_args(args: OidcCliArgs) -> Result<Option<Self>, Error> {
+        let Some(issuer) = args.oidc_issuer else {
+            return Ok(None);
+        };
+        let jwks = match (args.oidc_jwks_file, args.oidc_jwks_url) {
+            (Some(path), None) => {
+                let jwks = std::fs::read(&path)
+                    .with_context(|| format!("reading {path:?} for --oidc-jwks-file"))?;
+                let jwks = serde_json::from_slice(&jwks)
+                    .with_context(|| format!("parsing {path:?} for --oidc-jwks-file"))?;
+                JwksSource::Static(jwks)
+            }
+            (None, Some(url)) => JwksSource::Url(url),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "expected exactly one of --oidc-jwks-file or --oidc-jwks-url"
+                )
+                .into())
+            }
+        };
+        let config = AuthenticatorConfig {
+            issuer,
+            audience: args.oidc_audience,
+            jwks,
+            user_claim: args.oidc_user_claim,
+            role_claim: args.oidc_role_claim,
+            admin_role: args.oidc_admin_role,
+            now: mz_ore::now::SYSTEM_TIME.clone(),
+        };
+        Ok(Some(Self::new(config)))
+    }
+
+    /// Establishes a new authentication session from an access token that was
+    /// presented in place of a password.
+    ///
+    /// If successful, returns a handle to the authentication session, which
+    /// expires when the token does. Otherwise, returns the authentication
+    /// error.
+    pub async fn authenticate(
+        &self,
+        expected_user: &str,
+        token: &str,
+    ) -> Result<AuthSessionHandle, Error> {
+        match self.validate_access_token(token, Some(expected_user)).await {
+            Ok(claims) => {
+                tracing::debug!("authentication successful");
+                let (external_metadata_tx, external_metadata_rx) =
+                    watch::channel(claims.to_external_user_metadata());
+                Ok(AuthSessionHandle {
+                    user: claims.user,
+                    exp: claims.exp,
+                    now: self.inner.now.clone(),
+                    _external_metadata_tx: Arc::new(external_metadata_tx),
+                    external_metadata_rx,
+                })
+            }
+            Err(e) => {
+                tracing::debug!(error = ?e, "authentication failed");
+                Err(e)
+            }
+        }
+    }
+
+    /// Validates an access token, returning the validated claims.
+    ///
+    /// The following validations are always performed:
+    ///
+    ///   * The token is signed by a key in the configured key set.
+    ///
+    ///   * The token is not expired, according to the `Authenticator`'s clock.
+    ///
+    ///   * The issuer, and the audience if configured, match the
+    ///     `Authenticator`'s.
+    ///
+    /// If `expected_user` is provided, the token's user name is additionally
+    /// validated to match `expected_user`.
+    pub async fn validate_access_token(
+        &self,
+        token: &str,
+        expected_user: Option<&str>,
+    ) -> Result<ValidatedClaims, Error> {
+        self.inner.validate_access_token(token, expected_user).await
+    }
+}
+
+/// A handle to an authentication session.
+///
+/// An authentication session lasts until the access token that established it
+/// expires. Unlike Frontegg sessions, OIDC sessions are not refreshed, as we
+/// only ever see the access token and not the means to obtain a new one.
+///
+/// [`AuthSessionHandle::expired`] can be used to learn when the session has
+/// expired.
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+pub struct AuthSessionHandle {
+    user: String,
+    exp: i64,
+    #[derivative(Debug = "ignore")]
+    now: NowFn,
+    /// Held so that receivers are not closed while the session is live.
+    _external_metadata_tx: Arc<watch::Sender<ExternalUserMetadata>>,
+    external_metadata_rx: watch::Receiver<ExternalUserMetadata>,
+}
+
+impl AuthSessionHandle {
+    /// Returns the name of the user that created the session.
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Mints a receiver for updates to the session user's external metadata.
+    pub fn external_metadata_rx(&self) -> watch::Receiver<ExternalUserMetadata> {
+        self.external_metadata_rx.clone()
+    }
+
+    /// Completes when the authentication session has expired.
+    pub async fn expired(&mut self) {
+        loop {
+            let remaining = self.exp.saturating_sub(self.now.as_secs());
+            let Ok(remaining) = u64::try_from(remaining) else {
+                break;
+            };
+            if remaining == 0 {
+                break;
+            }
+            // Recheck after sleeping, in case the clock used for validation
+            // disagrees with the clock used for sleeping.
+            time::sleep(Duration::from_secs(remaining)).await;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AuthenticatorInner {
+    issuer: String,
+    audience: Option<String>,
+    user_claim: String,
+    role_claim: Option<String>,
+    admin_role: Option<String>,
+    now: NowFn,
+    /// Where to refetch keys from, if they are not static.
+    jwks_url: Option<String>,
+    client: reqwest::Client,
+    keys: Mutex<Keys>,
+}
+
+#[derive(Debug)]
+struct Keys {
+    set: JwkSet,
+    /// The time at which the key set was last fetched from
+    /// [`AuthenticatorInner::jwks_url`].
+    last_fetch: Option<Instant>,
+}
+
+impl Keys {
+    /// Returns the key identified by `kid`.
+    ///
+    /// Tokens are not required to identify their signing key if the key set
+    /// contains only a single key.
+    fn find(&self, kid: Option<&str>) -> Option<Jwk> {
+        match kid {
+            Some(kid) => self.set.find(kid).cloned(),
+            None => match self.set.keys.as_slice() {
+                [key] => Some(key.clone()),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl AuthenticatorInner {
+    async fn validate_access_token(
+        &self,
+        token: &str,
+        expected_user: Option<&str>,
+    ) -> Result<ValidatedClaims, Error> {
+        let header = jsonwebtoken::decode_header(token)?;
+        let jwk = self.signing_key(header.kid.as_deref()).await?;
+        let decoding_key = DecodingKey::from_jwk(&jwk)?;
+
+        // The algorithm must still match the family of the key, which rules
+        // out symmetric algorithms, as we only accept public keys.
+        let mut validation = Validation::new(header.alg);
+        // We validate the token expiration with our own now function.
+        validation.validate_exp = false;
+        validation.set_issuer(&[&self.issuer]);
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        let msg =
+            jsonwebtoken::decode::<BTreeMap<String, Value>>(token, &decoding_key, &validation)?;
+        let claims = msg.claims;
+
+        let exp = claims
+            .get("exp")
+            .and_then(Value::as_i64)
+            .ok_or(Error::MissingClaims)?;
+        if exp < self.now.as_secs() {
+            return Err(Error::TokenExpired);
+        }
+        let sub = claims
+            .get("sub")
+            .and_then(Value::as_str)
+            .ok_or(Error::MissingClaims)?;
+        let user = lookup_claim(&claims, &self.user_claim)
+            .and_then(Value::as_str)
+            .ok_or(Error::MissingClaims)?;
+
+        if let Some(expected_user) = expected_user {
+            validate_user(user, expected_user)?;
+        }
+
+        let roles = self.role_claim.as_ref().map(|role_claim| {
+            match lookup_claim(&claims, role_claim) {
+                Some(Value::Array(roles)) => roles
+                    .iter()
+                    .filter_map(|role| role.as_str().map(String::from))
+                    .collect(),
+                Some(Value::String(role)) => vec![role.clone()],
+                // A missing claim means the user is not a member of any role.
+                _ => vec![],
+            }
+        });
+        let is_admin = match (&self.admin_role, &roles) {
+            (Some(admin_role), Some(roles)) => roles.contains(admin_role),
+            _ => false,
+        };
+
+        Ok(ValidatedClaims {
+            exp,
+            // The `sub` claim is only unique within an issuer, and need not be
+            // a UUID, so derive a stable UUID from both.
+            user_id: Uuid::new_v5(
+                &Uuid::NAMESPACE_URL,
+                format!("{}#{}", self.issuer, sub).as_bytes(),
+            ),
+            user: user.to_string(),
+            is_admin,
+            roles,
+            _private: (),
+        })
+    }
+
+    /// Returns the key identified by `kid`, refetching the key set if the key
+    /// is unknown and the key set is fetched from a URL.
+    async fn signing_key(&self, kid: Option<&str>) -> Result<Jwk, Error> {
+        {
+            let mut keys = self.keys.lock().expect("lock poisoned");
+            if let Some(jwk) = keys.find(kid) {
+                return Ok(jwk);
+            }
+            if self.jwks_url.is_none() {
+                return Err(Error::UnknownSigningKey);
+            }
+            if let Some(last_fetch) = keys.last_fetch {
+                if last_fetch.elapsed() < JWKS_MIN_REFETCH_INTERVAL {
+                    return Err(Error::UnknownSigningKey);
+                }
+            }
+            keys.last_fetch = Some(Instant::now());
+        }
+
+        let url = self.jwks_url.as_ref().expect("checked above");
+        tracing::debug!(?kid, %url, "fetching JSON Web Key Set");
+        let set: JwkSet = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let mut keys = self.keys.lock().expect("lock poisoned");
+        keys.set = public_keys(set);
+        keys.find(kid).ok_or(Error::UnknownSigningKey)
+    }
+}
+
+/// [`Claims`](jsonwebtoken::TokenData::claims) that have been validated by
+/// [`Authenticator::validate_access_token`].
+#[derive(Clone, Debug)]
+pub struct ValidatedClaims {
+    /// The time at which the claims expire, represented in seconds since the
+    /// Unix epoch.
+    pub exp: i64,
+    /// The ID of the authenticated user, derived from the issuer and the `sub`
+    /// claim.
+    pub user_id: Uuid,
+    /// The name of the authenticated user, taken from the configured user
+    /// claim.
+    pub user: String,
+    /// Whether the authenticated user is an administrator.
+    pub is_admin: bool,
+    /// The names of the roles the user is a member of, if a role claim is
+    /// configured.
+    pub roles: Option<Vec<String>>,
+    // Prevent construction outside of `Authenticator::validate_access_token`.
+    _private: (),
+}
+
+impl ValidatedClaims {
+    /// Constructs an [`ExternalUserMetadata`] from the claims data.
+    pub fn to_external_user_metadata(&self) -> ExternalUserMetadata {
+        ExternalUserMetadata {
+            admin: self.is_admin,
+            user_id: self.user_id,
+            roles: self.roles.clone(),
+        }
+    }
+}
+
+/// Looks up a possibly nested claim, where `path` separates the names of
+/// nested claims with dots.
+fn lookup_claim<'a>(claims: &'a BTreeMap<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut names = path.split('.');
+    let mut value = claims.get(names.next()?)?;
+    for name in names {
+        value = value.get(name)?;
+    }
+    Some(value)
+}
+
+/// Removes any symmetric keys from `set`, as tokens signed with a secret that
+/// is known to the identity provider's clients must not be trusted.
+fn public_keys(mut set: JwkSet) -> JwkSet {
+    set.keys
+        .retain(|key| !matches!(key.algorithm, AlgorithmParameters::OctetKey(_)));
+    set
+}
+
+fn validate_user(user: &str, expected_user: &str) -> Result<(), Error> {
+    // Impose a maximum length on user names for sanity.
+    if user.len() > MAX_USER_NAME_LENGTH {
+        return Err(Error::UserNameTooLong);
+    }
+    // As with Frontegg, email addresses are compared case insensitively.
+    let valid = match expected_user.contains('@') {
+        false => user == expected_user,
+        true => user.to_lowercase() == expected_user.to_lowercase(),
+    };
+    match valid {
+        false => Err(Error::WrongUser),
+        true => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use clap::Parser;
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use mz_ore::now::NOW_ZERO;
+    use openssl::rsa::Rsa;
+    use serde_json::json;
+
+    use super::*;
+
+    const ISSUER: &str = "https://idp.example.com";
+
+    struct Signer {
+        kid: &'static str,
+        encoding_key: EncodingKey,
+        jwk: Value,
+    }
+
+    impl Signer {
+        fn new(kid: &'static str) -> Signer {
+            let rsa = Rsa::generate(2048).unwrap();
+            let b64 = |n: &openssl::bn::BigNumRef| {
+                base64::encode_config(n.to_vec(), base64::URL_SAFE_NO_PAD)
+            };
+            let jwk = json!({
+                "kty": "RSA",
+                "use": "sig",
+                "alg": "RS256",
+                "kid": kid,
+                "n": b64(rsa.n()),
+                "e": b64(rsa.e()),
+            });
+            let encoding_key =
+                EncodingKey::from_rsa_pem(&rsa.private_key_to_pem().unwrap()).unwrap();
+            Signer {
+                kid,
+                encoding_key,
+                jwk,
+            }
+        }
+
+        fn sign(&self, claims: &Value) -> String {
+            let mut header = Header::new(Algorithm::RS256);
+            header.kid = Some(self.kid.into());
+            jsonwebtoken::encode(&header, claims, &self.encoding_key).unwrap()
+        }
+    }
+
+    fn jwks(signers: &[&Signer]) -> JwkSet {
+        let keys: Vec<_> = signers.iter().map(|s| s.jwk.clone()).collect();
+        serde_json::from_value(json!({ "keys": keys })).unwrap()
+    }
+
+    fn authenticator(jwks: JwkSet) -> Authenticator {
+        Authenticator::new(AuthenticatorConfig {
+            issuer: ISSUER.into(),
+            audience: Some("materialize".into()),
+            jwks: JwksSource::Static(jwks),
+            user_claim: "email".into(),
+            role_claim: Some("realm_access.roles".into()),
+            admin_role: Some("mzadmin".into()),
+            now: NOW_ZERO.clone(),
+        })
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_validate_access_token() {
+        let signer = Signer::new("k1");
+        let other = Signer::new("k2");
+        let auth = authenticator(jwks(&[&signer]));
+
+        let claims = json!({
+            "iss": ISSUER,
+            "aud": "materialize",
+            "sub": "8d0a6d4f",
+            "exp": 100,
+            "email": "Alice@example.com",
+            "realm_access": { "roles": ["analysts", "mzadmin"] },
+        });
+        let validated = auth
+            .validate_access_token(&signer.sign(&claims), Some("alice@example.com"))
+            .await
+            .unwrap();
+        assert_eq!(validated.user, "Alice@example.com");
+        assert_eq!(validated.exp, 100);
+        assert!(validated.is_admin);
+        assert_eq!(
+            validated.roles,
+            Some(vec!["analysts".to_string(), "mzadmin".to_string()])
+        );
+        // User IDs are stable.
+        let again = auth
+            .validate_access_token(&signer.sign(&claims), None)
+            .await
+            .unwrap();
+        assert_eq!(validated.user_id, again.user_id);
+
+        // A missing role claim means no roles.
+        let mut no_roles = claims.clone();
+        no_roles.as_object_mut().unwrap().remove("realm_access");
+        let validated = auth
+            .validate_access_token(&signer.sign(&no_roles), None)
+            .await
+            .unwrap();
+        assert!(!validated.is_admin);
+        assert_eq!(validated.roles, Some(vec![]));
+
+        let invalid = |f: fn(&mut serde_json::Map<String, Value>)| {
+            let mut claims = claims.clone();
+            f(claims.as_object_mut().unwrap());
+            signer.sign(&claims)
+        };
+        let cases = [
+            (
+                invalid(|c| {
+                    c.insert("exp".into(), json!(-1));
+                }),
+                "authentication token expired",
+            ),
+            (
+                invalid(|c| {
+                    c.insert("iss".into(), json!("https://evil.example.com"));
+                }),
+                "invalid token format: InvalidIssuer",
+            ),
+            (
+                invalid(|c| {
+                    c.insert("aud".into(), json!("other"));
+                }),
+                "invalid token format: InvalidAudience",
+            ),
+            (
+                invalid(|c| {
+                    c.remove("email");
+                }),
+                "authentication token missing claims",
+            ),
+            (
+                other.sign(&claims),
+                "authentication token signed by unknown key",
+            ),
+        ];
+        for (token, expected) in cases {
+            let err = auth.validate_access_token(&token, None).await.unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        }
+
+        // Tokens signed by a known key ID with a different key are rejected.
+        let forged = Signer::new("k1");
+        let err = auth
+            .validate_access_token(&forged.sign(&claims), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid token format: InvalidSignature");
+
+        let err = auth
+            .validate_access_token(&signer.sign(&claims), Some("bob@example.com"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "user in access token did not match the expected user"
+        );
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_from_args() {
+        let signer = Signer::new("k1");
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let jwks = json!({
+            "keys": [
+                signer.jwk,
+                // Symmetric keys are ignored.
+                { "kty": "oct", "kid": "hmac", "k": "c2VjcmV0" },
+            ],
+        });
+        file.write_all(jwks.to_string().as_bytes()).unwrap();
+
+        let args = OidcCliArgs::parse_from([
+            "test",
+            "--oidc-issuer",
+            ISSUER,
+            "--oidc-jwks-file",
+            file.path().to_str().unwrap(),
+        ]);
+        let auth = Authenticator::from_args(args).unwrap().unwrap();
+
+        let token = signer.sign(&json!({
+            "iss": ISSUER,
+            "sub": "alice",
+            "exp": i64::MAX,
+        }));
+        let mut session = auth.authenticate("alice", &token).await.unwrap();
+        assert_eq!(session.user(), "alice");
+        assert_eq!(session.external_metadata_rx().borrow().roles, None);
+        assert!(!session.external_metadata_rx().borrow().admin);
+
+        // The session lasts until the token expires.
+        let expired = tokio::time::timeout(Duration::from_millis(100), session.expired()).await;
+        assert!(expired.is_err());
+
+        let token = signer.sign(&json!({
+            "iss": ISSUER,
+            "sub": "alice",
+            "exp": mz_ore::now::SYSTEM_TIME.as_secs() + 1,
+        }));
+        let mut session = auth.authenticate("alice", &token).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(10), session.expired())
+            .await
+            .unwrap();
+
+        let args = OidcCliArgs::parse_from(["test"]);
+        assert!(Authenticator::from_args(args).unwrap().is_none());
+    }
+}
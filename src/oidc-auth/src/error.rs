@@ -0,0 +1,44 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+#[derive(Clone, Error, Debug)]
+pub enum Error {
+    #[error("invalid token format: {0}")]
+    InvalidTokenFormat(#[from] jsonwebtoken::errors::Error),
+    #[error("authentication token signed by unknown key")]
+    UnknownSigningKey,
+    #[error("fetching JSON Web Key Set failed: {0}")]
+    ReqwestError(Arc<reqwest::Error>),
+    #[error("authentication token missing claims")]
+    MissingClaims,
+    #[error("authentication token expired")]
+    TokenExpired,
+    #[error("user in access token did not match the expected user")]
+    WrongUser,
+    #[error("user name too long")]
+    UserNameTooLong,
+    #[error("internal error")]
+    Internal(Arc<anyhow::Error>),
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(value: anyhow::Error) -> Self {
+        Error::Internal(Arc::new(value))
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        Error::ReqwestError(Arc::new(value))
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Authentication of JWTs issued by OpenID Connect providers.
+//!
+//! Unlike `mz-frontegg-auth`, which exchanges app passwords for tokens with
+//! Frontegg, this crate expects clients to present an access token that they
+//! obtained from the identity provider themselves. The token is validated
+//! against the provider's JSON Web Key Set (JWKS), and its claims determine
+//! the user's name and role membership.
+
+mod auth;
+mod error;
+
+use std::path::PathBuf;
+
+pub use auth::{
+    AuthSessionHandle, Authenticator, AuthenticatorConfig, JwksSource, ValidatedClaims,
+    DEFAULT_USER_CLAIM, MAX_USER_NAME_LENGTH,
+};
+pub use error::Error;
+
+/// Command line arguments for OIDC authentication.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct OidcCliArgs {
+    /// Enables OIDC authentication of JWTs issued by the specified issuer.
+    ///
+    /// Must exactly match the `iss` claim of accepted tokens.
+    #[clap(long, env = "OIDC_ISSUER", value_name = "URL")]
+    oidc_issuer: Option<String>,
+    /// Path to a JSON Web Key Set used to validate JWTs during OIDC
+    /// authentication.
+    #[clap(
+        long,
+        env = "OIDC_JWKS_FILE",
+        requires = "oidc-issuer",
+        conflicts_with = "oidc-jwks-url"
+    )]
+    oidc_jwks_file: Option<PathBuf>,
+    /// The URL of a JSON Web Key Set used to validate JWTs during OIDC
+    /// authentication. The key set is refetched when a token is signed by an
+    /// unknown key.
+    #[clap(
+        long,
+        env = "OIDC_JWKS_URL",
+        requires = "oidc-issuer",
+        value_name = "URL"
+    )]
+    oidc_jwks_url: Option<String>,
+    /// The audience that must be present in the `aud` claim of accepted
+    /// tokens. If unset, the audience is not validated.
+    #[clap(long, env = "OIDC_AUDIENCE", requires = "oidc-issuer")]
+    oidc_audience: Option<String>,
+    /// The claim that contains the name of the user.
+    #[clap(
+        long,
+        env = "OIDC_USER_CLAIM",
+        requires = "oidc-issuer",
+        default_value = DEFAULT_USER_CLAIM
+    )]
+    oidc_user_claim: String,
+    /// The claim that contains the names of the roles the user is a member of,
+    /// e.g., `groups`. Nested claims can be specified with dots, e.g.,
+    /// `realm_access.roles`.
+    ///
+    /// If set, the user's role membership is synchronized with the claim every
+    /// time the user connects.
+    #[clap(long, env = "OIDC_ROLE_CLAIM", requires = "oidc-issuer")]
+    oidc_role_claim: Option<String>,
+    /// The role in the role claim that makes a user a superuser.
+    #[clap(long, env = "OIDC_ADMIN_ROLE", requires = "oidc-role-claim")]
+    oidc_admin_role: Option<String>,
+}
@@ -43,6 +43,8 @@ enum Command {
     Inspect(mz_persist_client::cli::inspect::InspectArgs),
     Admin(mz_persist_client::cli::admin::AdminArgs),
     Bench(mz_persist_client::cli::bench::BenchArgs),
+    Export(mz_persist_client::cli::transfer::ExportArgs),
+    Import(mz_persist_client::cli::transfer::ImportArgs),
     Service(crate::service::Args),
 }
 
@@ -80,6 +82,8 @@ fn main() {
         }
         Command::Admin(command) => runtime.block_on(mz_persist_client::cli::admin::run(command)),
         Command::Bench(command) => runtime.block_on(mz_persist_client::cli::bench::run(command)),
+        Command::Export(args) => runtime.block_on(mz_persist_client::cli::transfer::export(args)),
+        Command::Import(args) => runtime.block_on(mz_persist_client::cli::transfer::import(args)),
         Command::Service(args) => runtime.block_on(crate::service::run(args)),
     };
 
@@ -1488,6 +1488,18 @@ impl<T: Timestamp> PartDeletes<T> {
         }
     }
 
+    /// The keys of all parts and runs to be deleted.
+    pub fn keys(&self) -> impl Iterator<Item = &PartialBatchKey> {
+        self.blob_keys.iter().chain(self.hollow_runs.keys())
+    }
+
+    /// Keeps only the parts and runs whose keys satisfy `f`, along with all
+    /// the parts in those runs.
+    pub fn retain(&mut self, mut f: impl FnMut(&PartialBatchKey) -> bool) {
+        self.blob_keys.retain(|key| f(key));
+        self.hollow_runs.retain(|key, _| f(key));
+    }
+
     pub async fn delete(
         mut self,
         blob: &dyn Blob,
@@ -31,11 +31,11 @@ use mz_persist::location::{
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::Timestamp;
 use tokio::sync::{Mutex, OnceCell};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
-use crate::internal::cache::BlobMemCache;
+use crate::internal::cache::{BlobDiskCache, BlobMemCache};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -196,7 +196,11 @@ impl PersistClientCache {
         Ok(consensus)
     }
 
-    async fn open_blob(&self, blob_uri: SensitiveUrl) -> Result<Arc<dyn Blob>, ExternalError> {
+    /// Returns the [`Blob`] for the given URI, opening it if necessary.
+    ///
+    /// Persist owns the keys of the shards it manages. Other users of the returned handle must
+    /// confine themselves to keys that cannot collide with those, e.g. by using a distinct prefix.
+    pub async fn open_blob(&self, blob_uri: SensitiveUrl) -> Result<Arc<dyn Blob>, ExternalError> {
         let mut blob_by_uri = self.blob_by_uri.lock().await;
         let blob = match blob_by_uri.entry(blob_uri) {
             Entry::Occupied(x) => Arc::clone(&x.get().1),
@@ -222,8 +226,34 @@ impl PersistClientCache {
                     Self::PROMETHEUS_SCRAPE_INTERVAL,
                 )
                 .await;
-                // This is intentionally "outside" (wrapping) MetricsBlob so
-                // that we don't include cached responses in blob metrics.
+                // The caches are intentionally "outside" (wrapping) MetricsBlob
+                // so that we don't include cached responses in blob metrics.
+                let blob: Arc<dyn Blob> = match &self.cfg.blob_cache_disk_dir {
+                    Some(dir) => {
+                        let disk_cache = BlobDiskCache::open(
+                            &self.cfg,
+                            dir.clone(),
+                            self.cfg.blob_cache_disk_limit_bytes,
+                            Arc::clone(&self.metrics),
+                            Arc::clone(&blob) as Arc<dyn Blob>,
+                        )
+                        .await;
+                        match disk_cache {
+                            Ok(disk_cache) => disk_cache,
+                            // The cache is only an optimization, so continue
+                            // without it rather than failing to open blob.
+                            Err(err) => {
+                                warn!(
+                                    "failed to open blob disk cache in {}: {}",
+                                    dir.display(),
+                                    err
+                                );
+                                blob
+                            }
+                        }
+                    }
+                    None => blob,
+                };
                 let blob = BlobMemCache::new(&self.cfg, Arc::clone(&self.metrics), blob);
                 Arc::clone(&x.insert((RttLatencyTask(task.abort_on_drop()), blob)).1)
             }
@@ -11,6 +11,7 @@
 
 //! The tunable knobs for persist.
 
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -149,6 +150,11 @@ pub struct PersistConfig {
     /// Number of worker threads to create for the [`crate::IsolatedRuntime`], defaults to the
     /// number of threads.
     pub isolated_runtime_worker_threads: usize,
+    /// A local directory in which to cache blobs read from blob storage, if
+    /// any.
+    pub blob_cache_disk_dir: Option<PathBuf>,
+    /// The maximum number of bytes to cache in `blob_cache_disk_dir`.
+    pub blob_cache_disk_limit_bytes: usize,
 }
 
 // Impl Deref to ConfigSet for convenience of accessing the dynamic configs.
@@ -211,6 +217,8 @@ impl PersistConfig {
             pubsub_state_cache_shard_ref_channel_size: 25,
             pubsub_reconnect_backoff: Duration::from_secs(5),
             isolated_runtime_worker_threads: num_cpus::get(),
+            blob_cache_disk_dir: None,
+            blob_cache_disk_limit_bytes: 8 * 1024 * MiB,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -334,6 +342,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::fetch::FETCH_SEMAPHORE_COST_ADJUSTMENT)
         .add(&crate::fetch::FETCH_SEMAPHORE_PERMIT_ADJUSTMENT)
         .add(&crate::internal::cache::BLOB_CACHE_MEM_LIMIT_BYTES)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_ENABLED)
         .add(&crate::internal::compact::COMPACTION_MINIMUM_TIMEOUT)
         .add(&crate::internal::machine::CLAIM_UNCLAIMED_COMPACTIONS)
         .add(&crate::internal::machine::CLAIM_COMPACTION_PERCENT)
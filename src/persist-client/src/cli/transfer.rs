@@ -0,0 +1,882 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! CLI tools for moving a shard between environments.
+//!
+//! An export is a directory holding the updates of a shard at some `as_of`,
+//! consolidated and split across Parquet files with a `(k, v, t, d)` schema,
+//! plus a `manifest.json` describing the shard's codecs, registered schemas,
+//! and frontiers. Keys and values are kept in their [Codec] encoding, so an
+//! export can be re-ingested without this tool knowing the concrete types.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fs::File;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail, Context};
+use arrow::array::{new_empty_array, Array, ArrayRef, BinaryArray, Int64Array, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use bytes::{BufMut, Bytes};
+use differential_dataflow::lattice::Lattice;
+use futures_util::TryStreamExt;
+use mz_dyncfg::ConfigUpdates;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
+use mz_persist_types::codec_impls::TodoColumnarDecoder;
+use mz_persist_types::columnar::{ColumnEncoder, Schema2};
+use mz_persist_types::schema::SchemaId;
+use mz_persist_types::stats::NoneStats;
+use mz_persist_types::{Codec, Codec64};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use serde::{Deserialize, Serialize};
+use timely::progress::{Antichain, Timestamp};
+use timely::PartialOrder;
+use tracing::info;
+
+use crate::async_runtime::IsolatedRuntime;
+use crate::batch::BATCH_COLUMNAR_FORMAT;
+use crate::cache::StateCache;
+use crate::cli::args::{make_blob, make_consensus, StateArgs};
+use crate::fetch::{Cursor, EncodedPart};
+use crate::internal::state::{EncodedSchemas, StateCollections};
+use crate::internal::state_versions::StateVersions;
+use crate::read::ReadHandle;
+use crate::rpc::{NoopPubSubSender, PubSubSender};
+use crate::schema::CaESchema;
+use crate::stats::STATS_COLLECTION_ENABLED;
+use crate::{
+    Diagnostics, Metrics, PersistClient, PersistConfig, BUILD_INFO,
+    DANGEROUS_ENABLE_SCHEMA_EVOLUTION,
+};
+
+/// The name of the file describing an export.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// The version of the export format written by this tool.
+const MANIFEST_VERSION: u64 = 1;
+
+/// Exports the contents of a shard at an `as_of` to a directory.
+#[derive(Debug, clap::Parser)]
+pub struct ExportArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The timestamp at which to export the shard. Must be readable, i.e. not
+    /// less than the shard's since and less than its upper.
+    #[clap(long)]
+    as_of: u64,
+
+    /// Directory to write the export to. Created if it doesn't exist, and must
+    /// not already contain an export.
+    #[clap(long)]
+    output_dir: PathBuf,
+
+    /// The maximum number of updates to write to each Parquet file.
+    #[clap(long, default_value_t = 1_000_000)]
+    updates_per_file: usize,
+}
+
+/// Imports an export into a new shard, which must not have been written to.
+#[derive(Debug, clap::Parser)]
+pub struct ImportArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// Directory containing an export written by `export`.
+    #[clap(long)]
+    input_dir: PathBuf,
+
+    /// Whether to write to the shard (defaults to only verifying the export).
+    #[clap(long)]
+    commit: bool,
+}
+
+/// A description of an export, written to its [MANIFEST_FILE].
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    version: u64,
+    shard_id: String,
+    as_of: u64,
+    since: Vec<u64>,
+    upper: Vec<u64>,
+    key_codec: String,
+    val_codec: String,
+    ts_codec: String,
+    diff_codec: String,
+    /// Every schema registered for the shard, in the order of their ids.
+    schemas: Vec<ManifestSchemas>,
+    files: Vec<ManifestFile>,
+}
+
+/// The hex-encoded contents of an [EncodedSchemas].
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestSchemas {
+    key: String,
+    key_data_type: String,
+    val: String,
+    val_data_type: String,
+}
+
+/// A Parquet file of an export, along with enough information to validate
+/// its contents.
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestFile {
+    name: String,
+    updates: usize,
+    diffs_sum: i64,
+}
+
+/// Writes the updates of `args.state`'s shard at `args.as_of` to
+/// `args.output_dir`.
+///
+/// A reader is registered with the shard for the duration of the export,
+/// which holds back the shard's since at the `as_of` and keeps the parts being
+/// read from being deleted. The parts are otherwise read directly, without
+/// decoding their updates. All updates are consolidated in memory.
+pub async fn export(args: ExportArgs) -> Result<(), anyhow::Error> {
+    let shard_id = args.state.shard_id();
+    let state_versions = args.state.open().await?;
+    let versions = state_versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await;
+    let state = state_versions
+        .fetch_current_state::<u64>(&shard_id, versions.0.clone())
+        .await;
+    let (key_codec, val_codec, ts_codec, diff_codec) = (
+        state.key_codec.clone(),
+        state.val_codec.clone(),
+        state.ts_codec.clone(),
+        state.diff_codec.clone(),
+    );
+    if diff_codec != i64::codec_name() {
+        bail!(
+            "unsupported diff codec {diff_codec}, expected {}",
+            i64::codec_name()
+        );
+    }
+    let _ = state.check_ts_codec(&shard_id)?;
+
+    let manifest_path = args.output_dir.join(MANIFEST_FILE);
+    if manifest_path.exists() {
+        bail!("{} already contains an export", args.output_dir.display());
+    }
+
+    *SHARD_CODECS.lock().expect("lockable") = (key_codec.clone(), val_codec.clone());
+    let client = make_client(&args.state).await?;
+    let diagnostics = Diagnostics {
+        shard_name: shard_id.to_string(),
+        handle_purpose: "persist-cli export".to_string(),
+    };
+    // The reader never decodes anything, so it doesn't need real schemas.
+    let mut read = client
+        .open_leased_reader::<EncodedKey, EncodedVal, u64, i64>(
+            shard_id,
+            Arc::new(EncodedSchema::decode(&Bytes::new())),
+            Arc::new(EncodedSchema::decode(&Bytes::new())),
+            diagnostics,
+            false,
+        )
+        .await?;
+
+    // Hold the since at the as_of for the whole export and make sure the
+    // reader goes away again, whether or not the export succeeds.
+    let res = read_snapshot(&mut read, &state_versions, args.as_of).await;
+    read.expire().await;
+    let (updates, collections) = res?;
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let mut files = Vec::new();
+    for (idx, chunk) in updates.chunks(args.updates_per_file.max(1)).enumerate() {
+        let name = format!("part-{idx:05}.parquet");
+        write_updates(&args.output_dir.join(&name), chunk)?;
+        files.push(ManifestFile {
+            name,
+            updates: chunk.len(),
+            diffs_sum: chunk.iter().map(|(_, _, d)| d).sum(),
+        });
+    }
+
+    let to_hex = |x: &Bytes| hex::encode(x);
+    let manifest = Manifest {
+        version: MANIFEST_VERSION,
+        shard_id: shard_id.to_string(),
+        as_of: args.as_of,
+        since: collections.trace.since().elements().to_vec(),
+        upper: collections.trace.upper().elements().to_vec(),
+        key_codec,
+        val_codec,
+        ts_codec,
+        diff_codec,
+        schemas: collections
+            .schemas
+            .values()
+            .map(|x| ManifestSchemas {
+                key: to_hex(&x.key),
+                key_data_type: to_hex(&x.key_data_type),
+                val: to_hex(&x.val),
+                val_data_type: to_hex(&x.val_data_type),
+            })
+            .collect(),
+        files,
+    };
+    // Write the manifest last, so that its presence marks a complete export.
+    std::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)?;
+    println!(
+        "exported {} updates in {} files from shard {} at {}",
+        updates.len(),
+        manifest.files.len(),
+        shard_id,
+        args.as_of
+    );
+
+    Ok(())
+}
+
+/// Reads the consolidated contents of `read`'s shard at `as_of_ts`, along with
+/// the state it was read from.
+async fn read_snapshot(
+    read: &mut ReadHandle<EncodedKey, EncodedVal, u64, i64>,
+    state_versions: &StateVersions,
+    as_of_ts: u64,
+) -> Result<(Vec<((Vec<u8>, Vec<u8>), u64, i64)>, StateCollections<u64>), anyhow::Error> {
+    let as_of = Antichain::from_elem(as_of_ts);
+    if !PartialOrder::less_equal(read.since(), &as_of) {
+        bail!(
+            "as_of {} is not readable: shard since is {:?}",
+            as_of_ts,
+            read.since().elements()
+        );
+    }
+    read.downgrade_since(&as_of).await;
+    let upper = read
+        .machine
+        .applier
+        .fetch_upper(|upper| upper.clone())
+        .await;
+    if !upper.less_than(&as_of_ts) {
+        bail!(
+            "as_of {} is not readable: shard upper is {:?}",
+            as_of_ts,
+            upper.elements()
+        );
+    }
+    let batches = read
+        .machine
+        .snapshot(&as_of)
+        .await
+        .map_err(|since| anyhow!("as_of {} is not readable: {:?}", as_of_ts, since))?;
+    // Keep the parts of the snapshot from being deleted until we're done.
+    let lease = read.lease_seqno();
+    let collections = read.machine.applier.clone_collections();
+
+    let shard_id = read.shard_id();
+    let shard_metrics = state_versions.metrics.shards.shard(&shard_id, "unknown");
+    let mut updates = Vec::new();
+    for batch in batches.iter() {
+        // Batches entirely beyond the as_of contribute nothing.
+        if !batch.desc.lower().less_equal(&as_of_ts) {
+            continue;
+        }
+        let mut part_stream =
+            pin!(batch.part_stream(shard_id, &*state_versions.blob, &*state_versions.metrics));
+        while let Some(part) = part_stream.try_next().await? {
+            info!("fetching {}", part.printable_name());
+            let encoded_part = EncodedPart::fetch(
+                &shard_id,
+                &*state_versions.blob,
+                &state_versions.metrics,
+                &shard_metrics,
+                &state_versions.metrics.read.snapshot,
+                &batch.desc,
+                &part,
+            )
+            .await
+            .map_err(|key| anyhow!("blob {key} is missing, was the shard compacted?"))?;
+            let mut cursor = Cursor::default();
+            while let Some(((k, v, mut t, d), _)) = cursor.pop(&encoded_part) {
+                if !t.less_equal(&as_of_ts) {
+                    continue;
+                }
+                t.advance_by(as_of.borrow());
+                let d = <i64 as Codec64>::decode(d);
+                updates.push(((k.to_owned(), v.to_owned()), t, d));
+            }
+        }
+    }
+    differential_dataflow::consolidation::consolidate_updates(&mut updates);
+    drop(lease);
+
+    Ok((updates, collections))
+}
+
+/// Imports the export in `args.input_dir` into `args.state`'s shard.
+///
+/// The export's schemas are registered with the shard in order, evolving each
+/// into the next with [PersistClient::compare_and_evolve_schema], which
+/// verifies that they remain backward compatible. The updates are then
+/// written as a single batch at the export's `as_of`, leaving the shard's
+/// upper at `as_of + 1`.
+pub async fn import(args: ImportArgs) -> Result<(), anyhow::Error> {
+    let shard_id = args.state.shard_id();
+    let manifest: Manifest = serde_json::from_slice(
+        &std::fs::read(args.input_dir.join(MANIFEST_FILE)).context("reading manifest")?,
+    )
+    .context("decoding manifest")?;
+    if manifest.version != MANIFEST_VERSION {
+        bail!("unsupported export version {}", manifest.version);
+    }
+    if manifest.ts_codec != u64::codec_name() || manifest.diff_codec != i64::codec_name() {
+        bail!(
+            "unsupported codecs: ts {} diff {}",
+            manifest.ts_codec,
+            manifest.diff_codec
+        );
+    }
+    let Some(new_upper) = manifest.as_of.checked_add(1) else {
+        bail!("as_of {} cannot be imported", manifest.as_of);
+    };
+
+    // Validate every file against the manifest before touching the shard.
+    let mut updates = Vec::new();
+    for file in manifest.files.iter() {
+        let file_updates = read_updates(&args.input_dir.join(&file.name))
+            .with_context(|| format!("reading {}", file.name))?;
+        let diffs_sum: i64 = file_updates.iter().map(|(_, _, d)| d).sum();
+        if file_updates.len() != file.updates || diffs_sum != file.diffs_sum {
+            bail!(
+                "{} has {} updates with diffs summing to {}, expected {} and {}",
+                file.name,
+                file_updates.len(),
+                diffs_sum,
+                file.updates,
+                file.diffs_sum
+            );
+        }
+        if let Some((_, t, _)) = file_updates.iter().find(|(_, t, _)| *t != manifest.as_of) {
+            bail!(
+                "{} has an update at {t}, expected {}",
+                file.name,
+                manifest.as_of
+            );
+        }
+        updates.extend(file_updates);
+    }
+    let schemas = manifest
+        .schemas
+        .iter()
+        .map(|x| {
+            let unhex = |x: &str| hex::decode(x).map(Bytes::from);
+            Ok(EncodedSchemas {
+                key: unhex(&x.key)?,
+                key_data_type: unhex(&x.key_data_type)?,
+                val: unhex(&x.val)?,
+                val_data_type: unhex(&x.val_data_type)?,
+            })
+        })
+        .collect::<Result<Vec<_>, hex::FromHexError>>()?;
+    if !args.commit {
+        println!(
+            "verified {} updates in {} files for shard {} at {}; pass --commit to import",
+            updates.len(),
+            manifest.files.len(),
+            manifest.shard_id,
+            manifest.as_of
+        );
+        return Ok(());
+    }
+
+    *SHARD_CODECS.lock().expect("lockable") =
+        (manifest.key_codec.clone(), manifest.val_codec.clone());
+    let schemas = schemas
+        .iter()
+        .map(|x| {
+            (
+                EncodedSchema::<EncodedKey>::register(&x.key, &x.key_data_type),
+                EncodedSchema::<EncodedVal>::register(&x.val, &x.val_data_type),
+            )
+        })
+        .collect::<Vec<_>>();
+    let Some(((first_key, first_val), evolutions)) = schemas.split_first() else {
+        bail!("export has no registered schemas");
+    };
+
+    let client = make_client(&args.state).await?;
+    let diagnostics = Diagnostics {
+        shard_name: shard_id.to_string(),
+        handle_purpose: "persist-cli import".to_string(),
+    };
+
+    let latest = client
+        .latest_schema::<EncodedKey, EncodedVal, u64, i64>(shard_id, diagnostics.clone())
+        .await?;
+    if let Some((schema_id, _, _)) = latest {
+        bail!("shard {shard_id} already has registered schema {schema_id}");
+    }
+    let mut write = client
+        .open_writer::<EncodedKey, EncodedVal, u64, i64>(
+            shard_id,
+            Arc::new(first_key.clone()),
+            Arc::new(first_val.clone()),
+            diagnostics.clone(),
+        )
+        .await?;
+    if write.schema_id() != Some(SchemaId(0)) {
+        bail!("unable to register schemas for shard {shard_id}");
+    }
+    if write.upper() != &Antichain::from_elem(u64::minimum()) {
+        bail!(
+            "shard {shard_id} has already been written to: upper is {:?}",
+            write.upper().elements()
+        );
+    }
+    for (idx, (key, val)) in evolutions.iter().enumerate() {
+        let res = client
+            .compare_and_evolve_schema::<EncodedKey, EncodedVal, u64, i64>(
+                shard_id,
+                SchemaId(idx),
+                key,
+                val,
+                diagnostics.clone(),
+            )
+            .await?;
+        match res {
+            CaESchema::Ok(id) if id == SchemaId(idx + 1) => {}
+            CaESchema::Ok(id) => bail!("schema {} was registered as {id}", idx + 1),
+            CaESchema::Incompatible => {
+                bail!(
+                    "schema {} is not backward compatible with its predecessor",
+                    idx + 1
+                )
+            }
+            CaESchema::ExpectedMismatch { schema_id, .. } => {
+                bail!("shard {shard_id} concurrently registered schema {schema_id}")
+            }
+        }
+    }
+    if let Some((key, val)) = evolutions.last() {
+        // Write with the latest schema.
+        write.expire().await;
+        write = client
+            .open_writer(
+                shard_id,
+                Arc::new(key.clone()),
+                Arc::new(val.clone()),
+                diagnostics.clone(),
+            )
+            .await?;
+    }
+
+    let lower = Antichain::from_elem(u64::minimum());
+    let upper = Antichain::from_elem(new_upper);
+    let mut builder = write.builder(lower.clone());
+    for ((k, v), t, d) in updates.iter() {
+        builder
+            .add(&EncodedKey(k.clone()), &EncodedVal(v.clone()), t, d)
+            .await?;
+    }
+    let mut batch = builder.finish(upper.clone()).await?;
+    let result = write
+        .compare_and_append_batch(&mut [&mut batch], lower, upper)
+        .await?;
+    if let Err(err) = result {
+        bail!("failed to append to shard {shard_id}: {err:?}");
+    }
+    write.expire().await;
+    println!(
+        "imported {} updates from shard {} into shard {} at {}",
+        updates.len(),
+        manifest.shard_id,
+        shard_id,
+        manifest.as_of
+    );
+
+    Ok(())
+}
+
+/// Returns a client that can write to `state`'s location, for shards whose
+/// keys and values are only known by their [SHARD_CODECS].
+async fn make_client(state: &StateArgs) -> Result<PersistClient, anyhow::Error> {
+    let cfg = PersistConfig::new_default_configs(&BUILD_INFO, SYSTEM_TIME.clone());
+    // The real types of the keys and values are unknown here, so only write
+    // them in their codec encoding.
+    let mut updates_cfg = ConfigUpdates::default();
+    updates_cfg.add(&DANGEROUS_ENABLE_SCHEMA_EVOLUTION, true);
+    updates_cfg.add(&STATS_COLLECTION_ENABLED, false);
+    updates_cfg.add(&BATCH_COLUMNAR_FORMAT, "row");
+    updates_cfg.apply(&cfg.configs);
+    let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+    let consensus = make_consensus(&cfg, &state.consensus_uri, true, Arc::clone(&metrics)).await?;
+    let blob = make_blob(&cfg, &state.blob_uri, true, Arc::clone(&metrics)).await?;
+    let pubsub_sender: Arc<dyn PubSubSender> = Arc::new(NoopPubSubSender);
+    let shared_states = Arc::new(StateCache::new(
+        &cfg,
+        Arc::clone(&metrics),
+        Arc::clone(&pubsub_sender),
+    ));
+    let client = PersistClient::new(
+        cfg,
+        blob,
+        consensus,
+        metrics,
+        Arc::new(IsolatedRuntime::default()),
+        shared_states,
+        pubsub_sender,
+    )?;
+    Ok(client)
+}
+
+/// The Arrow schema of the Parquet files in an export.
+fn updates_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("k", DataType::Binary, false),
+        Field::new("v", DataType::Binary, false),
+        Field::new("t", DataType::UInt64, false),
+        Field::new("d", DataType::Int64, false),
+    ]))
+}
+
+fn write_updates(path: &Path, updates: &[((Vec<u8>, Vec<u8>), u64, i64)]) -> anyhow::Result<()> {
+    let schema = updates_schema();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(BinaryArray::from_iter_values(
+            updates.iter().map(|((k, _), _, _)| k),
+        )),
+        Arc::new(BinaryArray::from_iter_values(
+            updates.iter().map(|((_, v), _, _)| v),
+        )),
+        Arc::new(UInt64Array::from_iter_values(
+            updates.iter().map(|(_, t, _)| *t),
+        )),
+        Arc::new(Int64Array::from_iter_values(
+            updates.iter().map(|(_, _, d)| *d),
+        )),
+    ];
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::default()))
+        .build();
+    let mut writer =
+        ArrowWriter::try_new(File::create(path)?, Arc::clone(&schema), Some(properties))?;
+    writer.write(&RecordBatch::try_new(schema, columns)?)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn read_updates(path: &Path) -> anyhow::Result<Vec<((Vec<u8>, Vec<u8>), u64, i64)>> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+    if builder.schema().fields() != updates_schema().fields() {
+        bail!("unexpected schema {:?}", builder.schema());
+    }
+    let mut updates = Vec::new();
+    for batch in builder.build()? {
+        let batch = batch?;
+        let (Some(k), Some(v), Some(t), Some(d)) = (
+            batch.column(0).as_any().downcast_ref::<BinaryArray>(),
+            batch.column(1).as_any().downcast_ref::<BinaryArray>(),
+            batch.column(2).as_any().downcast_ref::<UInt64Array>(),
+            batch.column(3).as_any().downcast_ref::<Int64Array>(),
+        ) else {
+            bail!("unexpected column types");
+        };
+        for idx in 0..batch.num_rows() {
+            updates.push((
+                (k.value(idx).to_vec(), v.value(idx).to_vec()),
+                t.value(idx),
+                d.value(idx),
+            ));
+        }
+    }
+    Ok(updates)
+}
+
+/// The codec names of the shard being exported or imported, which must match
+/// the ones recorded in its state. See the similar hack in `inspect`.
+static SHARD_CODECS: Mutex<(String, String)> = Mutex::new((String::new(), String::new()));
+
+/// The Arrow data types of the schemas being imported, keyed by their
+/// encoding, so that [Codec::decode_schema] can reconstruct an
+/// [EncodedSchema] from the bytes recorded in state.
+static IMPORT_DATA_TYPES: Mutex<BTreeMap<(&'static str, Bytes), DataType>> =
+    Mutex::new(BTreeMap::new());
+
+/// A key of the shard being exported or imported, in its codec encoding.
+#[derive(Debug, Default, PartialEq)]
+struct EncodedKey(Vec<u8>);
+
+/// A value of the shard being exported or imported, in its codec encoding.
+#[derive(Debug, Default, PartialEq)]
+struct EncodedVal(Vec<u8>);
+
+/// A schema known only by its encoding and the Arrow data type it produces.
+///
+/// Exports and imports only read and write codec-encoded data, so this never
+/// encodes or decodes anything in a structured format, but it does produce empty columns of the
+/// recorded data type, which is what persist's schema registry compares.
+#[derive(Debug)]
+struct EncodedSchema<T> {
+    encoded: Bytes,
+    data_type: DataType,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: EncodedCodec> EncodedSchema<T> {
+    fn register(encoded: &Bytes, data_type: &Bytes) -> Self {
+        let data_type = EncodedSchemas::decode_data_type(data_type);
+        IMPORT_DATA_TYPES
+            .lock()
+            .expect("lockable")
+            .insert((T::NAME, encoded.clone()), data_type.clone());
+        EncodedSchema {
+            encoded: encoded.clone(),
+            data_type,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn decode(encoded: &Bytes) -> Self {
+        // Schemas not from the export (e.g. ones already registered with the
+        // target shard) are only needed for error messages.
+        let data_type = IMPORT_DATA_TYPES
+            .lock()
+            .expect("lockable")
+            .get(&(T::NAME, encoded.clone()))
+            .cloned()
+            .unwrap_or(DataType::Null);
+        EncodedSchema {
+            encoded: encoded.clone(),
+            data_type,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for EncodedSchema<T> {
+    fn clone(&self) -> Self {
+        EncodedSchema {
+            encoded: self.encoded.clone(),
+            data_type: self.data_type.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for EncodedSchema<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoded == other.encoded
+    }
+}
+
+impl<T: Debug + Send + Sync> Schema2<T> for EncodedSchema<T> {
+    type ArrowColumn = ArrayRef;
+    type Statistics = NoneStats;
+
+    type Decoder = TodoColumnarDecoder<T>;
+    type Encoder = EmptyEncoder<T>;
+
+    fn decoder(&self, _col: Self::ArrowColumn) -> Result<Self::Decoder, anyhow::Error> {
+        bail!("imports don't decode structured data")
+    }
+
+    fn encoder(&self) -> Result<Self::Encoder, anyhow::Error> {
+        Ok(EmptyEncoder {
+            data_type: self.data_type.clone(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// A [ColumnEncoder] that only produces empty columns.
+#[derive(Debug)]
+struct EmptyEncoder<T> {
+    data_type: DataType,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> ColumnEncoder<T> for EmptyEncoder<T> {
+    type FinishedColumn = ArrayRef;
+
+    fn goodbytes(&self) -> usize {
+        0
+    }
+
+    fn append(&mut self, _val: &T) {
+        panic!("imports don't encode structured data")
+    }
+
+    fn append_null(&mut self) {
+        panic!("imports don't encode structured data")
+    }
+
+    fn finish(self) -> Self::FinishedColumn {
+        new_empty_array(&self.data_type)
+    }
+}
+
+/// Distinguishes the key and value schemas in [IMPORT_DATA_TYPES].
+trait EncodedCodec {
+    const NAME: &'static str;
+}
+
+impl EncodedCodec for EncodedKey {
+    const NAME: &'static str = "key";
+}
+
+impl EncodedCodec for EncodedVal {
+    const NAME: &'static str = "val";
+}
+
+impl Codec for EncodedKey {
+    type Storage = ();
+    type Schema = EncodedSchema<EncodedKey>;
+
+    fn codec_name() -> String {
+        SHARD_CODECS.lock().expect("lockable").0.clone()
+    }
+
+    fn encode<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        buf.put_slice(&self.0)
+    }
+
+    fn decode(buf: &[u8], _schema: &Self::Schema) -> Result<Self, String> {
+        Ok(Self(buf.to_vec()))
+    }
+
+    fn encode_schema(schema: &Self::Schema) -> Bytes {
+        schema.encoded.clone()
+    }
+
+    fn decode_schema(buf: &Bytes) -> Self::Schema {
+        EncodedSchema::decode(buf)
+    }
+}
+
+impl Codec for EncodedVal {
+    type Storage = ();
+    type Schema = EncodedSchema<EncodedVal>;
+
+    fn codec_name() -> String {
+        SHARD_CODECS.lock().expect("lockable").1.clone()
+    }
+
+    fn encode<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        buf.put_slice(&self.0)
+    }
+
+    fn decode(buf: &[u8], _schema: &Self::Schema) -> Result<Self, String> {
+        Ok(Self(buf.to_vec()))
+    }
+
+    fn encode_schema(schema: &Self::Schema) -> Bytes {
+        schema.encoded.clone()
+    }
+
+    fn decode_schema(buf: &Bytes) -> Self::Schema {
+        EncodedSchema::decode(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::all_ok;
+    use crate::ShardId;
+
+    use super::*;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn updates_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("part-00000.parquet");
+        let updates = vec![
+            ((b"k0".to_vec(), b"v0".to_vec()), 5, 1),
+            ((b"k1".to_vec(), vec![]), 5, -2),
+            ((vec![0, 159, 146, 150], b"v2".to_vec()), 5, 3),
+        ];
+        write_updates(&path, &updates).unwrap();
+        assert_eq!(read_updates(&path).unwrap(), updates);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn export_import_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_args = |shard_id: ShardId| StateArgs {
+            shard_id: shard_id.to_string(),
+            consensus_uri: format!("file://{}", dir.path().join("consensus").display())
+                .parse()
+                .unwrap(),
+            blob_uri: format!("file://{}", dir.path().join("blob").display())
+                .parse()
+                .unwrap(),
+        };
+        let (source, target) = (ShardId::new(), ShardId::new());
+
+        let data = vec![
+            (("k1".to_owned(), "v1".to_owned()), 1u64, 1i64),
+            (("k2".to_owned(), "v2".to_owned()), 2, 1),
+            (("k1".to_owned(), "v1".to_owned()), 3, -1),
+            (("k3".to_owned(), "v3".to_owned()), 4, 1),
+        ];
+        let client = make_client(&state_args(source)).await.unwrap();
+        let (mut write, _read) = client.expect_open::<String, String, u64, i64>(source).await;
+        write.expect_compare_and_append(&data[..3], 0, 4).await;
+        write.expect_compare_and_append(&data[3..], 4, 5).await;
+
+        // An as_of that the shard hasn't advanced past can't be exported.
+        let output_dir = dir.path().join("export");
+        let res = export(ExportArgs {
+            state: state_args(source),
+            as_of: 5,
+            output_dir: output_dir.clone(),
+            updates_per_file: 1,
+        })
+        .await;
+        assert!(res.is_err());
+
+        export(ExportArgs {
+            state: state_args(source),
+            as_of: 3,
+            output_dir: output_dir.clone(),
+            updates_per_file: 1,
+        })
+        .await
+        .unwrap();
+        import(ImportArgs {
+            state: state_args(target),
+            input_dir: output_dir,
+            commit: true,
+        })
+        .await
+        .unwrap();
+
+        // The export's reader is gone once it finishes.
+        write.machine.applier.fetch_and_update_state(None).await;
+        let collections = write.machine.applier.clone_collections();
+        assert_eq!(collections.leased_readers.len(), 1);
+
+        let (_, mut read) = client.expect_open::<String, String, u64, i64>(target).await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(3).await,
+            all_ok(&[(("k2".to_owned(), "v2".to_owned()), 3, 1)], 3)
+        );
+    }
+}
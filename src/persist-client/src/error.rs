@@ -79,6 +79,11 @@ pub enum InvalidUsage<T> {
     CodecMismatch(Box<CodecMismatch>),
     /// An invalid usage of [crate::batch::Batch::rewrite_ts].
     InvalidRewrite(String),
+    /// Attempted to fork a shard into one that has already been used.
+    ForkTargetInUse {
+        /// The shard that was to become the fork.
+        shard_id: ShardId,
+    },
 }
 
 impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
@@ -126,6 +131,12 @@ impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
             }
             InvalidUsage::CodecMismatch(err) => std::fmt::Display::fmt(err, f),
             InvalidUsage::InvalidRewrite(err) => write!(f, "invalid rewrite: {err}"),
+            InvalidUsage::ForkTargetInUse { shard_id } => {
+                write!(
+                    f,
+                    "cannot fork into {shard_id}, which has already been used"
+                )
+            }
         }
     }
 }
@@ -265,6 +265,18 @@ where
             })
     }
 
+    /// A point-in-time copy of the collections in the current state.
+    ///
+    /// Due to sharing state with other handles, successive reads to this fn or any other may
+    /// see a different version of state, even if this Applier has not explicitly fetched and
+    /// updated to the latest state.
+    pub fn clone_collections(&self) -> StateCollections<T> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_noncacheable, |state| {
+                state.collections.clone()
+            })
+    }
+
     /// Returns all rollups that are <= the given `seqno`.
     ///
     /// Due to sharing state with other handles, successive reads to this fn or any other may
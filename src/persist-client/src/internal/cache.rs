@@ -9,7 +9,11 @@
 
 //! In-process caches of [Blob].
 
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -17,6 +21,8 @@ use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use mz_persist::location::{Blob, BlobMetadata, ExternalError};
+use tokio::fs;
+use tracing::{info, warn};
 
 use crate::cfg::PersistConfig;
 use crate::internal::metrics::Metrics;
@@ -140,6 +146,277 @@ impl Blob for BlobMemCache {
     }
 }
 
+pub(crate) const BLOB_CACHE_DISK_ENABLED: Config<bool> = Config::new(
+    "persist_blob_cache_disk_enabled",
+    true,
+    "Whether to serve blob reads via the on-disk blob cache, in processes \
+    configured with one (Materialize).",
+);
+
+/// The length of the checksum that prefixes each file in a [BlobDiskCache].
+const CHECKSUM_LEN: usize = 4;
+
+/// A read-through cache of [Blob] on local disk.
+///
+/// Unlike [BlobMemCache], the contents of this cache outlive the process: on
+/// open, any blobs cached in the directory by a previous process are adopted,
+/// so that a restarted replica can rehydrate from local disk instead of
+/// fetching the same parts from blob storage again.
+///
+/// Each cached blob is stored in its own file, named by the hex encoding of
+/// its key and prefixed by a CRC32 checksum of its contents. The checksum is
+/// verified on every read, and a blob that fails verification is discarded
+/// and refetched. Like [BlobMemCache], this relies on blobs being write-once
+/// modify-never, so a cached value never needs to be invalidated.
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    cfg: Arc<ConfigSet>,
+    metrics: Arc<Metrics>,
+    dir: PathBuf,
+    /// The keys of the cached blobs, weighted by the size of their files.
+    cache: Mutex<lru::Lru<String, ()>>,
+    /// Used to give concurrent writes of the same blob distinct temp files.
+    next_tmp_id: AtomicU64,
+    blob: Arc<dyn Blob>,
+}
+
+impl BlobDiskCache {
+    /// Opens a cache of at most `limit_bytes` in `dir`, creating the directory
+    /// if necessary.
+    pub async fn open(
+        cfg: &PersistConfig,
+        dir: PathBuf,
+        limit_bytes: usize,
+        metrics: Arc<Metrics>,
+        blob: Arc<dyn Blob>,
+    ) -> Result<Arc<dyn Blob>, ExternalError> {
+        fs::create_dir_all(&dir).await?;
+
+        let eviction_metrics = Arc::clone(&metrics);
+        let eviction_dir = dir.clone();
+        let mut cache = lru::Lru::new(limit_bytes, move |key: String, (), _| {
+            eviction_metrics.blob_cache_disk.evictions.inc();
+            // This is a single metadata operation, so it's done inline (and
+            // under the lock) instead of in a task, which could race with the
+            // same blob being cached again.
+            if let Err(err) = std::fs::remove_file(Self::path(&eviction_dir, &key)) {
+                if err.kind() != ErrorKind::NotFound {
+                    eviction_metrics.blob_cache_disk.errors.inc();
+                    warn!(
+                        "failed to remove evicted blob {} from disk cache: {}",
+                        key, err
+                    );
+                }
+            }
+        });
+
+        // Adopt the blobs cached by a previous process, oldest first so that
+        // they're the first to be evicted.
+        let mut cached = Vec::new();
+        let mut entries = fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let key = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| hex::decode(name).ok())
+                .and_then(|key| String::from_utf8(key).ok());
+            let metadata = entry.metadata().await?;
+            match key {
+                Some(key) if metadata.is_file() => {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    cached.push((modified, key, metadata.len()));
+                }
+                // Temp files from writes that were interrupted, or anything
+                // else that isn't ours.
+                _ => {
+                    if let Err(err) = fs::remove_file(&path).await {
+                        warn!(
+                            "failed to remove {} from disk cache: {}",
+                            path.display(),
+                            err
+                        );
+                    }
+                }
+            }
+        }
+        cached.sort();
+        let adopted = cached.len();
+        for (_, key, len) in cached {
+            cache.insert(key, (), usize::cast_from(len));
+        }
+        info!(
+            "opened persist blob disk cache in {} with {} blobs",
+            dir.display(),
+            adopted
+        );
+
+        let blob = BlobDiskCache {
+            cfg: Arc::clone(&cfg.configs),
+            metrics,
+            dir,
+            cache: Mutex::new(cache),
+            next_tmp_id: AtomicU64::new(0),
+            blob,
+        };
+        blob.update_size_metrics(&blob.cache.lock().expect("lock poisoned"));
+        Ok(Arc::new(blob))
+    }
+
+    fn path(dir: &Path, key: &str) -> PathBuf {
+        dir.join(hex::encode(key))
+    }
+
+    fn update_size_metrics(&self, cache: &lru::Lru<String, ()>) {
+        self.metrics
+            .blob_cache_disk
+            .size_blobs
+            .set(u64::cast_from(cache.entry_count()));
+        self.metrics
+            .blob_cache_disk
+            .size_bytes
+            .set(u64::cast_from(cache.entry_weight()));
+    }
+
+    fn remove(&self, key: &str) {
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        cache.remove(key);
+        self.update_size_metrics(&cache);
+    }
+
+    /// Returns the cached value of `key`, or None if the file is missing or
+    /// fails checksum verification.
+    async fn read(&self, key: &str) -> Result<Option<Bytes>, std::io::Error> {
+        let buf = match fs::read(Self::path(&self.dir, key)).await {
+            Ok(buf) => Bytes::from(buf),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if buf.len() < CHECKSUM_LEN {
+            self.metrics.blob_cache_disk.corrupted_blobs.inc();
+            return Ok(None);
+        }
+        let (checksum, value) = buf.split_at(CHECKSUM_LEN);
+        let checksum = u32::from_le_bytes(checksum.try_into().expect("checksum len"));
+        if crc32fast::hash(value) != checksum {
+            self.metrics.blob_cache_disk.corrupted_blobs.inc();
+            return Ok(None);
+        }
+        Ok(Some(buf.slice(CHECKSUM_LEN..)))
+    }
+
+    /// Writes `value` to the cache as `key`.
+    async fn write(&self, key: &str, value: SegmentedBytes) -> Result<(), std::io::Error> {
+        let weight = CHECKSUM_LEN + value.len();
+        // If the weight of this single blob is greater than the capacity of
+        // the cache, it will push out everything in the cache and then
+        // immediately get evicted itself. So, skip adding it in that case.
+        if weight > self.cache.lock().expect("lock poisoned").capacity() {
+            return Ok(());
+        }
+
+        let mut buf = Vec::with_capacity(weight);
+        buf.extend_from_slice(&[0; CHECKSUM_LEN]);
+        for segment in value.into_segments() {
+            buf.extend_from_slice(segment.as_ref());
+        }
+        let checksum = crc32fast::hash(&buf[CHECKSUM_LEN..]);
+        buf[..CHECKSUM_LEN].copy_from_slice(&checksum.to_le_bytes());
+
+        // Write to a temp file and rename it into place, so that a crash
+        // never leaves a partially written blob under its final name.
+        let path = Self::path(&self.dir, key);
+        let tmp_id = self.next_tmp_id.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = path.with_extension(format!("{}.tmp", tmp_id));
+        if let Err(err) = fs::write(&tmp_path, buf).await {
+            let _ = fs::remove_file(&tmp_path).await;
+            return Err(err);
+        }
+        fs::rename(&tmp_path, &path).await?;
+
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        cache.insert(key.to_owned(), (), weight);
+        self.update_size_metrics(&cache);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Blob for BlobDiskCache {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        if !BLOB_CACHE_DISK_ENABLED.get(&self.cfg) {
+            return self.blob.get(key).await;
+        }
+
+        let cached = self.cache.lock().expect("lock poisoned").get(key).is_some();
+        if cached {
+            match self.read(key).await {
+                Ok(Some(value)) => {
+                    self.metrics.blob_cache_disk.hits_blobs.inc();
+                    self.metrics
+                        .blob_cache_disk
+                        .hits_bytes
+                        .inc_by(u64::cast_from(value.len()));
+                    return Ok(Some(SegmentedBytes::from(value)));
+                }
+                // Evicted out from under us, or corrupted. Either way, fall
+                // back to blob storage, which will also rewrite the file.
+                Ok(None) => self.remove(key),
+                Err(err) => {
+                    self.metrics.blob_cache_disk.errors.inc();
+                    warn!("failed to read blob {} from disk cache: {}", key, err);
+                    self.remove(key);
+                }
+            }
+        }
+
+        let res = self.blob.get(key).await?;
+        if let Some(value) = res.as_ref() {
+            // The cache is best-effort: failing to populate it (e.g. because
+            // the disk is full) doesn't fail the read.
+            if let Err(err) = self.write(key, value.clone()).await {
+                self.metrics.blob_cache_disk.errors.inc();
+                warn!("failed to write blob {} to disk cache: {}", key, err);
+            }
+        }
+        Ok(res)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        // Only reads populate the cache. Anything written by this process that
+        // it then reads back is likely to still be in the in-mem cache.
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let res = self.blob.delete(key).await;
+        self.remove(key);
+        // Deleted blobs are never read again, so a leftover file only wastes
+        // space until it's evicted.
+        match fs::remove_file(Self::path(&self.dir, key)).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => {
+                self.metrics.blob_cache_disk.errors.inc();
+                warn!("failed to remove blob {} from disk cache: {}", key, err);
+            }
+        }
+        res
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
 mod lru {
     use std::borrow::Borrow;
     use std::collections::BTreeMap;
@@ -379,12 +656,22 @@ mod lru {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use bytes::Bytes;
     use mz_ore::assert_none;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::location::Blob;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
     use proptest::arbitrary::any;
     use proptest::proptest;
     use proptest_derive::Arbitrary;
 
+    use crate::cfg::PersistConfig;
+    use crate::internal::metrics::Metrics;
+
     use super::lru::*;
+    use super::BlobDiskCache;
 
     #[derive(Debug, Arbitrary)]
     enum LruOp {
@@ -524,4 +811,72 @@ mod tests {
         assert_eq!(cache.entry_weight(), 2);
         assert_eq!(cache.keys(), &["j", "i"]);
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn blob_disk_cache() {
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let dir = tempfile::tempdir().expect("tempdir");
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        blob.set("a", Bytes::from_static(b"aaaa")).await.unwrap();
+        blob.set("b", Bytes::from_static(b"bbbb")).await.unwrap();
+        let get = |cache: &Arc<dyn Blob>, key: &'static str| {
+            let cache = Arc::clone(cache);
+            async move {
+                cache
+                    .get(key)
+                    .await
+                    .expect("get")
+                    .map(|x| x.into_contiguous())
+            }
+        };
+        let open = |limit_bytes| {
+            BlobDiskCache::open(
+                &cfg,
+                dir.path().to_owned(),
+                limit_bytes,
+                Arc::clone(&metrics),
+                Arc::clone(&blob),
+            )
+        };
+        let disk = &metrics.blob_cache_disk;
+
+        // A miss populates the cache and the next get is a hit.
+        let cache = open(100).await.expect("open");
+        assert_eq!(get(&cache, "a").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(disk.hits_blobs.get(), 0);
+        assert_eq!(get(&cache, "a").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(disk.hits_blobs.get(), 1);
+        assert_eq!(disk.size_blobs.get(), 1);
+        assert_none!(get(&cache, "missing").await);
+
+        // A corrupted file is discarded and refetched.
+        let path = BlobDiskCache::path(dir.path(), "a");
+        let mut contents = std::fs::read(&path).unwrap();
+        *contents.last_mut().unwrap() ^= 0xff;
+        std::fs::write(&path, contents).unwrap();
+        assert_eq!(get(&cache, "a").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(disk.corrupted_blobs.get(), 1);
+        assert_eq!(disk.hits_blobs.get(), 1);
+        assert_eq!(get(&cache, "a").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(disk.hits_blobs.get(), 2);
+        drop(cache);
+
+        // Cached blobs are adopted by a new cache in the same directory, and
+        // evicted once it's over capacity.
+        let cache = open(10).await.expect("open");
+        assert_eq!(disk.size_blobs.get(), 1);
+        assert_eq!(get(&cache, "a").await.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(disk.hits_blobs.get(), 3);
+        assert_eq!(get(&cache, "b").await.as_deref(), Some(&b"bbbb"[..]));
+        assert_eq!(disk.evictions.get(), 1);
+        assert_eq!(disk.size_blobs.get(), 1);
+        assert!(!path.exists());
+
+        // Deletes remove the cached file.
+        cache.delete("b").await.expect("delete");
+        assert!(!BlobDiskCache::path(dir.path(), "b").exists());
+        assert_eq!(disk.size_blobs.get(), 0);
+    }
 }
@@ -337,6 +337,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
             since,
             legacy_batches,
             hollow_batches,
@@ -360,6 +361,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
         );
         field_diffs_into_proto(ProtoStateField::Writers, writers, &mut writer);
         field_diffs_into_proto(ProtoStateField::Schemas, schemas, &mut writer);
+        field_diffs_into_proto(ProtoStateField::Forks, forks, &mut writer);
         field_diffs_into_proto(ProtoStateField::Since, since, &mut writer);
         field_diffs_into_proto(ProtoStateField::LegacyBatches, legacy_batches, &mut writer);
         field_diffs_into_proto(ProtoStateField::HollowBatches, hollow_batches, &mut writer);
@@ -474,6 +476,12 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
                             |v| v.into_rust(),
                         )?
                     }
+                    ProtoStateField::Forks => field_diff_into_rust::<String, (), _, _, _, _>(
+                        diff,
+                        &mut state_diff.forks,
+                        |k| k.into_rust(),
+                        |()| Ok(()),
+                    )?,
                     ProtoStateField::Since => {
                         field_diff_into_rust::<(), ProtoU64Antichain, _, _, _, _>(
                             diff,
@@ -887,6 +895,14 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
                 .iter()
                 .map(|(id, schema)| (id.into_proto(), schema.into_proto()))
                 .collect(),
+            forks: self
+                .state
+                .state
+                .collections
+                .forks
+                .keys()
+                .map(|shard_id| shard_id.into_proto())
+                .collect(),
             trace: Some(self.state.state.collections.trace.into_proto()),
             diffs: self.diffs.as_ref().map(|x| x.into_proto()),
         }
@@ -936,6 +952,10 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
         for (id, x) in x.schemas {
             schemas.insert(id.into_rust()?, x.into_rust()?);
         }
+        let mut forks = BTreeMap::new();
+        for shard_id in x.forks {
+            forks.insert(shard_id.into_rust()?, ());
+        }
         let collections = StateCollections {
             rollups,
             last_gc_req: x.last_gc_req.into_rust()?,
@@ -943,6 +963,7 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
             trace: x.trace.into_rust_if_some("trace")?,
         };
         let state = State {
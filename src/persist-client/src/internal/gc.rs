@@ -20,7 +20,7 @@ use differential_dataflow::lattice::Lattice;
 use futures_util::stream::FuturesUnordered;
 use futures_util::StreamExt;
 use prometheus::Counter;
-use timely::progress::Timestamp;
+use timely::progress::{Antichain, Timestamp};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::{mpsc, oneshot, Semaphore};
 use tracing::{debug, debug_span, error, warn, Instrument, Span};
@@ -36,7 +36,7 @@ use crate::internal::machine::{retry_external, Machine};
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{GcStepTimings, RetryMetrics};
 use crate::internal::paths::{BlobKey, PartialBlobKey, PartialRollupKey};
-use crate::internal::state::HollowBlobRef;
+use crate::internal::state::{BatchPart, HollowBlobRef, RunPart, State};
 use crate::internal::state_versions::{InspectDiff, StateVersionsIter};
 use crate::ShardId;
 
@@ -317,12 +317,26 @@ where
         // In short, while this step is not incremental, it does not need
         // to be for GC to efficiently resume. And in fact, making it
         // incremental could be quite expensive (e.g. more CaS operations).
-        let (removed_rollups, maintenance) =
+        let (removed_rollups, mut maintenance) =
             machine.remove_rollups(rollups_to_remove_from_state).await;
         report_step_timing(&machine.applier.metrics.gc.steps.remove_rollups_from_state);
         debug!("CaS removed rollups from state: {:?}", removed_rollups);
         gc_results.rollups_removed_from_state = removed_rollups;
 
+        // Similarly, forks that no longer share parts with this shard are only
+        // forgotten once we're done deciding which parts to delete.
+        if !gc_results.forks_unregistered.is_empty() {
+            maintenance.merge(
+                machine
+                    .unregister_forks(&gc_results.forks_unregistered)
+                    .await,
+            );
+            debug!(
+                "CaS unregistered forks from state: {:?}",
+                gc_results.forks_unregistered
+            );
+        }
+
         // Everything here and below is not strictly needed for GC to complete,
         // but it's a good opportunity, while we have all live states in hand,
         // to run some metrics and assertions.
@@ -485,6 +499,21 @@ where
                 }
             });
 
+            // Parts shared with other shards are only deleted once none of
+            // them refer to the parts anymore.
+            let unused_forks =
+                Self::retain_shared_parts(states.state(), machine, &mut batch_parts_to_delete)
+                    .await;
+            gc_results.forks_unregistered.extend(unused_forks);
+            timer(
+                &machine
+                    .applier
+                    .metrics
+                    .gc
+                    .steps
+                    .find_deletable_blobs_seconds,
+            );
+
             gc_results.truncated_consensus_to.push(truncate_lt);
             gc_results.batch_parts_deleted_from_blob += batch_parts_to_delete.len();
             gc_results.rollups_deleted_from_blob += rollups_to_delete.len();
@@ -541,6 +570,117 @@ where
         timer(&metrics.find_deletable_blobs_seconds);
     }
 
+    /// Removes from `batch_parts` every part that may still be referenced by
+    /// another shard.
+    ///
+    /// A part is shared by the shard that owns its blob and by all the shards
+    /// that were forked from that shard (see [crate::PersistClient::fork_shard]),
+    /// so it's only deleted once none of them refers to it in any live version
+    /// of its state. A fork that hasn't been given its batches yet may come to
+    /// refer to any part of its source, so while one exists, none of the parts
+    /// it might share are deleted.
+    ///
+    /// `state` must be at least as recent as the state that dropped the parts.
+    /// If two shards drop the same part concurrently, each may see the other
+    /// still referring to it, in which case the part is leaked.
+    ///
+    /// Returns the forks of this shard that have been given their batches but
+    /// no longer refer to any of its parts, e.g. because they've compacted them
+    /// away or been finalized. They can't come to refer to its parts again, so
+    /// they no longer need to be registered.
+    async fn retain_shared_parts(
+        state: &State<T>,
+        machine: &Machine<K, V, T, D>,
+        batch_parts: &mut PartDeletes<T>,
+    ) -> BTreeSet<ShardId> {
+        let shard_id = state.shard_id;
+        let mut owners: BTreeSet<_> = batch_parts
+            .keys()
+            .map(|key| key.owner().unwrap_or(shard_id))
+            .collect();
+        // Visit this shard's forks even if it has no parts to delete, so that
+        // the ones it no longer shares parts with are found.
+        if !state.collections.forks.is_empty() {
+            owners.insert(shard_id);
+        }
+
+        let mut referenced = BTreeSet::new();
+        let mut retained_owners = BTreeSet::new();
+        let mut unused_forks = BTreeSet::new();
+        for owner in owners {
+            // The owner itself is visited first, to learn about its forks.
+            let mut sharers = vec![owner];
+            let mut visited = BTreeSet::new();
+            if owner == shard_id {
+                sharers.extend(state.collections.forks.keys());
+            }
+            while let Some(sharer) = sharers.pop() {
+                if !visited.insert(sharer) {
+                    continue;
+                }
+                if sharer == shard_id {
+                    // We're only deleting parts that this shard dropped.
+                    continue;
+                }
+                let states = machine
+                    .applier
+                    .state_versions
+                    .fetch_all_live_states::<T>(sharer)
+                    .await
+                    .map(|states| states.check_ts_codec());
+                let mut states = match states {
+                    Some(Ok(states)) => states,
+                    Some(Err(err)) => {
+                        error!("shard {} shares parts with {}: {}", sharer, shard_id, err);
+                        retained_owners.insert(owner);
+                        break;
+                    }
+                    None => {
+                        retained_owners.insert(owner);
+                        break;
+                    }
+                };
+                let mut refers_to_owner = false;
+                while let Some(_) = states.next(|diff| {
+                    diff.referenced_blobs().for_each(|blob| match blob {
+                        HollowBlobRef::Batch(batch) => {
+                            for part in &batch.parts {
+                                let mut part = part.clone();
+                                part.qualify(&sharer);
+                                let key = match part {
+                                    RunPart::Single(BatchPart::Hollow(x)) => x.key,
+                                    RunPart::Single(BatchPart::Inline { .. }) => continue,
+                                    RunPart::Many(x) => x.key,
+                                };
+                                refers_to_owner |= key.owner() == Some(owner);
+                                referenced.insert(key);
+                            }
+                        }
+                        HollowBlobRef::Rollup(_) => {}
+                    })
+                }) {}
+                let sharer_state = states.state();
+                if sharer_state.upper() == &Antichain::from_elem(T::minimum()) {
+                    // A fork that hasn't been given its batches yet.
+                    retained_owners.insert(owner);
+                    break;
+                }
+                if owner == shard_id && !refers_to_owner {
+                    unused_forks.insert(sharer);
+                }
+                if sharer == owner {
+                    sharers.extend(sharer_state.collections.forks.keys());
+                }
+            }
+        }
+
+        batch_parts.retain(|key| {
+            let owner = key.owner().unwrap_or(shard_id);
+            !retained_owners.contains(&owner) && !referenced.contains(&key.qualify(&shard_id))
+        });
+        unused_forks
+    }
+
     /// Deletes `batch_parts` and `rollups` from Blob.
     /// Truncates Consensus to `truncate_lt`.
     async fn delete_and_truncate<F>(
@@ -635,6 +775,7 @@ pub(crate) struct GcResults {
     pub(crate) rollups_deleted_from_blob: usize,
     pub(crate) truncated_consensus_to: Vec<SeqNo>,
     pub(crate) rollups_removed_from_state: Vec<SeqNo>,
+    pub(crate) forks_unregistered: BTreeSet<ShardId>,
 }
 
 #[derive(Debug)]
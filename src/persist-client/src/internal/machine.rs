@@ -9,6 +9,7 @@
 
 //! Implementation of the persist state machine.
 
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::future::Future;
 use std::ops::ControlFlow::{self, Continue};
@@ -264,6 +265,45 @@ where
         (state, maintenance)
     }
 
+    /// See [StateCollections::register_fork].
+    pub async fn register_fork(&self, fork: ShardId) -> (bool, RoutineMaintenance) {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, registered, maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.register_fork, |_seqno, _cfg, state| {
+                state.register_fork(fork)
+            })
+            .await;
+        (registered, maintenance)
+    }
+
+    /// See [StateCollections::unregister_forks].
+    pub async fn unregister_forks(&self, forks: &BTreeSet<ShardId>) -> RoutineMaintenance {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, (), maintenance) = self
+            .apply_unbatched_idempotent_cmd(
+                &metrics.cmds.unregister_forks,
+                |_seqno, _cfg, state| state.unregister_forks(forks),
+            )
+            .await;
+        maintenance
+    }
+
+    /// See [StateCollections::fork].
+    pub async fn fork(
+        &self,
+        source_id: &ShardId,
+        source: &StateCollections<T>,
+        upper_bound: &Antichain<T>,
+    ) -> (bool, RoutineMaintenance) {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, forked, maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.fork, |_seqno, _cfg, state| {
+                state.fork(source_id, source, upper_bound)
+            })
+            .await;
+        (forked, maintenance)
+    }
+
     pub async fn spine_exert(&self, fuel: usize) -> (Vec<CompactReq<T>>, RoutineMaintenance) {
         // Performance special case for no-ops, to avoid the State clones.
         if fuel == 0 || self.applier.all_batches().len() < 2 {
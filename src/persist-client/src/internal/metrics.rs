@@ -94,6 +94,8 @@ pub struct Metrics {
     pub consolidation: ConsolidationMetrics,
     /// Metrics for blob caching.
     pub blob_cache_mem: BlobMemCache,
+    /// Metrics for on-disk blob caching.
+    pub blob_cache_disk: BlobDiskCache,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
     /// Metrics for columnar data encoding and decoding.
@@ -166,6 +168,7 @@ impl Metrics {
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
             blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_disk: BlobDiskCache::new(registry),
             tasks: TasksMetrics::new(registry),
             columnar,
             schema: SchemaMetrics::new(registry),
@@ -428,6 +431,9 @@ impl MetricsVecs {
             become_tombstone: self.cmd_metrics("become_tombstone"),
             compare_and_evolve_schema: self.cmd_metrics("compare_and_evolve_schema"),
             spine_exert: self.cmd_metrics("spine_exert"),
+            register_fork: self.cmd_metrics("register_fork"),
+            unregister_forks: self.cmd_metrics("unregister_forks"),
+            fork: self.cmd_metrics("fork"),
             fetch_upper_count: registry.register(metric!(
                 name: "mz_persist_cmd_fetch_upper_count",
                 help: "count of fetch_upper calls",
@@ -637,6 +643,9 @@ pub struct CmdsMetrics {
     pub(crate) become_tombstone: CmdMetrics,
     pub(crate) compare_and_evolve_schema: CmdMetrics,
     pub(crate) spine_exert: CmdMetrics,
+    pub(crate) register_fork: CmdMetrics,
+    pub(crate) unregister_forks: CmdMetrics,
+    pub(crate) fork: CmdMetrics,
     pub(crate) fetch_upper_count: IntCounter,
 }
 
@@ -2479,6 +2488,57 @@ impl BlobMemCache {
     }
 }
 
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    pub(crate) size_blobs: UIntGauge,
+    pub(crate) size_bytes: UIntGauge,
+    pub(crate) hits_blobs: IntCounter,
+    pub(crate) hits_bytes: IntCounter,
+    pub(crate) evictions: IntCounter,
+    pub(crate) corrupted_blobs: IntCounter,
+    pub(crate) errors: IntCounter,
+}
+
+impl BlobDiskCache {
+    fn new(registry: &MetricsRegistry) -> Self {
+        BlobDiskCache {
+            size_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_blobs",
+                help: "count of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            size_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_bytes",
+                help: "total size of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_blobs",
+                help: "count of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_bytes",
+                help: "total size of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            evictions: registry.register(metric!(
+                name: "mz_persist_blob_cache_evictions",
+                help: "count of capacity-based cache evictions",
+                const_labels: {"cache" => "disk"},
+            )),
+            corrupted_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_disk_corrupted_blobs",
+                help: "count of cached blobs discarded because they failed checksum verification",
+            )),
+            errors: registry.register(metric!(
+                name: "mz_persist_blob_cache_disk_errors",
+                help: "count of local disk errors while reading or writing the blob cache",
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SemaphoreMetrics {
     cfg: PersistConfig,
@@ -116,6 +116,11 @@ impl Display for WriterKey {
 /// Used to reduce the bytes needed to refer to a blob key in memory and in
 /// persistent state, all access to blobs are always within the context of an
 /// individual shard.
+///
+/// The exception is a part that a shard shares with the shard it was forked
+/// from: its key is additionally prefixed with the [ShardId] of the shard that
+/// owns the blob, and completes to the same [BlobKey] regardless of which shard
+/// it is completed with.
 #[derive(Arbitrary, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PartialBatchKey(pub(crate) String);
 
@@ -135,11 +140,38 @@ impl PartialBatchKey {
     }
 
     pub fn split(&self) -> Option<(WriterKey, PartId)> {
-        split_batch_key(&self.0).ok()
+        split_batch_key(self.owned_key()).ok()
     }
 
     pub fn complete(&self, shard_id: &ShardId) -> BlobKey {
-        BlobKey(format!("{}/{}", shard_id, self))
+        match self.owner() {
+            Some(_) => BlobKey(self.0.clone()),
+            None => BlobKey(format!("{}/{}", shard_id, self)),
+        }
+    }
+
+    /// Returns the shard whose blobs this part was written to, if that's not
+    /// the shard that refers to it.
+    pub fn owner(&self) -> Option<ShardId> {
+        let (shard_id, _) = self.0.split_once('/')?;
+        ShardId::from_str(shard_id).ok()
+    }
+
+    /// Returns a key for this part that refers to the same blob when used by
+    /// any shard, given that `shard_id` is the shard using it now.
+    pub fn qualify(&self, shard_id: &ShardId) -> Self {
+        match self.owner() {
+            Some(_) => self.clone(),
+            None => PartialBatchKey(format!("{}/{}", shard_id, self)),
+        }
+    }
+
+    /// Returns this key with the owning shard, if any, stripped.
+    fn owned_key(&self) -> &str {
+        match self.0.split_once('/') {
+            Some((shard_id, key)) if ShardId::from_str(shard_id).is_ok() => key,
+            _ => &self.0,
+        }
     }
 }
 
@@ -330,6 +362,19 @@ mod tests {
             partial_key.complete(&shard_id),
             BlobKey(format!("{}/{}/{}", shard_id, writer_id, part_id))
         );
+
+        // A key qualified with its owning shard completes to the owner's blob
+        // no matter which shard completes it.
+        let fork_id = ShardId::new();
+        let qualified_key = partial_key.qualify(&shard_id);
+        assert_eq!(qualified_key.owner(), Some(shard_id));
+        assert_eq!(qualified_key.qualify(&fork_id), qualified_key);
+        assert_eq!(
+            qualified_key.complete(&fork_id),
+            partial_key.complete(&shard_id)
+        );
+        assert_eq!(qualified_key.split(), partial_key.split());
+        assert_eq!(partial_key.owner(), None);
     }
 
     #[mz_ore::test]
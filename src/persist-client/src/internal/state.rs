@@ -11,7 +11,7 @@ use anyhow::ensure;
 use async_stream::{stream, try_stream};
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 use std::ops::ControlFlow::{self, Break, Continue};
@@ -420,9 +420,14 @@ impl<T: Timestamp + Codec64> HollowRunRef<T> {
         .await?;
         let proto_runs: ProtoHollowRun =
             prost::Message::decode(&mut bytes).expect("illegal state: invalid proto bytes");
-        let runs = proto_runs
+        let mut runs: HollowRun<T> = proto_runs
             .into_rust()
             .expect("illegal state: invalid encoded runs proto");
+        // A run shared by the shard it was forked from refers to that shard's
+        // blobs, so the parts in it do too.
+        if let Some(owner) = self.key.owner() {
+            runs.parts.iter_mut().for_each(|part| part.qualify(&owner));
+        }
         Some(runs)
     }
 }
@@ -549,6 +554,16 @@ impl<T> RunPart<T> {
             Self::Many(_) => None,
         }
     }
+
+    /// Qualifies the key of this part with `shard_id`, see
+    /// [PartialBatchKey::qualify].
+    pub(crate) fn qualify(&mut self, shard_id: &ShardId) {
+        match self {
+            Self::Single(BatchPart::Hollow(x)) => x.key = x.key.qualify(shard_id),
+            Self::Single(BatchPart::Inline { .. }) => {}
+            Self::Many(x) => x.key = x.key.qualify(shard_id),
+        }
+    }
 }
 
 /// A blob was missing!
@@ -923,6 +938,14 @@ impl<T> HollowBatch<T> {
     pub fn encoded_size_bytes(&self) -> usize {
         self.parts.iter().map(|p| p.encoded_size_bytes()).sum()
     }
+
+    /// Qualifies the keys of all parts in the batch with `shard_id`, so that
+    /// the batch can be shared with a shard forked from it.
+    pub(crate) fn qualify(&mut self, shard_id: &ShardId) {
+        self.parts
+            .iter_mut()
+            .for_each(|part| part.qualify(shard_id));
+    }
 }
 
 // See the comment on [Batch::rewrite_ts] for why this is TotalOrder.
@@ -1097,6 +1120,14 @@ pub struct StateCollections<T> {
     pub(crate) writers: BTreeMap<WriterId, WriterState<T>>,
     pub(crate) schemas: BTreeMap<SchemaId, EncodedSchemas>,
 
+    // The shards forked from this one, which may refer to the blobs of any
+    // batch part in this shard's trace.
+    //
+    // - Invariant: A fork is registered here before any of this shard's parts
+    //   are copied into it, and only unregistered once it no longer refers to
+    //   any of them or failed to become a fork.
+    pub(crate) forks: BTreeMap<ShardId, ()>,
+
     // - Invariant: `trace.since == meet(all reader.since)`
     // - Invariant: `trace.since` doesn't regress across state versions.
     // - Invariant: `trace.upper` doesn't regress across state versions.
@@ -1424,6 +1455,95 @@ where
         Continue(CaESchema::Ok(id))
     }
 
+    /// Records that `fork` may refer to the blobs of this shard's batch parts,
+    /// so that they're not deleted while it does.
+    ///
+    /// Unlike most transitions, this is also allowed on a tombstone: its forks
+    /// may still refer to its parts after it has been finalized. Returns
+    /// whether `fork` wasn't registered already.
+    pub fn register_fork(&mut self, fork: ShardId) -> ControlFlow<NoOpStateTransition<bool>, bool> {
+        if self.forks.contains_key(&fork) {
+            return Break(NoOpStateTransition(false));
+        }
+        self.forks.insert(fork, ());
+        Continue(true)
+    }
+
+    /// Forgets about `forks`, which must neither refer to the blobs of this
+    /// shard's batch parts anymore nor come to refer to them, see
+    /// [Self::register_fork].
+    ///
+    /// Like registration, this is also allowed on a tombstone.
+    pub fn unregister_forks(
+        &mut self,
+        forks: &BTreeSet<ShardId>,
+    ) -> ControlFlow<NoOpStateTransition<()>, ()> {
+        if !forks.iter().any(|fork| self.forks.contains_key(fork)) {
+            return Break(NoOpStateTransition(()));
+        }
+        self.forks.retain(|fork, ()| !forks.contains(fork));
+        Continue(())
+    }
+
+    /// Makes this shard a fork of `source`: a shard with the same contents,
+    /// since, and schemas, whose trace refers to the blobs of `source`'s batch
+    /// parts instead of copying them.
+    ///
+    /// Only the longest prefix of `source`'s batches whose uppers are not
+    /// beyond `upper_bound` is forked, so this shard's upper may be earlier
+    /// than `source`'s. If `source`'s since is beyond `upper_bound`, nothing
+    /// is.
+    ///
+    /// This shard must already have been registered (see
+    /// [Self::register_fork]) with `source` and with every other shard that
+    /// owns a part in its trace. Returns false if this shard has already been
+    /// written to, read from, or has incompatible schemas.
+    pub fn fork(
+        &mut self,
+        source_id: &ShardId,
+        source: &StateCollections<T>,
+        upper_bound: &Antichain<T>,
+    ) -> ControlFlow<NoOpStateTransition<bool>, bool> {
+        let mut trace = Trace::default();
+        if PartialOrder::less_equal(source.trace.since(), upper_bound) {
+            for batch in source.trace.batches() {
+                if !PartialOrder::less_equal(batch.desc.upper(), upper_bound) {
+                    break;
+                }
+                // Batches with empty time ranges don't move the upper, so
+                // leaving them out doesn't leave a gap.
+                if batch.desc.lower() == batch.desc.upper() {
+                    continue;
+                }
+                let mut batch = batch.clone();
+                batch.qualify(source_id);
+                trace.push_batch_no_merge_reqs(batch);
+            }
+            trace.downgrade_since(source.trace.since());
+        }
+
+        // If a previous attempt already made this shard the fork, there's
+        // nothing to do.
+        if self.trace.since() == trace.since()
+            && self.trace.upper() == trace.upper()
+            && self.trace.batches().eq(trace.batches())
+            && self.schemas == source.schemas
+        {
+            return Break(NoOpStateTransition(true));
+        }
+
+        let unused = self.trace.upper() == &Antichain::from_elem(T::minimum())
+            && self.leased_readers.is_empty()
+            && self.critical_readers.is_empty()
+            && (self.schemas.is_empty() || self.schemas == source.schemas);
+        if !unused {
+            return Break(NoOpStateTransition(false));
+        }
+        self.schemas.clone_from(&source.schemas);
+        self.trace = trace;
+        Continue(true)
+    }
+
     pub fn compare_and_append(
         &mut self,
         batch: &HollowBatch<T>,
@@ -2118,6 +2238,7 @@ where
                 critical_readers: BTreeMap::new(),
                 writers: BTreeMap::new(),
                 schemas: BTreeMap::new(),
+                forks: BTreeMap::new(),
                 trace: Trace::default(),
             },
         };
@@ -2447,10 +2568,11 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
                     critical_readers,
                     writers,
                     schemas,
+                    forks,
                     trace,
                 },
         } = self;
-        let mut s = s.serialize_struct("State", 13)?;
+        let mut s = s.serialize_struct("State", 14)?;
         let () = s.serialize_field("applier_version", &applier_version.to_string())?;
         let () = s.serialize_field("shard_id", shard_id)?;
         let () = s.serialize_field("seqno", seqno)?;
@@ -2462,6 +2584,7 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
         let () = s.serialize_field("critical_readers", critical_readers)?;
         let () = s.serialize_field("writers", writers)?;
         let () = s.serialize_field("schemas", schemas)?;
+        let () = s.serialize_field("forks", &forks.keys().collect::<Vec<_>>())?;
         let () = s.serialize_field("since", &trace.since().elements())?;
         let () = s.serialize_field("upper", &trace.upper().elements())?;
         let trace = trace.flatten();
@@ -2770,6 +2893,7 @@ pub(crate) mod tests {
                     writers,
                     trace,
                     schemas,
+                    forks: BTreeMap::new(),
                 },
             },
         )
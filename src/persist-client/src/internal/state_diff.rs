@@ -80,6 +80,7 @@ pub struct StateDiff<T> {
     pub(crate) critical_readers: Vec<StateFieldDiff<CriticalReaderId, CriticalReaderState<T>>>,
     pub(crate) writers: Vec<StateFieldDiff<WriterId, WriterState<T>>>,
     pub(crate) schemas: Vec<StateFieldDiff<SchemaId, EncodedSchemas>>,
+    pub(crate) forks: Vec<StateFieldDiff<ShardId, ()>>,
     pub(crate) since: Vec<StateFieldDiff<(), Antichain<T>>>,
     pub(crate) legacy_batches: Vec<StateFieldDiff<HollowBatch<T>, ()>>,
     pub(crate) hollow_batches: Vec<StateFieldDiff<SpineId, Arc<HollowBatch<T>>>>,
@@ -108,6 +109,7 @@ impl<T: Timestamp + Codec64> StateDiff<T> {
             critical_readers: Vec::default(),
             writers: Vec::default(),
             schemas: Vec::default(),
+            forks: Vec::default(),
             since: Vec::default(),
             legacy_batches: Vec::default(),
             hollow_batches: Vec::default(),
@@ -152,6 +154,7 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     critical_readers: from_critical_readers,
                     writers: from_writers,
                     schemas: from_schemas,
+                    forks: from_forks,
                     trace: from_trace,
                 },
         } = from;
@@ -169,6 +172,7 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     critical_readers: to_critical_readers,
                     writers: to_writers,
                     schemas: to_schemas,
+                    forks: to_forks,
                     trace: to_trace,
                 },
         } = to;
@@ -197,6 +201,7 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
         );
         diff_field_sorted_iter(from_writers.iter(), to_writers, &mut diffs.writers);
         diff_field_sorted_iter(from_schemas.iter(), to_schemas, &mut diffs.schemas);
+        diff_field_sorted_iter(from_forks.iter(), to_forks, &mut diffs.forks);
         diff_field_single(from_trace.since(), to_trace.since(), &mut diffs.since);
 
         let from_flat = from_trace.flatten();
@@ -384,6 +389,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             critical_readers: diff_critical_readers,
             writers: diff_writers,
             schemas: diff_schemas,
+            forks: diff_forks,
             since: diff_since,
             legacy_batches: diff_legacy_batches,
             hollow_batches: diff_hollow_batches,
@@ -420,6 +426,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             critical_readers,
             writers,
             schemas,
+            forks,
             trace,
         } = &mut self.collections;
 
@@ -429,6 +436,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
         apply_diffs_map("critical_readers", diff_critical_readers, critical_readers)?;
         apply_diffs_map("writers", diff_writers, writers)?;
         apply_diffs_map("schemas", diff_schemas, schemas)?;
+        apply_diffs_map("forks", diff_forks, forks)?;
 
         let structure_unchanged = diff_hollow_batches.is_empty()
             && diff_spine_batches.is_empty()
@@ -15,6 +15,7 @@
 // https://github.com/rust-lang/rust/issues/87417 pans out.
 #![allow(ungated_async_fn_track_caller)]
 
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -27,7 +28,7 @@ use mz_ore::{instrument, soft_assert_or_log};
 use mz_persist::location::{Blob, Consensus, ExternalError};
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64, Opaque};
-use timely::progress::Timestamp;
+use timely::progress::{Antichain, Timestamp};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::{PersistClientCache, StateCache};
@@ -39,6 +40,7 @@ use crate::internal::compact::Compactor;
 use crate::internal::encoding::{parse_id, Schemas};
 use crate::internal::gc::GarbageCollector;
 use crate::internal::machine::{retry_external, Machine};
+use crate::internal::state::{BatchPart, RunPart};
 use crate::internal::state_versions::StateVersions;
 use crate::metrics::Metrics;
 use crate::read::{LeasedReaderId, ReadHandle, READER_LEASE_DURATION};
@@ -56,6 +58,7 @@ pub mod cli {
     pub mod args;
     pub mod bench;
     pub mod inspect;
+    pub mod transfer;
 }
 pub mod critical;
 pub mod error;
@@ -679,6 +682,132 @@ impl PersistClient {
         Ok(())
     }
 
+    /// Makes `target` a fork of `source`: a shard with the same contents and
+    /// since that `source` has now, but which refers to the blobs of
+    /// `source`'s batch parts instead of copying them. Afterward, the two
+    /// shards can be written, compacted, and finalized independently.
+    ///
+    /// The upper of `target` is not advanced beyond `upper_bound`: if
+    /// `source`'s upper is, `target` gets the contents of `source` only up to
+    /// the end of the last batch that isn't, and possibly none at all. Returns
+    /// the upper of `target`.
+    ///
+    /// `target` must not have been written to or read from. Forking the same
+    /// `source` into the same `target` again is a no-op.
+    #[instrument(level = "debug", fields(source = %source, target = %target))]
+    pub async fn fork_shard<K, V, T, D>(
+        &self,
+        source: ShardId,
+        target: ShardId,
+        upper_bound: &Antichain<T>,
+        diagnostics: Diagnostics,
+    ) -> Result<Antichain<T>, InvalidUsage<T>>
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64,
+        D: Semigroup + Codec64 + Send + Sync,
+    {
+        let source_machine = self
+            .make_machine::<K, V, T, D>(source, diagnostics.clone())
+            .await?;
+        let target_machine = self
+            .make_machine::<K, V, T, D>(target, diagnostics.clone())
+            .await?;
+
+        // Fail before registering the fork anywhere if `target` has obviously
+        // been used, unless this is a retry of a fork that got that far.
+        let registered = source_machine
+            .applier
+            .clone_collections()
+            .forks
+            .contains_key(&target);
+        if !registered && target_machine.applier.clone_upper() != Antichain::from_elem(T::minimum())
+        {
+            return Err(InvalidUsage::ForkTargetInUse { shard_id: target });
+        }
+
+        // Register the fork with the owner of every part it's about to refer
+        // to, so that GC keeps those parts around for it. The shards that
+        // `source` was itself forked from only ever change when `source` is
+        // created, so looking them up after `source` has registered the fork
+        // is enough to find them all.
+        //
+        // If the fork doesn't happen, the registrations made here are undone,
+        // so that they don't keep GC from deleting the parts forever.
+        let mut registered_with = Vec::new();
+        let (registered, maintenance) = source_machine.register_fork(target).await;
+        let gc = GarbageCollector::new(source_machine.clone(), Arc::clone(&self.isolated_runtime));
+        let () = maintenance.perform(&source_machine, &gc).await;
+        if registered {
+            registered_with.push(source_machine.clone());
+        }
+        let owners: BTreeSet<_> = source_machine
+            .applier
+            .clone_collections()
+            .trace
+            .batches()
+            .flat_map(|batch| batch.parts.iter())
+            .filter_map(|part| match part {
+                RunPart::Single(BatchPart::Hollow(x)) => x.key.owner(),
+                RunPart::Single(BatchPart::Inline { .. }) => None,
+                RunPart::Many(x) => x.key.owner(),
+            })
+            .collect();
+        for owner in owners {
+            let owner_machine = match self
+                .make_machine::<K, V, T, D>(owner, diagnostics.clone())
+                .await
+            {
+                Ok(machine) => machine,
+                Err(err) => {
+                    self.unregister_fork(&registered_with, target).await;
+                    return Err(err);
+                }
+            };
+            let (registered, maintenance) = owner_machine.register_fork(target).await;
+            let gc =
+                GarbageCollector::new(owner_machine.clone(), Arc::clone(&self.isolated_runtime));
+            let () = maintenance.perform(&owner_machine, &gc).await;
+            if registered {
+                registered_with.push(owner_machine);
+            }
+        }
+
+        // Only parts that are still in `source` once every owner knows about
+        // the fork are guaranteed to stay around, so copy a state that's at
+        // least that recent.
+        source_machine.applier.fetch_and_update_state(None).await;
+        let collections = source_machine.applier.clone_collections();
+        let (forked, maintenance) = target_machine
+            .fork(&source, &collections, upper_bound)
+            .await;
+        let gc = GarbageCollector::new(target_machine.clone(), Arc::clone(&self.isolated_runtime));
+        let () = maintenance.perform(&target_machine, &gc).await;
+        if !forked {
+            self.unregister_fork(&registered_with, target).await;
+            return Err(InvalidUsage::ForkTargetInUse { shard_id: target });
+        }
+        Ok(target_machine.applier.clone_upper())
+    }
+
+    /// Undoes the registration of `fork` with each of `machines`, after it
+    /// turned out not to refer to any of their parts.
+    async fn unregister_fork<K, V, T, D>(&self, machines: &[Machine<K, V, T, D>], fork: ShardId)
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64,
+        D: Semigroup + Codec64 + Send + Sync,
+    {
+        let forks = BTreeSet::from([fork]);
+        for machine in machines {
+            let maintenance = machine.unregister_forks(&forks).await;
+            let gc = GarbageCollector::new(machine.clone(), Arc::clone(&self.isolated_runtime));
+            let () = maintenance.perform(machine, &gc).await;
+        }
+    }
+
     /// Returns the internal state of the shard for debugging and QA.
     ///
     /// We'll be thoughtful about making unnecessary changes, but the **output
@@ -763,10 +892,11 @@ mod tests {
     use timely::order::PartialOrder;
     use timely::progress::Antichain;
 
-    use crate::batch::BLOB_TARGET_SIZE;
+    use crate::batch::{BLOB_TARGET_SIZE, INLINE_WRITES_SINGLE_MAX_BYTES};
     use crate::cache::PersistClientCache;
     use crate::error::{CodecConcreteType, CodecMismatch, UpperMismatch};
-    use crate::internal::paths::BlobKey;
+    use crate::internal::gc::GcReq;
+    use crate::internal::paths::{BlobKey, PartialBlobKey};
     use crate::read::ListenEvent;
 
     use super::*;
@@ -1990,6 +2120,174 @@ mod tests {
         assert!(is_finalized, "shard must still be finalized");
     }
 
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn fork_shard(dyncfgs: ConfigUpdates) {
+        const EMPTY: &[((String, String), u64, i64)] = &[];
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+        ];
+        let client = &new_test_client(&dyncfgs).await;
+        client
+            .cfg
+            .set_config(&crate::internal::state::ROLLUP_THRESHOLD, 1);
+        let (source, target) = (ShardId::new(), ShardId::new());
+
+        let (mut source_write, mut source_read) =
+            client.expect_open::<String, String, u64, i64>(source).await;
+        source_write
+            .expect_compare_and_append(&data[..2], 0, 3)
+            .await;
+
+        let fork = |source, target, upper_bound: Antichain<u64>| async move {
+            client
+                .fork_shard::<String, String, u64, i64>(
+                    source,
+                    target,
+                    &upper_bound,
+                    Diagnostics::for_tests(),
+                )
+                .await
+        };
+        let upper = fork(source, target, Antichain::new())
+            .await
+            .expect("valid usage");
+        assert_eq!(upper, Antichain::from_elem(3));
+        // Forking again is a no-op.
+        let upper = fork(source, target, Antichain::new())
+            .await
+            .expect("valid usage");
+        assert_eq!(upper, Antichain::from_elem(3));
+        // Batches that end beyond the upper bound aren't forked.
+        let upper = fork(source, ShardId::new(), Antichain::from_elem(2))
+            .await
+            .expect("valid usage");
+        assert_eq!(upper, Antichain::from_elem(0));
+
+        let (mut target_write, mut target_read) =
+            client.expect_open::<String, String, u64, i64>(target).await;
+        assert_eq!(target_write.upper(), &Antichain::from_elem(3));
+        assert_eq!(
+            target_read.expect_snapshot_and_fetch(2).await,
+            all_ok(&data[..2], 2)
+        );
+
+        // A shard that's been used can't become a fork.
+        let err = fork(target, source, Antichain::new())
+            .await
+            .expect_err("source has been written to");
+        assert!(matches!(err, InvalidUsage::ForkTargetInUse { .. }));
+
+        // Afterward, the two shards are independent.
+        source_write
+            .expect_compare_and_append(&data[2..], 3, 4)
+            .await;
+        target_write.expect_compare_and_append(EMPTY, 3, 5).await;
+        assert_eq!(
+            source_read.expect_snapshot_and_fetch(3).await,
+            all_ok(&data, 3)
+        );
+        assert_eq!(
+            target_read.expect_snapshot_and_fetch(4).await,
+            all_ok(&data[..2], 4)
+        );
+
+        // Finalizing the source and collecting its garbage leaves the parts
+        // it shares with the fork alone.
+        let () = source_read.downgrade_since(&Antichain::new()).await;
+        let () = source_write
+            .compare_and_append(EMPTY, Antichain::from_elem(4), Antichain::new())
+            .await
+            .expect("usage should be valid")
+            .expect("upper should match");
+        source_read.expire().await;
+        let machine = source_write.machine.clone();
+        source_write.expire().await;
+        client
+            .finalize_shard::<String, String, u64, i64>(source, Diagnostics::for_tests())
+            .await
+            .expect("finalization must succeed");
+        machine.applier.fetch_and_update_state(None).await;
+        let req = GcReq {
+            shard_id: source,
+            new_seqno_since: machine.applier.seqno_since(),
+        };
+        let _ = GarbageCollector::gc_and_truncate(&machine, req).await;
+        assert_eq!(
+            target_read.expect_snapshot_and_fetch(4).await,
+            all_ok(&data[..2], 4)
+        );
+    }
+
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn fork_shard_failed(dyncfgs: ConfigUpdates) {
+        const EMPTY: &[((String, String), u64, i64)] = &[];
+        let data = [(("1".to_owned(), "one".to_owned()), 1, 1)];
+        let client = new_test_client(&dyncfgs).await;
+        client
+            .cfg
+            .set_config(&crate::internal::state::ROLLUP_THRESHOLD, 1);
+        // Write the data to its own part, so that there's a part to share.
+        client.cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+        let (source, target) = (ShardId::new(), ShardId::new());
+
+        let (mut source_write, mut source_read) =
+            client.expect_open::<String, String, u64, i64>(source).await;
+        source_write.expect_compare_and_append(&data, 0, 2).await;
+
+        // The target has a reader, which is only noticed once the fork has
+        // been registered with the source.
+        let (_target_write, _target_read) =
+            client.expect_open::<String, String, u64, i64>(target).await;
+        let err = client
+            .fork_shard::<String, String, u64, i64>(
+                source,
+                target,
+                &Antichain::new(),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect_err("target has been read from");
+        assert!(matches!(err, InvalidUsage::ForkTargetInUse { .. }));
+        let machine = source_write.machine.clone();
+        machine.applier.fetch_and_update_state(None).await;
+        assert!(machine.applier.clone_collections().forks.is_empty());
+
+        // So the failed fork doesn't keep GC from deleting the source's parts.
+        let () = source_read.downgrade_since(&Antichain::new()).await;
+        let () = source_write
+            .compare_and_append(EMPTY, Antichain::from_elem(2), Antichain::new())
+            .await
+            .expect("usage should be valid")
+            .expect("upper should match");
+        source_read.expire().await;
+        source_write.expire().await;
+        client
+            .finalize_shard::<String, String, u64, i64>(source, Diagnostics::for_tests())
+            .await
+            .expect("finalization must succeed");
+        machine.applier.fetch_and_update_state(None).await;
+        let req = GcReq {
+            shard_id: source,
+            new_seqno_since: machine.applier.seqno_since(),
+        };
+        let _ = GarbageCollector::gc_and_truncate(&machine, req).await;
+        let mut batch_parts = 0;
+        client
+            .blob
+            .list_keys_and_metadata(&source.to_string(), &mut |metadata| {
+                if let Ok((_, PartialBlobKey::Batch(..))) = BlobKey::parse_ids(metadata.key) {
+                    batch_parts += 1;
+                }
+            })
+            .await
+            .expect("listing must succeed");
+        assert_eq!(batch_parts, 0);
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(4096))]
 
@@ -752,7 +752,7 @@ where
     /// Tracks that the `ReadHandle`'s machine's current `SeqNo` is being
     /// "leased out" to a `LeasedBatchPart`, and cannot be garbage
     /// collected until its lease has been returned.
-    fn lease_seqno(&mut self) -> Lease {
+    pub(crate) fn lease_seqno(&mut self) -> Lease {
         let seqno = self.machine.seqno();
         let lease = self.leased_seqnos.entry(seqno).or_default();
         lease.clone()
@@ -0,0 +1,828 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! An Azure Blob Storage implementation of [Blob] storage.
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::time::{Instant, SystemTime};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use hmac::{Hmac, Mac};
+use mz_ore::bytes::SegmentedBytes;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH};
+use reqwest::{Method, Response, StatusCode};
+use sha2::Sha256;
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::cfg::BlobKnobs;
+use crate::error::Error;
+use crate::http::{self, MB};
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+/// The version of the Blob service REST API that requests are made against.
+const API_VERSION: &str = "2021-08-06";
+
+/// Blobs larger than this are uploaded as multiple blocks, concurrently.
+const BLOCK_UPLOAD_THRESHOLD: usize = 8 * MB;
+/// The size of each block (except the last) of a blob uploaded in blocks.
+const DEFAULT_BLOCK_SIZE: usize = 8 * MB;
+/// The largest number of blocks a blob can be made of.
+///
+/// From <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list>
+const MAX_BLOCKS: usize = 50_000;
+/// The most blocks of a single blob that are uploaded at once.
+const MAX_CONCURRENT_BLOCK_UPLOADS: usize = 16;
+
+/// Configuration for opening an [AzureBlob].
+#[derive(Clone, Debug)]
+pub struct AzureBlobConfig {
+    client: reqwest::Client,
+    account: String,
+    container_url: Url,
+    prefix: String,
+    credentials: AzureCredentials,
+}
+
+/// How requests to Azure are authorized.
+#[derive(Clone)]
+enum AzureCredentials {
+    /// Requests are signed with the storage account's access key.
+    SharedKey(Vec<u8>),
+    /// A shared access signature is appended to the query of each request.
+    Sas(String),
+}
+
+impl Debug for AzureCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AzureCredentials::SharedKey(_) => f.write_str("SharedKey(<redacted>)"),
+            AzureCredentials::Sas(_) => f.write_str("Sas(<redacted>)"),
+        }
+    }
+}
+
+impl AzureBlobConfig {
+    const EXTERNAL_TESTS_AZURE_CONTAINER: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_CONTAINER";
+    const EXTERNAL_TESTS_AZURE_ENDPOINT: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_ENDPOINT";
+    const EXTERNAL_TESTS_AZURE_ACCOUNT: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_ACCOUNT";
+    const EXTERNAL_TESTS_AZURE_ACCESS_KEY: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_ACCESS_KEY";
+
+    /// Returns a new [AzureBlobConfig] for use in production.
+    ///
+    /// Stores objects in the given container of the given storage account,
+    /// prepended with the (possibly empty) prefix. Requests are authorized
+    /// with exactly one of the account's access key or a shared access
+    /// signature. If `endpoint` is set, it replaces the account's default Blob
+    /// service endpoint, e.g. to use a local Azurite emulator.
+    pub fn new(
+        account: String,
+        container: String,
+        prefix: String,
+        endpoint: Option<String>,
+        access_key: Option<String>,
+        sas_token: Option<String>,
+        knobs: Box<dyn BlobKnobs>,
+    ) -> Result<Self, Error> {
+        let credentials = match (access_key, sas_token) {
+            (Some(access_key), None) => {
+                let access_key = base64::decode(access_key)
+                    .map_err(|err| Error::from(format!("invalid azure access key: {}", err)))?;
+                AzureCredentials::SharedKey(access_key)
+            }
+            (None, Some(sas_token)) => {
+                AzureCredentials::Sas(sas_token.trim_start_matches('?').to_string())
+            }
+            _ => {
+                return Err(Error::from(
+                    "exactly one of an azure access key or sas_token must be provided".to_string(),
+                ))
+            }
+        };
+
+        let endpoint =
+            endpoint.unwrap_or_else(|| format!("https://{}.blob.core.windows.net", account));
+        let mut container_url = Url::parse(&endpoint)
+            .map_err(|err| Error::from(format!("invalid azure endpoint {}: {}", endpoint, err)))?;
+        container_url
+            .path_segments_mut()
+            .map_err(|()| Error::from(format!("invalid azure endpoint: {}", endpoint)))?
+            .pop_if_empty()
+            .push(&container);
+
+        Ok(AzureBlobConfig {
+            client: http::client(knobs.as_ref())?,
+            account,
+            container_url,
+            prefix,
+            credentials,
+        })
+    }
+
+    /// Returns a new [AzureBlobConfig] for use in unit tests.
+    ///
+    /// By default, persist tests that use external storage (like Azure) are
+    /// no-ops, so that `cargo test` works on new environments without any
+    /// configuration. To activate the tests for [AzureBlob], set the
+    /// `MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_CONTAINER` environment variable
+    /// to the name of a container. The account and access key default to
+    /// those of the Azurite emulator, at the endpoint set in
+    /// `MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_ENDPOINT`.
+    pub fn new_for_test() -> Result<Option<Self>, Error> {
+        let container = match std::env::var(Self::EXTERNAL_TESTS_AZURE_CONTAINER) {
+            Ok(container) => container,
+            Err(_) => return Ok(None),
+        };
+        let endpoint = std::env::var(Self::EXTERNAL_TESTS_AZURE_ENDPOINT).ok();
+        // The well-known development credentials of Azurite.
+        let account = std::env::var(Self::EXTERNAL_TESTS_AZURE_ACCOUNT)
+            .unwrap_or_else(|_| "devstoreaccount1".into());
+        let access_key = std::env::var(Self::EXTERNAL_TESTS_AZURE_ACCESS_KEY).unwrap_or_else(|_| {
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
+                .into()
+        });
+
+        #[derive(Debug)]
+        struct TestBlobKnobs;
+        impl BlobKnobs for TestBlobKnobs {
+            fn operation_timeout(&self) -> std::time::Duration {
+                std::time::Duration::from_secs(60)
+            }
+
+            fn operation_attempt_timeout(&self) -> std::time::Duration {
+                std::time::Duration::from_secs(30)
+            }
+
+            fn connect_timeout(&self) -> std::time::Duration {
+                std::time::Duration::from_secs(5)
+            }
+
+            fn read_timeout(&self) -> std::time::Duration {
+                std::time::Duration::from_secs(5)
+            }
+
+            fn is_cc_active(&self) -> bool {
+                false
+            }
+        }
+
+        // Give each test a unique prefix so they don't conflict.
+        let prefix = uuid::Uuid::new_v4().to_string();
+        let config = AzureBlobConfig::new(
+            account,
+            container,
+            prefix,
+            endpoint,
+            Some(access_key),
+            None,
+            Box::new(TestBlobKnobs),
+        )?;
+        Ok(Some(config))
+    }
+}
+
+/// Implementation of [Blob] backed by Azure Blob Storage.
+#[derive(Debug)]
+pub struct AzureBlob {
+    client: reqwest::Client,
+    account: String,
+    container_url: Url,
+    prefix: String,
+    credentials: AzureCredentials,
+    // Maximum number of blobs we get information about per list request.
+    //
+    // Defaults to 5000, which is the current Azure max.
+    max_results: usize,
+    block_upload_threshold: usize,
+}
+
+impl AzureBlob {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: AzureBlobConfig) -> Result<Self, ExternalError> {
+        let ret = AzureBlob {
+            client: config.client,
+            account: config.account,
+            container_url: config.container_url,
+            prefix: config.prefix,
+            credentials: config.credentials,
+            max_results: 5_000,
+            block_upload_threshold: BLOCK_UPLOAD_THRESHOLD,
+        };
+        // Connect before returning success. We don't particularly care about
+        // what's stored in this blob (nothing writes to it, so presumably it's
+        // empty) just that we were able and allowed to fetch it.
+        let _ = ret.get("HEALTH_CHECK").await?;
+        Ok(ret)
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.prefix, key)
+    }
+
+    fn blob_url(&self, key: &str) -> Url {
+        let mut url = self.container_url.clone();
+        url.path_segments_mut()
+            .expect("validated in AzureBlobConfig::new")
+            .extend(self.get_path(key).split('/'));
+        url
+    }
+
+    /// Authorizes and sends a request.
+    async fn send(
+        &self,
+        method: Method,
+        mut url: Url,
+        mut headers: HeaderMap,
+        body: Option<Bytes>,
+    ) -> Result<Response, ExternalError> {
+        let date = httpdate::fmt_http_date(SystemTime::now());
+        headers.insert(
+            "x-ms-date",
+            HeaderValue::from_str(&date).expect("valid date"),
+        );
+        headers.insert("x-ms-version", HeaderValue::from_static(API_VERSION));
+        let content_length = body.as_ref().map_or(0, |body| body.len());
+        if body.is_some() {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(content_length));
+        }
+
+        match &self.credentials {
+            AzureCredentials::SharedKey(access_key) => {
+                let string_to_sign = string_to_sign(&self.account, &method, &url, &headers);
+                let signature = sign(access_key, &string_to_sign);
+                let authorization = format!("SharedKey {}:{}", self.account, signature);
+                headers.insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&authorization).expect("valid authorization"),
+                );
+            }
+            AzureCredentials::Sas(sas_token) => {
+                let query = match url.query() {
+                    Some(query) => format!("{}&{}", query, sas_token),
+                    None => sas_token.clone(),
+                };
+                url.set_query(Some(&query));
+            }
+        }
+
+        let mut request = self.client.request(method, url).headers(headers);
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+        request
+            .send()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("azure request err: {}", err)))
+    }
+
+    /// Returns the requested range of a blob along with its total length, or
+    /// None if it doesn't exist.
+    async fn get_range(
+        &self,
+        url: &Url,
+        range: Range<usize>,
+    ) -> Result<Option<(Bytes, usize)>, ExternalError> {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ms-range", http::range_header(&range));
+        let response = self.send(Method::GET, url.clone(), headers, None).await?;
+        let len = match response.status() {
+            StatusCode::PARTIAL_CONTENT => http::content_range_len(response.headers())?,
+            StatusCode::OK => usize::try_from(response.content_length().unwrap_or(0))
+                .expect("blob fits in memory"),
+            // Ranges of empty blobs can't be satisfied.
+            StatusCode::RANGE_NOT_SATISFIABLE if range.start == 0 => {
+                return Ok(Some((Bytes::new(), 0)))
+            }
+            StatusCode::NOT_FOUND if error_code(&response) == Some("BlobNotFound") => {
+                return Ok(None)
+            }
+            _ => return Err(response_error("get", response).await),
+        };
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("azure get body err: {}", err)))?;
+        Ok(Some((bytes, len)))
+    }
+
+    /// Returns the size of a blob, or None if it doesn't exist.
+    async fn head(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let response = self
+            .send(Method::HEAD, self.blob_url(key), HeaderMap::new(), None)
+            .await?;
+        match response.status() {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(response_error("head", response).await),
+        }
+        // NB: reqwest reports a content length of zero for all responses to
+        // HEAD requests, so read the header itself.
+        let size = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|len| len.to_str().ok())
+            .and_then(|len| len.parse().ok())
+            .ok_or_else(|| anyhow!("azure head response missing content length"))?;
+        Ok(Some(size))
+    }
+
+    async fn set_single(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start = Instant::now();
+        let value_len = value.len();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ms-blob-type", HeaderValue::from_static("BlockBlob"));
+        let response = self
+            .send(Method::PUT, self.blob_url(key), headers, Some(value))
+            .await?;
+        if response.status() != StatusCode::CREATED {
+            return Err(response_error("put blob", response).await);
+        }
+        debug!("azure PutBlob done {}b / {:?}", value_len, start.elapsed());
+        Ok(())
+    }
+
+    /// Uploads the blob as blocks, up to [MAX_CONCURRENT_BLOCK_UPLOADS] at a
+    /// time, then commits them.
+    async fn set_blocks(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start = Instant::now();
+        let url = self.blob_url(key);
+        let block_size = http::chunk_size(value.len(), DEFAULT_BLOCK_SIZE, MAX_BLOCKS);
+
+        let mut block_ids = Vec::new();
+        let mut block_futs = Vec::new();
+        for (idx, range) in http::chunk_ranges(0, value.len(), block_size).enumerate() {
+            // Block ids must all have the same length within a blob.
+            let block_id = base64::encode(format!("{:08}", idx));
+            let mut url = url.clone();
+            url.query_pairs_mut()
+                .append_pair("comp", "block")
+                .append_pair("blockid", &block_id);
+            block_ids.push(block_id);
+            let block = value.slice(range);
+            block_futs.push(async move {
+                let response = self
+                    .send(Method::PUT, url, HeaderMap::new(), Some(block))
+                    .await?;
+                if response.status() != StatusCode::CREATED {
+                    return Err(response_error("put block", response).await);
+                }
+                Ok::<_, ExternalError>(())
+            });
+        }
+        let num_blocks = block_futs.len();
+        stream::iter(block_futs)
+            .buffer_unordered(MAX_CONCURRENT_BLOCK_UPLOADS)
+            .try_collect::<Vec<()>>()
+            .await?;
+        trace!(
+            "azure PutBlock took {:?} ({} blocks)",
+            start.elapsed(),
+            num_blocks
+        );
+
+        // Blocks that are never committed are garbage collected by Azure after
+        // a week, so there's no need to clean up after a failure.
+        let block_list: String = block_ids
+            .iter()
+            .map(|block_id| format!("<Latest>{}</Latest>", block_id))
+            .collect();
+        let body = format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><BlockList>{}</BlockList>",
+            block_list
+        );
+        let mut url = url;
+        url.query_pairs_mut().append_pair("comp", "blocklist");
+        let response = self
+            .send(Method::PUT, url, HeaderMap::new(), Some(Bytes::from(body)))
+            .await?;
+        if response.status() != StatusCode::CREATED {
+            return Err(response_error("put block list", response).await);
+        }
+        debug!(
+            "azure PutBlockList done {}b / {:?} ({} blocks)",
+            value.len(),
+            start.elapsed(),
+            num_blocks
+        );
+        Ok(())
+    }
+
+    /// Creates the container, if it doesn't already exist.
+    #[cfg(test)]
+    async fn create_container(&self) -> Result<(), ExternalError> {
+        let mut url = self.container_url.clone();
+        url.query_pairs_mut().append_pair("restype", "container");
+        let response = self
+            .send(Method::PUT, url, HeaderMap::new(), Some(Bytes::new()))
+            .await?;
+        match response.status() {
+            StatusCode::CREATED | StatusCode::CONFLICT => Ok(()),
+            _ => Err(response_error("create container", response).await),
+        }
+    }
+}
+
+#[async_trait]
+impl Blob for AzureBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let url = self.blob_url(key);
+        http::get_ranged(|range| self.get_range(&url, range)).await
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        // we only want to return keys that match the specified blob key prefix
+        let blob_key_prefix = self.get_path(key_prefix);
+        // but we want to exclude the shared root prefix from our returned keys,
+        // so only the blob key itself is passed in to `f`
+        let strippable_root_prefix = format!("{}/", self.prefix);
+
+        let mut marker = None;
+        loop {
+            let mut url = self.container_url.clone();
+            {
+                let mut query = url.query_pairs_mut();
+                query
+                    .append_pair("restype", "container")
+                    .append_pair("comp", "list")
+                    .append_pair("prefix", &blob_key_prefix)
+                    .append_pair("maxresults", &self.max_results.to_string());
+                if let Some(marker) = &marker {
+                    query.append_pair("marker", marker);
+                }
+            }
+            let response = self.send(Method::GET, url, HeaderMap::new(), None).await?;
+            if response.status() != StatusCode::OK {
+                return Err(response_error("list blobs", response).await);
+            }
+            let body = response
+                .text()
+                .await
+                .map_err(|err| ExternalError::from(anyhow!("azure list body err: {}", err)))?;
+            let page = ListBlobsPage::parse(&body)?;
+            for (name, size_in_bytes) in page.blobs {
+                match name.strip_prefix(&strippable_root_prefix) {
+                    Some(key) => f(BlobMetadata { key, size_in_bytes }),
+                    None => {
+                        return Err(ExternalError::from(anyhow!(
+                            "found key with invalid prefix: {}",
+                            name
+                        )))
+                    }
+                }
+            }
+
+            match page.next_marker {
+                Some(next_marker) => marker = Some(next_marker),
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        if value.len() > self.block_upload_threshold {
+            self.set_blocks(key, value).await
+        } else {
+            self.set_single(key, value).await
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        // There is a race condition here where, if two delete calls for the
+        // same key occur simultaneously, both might think they did the actual
+        // deletion. This return value is only used for metrics, so it's
+        // unfortunate, but fine.
+        let size_bytes = match self.head(key).await? {
+            Some(size_bytes) => size_bytes,
+            None => return Ok(None),
+        };
+        let response = self
+            .send(Method::DELETE, self.blob_url(key), HeaderMap::new(), None)
+            .await?;
+        match response.status() {
+            StatusCode::ACCEPTED => Ok(Some(size_bytes)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(response_error("delete", response).await),
+        }
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        if self.head(key).await?.is_some() {
+            return Ok(());
+        }
+
+        // Deleted blobs can only be restored if soft delete is enabled for the
+        // storage account.
+        let mut url = self.blob_url(key);
+        url.query_pairs_mut().append_pair("comp", "undelete");
+        let response = self
+            .send(Method::PUT, url, HeaderMap::new(), Some(Bytes::new()))
+            .await?;
+        let status = response.status();
+        if status.is_server_error() {
+            return Err(response_error("undelete", response).await);
+        }
+        if status.is_success() && self.head(key).await?.is_some() {
+            return Ok(());
+        }
+        Err(Determinate::new(anyhow!(
+            "unable to restore {} in azure: no soft-deleted version exists ({})",
+            key,
+            status
+        ))
+        .into())
+    }
+}
+
+/// Returns the Azure error code of an unsuccessful response, if it has one.
+fn error_code(response: &Response) -> Option<&str> {
+    response
+        .headers()
+        .get("x-ms-error-code")
+        .and_then(|code| code.to_str().ok())
+}
+
+/// Returns an error describing an unsuccessful response.
+async fn response_error(op: &str, response: Response) -> ExternalError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    ExternalError::from(anyhow!("azure {} err: {}: {}", op, status, body))
+}
+
+/// Returns the string that is signed to authorize a request with a shared key.
+///
+/// See <https://learn.microsoft.com/en-us/rest/api/storageservices/authorize-with-shared-key>.
+fn string_to_sign(account: &str, method: &Method, url: &Url, headers: &HeaderMap) -> String {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("")
+    };
+    // A content length of zero is signed as the empty string.
+    let content_length = match header("content-length") {
+        "0" => "",
+        content_length => content_length,
+    };
+
+    let mut canonicalized_headers: Vec<_> = headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with("x-ms-"))
+        .map(|(name, value)| {
+            let value = value.to_str().unwrap_or("").trim();
+            format!("{}:{}\n", name.as_str(), value)
+        })
+        .collect();
+    canonicalized_headers.sort();
+
+    let mut params: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, value) in url.query_pairs() {
+        params
+            .entry(name.to_lowercase())
+            .or_default()
+            .push(value.into_owned());
+    }
+    let mut canonicalized_resource = format!("/{}{}", account, url.path());
+    for (name, mut values) in params {
+        values.sort();
+        canonicalized_resource.push_str(&format!("\n{}:{}", name, values.join(",")));
+    }
+
+    format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}{}",
+        method.as_str(),
+        header("content-encoding"),
+        header("content-language"),
+        content_length,
+        header("content-md5"),
+        header("content-type"),
+        header("date"),
+        header("if-modified-since"),
+        header("if-match"),
+        header("if-none-match"),
+        header("if-unmodified-since"),
+        header("range"),
+        canonicalized_headers.concat(),
+        canonicalized_resource,
+    )
+}
+
+fn sign(access_key: &[u8], string_to_sign: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(access_key).expect("HMAC accepts keys of any size");
+    mac.update(string_to_sign.as_bytes());
+    base64::encode(mac.finalize().into_bytes())
+}
+
+/// A page of the response to a List Blobs request.
+#[derive(Debug, Default, PartialEq)]
+struct ListBlobsPage {
+    /// The name and size of each blob.
+    blobs: Vec<(String, u64)>,
+    /// The marker to request the next page with, if there is one.
+    next_marker: Option<String>,
+}
+
+impl ListBlobsPage {
+    fn parse(xml: &str) -> Result<Self, ExternalError> {
+        let invalid = |err: &dyn std::fmt::Display| {
+            ExternalError::from(anyhow!("invalid azure list blobs response: {}", err))
+        };
+
+        let mut page = ListBlobsPage::default();
+        let mut reader = Reader::from_str(xml);
+        // The names of the elements enclosing the current position.
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        let (mut name, mut size) = (None, None);
+        loop {
+            match reader.read_event().map_err(|err| invalid(&err))? {
+                Event::Start(start) => path.push(start.name().as_ref().to_vec()),
+                Event::End(_) => {
+                    if path.last().map(Vec::as_slice) == Some(b"Blob") {
+                        let blob = name.take().zip(size.take());
+                        let blob = blob.ok_or_else(|| invalid(&"blob missing name or size"))?;
+                        page.blobs.push(blob);
+                    }
+                    path.pop();
+                }
+                Event::Text(text) => {
+                    let text = text.unescape().map_err(|err| invalid(&err))?;
+                    let parent = path.len().checked_sub(2).map(|idx| path[idx].as_slice());
+                    match (parent, path.last().map(Vec::as_slice)) {
+                        (Some(b"Blob"), Some(b"Name")) => name = Some(text.into_owned()),
+                        (Some(b"Properties"), Some(b"Content-Length")) => {
+                            size = Some(text.parse().map_err(|err| invalid(&err))?)
+                        }
+                        (_, Some(b"NextMarker")) if !text.is_empty() => {
+                            page.next_marker = Some(text.into_owned())
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info;
+
+    use crate::location::tests::blob_impl_test;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    async fn azure_blob() -> Result<(), ExternalError> {
+        let config = match AzureBlobConfig::new_for_test()? {
+            Some(config) => config,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    AzureBlobConfig::EXTERNAL_TESTS_AZURE_CONTAINER
+                );
+                return Ok(());
+            }
+        };
+        let root_prefix = config.prefix.clone();
+
+        // The emulator starts out without any containers.
+        let blob = AzureBlob {
+            client: config.client.clone(),
+            account: config.account.clone(),
+            container_url: config.container_url.clone(),
+            prefix: format!("{}/setup", root_prefix),
+            credentials: config.credentials.clone(),
+            max_results: 5_000,
+            block_upload_threshold: BLOCK_UPLOAD_THRESHOLD,
+        };
+        blob.create_container().await?;
+
+        let blob_config = config.clone();
+        blob_impl_test(move |path| {
+            let mut config = blob_config.clone();
+            config.prefix = format!("{}/azure_blob_impl_test/{}", root_prefix, path);
+            async move {
+                let mut blob = AzureBlob::open(config).await?;
+                blob.max_results = 2;
+                Ok(blob)
+            }
+        })
+        .await?;
+
+        // Also specifically test uploads in blocks, and reading them back in
+        // more than one range.
+        let mut config = config;
+        config.prefix = format!("{}/blocks", config.prefix);
+        let mut blob = AzureBlob::open(config).await?;
+        blob.block_upload_threshold = 0;
+        let value: Bytes = (0..u8::MAX)
+            .cycle()
+            .take(http::READ_CHUNK_SIZE + 1)
+            .collect::<Vec<_>>()
+            .into();
+        blob.set("blocks", value.clone()).await?;
+        assert_eq!(
+            blob.get("blocks")
+                .await?
+                .map(|value| value.into_contiguous()),
+            Some(value.to_vec())
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn shared_key_string_to_sign() {
+        let url = Url::parse(
+            "http://127.0.0.1:10000/devstoreaccount1/container/prefix/k%20ey?comp=list&restype=container&Prefix=b&prefix=a",
+        )
+        .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ms-date",
+            HeaderValue::from_static("Mon, 19 Oct 2026 00:00:00 GMT"),
+        );
+        headers.insert("x-ms-version", HeaderValue::from_static(API_VERSION));
+        headers.insert("x-ms-range", HeaderValue::from_static("bytes=0-9"));
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
+
+        let string_to_sign = string_to_sign("devstoreaccount1", &Method::GET, &url, &headers);
+        assert_eq!(
+            string_to_sign,
+            "GET\n\n\n\n\n\n\n\n\n\n\n\n\
+             x-ms-date:Mon, 19 Oct 2026 00:00:00 GMT\n\
+             x-ms-range:bytes=0-9\n\
+             x-ms-version:2021-08-06\n\
+             /devstoreaccount1/devstoreaccount1/container/prefix/k%20ey\n\
+             comp:list\n\
+             prefix:a,b\n\
+             restype:container"
+        );
+        assert_eq!(
+            sign(b"key", "string to sign"),
+            "rZgAb/fONOJUp5e9IfJKpfmMNSO6NQSTtvFogx4DuQQ="
+        );
+    }
+
+    #[mz_ore::test]
+    fn list_blobs_page() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ServiceEndpoint="http://127.0.0.1:10000/devstoreaccount1" ContainerName="c">
+  <Prefix>p/</Prefix>
+  <MaxResults>2</MaxResults>
+  <Blobs>
+    <Blob>
+      <Name>p/k0</Name>
+      <Properties>
+        <Content-Length>3</Content-Length>
+        <BlobType>BlockBlob</BlobType>
+      </Properties>
+    </Blob>
+    <Blob>
+      <Name>p/a&amp;b</Name>
+      <Properties>
+        <Content-Length>0</Content-Length>
+      </Properties>
+    </Blob>
+  </Blobs>
+  <NextMarker>marker</NextMarker>
+</EnumerationResults>"#;
+        assert_eq!(
+            ListBlobsPage::parse(xml),
+            Ok(ListBlobsPage {
+                blobs: vec![("p/k0".into(), 3), ("p/a&b".into(), 0)],
+                next_marker: Some("marker".into()),
+            })
+        );
+
+        let xml = r#"<EnumerationResults><Blobs /><NextMarker /></EnumerationResults>"#;
+        assert_eq!(ListBlobsPage::parse(xml), Ok(ListBlobsPage::default()));
+    }
+}
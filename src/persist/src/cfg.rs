@@ -21,7 +21,10 @@ use tracing::warn;
 use mz_postgres_client::metrics::PostgresClientMetrics;
 use mz_postgres_client::PostgresClientKnobs;
 
-use crate::file::{FileBlob, FileBlobConfig};
+use crate::azure::{AzureBlob, AzureBlobConfig};
+use crate::encryption::{EncryptedBlob, KeyProvider, StaticKeyProvider};
+use crate::file::{FileBlob, FileBlobConfig, FileConsensus, FileConsensusConfig};
+use crate::gcs::{GcsBlob, GcsBlobConfig};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
 use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::S3BlobMetrics;
@@ -45,9 +48,22 @@ pub enum BlobConfig {
     File(FileBlobConfig),
     /// Config for [S3Blob].
     S3(S3BlobConfig),
+    /// Config for [AzureBlob].
+    Azure(AzureBlobConfig),
+    /// Config for [GcsBlob].
+    Gcs(GcsBlobConfig),
     /// Config for [MemBlob], only available in testing to prevent
     /// footguns.
     Mem(bool),
+    /// Config for an [EncryptedBlob] wrapping another implementation.
+    Encrypted {
+        /// The config of the wrapped implementation.
+        blob: Box<BlobConfig>,
+        /// The provider of the master keys that wrap data keys.
+        keys: Arc<dyn KeyProvider>,
+        /// Whether values written without encryption can be read.
+        read_unencrypted: bool,
+    },
 }
 
 /// Configuration knobs for [Blob].
@@ -70,9 +86,19 @@ impl BlobConfig {
         match self {
             BlobConfig::File(config) => Ok(Arc::new(FileBlob::open(config).await?)),
             BlobConfig::S3(config) => Ok(Arc::new(S3Blob::open(config).await?)),
+            BlobConfig::Azure(config) => Ok(Arc::new(AzureBlob::open(config).await?)),
+            BlobConfig::Gcs(config) => Ok(Arc::new(GcsBlob::open(config).await?)),
             BlobConfig::Mem(tombstone) => {
                 Ok(Arc::new(MemBlob::open(MemBlobConfig::new(tombstone))))
             }
+            BlobConfig::Encrypted {
+                blob,
+                keys,
+                read_unencrypted,
+            } => {
+                let blob = Box::pin(blob.open()).await?;
+                Ok(Arc::new(EncryptedBlob::new(blob, keys, read_unencrypted)))
+            }
         }
     }
 
@@ -84,6 +110,18 @@ impl BlobConfig {
         cfg: Arc<ConfigSet>,
     ) -> Result<Self, ExternalError> {
         let mut query_params = url.query_pairs().collect::<BTreeMap<_, _>>();
+        // Encryption applies to every scheme, so is parsed up front.
+        let encryption_keys = query_params.remove("encryption_keys");
+        let read_unencrypted = match query_params
+            .remove("encryption_read_unencrypted")
+            .as_deref()
+        {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => Err(Determinate::new(anyhow!(
+                "invalid encryption_read_unencrypted param value: {other}"
+            )))?,
+        };
 
         let config = match url.scheme() {
             "file" => {
@@ -127,6 +165,57 @@ impl BlobConfig {
 
                 Ok(BlobConfig::S3(config))
             }
+            "azblob" => {
+                let container = url
+                    .host()
+                    .ok_or_else(|| anyhow!("missing container: {}", &url.as_str()))?
+                    .to_string();
+                let account = url.username().to_string();
+                if account.is_empty() {
+                    return Err(anyhow!("missing account: {}", &url.as_str()).into());
+                }
+                let prefix = url
+                    .path()
+                    .strip_prefix('/')
+                    .unwrap_or_else(|| url.path())
+                    .to_string();
+                let endpoint = query_params.remove("endpoint").map(|x| x.into_owned());
+                let sas_token = query_params.remove("sas_token").map(|x| x.into_owned());
+                // Access keys are base64, so are likely to be percent-encoded.
+                let access_key = url
+                    .password()
+                    .map(|password| {
+                        percent_encoding::percent_decode_str(password)
+                            .decode_utf8()
+                            .map(|x| x.into_owned())
+                    })
+                    .transpose()
+                    .map_err(|err| anyhow!("invalid access key: {}", err))?;
+
+                let config = AzureBlobConfig::new(
+                    account, container, prefix, endpoint, access_key, sas_token, knobs,
+                )?;
+
+                Ok(BlobConfig::Azure(config))
+            }
+            "gs" => {
+                let bucket = url
+                    .host()
+                    .ok_or_else(|| anyhow!("missing bucket: {}", &url.as_str()))?
+                    .to_string();
+                let prefix = url
+                    .path()
+                    .strip_prefix('/')
+                    .unwrap_or_else(|| url.path())
+                    .to_string();
+                let endpoint = query_params.remove("endpoint").map(|x| x.into_owned());
+                let credentials = query_params.remove("credentials").map(|x| x.into_owned());
+
+                let config =
+                    GcsBlobConfig::new(bucket, prefix, endpoint, credentials, knobs).await?;
+
+                Ok(BlobConfig::Gcs(config))
+            }
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem blob in a release binary");
@@ -160,13 +249,26 @@ impl BlobConfig {
             )));
         }
 
-        Ok(config)
+        match encryption_keys {
+            Some(path) => Ok(BlobConfig::Encrypted {
+                blob: Box::new(config),
+                keys: Arc::new(StaticKeyProvider::from_file(&path)?),
+                read_unencrypted,
+            }),
+            None if read_unencrypted => Err(ExternalError::from(anyhow!(
+                "encryption_read_unencrypted requires encryption_keys: {}",
+                url.as_str()
+            ))),
+            None => Ok(config),
+        }
     }
 }
 
 /// Config for an implementation of [Consensus].
 #[derive(Debug, Clone)]
 pub enum ConsensusConfig {
+    /// Config for [FileConsensus].
+    File(FileConsensusConfig),
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
@@ -177,6 +279,7 @@ impl ConsensusConfig {
     /// Opens the associated implementation of [Consensus].
     pub async fn open(self) -> Result<Arc<dyn Consensus>, ExternalError> {
         match self {
+            ConsensusConfig::File(config) => Ok(Arc::new(FileConsensus::open(config).await?)),
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
@@ -191,6 +294,7 @@ impl ConsensusConfig {
         metrics: PostgresClientMetrics,
     ) -> Result<Self, ExternalError> {
         let config = match url.scheme() {
+            "file" => Ok(ConsensusConfig::File(FileConsensusConfig::from(url.path()))),
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(url, knobs, metrics)?,
             )),
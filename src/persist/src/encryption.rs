@@ -0,0 +1,503 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of [Blob] contents.
+//!
+//! Each blob is encrypted with AES-256-GCM under its own randomly generated
+//! data key. The data key is in turn encrypted ("wrapped") by a [KeyProvider],
+//! which holds the customer-managed master key, and is stored alongside the
+//! ciphertext in a small header. The header also records the version of the
+//! master key that wrapped the data key, so that blobs written before a key
+//! rotation remain readable for as long as the provider can unwrap with the
+//! old version.
+//!
+//! An encrypted blob is laid out as:
+//!
+//! ```text
+//! magic (4) | format version (1)
+//!   | key version len (u16 LE) | key version
+//!   | wrapped key len (u16 LE) | wrapped key
+//!   | nonce (12) | tag (16) | ciphertext
+//! ```
+//!
+//! Everything before the tag is authenticated as additional data, along with
+//! the key of the blob, so neither the header can be tampered with nor the
+//! value moved to another key without the blob failing to decrypt.
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes};
+use mz_ore::bytes::SegmentedBytes;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+const MAGIC: &[u8; 4] = b"MZBE";
+const FORMAT_VERSION: u8 = 1;
+/// The length of data keys and of the master keys of [StaticKeyProvider].
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// A source of master keys that wrap and unwrap the per-blob data keys used by
+/// [EncryptedBlob].
+///
+/// Implementations are expected to delegate to a key management service that
+/// holds customer-managed keys, such that the master keys never leave it.
+#[async_trait]
+pub trait KeyProvider: Debug + Send + Sync + 'static {
+    /// Wraps a data key with the current version of the master key.
+    ///
+    /// Returns the version of the master key that was used, along with the
+    /// wrapped data key.
+    async fn wrap(&self, data_key: &[u8]) -> Result<(String, Vec<u8>), ExternalError>;
+
+    /// Unwraps a data key that was wrapped with the given version of the master
+    /// key.
+    ///
+    /// Returns a [Determinate] error if the version is unknown or the wrapped
+    /// key is invalid.
+    async fn unwrap(&self, version: &str, wrapped_key: &[u8]) -> Result<Vec<u8>, ExternalError>;
+}
+
+/// A [KeyProvider] that holds its versioned master keys in memory.
+///
+/// This is suitable for tests and for deployments that mount their master keys
+/// from a secret store.
+pub struct StaticKeyProvider {
+    /// The version of the master key that wraps new data keys.
+    current: String,
+    keys: BTreeMap<String, [u8; KEY_LEN]>,
+}
+
+impl Debug for StaticKeyProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticKeyProvider")
+            .field("current", &self.current)
+            .field("versions", &self.keys.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The contents of a master key file read by [StaticKeyProvider::from_file].
+#[derive(Deserialize)]
+struct StaticKeyFile {
+    current: String,
+    keys: BTreeMap<String, String>,
+}
+
+impl StaticKeyProvider {
+    /// Returns a new provider whose current master key is `key`.
+    pub fn new(version: String, key: [u8; KEY_LEN]) -> Self {
+        StaticKeyProvider {
+            current: version.clone(),
+            keys: BTreeMap::from([(version, key)]),
+        }
+    }
+
+    /// Reads a provider from a JSON file of the form
+    /// `{"current": "2", "keys": {"1": "<base64>", "2": "<base64>"}}`, where
+    /// each key is 32 bytes.
+    pub fn from_file(path: &str) -> Result<Self, Error> {
+        let contents = std::fs::read(path)
+            .map_err(|err| Error::from(format!("reading encryption keys {}: {}", path, err)))?;
+        let file: StaticKeyFile = serde_json::from_slice(&contents)
+            .map_err(|err| Error::from(format!("invalid encryption keys {}: {}", path, err)))?;
+        let mut keys = BTreeMap::new();
+        for (version, key) in file.keys {
+            let key = base64::decode(key)
+                .ok()
+                .and_then(|key| <[u8; KEY_LEN]>::try_from(key).ok())
+                .ok_or_else(|| {
+                    Error::from(format!(
+                        "invalid encryption key version {} in {}: expected {} base64 bytes",
+                        version, path, KEY_LEN
+                    ))
+                })?;
+            keys.insert(version, key);
+        }
+        if !keys.contains_key(&file.current) {
+            return Err(Error::from(format!(
+                "current encryption key version {} missing from {}",
+                file.current, path
+            )));
+        }
+        Ok(StaticKeyProvider {
+            current: file.current,
+            keys,
+        })
+    }
+}
+
+#[async_trait]
+impl KeyProvider for StaticKeyProvider {
+    async fn wrap(&self, data_key: &[u8]) -> Result<(String, Vec<u8>), ExternalError> {
+        let key = &self.keys[&self.current];
+        let wrapped_key = seal(key, self.current.as_bytes(), data_key)?;
+        Ok((self.current.clone(), wrapped_key))
+    }
+
+    async fn unwrap(&self, version: &str, wrapped_key: &[u8]) -> Result<Vec<u8>, ExternalError> {
+        let key = self.keys.get(version).ok_or_else(|| {
+            Determinate::new(anyhow!("unknown encryption key version: {}", version))
+        })?;
+        open(key, version.as_bytes(), wrapped_key)
+    }
+}
+
+/// Encrypts `plaintext` under `key`, returning `nonce | tag | ciphertext`.
+fn seal(key: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce).map_err(|err| anyhow!("generating nonce: {}", err))?;
+    let mut tag = [0u8; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        aad,
+        plaintext,
+        &mut tag,
+    )
+    .map_err(|err| anyhow!("encrypting: {}", err))?;
+    let mut sealed = Vec::with_capacity(NONCE_LEN + TAG_LEN + ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&tag);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypts the output of [seal].
+fn open(key: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        return Err(Determinate::new(anyhow!("sealed data too short: {}", sealed.len())).into());
+    }
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (tag, ciphertext) = rest.split_at(TAG_LEN);
+    decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        aad,
+        ciphertext,
+        tag,
+    )
+    .map_err(|err| {
+        // Retrying won't make corrupted or tampered data decrypt.
+        Determinate::new(anyhow!("decrypting: {}", err)).into()
+    })
+}
+
+/// The parsed header of an encrypted blob.
+#[derive(Debug)]
+struct Header<'a> {
+    key_version: &'a str,
+    wrapped_key: &'a [u8],
+    /// The length of the header, i.e. the offset of the nonce.
+    len: usize,
+}
+
+impl<'a> Header<'a> {
+    fn encode(key_version: &str, wrapped_key: &[u8], buf: &mut Vec<u8>) -> Result<(), Error> {
+        let key_version_len = u16::try_from(key_version.len())
+            .map_err(|_| Error::from(format!("key version too long: {}", key_version)))?;
+        let wrapped_key_len = u16::try_from(wrapped_key.len())
+            .map_err(|_| Error::from(format!("wrapped key too long: {}", wrapped_key.len())))?;
+        buf.extend_from_slice(MAGIC);
+        buf.put_u8(FORMAT_VERSION);
+        buf.put_u16_le(key_version_len);
+        buf.extend_from_slice(key_version.as_bytes());
+        buf.put_u16_le(wrapped_key_len);
+        buf.extend_from_slice(wrapped_key);
+        Ok(())
+    }
+
+    /// Parses the header of an encrypted blob, or returns None if `buf` isn't
+    /// an encrypted blob.
+    fn decode(buf: &'a [u8]) -> Result<Option<Self>, ExternalError> {
+        fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], ExternalError> {
+            if buf.len() < len {
+                return Err(Determinate::new(anyhow!("truncated encrypted blob header")).into());
+            }
+            let (ret, rest) = buf.split_at(len);
+            *buf = rest;
+            Ok(ret)
+        }
+        fn take_u16(buf: &mut &[u8]) -> Result<usize, ExternalError> {
+            let bytes = take(buf, 2)?;
+            Ok(usize::from(u16::from_le_bytes([bytes[0], bytes[1]])))
+        }
+
+        if !buf.starts_with(MAGIC) {
+            return Ok(None);
+        }
+        let mut rest = &buf[MAGIC.len()..];
+        let format_version = take(&mut rest, 1)?[0];
+        if format_version != FORMAT_VERSION {
+            return Err(Determinate::new(anyhow!(
+                "unknown encrypted blob format version: {}",
+                format_version
+            ))
+            .into());
+        }
+        let key_version_len = take_u16(&mut rest)?;
+        let key_version = std::str::from_utf8(take(&mut rest, key_version_len)?)
+            .map_err(|err| Determinate::new(anyhow!("invalid key version: {}", err)))?;
+        let wrapped_key_len = take_u16(&mut rest)?;
+        let wrapped_key = take(&mut rest, wrapped_key_len)?;
+        Ok(Some(Header {
+            key_version,
+            wrapped_key,
+            len: buf.len() - rest.len(),
+        }))
+    }
+}
+
+/// A [Blob] that encrypts values before writing them to another [Blob], and
+/// decrypts them after reading them back.
+///
+/// Keys and listings are passed through unchanged. Sizes reported by
+/// [Blob::list_keys_and_metadata] and [Blob::delete] are those of the
+/// encrypted values.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob>,
+    keys: Arc<dyn KeyProvider>,
+    read_unencrypted: bool,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob].
+    ///
+    /// If `read_unencrypted` is set, values that were written without
+    /// encryption are returned as-is, which allows encryption to be enabled for
+    /// an existing location. Otherwise, reading them is an error.
+    pub fn new(blob: Arc<dyn Blob>, keys: Arc<dyn KeyProvider>, read_unencrypted: bool) -> Self {
+        EncryptedBlob {
+            blob,
+            keys,
+            read_unencrypted,
+        }
+    }
+
+    fn unencrypted_err(&self, key: &str) -> ExternalError {
+        Determinate::new(anyhow!("blob {} is not encrypted", key)).into()
+    }
+}
+
+/// Returns the additional data authenticated with a blob: its header followed
+/// by its key.
+///
+/// NB: The header encodes its own length, so the concatenation is unambiguous.
+fn additional_data(header: &[u8], blob_key: &str) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(header.len() + blob_key.len());
+    aad.extend_from_slice(header);
+    aad.extend_from_slice(blob_key.as_bytes());
+    aad
+}
+
+fn encrypt(
+    blob_key: &str,
+    data_key: &[u8],
+    key_version: &str,
+    wrapped_key: &[u8],
+    plaintext: &[u8],
+) -> Result<Bytes, ExternalError> {
+    let mut header = Vec::new();
+    Header::encode(key_version, wrapped_key, &mut header)?;
+    let sealed = seal(data_key, &additional_data(&header, blob_key), plaintext)?;
+    let mut value = header;
+    value.extend_from_slice(&sealed);
+    Ok(Bytes::from(value))
+}
+
+fn decrypt(
+    blob_key: &str,
+    data_key: &[u8],
+    header: &Header,
+    value: &[u8],
+) -> Result<Vec<u8>, ExternalError> {
+    let (header, sealed) = value.split_at(header.len);
+    open(data_key, &additional_data(header, blob_key), sealed)
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let value = match self.blob.get(key).await? {
+            Some(value) => value.into_contiguous(),
+            None => return Ok(None),
+        };
+        let header = match Header::decode(&value)? {
+            Some(header) => header,
+            None if self.read_unencrypted => return Ok(Some(SegmentedBytes::from(value))),
+            None => return Err(self.unencrypted_err(key)),
+        };
+        let data_key = self
+            .keys
+            .unwrap(header.key_version, header.wrapped_key)
+            .await?;
+        let plaintext = decrypt(key, &data_key, &header, &value)?;
+        Ok(Some(SegmentedBytes::from(plaintext)))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let mut data_key = [0u8; KEY_LEN];
+        openssl::rand::rand_bytes(&mut data_key)
+            .map_err(|err| anyhow!("generating data key: {}", err))?;
+        let (key_version, wrapped_key) = self.keys.wrap(&data_key).await?;
+        let value = encrypt(key, &data_key, &key_version, &wrapped_key, &value)?;
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mem::{MemBlob, MemBlobConfig};
+
+    use super::*;
+
+    fn key_version(value: &SegmentedBytes) -> String {
+        let value = value.clone().into_contiguous();
+        let header = Header::decode(&value).unwrap().expect("encrypted");
+        header.key_version.to_owned()
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::new(false)));
+        let keys = Arc::new(StaticKeyProvider::new("1".into(), [1; KEY_LEN]));
+        let blob = EncryptedBlob::new(Arc::clone(&inner), Arc::clone(&keys) as _, false);
+
+        // Values round trip, and aren't stored in the clear.
+        blob.set("k0", Bytes::from("plaintext")).await?;
+        let got = blob.get("k0").await?.map(|x| x.into_contiguous());
+        assert_eq!(got, Some(b"plaintext".to_vec()));
+        let stored = inner.get("k0").await?.expect("exists");
+        assert_eq!(key_version(&stored), "1");
+        let stored = stored.into_contiguous();
+        assert!(!stored.windows("plaintext".len()).any(|x| x == b"plaintext"));
+        blob.set("empty", Bytes::new()).await?;
+        let got = blob.get("empty").await?.map(|x| x.into_contiguous());
+        assert_eq!(got, Some(vec![]));
+        assert_eq!(blob.get("nope").await?, None);
+
+        // Each value gets its own data key and nonce.
+        blob.set("k1", Bytes::from("plaintext")).await?;
+        assert_ne!(
+            inner.get("k0").await?.map(|x| x.into_contiguous()),
+            inner.get("k1").await?.map(|x| x.into_contiguous())
+        );
+
+        // Values can't be moved to another key.
+        let moved = inner.get("k0").await?.expect("exists").into_contiguous();
+        inner.set("moved", Bytes::from(moved)).await?;
+        assert!(matches!(
+            blob.get("moved").await,
+            Err(ExternalError::Determinate(_))
+        ));
+
+        // After a rotation, old values remain readable and new values use the
+        // new key version.
+        let rotated_keys = StaticKeyProvider {
+            current: "2".into(),
+            keys: BTreeMap::from([("1".into(), [1; KEY_LEN]), ("2".into(), [2; KEY_LEN])]),
+        };
+        let blob = EncryptedBlob::new(Arc::clone(&inner), Arc::new(rotated_keys), false);
+        blob.set("k2", Bytes::from("rotated")).await?;
+        assert_eq!(key_version(&inner.get("k2").await?.expect("exists")), "2");
+        let got = blob.get("k0").await?.map(|x| x.into_contiguous());
+        assert_eq!(got, Some(b"plaintext".to_vec()));
+
+        // Values whose key version is unknown can't be read.
+        let other_keys = Arc::new(StaticKeyProvider::new("3".into(), [3; KEY_LEN]));
+        let other_blob = EncryptedBlob::new(Arc::clone(&inner), other_keys, false);
+        assert!(matches!(
+            other_blob.get("k0").await,
+            Err(ExternalError::Determinate(_))
+        ));
+
+        // Tampering with any part of the value, including the header, is
+        // detected.
+        let stored = inner.get("k2").await?.expect("exists").into_contiguous();
+        for idx in [MAGIC.len() + 3, stored.len() - 1] {
+            let mut tampered = stored.clone();
+            tampered[idx] ^= 1;
+            inner.set("tampered", Bytes::from(tampered)).await?;
+            assert!(matches!(
+                blob.get("tampered").await,
+                Err(ExternalError::Determinate(_))
+            ));
+        }
+
+        // Unencrypted values are only readable when allowed.
+        inner.set("legacy", Bytes::from("legacy")).await?;
+        assert!(matches!(
+            blob.get("legacy").await,
+            Err(ExternalError::Determinate(_))
+        ));
+        let migrating_blob = EncryptedBlob::new(Arc::clone(&inner), keys, true);
+        let got = migrating_blob
+            .get("legacy")
+            .await?
+            .map(|x| x.into_contiguous());
+        assert_eq!(got, Some(b"legacy".to_vec()));
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: `open` not available when isolation is enabled
+    fn static_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.json");
+        let path = path.to_str().unwrap();
+
+        let key = base64::encode([7u8; KEY_LEN]);
+        std::fs::write(
+            path,
+            format!(r#"{{"current": "b", "keys": {{"a": "{key}", "b": "{key}"}}}}"#),
+        )
+        .unwrap();
+        let provider = StaticKeyProvider::from_file(path).unwrap();
+        assert_eq!(provider.current, "b");
+        assert_eq!(provider.keys.len(), 2);
+
+        std::fs::write(
+            path,
+            format!(r#"{{"current": "c", "keys": {{"a": "{key}"}}}}"#),
+        )
+        .unwrap();
+        assert!(StaticKeyProvider::from_file(path).is_err());
+        std::fs::write(path, r#"{"current": "a", "keys": {"a": "c2hvcnQ="}}"#).unwrap();
+        assert!(StaticKeyProvider::from_file(path).is_err());
+    }
+}
@@ -7,22 +7,38 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! File backed implementations for testing and benchmarking.
+//! File backed implementations of [Blob] and [Consensus].
+//!
+//! [FileBlob] is intended for testing and benchmarking. [FileConsensus] is
+//! durable and crash-safe, and so usable by single-node deployments that don't
+//! want to run a separate database, but only when every process that uses it
+//! shares the same local filesystem.
 
-use std::io::ErrorKind;
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::io::AsRawFd;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::anyhow;
+use async_stream::try_stream;
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes};
 use fail::fail_point;
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
+use mz_ore::task;
+use nix::fcntl::{flock, FlockArg};
 use tokio::fs::{self, File};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::warn;
 
 use crate::error::Error;
-use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::location::{
+    Blob, BlobMetadata, CaSResult, Consensus, Determinate, ExternalError, ResultStream, SeqNo,
+    VersionedData,
+};
 
 /// Configuration for opening a [FileBlob].
 #[derive(Debug, Clone)]
@@ -269,9 +285,554 @@ impl Blob for FileBlob {
     }
 }
 
+/// The name of the [FileConsensus] log within its directory.
+const CONSENSUS_LOG: &str = "consensus.log";
+/// The name of the file that [FileConsensus] instances lock to serialize their
+/// operations.
+const CONSENSUS_LOCK: &str = "consensus.lock";
+/// The name of the file a compacted [FileConsensus] log is written to before
+/// it replaces the log.
+const CONSENSUS_COMPACTED: &str = "consensus.tmp";
+
+/// The length of the header that frames each record in the [FileConsensus]
+/// log: the length of the record's payload as a u64, followed by its CRC-32
+/// checksum as a u32, both little endian.
+const FRAME_HEADER_LEN: usize = 12;
+const SET_TAG: u8 = 1;
+const TRUNCATE_TAG: u8 = 2;
+
+/// Configuration for opening a [FileConsensus].
+#[derive(Debug, Clone)]
+pub struct FileConsensusConfig {
+    base_dir: PathBuf,
+    /// The size in bytes below which the log is never compacted.
+    pub(crate) compaction_min_bytes: usize,
+}
+
+impl<P: AsRef<Path>> From<P> for FileConsensusConfig {
+    fn from(base_dir: P) -> Self {
+        FileConsensusConfig {
+            base_dir: base_dir.as_ref().to_path_buf(),
+            compaction_min_bytes: 16 * 1024 * 1024,
+        }
+    }
+}
+
+/// Implementation of [Consensus] backed by an append-only log in a local
+/// directory.
+///
+/// Every successful `compare_and_set` and `truncate` appends a checksummed
+/// record to the log and fsyncs it before returning. The log is replayed into
+/// memory when opened, and compacted by rewriting only the live versions once
+/// it has grown to more than twice their size. A record that was only
+/// partially written when a process crashed is discarded by the next replay,
+/// but any other damage to the log is an error, so that committed records are
+/// never silently lost.
+///
+/// Each operation holds an exclusive `flock` on a lock file in the directory
+/// and first catches up on any records appended by others, so that several
+/// processes, or several instances within one process, can share a directory.
+#[derive(Debug)]
+pub struct FileConsensus {
+    lock_file: Arc<std::fs::File>,
+    core: Arc<Mutex<FileConsensusCore>>,
+}
+
+#[derive(Debug)]
+struct FileConsensusCore {
+    base_dir: PathBuf,
+    compaction_min_bytes: usize,
+    /// The log and its inode, or None if it must be reopened and replayed from
+    /// the start, e.g. because an append to it failed partway through.
+    log: Option<(std::fs::File, u64)>,
+    /// The length of the prefix of the log that has been applied to `data`.
+    applied_len: usize,
+    /// The versions of each key, in ascending order of sequence number.
+    data: BTreeMap<String, Vec<VersionedData>>,
+    /// The length of a log that contains exactly the versions in `data`.
+    live_len: usize,
+}
+
+impl FileConsensus {
+    /// Opens the given location for read-write access, creating it if it
+    /// doesn't exist.
+    pub async fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let FileConsensusConfig {
+            base_dir,
+            compaction_min_bytes,
+        } = config;
+        fs::create_dir_all(&base_dir).await.map_err(Error::from)?;
+        let lock_file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(base_dir.join(CONSENSUS_LOCK))
+            .await?
+            .into_std()
+            .await;
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(base_dir.join(CONSENSUS_LOG))
+            .await?;
+        // fsync the directory so that the log is guaranteed to be found again.
+        File::open(&base_dir).await?.sync_all().await?;
+
+        let core = FileConsensusCore {
+            base_dir,
+            compaction_min_bytes,
+            log: None,
+            applied_len: 0,
+            data: BTreeMap::new(),
+            live_len: 0,
+        };
+        let consensus = FileConsensus {
+            lock_file: Arc::new(lock_file),
+            core: Arc::new(Mutex::new(core)),
+        };
+        // Replay the log immediately, rather than on the first operation.
+        consensus.run("file_consensus_open", |_| Ok(())).await?;
+        Ok(consensus)
+    }
+
+    /// Runs `f` in a blocking task while holding the lock on the directory,
+    /// after catching up on the log.
+    async fn run<R, F>(&self, name: &'static str, f: F) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut FileConsensusCore) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let lock_file = Arc::clone(&self.lock_file);
+        let core = Arc::clone(&self.core);
+        task::spawn_blocking(
+            || name,
+            move || {
+                let mut core = core.lock().map_err(Error::from)?;
+                let _flock = FlockGuard::lock(&lock_file)?;
+                core.catch_up()?;
+                f(&mut core)
+            },
+        )
+        .await?
+    }
+}
+
+impl FileConsensusCore {
+    fn log_path(&self) -> PathBuf {
+        self.base_dir.join(CONSENSUS_LOG)
+    }
+
+    /// Applies the records appended to the log since it was last read,
+    /// replaying it from the start if it was replaced by a compaction.
+    ///
+    /// Must be called while holding the lock on the directory.
+    fn catch_up(&mut self) -> Result<(), ExternalError> {
+        let log_path = self.log_path();
+        let ino = std::fs::metadata(&log_path)?.ino();
+        if self.log.as_ref().map(|(_, ino)| *ino) != Some(ino) {
+            let log = std::fs::OpenOptions::new()
+                .read(true)
+                .append(true)
+                .open(&log_path)?;
+            let ino = log.metadata()?.ino();
+            self.log = Some((log, ino));
+            self.applied_len = 0;
+            self.data.clear();
+            self.live_len = 0;
+        }
+
+        let (log, _) = self.log.as_mut().expect("opened above");
+        let len = usize::cast_from(log.metadata()?.len());
+        if len < self.applied_len {
+            // Only partially written records are ever removed from the end of
+            // the log, and those are never applied.
+            return Err(ExternalError::from(anyhow!(
+                "consensus log {} unexpectedly shrank from {} to {} bytes",
+                log_path.display(),
+                self.applied_len,
+                len
+            )));
+        }
+        if len == self.applied_len {
+            return Ok(());
+        }
+        let mut buf = Vec::with_capacity(len - self.applied_len);
+        log.seek(SeekFrom::Start(u64::cast_from(self.applied_len)))?;
+        log.read_to_end(&mut buf)?;
+
+        let replayed_len = match self.replay(&buf) {
+            Ok(replayed_len) => replayed_len,
+            Err((offset, err)) => {
+                // Committed records may follow the damaged one, so leave the
+                // log as is, and replay it from the start next time rather
+                // than continuing from the records applied so far.
+                self.log = None;
+                return Err(ExternalError::from(anyhow!(
+                    "consensus log {} is corrupt at byte {}: {}",
+                    log_path.display(),
+                    self.applied_len + offset,
+                    err
+                )));
+            }
+        };
+        if replayed_len < buf.len() {
+            // The log ends in a record that runs past its end. Records
+            // are appended while holding the lock, so this must be left over
+            // from a writer that crashed or failed partway through.
+            warn!(
+                "discarding {} bytes of a partially written record at the end of consensus log {}",
+                buf.len() - replayed_len,
+                log_path.display()
+            );
+            let (log, _) = self.log.as_mut().expect("opened above");
+            log.set_len(u64::cast_from(self.applied_len + replayed_len))?;
+            log.sync_all()?;
+        }
+        self.applied_len += replayed_len;
+        Ok(())
+    }
+
+    /// Applies the records in `buf` and returns their length, which is less
+    /// than that of `buf` only if `buf` ends in an incomplete record.
+    ///
+    /// Returns the offset of the first complete record that is invalid, along
+    /// with why, if there is one.
+    fn replay(&mut self, mut buf: &[u8]) -> Result<usize, (usize, anyhow::Error)> {
+        let mut replayed_len = 0;
+        loop {
+            match LogRecord::decode(buf) {
+                Ok(Some((record, len))) => {
+                    self.apply(record);
+                    replayed_len += len;
+                    buf = &buf[len..];
+                }
+                Ok(None) => return Ok(replayed_len),
+                Err(err) => return Err((replayed_len, err)),
+            }
+        }
+    }
+
+    fn apply(&mut self, record: LogRecord) {
+        match record {
+            LogRecord::Set { key, data } => {
+                self.live_len += LogRecord::set_len(&key, &data);
+                self.data.entry(key).or_default().push(data);
+            }
+            LogRecord::Truncate { key, seqno } => {
+                if let Some(values) = self.data.get_mut(&key) {
+                    let idx = values.partition_point(|x| x.seqno < seqno);
+                    for data in values.drain(..idx) {
+                        self.live_len -= LogRecord::set_len(&key, &data);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Durably appends `record` to the log, then applies it.
+    ///
+    /// Must be called while holding the lock on the directory, after catching
+    /// up on the log.
+    fn append(&mut self, record: LogRecord) -> Result<(), ExternalError> {
+        let mut buf = Vec::new();
+        record.encode(&mut buf);
+        let (log, _) = self.log.as_mut().expect("caught up before appending");
+        if let Err(err) = Self::write_durably(log, &buf) {
+            // The record may have been partially written, or fully written but
+            // not synced. Either way, replaying the log from the start before
+            // the next operation gets us back in sync with it.
+            self.log = None;
+            return Err(err);
+        }
+        self.applied_len += buf.len();
+        self.apply(record);
+
+        if self.applied_len > self.compaction_min_bytes && self.applied_len > 2 * self.live_len {
+            if let Err(err) = self.compact() {
+                // The log is left intact, so this only delays compaction.
+                warn!("failed to compact consensus log: {}", err);
+                self.log = None;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_durably(log: &mut std::fs::File, buf: &[u8]) -> Result<(), ExternalError> {
+        log.write_all(buf)?;
+
+        fail_point!("fileconsensus_append_sync", |_| {
+            Err(ExternalError::from(anyhow!(
+                "FileConsensus::append_sync fail point reached"
+            )))
+        });
+
+        log.sync_data()?;
+        Ok(())
+    }
+
+    /// Replaces the log with one that contains only the live versions.
+    fn compact(&mut self) -> Result<(), ExternalError> {
+        let mut buf = Vec::with_capacity(self.live_len);
+        for (key, values) in &self.data {
+            for data in values {
+                LogRecord::encode_set(&mut buf, key, data);
+            }
+        }
+
+        // To replace the log atomically, write to a temp file and rename it
+        // into place.
+        let compacted_path = self.base_dir.join(CONSENSUS_COMPACTED);
+        let mut compacted = std::fs::File::create(&compacted_path)?;
+        compacted.write_all(&buf)?;
+        compacted.sync_all()?;
+        let log_path = self.log_path();
+        std::fs::rename(&compacted_path, &log_path)?;
+        std::fs::File::open(&self.base_dir)?.sync_all()?;
+
+        let log = std::fs::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&log_path)?;
+        let ino = log.metadata()?.ino();
+        self.log = Some((log, ino));
+        self.applied_len = buf.len();
+        Ok(())
+    }
+}
+
+/// An exclusive `flock` on a file, held until dropped.
+struct FlockGuard<'a>(&'a std::fs::File);
+
+impl<'a> FlockGuard<'a> {
+    fn lock(file: &'a std::fs::File) -> Result<Self, std::io::Error> {
+        flock(file.as_raw_fd(), FlockArg::LockExclusive)?;
+        Ok(FlockGuard(file))
+    }
+}
+
+impl Drop for FlockGuard<'_> {
+    fn drop(&mut self) {
+        // The lock is released when the file is closed anyway, so there's
+        // nothing more useful to do on failure.
+        let _ = flock(self.0.as_raw_fd(), FlockArg::Unlock);
+    }
+}
+
+/// A record in the [FileConsensus] log.
+#[derive(Debug, Clone, PartialEq)]
+enum LogRecord {
+    /// A successful `compare_and_set` of `key` to `data`.
+    Set { key: String, data: VersionedData },
+    /// A successful `truncate` of the versions of `key` before `seqno`.
+    Truncate { key: String, seqno: SeqNo },
+}
+
+impl LogRecord {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LogRecord::Set { key, data } => Self::encode_set(buf, key, data),
+            LogRecord::Truncate { key, seqno } => Self::encode_frame(buf, |buf| {
+                buf.put_u8(TRUNCATE_TAG);
+                Self::encode_key(buf, key);
+                buf.put_u64_le(seqno.0);
+            }),
+        }
+    }
+
+    fn encode_set(buf: &mut Vec<u8>, key: &str, data: &VersionedData) {
+        Self::encode_frame(buf, |buf| {
+            buf.put_u8(SET_TAG);
+            Self::encode_key(buf, key);
+            buf.put_u64_le(data.seqno.0);
+            buf.put_slice(&data.data);
+        })
+    }
+
+    /// The encoded length of a [LogRecord::Set] of `key` to `data`.
+    fn set_len(key: &str, data: &VersionedData) -> usize {
+        FRAME_HEADER_LEN + 1 + 8 + key.len() + 8 + data.data.len()
+    }
+
+    fn encode_key(buf: &mut Vec<u8>, key: &str) {
+        buf.put_u64_le(u64::cast_from(key.len()));
+        buf.put_slice(key.as_bytes());
+    }
+
+    /// Appends the payload written by `f` to `buf`, preceded by its length
+    /// and checksum.
+    fn encode_frame(buf: &mut Vec<u8>, f: impl FnOnce(&mut Vec<u8>)) {
+        let start = buf.len();
+        buf.put_bytes(0, FRAME_HEADER_LEN);
+        f(buf);
+        let payload = &buf[start + FRAME_HEADER_LEN..];
+        let len = u64::cast_from(payload.len()).to_le_bytes();
+        let crc = crc32fast::hash(payload).to_le_bytes();
+        buf[start..start + 8].copy_from_slice(&len);
+        buf[start + 8..start + FRAME_HEADER_LEN].copy_from_slice(&crc);
+    }
+
+    /// Decodes the record at the start of `buf`, returning it along with its
+    /// encoded length, or None if `buf` ends before the record does.
+    ///
+    /// Returns an error if the record is complete but fails its checksum or
+    /// is malformed.
+    fn decode(mut buf: &[u8]) -> Result<Option<(LogRecord, usize)>, anyhow::Error> {
+        fn take<'a>(buf: &mut &'a [u8], n: usize) -> Option<&'a [u8]> {
+            if buf.len() < n {
+                return None;
+            }
+            let (head, tail) = buf.split_at(n);
+            *buf = tail;
+            Some(head)
+        }
+        fn take_u64(buf: &mut &[u8]) -> Option<u64> {
+            let bytes = take(buf, 8)?;
+            Some(u64::from_le_bytes(bytes.try_into().expect("8 bytes")))
+        }
+
+        let (Some(len), Some(crc)) = (take_u64(&mut buf), take(&mut buf, 4)) else {
+            return Ok(None);
+        };
+        let crc = u32::from_le_bytes(crc.try_into().expect("4 bytes"));
+        let Some(mut payload) = usize::try_from(len)
+            .ok()
+            .and_then(|len| take(&mut buf, len))
+        else {
+            return Ok(None);
+        };
+        if crc32fast::hash(payload) != crc {
+            return Err(anyhow!("record of {} bytes fails its checksum", len));
+        }
+
+        let len = payload.len();
+        let record = (|| {
+            let tag = take(&mut payload, 1)?[0];
+            let key_len = usize::try_from(take_u64(&mut payload)?).ok()?;
+            let key = String::from_utf8(take(&mut payload, key_len)?.to_vec()).ok()?;
+            let seqno = SeqNo(take_u64(&mut payload)?);
+            match tag {
+                SET_TAG => Some(LogRecord::Set {
+                    key,
+                    data: VersionedData {
+                        seqno,
+                        data: Bytes::copy_from_slice(payload),
+                    },
+                }),
+                TRUNCATE_TAG if payload.is_empty() => Some(LogRecord::Truncate { key, seqno }),
+                _ => None,
+            }
+        })()
+        .ok_or_else(|| anyhow!("record of {} bytes is malformed", len))?;
+        Ok(Some((record, FRAME_HEADER_LEN + len)))
+    }
+}
+
+#[async_trait]
+impl Consensus for FileConsensus {
+    fn list_keys(&self) -> ResultStream<String> {
+        Box::pin(try_stream! {
+            let keys = self
+                .run("file_consensus_list_keys", |core| {
+                    Ok(core.data.keys().cloned().collect::<Vec<_>>())
+                })
+                .await?;
+            for key in keys {
+                yield key;
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("file_consensus_head", move |core| {
+            Ok(core
+                .data
+                .get(&key)
+                .and_then(|values| values.last().cloned()))
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(ExternalError::from(
+                        anyhow!("new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                                 new.seqno, expected)));
+            }
+        }
+
+        if new.seqno.0 > i64::MAX.try_into().expect("i64::MAX known to fit in u64") {
+            return Err(ExternalError::from(anyhow!(
+                "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+                new.seqno
+            )));
+        }
+
+        let key = key.to_owned();
+        self.run("file_consensus_compare_and_set", move |core| {
+            let seqno = core
+                .data
+                .get(&key)
+                .and_then(|values| values.last())
+                .map(|data| data.seqno);
+            if seqno != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+
+            core.append(LogRecord::Set { key, data: new })?;
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("file_consensus_scan", move |core| {
+            let values = core.data.get(&key).map_or(&[][..], |values| &values[..]);
+            let from_idx = values.partition_point(|x| x.seqno < from);
+            Ok(values[from_idx..].iter().take(limit).cloned().collect())
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<usize, ExternalError> {
+        let key = key.to_owned();
+        self.run("file_consensus_truncate", move |core| {
+            let values = match core.data.get(&key) {
+                Some(values) if values.last().map_or(false, |data| data.seqno >= seqno) => values,
+                _ => {
+                    return Err(ExternalError::from(anyhow!(
+                        "upper bound too high for truncate: {:?}",
+                        seqno
+                    )))
+                }
+            };
+
+            let deleted = values.partition_point(|x| x.seqno < seqno);
+            if deleted > 0 {
+                core.append(LogRecord::Truncate { key, seqno })?;
+            }
+            Ok(deleted)
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::location::tests::blob_impl_test;
+    use crate::location::tests::{blob_impl_test, consensus_impl_test};
+    use crate::location::SCAN_ALL;
+    use crate::unreliable::{UnreliableConsensus, UnreliableHandle};
 
     use super::*;
 
@@ -296,4 +857,264 @@ mod tests {
 
         Ok(())
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        consensus_impl_test(|| FileConsensus::open(temp_dir.path().join("default").into())).await?;
+
+        // Compact the log after every write.
+        consensus_impl_test(|| {
+            let mut config: FileConsensusConfig = temp_dir.path().join("compacted").into();
+            config.compaction_min_bytes = 0;
+            FileConsensus::open(config)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    fn versioned_data(seqno: u64) -> VersionedData {
+        VersionedData {
+            seqno: SeqNo(seqno),
+            data: Bytes::from(format!("state {seqno}")),
+        }
+    }
+
+    /// Appends a record to the log that was only partially written, as if
+    /// the process writing it crashed.
+    fn append_torn_record(base_dir: &Path, record: &LogRecord) {
+        let mut buf = Vec::new();
+        record.encode(&mut buf);
+        buf.truncate(buf.len() - 1);
+        let mut log = std::fs::OpenOptions::new()
+            .append(true)
+            .open(base_dir.join(CONSENSUS_LOG))
+            .expect("log exists");
+        log.write_all(&buf).expect("write succeeds");
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_shared() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let open = || {
+            let mut config: FileConsensusConfig = temp_dir.path().into();
+            config.compaction_min_bytes = 0;
+            FileConsensus::open(config)
+        };
+        let a = open().await?;
+        let b = open().await?;
+
+        // Writes through one instance are visible through the other, even
+        // once the other has compacted the log.
+        assert_eq!(
+            a.compare_and_set("k", None, versioned_data(1)).await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(b.head("k").await, Ok(Some(versioned_data(1))));
+        assert_eq!(
+            b.compare_and_set("k", Some(SeqNo(1)), versioned_data(2))
+                .await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(b.truncate("k", SeqNo(2)).await, Ok(1));
+        assert_eq!(
+            a.compare_and_set("k", Some(SeqNo(1)), versioned_data(3))
+                .await,
+            Ok(CaSResult::ExpectationMismatch)
+        );
+        assert_eq!(
+            a.scan("k", SeqNo::minimum(), SCAN_ALL).await,
+            Ok(vec![versioned_data(2)])
+        );
+        assert_eq!(
+            a.compare_and_set("k", Some(SeqNo(2)), versioned_data(3))
+                .await,
+            Ok(CaSResult::Committed)
+        );
+
+        // As is everything, once reopened.
+        drop((a, b));
+        let c = open().await?;
+        assert_eq!(
+            c.scan("k", SeqNo::minimum(), SCAN_ALL).await,
+            Ok(vec![versioned_data(2), versioned_data(3)])
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_torn_write() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(
+            consensus
+                .compare_and_set("k", None, versioned_data(1))
+                .await,
+            Ok(CaSResult::Committed)
+        );
+        drop(consensus);
+
+        // A partially written record is discarded, and the log is usable
+        // afterward.
+        append_torn_record(
+            temp_dir.path(),
+            &LogRecord::Set {
+                key: "k".into(),
+                data: versioned_data(2),
+            },
+        );
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(consensus.head("k").await, Ok(Some(versioned_data(1))));
+        assert_eq!(
+            consensus
+                .compare_and_set("k", Some(SeqNo(1)), versioned_data(3))
+                .await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(
+            consensus.scan("k", SeqNo::minimum(), SCAN_ALL).await,
+            Ok(vec![versioned_data(1), versioned_data(3)])
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_corrupt_record() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        for seqno in 1..=2 {
+            let expected = (seqno > 1).then(|| SeqNo(seqno - 1));
+            assert_eq!(
+                consensus
+                    .compare_and_set("k", expected, versioned_data(seqno))
+                    .await,
+                Ok(CaSResult::Committed)
+            );
+        }
+        drop(consensus);
+
+        // A fully written record whose contents are damaged is an error, not
+        // a partial write, whether or not it's the last one, and the log is
+        // left as is.
+        let log_path = temp_dir.path().join(CONSENSUS_LOG);
+        let log = std::fs::read(&log_path).map_err(Error::from)?;
+        let first_len = LogRecord::set_len("k", &versioned_data(1));
+        for idx in [first_len - 1, log.len() - 1] {
+            let mut corrupt = log.clone();
+            corrupt[idx] ^= 0xff;
+            std::fs::write(&log_path, &corrupt).map_err(Error::from)?;
+            assert!(FileConsensus::open(temp_dir.path().into()).await.is_err());
+            assert_eq!(std::fs::read(&log_path).map_err(Error::from)?, corrupt);
+        }
+
+        // Once repaired, all the committed records are there.
+        std::fs::write(&log_path, &log).map_err(Error::from)?;
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(
+            consensus.scan("k", SeqNo::minimum(), SCAN_ALL).await,
+            Ok(vec![versioned_data(1), versioned_data(2)])
+        );
+
+        Ok(())
+    }
+
+    /// Verifies that every acknowledged operation survives crashes while the
+    /// store is intermittently failing, including failures that happen after
+    /// an operation was durably applied.
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_unreliable() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let open = || {
+            let mut config: FileConsensusConfig = temp_dir.path().into();
+            // Small enough that the log is compacted regularly.
+            config.compaction_min_bytes = 1024;
+            FileConsensus::open(config)
+        };
+        // Use a fixed seed so this test doesn't flake.
+        let handle = UnreliableHandle::new(0, 0.8, 0.2);
+        let key = "k";
+
+        let mut consensus = Arc::new(open().await?);
+        // The versions we know to be durable.
+        let mut expected: Vec<VersionedData> = Vec::new();
+        for seqno in 1..=300 {
+            if seqno % 25 == 0 {
+                // Crash in the middle of writing the next version.
+                drop(consensus);
+                append_torn_record(
+                    temp_dir.path(),
+                    &LogRecord::Set {
+                        key: key.into(),
+                        data: versioned_data(seqno),
+                    },
+                );
+                consensus = Arc::new(open().await?);
+            }
+            let unreliable = UnreliableConsensus::new(
+                Arc::clone(&consensus) as Arc<dyn Consensus>,
+                handle.clone(),
+            );
+
+            let new = versioned_data(seqno);
+            let current = expected.last().map(|data| data.seqno);
+            match unreliable.compare_and_set(key, current, new.clone()).await {
+                Ok(CaSResult::Committed) => expected.push(new),
+                Ok(CaSResult::ExpectationMismatch) => panic!("unexpected mismatch at {seqno}"),
+                Err(ExternalError::Determinate(_)) => {}
+                // The write may or may not have happened.
+                Err(ExternalError::Indeterminate(_)) => {
+                    if consensus.head(key).await? == Some(new.clone()) {
+                        expected.push(new);
+                    }
+                }
+            }
+
+            if seqno % 7 == 0 && !expected.is_empty() {
+                let upper = expected[expected.len() / 2].seqno;
+                let before = expected.len();
+                let truncated: Vec<_> = expected
+                    .iter()
+                    .filter(|data| data.seqno >= upper)
+                    .cloned()
+                    .collect();
+                match unreliable.truncate(key, upper).await {
+                    Ok(deleted) => {
+                        assert_eq!(deleted, before - truncated.len());
+                        expected = truncated;
+                    }
+                    Err(ExternalError::Determinate(_)) => {}
+                    // The truncation may or may not have happened.
+                    Err(ExternalError::Indeterminate(_)) => {
+                        let actual = consensus.scan(key, SeqNo::minimum(), SCAN_ALL).await?;
+                        if actual == truncated {
+                            expected = truncated;
+                        }
+                    }
+                }
+            }
+
+            assert_eq!(
+                consensus.scan(key, SeqNo::minimum(), SCAN_ALL).await?,
+                expected
+            );
+        }
+
+        // Everything that was acknowledged survives a final reopen.
+        drop(consensus);
+        let consensus = open().await?;
+        assert_eq!(
+            consensus.scan(key, SeqNo::minimum(), SCAN_ALL).await?,
+            expected
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,843 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A Google Cloud Storage implementation of [Blob] storage.
+
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::future::{join_all, try_join_all};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use mz_ore::bytes::SegmentedBytes;
+use reqwest::header::RANGE;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, trace, warn};
+use url::Url;
+
+use crate::cfg::BlobKnobs;
+use crate::error::Error;
+use crate::http::{self, MB};
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+/// Blobs larger than this are uploaded as multiple parts, concurrently, and
+/// then composed into a single object.
+const COMPOSITE_UPLOAD_THRESHOLD: usize = 8 * MB;
+/// The size of each part (except the last) of a composite upload.
+const DEFAULT_PART_SIZE: usize = 8 * MB;
+/// The largest number of objects that can be composed in one request.
+///
+/// From <https://cloud.google.com/storage/docs/composing-objects>
+const MAX_COMPOSE_SOURCES: usize = 32;
+
+/// The OAuth scope requested for access tokens.
+const SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+/// The URL of the metadata server's access token endpoint, for use when
+/// running on Google Cloud without explicit credentials.
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+/// Access tokens are refreshed once they are this close to expiring.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Configuration for opening a [GcsBlob].
+#[derive(Clone, Debug)]
+pub struct GcsBlobConfig {
+    client: reqwest::Client,
+    endpoint: Url,
+    bucket: String,
+    prefix: String,
+    credentials: Arc<GcsCredentials>,
+}
+
+/// How requests to GCS are authorized.
+enum GcsCredentials {
+    /// Requests are sent without authorization, e.g. to a local emulator.
+    Anonymous,
+    /// Access tokens are obtained by signing JWTs with a service account key.
+    ServiceAccount {
+        key: ServiceAccountKey,
+        token: Mutex<Option<AccessToken>>,
+    },
+    /// Access tokens are obtained from the metadata server of the Google Cloud
+    /// instance this is running on.
+    Metadata { token: Mutex<Option<AccessToken>> },
+}
+
+impl Debug for GcsCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GcsCredentials::Anonymous => f.write_str("Anonymous"),
+            GcsCredentials::ServiceAccount { key, .. } => f
+                .debug_struct("ServiceAccount")
+                .field("client_email", &key.client_email)
+                .finish_non_exhaustive(),
+            GcsCredentials::Metadata { .. } => f.write_str("Metadata"),
+        }
+    }
+}
+
+/// The fields of a service account key file that are needed to authorize
+/// requests.
+#[derive(Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+/// An OAuth access token and when it expires.
+struct AccessToken {
+    token: String,
+    expires_at: Instant,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Serialize)]
+struct JwtClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+impl GcsBlobConfig {
+    const EXTERNAL_TESTS_GCS_BUCKET: &'static str = "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET";
+    const EXTERNAL_TESTS_GCS_ENDPOINT: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_ENDPOINT";
+
+    /// Returns a new [GcsBlobConfig] for use in production.
+    ///
+    /// Stores objects in the given bucket prepended with the (possibly empty)
+    /// prefix. Requests are authorized with the service account key at
+    /// `credentials_path` if one is given. Otherwise, they are sent without
+    /// authorization if `endpoint` is set (e.g. to use a local fake-gcs-server
+    /// emulator), and authorized with the instance's metadata server if not.
+    pub async fn new(
+        bucket: String,
+        prefix: String,
+        endpoint: Option<String>,
+        credentials_path: Option<String>,
+        knobs: Box<dyn BlobKnobs>,
+    ) -> Result<Self, Error> {
+        let credentials = match credentials_path {
+            Some(path) => {
+                let key = tokio::fs::read(&path).await.map_err(|err| {
+                    Error::from(format!("reading gcs credentials {}: {}", path, err))
+                })?;
+                let key: ServiceAccountKey = serde_json::from_slice(&key).map_err(|err| {
+                    Error::from(format!("invalid gcs credentials {}: {}", path, err))
+                })?;
+                // Validate the key up front, rather than on the first request.
+                EncodingKey::from_rsa_pem(key.private_key.as_bytes()).map_err(|err| {
+                    Error::from(format!("invalid gcs credentials {}: {}", path, err))
+                })?;
+                GcsCredentials::ServiceAccount {
+                    key,
+                    token: Mutex::new(None),
+                }
+            }
+            None if endpoint.is_some() => GcsCredentials::Anonymous,
+            None => GcsCredentials::Metadata {
+                token: Mutex::new(None),
+            },
+        };
+
+        let endpoint = endpoint.unwrap_or_else(|| "https://storage.googleapis.com".into());
+        let endpoint = Url::parse(&endpoint)
+            .map_err(|err| Error::from(format!("invalid gcs endpoint {}: {}", endpoint, err)))?;
+        if endpoint.cannot_be_a_base() {
+            return Err(Error::from(format!("invalid gcs endpoint: {}", endpoint)));
+        }
+
+        Ok(GcsBlobConfig {
+            client: http::client(knobs.as_ref())?,
+            endpoint,
+            bucket,
+            prefix,
+            credentials: Arc::new(credentials),
+        })
+    }
+
+    /// Returns a new [GcsBlobConfig] for use in unit tests.
+    ///
+    /// By default, persist tests that use external storage (like GCS) are
+    /// no-ops, so that `cargo test` works on new environments without any
+    /// configuration. To activate the tests for [GcsBlob], set the
+    /// `MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET` environment variable to
+    /// the name of a bucket, and `MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_ENDPOINT`
+    /// to the endpoint of a fake-gcs-server emulator.
+    pub async fn new_for_test() -> Result<Option<Self>, Error> {
+        let bucket = match std::env::var(Self::EXTERNAL_TESTS_GCS_BUCKET) {
+            Ok(bucket) => bucket,
+            Err(_) => return Ok(None),
+        };
+        let endpoint = std::env::var(Self::EXTERNAL_TESTS_GCS_ENDPOINT).map_err(|_| {
+            Error::from(format!(
+                "{} must be set alongside {}",
+                Self::EXTERNAL_TESTS_GCS_ENDPOINT,
+                Self::EXTERNAL_TESTS_GCS_BUCKET
+            ))
+        })?;
+
+        #[derive(Debug)]
+        struct TestBlobKnobs;
+        impl BlobKnobs for TestBlobKnobs {
+            fn operation_timeout(&self) -> Duration {
+                Duration::from_secs(60)
+            }
+
+            fn operation_attempt_timeout(&self) -> Duration {
+                Duration::from_secs(30)
+            }
+
+            fn connect_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn read_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn is_cc_active(&self) -> bool {
+                false
+            }
+        }
+
+        // Give each test a unique prefix so they don't conflict.
+        let prefix = uuid::Uuid::new_v4().to_string();
+        let config = GcsBlobConfig::new(
+            bucket,
+            prefix,
+            Some(endpoint),
+            None,
+            Box::new(TestBlobKnobs),
+        )
+        .await?;
+        Ok(Some(config))
+    }
+}
+
+/// Implementation of [Blob] backed by Google Cloud Storage.
+#[derive(Debug)]
+pub struct GcsBlob {
+    client: reqwest::Client,
+    endpoint: Url,
+    bucket: String,
+    prefix: String,
+    credentials: Arc<GcsCredentials>,
+    // Maximum number of objects we get information about per list request.
+    //
+    // Defaults to 1000, which is the current GCS max.
+    max_results: usize,
+    composite_upload_threshold: usize,
+}
+
+impl GcsBlob {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: GcsBlobConfig) -> Result<Self, ExternalError> {
+        let ret = GcsBlob {
+            client: config.client,
+            endpoint: config.endpoint,
+            bucket: config.bucket,
+            prefix: config.prefix,
+            credentials: config.credentials,
+            max_results: 1_000,
+            composite_upload_threshold: COMPOSITE_UPLOAD_THRESHOLD,
+        };
+        // Connect before returning success. We don't particularly care about
+        // what's stored in this blob (nothing writes to it, so presumably it's
+        // empty) just that we were able and allowed to fetch it.
+        let _ = ret.get("HEALTH_CHECK").await?;
+        Ok(ret)
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.prefix, key)
+    }
+
+    /// Returns the URL of an API path under the endpoint, e.g. of the bucket.
+    fn api_url(&self, segments: &[&str]) -> Url {
+        let mut url = self.endpoint.clone();
+        url.path_segments_mut()
+            .expect("validated in GcsBlobConfig::new")
+            .pop_if_empty()
+            .extend(segments);
+        url
+    }
+
+    /// Returns the URL of the metadata of an object, or of one of its
+    /// sub-resources.
+    fn object_url(&self, name: &str, sub_resource: Option<&str>) -> Url {
+        let mut url = self.api_url(&["storage", "v1", "b", &self.bucket, "o", name]);
+        if let Some(sub_resource) = sub_resource {
+            url.path_segments_mut()
+                .expect("validated in GcsBlobConfig::new")
+                .push(sub_resource);
+        }
+        url
+    }
+
+    /// Adds authorization to a request and sends it.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ExternalError> {
+        let request = match self.access_token().await? {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        request
+            .send()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("gcs request err: {}", err)))
+    }
+
+    /// Returns a current access token, refreshing it if necessary, or None if
+    /// requests are sent without authorization.
+    async fn access_token(&self) -> Result<Option<String>, ExternalError> {
+        let token = match self.credentials.as_ref() {
+            GcsCredentials::Anonymous => return Ok(None),
+            GcsCredentials::ServiceAccount { token, .. } => token,
+            GcsCredentials::Metadata { token } => token,
+        };
+        // Hold the lock while refreshing, so that concurrent requests wait for
+        // a single refresh.
+        let mut token = token.lock().await;
+        if let Some(token) = token.as_ref() {
+            if token.expires_at > Instant::now() + TOKEN_REFRESH_MARGIN {
+                return Ok(Some(token.token.clone()));
+            }
+        }
+
+        let request = match self.credentials.as_ref() {
+            GcsCredentials::Anonymous => unreachable!("returned above"),
+            GcsCredentials::ServiceAccount { key, .. } => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("after the epoch")
+                    .as_secs();
+                let claims = JwtClaims {
+                    iss: &key.client_email,
+                    scope: SCOPE,
+                    aud: &key.token_uri,
+                    iat: now,
+                    exp: now + 3600,
+                };
+                let encoding_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes())
+                    .map_err(|err| anyhow!("invalid gcs credentials: {}", err))?;
+                let assertion =
+                    jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)
+                        .map_err(|err| anyhow!("signing gcs token request: {}", err))?;
+                self.client.post(&key.token_uri).form(&[
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                    ("assertion", &assertion),
+                ])
+            }
+            GcsCredentials::Metadata { .. } => self
+                .client
+                .get(METADATA_TOKEN_URL)
+                .header("Metadata-Flavor", "Google"),
+        };
+        let response = request
+            .send()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("gcs token request err: {}", err)))?;
+        if !response.status().is_success() {
+            return Err(response_error("token", response).await);
+        }
+        let response: TokenResponse = response
+            .json()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("invalid gcs token response: {}", err)))?;
+        *token = Some(AccessToken {
+            token: response.access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+        });
+        Ok(Some(response.access_token))
+    }
+
+    /// Returns the requested range of an object along with its total length,
+    /// or None if it doesn't exist.
+    async fn get_range(
+        &self,
+        url: &Url,
+        range: Range<usize>,
+    ) -> Result<Option<(Bytes, usize)>, ExternalError> {
+        let request = self
+            .client
+            .get(url.clone())
+            .header(RANGE, http::range_header(&range));
+        let response = self.send(request).await?;
+        let len = match response.status() {
+            StatusCode::PARTIAL_CONTENT => http::content_range_len(response.headers())?,
+            StatusCode::OK => usize::try_from(response.content_length().unwrap_or(0))
+                .expect("blob fits in memory"),
+            // Ranges of empty objects can't be satisfied.
+            StatusCode::RANGE_NOT_SATISFIABLE if range.start == 0 => {
+                return Ok(Some((Bytes::new(), 0)))
+            }
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(response_error("get", response).await),
+        };
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("gcs get body err: {}", err)))?;
+        Ok(Some((bytes, len)))
+    }
+
+    /// Returns the metadata of an object, or None if it doesn't exist.
+    async fn metadata(&self, name: &str) -> Result<Option<ObjectMetadata>, ExternalError> {
+        let request = self.client.get(self.object_url(name, None));
+        let response = self.send(request).await?;
+        match response.status() {
+            StatusCode::OK => {}
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(response_error("get metadata", response).await),
+        }
+        let metadata = response.json().await.map_err(|err| {
+            ExternalError::from(anyhow!("invalid gcs metadata response: {}", err))
+        })?;
+        Ok(Some(metadata))
+    }
+
+    async fn upload(&self, name: &str, value: Bytes) -> Result<(), ExternalError> {
+        let mut url = self.api_url(&["upload", "storage", "v1", "b", &self.bucket, "o"]);
+        url.query_pairs_mut()
+            .append_pair("uploadType", "media")
+            .append_pair("name", name);
+        let request = self
+            .client
+            .post(url)
+            .header("Content-Type", "application/octet-stream")
+            .body(value);
+        let response = self.send(request).await?;
+        if !response.status().is_success() {
+            return Err(response_error("upload", response).await);
+        }
+        Ok(())
+    }
+
+    async fn delete_object(&self, name: &str) -> Result<bool, ExternalError> {
+        let request = self.client.delete(self.object_url(name, None));
+        let response = self.send(request).await?;
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => Err(response_error("delete", response).await),
+        }
+    }
+
+    async fn set_single(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start = Instant::now();
+        let value_len = value.len();
+        self.upload(&self.get_path(key), value).await?;
+        debug!("gcs upload done {}b / {:?}", value_len, start.elapsed());
+        Ok(())
+    }
+
+    /// Uploads the object as parts, concurrently, then composes them.
+    ///
+    /// The parts are stored outside of the prefix so that they're never seen
+    /// by [Blob::list_keys_and_metadata], and deleted (on a best-effort basis)
+    /// once they've been composed.
+    async fn set_composite(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start = Instant::now();
+        let parts_prefix = format!("{}.parts/{}", self.prefix, uuid::Uuid::new_v4());
+        let part_size = http::chunk_size(value.len(), DEFAULT_PART_SIZE, MAX_COMPOSE_SOURCES);
+        let parts: Vec<_> = http::chunk_ranges(0, value.len(), part_size)
+            .enumerate()
+            .map(|(idx, range)| (format!("{}/{}", parts_prefix, idx), value.slice(range)))
+            .collect();
+        let num_parts = parts.len();
+
+        let uploaded = try_join_all(
+            parts
+                .iter()
+                .map(|(name, part)| self.upload(name, part.clone())),
+        )
+        .await;
+        trace!(
+            "gcs part uploads took {:?} ({} parts)",
+            start.elapsed(),
+            num_parts
+        );
+        let composed = match uploaded {
+            Ok(_) => self.compose(key, parts.iter().map(|(name, _)| name)).await,
+            Err(err) => Err(err),
+        };
+
+        let deletes = join_all(parts.iter().map(|(name, _)| self.delete_object(name))).await;
+        for delete in deletes {
+            if let Err(err) = delete {
+                warn!("failed to delete part of gcs composite upload: {}", err);
+            }
+        }
+
+        composed?;
+        debug!(
+            "gcs composite upload done {}b / {:?} ({} parts)",
+            value.len(),
+            start.elapsed(),
+            num_parts
+        );
+        Ok(())
+    }
+
+    async fn compose<'a>(
+        &self,
+        key: &str,
+        sources: impl Iterator<Item = &'a String>,
+    ) -> Result<(), ExternalError> {
+        let request = ComposeRequest {
+            source_objects: sources.map(|name| ComposeSource { name }).collect(),
+            destination: ComposeDestination {
+                content_type: "application/octet-stream",
+            },
+        };
+        let url = self.object_url(&self.get_path(key), Some("compose"));
+        let response = self.send(self.client.post(url).json(&request)).await?;
+        if !response.status().is_success() {
+            return Err(response_error("compose", response).await);
+        }
+        Ok(())
+    }
+
+    /// Lists the objects whose names start with `prefix`, one page at a time.
+    async fn list_page(
+        &self,
+        prefix: &str,
+        soft_deleted: bool,
+        page_token: Option<&str>,
+    ) -> Result<ListObjectsPage, ExternalError> {
+        let mut url = self.api_url(&["storage", "v1", "b", &self.bucket, "o"]);
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("prefix", prefix)
+                .append_pair("maxResults", &self.max_results.to_string());
+            if soft_deleted {
+                query.append_pair("softDeleted", "true");
+            }
+            if let Some(page_token) = page_token {
+                query.append_pair("pageToken", page_token);
+            }
+        }
+        let response = self.send(self.client.get(url)).await?;
+        if response.status() != StatusCode::OK {
+            return Err(response_error("list", response).await);
+        }
+        response
+            .json()
+            .await
+            .map_err(|err| ExternalError::from(anyhow!("invalid gcs list response: {}", err)))
+    }
+
+    /// Creates the bucket, if it doesn't already exist.
+    #[cfg(test)]
+    async fn create_bucket(&self) -> Result<(), ExternalError> {
+        let mut url = self.api_url(&["storage", "v1", "b"]);
+        url.query_pairs_mut().append_pair("project", "test");
+        let body = serde_json::json!({ "name": self.bucket });
+        let response = self.send(self.client.post(url).json(&body)).await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::CONFLICT => Ok(()),
+            _ => Err(response_error("create bucket", response).await),
+        }
+    }
+}
+
+#[async_trait]
+impl Blob for GcsBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let mut url = self.object_url(&self.get_path(key), None);
+        url.query_pairs_mut().append_pair("alt", "media");
+        http::get_ranged(|range| self.get_range(&url, range)).await
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        // we only want to return keys that match the specified blob key prefix
+        let blob_key_prefix = self.get_path(key_prefix);
+        // but we want to exclude the shared root prefix from our returned keys,
+        // so only the blob key itself is passed in to `f`
+        let strippable_root_prefix = format!("{}/", self.prefix);
+
+        let mut page_token = None;
+        loop {
+            let page = self
+                .list_page(&blob_key_prefix, false, page_token.as_deref())
+                .await?;
+            for object in page.items {
+                let size_in_bytes = object.size()?;
+                match object.name.strip_prefix(&strippable_root_prefix) {
+                    Some(key) => f(BlobMetadata { key, size_in_bytes }),
+                    None => {
+                        return Err(ExternalError::from(anyhow!(
+                            "found key with invalid prefix: {}",
+                            object.name
+                        )))
+                    }
+                }
+            }
+
+            match page.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        if value.len() > self.composite_upload_threshold {
+            self.set_composite(key, value).await
+        } else {
+            self.set_single(key, value).await
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        // There is a race condition here where, if two delete calls for the
+        // same key occur simultaneously, both might think they did the actual
+        // deletion. This return value is only used for metrics, so it's
+        // unfortunate, but fine.
+        let path = self.get_path(key);
+        let size_bytes = match self.metadata(&path).await? {
+            Some(metadata) => metadata.size()?,
+            None => return Ok(None),
+        };
+        let size_bytes = usize::try_from(size_bytes).expect("blob fits in memory");
+        match self.delete_object(&path).await? {
+            true => Ok(Some(size_bytes)),
+            false => Ok(None),
+        }
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        let path = self.get_path(key);
+        if self.metadata(&path).await?.is_some() {
+            return Ok(());
+        }
+
+        // Deleted objects can only be restored if soft delete is enabled for
+        // the bucket. Restore the most recently deleted generation.
+        let mut generation = None;
+        let mut page_token = None;
+        loop {
+            let page = self.list_page(&path, true, page_token.as_deref()).await?;
+            for object in page.items.into_iter().filter(|object| object.name == path) {
+                let object_generation = object.generation()?;
+                generation = generation.max(Some(object_generation));
+            }
+            match page.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => break,
+            }
+        }
+        let Some(generation) = generation else {
+            return Err(Determinate::new(anyhow!(
+                "unable to restore {} in gcs: no soft-deleted generation exists",
+                key
+            ))
+            .into());
+        };
+
+        let mut url = self.object_url(&path, Some("restore"));
+        url.query_pairs_mut()
+            .append_pair("generation", &generation.to_string());
+        let response = self.send(self.client.post(url)).await?;
+        let status = response.status();
+        if status.is_client_error() {
+            return Err(
+                Determinate::new(anyhow!("unable to restore {} in gcs: {}", key, status)).into(),
+            );
+        }
+        if !status.is_success() {
+            return Err(response_error("restore", response).await);
+        }
+        Ok(())
+    }
+}
+
+/// Returns an error describing an unsuccessful response.
+async fn response_error(op: &str, response: Response) -> ExternalError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    ExternalError::from(anyhow!("gcs {} err: {}: {}", op, status, body))
+}
+
+/// The subset of an object's metadata that's used.
+#[derive(Debug, Deserialize)]
+struct ObjectMetadata {
+    name: String,
+    // NB: The JSON API represents 64-bit integers as strings.
+    size: String,
+    #[serde(default)]
+    generation: Option<String>,
+}
+
+impl ObjectMetadata {
+    fn size(&self) -> Result<u64, ExternalError> {
+        self.size
+            .parse()
+            .map_err(|err| ExternalError::from(anyhow!("invalid gcs object size: {}", err)))
+    }
+
+    fn generation(&self) -> Result<u64, ExternalError> {
+        self.generation
+            .as_deref()
+            .and_then(|generation| generation.parse().ok())
+            .ok_or_else(|| {
+                ExternalError::from(anyhow!(
+                    "invalid gcs object generation: {:?}",
+                    self.generation
+                ))
+            })
+    }
+}
+
+/// A page of the response to a list objects request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListObjectsPage {
+    #[serde(default)]
+    items: Vec<ObjectMetadata>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComposeRequest<'a> {
+    source_objects: Vec<ComposeSource<'a>>,
+    destination: ComposeDestination,
+}
+
+#[derive(Serialize)]
+struct ComposeSource<'a> {
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComposeDestination {
+    content_type: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info;
+
+    use crate::location::tests::blob_impl_test;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    async fn gcs_blob() -> Result<(), ExternalError> {
+        let config = match GcsBlobConfig::new_for_test().await? {
+            Some(config) => config,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    GcsBlobConfig::EXTERNAL_TESTS_GCS_BUCKET
+                );
+                return Ok(());
+            }
+        };
+        let root_prefix = config.prefix.clone();
+
+        // The emulator starts out without any buckets.
+        let blob = GcsBlob {
+            client: config.client.clone(),
+            endpoint: config.endpoint.clone(),
+            bucket: config.bucket.clone(),
+            prefix: format!("{}/setup", root_prefix),
+            credentials: Arc::clone(&config.credentials),
+            max_results: 1_000,
+            composite_upload_threshold: COMPOSITE_UPLOAD_THRESHOLD,
+        };
+        blob.create_bucket().await?;
+
+        let blob_config = config.clone();
+        blob_impl_test(move |path| {
+            let mut config = blob_config.clone();
+            config.prefix = format!("{}/gcs_blob_impl_test/{}", root_prefix, path);
+            async move {
+                let mut blob = GcsBlob::open(config).await?;
+                blob.max_results = 2;
+                Ok(blob)
+            }
+        })
+        .await?;
+
+        // Also specifically test composite uploads, and reading them back in
+        // more than one range.
+        let mut config = config;
+        config.prefix = format!("{}/composite", config.prefix);
+        let mut blob = GcsBlob::open(config).await?;
+        blob.composite_upload_threshold = 0;
+        let value: Bytes = (0..u8::MAX)
+            .cycle()
+            .take(http::READ_CHUNK_SIZE + 1)
+            .collect::<Vec<_>>()
+            .into();
+        blob.set("composite", value.clone()).await?;
+        assert_eq!(
+            blob.get("composite")
+                .await?
+                .map(|value| value.into_contiguous()),
+            Some(value.to_vec())
+        );
+        // The parts were cleaned up.
+        let parts = blob
+            .list_page(&format!("{}.parts/", blob.prefix), false, None)
+            .await?;
+        assert!(parts.items.is_empty());
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn list_objects_page() {
+        let json = r#"{
+            "kind": "storage#objects",
+            "nextPageToken": "token",
+            "items": [
+                {"name": "p/k0", "size": "3", "generation": "1700000000000000"},
+                {"name": "p/k1", "size": "0"}
+            ]
+        }"#;
+        let page: ListObjectsPage = serde_json::from_str(json).unwrap();
+        assert_eq!(page.next_page_token.as_deref(), Some("token"));
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].size(), Ok(3));
+        assert_eq!(page.items[0].generation(), Ok(1_700_000_000_000_000));
+        assert_eq!(page.items[1].name, "p/k1");
+        assert!(page.items[1].generation().is_err());
+
+        let page: ListObjectsPage = serde_json::from_str(r#"{"kind": "storage#objects"}"#).unwrap();
+        assert!(page.items.is_empty());
+        assert!(page.next_page_token.is_none());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Helpers shared by the [Blob](crate::location::Blob) implementations that
+//! talk to an object store's HTTP API directly.
+
+use std::cmp;
+use std::future::Future;
+use std::ops::Range;
+
+use anyhow::anyhow;
+use bytes::Bytes;
+use futures_util::stream::FuturesOrdered;
+use futures_util::StreamExt;
+use mz_ore::bytes::SegmentedBytes;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_RANGE};
+use tracing::debug;
+
+use crate::cfg::BlobKnobs;
+use crate::error::Error;
+use crate::location::ExternalError;
+
+pub(crate) const MB: usize = 1024 * 1024;
+
+/// The size of the ranges that blobs are read in. All but the first range of
+/// a blob are read concurrently.
+pub(crate) const READ_CHUNK_SIZE: usize = 8 * MB;
+
+/// Returns a new HTTP client with the timeouts in `knobs`.
+pub(crate) fn client(knobs: &dyn BlobKnobs) -> Result<reqwest::Client, Error> {
+    reqwest::Client::builder()
+        .connect_timeout(knobs.connect_timeout())
+        .timeout(knobs.operation_attempt_timeout())
+        .build()
+        .map_err(|err| Error::from(format!("building http client: {}", err)))
+}
+
+/// Splits `start..len` into consecutive ranges of at most `chunk_size` bytes.
+pub(crate) fn chunk_ranges(
+    start: usize,
+    len: usize,
+    chunk_size: usize,
+) -> impl Iterator<Item = Range<usize>> {
+    assert!(chunk_size > 0);
+    (start..len)
+        .step_by(chunk_size)
+        .map(move |start| start..cmp::min(start + chunk_size, len))
+}
+
+/// Returns the smallest chunk size of at least `default_chunk_size` that
+/// splits `len` bytes into at most `max_chunks` chunks.
+pub(crate) fn chunk_size(len: usize, default_chunk_size: usize, max_chunks: usize) -> usize {
+    cmp::max((len + max_chunks - 1) / max_chunks, default_chunk_size)
+}
+
+/// Returns the value of a `Range` header that requests `range`.
+pub(crate) fn range_header(range: &Range<usize>) -> HeaderValue {
+    assert!(!range.is_empty());
+    HeaderValue::from_str(&format!("bytes={}-{}", range.start, range.end - 1))
+        .expect("valid header value")
+}
+
+/// Returns the total length of the blob that a ranged read was served from,
+/// according to its `Content-Range` header of the form
+/// `bytes <first>-<last>/<len>`.
+pub(crate) fn content_range_len(headers: &HeaderMap) -> Result<usize, ExternalError> {
+    let value = headers
+        .get(CONTENT_RANGE)
+        .ok_or_else(|| anyhow!("response missing Content-Range"))?;
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.rsplit_once('/'))
+        .and_then(|(_, len)| len.parse().ok())
+        .ok_or_else(|| ExternalError::from(anyhow!("invalid Content-Range: {:?}", value)))
+}
+
+/// Reads a blob with `get_range`, which returns the requested range of the
+/// blob along with the blob's total length, or None if the blob doesn't exist.
+///
+/// The first [READ_CHUNK_SIZE] bytes are read alone to learn the length of the
+/// blob, and the remaining chunks are then read concurrently.
+pub(crate) async fn get_ranged<F, Fut>(
+    get_range: F,
+) -> Result<Option<SegmentedBytes>, ExternalError>
+where
+    F: Fn(Range<usize>) -> Fut,
+    Fut: Future<Output = Result<Option<(Bytes, usize)>, ExternalError>>,
+{
+    let (first, len) = match get_range(0..READ_CHUNK_SIZE).await? {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+
+    let mut chunk_futures = FuturesOrdered::new();
+    for range in chunk_ranges(first.len(), len, READ_CHUNK_SIZE) {
+        let get_range = &get_range;
+        chunk_futures.push_back(async move {
+            let expected_len = range.len();
+            match get_range(range).await? {
+                Some((chunk, _)) if chunk.len() == expected_len => Ok(chunk),
+                Some((chunk, _)) => Err(ExternalError::from(anyhow!(
+                    "expected {} bytes but got {}",
+                    expected_len,
+                    chunk.len()
+                ))),
+                None => Err(ExternalError::from(anyhow!(
+                    "blob was deleted while it was being read"
+                ))),
+            }
+        });
+    }
+    let num_chunks = chunk_futures.len() + 1;
+
+    let mut chunks = vec![first];
+    while let Some(chunk) = chunk_futures.next().await {
+        chunks.push(chunk?);
+    }
+    debug!("read {}b blob in {} chunks", len, num_chunks);
+    Ok(Some(chunks.into_iter().collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn chunks() {
+        assert_eq!(chunk_ranges(0, 0, 10).collect::<Vec<_>>(), vec![]);
+        assert_eq!(chunk_ranges(0, 9, 10).collect::<Vec<_>>(), vec![0..9]);
+        assert_eq!(chunk_ranges(0, 10, 10).collect::<Vec<_>>(), vec![0..10]);
+        assert_eq!(
+            chunk_ranges(0, 21, 10).collect::<Vec<_>>(),
+            vec![0..10, 10..20, 20..21]
+        );
+        assert_eq!(
+            chunk_ranges(10, 25, 10).collect::<Vec<_>>(),
+            vec![10..20, 20..25]
+        );
+        assert_eq!(chunk_ranges(10, 10, 10).collect::<Vec<_>>(), vec![]);
+
+        assert_eq!(chunk_size(0, 10, 4), 10);
+        assert_eq!(chunk_size(40, 10, 4), 10);
+        assert_eq!(chunk_size(41, 10, 4), 11);
+    }
+
+    #[mz_ore::test]
+    fn content_range() {
+        let mut headers = HeaderMap::new();
+        assert!(content_range_len(&headers).is_err());
+        headers.insert(CONTENT_RANGE, HeaderValue::from_static("bytes 0-9/1234"));
+        assert_eq!(content_range_len(&headers), Ok(1234));
+        headers.insert(CONTENT_RANGE, HeaderValue::from_static("bytes */x"));
+        assert!(content_range_len(&headers).is_err());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn get_ranged_reassembles_chunks() -> Result<(), ExternalError> {
+        let blob: Vec<u8> = (0..u8::MAX).cycle().take(2 * READ_CHUNK_SIZE + 7).collect();
+        let get_range = |range: Range<usize>| {
+            let blob = &blob;
+            async move {
+                let end = cmp::min(range.end, blob.len());
+                let chunk = Bytes::copy_from_slice(&blob[range.start..end]);
+                Ok(Some((chunk, blob.len())))
+            }
+        };
+        let got = get_ranged(get_range)
+            .await?
+            .map(|got| got.into_contiguous());
+        assert_eq!(got.as_ref(), Some(&blob));
+
+        let missing = get_ranged(|_| async { Ok(None) }).await?;
+        assert!(missing.is_none());
+        Ok(())
+    }
+}
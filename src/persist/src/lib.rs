@@ -17,10 +17,14 @@
     clippy::clone_on_ref_ptr
 )]
 
+pub mod azure;
 pub mod cfg;
+pub mod encryption;
 pub mod error;
 pub mod file;
+pub mod gcs;
 pub mod gen;
+mod http;
 pub mod indexed;
 pub mod intercept;
 pub mod location;
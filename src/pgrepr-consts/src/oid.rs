@@ -767,3 +767,15 @@ pub const LOG_MZ_COMPUTE_LIR_MAPPING_PER_WORKER_OID: u32 = 17044;
 pub const LOG_MZ_COMPUTE_DATAFLOW_GLOBAL_IDS_PER_WORKER_OID: u32 = 17045;
 pub const VIEW_MZ_LIR_MAPPING_OID: u32 = 17046;
 pub const VIEW_MZ_DATAFLOW_GLOBAL_IDS_OID: u32 = 17047;
+pub const LOG_MZ_COMPUTE_ARRANGEMENT_CHECKPOINTS_PER_WORKER_OID: u32 = 17048;
+pub const SOURCE_MZ_COMPUTE_DATAFLOW_MEMORY_LIMIT_STATUSES_PER_WORKER_OID: u32 = 17049;
+pub const TABLE_MZ_COLUMN_PRIVILEGES_OID: u32 = 17050;
+pub const TABLE_MZ_COLUMN_MASKS_OID: u32 = 17051;
+pub const TABLE_MZ_RESOURCE_GROUPS_OID: u32 = 17052;
+pub const TABLE_MZ_RESOURCE_GROUP_MEMBERS_OID: u32 = 17053;
+pub const TABLE_MZ_RESOURCE_GROUP_STATUSES_OID: u32 = 17054;
+pub const TABLE_MZ_TABLE_RETENTION_POLICIES_OID: u32 = 17055;
+pub const TABLE_MZ_TABLE_RETENTION_STATUSES_OID: u32 = 17056;
+pub const FUNC_MZ_PERSIST_SHARD_PARTS_OID: u32 = 17057;
+pub const FUNC_MZ_PERSIST_SHARD_STATE_OID: u32 = 17058;
+pub const ROLE_MZ_EXTERNAL_ROLE_SYNC_OID: u32 = 17059;
@@ -13,11 +13,13 @@ use std::task::{Context, Poll};
 use async_trait::async_trait;
 use mz_ore::netio::AsyncReady;
 use mz_server_core::TlsMode;
+use openssl::ssl::SslRef;
 use tokio::io::{self, AsyncRead, AsyncWrite, Interest, ReadBuf, Ready};
 use tokio_openssl::SslStream;
 use tokio_postgres::error::SqlState;
+use tracing::warn;
 
-use crate::ErrorResponse;
+use crate::{scram, ErrorResponse};
 
 pub const CONN_UUID_KEY: &str = "mz_connection_uuid";
 pub const MZ_FORWARDED_FOR_KEY: &str = "mz_forwarded_for";
@@ -36,6 +38,33 @@ impl<A> Conn<A> {
         }
     }
 
+    /// Returns the TLS session of this connection, or `None` if the connection
+    /// is not encrypted.
+    pub fn ssl(&self) -> Option<&SslRef> {
+        match self {
+            Conn::Unencrypted(_) => None,
+            Conn::Ssl(stream) => Some(stream.ssl()),
+        }
+    }
+
+    /// Returns the `tls-server-end-point` channel binding data for this
+    /// connection, or `None` if the connection is not encrypted.
+    pub fn channel_binding(&self) -> Option<Vec<u8>> {
+        match self {
+            Conn::Unencrypted(_) => None,
+            Conn::Ssl(stream) => {
+                let cert = stream.ssl().certificate()?;
+                match scram::tls_server_end_point(cert) {
+                    Ok(data) => Some(data),
+                    Err(err) => {
+                        warn!(?err, "unable to compute TLS channel binding");
+                        None
+                    }
+                }
+            }
+        }
+    }
+
     /// Returns an error if tls_mode is incompatible with this connection's stream type.
     pub fn ensure_tls_compatibility(
         &self,
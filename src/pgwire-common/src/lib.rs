@@ -16,6 +16,7 @@ mod codec;
 mod conn;
 mod format;
 mod message;
+pub mod scram;
 mod severity;
 
 pub use codec::{
@@ -182,6 +182,18 @@ pub enum FrontendMessage {
     Password {
         password: String,
     },
+
+    /// The first message of a SASL authentication exchange, which selects the
+    /// mechanism.
+    SaslInitialResponse {
+        mechanism: String,
+        data: Vec<u8>,
+    },
+
+    /// A subsequent message of a SASL authentication exchange.
+    SaslResponse {
+        data: Vec<u8>,
+    },
 }
 
 impl FrontendMessage {
@@ -202,6 +214,8 @@ impl FrontendMessage {
             FrontendMessage::CopyDone => "copy_done",
             FrontendMessage::CopyFail(_) => "copy_fail",
             FrontendMessage::Password { .. } => "password",
+            FrontendMessage::SaslInitialResponse { .. } => "sasl_initial_response",
+            FrontendMessage::SaslResponse { .. } => "sasl_response",
         }
     }
 }
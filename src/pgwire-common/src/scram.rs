@@ -0,0 +1,629 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! SCRAM-SHA-256 password verifiers and the server side of the SCRAM
+//! authentication exchange.
+//!
+//! Passwords are never stored. Instead we store a verifier in the same format
+//! as PostgreSQL's `pg_authid.rolpassword`:
+//!
+//! ```text
+//! SCRAM-SHA-256$<iterations>:<salt>$<StoredKey>:<ServerKey>
+//! ```
+//!
+//! where the salt and keys are base64 encoded. See [RFC 5802] and [RFC 7677]
+//! for the protocol, and [RFC 5929] for the `tls-server-end-point` channel
+//! binding type used by `SCRAM-SHA-256-PLUS`.
+//!
+//! Unlike PostgreSQL, passwords are not normalized with SASLprep before
+//! hashing. This only matters for passwords containing non-ASCII characters
+//! that SASLprep would rewrite.
+//!
+//! [RFC 5802]: https://datatracker.ietf.org/doc/html/rfc5802
+//! [RFC 7677]: https://datatracker.ietf.org/doc/html/rfc7677
+//! [RFC 5929]: https://datatracker.ietf.org/doc/html/rfc5929
+
+use std::error::Error;
+use std::fmt;
+use std::str;
+
+use openssl::error::ErrorStack;
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use openssl::x509::X509Ref;
+
+/// The name of the SCRAM-SHA-256 SASL mechanism.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+/// The name of the SCRAM-SHA-256 SASL mechanism with channel binding.
+pub const SCRAM_SHA_256_PLUS: &str = "SCRAM-SHA-256-PLUS";
+
+/// The number of PBKDF2 iterations used for new verifiers. Matches the
+/// PostgreSQL default for `scram_iterations`.
+const DEFAULT_ITERATIONS: u32 = 4096;
+/// The length of the random salt used for new verifiers, in bytes.
+const SALT_LEN: usize = 16;
+/// The length of the random server nonce, in bytes, before base64 encoding.
+const NONCE_LEN: usize = 18;
+/// The only channel binding type we support.
+const CHANNEL_BINDING_TYPE: &str = "tls-server-end-point";
+
+/// A parsed SCRAM-SHA-256 password verifier.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ScramVerifier {
+    iterations: u32,
+    salt: Vec<u8>,
+    stored_key: Vec<u8>,
+    server_key: Vec<u8>,
+}
+
+impl ScramVerifier {
+    /// Computes a verifier for `password` with a fresh random salt.
+    pub fn new(password: &str) -> Result<ScramVerifier, ErrorStack> {
+        let mut salt = vec![0; SALT_LEN];
+        openssl::rand::rand_bytes(&mut salt)?;
+        ScramVerifier::with_salt(password, salt, DEFAULT_ITERATIONS)
+    }
+
+    fn with_salt(
+        password: &str,
+        salt: Vec<u8>,
+        iterations: u32,
+    ) -> Result<ScramVerifier, ErrorStack> {
+        let salted_password = salted_password(password, &salt, iterations)?;
+        let client_key = hmac(&salted_password, b"Client Key")?;
+        let stored_key = openssl::sha::sha256(&client_key).to_vec();
+        let server_key = hmac(&salted_password, b"Server Key")?;
+        Ok(ScramVerifier {
+            iterations,
+            salt,
+            stored_key,
+            server_key,
+        })
+    }
+
+    /// Parses a verifier in the format produced by this type's `Display`
+    /// implementation. Returns `None` if `s` is not a valid verifier.
+    pub fn parse(s: &str) -> Option<ScramVerifier> {
+        let rest = s.strip_prefix(SCRAM_SHA_256)?.strip_prefix('$')?;
+        let (params, keys) = rest.split_once('$')?;
+        let (iterations, salt) = params.split_once(':')?;
+        let (stored_key, server_key) = keys.split_once(':')?;
+        let verifier = ScramVerifier {
+            iterations: iterations.parse().ok()?,
+            salt: openssl::base64::decode_block(salt).ok()?,
+            stored_key: openssl::base64::decode_block(stored_key).ok()?,
+            server_key: openssl::base64::decode_block(server_key).ok()?,
+        };
+        if verifier.iterations == 0
+            || verifier.stored_key.len() != 32
+            || verifier.server_key.len() != 32
+        {
+            return None;
+        }
+        Some(verifier)
+    }
+
+    /// Reports whether `password` matches this verifier.
+    ///
+    /// This is used by authentication methods that receive the plaintext
+    /// password, like HTTP basic authentication.
+    pub fn verify_password(&self, password: &str) -> bool {
+        match ScramVerifier::with_salt(password, self.salt.clone(), self.iterations) {
+            Ok(computed) => {
+                openssl::memcmp::eq(&computed.stored_key, &self.stored_key)
+                    && openssl::memcmp::eq(&computed.server_key, &self.server_key)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}${}:{}${}:{}",
+            SCRAM_SHA_256,
+            self.iterations,
+            openssl::base64::encode_block(&self.salt),
+            openssl::base64::encode_block(&self.stored_key),
+            openssl::base64::encode_block(&self.server_key),
+        )
+    }
+}
+
+impl fmt::Debug for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScramVerifier")
+            .field("iterations", &self.iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Computes the `tls-server-end-point` channel binding data for the server
+/// certificate `cert`, as described in [RFC 5929, section 4.1].
+///
+/// [RFC 5929, section 4.1]: https://datatracker.ietf.org/doc/html/rfc5929#section-4.1
+pub fn tls_server_end_point(cert: &X509Ref) -> Result<Vec<u8>, ErrorStack> {
+    // The certificate is hashed with the hash function used in its signature,
+    // except that MD5 and SHA-1 are upgraded to SHA-256.
+    let digest = cert
+        .signature_algorithm()
+        .object()
+        .nid()
+        .signature_algorithms()
+        .and_then(|algs| match algs.digest {
+            Nid::MD5 | Nid::SHA1 => None,
+            nid => MessageDigest::from_nid(nid),
+        })
+        .unwrap_or_else(MessageDigest::sha256);
+    Ok(cert.digest(digest)?.to_vec())
+}
+
+/// An error that occurred during a SCRAM exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScramError {
+    /// The client sent a message that does not conform to the protocol.
+    Protocol(String),
+    /// The client's proof did not match the stored verifier.
+    InvalidProof,
+    /// An unexpected OpenSSL failure.
+    Internal(String),
+}
+
+impl Error for ScramError {}
+
+impl fmt::Display for ScramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScramError::Protocol(msg) => write!(f, "malformed SCRAM message: {msg}"),
+            ScramError::InvalidProof => f.write_str("invalid password"),
+            ScramError::Internal(msg) => write!(f, "internal SCRAM error: {msg}"),
+        }
+    }
+}
+
+impl From<ErrorStack> for ScramError {
+    fn from(e: ErrorStack) -> ScramError {
+        ScramError::Internal(e.to_string())
+    }
+}
+
+fn protocol_err<T>(msg: impl Into<String>) -> Result<T, ScramError> {
+    Err(ScramError::Protocol(msg.into()))
+}
+
+/// The server side of a single SCRAM-SHA-256 authentication exchange.
+///
+/// The exchange consists of two round trips:
+///
+///   1. [`ScramServer::handle_client_first`] consumes the client-first-message
+///      and produces the server-first-message.
+///   2. [`ScramServer::handle_client_final`] consumes the client-final-message,
+///      verifies the client's proof, and produces the server-final-message.
+#[derive(Debug)]
+pub struct ScramServer {
+    verifier: ScramVerifier,
+    /// The `tls-server-end-point` binding data for the connection, if the
+    /// connection uses TLS.
+    channel_binding: Option<Vec<u8>>,
+    state: ScramState,
+}
+
+#[derive(Debug)]
+enum ScramState {
+    Initial,
+    SentServerFirst {
+        gs2_header: String,
+        binds_channel: bool,
+        client_first_bare: String,
+        server_first: String,
+        nonce: String,
+    },
+    Done,
+}
+
+impl ScramServer {
+    /// Creates the server side of an exchange that authenticates against
+    /// `verifier`. `channel_binding` should contain the
+    /// [`tls_server_end_point`] data when the connection uses TLS.
+    pub fn new(verifier: ScramVerifier, channel_binding: Option<Vec<u8>>) -> ScramServer {
+        ScramServer {
+            verifier,
+            channel_binding,
+            state: ScramState::Initial,
+        }
+    }
+
+    /// Returns the SASL mechanisms to advertise to the client, in order of
+    /// preference.
+    pub fn mechanisms(&self) -> &'static [&'static str] {
+        if self.channel_binding.is_some() {
+            &[SCRAM_SHA_256_PLUS, SCRAM_SHA_256]
+        } else {
+            &[SCRAM_SHA_256]
+        }
+    }
+
+    /// Processes the client-first-message sent by the client with the chosen
+    /// `mechanism`, returning the server-first-message.
+    pub fn handle_client_first(
+        &mut self,
+        mechanism: &str,
+        message: &[u8],
+    ) -> Result<Vec<u8>, ScramError> {
+        let mut server_nonce = [0; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut server_nonce)?;
+        let server_nonce = openssl::base64::encode_block(&server_nonce);
+        self.handle_client_first_with_nonce(mechanism, message, &server_nonce)
+    }
+
+    fn handle_client_first_with_nonce(
+        &mut self,
+        mechanism: &str,
+        message: &[u8],
+        server_nonce: &str,
+    ) -> Result<Vec<u8>, ScramError> {
+        if !matches!(self.state, ScramState::Initial) {
+            return protocol_err("unexpected client-first-message");
+        }
+        if !self.mechanisms().contains(&mechanism) {
+            return protocol_err(format!("unsupported SASL mechanism {mechanism}"));
+        }
+        let Ok(message) = str::from_utf8(message) else {
+            return protocol_err("client-first-message is not valid UTF-8");
+        };
+
+        // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+        let mut parts = message.splitn(3, ',');
+        let (Some(cbind_flag), Some(authzid), Some(client_first_bare)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return protocol_err("missing GS2 header");
+        };
+        let binds_channel = match cbind_flag {
+            "n" => false,
+            // The client supports channel binding but thinks we don't. If we
+            // advertised channel binding, something stripped it in transit.
+            "y" if self.channel_binding.is_some() => {
+                return protocol_err("client does not support channel binding but server does");
+            }
+            "y" => false,
+            flag => match flag.strip_prefix("p=") {
+                Some(CHANNEL_BINDING_TYPE) => true,
+                Some(typ) => return protocol_err(format!("unsupported channel binding {typ}")),
+                None => return protocol_err(format!("invalid channel binding flag {flag}")),
+            },
+        };
+        if binds_channel != (mechanism == SCRAM_SHA_256_PLUS) {
+            return protocol_err(format!(
+                "channel binding flag does not match mechanism {mechanism}"
+            ));
+        }
+        if !authzid.is_empty() {
+            return protocol_err("authorization identities are not supported");
+        }
+
+        // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
+        //
+        // Like PostgreSQL, we ignore the username in favor of the one from the
+        // startup message.
+        let mut attrs = client_first_bare.split(',');
+        if attrs.next().and_then(|a| a.strip_prefix("n=")).is_none() {
+            return protocol_err("missing username");
+        }
+        let Some(client_nonce) = attrs.next().and_then(|a| a.strip_prefix("r=")) else {
+            return protocol_err("missing nonce");
+        };
+        if client_nonce.is_empty() || !client_nonce.bytes().all(is_printable) {
+            return protocol_err("invalid nonce");
+        }
+
+        let nonce = format!("{client_nonce}{server_nonce}");
+        let server_first = format!(
+            "r={nonce},s={},i={}",
+            openssl::base64::encode_block(&self.verifier.salt),
+            self.verifier.iterations,
+        );
+
+        let gs2_header_len = cbind_flag.len() + authzid.len() + 2;
+        self.state = ScramState::SentServerFirst {
+            gs2_header: message[..gs2_header_len].to_owned(),
+            binds_channel,
+            client_first_bare: client_first_bare.to_owned(),
+            server_first: server_first.clone(),
+            nonce,
+        };
+        Ok(server_first.into_bytes())
+    }
+
+    /// Processes the client-final-message, returning the server-final-message
+    /// if the client proved knowledge of the password.
+    pub fn handle_client_final(&mut self, message: &[u8]) -> Result<Vec<u8>, ScramError> {
+        let ScramState::SentServerFirst {
+            gs2_header,
+            binds_channel,
+            client_first_bare,
+            server_first,
+            nonce,
+        } = std::mem::replace(&mut self.state, ScramState::Done)
+        else {
+            return protocol_err("unexpected client-final-message");
+        };
+        let Ok(message) = str::from_utf8(message) else {
+            return protocol_err("client-final-message is not valid UTF-8");
+        };
+
+        // client-final-message = channel-binding "," nonce ["," extensions] "," proof
+        let Some((without_proof, proof)) = message.rsplit_once(',') else {
+            return protocol_err("missing proof");
+        };
+        let Some(proof) = proof.strip_prefix("p=") else {
+            return protocol_err("missing proof");
+        };
+        let mut attrs = without_proof.split(',');
+        let Some(channel_binding) = attrs.next().and_then(|a| a.strip_prefix("c=")) else {
+            return protocol_err("missing channel binding");
+        };
+        let Some(client_nonce) = attrs.next().and_then(|a| a.strip_prefix("r=")) else {
+            return protocol_err("missing nonce");
+        };
+
+        let mut expected_binding = gs2_header.into_bytes();
+        if binds_channel {
+            match &self.channel_binding {
+                Some(data) => expected_binding.extend(data),
+                None => return protocol_err("channel binding is not available"),
+            }
+        }
+        let Ok(channel_binding) = openssl::base64::decode_block(channel_binding) else {
+            return protocol_err("invalid channel binding encoding");
+        };
+        if channel_binding != expected_binding {
+            return protocol_err("channel binding mismatch");
+        }
+        if client_nonce != nonce {
+            return protocol_err("nonce mismatch");
+        }
+        let Ok(proof) = openssl::base64::decode_block(proof) else {
+            return protocol_err("invalid proof encoding");
+        };
+        if proof.len() != self.verifier.stored_key.len() {
+            return Err(ScramError::InvalidProof);
+        }
+
+        // ClientKey = ClientProof XOR HMAC(StoredKey, AuthMessage), and the
+        // client is authenticated iff H(ClientKey) = StoredKey.
+        let auth_message = format!("{client_first_bare},{server_first},{without_proof}");
+        let client_signature = hmac(&self.verifier.stored_key, auth_message.as_bytes())?;
+        let client_key: Vec<u8> = proof
+            .iter()
+            .zip(client_signature)
+            .map(|(p, s)| p ^ s)
+            .collect();
+        let computed_stored_key = openssl::sha::sha256(&client_key);
+        if !openssl::memcmp::eq(&computed_stored_key, &self.verifier.stored_key) {
+            return Err(ScramError::InvalidProof);
+        }
+
+        let server_signature = hmac(&self.verifier.server_key, auth_message.as_bytes())?;
+        Ok(format!("v={}", openssl::base64::encode_block(&server_signature)).into_bytes())
+    }
+}
+
+/// Reports whether `b` is a printable character as defined by RFC 5802, which
+/// excludes the attribute separator `,`.
+fn is_printable(b: u8) -> bool {
+    (0x21..=0x7e).contains(&b) && b != b','
+}
+
+fn salted_password(password: &str, salt: &[u8], iterations: u32) -> Result<Vec<u8>, ErrorStack> {
+    let mut salted_password = vec![0; 32];
+    openssl::pkcs5::pbkdf2_hmac(
+        password.as_bytes(),
+        salt,
+        usize::try_from(iterations).expect("u32 fits in usize"),
+        MessageDigest::sha256(),
+        &mut salted_password,
+    )?;
+    Ok(salted_password)
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Result<Vec<u8>, ErrorStack> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(data)?;
+    signer.sign_to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The verifier for the password `pencil` from the test vector in RFC 7677.
+    const RFC_7677_VERIFIER: &str = "SCRAM-SHA-256$4096:W22ZaJ0SNY7soEsUEjb6gQ==$\
+        WG5d8oPm3OtcPnkdi4Uo7BkeZkBFzpcXkuLmtbsT4qY=:\
+        wfPLwcE6nTWhTAmQ7tl2KeoiWGPlZqQxSrmfPwDl2dU=";
+
+    /// Runs the client side of an exchange against `server`, following
+    /// RFC 5802, section 3.
+    fn client_exchange(
+        server: &mut ScramServer,
+        mechanism: &str,
+        gs2_header: &str,
+        client_binding: &[u8],
+        password: &str,
+    ) -> Result<(), ScramError> {
+        let client_first_bare = "n=,r=rOprNGfwEbeRWgbNEkqO";
+        let client_first = format!("{gs2_header}{client_first_bare}");
+        let server_first = server.handle_client_first(mechanism, client_first.as_bytes())?;
+        let server_first = String::from_utf8(server_first).unwrap();
+
+        let mut attrs = server_first.split(',');
+        let nonce = attrs.next().unwrap().strip_prefix("r=").unwrap();
+        assert!(nonce.starts_with("rOprNGfwEbeRWgbNEkqO"));
+        let salt = attrs.next().unwrap().strip_prefix("s=").unwrap();
+        let salt = openssl::base64::decode_block(salt).unwrap();
+        let iterations = attrs.next().unwrap().strip_prefix("i=").unwrap();
+        let iterations = iterations.parse().unwrap();
+
+        let mut binding = gs2_header.as_bytes().to_vec();
+        binding.extend(client_binding);
+        let without_proof = format!("c={},r={nonce}", openssl::base64::encode_block(&binding));
+        let auth_message = format!("{client_first_bare},{server_first},{without_proof}");
+        let salted = salted_password(password, &salt, iterations).unwrap();
+        let client_key = hmac(&salted, b"Client Key").unwrap();
+        let stored_key = openssl::sha::sha256(&client_key);
+        let client_signature = hmac(&stored_key, auth_message.as_bytes()).unwrap();
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature)
+            .map(|(k, s)| k ^ s)
+            .collect();
+        let client_final = format!(
+            "{without_proof},p={}",
+            openssl::base64::encode_block(&proof)
+        );
+        let server_final = server.handle_client_final(client_final.as_bytes())?;
+
+        let server_key = hmac(&salted, b"Server Key").unwrap();
+        let server_signature = hmac(&server_key, auth_message.as_bytes()).unwrap();
+        assert_eq!(
+            String::from_utf8(server_final).unwrap(),
+            format!("v={}", openssl::base64::encode_block(&server_signature)),
+        );
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl`
+    fn test_verifier() {
+        let verifier = ScramVerifier::new("pencil").unwrap();
+        assert!(verifier.verify_password("pencil"));
+        assert!(!verifier.verify_password("pen"));
+
+        let s = verifier.to_string();
+        assert!(s.starts_with("SCRAM-SHA-256$4096:"));
+        assert_eq!(ScramVerifier::parse(&s), Some(verifier));
+
+        // The key derivation matches the test vector from RFC 7677.
+        let salt = openssl::base64::decode_block("W22ZaJ0SNY7soEsUEjb6gQ==").unwrap();
+        let verifier = ScramVerifier::with_salt("pencil", salt, 4096).unwrap();
+        assert_eq!(verifier.to_string(), RFC_7677_VERIFIER);
+
+        for invalid in [
+            "",
+            "hunter2",
+            "SCRAM-SHA-256$4096:c2FsdA==",
+            "SCRAM-SHA-256$0:c2FsdA==$AAAA:AAAA",
+            "md5a3556571e93b0d20722ba62be61e8c2d",
+        ] {
+            assert_eq!(ScramVerifier::parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl`
+    fn test_rfc_7677_exchange() {
+        let verifier = ScramVerifier::parse(RFC_7677_VERIFIER).unwrap();
+        let mut server = ScramServer::new(verifier, None);
+        let server_first = server
+            .handle_client_first_with_nonce(
+                SCRAM_SHA_256,
+                b"n,,n=user,r=rOprNGfwEbeRWgbNEkqO",
+                "%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0",
+            )
+            .unwrap();
+        assert_eq!(
+            server_first,
+            b"r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096",
+        );
+        let server_final = server
+            .handle_client_final(
+                b"c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,\
+                  p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=",
+            )
+            .unwrap();
+        assert_eq!(
+            server_final,
+            b"v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=",
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl`
+    fn test_exchange() {
+        let verifier = ScramVerifier::new("pencil").unwrap();
+
+        let mut server = ScramServer::new(verifier.clone(), None);
+        assert_eq!(server.mechanisms(), &[SCRAM_SHA_256]);
+        client_exchange(&mut server, SCRAM_SHA_256, "n,,", b"", "pencil").unwrap();
+
+        let mut server = ScramServer::new(verifier.clone(), None);
+        assert_eq!(
+            client_exchange(&mut server, SCRAM_SHA_256, "n,,", b"", "pen"),
+            Err(ScramError::InvalidProof),
+        );
+
+        // Channel binding.
+        let cb = b"certificate hash".to_vec();
+        let mut server = ScramServer::new(verifier.clone(), Some(cb.clone()));
+        assert_eq!(server.mechanisms(), &[SCRAM_SHA_256_PLUS, SCRAM_SHA_256]);
+        client_exchange(
+            &mut server,
+            SCRAM_SHA_256_PLUS,
+            "p=tls-server-end-point,,",
+            &cb,
+            "pencil",
+        )
+        .unwrap();
+
+        // Clients may decline channel binding when the server offers it.
+        let mut server = ScramServer::new(verifier.clone(), Some(cb.clone()));
+        client_exchange(&mut server, SCRAM_SHA_256, "n,,", b"", "pencil").unwrap();
+
+        // Binding to a different certificate fails.
+        let mut server = ScramServer::new(verifier.clone(), Some(cb));
+        assert!(matches!(
+            client_exchange(
+                &mut server,
+                SCRAM_SHA_256_PLUS,
+                "p=tls-server-end-point,,",
+                b"another certificate",
+                "pencil",
+            ),
+            Err(ScramError::Protocol(_)),
+        ));
+
+        // A client that believes the server lacks channel binding is rejected
+        // when the server supports it, as this indicates a downgrade attack.
+        let mut server = ScramServer::new(verifier.clone(), Some(b"cb".to_vec()));
+        assert!(matches!(
+            client_exchange(&mut server, SCRAM_SHA_256, "y,,", b"", "pencil"),
+            Err(ScramError::Protocol(_)),
+        ));
+        let mut server = ScramServer::new(verifier.clone(), None);
+        client_exchange(&mut server, SCRAM_SHA_256, "y,,", b"", "pencil").unwrap();
+
+        // Channel binding is unavailable without TLS.
+        let mut server = ScramServer::new(verifier, None);
+        assert!(matches!(
+            client_exchange(
+                &mut server,
+                SCRAM_SHA_256_PLUS,
+                "p=tls-server-end-point,,",
+                b"",
+                "pencil",
+            ),
+            Err(ScramError::Protocol(_)),
+        ));
+    }
+}
@@ -143,6 +143,12 @@ where
         self.inner.get_mut().codec_mut().encode_state = encode_state;
     }
 
+    /// Sets how the codec decodes the client's authentication messages, which
+    /// depends on the authentication method requested by the server.
+    pub fn set_auth_decode_state(&mut self, auth_decode_state: AuthDecodeState) {
+        self.inner.get_mut().codec_mut().auth_decode_state = auth_decode_state;
+    }
+
     /// Waits for the connection to be closed.
     ///
     /// Returns a "connection closed" error when the connection is closed. If
@@ -203,9 +209,24 @@ where
     }
 }
 
+/// Describes how password (`p`) messages are decoded.
+///
+/// The same message type is used for every authentication method, so its
+/// format depends on which authentication request the server last sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthDecodeState {
+    /// A cleartext password.
+    Password,
+    /// The first message of a SASL exchange.
+    SaslInitialResponse,
+    /// A subsequent message of a SASL exchange.
+    SaslResponse,
+}
+
 struct Codec {
     decode_state: DecodeState,
     encode_state: Vec<(mz_pgrepr::Type, mz_pgwire_common::Format)>,
+    auth_decode_state: AuthDecodeState,
 }
 
 impl Codec {
@@ -214,6 +235,7 @@ impl Codec {
         Codec {
             decode_state: DecodeState::Head,
             encode_state: vec![],
+            auth_decode_state: AuthDecodeState::Password,
         }
     }
 }
@@ -232,6 +254,9 @@ impl Encoder<BackendMessage> for Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationSasl { .. } => b'R',
+            BackendMessage::AuthenticationSaslContinue(_) => b'R',
+            BackendMessage::AuthenticationSaslFinal(_) => b'R',
             BackendMessage::RowDescription(_) => b'T',
             BackendMessage::DataRow(_) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
@@ -289,6 +314,21 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
+            BackendMessage::AuthenticationSasl { mechanisms } => {
+                dst.put_u32(10);
+                for mechanism in mechanisms {
+                    dst.put_string(mechanism);
+                }
+                dst.put_u8(b'\0');
+            }
+            BackendMessage::AuthenticationSaslContinue(data) => {
+                dst.put_u32(11);
+                dst.put_slice(&data);
+            }
+            BackendMessage::AuthenticationSaslFinal(data) => {
+                dst.put_u32(12);
+                dst.put_slice(&data);
+            }
             BackendMessage::RowDescription(fields) => {
                 dst.put_length_i16(fields.len())?;
                 for f in &fields {
@@ -446,7 +486,13 @@ impl Decoder for Codec {
                         b'X' => decode_terminate(buf)?,
 
                         // Authentication.
-                        b'p' => decode_password(buf)?,
+                        b'p' => match self.auth_decode_state {
+                            AuthDecodeState::Password => decode_password(buf)?,
+                            AuthDecodeState::SaslInitialResponse => {
+                                decode_sasl_initial_response(buf)?
+                            }
+                            AuthDecodeState::SaslResponse => decode_sasl_response(buf, frame_len)?,
+                        },
 
                         // Copy from flow.
                         b'f' => decode_copy_fail(buf)?,
@@ -481,6 +527,25 @@ fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
     })
 }
 
+fn decode_sasl_initial_response(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+    let mechanism = buf.read_cstr()?.to_owned();
+    // A length of -1 indicates that there is no initial response.
+    let len = buf.read_i32()?;
+    let mut data = vec![];
+    for _ in 0..len {
+        data.push(buf.read_byte()?);
+    }
+    Ok(FrontendMessage::SaslInitialResponse { mechanism, data })
+}
+
+fn decode_sasl_response(mut buf: Cursor, frame_len: usize) -> Result<FrontendMessage, io::Error> {
+    let mut data = Vec::with_capacity(frame_len);
+    for _ in 0..frame_len {
+        data.push(buf.read_byte()?);
+    }
+    Ok(FrontendMessage::SaslResponse { data })
+}
+
 fn decode_query(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Query {
         sql: buf.read_cstr()?.to_string(),
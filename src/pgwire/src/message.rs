@@ -21,6 +21,11 @@ use mz_repr::{ColumnName, RelationDesc};
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    AuthenticationSasl {
+        mechanisms: &'static [&'static str],
+    },
+    AuthenticationSaslContinue(Vec<u8>),
+    AuthenticationSaslFinal(Vec<u8>),
     CommandComplete {
         tag: String,
     },
@@ -14,6 +14,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use std::{iter, mem};
 
+use anyhow::anyhow;
 use byteorder::{ByteOrder, NetworkEndian};
 use futures::future::{pending, BoxFuture, FutureExt};
 use itertools::izip;
@@ -27,16 +28,18 @@ use mz_adapter::{
     PeekResponseUnary, RowsFuture,
 };
 use mz_frontegg_auth::Authenticator as FronteggAuthentication;
+use mz_oidc_auth::Authenticator as OidcAuthentication;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
 use mz_ore::{assert_none, assert_ok, instrument};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
+use mz_pgwire_common::scram::{ScramError, ScramServer, ScramVerifier};
 use mz_pgwire_common::{ErrorResponse, Format, FrontendMessage, Severity, VERSIONS, VERSION_3};
 use mz_repr::{
     Datum, GlobalId, RelationDesc, RelationType, RowArena, RowIterator, RowRef, ScalarType,
 };
-use mz_server_core::TlsMode;
+use mz_server_core::{ClientCertRoleSource, TlsMode};
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{CopyDirection, CopyStatement, FetchDirection, Ident, Raw, Statement};
 use mz_sql::parse::StatementParseResult;
@@ -53,7 +56,7 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, debug_span, warn, Instrument};
 use uuid::Uuid;
 
-use crate::codec::FramedConn;
+use crate::codec::{AuthDecodeState, FramedConn};
 use crate::message::{self, BackendMessage};
 
 /// Reports whether the given stream begins with a pgwire handshake.
@@ -80,6 +83,9 @@ pub fn match_handshake(buf: &[u8]) -> bool {
 pub struct RunParams<'a, A> {
     /// The TLS mode of the pgwire server.
     pub tls_mode: Option<TlsMode>,
+    /// How to authenticate clients by their TLS client certificate, if the
+    /// pgwire server requires client certificates.
+    pub client_auth: Option<ClientCertRoleSource>,
     /// A client for the adapter.
     pub adapter_client: mz_adapter::Client,
     /// The connection to the client.
@@ -92,6 +98,8 @@ pub struct RunParams<'a, A> {
     pub params: BTreeMap<String, String>,
     /// Frontegg authentication.
     pub frontegg: Option<&'a FronteggAuthentication>,
+    /// OIDC authentication.
+    pub oidc: Option<&'a OidcAuthentication>,
     /// Whether this is an internal server that permits access to restricted
     /// system resources.
     pub internal: bool,
@@ -114,12 +122,14 @@ pub struct RunParams<'a, A> {
 pub async fn run<'a, A>(
     RunParams {
         tls_mode,
+        client_auth,
         adapter_client,
         conn,
         conn_uuid,
         version,
         mut params,
         frontegg,
+        oidc,
         internal,
         active_connection_count,
         helm_chart_version,
@@ -161,7 +171,55 @@ where
         return conn.send(err).await;
     }
 
-    let (mut session, expired) = if let Some(frontegg) = frontegg {
+    let (mut session, expired) = if let Some(role_source) = client_auth {
+        // The client certificate was verified against the trusted certificate
+        // authorities during the TLS handshake. It authenticates the client in
+        // place of a password, but only as the role that it names.
+        let certificate = conn
+            .inner()
+            .ssl()
+            .ok_or_else(|| anyhow!("client did not negotiate TLS"))
+            .and_then(|ssl| role_source.client_certificate(ssl))
+            .and_then(|certificate| {
+                if certificate.role == user {
+                    Ok(certificate)
+                } else {
+                    Err(anyhow!(
+                        "client certificate {} authenticates role {}",
+                        certificate.subject,
+                        certificate.role
+                    ))
+                }
+            });
+        let certificate = match certificate {
+            Ok(certificate) => certificate,
+            Err(err) => {
+                warn!(?err, "pgwire connection failed certificate authentication");
+                adapter_client.audit_login_failure(&user, *conn.peer_addr(), err.to_string());
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                        format!(
+                            "certificate authentication failed for user {}",
+                            user.quoted()
+                        ),
+                    ))
+                    .await;
+            }
+        };
+        let session = adapter_client.new_session(SessionConfig {
+            conn_id: conn.conn_id().clone(),
+            uuid: conn_uuid,
+            user,
+            client_ip: conn.peer_addr().clone(),
+            external_metadata_rx: None,
+            helm_chart_version,
+            client_certificate_subject: Some(certificate.subject),
+        });
+        // Certificates are only checked when the connection is established.
+        let auth_session = pending().boxed();
+        (session, auth_session)
+    } else if let Some(frontegg) = frontegg {
         conn.send(BackendMessage::AuthenticationCleartextPassword)
             .await?;
         conn.flush().await?;
@@ -194,12 +252,56 @@ where
                     client_ip: conn.peer_addr().clone(),
                     external_metadata_rx: Some(auth_session.external_metadata_rx()),
                     helm_chart_version,
+                    client_certificate_subject: None,
+                });
+                let expired = async move { auth_session.expired().await };
+                (session, expired.boxed())
+            }
+            Err(err) => {
+                warn!(?err, "pgwire connection failed authentication");
+                adapter_client.audit_login_failure(&user, *conn.peer_addr(), err.to_string());
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_PASSWORD,
+                        "invalid password",
+                    ))
+                    .await;
+            }
+        }
+    } else if let Some(oidc) = oidc {
+        // The JWT issued by the identity provider is presented as the password.
+        conn.send(BackendMessage::AuthenticationCleartextPassword)
+            .await?;
+        conn.flush().await?;
+        let token = match conn.recv().await? {
+            Some(FrontendMessage::Password { password }) => password,
+            _ => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                        "expected Password message",
+                    ))
+                    .await
+            }
+        };
+
+        match oidc.authenticate(&user, &token).await {
+            Ok(mut auth_session) => {
+                let session = adapter_client.new_session(SessionConfig {
+                    conn_id: conn.conn_id().clone(),
+                    uuid: conn_uuid,
+                    user: auth_session.user().into(),
+                    client_ip: conn.peer_addr().clone(),
+                    external_metadata_rx: Some(auth_session.external_metadata_rx()),
+                    helm_chart_version,
+                    client_certificate_subject: None,
                 });
                 let expired = async move { auth_session.expired().await };
-                (session, expired.left_future())
+                (session, expired.boxed())
             }
             Err(err) => {
                 warn!(?err, "pgwire connection failed authentication");
+                adapter_client.audit_login_failure(&user, *conn.peer_addr(), err.to_string());
                 return conn
                     .send(ErrorResponse::fatal(
                         SqlState::INVALID_PASSWORD,
@@ -209,6 +311,14 @@ where
             }
         }
     } else {
+        // Without Frontegg, roles with a password must prove that they know it.
+        // Roles without a password are trusted.
+        if let Some(verifier) = adapter_client.role_password_verifier(&user).await {
+            if let Err(err) = authenticate_scram(conn, &user, &verifier).await? {
+                adapter_client.audit_login_failure(&user, *conn.peer_addr(), err.message.clone());
+                return conn.send(err).await;
+            }
+        }
         let session = adapter_client.new_session(SessionConfig {
             conn_id: conn.conn_id().clone(),
             uuid: conn_uuid,
@@ -216,9 +326,10 @@ where
             client_ip: conn.peer_addr().clone(),
             external_metadata_rx: None,
             helm_chart_version,
+            client_certificate_subject: None,
         });
-        // No frontegg check, so auth session lasts indefinitely.
-        let auth_session = pending().right_future();
+        // No frontegg or OIDC check, so auth session lasts indefinitely.
+        let auth_session = pending().boxed();
         (session, auth_session)
     };
 
@@ -405,6 +516,86 @@ fn split_options(value: &str) -> Vec<String> {
     strs
 }
 
+/// Runs a SCRAM-SHA-256 exchange in which the client proves that it knows the
+/// password described by `verifier`.
+///
+/// The outer error reports a broken connection. The inner error is the response
+/// to send to the client if authentication fails.
+async fn authenticate_scram<A>(
+    conn: &mut FramedConn<A>,
+    user: &str,
+    verifier: &str,
+) -> Result<Result<(), ErrorResponse>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + Unpin,
+{
+    let auth_failed = || {
+        ErrorResponse::fatal(
+            SqlState::INVALID_PASSWORD,
+            format!("password authentication failed for user {}", user.quoted()),
+        )
+    };
+    let scram_failed = |err: ScramError| match err {
+        ScramError::InvalidProof => auth_failed(),
+        ScramError::Protocol(_) => {
+            ErrorResponse::fatal(SqlState::PROTOCOL_VIOLATION, err.to_string())
+        }
+        ScramError::Internal(_) => ErrorResponse::fatal(SqlState::INTERNAL_ERROR, err.to_string()),
+    };
+
+    let Some(verifier) = ScramVerifier::parse(verifier) else {
+        warn!(user, "role has an invalid password verifier");
+        return Ok(Err(auth_failed()));
+    };
+    let mut scram = ScramServer::new(verifier, conn.inner().channel_binding());
+
+    conn.set_auth_decode_state(AuthDecodeState::SaslInitialResponse);
+    conn.send(BackendMessage::AuthenticationSasl {
+        mechanisms: scram.mechanisms(),
+    })
+    .await?;
+    conn.flush().await?;
+    let (mechanism, data) = match conn.recv().await? {
+        Some(FrontendMessage::SaslInitialResponse { mechanism, data }) => (mechanism, data),
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "expected SASLInitialResponse message",
+            )))
+        }
+    };
+    let server_first = match scram.handle_client_first(&mechanism, &data) {
+        Ok(server_first) => server_first,
+        Err(err) => return Ok(Err(scram_failed(err))),
+    };
+
+    conn.set_auth_decode_state(AuthDecodeState::SaslResponse);
+    conn.send(BackendMessage::AuthenticationSaslContinue(server_first))
+        .await?;
+    conn.flush().await?;
+    let data = match conn.recv().await? {
+        Some(FrontendMessage::SaslResponse { data }) => data,
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "expected SASLResponse message",
+            )))
+        }
+    };
+    let server_final = match scram.handle_client_final(&data) {
+        Ok(server_final) => server_final,
+        Err(err) => {
+            warn!(?err, user, "pgwire connection failed authentication");
+            return Ok(Err(scram_failed(err)));
+        }
+    };
+
+    conn.set_auth_decode_state(AuthDecodeState::Password);
+    conn.send(BackendMessage::AuthenticationSaslFinal(server_final))
+        .await?;
+    Ok(Ok(()))
+}
+
 #[derive(Debug)]
 enum State {
     Ready,
@@ -570,7 +761,9 @@ where
             Some(FrontendMessage::CopyData(_))
             | Some(FrontendMessage::CopyDone)
             | Some(FrontendMessage::CopyFail(_))
-            | Some(FrontendMessage::Password { .. }) => State::Drain,
+            | Some(FrontendMessage::Password { .. })
+            | Some(FrontendMessage::SaslInitialResponse { .. })
+            | Some(FrontendMessage::SaslResponse { .. }) => State::Drain,
             None => State::Done,
         };
 
@@ -1732,6 +1925,7 @@ where
 
             ExecuteResponse::AlteredDefaultPrivileges
             | ExecuteResponse::AlteredObject(..)
+            | ExecuteResponse::AlteredResourceGroup
             | ExecuteResponse::AlteredRole
             | ExecuteResponse::AlteredSystemConfiguration
             | ExecuteResponse::CreatedCluster { .. }
@@ -1752,6 +1946,8 @@ where
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPolicy
+            | ExecuteResponse::CreatedResourceGroup
             | ExecuteResponse::Comment
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
@@ -1759,6 +1955,8 @@ where
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPolicy
+            | ExecuteResponse::DroppedResourceGroup
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
@@ -16,6 +16,7 @@ use std::sync::{Arc, Mutex};
 use anyhow::Context;
 use async_trait::async_trait;
 use mz_frontegg_auth::Authenticator as FronteggAuthentication;
+use mz_oidc_auth::Authenticator as OidcAuthentication;
 use mz_pgwire_common::{
     decode_startup, Conn, FrontendStartupMessage, ACCEPT_SSL_ENCRYPTION, CONN_UUID_KEY,
     MZ_FORWARDED_FOR_KEY, REJECT_ENCRYPTION,
@@ -50,6 +51,12 @@ pub struct Config {
     /// a valid Frontegg API token as a password to authenticate. Otherwise,
     /// password authentication is disabled.
     pub frontegg: Option<FronteggAuthentication>,
+    /// The OIDC authentication configuration.
+    ///
+    /// If present, OIDC authentication is enabled, and users may present a
+    /// valid JWT issued by the configured identity provider as a password to
+    /// authenticate. Mutually exclusive with `frontegg`.
+    pub oidc: Option<OidcAuthentication>,
     /// The registry entries that the pgwire server uses to report metrics.
     pub metrics: MetricsConfig,
     /// Whether this is an internal server that permits access to restricted
@@ -66,6 +73,7 @@ pub struct Server {
     tls: Option<ReloadingTlsConfig>,
     adapter_client: mz_adapter::Client,
     frontegg: Option<FronteggAuthentication>,
+    oidc: Option<OidcAuthentication>,
     metrics: Metrics,
     internal: bool,
     active_connection_count: Arc<Mutex<ConnectionCounter>>,
@@ -91,6 +99,7 @@ impl Server {
             tls: config.tls,
             adapter_client: config.adapter_client,
             frontegg: config.frontegg,
+            oidc: config.oidc,
             metrics: Metrics::new(config.metrics, config.label),
             internal: config.internal,
             active_connection_count: config.active_connection_count,
@@ -105,6 +114,7 @@ impl Server {
     ) -> impl Future<Output = Result<(), anyhow::Error>> + 'static + Send {
         let adapter_client = self.adapter_client.clone();
         let frontegg = self.frontegg.clone();
+        let oidc = self.oidc.clone();
         let tls = self.tls.clone();
         let internal = self.internal;
         let metrics = self.metrics.clone();
@@ -171,12 +181,14 @@ impl Server {
 
                                 protocol::run(protocol::RunParams {
                                     tls_mode: tls.as_ref().map(|tls| tls.mode),
+                                    client_auth: tls.as_ref().and_then(|tls| tls.client_auth),
                                     adapter_client,
                                     conn: &mut conn,
                                     conn_uuid,
                                     version,
                                     params,
                                     frontegg: frontegg.as_ref(),
+                                    oidc: oidc.as_ref(),
                                     internal,
                                     active_connection_count,
                                     helm_chart_version,
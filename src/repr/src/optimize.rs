@@ -122,6 +122,10 @@ optimizer_feature_flags!({
     enable_reduce_unnest_list_fusion: bool,
     // See the feature flag of the same name.
     enable_window_aggregation_fusion: bool,
+    // Bound from `SystemVars::enable_bounded_top_k`.
+    enable_bounded_top_k: bool,
+    // Bound from `SystemVars::bounded_top_k_buffer`.
+    bounded_top_k_buffer: usize,
 });
 
 /// A trait used to implement layered config construction.
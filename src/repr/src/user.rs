@@ -17,4 +17,7 @@ pub struct ExternalUserMetadata {
     pub user_id: Uuid,
     /// Indicates if the user is an admin in the external system.
     pub admin: bool,
+    /// The names of the roles the user is a member of, if role membership is
+    /// managed by the external system.
+    pub roles: Option<Vec<String>>,
 }
@@ -0,0 +1,299 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Secrets whose contents live in a secrets store outside of Materialize's
+//! control.
+//!
+//! An external secret is stored in the [`SecretsController`] like any other
+//! secret, but its contents are an [`ExternalSecretReference`] rather than the
+//! secret value itself. A [`ResolvingSecretsReader`] recognizes references and
+//! resolves them through an [`ExternalSecretsReader`] each time the secret is
+//! read, so values rotated in the external store are picked up without an
+//! `ALTER SECRET`.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use mz_repr::GlobalId;
+
+use crate::cache::CachingSecretsReader;
+use crate::{SecretsController, SecretsReader};
+
+/// Marks the contents of a secret as an [`ExternalSecretReference`].
+///
+/// The marker starts with a NUL byte, which cannot appear in a `text` value, so
+/// secrets created from strings are never mistaken for references. Secrets
+/// created from `bytea` values can contain it, so callers that store
+/// user-provided values must reject those for which
+/// [`ExternalSecretReference::is_encoded`] holds.
+const REFERENCE_MARKER: &[u8] = b"\0mz-external-secret:vault\0";
+
+/// The key read from an external secret when a reference does not name one.
+pub const DEFAULT_KEY: &str = "value";
+
+/// A reference to a secret in HashiCorp Vault.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalSecretReference {
+    /// The path of the secret, starting with the KV secrets engine's mount.
+    pub path: String,
+    /// The key within the secret whose value to read.
+    pub key: String,
+}
+
+impl ExternalSecretReference {
+    /// Encodes the reference as the contents of a secret.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut contents = REFERENCE_MARKER.to_vec();
+        contents.extend(self.path.as_bytes());
+        contents.push(0);
+        contents.extend(self.key.as_bytes());
+        contents
+    }
+
+    /// Reports whether the contents of a secret are, or could be mistaken for,
+    /// an encoded reference.
+    pub fn is_encoded(contents: &[u8]) -> bool {
+        contents.starts_with(REFERENCE_MARKER)
+    }
+
+    /// Decodes the contents of a secret, returning `None` if they are not an
+    /// encoded reference.
+    pub fn decode(contents: &[u8]) -> Option<ExternalSecretReference> {
+        let reference = contents.strip_prefix(REFERENCE_MARKER)?;
+        let reference = std::str::from_utf8(reference).ok()?;
+        let (path, key) = reference.split_once('\0')?;
+        Some(ExternalSecretReference {
+            path: path.into(),
+            key: key.into(),
+        })
+    }
+}
+
+/// Reads secrets from a secrets store outside of Materialize's control.
+#[async_trait]
+pub trait ExternalSecretsReader: Debug + Send + Sync {
+    /// Returns the value of `key` in the secret at `path`.
+    async fn read(&self, path: &str, key: &str) -> Result<Vec<u8>, anyhow::Error>;
+}
+
+/// An [`ExternalSecretsReader`] backed by an in-memory map, for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryExternalSecretsReader {
+    data: Mutex<BTreeMap<(String, String), Vec<u8>>>,
+}
+
+impl InMemoryExternalSecretsReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates or updates the value of `key` in the secret at `path`.
+    pub fn set(&self, path: &str, key: &str, value: &[u8]) {
+        self.data
+            .lock()
+            .unwrap()
+            .insert((path.into(), key.into()), value.to_vec());
+    }
+}
+
+#[async_trait]
+impl ExternalSecretsReader for InMemoryExternalSecretsReader {
+    async fn read(&self, path: &str, key: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let value = self
+            .data
+            .lock()
+            .unwrap()
+            .get(&(path.to_string(), key.to_string()))
+            .cloned();
+        value.ok_or_else(|| anyhow!("external secret {path} has no key {key}"))
+    }
+}
+
+/// A [`SecretsReader`] that resolves [`ExternalSecretReference`]s.
+///
+/// Resolved values are cached with a [`CachingSecretsReader`], so each
+/// external secret is read from the external store at most once per cache TTL.
+/// Secrets whose contents are stored by Materialize are never cached.
+#[derive(Debug)]
+pub struct ResolvingSecretsReader {
+    inner: Arc<dyn SecretsReader>,
+    resolved: CachingSecretsReader,
+}
+
+impl ResolvingSecretsReader {
+    pub fn new(
+        inner: Arc<dyn SecretsReader>,
+        external: Option<Arc<dyn ExternalSecretsReader>>,
+    ) -> Self {
+        let resolver = ExternalResolver {
+            inner: Arc::clone(&inner),
+            external,
+        };
+        ResolvingSecretsReader {
+            inner,
+            resolved: CachingSecretsReader::new(Arc::new(resolver)),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretsReader for ResolvingSecretsReader {
+    async fn read(&self, id: GlobalId) -> Result<Vec<u8>, anyhow::Error> {
+        let contents = self.inner.read(id).await?;
+        if ExternalSecretReference::decode(&contents).is_none() {
+            return Ok(contents);
+        }
+        self.resolved.read(id).await
+    }
+}
+
+/// Reads external secrets on behalf of a [`ResolvingSecretsReader`]'s cache.
+#[derive(Debug)]
+struct ExternalResolver {
+    inner: Arc<dyn SecretsReader>,
+    external: Option<Arc<dyn ExternalSecretsReader>>,
+}
+
+#[async_trait]
+impl SecretsReader for ExternalResolver {
+    async fn read(&self, id: GlobalId) -> Result<Vec<u8>, anyhow::Error> {
+        let contents = self.inner.read(id).await?;
+        let Some(reference) = ExternalSecretReference::decode(&contents) else {
+            bail!("secret {id} is not an external secret");
+        };
+        let Some(external) = &self.external else {
+            bail!(
+                "secret {id} refers to Vault path {}, but no Vault server is configured",
+                reference.path
+            );
+        };
+        external.read(&reference.path, &reference.key).await
+    }
+}
+
+/// A [`SecretsController`] whose readers resolve [`ExternalSecretReference`]s
+/// with a [`ResolvingSecretsReader`].
+#[derive(Debug)]
+pub struct ResolvingSecretsController {
+    inner: Arc<dyn SecretsController>,
+    external: Option<Arc<dyn ExternalSecretsReader>>,
+}
+
+impl ResolvingSecretsController {
+    pub fn new(
+        inner: Arc<dyn SecretsController>,
+        external: Option<Arc<dyn ExternalSecretsReader>>,
+    ) -> Self {
+        ResolvingSecretsController { inner, external }
+    }
+}
+
+#[async_trait]
+impl SecretsController for ResolvingSecretsController {
+    async fn ensure(&self, id: GlobalId, contents: &[u8]) -> Result<(), anyhow::Error> {
+        self.inner.ensure(id, contents).await
+    }
+
+    async fn delete(&self, id: GlobalId) -> Result<(), anyhow::Error> {
+        self.inner.delete(id).await
+    }
+
+    async fn list(&self) -> Result<Vec<GlobalId>, anyhow::Error> {
+        self.inner.list().await
+    }
+
+    fn reader(&self) -> Arc<dyn SecretsReader> {
+        Arc::new(ResolvingSecretsReader::new(
+            self.inner.reader(),
+            self.external.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mz_repr::GlobalId;
+
+    use crate::external::{
+        ExternalSecretReference, ExternalSecretsReader, InMemoryExternalSecretsReader,
+        ResolvingSecretsController,
+    };
+    use crate::{InMemorySecretsController, SecretsController};
+
+    #[mz_ore::test]
+    fn test_reference_roundtrip() {
+        let reference = ExternalSecretReference {
+            path: "kv/payments/db".into(),
+            key: "password".into(),
+        };
+        assert_eq!(
+            ExternalSecretReference::decode(&reference.encode()),
+            Some(reference)
+        );
+        assert!(ExternalSecretReference::is_encoded(&reference.encode()));
+        assert_eq!(ExternalSecretReference::decode(b"kv/payments/db"), None);
+        assert!(!ExternalSecretReference::is_encoded(b"kv/payments/db"));
+    }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_resolve_external_secrets() {
+        let vault = Arc::new(InMemoryExternalSecretsReader::new());
+        vault.set("kv/payments/db", "password", b"hunter2");
+        let controller = ResolvingSecretsController::new(
+            Arc::new(InMemorySecretsController::new()),
+            Some(Arc::clone(&vault) as Arc<dyn ExternalSecretsReader>),
+        );
+        let reader = controller.reader();
+
+        let inline = GlobalId::User(1);
+        controller.ensure(inline, b"plain").await.unwrap();
+        assert_eq!(reader.read(inline).await.unwrap(), b"plain");
+
+        let external = GlobalId::User(2);
+        let reference = ExternalSecretReference {
+            path: "kv/payments/db".into(),
+            key: "password".into(),
+        };
+        controller
+            .ensure(external, &reference.encode())
+            .await
+            .unwrap();
+        assert_eq!(reader.read_string(external).await.unwrap(), "hunter2");
+
+        let missing = GlobalId::User(3);
+        let reference = ExternalSecretReference {
+            path: "kv/payments/db".into(),
+            key: "username".into(),
+        };
+        controller
+            .ensure(missing, &reference.encode())
+            .await
+            .unwrap();
+        assert!(reader.read(missing).await.is_err());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_resolve_without_external_reader() {
+        let controller =
+            ResolvingSecretsController::new(Arc::new(InMemorySecretsController::new()), None);
+        let id = GlobalId::User(1);
+        let reference = ExternalSecretReference {
+            path: "kv/payments/db".into(),
+            key: "password".into(),
+        };
+        controller.ensure(id, &reference.encode()).await.unwrap();
+        let err = controller.reader().read(id).await.unwrap_err();
+        assert!(err.to_string().contains("no Vault server is configured"));
+    }
+}
@@ -19,7 +19,7 @@ use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
 use std::task::{Context, Poll};
 use std::time::Duration;
 
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use async_trait::async_trait;
 use futures::stream::{BoxStream, Stream, StreamExt};
 use mz_dyncfg::{Config, ConfigSet};
@@ -28,7 +28,9 @@ use mz_ore::error::ErrorExt;
 use mz_ore::netio::AsyncReady;
 use mz_ore::option::OptionExt;
 use mz_ore::task::JoinSetExt;
-use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod};
+use openssl::nid::Nid;
+use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod, SslRef, SslVerifyMode};
+use openssl::x509::{X509Name, X509NameRef, X509Ref};
 use proxy_header::{ParseConfig, ProxiedAddress, ProxyHeader};
 use scopeguard::ScopeGuard;
 use socket2::{SockRef, TcpKeepalive};
@@ -420,6 +422,90 @@ pub struct TlsCertConfig {
     pub cert: PathBuf,
     /// The path to the TLS key.
     pub key: PathBuf,
+    /// If set, clients must present a certificate that is signed by a trusted
+    /// certificate authority.
+    pub client_auth: Option<TlsClientAuthConfig>,
+}
+
+/// Configures authentication of clients by TLS client certificates.
+#[derive(Debug, Clone)]
+pub struct TlsClientAuthConfig {
+    /// The path to the certificates of the certificate authorities that are
+    /// trusted to sign client certificates.
+    pub ca: PathBuf,
+    /// How to determine the role that a client certificate authenticates.
+    pub role_source: ClientCertRoleSource,
+}
+
+/// Specifies which field of a client certificate names the role that the
+/// certificate authenticates.
+#[derive(Debug, Clone, Copy)]
+pub enum ClientCertRoleSource {
+    /// The common name (CN) of the certificate's subject.
+    CommonName,
+    /// The first email address among the certificate's subject alternative
+    /// names.
+    SanEmail,
+    /// The first DNS name among the certificate's subject alternative names.
+    SanDns,
+}
+
+impl ClientCertRoleSource {
+    /// Returns the identity that the peer certificate of `ssl` authenticates.
+    ///
+    /// The peer certificate has already been verified against the trusted
+    /// certificate authorities during the TLS handshake.
+    pub fn client_certificate(&self, ssl: &SslRef) -> Result<ClientCertificate, anyhow::Error> {
+        let cert = ssl
+            .peer_certificate()
+            .ok_or_else(|| anyhow!("client did not present a certificate"))?;
+        let subject = format_x509_name(cert.subject_name());
+        let role = self
+            .role_name(&cert)
+            .ok_or_else(|| anyhow!("client certificate {subject} does not name a role"))?;
+        Ok(ClientCertificate { role, subject })
+    }
+
+    fn role_name(&self, cert: &X509Ref) -> Option<String> {
+        match self {
+            ClientCertRoleSource::CommonName => {
+                let entry = cert.subject_name().entries_by_nid(Nid::COMMONNAME).next()?;
+                Some(entry.data().as_utf8().ok()?.to_string())
+            }
+            ClientCertRoleSource::SanEmail => cert
+                .subject_alt_names()?
+                .iter()
+                .find_map(|name| name.email().map(|email| email.to_string())),
+            ClientCertRoleSource::SanDns => cert
+                .subject_alt_names()?
+                .iter()
+                .find_map(|name| name.dnsname().map(|dns| dns.to_string())),
+        }
+    }
+}
+
+/// The identity established by a verified TLS client certificate.
+#[derive(Debug, Clone)]
+pub struct ClientCertificate {
+    /// The name of the role that the certificate authenticates.
+    pub role: String,
+    /// The certificate's subject, e.g. `CN=alice,O=Example`.
+    pub subject: String,
+}
+
+/// Formats `name` as a comma-separated list of `KEY=value` pairs.
+fn format_x509_name(name: &X509NameRef) -> String {
+    name.entries()
+        .map(|entry| {
+            let key = entry.object().nid().short_name().unwrap_or("UNKNOWN");
+            let value = match entry.data().as_utf8() {
+                Ok(value) => value.to_string(),
+                Err(_) => "?".into(),
+            };
+            format!("{key}={value}")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 impl TlsCertConfig {
@@ -434,6 +520,11 @@ impl TlsCertConfig {
         let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         builder.set_certificate_chain_file(&self.cert)?;
         builder.set_private_key_file(&self.key, SslFiletype::PEM)?;
+        if let Some(client_auth) = &self.client_auth {
+            builder.set_ca_file(&client_auth.ca)?;
+            builder.set_client_ca_list(X509Name::load_client_ca_file(&client_auth.ca)?);
+            builder.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        }
         Ok(builder.build().into_context())
     }
 
@@ -491,6 +582,9 @@ pub struct ReloadingTlsConfig {
     pub context: ReloadingSslContext,
     /// The TLS mode.
     pub mode: TlsMode,
+    /// If set, clients are authenticated by their TLS client certificate,
+    /// which names their role as specified.
+    pub client_auth: Option<ClientCertRoleSource>,
 }
 
 pub type ReloadTrigger = BoxStream<'static, Option<oneshot::Sender<Result<(), anyhow::Error>>>>;
@@ -550,6 +644,30 @@ pub struct TlsCliArgs {
         value_name = "PATH"
     )]
     tls_key: Option<PathBuf>,
+    /// Certificate authorities that are trusted to sign TLS client
+    /// certificates.
+    ///
+    /// If set, HTTP and PostgreSQL clients must present a certificate that is
+    /// signed by one of these certificate authorities. The certificate, rather
+    /// than a password, authenticates the client as the role named by the
+    /// certificate, as determined by `--tls-client-cert-role`.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CA",
+        requires = "tls-cert",
+        value_name = "PATH"
+    )]
+    tls_client_ca: Option<PathBuf>,
+    /// Which field of a TLS client certificate names the role that the
+    /// certificate authenticates.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CERT_ROLE",
+        possible_values = &["common-name", "san-email", "san-dns"],
+        default_value = "common-name",
+        value_name = "FIELD",
+    )]
+    tls_client_cert_role: String,
 }
 
 impl TlsCliArgs {
@@ -562,11 +680,27 @@ impl TlsCliArgs {
             if self.tls_key.is_some() {
                 bail!("cannot specify --tls-mode=disable and --tls-key simultaneously");
             }
+            if self.tls_client_ca.is_some() {
+                bail!("cannot specify --tls-mode=disable and --tls-client-ca simultaneously");
+            }
             Ok(None)
         } else {
             let cert = self.tls_cert.unwrap();
             let key = self.tls_key.unwrap();
-            Ok(Some(TlsCertConfig { cert, key }))
+            let client_auth = self.tls_client_ca.map(|ca| {
+                let role_source = match self.tls_client_cert_role.as_str() {
+                    "common-name" => ClientCertRoleSource::CommonName,
+                    "san-email" => ClientCertRoleSource::SanEmail,
+                    "san-dns" => ClientCertRoleSource::SanDns,
+                    _ => unreachable!("clap enforced"),
+                };
+                TlsClientAuthConfig { ca, role_source }
+            });
+            Ok(Some(TlsCertConfig {
+                cert,
+                key,
+                client_auth,
+            }))
         }
     }
 }
@@ -14,7 +14,10 @@ use clap::ArgEnum;
 use mz_aws_secrets_controller::AwsSecretsClient;
 use mz_orchestrator_kubernetes::secrets::KubernetesSecretsReader;
 use mz_orchestrator_process::secrets::ProcessSecretsReader;
+use mz_secrets::external::{ExternalSecretsReader, ResolvingSecretsReader};
 use mz_secrets::SecretsReader;
+use mz_vault_secrets::VaultSecretsReader;
+use url::Url;
 
 #[derive(clap::Parser, Clone, Debug)]
 pub struct SecretsReaderCliArgs {
@@ -44,6 +47,30 @@ pub struct SecretsReaderCliArgs {
         env = "SECRETS_READER_AWS_PREFIX"
     )]
     pub secrets_reader_aws_prefix: Option<String>,
+    /// The address of the HashiCorp Vault server from which to read secrets
+    /// created with `CREATE SECRET ... FROM VAULT`.
+    #[structopt(
+        long,
+        requires_all = &["secrets-reader-vault-mount", "secrets-reader-vault-token-file"],
+        env = "SECRETS_READER_VAULT_ADDRESS"
+    )]
+    pub secrets_reader_vault_address: Option<Url>,
+    /// The mount of the KV secrets engine that secrets created with `CREATE
+    /// SECRET ... FROM VAULT` may refer to.
+    #[structopt(
+        long,
+        requires = "secrets-reader-vault-address",
+        env = "SECRETS_READER_VAULT_MOUNT"
+    )]
+    pub secrets_reader_vault_mount: Option<String>,
+    /// The file containing the token with which to authenticate to the
+    /// HashiCorp Vault server.
+    #[structopt(
+        long,
+        requires = "secrets-reader-vault-address",
+        env = "SECRETS_READER_VAULT_TOKEN_FILE"
+    )]
+    pub secrets_reader_vault_token_file: Option<PathBuf>,
 }
 
 #[derive(ArgEnum, Debug, Clone, Copy)]
@@ -56,29 +83,48 @@ pub enum SecretsControllerKind {
 impl SecretsReaderCliArgs {
     /// Loads the secrets reader specified by the command-line arguments.
     pub async fn load(self) -> Result<Arc<dyn SecretsReader>, anyhow::Error> {
-        match self.secrets_reader {
+        let external = self.external_reader();
+        let reader: Arc<dyn SecretsReader> = match self.secrets_reader {
             SecretsControllerKind::LocalFile => {
                 let dir = self.secrets_reader_local_file_dir.expect("clap enforced");
-                Ok(Arc::new(ProcessSecretsReader::new(dir)))
+                Arc::new(ProcessSecretsReader::new(dir))
             }
             SecretsControllerKind::Kubernetes => {
                 let context = self
                     .secrets_reader_kubernetes_context
                     .expect("clap enforced");
-                Ok(Arc::new(KubernetesSecretsReader::new(context).await?))
+                Arc::new(KubernetesSecretsReader::new(context).await?)
             }
             SecretsControllerKind::AwsSecretsManager => {
                 let prefix = self.secrets_reader_aws_prefix.expect("clap enforced");
-                Ok(Arc::new(AwsSecretsClient::new(&prefix).await))
+                Arc::new(AwsSecretsClient::new(&prefix).await)
             }
-        }
+        };
+        Ok(Arc::new(ResolvingSecretsReader::new(reader, external)))
+    }
+
+    /// Returns the reader for secrets created with `CREATE SECRET ... FROM
+    /// VAULT`, if a Vault server is configured.
+    pub fn external_reader(&self) -> Option<Arc<dyn ExternalSecretsReader>> {
+        let address = self.secrets_reader_vault_address.clone()?;
+        let mount = self
+            .secrets_reader_vault_mount
+            .clone()
+            .expect("clap enforced");
+        let token_file = self
+            .secrets_reader_vault_token_file
+            .clone()
+            .expect("clap enforced");
+        Some(Arc::new(VaultSecretsReader::new(
+            address, mount, token_file,
+        )))
     }
 
     /// Turn this struct back into arguments. Useful for passing through to other services.
     ///
     /// Expects the correct arguments to be filled in, based on the `clap` requirements.
     pub fn to_flags(&self) -> Vec<String> {
-        match self.secrets_reader {
+        let mut flags = match self.secrets_reader {
             SecretsControllerKind::LocalFile => {
                 vec![
                     "--secrets-reader=local-file".to_string(),
@@ -113,6 +159,19 @@ impl SecretsReaderCliArgs {
                     ),
                 ]
             }
+        };
+        if let (Some(address), Some(mount), Some(token_file)) = (
+            &self.secrets_reader_vault_address,
+            &self.secrets_reader_vault_mount,
+            &self.secrets_reader_vault_token_file,
+        ) {
+            flags.push(format!("--secrets-reader-vault-address={address}"));
+            flags.push(format!("--secrets-reader-vault-mount={mount}"));
+            flags.push(format!(
+                "--secrets-reader-vault-token-file={}",
+                token_file.display()
+            ));
         }
+        flags
     }
 }
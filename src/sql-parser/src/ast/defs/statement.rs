@@ -65,11 +65,17 @@ pub enum Statement<T: AstInfo> {
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
     CreateNetworkPolicy(CreateNetworkPolicyStatement<T>),
+    CreatePolicy(CreatePolicyStatement<T>),
+    CreateResourceGroup(CreateResourceGroupStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterRetainHistory(AlterRetainHistoryStatement<T>),
+    AlterRetention(AlterRetentionStatement),
+    AlterRowSecurity(AlterRowSecurityStatement),
+    AlterColumnMask(AlterColumnMaskStatement<T>),
+    AlterResourceGroup(AlterResourceGroupStatement<T>),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
@@ -85,6 +91,8 @@ pub enum Statement<T: AstInfo> {
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropPolicy(DropPolicyStatement),
+    DropResourceGroup(DropResourceGroupStatement),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -141,11 +149,17 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::CreateNetworkPolicy(stmt) => f.write_node(stmt),
+            Statement::CreatePolicy(stmt) => f.write_node(stmt),
+            Statement::CreateResourceGroup(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
             Statement::AlterNetworkPolicy(stmt) => f.write_node(stmt),
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterRetainHistory(stmt) => f.write_node(stmt),
+            Statement::AlterRetention(stmt) => f.write_node(stmt),
+            Statement::AlterRowSecurity(stmt) => f.write_node(stmt),
+            Statement::AlterColumnMask(stmt) => f.write_node(stmt),
+            Statement::AlterResourceGroup(stmt) => f.write_node(stmt),
             Statement::AlterObjectSwap(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
@@ -161,6 +175,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropPolicy(stmt) => f.write_node(stmt),
+            Statement::DropResourceGroup(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -220,9 +236,15 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
         StatementKind::CreateNetworkPolicy => "create_network_policy",
+        StatementKind::CreatePolicy => "create_policy",
+        StatementKind::CreateResourceGroup => "create_resource_group",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterRetainHistory => "alter_retain_history",
+        StatementKind::AlterRetention => "alter_retention",
+        StatementKind::AlterRowSecurity => "alter_row_security",
+        StatementKind::AlterColumnMask => "alter_column_mask",
+        StatementKind::AlterResourceGroup => "alter_resource_group",
         StatementKind::AlterObjectSwap => "alter_object_swap",
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterNetworkPolicy => "alter_network_policy",
@@ -240,6 +262,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropPolicy => "drop_policy",
+        StatementKind::DropResourceGroup => "drop_resource_group",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -1538,6 +1562,8 @@ pub struct CreateTableStatement<T: AstInfo> {
     pub if_not_exists: bool,
     pub temporary: bool,
     pub with_options: Vec<TableOption<T>>,
+    /// The table or materialized view to clone, in place of the column list.
+    pub clone_of: Option<TableClone<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
@@ -1549,6 +1575,7 @@ impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
             if_not_exists,
             temporary,
             with_options,
+            clone_of,
         } = self;
         f.write_str("CREATE ");
         if *temporary {
@@ -1559,13 +1586,21 @@ impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
             f.write_str("IF NOT EXISTS ");
         }
         f.write_node(name);
-        f.write_str(" (");
-        f.write_node(&display::comma_separated(columns));
-        if !self.constraints.is_empty() {
-            f.write_str(", ");
-            f.write_node(&display::comma_separated(constraints));
+        match clone_of {
+            Some(clone_of) => {
+                f.write_str(" ");
+                f.write_node(clone_of);
+            }
+            None => {
+                f.write_str(" (");
+                f.write_node(&display::comma_separated(columns));
+                if !self.constraints.is_empty() {
+                    f.write_str(", ");
+                    f.write_node(&display::comma_separated(constraints));
+                }
+                f.write_str(")");
+            }
         }
-        f.write_str(")");
         if !with_options.is_empty() {
             f.write_str(" WITH (");
             f.write_node(&display::comma_separated(&self.with_options));
@@ -1575,10 +1610,33 @@ impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
 }
 impl_display_t!(CreateTableStatement);
 
+/// The `CLONE` clause of a `CREATE TABLE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableClone<T: AstInfo> {
+    /// The table or materialized view to clone.
+    pub source: T::ItemName,
+    /// The time at which to clone `source`.
+    pub as_of: Option<AsOf<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for TableClone<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CLONE ");
+        f.write_node(&self.source);
+        if let Some(as_of) = &self.as_of {
+            f.write_str(" ");
+            f.write_node(as_of);
+        }
+    }
+}
+impl_display_t!(TableClone);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TableOptionName {
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// The `RETENTION` option.
+    Retention,
     /// A special option to test that we do redact values.
     RedactedTest,
 }
@@ -1589,6 +1647,9 @@ impl AstDisplay for TableOptionName {
             TableOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            TableOptionName::Retention => {
+                f.write_str("RETENTION");
+            }
             TableOptionName::RedactedTest => {
                 f.write_str("REDACTED");
             }
@@ -1604,7 +1665,7 @@ impl WithOptionName for TableOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            TableOptionName::RetainHistory => false,
+            TableOptionName::RetainHistory | TableOptionName::Retention => false,
             TableOptionName::RedactedTest => true,
         }
     }
@@ -1825,6 +1886,8 @@ impl_display_t!(CreateIndexStatement);
 pub enum IndexOptionName {
     // The `RETAIN HISTORY` option
     RetainHistory,
+    // The `PERSISTED` option
+    Persisted,
 }
 
 impl AstDisplay for IndexOptionName {
@@ -1833,6 +1896,9 @@ impl AstDisplay for IndexOptionName {
             IndexOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            IndexOptionName::Persisted => {
+                f.write_str("PERSISTED");
+            }
         }
     }
 }
@@ -1845,7 +1911,7 @@ impl WithOptionName for IndexOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            IndexOptionName::RetainHistory => false,
+            IndexOptionName::RetainHistory | IndexOptionName::Persisted => false,
         }
     }
 }
@@ -1886,6 +1952,10 @@ pub enum RoleAttribute {
     Inherit,
     /// The `NOINHERIT` option.
     NoInherit,
+    /// The `PASSWORD` option.
+    ///
+    /// `None` indicates `PASSWORD NULL`, which removes the role's password.
+    Password(Option<String>),
     // The following are not supported, but included to give helpful error messages.
     Login,
     NoLogin,
@@ -1908,6 +1978,17 @@ impl AstDisplay for RoleAttribute {
             RoleAttribute::NoLogin => f.write_str("NOLOGIN"),
             RoleAttribute::Inherit => f.write_str("INHERIT"),
             RoleAttribute::NoInherit => f.write_str("NOINHERIT"),
+            RoleAttribute::Password(None) => f.write_str("PASSWORD NULL"),
+            RoleAttribute::Password(Some(password)) => {
+                f.write_str("PASSWORD ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(password));
+                    f.write_str("'");
+                }
+            }
             RoleAttribute::CreateCluster => f.write_str("CREATECLUSTER"),
             RoleAttribute::NoCreateCluster => f.write_str("NOCREATECLUSTER"),
             RoleAttribute::CreateDB => f.write_str("CREATEDB"),
@@ -1988,6 +2069,192 @@ impl<T: AstInfo> AstDisplay for CreateNetworkPolicyStatement<T> {
 }
 impl_display_t!(CreateNetworkPolicyStatement);
 
+/// A `CREATE POLICY` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePolicyStatement<T: AstInfo> {
+    /// The name of the policy, unique per relation.
+    pub name: Ident,
+    /// The relation the policy applies to.
+    pub relation: T::ItemName,
+    /// The roles the policy applies to. Empty means `PUBLIC`.
+    pub roles: Vec<T::RoleName>,
+    /// The predicate that rows must satisfy to be visible.
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE POLICY ");
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.relation);
+        if !self.roles.is_empty() {
+            f.write_str(" TO ");
+            f.write_node(&display::comma_separated(&self.roles));
+        }
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreatePolicyStatement);
+
+/// A `DROP POLICY` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropPolicyStatement {
+    pub if_exists: bool,
+    /// The name of the policy.
+    pub name: Ident,
+    /// The relation the policy belongs to.
+    pub relation: UnresolvedItemName,
+}
+
+impl AstDisplay for DropPolicyStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP POLICY ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.relation);
+    }
+}
+impl_display!(DropPolicyStatement);
+
+/// A `CREATE RESOURCE GROUP` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateResourceGroupStatement<T: AstInfo> {
+    /// The name of the resource group.
+    pub name: Ident,
+    /// The roles assigned to the resource group.
+    pub roles: Vec<T::RoleName>,
+    /// Any options that were attached, in the order they were presented.
+    pub options: Vec<ResourceGroupOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateResourceGroupStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE RESOURCE GROUP ");
+        f.write_node(&self.name);
+        if !self.roles.is_empty() {
+            f.write_str(" TO ");
+            f.write_node(&display::comma_separated(&self.roles));
+        }
+        if !self.options.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(CreateResourceGroupStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterResourceGroupAction<T: AstInfo> {
+    SetOptions(Vec<ResourceGroupOption<T>>),
+    ResetOptions(Vec<ResourceGroupOptionName>),
+    SetRoles(Vec<T::RoleName>),
+}
+
+/// An `ALTER RESOURCE GROUP` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterResourceGroupStatement<T: AstInfo> {
+    /// The name of the resource group.
+    pub name: Ident,
+    pub action: AlterResourceGroupAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterResourceGroupStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER RESOURCE GROUP ");
+        f.write_node(&self.name);
+        match &self.action {
+            AlterResourceGroupAction::SetOptions(options) => {
+                f.write_str(" SET (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+            }
+            AlterResourceGroupAction::ResetOptions(names) => {
+                f.write_str(" RESET (");
+                f.write_node(&display::comma_separated(names));
+                f.write_str(")");
+            }
+            AlterResourceGroupAction::SetRoles(roles) => {
+                f.write_str(" TO ");
+                f.write_node(&display::comma_separated(roles));
+            }
+        }
+    }
+}
+impl_display_t!(AlterResourceGroupStatement);
+
+/// A `DROP RESOURCE GROUP` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropResourceGroupStatement {
+    pub if_exists: bool,
+    /// The name of the resource group.
+    pub name: Ident,
+}
+
+impl AstDisplay for DropResourceGroupStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP RESOURCE GROUP ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+    }
+}
+impl_display!(DropResourceGroupStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResourceGroupOptionName {
+    /// The `MAX CONCURRENT PEEKS [=] <value>` option.
+    MaxConcurrentPeeks,
+    /// The `MAX QUEUED PEEKS [=] <value>` option.
+    MaxQueuedPeeks,
+    /// The `MAX RESULT SIZE [=] <value>` option.
+    MaxResultSize,
+    /// The `STATEMENT TIMEOUT [=] <value>` option.
+    StatementTimeout,
+}
+
+impl AstDisplay for ResourceGroupOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ResourceGroupOptionName::MaxConcurrentPeeks => f.write_str("MAX CONCURRENT PEEKS"),
+            ResourceGroupOptionName::MaxQueuedPeeks => f.write_str("MAX QUEUED PEEKS"),
+            ResourceGroupOptionName::MaxResultSize => f.write_str("MAX RESULT SIZE"),
+            ResourceGroupOptionName::StatementTimeout => f.write_str("STATEMENT TIMEOUT"),
+        }
+    }
+}
+
+impl WithOptionName for ResourceGroupOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            ResourceGroupOptionName::MaxConcurrentPeeks
+            | ResourceGroupOptionName::MaxQueuedPeeks
+            | ResourceGroupOptionName::MaxResultSize
+            | ResourceGroupOptionName::StatementTimeout => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE RESOURCE GROUP` or `ALTER RESOURCE GROUP` statement.
+pub struct ResourceGroupOption<T: AstInfo> {
+    pub name: ResourceGroupOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(ResourceGroupOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NetworkPolicyOption<T: AstInfo> {
     pub name: NetworkPolicyOptionName,
@@ -2081,7 +2348,7 @@ impl_display_for_with_option!(NetworkPolicyRuleOption);
 pub struct CreateSecretStatement<T: AstInfo> {
     pub name: UnresolvedItemName,
     pub if_not_exists: bool,
-    pub value: Expr<T>,
+    pub value: CreateSecretValue<T>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
@@ -2091,16 +2358,47 @@ impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
             f.write_str("IF NOT EXISTS ");
         }
         f.write_node(&self.name);
-        f.write_str(" AS ");
+        f.write_str(" ");
+        f.write_node(&self.value);
+    }
+}
+impl_display_t!(CreateSecretStatement);
 
-        if f.redacted() {
-            f.write_str("'<REDACTED>'");
-        } else {
-            f.write_node(&self.value);
+/// The contents of a secret created by `CREATE SECRET`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CreateSecretValue<T: AstInfo> {
+    /// `AS <expr>`: the contents are stored by Materialize.
+    Inline(Expr<T>),
+    /// `FROM VAULT '<path>' [KEY '<key>']`: the contents are read from
+    /// HashiCorp Vault each time the secret is used.
+    Vault { path: String, key: Option<String> },
+}
+
+impl<T: AstInfo> AstDisplay for CreateSecretValue<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            CreateSecretValue::Inline(value) => {
+                f.write_str("AS ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_node(value);
+                }
+            }
+            CreateSecretValue::Vault { path, key } => {
+                f.write_str("FROM VAULT '");
+                f.write_node(&display::escape_single_quote_string(path));
+                f.write_str("'");
+                if let Some(key) = key {
+                    f.write_str(" KEY '");
+                    f.write_node(&display::escape_single_quote_string(key));
+                    f.write_str("'");
+                }
+            }
         }
     }
 }
-impl_display_t!(CreateSecretStatement);
+impl_display_t!(CreateSecretValue);
 
 /// `CREATE TYPE ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2147,6 +2445,8 @@ impl_display_t!(CreateTypeStatement);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
+    /// The `ARRANGEMENT SPILL [[=] <enabled>]` option.
+    ArrangementSpill,
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
     AvailabilityZones,
     /// The `DISK` option.
@@ -2172,6 +2472,7 @@ pub enum ClusterOptionName {
 impl AstDisplay for ClusterOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            ClusterOptionName::ArrangementSpill => f.write_str("ARRANGEMENT SPILL"),
             ClusterOptionName::AvailabilityZones => f.write_str("AVAILABILITY ZONES"),
             ClusterOptionName::Disk => f.write_str("DISK"),
             ClusterOptionName::IntrospectionDebugging => f.write_str("INTROSPECTION DEBUGGING"),
@@ -2194,7 +2495,8 @@ impl WithOptionName for ClusterOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            ClusterOptionName::AvailabilityZones
+            ClusterOptionName::ArrangementSpill
+            | ClusterOptionName::AvailabilityZones
             | ClusterOptionName::Disk
             | ClusterOptionName::IntrospectionDebugging
             | ClusterOptionName::IntrospectionInterval
@@ -2722,6 +3024,98 @@ impl<T: AstInfo> AstDisplay for AlterRetainHistoryStatement<T> {
 }
 impl_display_t!(AlterRetainHistoryStatement);
 
+/// `ALTER <OBJECT> ... [RE]SET (RETENTION [= '<period>' ON <column>])`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterRetentionStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub retention: Option<RetentionOptionValue>,
+}
+
+impl AstDisplay for AlterRetentionStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        if let Some(retention) = &self.retention {
+            f.write_str(" SET (RETENTION = ");
+            f.write_node(retention);
+        } else {
+            f.write_str(" RESET (RETENTION");
+        }
+        f.write_str(")");
+    }
+}
+impl_display!(AlterRetentionStatement);
+
+/// `ALTER <OBJECT> ... { ENABLE | DISABLE } ROW LEVEL SECURITY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterRowSecurityStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub enabled: bool,
+}
+
+impl AstDisplay for AlterRowSecurityStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        if self.enabled {
+            f.write_str(" ENABLE");
+        } else {
+            f.write_str(" DISABLE");
+        }
+        f.write_str(" ROW LEVEL SECURITY");
+    }
+}
+impl_display!(AlterRowSecurityStatement);
+
+/// `ALTER <OBJECT> ... ALTER COLUMN ... { SET MASK (<expr>) | DROP MASK }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterColumnMaskStatement<T: AstInfo> {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub column_name: Ident,
+    /// The expression that replaces the values of the column, or `None` if
+    /// the mask is being dropped.
+    pub mask: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterColumnMaskStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ALTER COLUMN ");
+        f.write_node(&self.column_name);
+        match &self.mask {
+            Some(mask) => {
+                f.write_str(" SET MASK (");
+                f.write_node(mask);
+                f.write_str(")");
+            }
+            None => f.write_str(" DROP MASK"),
+        }
+    }
+}
+impl_display_t!(AlterColumnMaskStatement);
+
 /// `ALTER <OBJECT> SWAP ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterObjectSwapStatement {
@@ -4148,6 +4542,7 @@ pub enum WithOptionValue<T: AstInfo> {
     ConnectionKafkaBroker(KafkaBroker<T>),
     ConnectionAwsPrivatelink(ConnectionDefaultAwsPrivatelink<T>),
     RetainHistoryFor(Value),
+    Retention(RetentionOptionValue),
     Refresh(RefreshOptionValue<T>),
     ClusterScheduleOptionValue(ClusterScheduleOptionValue),
     ClusterAlterStrategy(ClusterAlterOptionValue<T>),
@@ -4164,6 +4559,7 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 | WithOptionValue::Sequence(_)
                 | WithOptionValue::Map(_)
                 | WithOptionValue::RetainHistoryFor(_)
+                | WithOptionValue::Retention(_)
                 | WithOptionValue::Refresh(_)
                 | WithOptionValue::Expr(_) => {
                     // These are redact-aware.
@@ -4235,6 +4631,7 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 f.write_str("FOR ");
                 f.write_node(value);
             }
+            WithOptionValue::Retention(value) => f.write_node(value),
             WithOptionValue::Refresh(opt) => f.write_node(opt),
             WithOptionValue::ClusterScheduleOptionValue(value) => f.write_node(value),
             WithOptionValue::ClusterAlterStrategy(value) => f.write_node(value),
@@ -4293,6 +4690,22 @@ impl<T: AstInfo> AstDisplay for RefreshOptionValue<T> {
     }
 }
 
+/// The value of a `RETENTION` option: `'<period>' ON <column>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RetentionOptionValue {
+    pub period: Value,
+    pub column: Ident,
+}
+
+impl AstDisplay for RetentionOptionValue {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.period);
+        f.write_str(" ON ");
+        f.write_node(&self.column);
+    }
+}
+impl_display!(RetentionOptionValue);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum ClusterScheduleOptionValue {
     Manual,
@@ -5026,10 +5439,29 @@ impl AstDisplay for Privilege {
 }
 impl_display!(Privilege);
 
+/// A privilege on a list of columns, e.g. `SELECT (a, b)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnPrivilege {
+    pub privilege: Privilege,
+    pub columns: Vec<Ident>,
+}
+
+impl AstDisplay for ColumnPrivilege {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.privilege);
+        f.write_str(" (");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(")");
+    }
+}
+impl_display!(ColumnPrivilege);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PrivilegeSpecification {
     All,
     Privileges(Vec<Privilege>),
+    /// Privileges on individual columns of a relation.
+    Columns(Vec<ColumnPrivilege>),
 }
 
 impl AstDisplay for PrivilegeSpecification {
@@ -5039,6 +5471,9 @@ impl AstDisplay for PrivilegeSpecification {
             PrivilegeSpecification::Privileges(privileges) => {
                 f.write_node(&display::comma_separated(privileges))
             }
+            PrivilegeSpecification::Columns(privileges) => {
+                f.write_node(&display::comma_separated(privileges))
+            }
         }
     }
 }
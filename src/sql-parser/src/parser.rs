@@ -1909,6 +1909,12 @@ impl<'a> Parser<'a> {
         } else if self.peek_keywords(&[NETWORK, POLICY]) {
             self.parse_create_network_policy()
                 .map_parser_err(StatementKind::CreateNetworkPolicy)
+        } else if self.peek_keyword(POLICY) {
+            self.parse_create_policy()
+                .map_parser_err(StatementKind::CreatePolicy)
+        } else if self.peek_keywords(&[RESOURCE, GROUP]) {
+            self.parse_create_resource_group()
+                .map_parser_err(StatementKind::CreateResourceGroup)
         } else {
             let index = self.index;
 
@@ -3842,6 +3848,42 @@ impl<'a> Parser<'a> {
         Ok(WithOptionValue::RetainHistoryFor(value))
     }
 
+    /// Parses the value of a `RETENTION` option, `[=] '<period>' ON <column>`.
+    fn parse_retention(&mut self) -> Result<RetentionOptionValue, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        let period = self.parse_value()?;
+        self.expect_keyword(ON)?;
+        let column = self.parse_identifier()?;
+        Ok(RetentionOptionValue { period, column })
+    }
+
+    /// Parses the remainder of `ALTER <OBJECT> ... SET (RETENTION ...)` or
+    /// `ALTER <OBJECT> ... RESET (RETENTION)`, after the `RETENTION` keyword.
+    fn parse_alter_retention(
+        &mut self,
+        object_type: ObjectType,
+        if_exists: bool,
+        name: UnresolvedItemName,
+        set: bool,
+    ) -> Result<Statement<Raw>, ParserStatementError> {
+        let retention = if set {
+            Some(
+                self.parse_retention()
+                    .map_parser_err(StatementKind::AlterRetention)?,
+            )
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)
+            .map_parser_err(StatementKind::AlterRetention)?;
+        Ok(Statement::AlterRetention(AlterRetentionStatement {
+            object_type,
+            if_exists,
+            name,
+            retention,
+        }))
+    }
+
     fn parse_materialized_view_refresh_option_value(
         &mut self,
     ) -> Result<WithOptionValue<Raw>, ParserError> {
@@ -3977,6 +4019,9 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(REDACTED) {
             return Ok(TableOptionName::RedactedTest);
         }
+        if self.parse_keyword(RETENTION) {
+            return Ok(TableOptionName::Retention);
+        }
         self.expect_keywords(&[RETAIN, HISTORY])?;
         Ok(TableOptionName::RetainHistory)
     }
@@ -3985,20 +4030,32 @@ impl<'a> Parser<'a> {
         let name = self.parse_table_option_name()?;
         let value = match name {
             TableOptionName::RetainHistory => self.parse_option_retain_history(),
+            TableOptionName::Retention => {
+                Ok(Some(WithOptionValue::Retention(self.parse_retention()?)))
+            }
             TableOptionName::RedactedTest => self.parse_optional_option_value(),
         }?;
         Ok(TableOption { name, value })
     }
 
     fn parse_index_option_name(&mut self) -> Result<IndexOptionName, ParserError> {
-        self.expect_keywords(&[RETAIN, HISTORY])?;
-        Ok(IndexOptionName::RetainHistory)
+        let option = self.expect_one_of_keywords(&[PERSISTED, RETAIN])?;
+        let name = match option {
+            PERSISTED => IndexOptionName::Persisted,
+            RETAIN => {
+                self.expect_keyword(HISTORY)?;
+                IndexOptionName::RetainHistory
+            }
+            _ => unreachable!(),
+        };
+        Ok(name)
     }
 
     fn parse_index_option(&mut self) -> Result<IndexOption<Raw>, ParserError> {
         let name = self.parse_index_option_name()?;
         let value = match name {
             IndexOptionName::RetainHistory => self.parse_option_retain_history(),
+            IndexOptionName::Persisted => self.parse_optional_option_value(),
         }?;
         Ok(IndexOption { name, value })
     }
@@ -4043,11 +4100,11 @@ impl<'a> Parser<'a> {
         self.expect_keyword(ROLE)?;
         let name = self.parse_identifier()?;
         let _ = self.parse_keyword(WITH);
-        let options = self.parse_role_attributes();
+        let options = self.parse_role_attributes()?;
         Ok(Statement::CreateRole(CreateRoleStatement { name, options }))
     }
 
-    fn parse_role_attributes(&mut self) -> Vec<RoleAttribute> {
+    fn parse_role_attributes(&mut self) -> Result<Vec<RoleAttribute>, ParserError> {
         let mut options = vec![];
         loop {
             match self.parse_one_of_keywords(&[
@@ -4063,6 +4120,7 @@ impl<'a> Parser<'a> {
                 NOCREATEDB,
                 CREATEROLE,
                 NOCREATEROLE,
+                PASSWORD,
             ]) {
                 None => break,
                 Some(SUPERUSER) => options.push(RoleAttribute::SuperUser),
@@ -4077,18 +4135,38 @@ impl<'a> Parser<'a> {
                 Some(NOCREATEDB) => options.push(RoleAttribute::NoCreateDB),
                 Some(CREATEROLE) => options.push(RoleAttribute::CreateRole),
                 Some(NOCREATEROLE) => options.push(RoleAttribute::NoCreateRole),
+                Some(PASSWORD) => {
+                    let password = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::Password(password));
+                }
                 Some(_) => unreachable!(),
             }
         }
-        options
+        Ok(options)
     }
 
     fn parse_create_secret(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(SECRET)?;
         let if_not_exists = self.parse_if_not_exists()?;
         let name = self.parse_item_name()?;
-        self.expect_keyword(AS)?;
-        let value = self.parse_expr()?;
+        let value = match self.expect_one_of_keywords(&[AS, FROM])? {
+            AS => CreateSecretValue::Inline(self.parse_expr()?),
+            FROM => {
+                self.expect_keyword(VAULT)?;
+                let path = self.parse_literal_string()?;
+                let key = if self.parse_keyword(KEY) {
+                    Some(self.parse_literal_string()?)
+                } else {
+                    None
+                };
+                CreateSecretValue::Vault { path, key }
+            }
+            _ => unreachable!(),
+        };
         Ok(Statement::CreateSecret(CreateSecretStatement {
             name,
             if_not_exists,
@@ -4187,6 +4265,7 @@ impl<'a> Parser<'a> {
 
     fn parse_cluster_option_name(&mut self) -> Result<ClusterOptionName, ParserError> {
         let option = self.expect_one_of_keywords(&[
+            ARRANGEMENT,
             AVAILABILITY,
             DISK,
             INTROSPECTION,
@@ -4198,6 +4277,10 @@ impl<'a> Parser<'a> {
             WORKLOAD,
         ])?;
         let name = match option {
+            ARRANGEMENT => {
+                self.expect_keyword(SPILL)?;
+                ClusterOptionName::ArrangementSpill
+            }
             AVAILABILITY => {
                 self.expect_keyword(ZONES)?;
                 ClusterOptionName::AvailabilityZones
@@ -4493,12 +4576,39 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(POLICY) {
+            self.parse_drop_policy()
+                .map_parser_err(StatementKind::DropPolicy)
+        } else if self.parse_keywords(&[RESOURCE, GROUP]) {
+            self.parse_drop_resource_group()
+                .map_parser_err(StatementKind::DropResourceGroup)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
         }
     }
 
+    fn parse_drop_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let relation = self.parse_item_name()?;
+        Ok(Statement::DropPolicy(DropPolicyStatement {
+            if_exists,
+            name,
+            relation,
+        }))
+    }
+
+    fn parse_drop_resource_group(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        Ok(Statement::DropResourceGroup(DropResourceGroupStatement {
+            if_exists,
+            name,
+        }))
+    }
+
     fn parse_drop_objects(&mut self) -> Result<Statement<Raw>, ParserError> {
         let object_type = self.expect_object_type()?;
         let if_exists = self.parse_if_exists()?;
@@ -4669,6 +4779,111 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    fn parse_create_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(POLICY)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let relation = self.parse_raw_name()?;
+        let roles = if self.parse_keyword(TO) {
+            self.parse_comma_separated(Parser::expect_role_specification)?
+        } else {
+            vec![]
+        };
+        self.expect_keyword(USING)?;
+        self.expect_token(&Token::LParen)?;
+        let using = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::CreatePolicy(CreatePolicyStatement {
+            name,
+            relation,
+            roles,
+            using,
+        }))
+    }
+
+    fn parse_create_resource_group(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[RESOURCE, GROUP])?;
+        let name = self.parse_identifier()?;
+        let roles = if self.parse_keyword(TO) {
+            self.parse_comma_separated(Parser::expect_role_specification)?
+        } else {
+            vec![]
+        };
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_resource_group_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+        Ok(Statement::CreateResourceGroup(
+            CreateResourceGroupStatement {
+                name,
+                roles,
+                options,
+            },
+        ))
+    }
+
+    fn parse_alter_resource_group(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_identifier()?;
+        let action = match self.expect_one_of_keywords(&[RESET, SET, TO])? {
+            RESET => {
+                self.expect_token(&Token::LParen)?;
+                let names = self.parse_comma_separated(Parser::parse_resource_group_option_name)?;
+                self.expect_token(&Token::RParen)?;
+                AlterResourceGroupAction::ResetOptions(names)
+            }
+            SET => {
+                self.expect_token(&Token::LParen)?;
+                let options = self.parse_comma_separated(Parser::parse_resource_group_option)?;
+                self.expect_token(&Token::RParen)?;
+                AlterResourceGroupAction::SetOptions(options)
+            }
+            TO => {
+                let roles = self.parse_comma_separated(Parser::expect_role_specification)?;
+                AlterResourceGroupAction::SetRoles(roles)
+            }
+            _ => unreachable!(),
+        };
+        Ok(Statement::AlterResourceGroup(AlterResourceGroupStatement {
+            name,
+            action,
+        }))
+    }
+
+    fn parse_resource_group_option_name(&mut self) -> Result<ResourceGroupOptionName, ParserError> {
+        let name = match self.expect_one_of_keywords(&[MAX, STATEMENT])? {
+            MAX => match self.expect_one_of_keywords(&[CONCURRENT, QUEUED, RESULT])? {
+                CONCURRENT => {
+                    self.expect_keyword(PEEKS)?;
+                    ResourceGroupOptionName::MaxConcurrentPeeks
+                }
+                QUEUED => {
+                    self.expect_keyword(PEEKS)?;
+                    ResourceGroupOptionName::MaxQueuedPeeks
+                }
+                RESULT => {
+                    self.expect_keyword(SIZE)?;
+                    ResourceGroupOptionName::MaxResultSize
+                }
+                _ => unreachable!(),
+            },
+            STATEMENT => {
+                self.expect_keyword(TIMEOUT)?;
+                ResourceGroupOptionName::StatementTimeout
+            }
+            _ => unreachable!(),
+        };
+        Ok(name)
+    }
+
+    fn parse_resource_group_option(&mut self) -> Result<ResourceGroupOption<Raw>, ParserError> {
+        let name = self.parse_resource_group_option_name()?;
+        let value = self.parse_optional_option_value()?;
+        Ok(ResourceGroupOption { name, value })
+    }
+
     fn parse_network_policy_option(&mut self) -> Result<NetworkPolicyOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[RULES])? {
             RULES => NetworkPolicyOptionName::Rules,
@@ -4722,8 +4937,15 @@ impl<'a> Parser<'a> {
         self.expect_keyword(TABLE)?;
         let if_not_exists = self.parse_if_not_exists()?;
         let table_name = self.parse_item_name()?;
-        // parse optional column list (schema)
-        let (columns, constraints) = self.parse_columns(Mandatory)?;
+        let (columns, constraints, clone_of) = if self.parse_keyword(CLONE) {
+            let source = self.parse_raw_name()?;
+            let as_of = self.parse_optional_as_of()?;
+            (vec![], vec![], Some(TableClone { source, as_of }))
+        } else {
+            // parse optional column list (schema)
+            let (columns, constraints) = self.parse_columns(Mandatory)?;
+            (columns, constraints, None)
+        };
 
         let with_options = if self.parse_keyword(WITH) {
             self.expect_token(&Token::LParen)?;
@@ -4745,6 +4967,7 @@ impl<'a> Parser<'a> {
             if_not_exists,
             temporary,
             with_options,
+            clone_of,
         }))
     }
 
@@ -5254,6 +5477,9 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[DEFAULT, PRIVILEGES]) {
             self.parse_alter_default_privileges()
                 .map_parser_err(StatementKind::AlterDefaultPrivileges)
+        } else if self.parse_keywords(&[RESOURCE, GROUP]) {
+            self.parse_alter_resource_group()
+                .map_parser_err(StatementKind::AlterResourceGroup)
         } else {
             self.parse_alter_object()
         }
@@ -5469,9 +5695,21 @@ impl<'a> Parser<'a> {
 
         Ok(
             match self
-                .expect_one_of_keywords(&[ADD, DROP, RESET, SET, RENAME, OWNER, REFRESH])
+                .expect_one_of_keywords(&[
+                    ADD, DROP, RESET, SET, RENAME, OWNER, REFRESH, ENABLE, DISABLE,
+                ])
                 .map_no_statement_parser_err()?
             {
+                action @ (ENABLE | DISABLE) => {
+                    self.expect_keywords(&[ROW, LEVEL, SECURITY])
+                        .map_parser_err(StatementKind::AlterRowSecurity)?;
+                    Statement::AlterRowSecurity(AlterRowSecurityStatement {
+                        object_type: ObjectType::Source,
+                        if_exists,
+                        name: source_name,
+                        enabled: action == ENABLE,
+                    })
+                }
                 ADD => {
                     self.expect_one_of_keywords(&[SUBSOURCE, TABLE])
                         .map_parser_err(StatementKind::AlterSource)?;
@@ -6016,7 +6254,7 @@ impl<'a> Parser<'a> {
             }
             Some(WITH) | None => {
                 let _ = self.parse_keyword(WITH);
-                let attrs = self.parse_role_attributes();
+                let attrs = self.parse_role_attributes()?;
                 AlterRoleOption::Attributes(attrs)
             }
             Some(k) => unreachable!("unmatched keyword: {k}"),
@@ -6051,7 +6289,7 @@ impl<'a> Parser<'a> {
             GrantTargetAllSpecification::All
         };
         let is_grant = self.expect_one_of_keywords(&[GRANT, REVOKE])? == GRANT;
-        let privileges = self.parse_privilege_specification().ok_or_else(|| {
+        let privileges = self.parse_privilege_specification()?.ok_or_else(|| {
             self.expected::<_, PrivilegeSpecification>(
                 self.peek_pos(),
                 "ALL or INSERT or SELECT or UPDATE or DELETE or USAGE or CREATE",
@@ -6059,6 +6297,13 @@ impl<'a> Parser<'a> {
             )
             .expect_err("only returns errors")
         })?;
+        if let PrivilegeSpecification::Columns(_) = privileges {
+            return parser_err!(
+                self,
+                self.peek_prev_pos(),
+                "column privileges are not supported in ALTER DEFAULT PRIVILEGES"
+            );
+        }
         self.expect_keyword(ON)?;
         let object_type =
             self.expect_grant_revoke_plural_object_type(if is_grant { "GRANT" } else { "REVOKE" })?;
@@ -6099,9 +6344,9 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let keywords = if object_type == ObjectType::Table {
-            [SET, RENAME, OWNER, RESET, ADD].as_slice()
+            [SET, RENAME, OWNER, RESET, ADD, ENABLE, DISABLE, ALTER].as_slice()
         } else {
-            [SET, RENAME, OWNER, RESET].as_slice()
+            [SET, RENAME, OWNER, RESET, ENABLE, DISABLE, ALTER].as_slice()
         };
 
         let action = self
@@ -6126,6 +6371,9 @@ impl<'a> Parser<'a> {
                 } else {
                     self.expect_token(&Token::LParen)
                         .map_no_statement_parser_err()?;
+                    if self.parse_keyword(RETENTION) {
+                        return self.parse_alter_retention(object_type, if_exists, name, true);
+                    }
                     self.expect_keywords(&[RETAIN, HISTORY])
                         .map_parser_err(StatementKind::AlterRetainHistory)?;
                     let history = self
@@ -6144,6 +6392,9 @@ impl<'a> Parser<'a> {
             RESET => {
                 self.expect_token(&Token::LParen)
                     .map_no_statement_parser_err()?;
+                if self.parse_keyword(RETENTION) {
+                    return self.parse_alter_retention(object_type, if_exists, name, false);
+                }
                 self.expect_keywords(&[RETAIN, HISTORY])
                     .map_parser_err(StatementKind::AlterRetainHistory)?;
                 self.expect_token(&Token::RParen)
@@ -6167,6 +6418,53 @@ impl<'a> Parser<'a> {
                     new_owner,
                 }))
             }
+            ENABLE | DISABLE => {
+                self.expect_keywords(&[ROW, LEVEL, SECURITY])
+                    .map_parser_err(StatementKind::AlterRowSecurity)?;
+                Ok(Statement::AlterRowSecurity(AlterRowSecurityStatement {
+                    object_type,
+                    if_exists,
+                    name,
+                    enabled: action == ENABLE,
+                }))
+            }
+            ALTER => {
+                self.expect_keyword(COLUMN)
+                    .map_parser_err(StatementKind::AlterColumnMask)?;
+                let column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterColumnMask)?;
+                let mask = match self
+                    .expect_one_of_keywords(&[SET, DROP])
+                    .map_parser_err(StatementKind::AlterColumnMask)?
+                {
+                    SET => {
+                        self.expect_keyword(MASK)
+                            .map_parser_err(StatementKind::AlterColumnMask)?;
+                        self.expect_token(&Token::LParen)
+                            .map_parser_err(StatementKind::AlterColumnMask)?;
+                        let mask = self
+                            .parse_expr()
+                            .map_parser_err(StatementKind::AlterColumnMask)?;
+                        self.expect_token(&Token::RParen)
+                            .map_parser_err(StatementKind::AlterColumnMask)?;
+                        Some(mask)
+                    }
+                    DROP => {
+                        self.expect_keyword(MASK)
+                            .map_parser_err(StatementKind::AlterColumnMask)?;
+                        None
+                    }
+                    _ => unreachable!(),
+                };
+                Ok(Statement::AlterColumnMask(AlterColumnMaskStatement {
+                    object_type,
+                    if_exists,
+                    name,
+                    column_name,
+                    mask,
+                }))
+            }
             ADD => {
                 assert_eq!(object_type, ObjectType::Table, "checked object_type above");
 
@@ -8830,7 +9128,10 @@ impl<'a> Parser<'a> {
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::GrantPrivileges)?
+        {
             Some(privileges) => self
                 .parse_grant_privilege(privileges)
                 .map_parser_err(StatementKind::GrantPrivileges),
@@ -8872,7 +9173,10 @@ impl<'a> Parser<'a> {
     /// Parse a `REVOKE` statement, assuming that the `REVOKE` token
     /// has already been consumed.
     fn parse_revoke(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::RevokePrivileges)?
+        {
             Some(privileges) => self
                 .parse_revoke_privilege(privileges)
                 .map_parser_err(StatementKind::RevokePrivileges),
@@ -9397,26 +9701,46 @@ impl<'a> Parser<'a> {
         )
     }
 
-    /// Parse one or more privileges separated by a ','.
-    fn parse_privilege_specification(&mut self) -> Option<PrivilegeSpecification> {
+    /// Parse one or more privileges separated by a ','. Each privilege may be
+    /// followed by a parenthesized list of columns, in which case all of them
+    /// must be.
+    fn parse_privilege_specification(
+        &mut self,
+    ) -> Result<Option<PrivilegeSpecification>, ParserError> {
         if self.parse_keyword(ALL) {
             let _ = self.parse_keyword(PRIVILEGES);
-            return Some(PrivilegeSpecification::All);
+            return Ok(Some(PrivilegeSpecification::All));
         }
 
         let mut privileges = Vec::new();
+        let mut column_privileges = Vec::new();
         while let Some(privilege) = self.parse_privilege() {
-            privileges.push(privilege);
+            if self.consume_token(&Token::LParen) {
+                let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+                self.expect_token(&Token::RParen)?;
+                column_privileges.push(ColumnPrivilege { privilege, columns });
+            } else {
+                privileges.push(privilege);
+            }
+            if !privileges.is_empty() && !column_privileges.is_empty() {
+                return parser_err!(
+                    self,
+                    self.peek_prev_pos(),
+                    "cannot combine column privileges with privileges on whole objects"
+                );
+            }
             if !self.consume_token(&Token::Comma) {
                 break;
             }
         }
 
-        if privileges.is_empty() {
-            None
-        } else {
+        Ok(if !column_privileges.is_empty() {
+            Some(PrivilegeSpecification::Columns(column_privileges))
+        } else if !privileges.is_empty() {
             Some(PrivilegeSpecification::Privileges(privileges))
-        }
+        } else {
+            None
+        })
     }
 
     /// Bail out if the current token is not a role specification, or consume and return it if it is.
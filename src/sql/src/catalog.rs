@@ -83,6 +83,9 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
     /// Returns the id of the role that is issuing the query.
     fn active_role_id(&self) -> &RoleId;
 
+    /// Reports whether the user that is issuing the query is a superuser.
+    fn is_superuser(&self) -> bool;
+
     /// Returns the database to use if one is not explicitly specified.
     fn active_database_name(&self) -> Option<&str> {
         self.active_database()
@@ -365,6 +368,21 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
 
     /// Returns the associated comments for the given `id`
     fn get_item_comments(&self, id: &GlobalId) -> Option<&BTreeMap<Option<usize>, String>>;
+
+    /// Returns the row-level security configuration of the relation with the
+    /// given `id`, if any has been configured.
+    fn get_row_security(&self, id: &GlobalId) -> Option<&RowSecurity>;
+
+    /// Returns the column-level security configuration of the relation with
+    /// the given `id`, keyed by column position, if any has been configured.
+    fn get_column_security(&self, id: &GlobalId) -> Option<&BTreeMap<usize, ColumnSecurity>>;
+
+    /// Returns the resource group named `name`, if it exists.
+    fn get_resource_group(&self, name: &str) -> Option<&ResourceGroup>;
+
+    /// Returns the name and configuration of the resource group that the role
+    /// with the given `id` has been assigned to, if any.
+    fn get_role_resource_group(&self, id: &RoleId) -> Option<(&str, &ResourceGroup)>;
 }
 
 /// Configuration associated with a catalog.
@@ -448,19 +466,34 @@ pub trait CatalogSchema {
 }
 
 /// Attributes belonging to a [`CatalogRole`].
-#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Arbitrary)]
+#[derive(Clone, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Arbitrary)]
 pub struct RoleAttributes {
     /// Indicates whether the role has inheritance of privileges.
     pub inherit: bool,
+    /// The SCRAM-SHA-256 verifier of the role's password, if it has one.
+    ///
+    /// This is never the plaintext password. It is omitted from serialized and debug output.
+    #[serde(skip)]
+    pub password: Option<String>,
     // Force use of constructor.
     _private: (),
 }
 
+impl fmt::Debug for RoleAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RoleAttributes")
+            .field("inherit", &self.inherit)
+            .field("password", &self.password.as_ref().map(|_| "<REDACTED>"))
+            .finish_non_exhaustive()
+    }
+}
+
 impl RoleAttributes {
     /// Creates a new [`RoleAttributes`] with default attributes.
     pub const fn new() -> RoleAttributes {
         RoleAttributes {
             inherit: true,
+            password: None,
             _private: (),
         }
     }
@@ -475,13 +508,19 @@ impl RoleAttributes {
     pub const fn is_inherit(&self) -> bool {
         self.inherit
     }
+
+    /// Returns whether or not the role has a password.
+    pub const fn has_password(&self) -> bool {
+        self.password.is_some()
+    }
 }
 
 impl From<PlannedRoleAttributes> for RoleAttributes {
-    fn from(PlannedRoleAttributes { inherit }: PlannedRoleAttributes) -> RoleAttributes {
+    fn from(PlannedRoleAttributes { inherit, password }: PlannedRoleAttributes) -> RoleAttributes {
         let default_attributes = RoleAttributes::new();
         RoleAttributes {
             inherit: inherit.unwrap_or(default_attributes.inherit),
+            password: password.unwrap_or(default_attributes.password),
             _private: (),
         }
     }
@@ -1700,6 +1739,98 @@ impl DefaultPrivilegeAclItem {
     }
 }
 
+/// The row-level security configuration of a relation.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct RowSecurity {
+    /// Whether row-level security is enforced for the relation.
+    pub enabled: bool,
+    /// The policies of the relation, keyed by name.
+    pub policies: BTreeMap<String, RowSecurityPolicy>,
+}
+
+impl RowSecurity {
+    /// Returns the policies that apply to a session whose active role is a
+    /// member of `role_membership`.
+    pub fn applicable_policies<'a>(
+        &'a self,
+        role_membership: &'a BTreeSet<RoleId>,
+    ) -> impl Iterator<Item = (&'a String, &'a RowSecurityPolicy)> {
+        self.policies
+            .iter()
+            .filter(|(_, policy)| !policy.roles.is_disjoint(role_membership))
+    }
+
+    /// Reports whether this configuration carries no information and can be
+    /// removed from the catalog.
+    pub fn is_empty(&self) -> bool {
+        !self.enabled && self.policies.is_empty()
+    }
+}
+
+/// A row-level security policy.
+///
+/// A row of the relation is visible to a role covered by the policy iff the
+/// `using` predicate evaluates to true for that row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RowSecurityPolicy {
+    /// The roles the policy applies to.
+    pub roles: BTreeSet<RoleId>,
+    /// The SQL text of the predicate, with all names fully qualified.
+    pub using: String,
+}
+
+/// The column-level security configuration of a column of a relation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnSecurity {
+    /// The privileges that have been granted on the column.
+    pub privileges: PrivilegeMap,
+    /// The SQL text of the expression that replaces the values of the column
+    /// for roles that have not been granted `SELECT` on the column, with all
+    /// names fully qualified.
+    pub mask: Option<String>,
+}
+
+impl ColumnSecurity {
+    /// Reports whether a session whose active role is a member of
+    /// `role_membership` has been granted all of `acl_mode` on the column.
+    pub fn has_privileges(&self, role_membership: &BTreeSet<RoleId>, acl_mode: AclMode) -> bool {
+        role_membership
+            .iter()
+            .flat_map(|role_id| self.privileges.get_acl_items_for_grantee(role_id))
+            .map(|mz_acl_item| mz_acl_item.acl_mode)
+            .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode))
+            .contains(acl_mode)
+    }
+
+    /// Reports whether this configuration carries no information and can be
+    /// removed from the catalog.
+    pub fn is_empty(&self) -> bool {
+        self.privileges.all_values().next().is_none() && self.mask.is_none()
+    }
+}
+
+/// A resource group, which limits the resources that may be consumed by the
+/// sessions of the roles assigned to it.
+///
+/// Unlike the corresponding session variables, these limits cannot be
+/// overridden by the sessions they apply to.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct ResourceGroup {
+    /// The roles assigned to the group.
+    pub roles: BTreeSet<RoleId>,
+    /// The maximum number of peeks that may execute concurrently across all
+    /// sessions of the group.
+    pub max_concurrent_peeks: Option<u32>,
+    /// The maximum number of peeks that may wait for admission once
+    /// `max_concurrent_peeks` has been reached. Peeks beyond this limit are
+    /// rejected.
+    pub max_queued_peeks: Option<u32>,
+    /// The maximum duration of a statement, including any time spent queued.
+    pub statement_timeout: Option<Duration>,
+    /// The maximum size in bytes of the result of a query.
+    pub max_result_size: Option<u64>,
+}
+
 /// Which builtins to return in `BUILTINS::iter`.
 ///
 /// All calls to `BUILTINS::iter` within the lifetime of an environmentd process
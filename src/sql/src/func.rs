@@ -4187,6 +4187,41 @@ pub static MZ_INTERNAL_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLo
                 ) AS i
             )") => ScalarType::Array(Box::new(ScalarType::String)), oid::FUNC_MZ_NORMALIZE_SCHEMA_NAME;
         },
+        // Both of these are resolved by the coordinator, which is also where
+        // the superuser check happens, so they can only be called from one-off
+        // SELECTs.
+        "mz_persist_shard_parts" => Table {
+            params!(String) => Operation::unary(move |_ecx, id| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::MzPersistShardParts,
+                        exprs: vec![id],
+                    },
+                    column_names: vec![
+                        "batch".into(), "batch_lower".into(), "batch_upper".into(),
+                        "batch_since".into(), "run".into(), "part".into(), "part_type".into(),
+                        "key".into(), "encoded_size_bytes".into(), "diffs_sum".into(),
+                        "ts_rewrite".into(), "stats".into(),
+                    ],
+                })
+            }) => ReturnType::set_of(RecordAny), oid::FUNC_MZ_PERSIST_SHARD_PARTS_OID;
+        },
+        "mz_persist_shard_state" => Table {
+            params!(String) => Operation::unary(move |_ecx, id| {
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::MzPersistShardState,
+                        exprs: vec![id],
+                    },
+                    column_names: vec![
+                        "shard_id".into(), "seqno".into(), "since".into(), "upper".into(),
+                        "batch_count".into(), "part_count".into(), "encoded_size_bytes".into(),
+                        "update_count".into(), "reader_count".into(), "writer_count".into(),
+                        "state".into(),
+                    ],
+                })
+            }) => ReturnType::set_of(RecordAny), oid::FUNC_MZ_PERSIST_SHARD_STATE_OID;
+        },
         "mz_render_typmod" => Scalar {
             params!(Oid, Int32) => BinaryFunc::MzRenderTypmod => String, oid::FUNC_MZ_RENDER_TYPMOD_OID;
         },
@@ -330,6 +330,7 @@ pub fn create_statement(
             if_not_exists,
             temporary,
             with_options: _,
+            clone_of: _,
         }) => {
             *name = if *temporary {
                 allocate_temporary_name(name)?
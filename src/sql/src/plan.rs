@@ -49,8 +49,8 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType, Timestamp};
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ClusterAlterOptionValue, ConnectionOptionName, QualifiedReplica,
-    SelectStatement, TransactionIsolationLevel, TransactionMode, UnresolvedItemName, Value,
-    WithOptionValue,
+    RetentionOptionValue, SelectStatement, TransactionIsolationLevel, TransactionMode,
+    UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_ssh_util::keys::SshKeyPair;
 use mz_storage_types::connections::aws::AwsConnection;
@@ -74,7 +74,7 @@ use crate::ast::{
 };
 use crate::catalog::{
     CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
-    RoleAttributes,
+    ResourceGroup, RoleAttributes, RowSecurityPolicy,
 };
 use crate::names::{
     Aug, CommentObjectId, FullItemName, ObjectId, QualifiedItemName, ResolvedDataType,
@@ -142,6 +142,8 @@ pub enum Plan {
     CreateMaterializedView(CreateMaterializedViewPlan),
     CreateContinualTask(CreateContinualTaskPlan),
     CreateNetworkPolicy(CreateNetworkPolicyPlan),
+    CreatePolicy(CreatePolicyPlan),
+    CreateResourceGroup(CreateResourceGroupPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
     Comment(CommentPlan),
@@ -149,6 +151,8 @@ pub enum Plan {
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropPolicy(DropPolicyPlan),
+    DropResourceGroup(DropResourceGroupPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -190,6 +194,7 @@ pub enum Plan {
     AlterOwner(AlterOwnerPlan),
     AlterTableAddColumn(AlterTablePlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
+    AlterResourceGroup(AlterResourceGroupPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
     Close(ClosePlan),
@@ -207,6 +212,9 @@ pub enum Plan {
     SideEffectingFunc(SideEffectingFunc),
     ValidateConnection(ValidateConnectionPlan),
     AlterRetainHistory(AlterRetainHistoryPlan),
+    AlterRetention(AlterRetentionPlan),
+    AlterRowSecurity(AlterRowSecurityPlan),
+    AlterColumnMask(AlterColumnMaskPlan),
 }
 
 impl Plan {
@@ -232,6 +240,7 @@ impl Plan {
             ],
             StatementKind::AlterRole => &[PlanKind::AlterRole],
             StatementKind::AlterNetworkPolicy => &[PlanKind::AlterNetworkPolicy],
+            StatementKind::AlterResourceGroup => &[PlanKind::AlterResourceGroup],
             StatementKind::AlterSecret => &[PlanKind::AlterNoop, PlanKind::AlterSecret],
             StatementKind::AlterSetCluster => &[PlanKind::AlterNoop, PlanKind::AlterSetCluster],
             StatementKind::AlterSink => &[PlanKind::AlterNoop, PlanKind::AlterSink],
@@ -264,6 +273,8 @@ impl Plan {
             StatementKind::CreateDatabase => &[PlanKind::CreateDatabase],
             StatementKind::CreateIndex => &[PlanKind::CreateIndex],
             StatementKind::CreateNetworkPolicy => &[PlanKind::CreateNetworkPolicy],
+            StatementKind::CreatePolicy => &[PlanKind::CreatePolicy],
+            StatementKind::CreateResourceGroup => &[PlanKind::CreateResourceGroup],
             StatementKind::CreateMaterializedView => &[PlanKind::CreateMaterializedView],
             StatementKind::CreateContinualTask => &[PlanKind::CreateContinualTask],
             StatementKind::CreateRole => &[PlanKind::CreateRole],
@@ -283,6 +294,8 @@ impl Plan {
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
+            StatementKind::DropPolicy => &[PlanKind::DropPolicy],
+            StatementKind::DropResourceGroup => &[PlanKind::DropResourceGroup],
             StatementKind::Execute => &[PlanKind::Execute],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
@@ -315,6 +328,9 @@ impl Plan {
             StatementKind::Update => &[PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => &[PlanKind::ValidateConnection],
             StatementKind::AlterRetainHistory => &[PlanKind::AlterRetainHistory],
+            StatementKind::AlterRetention => &[PlanKind::AlterNoop, PlanKind::AlterRetention],
+            StatementKind::AlterRowSecurity => &[PlanKind::AlterNoop, PlanKind::AlterRowSecurity],
+            StatementKind::AlterColumnMask => &[PlanKind::AlterNoop, PlanKind::AlterColumnMask],
         }
     }
 
@@ -338,6 +354,8 @@ impl Plan {
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
             Plan::CreateNetworkPolicy(_) => "create network policy",
+            Plan::CreatePolicy(_) => "create policy",
+            Plan::CreateResourceGroup(_) => "create resource group",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -361,6 +379,8 @@ impl Plan {
                 ObjectType::NetworkPolicy => "drop network policy",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropPolicy(_) => "drop policy",
+            Plan::DropResourceGroup(_) => "drop resource group",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -418,6 +438,7 @@ impl Plan {
             Plan::AlterSystemResetAll(_) => "alter system",
             Plan::AlterRole(_) => "alter role",
             Plan::AlterNetworkPolicy(_) => "alter network policy",
+            Plan::AlterResourceGroup(_) => "alter resource group",
             Plan::AlterOwner(plan) => match plan.object_type {
                 ObjectType::Table => "alter table owner",
                 ObjectType::View => "alter view owner",
@@ -459,6 +480,9 @@ impl Plan {
             Plan::SideEffectingFunc(_) => "side effecting func",
             Plan::ValidateConnection(_) => "validate connection",
             Plan::AlterRetainHistory(_) => "alter retain history",
+            Plan::AlterRetention(_) => "alter retention",
+            Plan::AlterRowSecurity(_) => "alter row level security",
+            Plan::AlterColumnMask(_) => "alter column mask",
         }
     }
 
@@ -552,6 +576,7 @@ pub struct CreateClusterPlan {
     pub name: String,
     pub variant: CreateClusterVariant,
     pub workload_class: Option<String>,
+    pub arrangement_spill: Option<bool>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -707,6 +732,19 @@ pub struct CreateTablePlan {
     pub name: QualifiedItemName,
     pub table: Table,
     pub if_not_exists: bool,
+    /// The relation whose contents the table starts with, for `CREATE TABLE
+    /// ... CLONE`.
+    pub clone_of: Option<TableCloneSource>,
+}
+
+/// The relation cloned by a `CREATE TABLE ... CLONE`.
+#[derive(Debug)]
+pub struct TableCloneSource {
+    /// The table or materialized view to clone.
+    pub id: GlobalId,
+    /// The time at which to clone it, or `None` to clone it at the latest
+    /// time at which it is readable.
+    pub as_of: Option<MirScalarExpr>,
 }
 
 #[derive(Debug, Clone)]
@@ -1110,6 +1148,32 @@ pub struct AlterRetainHistoryPlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterRetentionPlan {
+    pub id: GlobalId,
+    /// The original value of the option, written back to the item's `create_sql`. `None` for
+    /// `RESET (RETENTION)`.
+    pub value: Option<RetentionOptionValue>,
+    pub policy: Option<RetentionPolicy>,
+}
+
+#[derive(Debug)]
+pub struct AlterRowSecurityPlan {
+    pub id: GlobalId,
+    pub enabled: bool,
+    pub object_type: ObjectType,
+}
+
+#[derive(Debug)]
+pub struct AlterColumnMaskPlan {
+    pub id: GlobalId,
+    /// The position of the column in the relation.
+    pub column_pos: usize,
+    /// The SQL text of the mask, or `None` if the mask is being dropped.
+    pub mask: Option<String>,
+    pub object_type: ObjectType,
+}
+
 #[derive(Debug, Clone)]
 
 pub enum AlterOptionParameter<T = String> {
@@ -1325,6 +1389,9 @@ pub struct UpdatePrivilege {
     pub acl_mode: AclMode,
     /// The ID of the object receiving privileges.
     pub target_id: SystemObjectId,
+    /// The positions of the columns receiving privileges, or empty if the
+    /// privileges apply to the whole object.
+    pub column_positions: Vec<usize>,
     /// The role that is granting the privileges.
     pub grantor: RoleId,
 }
@@ -1364,6 +1431,42 @@ pub struct ReassignOwnedPlan {
     pub reassign_ids: Vec<ObjectId>,
 }
 
+#[derive(Debug)]
+pub struct CreatePolicyPlan {
+    /// The relation the policy applies to.
+    pub relation_id: GlobalId,
+    /// The name of the policy.
+    pub name: String,
+    pub policy: RowSecurityPolicy,
+}
+
+#[derive(Debug)]
+pub struct DropPolicyPlan {
+    /// The relation and name of the policy to drop, or `None` if the policy
+    /// does not exist and `IF EXISTS` was specified.
+    pub policy: Option<(GlobalId, String)>,
+}
+
+#[derive(Debug)]
+pub struct CreateResourceGroupPlan {
+    pub name: String,
+    pub group: ResourceGroup,
+}
+
+#[derive(Debug)]
+pub struct AlterResourceGroupPlan {
+    pub name: String,
+    /// The configuration of the group after the alteration.
+    pub group: ResourceGroup,
+}
+
+#[derive(Debug)]
+pub struct DropResourceGroupPlan {
+    /// The name of the group to drop, or `None` if the group does not exist
+    /// and `IF EXISTS` was specified.
+    pub name: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct CommentPlan {
     /// The object that this comment is associated with.
@@ -1395,9 +1498,20 @@ pub struct Table {
     pub desc: RelationDesc,
     pub temporary: bool,
     pub compaction_window: Option<CompactionWindow>,
+    pub retention: Option<RetentionPolicy>,
     pub data_source: TableDataSource,
 }
 
+/// A policy that retracts the rows of a table whose timestamp column has fallen more than
+/// `period` behind the current time.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// How long rows are retained.
+    pub period: Duration,
+    /// The index of the `date`, `timestamp`, or `timestamptz` column that rows are expired by.
+    pub column: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct Source {
     pub create_sql: String,
@@ -1691,6 +1805,9 @@ impl SshKey {
 pub struct Secret {
     pub create_sql: String,
     pub secret_as: MirScalarExpr,
+    /// Whether `secret_as` evaluates to a reference to a secret stored outside
+    /// of Materialize, rather than to the secret's value.
+    pub external: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1731,6 +1848,8 @@ pub struct Index {
     pub keys: Vec<mz_expr::MirScalarExpr>,
     pub compaction_window: Option<CompactionWindow>,
     pub cluster_id: ClusterId,
+    /// Whether the index is written to persist, to be shared by dataflows on other clusters.
+    pub persisted: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1836,12 +1955,16 @@ pub enum ExecuteTimeout {
 pub enum IndexOption {
     /// Configures the logical compaction window for an index.
     RetainHistory(CompactionWindow),
+    /// Writes the index to persist.
+    Persisted,
 }
 
 #[derive(Clone, Debug)]
 pub enum TableOption {
     /// Configures the logical compaction window for a table.
     RetainHistory(CompactionWindow),
+    /// Configures the retention policy for a table.
+    Retention(RetentionPolicy),
 }
 
 #[derive(Clone, Debug)]
@@ -1856,6 +1979,7 @@ pub struct PlanClusterOption {
     pub disk: AlterOptionParameter<bool>,
     pub schedule: AlterOptionParameter<ClusterSchedule>,
     pub workload_class: AlterOptionParameter<Option<String>>,
+    pub arrangement_spill: AlterOptionParameter<Option<bool>>,
 }
 
 impl Default for PlanClusterOption {
@@ -1871,6 +1995,7 @@ impl Default for PlanClusterOption {
             disk: AlterOptionParameter::Unchanged,
             schedule: AlterOptionParameter::Unchanged,
             workload_class: AlterOptionParameter::Unchanged,
+            arrangement_spill: AlterOptionParameter::Unchanged,
         }
     }
 }
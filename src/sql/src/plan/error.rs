@@ -72,6 +72,10 @@ pub enum PlanError {
         table: Option<PartialItemName>,
         column: ColumnName,
     },
+    ColumnPermissionDenied {
+        table: Option<PartialItemName>,
+        column: ColumnName,
+    },
     AmbiguousColumn(ColumnName),
     TooManyColumns {
         max_num_columns: usize,
@@ -492,6 +496,11 @@ impl fmt::Display for PlanError {
                 the combining JOIN type must be INNER or LEFT for a LATERAL reference",
                 ColumnDisplay { table, column },
             ),
+            Self::ColumnPermissionDenied { table, column } => write!(
+                f,
+                "permission denied for column {}",
+                ColumnDisplay { table, column },
+            ),
             Self::AmbiguousColumn(column) => write!(
                 f,
                 "column reference {} is ambiguous",
@@ -37,6 +37,13 @@ pub enum PlanNotice {
         column_name: String,
         object_name: String,
     },
+    PolicyDoesNotExist {
+        name: String,
+        relation_name: String,
+    },
+    ResourceGroupDoesNotExist {
+        name: String,
+    },
     UpsertSinkKeyNotEnforced {
         key: Vec<ColumnName>,
         name: String,
@@ -95,6 +102,24 @@ impl fmt::Display for PlanNotice {
                     object_name.quoted()
                 )
             }
+            PlanNotice::PolicyDoesNotExist {
+                name,
+                relation_name,
+            } => {
+                write!(
+                    f,
+                    "policy {} for relation {} does not exist, skipping",
+                    name.quoted(),
+                    relation_name.quoted()
+                )
+            }
+            PlanNotice::ResourceGroupDoesNotExist { name } => {
+                write!(
+                    f,
+                    "resource group {} does not exist, skipping",
+                    name.quoted()
+                )
+            }
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
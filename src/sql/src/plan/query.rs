@@ -47,6 +47,7 @@ use mz_ore::option::FallibleMapExt;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
+use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::adt::timestamp::TimestampPrecision;
 use mz_repr::adt::varchar::VarCharMaxLength;
@@ -70,10 +71,10 @@ use mz_sql_parser::ast::{
 use mz_sql_parser::ident;
 use uuid::Uuid;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, SessionCatalog};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{
-    Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
+    self, Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
 };
 use crate::normalize;
 use crate::plan::error::PlanError;
@@ -709,7 +710,7 @@ pub fn plan_mutation_query_inner(
     }
 
     // Derive structs for operation from validated table
-    let (mut get, scope) = qcx.resolve_table_name(table_name)?;
+    let (mut get, scope) = qcx.resolve_mutation_target(table_name)?;
     let scope = plan_table_alias(scope, alias.as_ref())?;
     let desc = item.desc(&qcx.scx.catalog.resolve_full_name(item.name()))?;
     let relation_type = qcx.relation_type(&get);
@@ -1207,6 +1208,56 @@ pub fn plan_default_expr(
     Ok(hir)
 }
 
+/// Plans the `USING` predicate of a row-level security policy against a
+/// relation described by `desc`.
+pub fn plan_row_security_predicate(
+    scx: &StatementContext,
+    lifetime: QueryLifetime,
+    desc: &RelationDesc,
+    mut expr: Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    transform_ast::transform(scx, &mut expr)?;
+    let scope = Scope::from_source(None, desc.iter_names());
+    let qcx = QueryContext::root(scx, lifetime);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "USING clause",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(ecx, &expr)?.type_as(ecx, &ScalarType::Bool)
+}
+
+/// Plans the mask of the column at position `column_pos` of a relation
+/// described by `desc`.
+pub fn plan_column_mask(
+    scx: &StatementContext,
+    lifetime: QueryLifetime,
+    desc: &RelationDesc,
+    column_pos: usize,
+    mut expr: Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    transform_ast::transform(scx, &mut expr)?;
+    let scope = Scope::from_source(None, desc.iter_names());
+    let qcx = QueryContext::root(scx, lifetime);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "MASK expression",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let target_ty = &desc.typ().column_types[column_pos].scalar_type;
+    plan_expr(ecx, &expr)?.cast_to(ecx, CastContext::Assignment, target_ty)
+}
+
 pub fn plan_params<'a>(
     scx: &'a StatementContext,
     params: Vec<Expr<Aug>>,
@@ -3363,10 +3414,11 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.is_from_table(&table_name))
                 .map(|(i, item)| {
+                    item.check_referenceable()?;
                     let name = item.column_name.clone();
-                    (ExpandedSelectItem::InputOrdinal(i), name)
+                    Ok((ExpandedSelectItem::InputOrdinal(i), name))
                 })
-                .collect();
+                .collect::<Result<_, PlanError>>()?;
             if out.is_empty() {
                 sql_bail!("no table named '{}' in scope", table_name);
             }
@@ -3433,10 +3485,11 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.allow_unqualified_references)
                 .map(|(i, item)| {
+                    item.check_referenceable()?;
                     let name = item.column_name.clone();
-                    (ExpandedSelectItem::InputOrdinal(i), name)
+                    Ok((ExpandedSelectItem::InputOrdinal(i), name))
                 })
-                .collect();
+                .collect::<Result<_, PlanError>>()?;
 
             Ok(items)
         }
@@ -4994,6 +5047,9 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         // The name refers to a normal table. Return a record containing all the
         // columns of the table.
         _ => {
+            for (_, item) in &items {
+                item.check_referenceable()?;
+            }
             let mut has_exists_column = None;
             let (exprs, field_names): (Vec<_>, Vec<_>) = items
                 .into_iter()
@@ -6238,6 +6294,25 @@ impl<'a> QueryContext<'a> {
     pub fn resolve_table_name(
         &self,
         object: ResolvedItemName,
+    ) -> Result<(HirRelationExpr, Scope), PlanError> {
+        self.resolve_table_name_inner(object, true)
+    }
+
+    /// Like [`QueryContext::resolve_table_name`], but for the target of an
+    /// `UPDATE` or `DELETE`, whose rows are written back to it. Masked columns
+    /// are inaccessible rather than masked, unless the active role may read
+    /// them.
+    pub fn resolve_mutation_target(
+        &self,
+        object: ResolvedItemName,
+    ) -> Result<(HirRelationExpr, Scope), PlanError> {
+        self.resolve_table_name_inner(object, false)
+    }
+
+    fn resolve_table_name_inner(
+        &self,
+        object: ResolvedItemName,
+        apply_masks: bool,
     ) -> Result<(HirRelationExpr, Scope), PlanError> {
         match object {
             ResolvedItemName::Item { id, full_name, .. } => {
@@ -6250,8 +6325,11 @@ impl<'a> QueryContext<'a> {
                     id: Id::Global(item.id()),
                     typ: desc.typ().clone(),
                 };
+                let expr = self.apply_row_security(item, &desc, expr)?;
 
-                let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let mut scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let expr =
+                    self.apply_column_security(item, &desc, expr, &mut scope, apply_masks)?;
 
                 Ok((expr, scope))
             }
@@ -6282,6 +6360,158 @@ impl<'a> QueryContext<'a> {
         }
     }
 
+    /// Restricts `expr`, a `Get` of `item`, to the rows that the row-level
+    /// security policies of `item` make visible to the active role.
+    ///
+    /// A row is visible if any policy that applies to the active role accepts
+    /// it; if no policy applies, no row is visible. System roles, superusers,
+    /// and members of the role that owns `item` are not subject to row-level
+    /// security.
+    ///
+    /// Unmaterializable functions in the policies, like `current_role`, are
+    /// inlined when the query is optimized, which allows literal constraints
+    /// in the policies to be used for index lookups.
+    fn apply_row_security(
+        &self,
+        item: &dyn CatalogItem,
+        desc: &RelationDesc,
+        expr: HirRelationExpr,
+    ) -> Result<HirRelationExpr, PlanError> {
+        let catalog = self.scx.catalog;
+        let row_security = match catalog.get_row_security(&item.id()) {
+            Some(row_security) if row_security.enabled => row_security,
+            _ => return Ok(expr),
+        };
+        let role_id = catalog.active_role_id();
+        let role_membership = catalog.collect_role_membership(role_id);
+        if role_id.is_system()
+            || catalog.is_superuser()
+            || role_membership.contains(&item.owner_id())
+        {
+            return Ok(expr);
+        }
+
+        // The policies are evaluated against the role of the session that
+        // plans the query, so they cannot be baked into objects that outlive
+        // the session.
+        if !matches!(
+            self.lifetime,
+            QueryLifetime::OneShot | QueryLifetime::Subscribe
+        ) {
+            sql_bail!(
+                "cannot reference {} because row-level security is enabled for it",
+                catalog.resolve_full_name(item.name()).to_string().quoted()
+            );
+        }
+
+        let mut predicates = Vec::new();
+        for (_name, policy) in row_security.applicable_policies(&role_membership) {
+            let using = mz_sql_parser::parser::parse_expr(&policy.using)?;
+            let (using, _) = names::resolve(catalog, using)?;
+            predicates.push(plan_row_security_predicate(
+                self.scx,
+                self.lifetime,
+                desc,
+                using,
+            )?);
+        }
+        Ok(expr.filter(vec![HirScalarExpr::variadic_or(predicates)]))
+    }
+
+    /// Restricts `expr`, a `Get` of `item` whose columns are described by
+    /// `scope`, to the column values that the active role may read.
+    ///
+    /// Columns that the active role has been granted `SELECT` on are always
+    /// readable. Otherwise, masked columns are replaced by their masks if
+    /// `apply_masks` is set and are inaccessible if not, and unmasked columns
+    /// are readable only if the active role has been granted `SELECT` on
+    /// `item` itself. Inaccessible columns remain in `scope`, but referencing
+    /// them is an error. The roles that are exempt from row-level security
+    /// are exempt from column-level security as well.
+    fn apply_column_security(
+        &self,
+        item: &dyn CatalogItem,
+        desc: &RelationDesc,
+        expr: HirRelationExpr,
+        scope: &mut Scope,
+        apply_masks: bool,
+    ) -> Result<HirRelationExpr, PlanError> {
+        let catalog = self.scx.catalog;
+        let Some(column_security) = catalog.get_column_security(&item.id()) else {
+            return Ok(expr);
+        };
+        let role_id = catalog.active_role_id();
+        let role_membership = catalog.collect_role_membership(role_id);
+        if role_id.is_system()
+            || catalog.is_superuser()
+            || role_membership.contains(&item.owner_id())
+        {
+            return Ok(expr);
+        }
+
+        let has_table_select = role_membership
+            .iter()
+            .flat_map(|role_id| item.privileges().get_acl_items_for_grantee(role_id))
+            .any(|mz_acl_item| mz_acl_item.acl_mode.contains(AclMode::SELECT));
+        let has_column_select = |pos: usize| {
+            column_security
+                .get(&pos)
+                .is_some_and(|security| security.has_privileges(&role_membership, AclMode::SELECT))
+        };
+        // Roles that cannot read any column are rejected when the privileges
+        // required by the statement are checked.
+        if !has_table_select && !(0..desc.arity()).any(has_column_select) {
+            return Ok(expr);
+        }
+
+        let mut masks = Vec::new();
+        for (pos, scope_item) in scope.items.iter_mut().enumerate() {
+            if has_column_select(pos) {
+                continue;
+            }
+            match column_security
+                .get(&pos)
+                .and_then(|security| security.mask.as_ref())
+            {
+                Some(mask) if apply_masks => masks.push((pos, mask)),
+                None if has_table_select => {}
+                _ => {
+                    scope_item.error_if_referenced =
+                        Some(|table, column| PlanError::ColumnPermissionDenied {
+                            table: table.cloned(),
+                            column: column.clone(),
+                        })
+                }
+            }
+        }
+        if masks.is_empty() {
+            return Ok(expr);
+        }
+
+        // Like row-level security policies, masks depend on the role of the
+        // session that plans the query.
+        if !matches!(
+            self.lifetime,
+            QueryLifetime::OneShot | QueryLifetime::Subscribe
+        ) {
+            sql_bail!(
+                "cannot reference {} because some of its columns are masked",
+                catalog.resolve_full_name(item.name()).to_string().quoted()
+            );
+        }
+
+        let arity = desc.arity();
+        let mut projection: Vec<_> = (0..arity).collect();
+        let mut map_exprs = Vec::with_capacity(masks.len());
+        for (pos, mask) in masks {
+            let mask = mz_sql_parser::parser::parse_expr(mask)?;
+            let (mask, _) = names::resolve(catalog, mask)?;
+            projection[pos] = arity + map_exprs.len();
+            map_exprs.push(plan_column_mask(self.scx, self.lifetime, desc, pos, mask)?);
+        }
+        Ok(expr.map(map_exprs).project(projection))
+    }
+
     pub fn humanize_scalar_type(&self, typ: &ScalarType) -> String {
         self.scx.humanize_scalar_type(typ)
     }
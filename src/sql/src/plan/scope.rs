@@ -189,6 +189,18 @@ impl ScopeItem {
             Some(n) => n.matches(table_name),
         }
     }
+
+    /// Returns an error if this item cannot be referenced, for expanding
+    /// wildcards and whole-row references that include it.
+    pub fn check_referenceable(&self) -> Result<(), PlanError> {
+        match self.error_if_referenced {
+            Some(error_if_referenced) => Err(error_if_referenced(
+                self.table_name.as_ref(),
+                &self.column_name,
+            )),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Scope {
@@ -127,6 +127,7 @@ pub fn describe(
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
         Statement::AlterObjectSwap(stmt) => ddl::describe_alter_object_swap(&scx, stmt)?,
         Statement::AlterRetainHistory(stmt) => ddl::describe_alter_retain_history(&scx, stmt)?,
+        Statement::AlterRetention(stmt) => ddl::describe_alter_retention(&scx, stmt)?,
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSetCluster(stmt) => ddl::describe_alter_set_cluster(&scx, stmt)?,
@@ -137,6 +138,7 @@ pub fn describe(
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
         Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
         Statement::AlterNetworkPolicy(stmt) => ddl::describe_alter_network_policy(&scx, stmt)?,
+        Statement::AlterResourceGroup(stmt) => ddl::describe_alter_resource_group(&scx, stmt)?,
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
         Statement::CreateClusterReplica(stmt) => ddl::describe_create_cluster_replica(&scx, stmt)?,
@@ -161,8 +163,10 @@ pub fn describe(
         }
         Statement::CreateContinualTask(stmt) => ddl::describe_create_continual_task(&scx, stmt)?,
         Statement::CreateNetworkPolicy(stmt) => ddl::describe_create_network_policy(&scx, stmt)?,
+        Statement::CreateResourceGroup(stmt) => ddl::describe_create_resource_group(&scx, stmt)?,
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropOwned(stmt) => ddl::describe_drop_owned(&scx, stmt)?,
+        Statement::DropResourceGroup(stmt) => ddl::describe_drop_resource_group(&scx, stmt)?,
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::describe_alter_owner(&scx, stmt)?,
@@ -173,6 +177,10 @@ pub fn describe(
         Statement::AlterDefaultPrivileges(stmt) => {
             acl::describe_alter_default_privileges(&scx, stmt)?
         }
+        Statement::AlterRowSecurity(stmt) => acl::describe_alter_row_security(&scx, stmt)?,
+        Statement::AlterColumnMask(stmt) => acl::describe_alter_column_mask(&scx, stmt)?,
+        Statement::CreatePolicy(stmt) => acl::describe_create_policy(&scx, stmt)?,
+        Statement::DropPolicy(stmt) => acl::describe_drop_policy(&scx, stmt)?,
         Statement::ReassignOwned(stmt) => acl::describe_reassign_owned(&scx, stmt)?,
 
         // `SHOW` statements.
@@ -314,6 +322,7 @@ pub fn plan(
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
         Statement::AlterObjectSwap(stmt) => ddl::plan_alter_object_swap(scx, stmt),
         Statement::AlterRetainHistory(stmt) => ddl::plan_alter_retain_history(scx, stmt),
+        Statement::AlterRetention(stmt) => ddl::plan_alter_retention(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSetCluster(stmt) => ddl::plan_alter_item_set_cluster(scx, stmt),
@@ -324,6 +333,7 @@ pub fn plan(
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
         Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
         Statement::AlterNetworkPolicy(stmt) => ddl::plan_alter_network_policy(scx, stmt),
+        Statement::AlterResourceGroup(stmt) => ddl::plan_alter_resource_group(scx, stmt),
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
         Statement::CreateClusterReplica(stmt) => ddl::plan_create_cluster_replica(scx, stmt),
@@ -346,8 +356,10 @@ pub fn plan(
         }
         Statement::CreateContinualTask(stmt) => ddl::plan_create_continual_task(scx, stmt, params),
         Statement::CreateNetworkPolicy(stmt) => ddl::plan_create_network_policy(scx, stmt),
+        Statement::CreateResourceGroup(stmt) => ddl::plan_create_resource_group(scx, stmt),
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropOwned(stmt) => ddl::plan_drop_owned(scx, stmt),
+        Statement::DropResourceGroup(stmt) => ddl::plan_drop_resource_group(scx, stmt),
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::plan_alter_owner(scx, stmt),
@@ -357,6 +369,10 @@ pub fn plan(
         Statement::RevokePrivileges(stmt) => acl::plan_revoke_privileges(scx, stmt),
         Statement::AlterDefaultPrivileges(stmt) => acl::plan_alter_default_privileges(scx, stmt),
         Statement::ReassignOwned(stmt) => acl::plan_reassign_owned(scx, stmt),
+        Statement::AlterRowSecurity(stmt) => acl::plan_alter_row_security(scx, stmt),
+        Statement::AlterColumnMask(stmt) => acl::plan_alter_column_mask(scx, stmt),
+        Statement::CreatePolicy(stmt) => acl::plan_create_policy(scx, stmt),
+        Statement::DropPolicy(stmt) => acl::plan_drop_policy(scx, stmt),
 
         // DML statements.
         Statement::Copy(stmt) => dml::plan_copy(scx, stmt),
@@ -1030,7 +1046,9 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterObjectRename(_) => DDL,
             Statement::AlterObjectSwap(_) => DDL,
             Statement::AlterNetworkPolicy(_) => DDL,
+            Statement::AlterResourceGroup(_) => DDL,
             Statement::AlterRetainHistory(_) => DDL,
+            Statement::AlterRetention(_) => DDL,
             Statement::AlterRole(_) => DDL,
             Statement::AlterSecret(_) => DDL,
             Statement::AlterSetCluster(_) => DDL,
@@ -1060,8 +1078,10 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateView(_) => DDL,
             Statement::CreateMaterializedView(_) => DDL,
             Statement::CreateNetworkPolicy(_) => DDL,
+            Statement::CreateResourceGroup(_) => DDL,
             Statement::DropObjects(_) => DDL,
             Statement::DropOwned(_) => DDL,
+            Statement::DropResourceGroup(_) => DDL,
 
             // `ACL` statements.
             Statement::AlterOwner(_) => ACL,
@@ -1071,6 +1091,10 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::RevokePrivileges(_) => ACL,
             Statement::AlterDefaultPrivileges(_) => ACL,
             Statement::ReassignOwned(_) => ACL,
+            Statement::AlterRowSecurity(_) => ACL,
+            Statement::AlterColumnMask(_) => ACL,
+            Statement::CreatePolicy(_) => ACL,
+            Statement::DropPolicy(_) => ACL,
 
             // DML statements.
             Statement::Copy(_) => DML,
@@ -10,37 +10,44 @@
 //! Access control list (ACL).
 //!
 //! This module houses the handlers for statements that modify privileges in the catalog, like
-//! `GRANT`, `REVOKE`, and `REASSIGN OWNED`.
+//! `GRANT`, `REVOKE`, `REASSIGN OWNED`, and `CREATE POLICY`.
 
 use std::collections::BTreeSet;
 
 use itertools::Itertools;
 use mz_sql_parser::ast::display::AstDisplay;
 
-use crate::ast::{Ident, UnresolvedDatabaseName};
+use crate::ast::{Expr, Ident, UnresolvedDatabaseName};
 use crate::catalog::{
     DefaultPrivilegeAclItem, DefaultPrivilegeObject, ErrorMessageObjectDescription, ObjectType,
-    SystemObjectType,
+    RowSecurityPolicy, SystemObjectType,
 };
 use crate::names::{
-    Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
+    self, Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier,
+    SystemObjectId,
 };
+use crate::normalize;
 use crate::plan::error::PlanError;
+use crate::plan::query::{self, QueryLifetime};
 use crate::plan::statement::ddl::{
     resolve_cluster, resolve_database, resolve_item_or_type, resolve_network_policy, resolve_schema,
 };
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::{
-    AlterDefaultPrivilegesPlan, AlterNoopPlan, AlterOwnerPlan, GrantPrivilegesPlan, GrantRolePlan,
+    AlterColumnMaskPlan, AlterDefaultPrivilegesPlan, AlterNoopPlan, AlterOwnerPlan,
+    AlterRowSecurityPlan, CreatePolicyPlan, DropPolicyPlan, GrantPrivilegesPlan, GrantRolePlan,
     Plan, PlanNotice, ReassignOwnedPlan, RevokePrivilegesPlan, RevokeRolePlan, UpdatePrivilege,
 };
 use crate::session::user::SYSTEM_USER;
+use crate::session::vars;
 use mz_ore::str::StrExt;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::role_id::RoleId;
+use mz_repr::GlobalId;
 use mz_sql_parser::ast::{
-    AbbreviatedGrantOrRevokeStatement, AlterDefaultPrivilegesStatement, AlterOwnerStatement,
-    GrantPrivilegesStatement, GrantRoleStatement, GrantTargetAllSpecification,
+    AbbreviatedGrantOrRevokeStatement, AlterColumnMaskStatement, AlterDefaultPrivilegesStatement,
+    AlterOwnerStatement, AlterRowSecurityStatement, ColumnPrivilege, CreatePolicyStatement,
+    DropPolicyStatement, GrantPrivilegesStatement, GrantRoleStatement, GrantTargetAllSpecification,
     GrantTargetSpecification, GrantTargetSpecificationInner, Privilege, PrivilegeSpecification,
     ReassignOwnedStatement, RevokePrivilegesStatement, RevokeRoleStatement,
     TargetRoleSpecification, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName,
@@ -418,6 +425,25 @@ fn plan_update_privilege(
     target: GrantTargetSpecification<Aug>,
     roles: Vec<ResolvedRoleName>,
 ) -> Result<UpdatePrivilegesPlan, PlanError> {
+    if let PrivilegeSpecification::Columns(columns) = &privileges {
+        scx.require_feature_flag(&vars::ENABLE_COLUMN_LEVEL_SECURITY)?;
+        if columns
+            .iter()
+            .any(|column| column.privilege != Privilege::SELECT)
+        {
+            bail_unsupported!("column privileges other than SELECT");
+        }
+        if !matches!(
+            &target,
+            GrantTargetSpecification::Object {
+                object_type: mz_sql_parser::ast::ObjectType::Table,
+                object_spec_inner: GrantTargetSpecificationInner::Objects { .. },
+            }
+        ) {
+            sql_bail!("column privileges can only be specified for individual tables");
+        }
+    }
+
     let (object_type, target_ids) = match target {
         GrantTargetSpecification::Object {
             object_type,
@@ -544,6 +570,14 @@ fn plan_update_privilege(
             }
         }
 
+        let column_positions = match (&privileges, &target_id) {
+            (
+                PrivilegeSpecification::Columns(columns),
+                SystemObjectId::Object(ObjectId::Item(id)),
+            ) => plan_column_positions(scx, id, columns)?,
+            _ => Vec::new(),
+        };
+
         let all_object_privileges = scx.catalog.all_object_privileges(reference_object_type);
         let invalid_privileges = acl_mode.difference(all_object_privileges);
         if !invalid_privileges.is_empty() {
@@ -572,6 +606,7 @@ fn plan_update_privilege(
         update_privileges.push(UpdatePrivilege {
             acl_mode,
             target_id,
+            column_positions,
             grantor,
         });
     }
@@ -584,6 +619,39 @@ fn plan_update_privilege(
     })
 }
 
+/// Resolves the columns named in `columns` to their positions in the relation
+/// with the given `id`.
+fn plan_column_positions(
+    scx: &StatementContext,
+    id: &GlobalId,
+    columns: &[ColumnPrivilege],
+) -> Result<Vec<usize>, PlanError> {
+    let item = scx.get_item(id);
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    if item.name().qualifiers.schema_spec.is_temporary() {
+        sql_bail!(
+            "cannot grant privileges on columns of temporary relation {}",
+            full_name.to_string().quoted(),
+        );
+    }
+    let desc = item.desc(&full_name)?;
+    let mut column_positions = BTreeSet::new();
+    for column in columns.iter().flat_map(|privilege| &privilege.columns) {
+        let column_name = normalize::column_name(column.clone());
+        match desc.get_by_name(&column_name) {
+            Some((pos, _)) => {
+                column_positions.insert(pos);
+            }
+            None => sql_bail!(
+                "column {} of relation {} does not exist",
+                column_name.as_str().quoted(),
+                full_name.to_string().quoted(),
+            ),
+        }
+    }
+    Ok(column_positions.into_iter().collect())
+}
+
 fn privilege_spec_to_acl_mode(
     scx: &StatementContext,
     privilege_spec: &PrivilegeSpecification,
@@ -596,6 +664,10 @@ fn privilege_spec_to_acl_mode(
             .map(|privilege| privilege_to_acl_mode(privilege.clone()))
             // PostgreSQL doesn't care about duplicate privileges, so we don't either.
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
+        PrivilegeSpecification::Columns(columns) => columns
+            .iter()
+            .map(|column| privilege_to_acl_mode(column.privilege.clone()))
+            .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
     }
 }
 
@@ -813,3 +885,298 @@ pub fn plan_reassign_owned(
         reassign_ids,
     }))
 }
+
+pub fn describe_create_policy(
+    _: &StatementContext,
+    _: CreatePolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_policy(
+    scx: &StatementContext,
+    CreatePolicyStatement {
+        name,
+        relation,
+        roles,
+        using,
+    }: CreatePolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+
+    let item = scx.get_item_by_resolved_name(&relation)?;
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    if !ObjectType::from(item.item_type()).is_relation() {
+        sql_bail!("{} is not a relation", full_name.to_string().quoted());
+    }
+    if item.id().is_system() {
+        sql_bail!(
+            "cannot create policy on {} because it is required by the database system",
+            full_name.to_string().quoted(),
+        );
+    }
+
+    let name = normalize::ident(name);
+    if scx
+        .catalog
+        .get_row_security(&item.id())
+        .is_some_and(|row_security| row_security.policies.contains_key(&name))
+    {
+        sql_bail!(
+            "policy {} for relation {} already exists",
+            name.quoted(),
+            full_name.to_string().quoted(),
+        );
+    }
+
+    ensure_no_user_dependencies(scx, "policies", &using)?;
+    let desc = item.desc(&full_name)?;
+    query::plan_row_security_predicate(scx, QueryLifetime::OneShot, &desc, using.clone())?;
+
+    let roles = if roles.is_empty() {
+        BTreeSet::from([RoleId::Public])
+    } else {
+        roles.into_iter().map(|role| role.id).collect()
+    };
+
+    Ok(Plan::CreatePolicy(CreatePolicyPlan {
+        relation_id: item.id(),
+        name,
+        policy: RowSecurityPolicy {
+            roles,
+            using: using.to_ast_string_stable(),
+        },
+    }))
+}
+
+/// Ensures that `expr`, which is stored as SQL text and planned anew by every
+/// query, does not depend on user objects that could be dropped or altered out
+/// from under it.
+fn ensure_no_user_dependencies(
+    scx: &StatementContext,
+    what: &str,
+    expr: &Expr<Aug>,
+) -> Result<(), PlanError> {
+    if let Some(id) = names::visit_dependencies(expr)
+        .0
+        .into_iter()
+        .find(|id| !id.is_system())
+    {
+        let dependency = scx.catalog.get_item(&id);
+        sql_bail!(
+            "{what} cannot reference {} {}",
+            dependency.item_type(),
+            scx.catalog
+                .resolve_full_name(dependency.name())
+                .to_string()
+                .quoted(),
+        );
+    }
+    Ok(())
+}
+
+pub fn describe_drop_policy(
+    _: &StatementContext,
+    _: DropPolicyStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_policy(
+    scx: &StatementContext,
+    DropPolicyStatement {
+        if_exists,
+        name,
+        relation,
+    }: DropPolicyStatement,
+) -> Result<Plan, PlanError> {
+    let name = normalize::ident(name);
+    let relation_name = normalize::unresolved_item_name(relation)?;
+    let item = match scx.catalog.resolve_item(&relation_name) {
+        Ok(item) => item,
+        Err(_) if if_exists => {
+            scx.catalog.add_notice(PlanNotice::PolicyDoesNotExist {
+                name,
+                relation_name: relation_name.to_string(),
+            });
+            return Ok(Plan::DropPolicy(DropPolicyPlan { policy: None }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let exists = scx
+        .catalog
+        .get_row_security(&item.id())
+        .is_some_and(|row_security| row_security.policies.contains_key(&name));
+    if !exists {
+        let relation_name = scx.catalog.resolve_full_name(item.name()).to_string();
+        if if_exists {
+            scx.catalog.add_notice(PlanNotice::PolicyDoesNotExist {
+                name,
+                relation_name,
+            });
+            return Ok(Plan::DropPolicy(DropPolicyPlan { policy: None }));
+        }
+        sql_bail!(
+            "policy {} for relation {} does not exist",
+            name.quoted(),
+            relation_name.quoted(),
+        );
+    }
+
+    Ok(Plan::DropPolicy(DropPolicyPlan {
+        policy: Some((item.id(), name)),
+    }))
+}
+
+pub fn describe_alter_row_security(
+    _: &StatementContext,
+    _: AlterRowSecurityStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_row_security(
+    scx: &StatementContext,
+    AlterRowSecurityStatement {
+        object_type,
+        if_exists,
+        name,
+        enabled,
+    }: AlterRowSecurityStatement,
+) -> Result<Plan, PlanError> {
+    // Disabling row-level security is always allowed, so that it can be
+    // turned off after the feature flag has been.
+    if enabled {
+        scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    }
+
+    let object_type = object_type.into();
+    match resolve_item_or_type(scx, object_type, name.clone(), if_exists)? {
+        Some(item) => {
+            if item.id().is_system() {
+                sql_bail!(
+                    "cannot alter item {} because it is required by the database system",
+                    scx.catalog.resolve_full_name(item.name()),
+                );
+            }
+
+            // Policies are applied when planning queries, so objects that were planned before
+            // row-level security was enabled would bypass them. Indexes on the relation itself
+            // are fine, as queries still read through the relation and its policies.
+            if enabled {
+                let dependents: Vec<_> = item
+                    .used_by()
+                    .iter()
+                    .map(|id| scx.catalog.get_item(id))
+                    .filter(|dependent| {
+                        dependent
+                            .index_details()
+                            .map_or(true, |(_, on)| on != item.id())
+                    })
+                    .map(|dependent| {
+                        let name = scx.catalog.resolve_full_name(dependent.name());
+                        format!("{} {}", dependent.item_type(), name.to_string().quoted())
+                    })
+                    .collect();
+                if !dependents.is_empty() {
+                    sql_bail!(
+                        "cannot enable row-level security for {}: still depended upon by {}",
+                        scx.catalog
+                            .resolve_full_name(item.name())
+                            .to_string()
+                            .quoted(),
+                        dependents.join(", "),
+                    );
+                }
+            }
+
+            Ok(Plan::AlterRowSecurity(AlterRowSecurityPlan {
+                id: item.id(),
+                enabled,
+                object_type,
+            }))
+        }
+        None => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_ast_string(),
+                object_type,
+            });
+
+            Ok(Plan::AlterNoop(AlterNoopPlan { object_type }))
+        }
+    }
+}
+
+pub fn describe_alter_column_mask(
+    _: &StatementContext,
+    _: AlterColumnMaskStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_column_mask(
+    scx: &StatementContext,
+    AlterColumnMaskStatement {
+        object_type,
+        if_exists,
+        name,
+        column_name,
+        mask,
+    }: AlterColumnMaskStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    // Dropping a mask is always allowed, so that masks can be removed after
+    // the feature flag has been turned off.
+    if mask.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_COLUMN_LEVEL_SECURITY)?;
+    }
+
+    let object_type = object_type.into();
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    if item.id().is_system() {
+        sql_bail!(
+            "cannot alter item {} because it is required by the database system",
+            full_name,
+        );
+    }
+    if item.name().qualifiers.schema_spec.is_temporary() {
+        sql_bail!(
+            "cannot mask columns of temporary relation {}",
+            full_name.to_string().quoted(),
+        );
+    }
+
+    let desc = item.desc(&full_name)?;
+    let column_name = normalize::column_name(column_name);
+    let Some((column_pos, _)) = desc.get_by_name(&column_name) else {
+        sql_bail!(
+            "column {} of relation {} does not exist",
+            column_name.as_str().quoted(),
+            full_name.to_string().quoted(),
+        );
+    };
+
+    let mask = match mask {
+        Some(mask) => {
+            ensure_no_user_dependencies(scx, "masks", &mask)?;
+            query::plan_column_mask(scx, QueryLifetime::OneShot, &desc, column_pos, mask.clone())?;
+            Some(mask.to_ast_string_stable())
+        }
+        None => None,
+    };
+
+    Ok(Plan::AlterColumnMask(AlterColumnMaskPlan {
+        id: item.id(),
+        column_pos,
+        mask,
+        object_type,
+    }))
+}
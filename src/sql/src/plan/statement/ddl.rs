@@ -22,7 +22,7 @@ use mz_adapter_types::compaction::{CompactionWindow, DEFAULT_LOGICAL_COMPACTION_
 use mz_controller_types::{
     is_cluster_size_v2, ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL,
 };
-use mz_expr::{CollectionPlan, UnmaterializableFunc};
+use mz_expr::{CollectionPlan, MirScalarExpr, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, DocTarget};
 use mz_ore::cast::{CastFrom, TryCastFrom};
 use mz_ore::collections::{CollectionExt, HashSet};
@@ -30,23 +30,28 @@ use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::StrExt;
 use mz_ore::vec::VecExt;
+use mz_pgwire_common::scram::ScramVerifier;
 use mz_postgres_util::tunnel::PostgresFlavor;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::bytes::ByteSize;
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType, Timestamp,
+    strconv, ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, ScalarType,
+    Timestamp,
 };
+use mz_secrets::external::{ExternalSecretReference, DEFAULT_KEY};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     self, AlterClusterAction, AlterClusterStatement, AlterConnectionAction, AlterConnectionOption,
     AlterConnectionOptionName, AlterConnectionStatement, AlterIndexAction, AlterIndexStatement,
     AlterNetworkPolicyStatement, AlterObjectRenameStatement, AlterObjectSwapStatement,
-    AlterRetainHistoryStatement, AlterRoleOption, AlterRoleStatement, AlterSecretStatement,
+    AlterResourceGroupAction, AlterResourceGroupStatement, AlterRetainHistoryStatement,
+    AlterRetentionStatement, AlterRoleOption, AlterRoleStatement, AlterSecretStatement,
     AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement, AlterSourceAction,
     AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName, AlterSourceStatement,
     AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
@@ -59,27 +64,28 @@ use mz_sql_parser::ast::{
     CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
     CreateConnectionStatement, CreateConnectionType, CreateContinualTaskStatement,
     CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateNetworkPolicyStatement, CreateRoleStatement, CreateSchemaStatement,
-    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
-    CreateSinkStatement, CreateSourceConnection, CreateSourceOption, CreateSourceOptionName,
-    CreateSourceStatement, CreateSubsourceOption, CreateSubsourceOptionName,
-    CreateSubsourceStatement, CreateTableFromSourceStatement, CreateTableStatement, CreateTypeAs,
-    CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
-    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
-    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf,
-    CsvColumns, DeferredItemName, DocOnIdentifier, DocOnSchema, DropObjectsStatement,
-    DropOwnedStatement, Expr, Format, FormatSpecifier, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
+    CreateNetworkPolicyStatement, CreateResourceGroupStatement, CreateRoleStatement,
+    CreateSchemaStatement, CreateSecretStatement, CreateSecretValue, CreateSinkConnection,
+    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
+    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
+    CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
+    CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
+    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
+    DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement,
+    DropResourceGroupStatement, Expr, Format, FormatSpecifier, Ident, IfExistsBehavior,
+    IndexOption, IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
     LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
     MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
     NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
     PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RefreshAtOptionValue,
     RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition, ReplicaOption,
-    ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
-    Statement, TableConstraint, TableFromSourceColumns, TableFromSourceOption,
-    TableFromSourceOptionName, TableOption, TableOptionName, UnresolvedDatabaseName,
-    UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value, ViewDefinition,
-    WithOptionValue,
+    ReplicaOptionName, ResourceGroupOption, ResourceGroupOptionName, RetentionOptionValue,
+    RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata, Statement, TableClone,
+    TableConstraint, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
+    TableOption, TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
@@ -120,13 +126,14 @@ use prost::Message;
 use crate::ast::display::AstDisplay;
 use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
-    CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
+    CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, ResourceGroup,
+    SystemObjectType,
 };
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, ObjectId, PartialItemName, QualifiedItemName,
     ResolvedClusterName, ResolvedColumnReference, ResolvedDataType, ResolvedDatabaseSpecifier,
-    ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier, SystemObjectId,
+    ResolvedItemName, ResolvedNetworkPolicyName, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
@@ -143,20 +150,21 @@ use crate::plan::{
     literal, plan_utils, query, transform_ast, AlterClusterPlan, AlterClusterPlanStrategy,
     AlterClusterRenamePlan, AlterClusterReplicaRenamePlan, AlterClusterSwapPlan,
     AlterConnectionPlan, AlterItemRenamePlan, AlterNetworkPolicyPlan, AlterNoopPlan,
-    AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterSchemaRenamePlan,
-    AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan,
-    AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan, AlterTablePlan,
-    ClusterSchedule, CommentPlan, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
-    ConnectionDetails, CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
-    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan,
-    CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateNetworkPolicyPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
-    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
-    DropObjectsPlan, DropOwnedPlan, Index, Ingestion, MaterializedView, NetworkPolicyRule,
+    AlterOptionParameter, AlterResourceGroupPlan, AlterRetainHistoryPlan, AlterRetentionPlan,
+    AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan,
+    AlterSetClusterPlan, AlterSinkPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
+    AlterSystemSetPlan, AlterTablePlan, ClusterSchedule, CommentPlan, ComputeReplicaConfig,
+    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
+    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
+    CreateConnectionPlan, CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateResourceGroupPlan, CreateRolePlan,
+    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan,
+    DropResourceGroupPlan, Index, Ingestion, MaterializedView, NetworkPolicyRule,
     NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Params, Plan, PlanClusterOption,
-    PlanNotice, PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink, Source, Table,
-    TableDataSource, Type, VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters,
-    WebhookHeaders, WebhookValidation,
+    PlanNotice, PolicyAddress, QueryContext, QueryWhen, ReplicaConfig, RetentionPolicy, Secret,
+    Sink, Source, Table, TableCloneSource, TableDataSource, Type, VariableValue, View,
+    WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation,
 };
 use crate::session::vars::{
     self, ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_KAFKA_SINK_HEADERS,
@@ -243,8 +251,12 @@ pub fn describe_create_table(
 
 pub fn plan_create_table(
     scx: &StatementContext,
-    stmt: CreateTableStatement<Aug>,
+    mut stmt: CreateTableStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    if let Some(clone_of) = stmt.clone_of.take() {
+        return plan_create_table_clone(scx, stmt, clone_of);
+    }
+
     let CreateTableStatement {
         name,
         columns,
@@ -252,6 +264,7 @@ pub fn plan_create_table(
         if_not_exists,
         temporary,
         with_options,
+        clone_of: _,
     } = &stmt;
 
     let names: Vec<_> = columns
@@ -396,30 +409,99 @@ pub fn plan_create_table(
 
     let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
 
-    let options = plan_table_options(scx, with_options.clone())?;
+    let options = plan_table_options(scx, &desc, with_options.clone())?;
     let compaction_window = options.iter().find_map(|o| {
-        #[allow(irrefutable_let_patterns)]
         if let crate::plan::TableOption::RetainHistory(lcw) = o {
             Some(lcw.clone())
         } else {
             None
         }
     });
+    let retention = options.iter().find_map(|o| {
+        if let crate::plan::TableOption::Retention(policy) = o {
+            Some(policy.clone())
+        } else {
+            None
+        }
+    });
 
     let table = Table {
         create_sql,
         desc,
         temporary,
         compaction_window,
+        retention,
         data_source: TableDataSource::TableWrites { defaults },
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
         table,
         if_not_exists: *if_not_exists,
+        clone_of: None,
     }))
 }
 
+/// Plans a `CREATE TABLE ... CLONE`.
+///
+/// The table is planned as if its columns had been spelled out, so that its
+/// `create_sql` does not depend on the cloned relation, which may later be
+/// dropped.
+fn plan_create_table_clone(
+    scx: &StatementContext,
+    mut stmt: CreateTableStatement<Aug>,
+    clone_of: TableClone<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CREATE_TABLE_CLONE)?;
+
+    let TableClone { source, as_of } = clone_of;
+    let item = scx.get_item_by_resolved_name(&source)?;
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    match item.item_type() {
+        CatalogItemType::Table | CatalogItemType::MaterializedView => {}
+        ty => sql_bail!(
+            "cannot clone {} {}: only tables and materialized views can be cloned",
+            ty,
+            full_name.to_string().quoted()
+        ),
+    }
+    let desc = item.desc(&full_name)?;
+    if let Some((name, _)) = desc
+        .iter()
+        .find(|(_, typ)| typ.scalar_type.is_custom_type())
+    {
+        bail_unsupported!(format!(
+            "CREATE TABLE ... CLONE of {} with column {} of a custom type",
+            full_name.to_string().quoted(),
+            name.as_str().quoted()
+        ));
+    }
+
+    // The keys of the cloned relation are not carried over, as they are not
+    // enforced on tables.
+    let desc = desc.into_owned().without_keys();
+    let (columns, constraints) = scx.relation_desc_into_table_defs(&desc)?;
+    stmt.columns = columns;
+    stmt.constraints = constraints;
+
+    let as_of = match query::plan_as_of(scx, as_of)? {
+        QueryWhen::Immediately => None,
+        QueryWhen::AtTimestamp(as_of) => Some(as_of),
+        QueryWhen::AtLeastTimestamp(_) => {
+            bail_unsupported!("CREATE TABLE ... CLONE ... AS OF AT LEAST")
+        }
+        QueryWhen::FreshestTableWrite => unreachable!("not produced by AS OF"),
+    };
+
+    let Plan::CreateTable(mut plan) = plan_create_table(scx, stmt)? else {
+        unreachable!("CREATE TABLE plans to a CreateTablePlan");
+    };
+    plan.clone_of = Some(TableCloneSource {
+        id: item.id(),
+        as_of,
+    });
+    Ok(Plan::CreateTable(plan))
+}
+
 pub fn describe_create_table_from_source(
     _: &StatementContext,
     _: CreateTableFromSourceStatement<Aug>,
@@ -1828,6 +1910,7 @@ pub fn plan_create_table_from_source(
         desc,
         temporary: false,
         compaction_window: None,
+        retention: None,
         data_source: TableDataSource::DataSource {
             desc: data_source,
             timeline,
@@ -1838,6 +1921,7 @@ pub fn plan_create_table_from_source(
         name,
         table,
         if_not_exists,
+        clone_of: None,
     }))
 }
 
@@ -2544,6 +2628,27 @@ pub fn describe_alter_network_policy(
     Ok(StatementDesc::new(None))
 }
 
+pub fn describe_create_resource_group(
+    _: &StatementContext,
+    _: CreateResourceGroupStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn describe_alter_resource_group(
+    _: &StatementContext,
+    _: AlterResourceGroupStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn describe_drop_resource_group(
+    _: &StatementContext,
+    _: DropResourceGroupStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
 pub fn plan_create_materialized_view(
     scx: &StatementContext,
     mut stmt: CreateMaterializedViewStatement<Aug>,
@@ -3929,13 +4034,15 @@ pub fn plan_create_index(
 
     let create_sql = normalize::create_statement(scx, Statement::CreateIndex(stmt))?;
     let compaction_window = options.iter().find_map(|o| {
-        #[allow(irrefutable_let_patterns)]
         if let crate::plan::IndexOption::RetainHistory(lcw) = o {
             Some(lcw.clone())
         } else {
             None
         }
     });
+    let persisted = options
+        .iter()
+        .any(|o| matches!(o, crate::plan::IndexOption::Persisted));
 
     Ok(Plan::CreateIndex(CreateIndexPlan {
         name: index_name,
@@ -3945,6 +4052,7 @@ pub fn plan_create_index(
             keys,
             cluster_id,
             compaction_window,
+            persisted,
         },
         if_not_exists,
     }))
@@ -4091,15 +4199,24 @@ pub enum PlannedAlterRoleOption {
 #[derive(Debug)]
 pub struct PlannedRoleAttributes {
     pub inherit: Option<bool>,
+    /// The SCRAM-SHA-256 verifier of the role's new password. `Some(None)`
+    /// removes the role's password.
+    pub password: Option<Option<String>>,
 }
 
 fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttributes, PlanError> {
-    let mut planned_attributes = PlannedRoleAttributes { inherit: None };
+    let mut planned_attributes = PlannedRoleAttributes {
+        inherit: None,
+        password: None,
+    };
 
     for option in options {
         match option {
-            RoleAttribute::Login | RoleAttribute::NoLogin => {
-                bail_never_supported!("LOGIN attribute", "sql/create-role/#details");
+            // Every role is allowed to log in, so `LOGIN` is a no-op that we
+            // accept for compatibility with PostgreSQL.
+            RoleAttribute::Login => {}
+            RoleAttribute::NoLogin => {
+                bail_never_supported!("NOLOGIN attribute", "sql/create-role/#details");
             }
             RoleAttribute::SuperUser | RoleAttribute::NoSuperUser => {
                 bail_never_supported!("SUPERUSER attribute", "sql/create-role/#details");
@@ -4131,8 +4248,25 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
                 );
             }
 
+            RoleAttribute::Password(_) if planned_attributes.password.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
+
             RoleAttribute::Inherit => planned_attributes.inherit = Some(true),
             RoleAttribute::NoInherit => planned_attributes.inherit = Some(false),
+            RoleAttribute::Password(None) => planned_attributes.password = Some(None),
+            RoleAttribute::Password(Some(password)) => {
+                // Like PostgreSQL, accept passwords that are already SCRAM
+                // verifiers as-is, so that roles can be restored from a dump.
+                let verifier = match ScramVerifier::parse(&password) {
+                    Some(verifier) => verifier,
+                    None if password.is_empty() => sql_bail!("password must not be empty"),
+                    None => ScramVerifier::new(&password).map_err(|e| {
+                        PlanError::Unstructured(format!("unable to hash password: {e}"))
+                    })?,
+                };
+                planned_attributes.password = Some(Some(verifier.to_string()));
+            }
         }
     }
     if planned_attributes.inherit == Some(false) {
@@ -4297,6 +4431,165 @@ pub fn plan_alter_network_policy(
     }))
 }
 
+generate_extracted_config!(
+    ResourceGroupOption,
+    (MaxConcurrentPeeks, u32),
+    (MaxQueuedPeeks, u32),
+    (StatementTimeout, Duration),
+    (MaxResultSize, ByteSize)
+);
+
+pub fn plan_create_resource_group(
+    scx: &StatementContext,
+    CreateResourceGroupStatement {
+        name,
+        roles,
+        options,
+    }: CreateResourceGroupStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_RESOURCE_GROUPS)?;
+
+    let name = normalize::ident(name);
+    if scx.catalog.get_resource_group(&name).is_some() {
+        sql_bail!("resource group {} already exists", name.quoted());
+    }
+    let mut group = ResourceGroup {
+        roles: plan_resource_group_roles(scx, &name, roles)?,
+        ..Default::default()
+    };
+    plan_resource_group_options(&mut group, options)?;
+
+    Ok(Plan::CreateResourceGroup(CreateResourceGroupPlan {
+        name,
+        group,
+    }))
+}
+
+pub fn plan_alter_resource_group(
+    scx: &StatementContext,
+    AlterResourceGroupStatement { name, action }: AlterResourceGroupStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_RESOURCE_GROUPS)?;
+
+    let name = normalize::ident(name);
+    let Some(group) = scx.catalog.get_resource_group(&name) else {
+        sql_bail!("resource group {} does not exist", name.quoted());
+    };
+    let mut group = group.clone();
+    match action {
+        AlterResourceGroupAction::SetOptions(options) => {
+            plan_resource_group_options(&mut group, options)?;
+        }
+        AlterResourceGroupAction::ResetOptions(names) => {
+            for name in names {
+                match name {
+                    ResourceGroupOptionName::MaxConcurrentPeeks => {
+                        group.max_concurrent_peeks = None
+                    }
+                    ResourceGroupOptionName::MaxQueuedPeeks => group.max_queued_peeks = None,
+                    ResourceGroupOptionName::MaxResultSize => group.max_result_size = None,
+                    ResourceGroupOptionName::StatementTimeout => group.statement_timeout = None,
+                }
+            }
+        }
+        AlterResourceGroupAction::SetRoles(roles) => {
+            group.roles = plan_resource_group_roles(scx, &name, roles)?;
+        }
+    }
+
+    Ok(Plan::AlterResourceGroup(AlterResourceGroupPlan {
+        name,
+        group,
+    }))
+}
+
+pub fn plan_drop_resource_group(
+    scx: &StatementContext,
+    DropResourceGroupStatement { if_exists, name }: DropResourceGroupStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_RESOURCE_GROUPS)?;
+
+    let name = normalize::ident(name);
+    if scx.catalog.get_resource_group(&name).is_none() {
+        if if_exists {
+            scx.catalog
+                .add_notice(PlanNotice::ResourceGroupDoesNotExist { name });
+            return Ok(Plan::DropResourceGroup(DropResourceGroupPlan {
+                name: None,
+            }));
+        }
+        sql_bail!("resource group {} does not exist", name.quoted());
+    }
+
+    Ok(Plan::DropResourceGroup(DropResourceGroupPlan {
+        name: Some(name),
+    }))
+}
+
+/// Resolves the roles to assign to the resource group named `name`, ensuring
+/// that none of them is already assigned to a different group.
+fn plan_resource_group_roles(
+    scx: &StatementContext,
+    name: &str,
+    roles: Vec<ResolvedRoleName>,
+) -> Result<BTreeSet<RoleId>, PlanError> {
+    let mut role_ids = BTreeSet::new();
+    for role in roles {
+        if !role.id.is_user() {
+            sql_bail!(
+                "cannot assign role {} to a resource group",
+                role.name.quoted()
+            );
+        }
+        if let Some((group_name, _)) = scx.catalog.get_role_resource_group(&role.id) {
+            if group_name != name {
+                sql_bail!(
+                    "role {} is already a member of resource group {}",
+                    role.name.quoted(),
+                    group_name.quoted(),
+                );
+            }
+        }
+        role_ids.insert(role.id);
+    }
+    Ok(role_ids)
+}
+
+/// Applies the limits specified by `options` to `group`, leaving unspecified
+/// limits unchanged.
+fn plan_resource_group_options(
+    group: &mut ResourceGroup,
+    options: Vec<ResourceGroupOption<Aug>>,
+) -> Result<(), PlanError> {
+    let ResourceGroupOptionExtracted {
+        seen: _,
+        max_concurrent_peeks,
+        max_queued_peeks,
+        statement_timeout,
+        max_result_size,
+    } = options.try_into()?;
+
+    if let Some(max_concurrent_peeks) = max_concurrent_peeks {
+        if max_concurrent_peeks == 0 {
+            sql_bail!("MAX CONCURRENT PEEKS must be greater than 0");
+        }
+        group.max_concurrent_peeks = Some(max_concurrent_peeks);
+    }
+    if let Some(max_queued_peeks) = max_queued_peeks {
+        group.max_queued_peeks = Some(max_queued_peeks);
+    }
+    if let Some(statement_timeout) = statement_timeout {
+        if statement_timeout.is_zero() {
+            sql_bail!("STATEMENT TIMEOUT must be greater than 0");
+        }
+        group.statement_timeout = Some(statement_timeout);
+    }
+    if let Some(max_result_size) = max_result_size {
+        group.max_result_size = Some(max_result_size.as_bytes());
+    }
+    Ok(())
+}
+
 pub fn describe_create_cluster(
     _: &StatementContext,
     _: CreateClusterStatement<Aug>,
@@ -4311,6 +4604,7 @@ pub fn describe_create_cluster(
 // to ALTER CLUSTER would always reset the value of that option to the default.
 generate_extracted_config!(
     ClusterOption,
+    (ArrangementSpill, bool),
     (AvailabilityZones, Vec<String>),
     (Disk, bool),
     (IntrospectionDebugging, bool),
@@ -4404,6 +4698,7 @@ pub fn plan_create_cluster_inner(
     }: CreateClusterStatement<Aug>,
 ) -> Result<CreateClusterPlan, PlanError> {
     let ClusterOptionExtracted {
+        arrangement_spill,
         availability_zones,
         introspection_debugging,
         introspection_interval,
@@ -4520,6 +4815,7 @@ pub fn plan_create_cluster_inner(
                 schedule,
             }),
             workload_class,
+            arrangement_spill,
         })
     } else {
         let Some(replica_defs) = replicas else {
@@ -4561,6 +4857,7 @@ pub fn plan_create_cluster_inner(
             name: normalize::ident(name),
             variant: CreateClusterVariant::Unmanaged(CreateClusterUnmanagedPlan { replicas }),
             workload_class,
+            arrangement_spill,
         })
     }
 }
@@ -4574,6 +4871,7 @@ pub fn unplan_create_cluster(
         name,
         variant,
         workload_class,
+        arrangement_spill,
     }: CreateClusterPlan,
 ) -> Result<CreateClusterStatement<Aug>, PlanError> {
     match variant {
@@ -4592,6 +4890,8 @@ pub fn unplan_create_cluster(
                 enable_reduce_mfp_fusion: _,
                 enable_cardinality_estimates: _,
                 persist_fast_path_limit: _,
+                enable_bounded_top_k: _,
+                bounded_top_k_buffer: _,
                 reoptimize_imported_views,
                 enable_eager_delta_joins,
                 enable_new_outer_join_lowering,
@@ -4637,6 +4937,7 @@ pub fn unplan_create_cluster(
             let options_extracted = ClusterOptionExtracted {
                 // Seen is ignored when unplanning.
                 seen: Default::default(),
+                arrangement_spill,
                 availability_zones,
                 disk: Some(disk),
                 introspection_debugging: Some(introspection_debugging),
@@ -4979,14 +5280,30 @@ pub fn plan_create_secret(
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?;
     let mut create_sql_statement = stmt.clone();
-    create_sql_statement.value = Expr::Value(Value::String("********".to_string()));
+    let secret_as = match value {
+        CreateSecretValue::Inline(value) => {
+            create_sql_statement.value =
+                CreateSecretValue::Inline(Expr::Value(Value::String("********".to_string())));
+            query::plan_secret_as(scx, value.clone())?
+        }
+        // The reference, unlike the secret it refers to, is not sensitive, so
+        // it is retained in the `create_sql`.
+        CreateSecretValue::Vault { path, key } => {
+            scx.require_feature_flag(&vars::ENABLE_EXTERNAL_SECRETS)?;
+            let reference = ExternalSecretReference {
+                path: path.clone(),
+                key: key.clone().unwrap_or_else(|| DEFAULT_KEY.into()),
+            };
+            MirScalarExpr::literal_ok(Datum::Bytes(&reference.encode()), ScalarType::Bytes)
+        }
+    };
     let create_sql =
         normalize::create_statement(scx, Statement::CreateSecret(create_sql_statement))?;
-    let secret_as = query::plan_secret_as(scx, value.clone())?;
 
     let secret = Secret {
         create_sql,
         secret_as,
+        external: matches!(value, CreateSecretValue::Vault { .. }),
     };
 
     Ok(Plan::CreateSecret(CreateSecretPlan {
@@ -5653,38 +5970,55 @@ fn plan_retain_history(
     }
 }
 
-generate_extracted_config!(IndexOption, (RetainHistory, OptionalDuration));
+generate_extracted_config!(
+    IndexOption,
+    (RetainHistory, OptionalDuration),
+    (Persisted, bool, Default(false))
+);
 
 fn plan_index_options(
     scx: &StatementContext,
     with_opts: Vec<IndexOption<Aug>>,
 ) -> Result<Vec<crate::plan::IndexOption>, PlanError> {
-    if !with_opts.is_empty() {
+    let IndexOptionExtracted {
+        retain_history,
+        persisted,
+        ..
+    }: IndexOptionExtracted = with_opts.try_into()?;
+
+    if retain_history.is_some() {
         // Index options are not durable.
         scx.require_feature_flag(&vars::ENABLE_INDEX_OPTIONS)?;
     }
+    if persisted {
+        scx.require_feature_flag(&vars::ENABLE_PERSISTED_INDEXES)?;
+    }
 
-    let IndexOptionExtracted { retain_history, .. }: IndexOptionExtracted = with_opts.try_into()?;
-
-    let mut out = Vec::with_capacity(1);
+    let mut out = Vec::with_capacity(2);
     if let Some(cw) = plan_retain_history_option(scx, retain_history)? {
         out.push(crate::plan::IndexOption::RetainHistory(cw));
     }
+    if persisted {
+        out.push(crate::plan::IndexOption::Persisted);
+    }
     Ok(out)
 }
 
 generate_extracted_config!(
     TableOption,
     (RetainHistory, OptionalDuration),
+    (Retention, RetentionOptionValue),
     (RedactedTest, String)
 );
 
 fn plan_table_options(
     scx: &StatementContext,
+    desc: &RelationDesc,
     with_opts: Vec<TableOption<Aug>>,
 ) -> Result<Vec<crate::plan::TableOption>, PlanError> {
     let TableOptionExtracted {
         retain_history,
+        retention,
         redacted_test,
         ..
     }: TableOptionExtracted = with_opts.try_into()?;
@@ -5693,13 +6027,54 @@ fn plan_table_options(
         scx.require_feature_flag(&vars::ENABLE_REDACTED_TEST_OPTION)?;
     }
 
-    let mut out = Vec::with_capacity(1);
+    let mut out = Vec::with_capacity(2);
     if let Some(cw) = plan_retain_history_option(scx, retain_history)? {
         out.push(crate::plan::TableOption::RetainHistory(cw));
     }
+    if let Some(retention) = retention {
+        let policy = plan_retention(scx, desc, &retention)?;
+        out.push(crate::plan::TableOption::Retention(policy));
+    }
     Ok(out)
 }
 
+/// Converts a specified RETENTION option into a retention policy over the columns of `desc`.
+fn plan_retention(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    RetentionOptionValue { period, column }: &RetentionOptionValue,
+) -> Result<RetentionPolicy, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_TABLE_RETENTION)?;
+    let invalid = |err: PlanError| PlanError::InvalidOptionValue {
+        option_name: "RETENTION".to_string(),
+        err: Box::new(err),
+    };
+
+    let period = Duration::try_from_value(period.clone()).map_err(invalid)?;
+    if period.is_zero() {
+        return Err(invalid(sql_err!("retention period must be positive")));
+    }
+
+    let column_name = normalize::column_name(column.clone());
+    let Some((column, typ)) = desc.get_by_name(&column_name) else {
+        return Err(invalid(sql_err!(
+            "column {} does not exist",
+            column_name.as_str().quoted()
+        )));
+    };
+    match typ.scalar_type {
+        ScalarType::Date | ScalarType::Timestamp { .. } | ScalarType::TimestampTz { .. } => {}
+        _ => {
+            return Err(invalid(sql_err!(
+                "column {} must have type date, timestamp, or timestamp with time zone",
+                column_name.as_str().quoted()
+            )))
+        }
+    }
+
+    Ok(RetentionPolicy { period, column })
+}
+
 pub fn plan_alter_index_options(
     scx: &mut StatementContext,
     AlterIndexStatement {
@@ -5726,6 +6101,9 @@ pub fn plan_alter_index_options(
                             None,
                         );
                     }
+                    IndexOptionName::Persisted => {
+                        sql_bail!("cannot change whether an existing index is PERSISTED");
+                    }
                 }
             }
             sql_bail!("expected option");
@@ -5746,6 +6124,9 @@ pub fn plan_alter_index_options(
                             opt.value,
                         );
                     }
+                    IndexOptionName::Persisted => {
+                        sql_bail!("cannot change whether an existing index is PERSISTED");
+                    }
                 }
             }
             sql_bail!("expected option");
@@ -5791,6 +6172,7 @@ pub fn plan_alter_cluster(
             with_options,
         } => {
             let ClusterOptionExtracted {
+                arrangement_spill,
                 availability_zones,
                 introspection_debugging,
                 introspection_interval,
@@ -5973,6 +6355,9 @@ pub fn plan_alter_cluster(
             if let Some(workload_class) = workload_class {
                 options.workload_class = AlterOptionParameter::Set(workload_class.0);
             }
+            if let Some(arrangement_spill) = arrangement_spill {
+                options.arrangement_spill = AlterOptionParameter::Set(Some(arrangement_spill));
+            }
         }
         AlterClusterAction::ResetOptions(reset_options) => {
             use AlterOptionParameter::Reset;
@@ -5986,6 +6371,7 @@ pub fn plan_alter_cluster(
 
             for option in reset_options {
                 match option {
+                    ArrangementSpill => options.arrangement_spill = Reset,
                     AvailabilityZones => options.availability_zones = Reset,
                     Disk => options.disk = Reset,
                     IntrospectionInterval => options.introspection_interval = Reset,
@@ -6501,6 +6887,69 @@ fn alter_retain_history(
     }
 }
 
+pub fn describe_alter_retention(
+    _: &StatementContext,
+    _: AlterRetentionStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_retention(
+    scx: &StatementContext,
+    AlterRetentionStatement {
+        object_type,
+        if_exists,
+        name,
+        retention,
+    }: AlterRetentionStatement,
+) -> Result<Plan, PlanError> {
+    let object_type = object_type.into();
+    match object_type {
+        ObjectType::Table => {}
+        _ => sql_bail!("{object_type} does not support RETENTION"),
+    }
+
+    match resolve_item_or_type(scx, object_type, name.clone(), if_exists)? {
+        Some(item) => {
+            let full_name = scx.catalog.resolve_full_name(item.name());
+            if item.id().is_system() {
+                sql_bail!(
+                    "cannot alter item {} because it is required by the database system",
+                    full_name,
+                );
+            }
+            if item.writable_table_details().is_none() {
+                sql_bail!(
+                    "cannot set RETENTION on {} because it is populated by a source",
+                    full_name,
+                );
+            }
+
+            let policy = match &retention {
+                Some(retention) => {
+                    let desc = item.desc(&full_name)?;
+                    Some(plan_retention(scx, &desc, retention)?)
+                }
+                None => None,
+            };
+
+            Ok(Plan::AlterRetention(AlterRetentionPlan {
+                id: item.id(),
+                value: retention,
+                policy,
+            }))
+        }
+        None => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_ast_string(),
+                object_type,
+            });
+
+            Ok(Plan::AlterNoop(AlterNoopPlan { object_type }))
+        }
+    }
+}
+
 pub fn describe_alter_secret_options(
     _: &StatementContext,
     _: AlterSecretStatement<Aug>,
@@ -44,8 +44,8 @@ use crate::ast::display::AstDisplay;
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
     DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
-    SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    Select, SelectItem, SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation,
+    SubscribeStatement, TableFactor, TableWithJoins, UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{Aug, ResolvedItemName};
@@ -421,6 +421,8 @@ impl TryFrom<ExplainPlanOptionExtracted> for ExplainConfig {
                 enable_reduce_mfp_fusion: Default::default(),
                 enable_cardinality_estimates: Default::default(),
                 persist_fast_path_limit: Default::default(),
+                enable_bounded_top_k: Default::default(),
+                bounded_top_k_buffer: Default::default(),
                 reoptimize_imported_views: v.reoptimize_imported_views,
                 enable_value_window_function_fusion: v.enable_value_window_function_fusion,
                 enable_reduce_unnest_list_fusion: v.enable_reduce_unnest_list_fusion,
@@ -719,11 +721,54 @@ pub fn plan_query(
 
 generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
 
+/// Rewrites a `SUBSCRIBE` to a relation with row-level or column-level
+/// security into a `SUBSCRIBE` to `SELECT * FROM <relation>`, so that its
+/// policies and column privileges apply.
+fn subscribe_relation_with_security(
+    scx: &StatementContext,
+    relation: SubscribeRelation<Aug>,
+) -> SubscribeRelation<Aug> {
+    let SubscribeRelation::Name(name) = relation else {
+        return relation;
+    };
+    let has_security = match &name {
+        ResolvedItemName::Item { id, .. } => {
+            scx.catalog
+                .get_row_security(id)
+                .is_some_and(|row_security| row_security.enabled)
+                || scx.catalog.get_column_security(id).is_some()
+        }
+        _ => false,
+    };
+    if !has_security {
+        return SubscribeRelation::Name(name);
+    }
+    let select = Select {
+        from: vec![TableWithJoins {
+            relation: TableFactor::Table { name, alias: None },
+            joins: Vec::new(),
+        }],
+        selection: None,
+        distinct: None,
+        projection: vec![SelectItem::Wildcard],
+        group_by: Vec::new(),
+        having: None,
+        options: Vec::new(),
+    };
+    SubscribeRelation::Query(Query {
+        ctes: CteBlock::Simple(Vec::new()),
+        body: SetExpr::Select(Box::new(select)),
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    })
+}
+
 pub fn describe_subscribe(
     scx: &StatementContext,
     stmt: SubscribeStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    let relation_desc = match stmt.relation {
+    let relation_desc = match subscribe_relation_with_security(scx, stmt.relation) {
         SubscribeRelation::Name(name) => {
             let item = scx.get_item_by_resolved_name(&name)?;
             item.desc(&scx.catalog.resolve_full_name(item.name()))?
@@ -821,7 +866,7 @@ pub fn plan_subscribe(
     params: &Params,
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
-    let (from, desc, scope) = match relation {
+    let (from, desc, scope) = match subscribe_relation_with_security(scx, relation) {
         SubscribeRelation::Name(name) => {
             let entry = scx.get_item_by_resolved_name(&name)?;
             let desc = match entry.desc(&scx.catalog.resolve_full_name(entry.name())) {
@@ -18,6 +18,7 @@ use mz_repr::{strconv, GlobalId, RelationVersionSelector};
 use mz_sql_parser::ast::{
     ClusterAlterOptionValue, ClusterScheduleOptionValue, ConnectionDefaultAwsPrivatelink, Expr,
     Ident, KafkaBroker, NetworkPolicyRuleDefinition, RefreshOptionValue, ReplicaDefinition,
+    RetentionOptionValue,
 };
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
 use serde::{Deserialize, Serialize};
@@ -664,6 +665,7 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::ClusterAlterStrategy(_)
             | WithOptionValue::Refresh(_)
             | WithOptionValue::ClusterScheduleOptionValue(_)
+            | WithOptionValue::Retention(_)
             | WithOptionValue::NetworkPolicyRules(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
@@ -684,6 +686,7 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::ConnectionAwsPrivatelink(_) => "connection kafka brokers",
                     WithOptionValue::Refresh(_) => "refresh option values",
                     WithOptionValue::ClusterScheduleOptionValue(_) => "cluster schedule",
+                    WithOptionValue::Retention(_) => "retention policies",
                     WithOptionValue::NetworkPolicyRules(_) => "network policy rules",
                 },
                 V::name()
@@ -830,6 +833,12 @@ impl ImpliedValue for ClusterScheduleOptionValue {
     }
 }
 
+impl ImpliedValue for RetentionOptionValue {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a retention period and timestamp column")
+    }
+}
+
 impl ImpliedValue for ClusterAlterOptionValue<Aug> {
     fn implied_value() -> Result<Self, PlanError> {
         sql_bail!("must provide a value")
@@ -854,6 +863,24 @@ impl TryFromValue<WithOptionValue<Aug>> for ClusterScheduleOptionValue {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for RetentionOptionValue {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        if let WithOptionValue::Retention(r) = v {
+            Ok(r)
+        } else {
+            sql_bail!("cannot use value `{}` for a retention policy", v)
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::Retention(self))
+    }
+
+    fn name() -> String {
+        "retention policy".to_string()
+    }
+}
+
 impl<V: ImpliedValue> ImpliedValue for BTreeMap<String, V> {
     fn implied_value() -> Result<Self, PlanError> {
         sql_bail!("must provide a map of key-value pairs")
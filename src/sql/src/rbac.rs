@@ -451,6 +451,7 @@ fn generate_rbac_requirements(
             name: _,
             variant: _,
             workload_class: _,
+            arrangement_spill: _,
         }) => RbacRequirements {
             privileges: vec![(SystemObjectId::System, AclMode::CREATE_CLUSTER, role_id)],
             item_usage: &CREATE_ITEM_USAGE,
@@ -556,15 +557,26 @@ fn generate_rbac_requirements(
             name,
             table: _,
             if_not_exists: _,
-        }) => RbacRequirements {
-            privileges: vec![(
+            clone_of,
+        }) => {
+            let mut privileges = vec![(
                 SystemObjectId::Object(name.qualifiers.clone().into()),
                 AclMode::CREATE,
                 role_id,
-            )],
-            item_usage: &CREATE_ITEM_USAGE,
-            ..Default::default()
-        },
+            )];
+            if let Some(clone_of) = clone_of {
+                privileges.extend_from_slice(&generate_read_privileges(
+                    catalog,
+                    iter::once(clone_of.id),
+                    role_id,
+                ));
+            }
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::CreateView(plan::CreateViewPlan {
             name,
             view: _,
@@ -1026,6 +1038,62 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterRetention(plan::AlterRetentionPlan {
+            id,
+            value: _,
+            policy: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::AlterRowSecurity(plan::AlterRowSecurityPlan {
+            id,
+            enabled: _,
+            object_type: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            ..Default::default()
+        },
+        Plan::AlterColumnMask(plan::AlterColumnMaskPlan {
+            id,
+            column_pos: _,
+            mask: _,
+            object_type: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            ..Default::default()
+        },
+        Plan::CreatePolicy(plan::CreatePolicyPlan {
+            relation_id,
+            name: _,
+            policy: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*relation_id)],
+            ..Default::default()
+        },
+        Plan::DropPolicy(plan::DropPolicyPlan { policy }) => RbacRequirements {
+            ownership: policy
+                .iter()
+                .map(|(relation_id, _)| ObjectId::Item(*relation_id))
+                .collect(),
+            ..Default::default()
+        },
+        // Resource groups constrain the sessions of their members, so allowing
+        // anyone but a superuser to manage them would let roles lift their own
+        // limits.
+        Plan::CreateResourceGroup(plan::CreateResourceGroupPlan { .. }) => RbacRequirements {
+            superuser_action: Some("CREATE RESOURCE GROUP".to_string()),
+            ..Default::default()
+        },
+        Plan::AlterResourceGroup(plan::AlterResourceGroupPlan { .. }) => RbacRequirements {
+            superuser_action: Some("ALTER RESOURCE GROUP".to_string()),
+            ..Default::default()
+        },
+        Plan::DropResourceGroup(plan::DropResourceGroupPlan { .. }) => RbacRequirements {
+            superuser_action: Some("DROP RESOURCE GROUP".to_string()),
+            ..Default::default()
+        },
         Plan::AlterConnection(plan::AlterConnectionPlan { id, action: _ }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*id)],
             ..Default::default()
@@ -1694,7 +1762,15 @@ fn check_object_privileges(
             .flat_map(|role_id| object_privileges.get_acl_items_for_grantee(role_id))
             .map(|mz_acl_item| mz_acl_item.acl_mode)
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode));
-        if !role_privileges.contains(acl_mode) {
+        let mut missing_privileges = acl_mode.difference(role_privileges);
+        // Roles that have been granted `SELECT` on some columns of a relation
+        // may read it. Planning restricts them to those columns.
+        if missing_privileges.contains(AclMode::SELECT)
+            && has_column_select(catalog, &object_id, role_membership)
+        {
+            missing_privileges = missing_privileges.difference(AclMode::SELECT);
+        }
+        if !missing_privileges.is_empty() {
             let role_name = catalog.get_role(&role_id).name().to_string();
             let privileges = acl_mode.to_error_string();
             return Err(UnauthorizedError::Privilege {
@@ -1708,6 +1784,25 @@ fn check_object_privileges(
     Ok(())
 }
 
+/// Reports whether any role in `role_membership` has been granted `SELECT` on
+/// a column of `object_id`.
+fn has_column_select(
+    catalog: &impl SessionCatalog,
+    object_id: &SystemObjectId,
+    role_membership: &BTreeSet<RoleId>,
+) -> bool {
+    let SystemObjectId::Object(ObjectId::Item(id)) = object_id else {
+        return false;
+    };
+    catalog
+        .get_column_security(id)
+        .is_some_and(|column_security| {
+            column_security
+                .values()
+                .any(|security| security.has_privileges(role_membership, AclMode::SELECT))
+        })
+}
+
 pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
     const TABLE_ACL_MODE: AclMode = AclMode::INSERT
         .union(AclMode::SELECT)
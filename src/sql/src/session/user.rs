@@ -18,18 +18,21 @@ pub const SYSTEM_USER_NAME: &str = "mz_system";
 pub static SYSTEM_USER: LazyLock<User> = LazyLock::new(|| User {
     name: SYSTEM_USER_NAME.into(),
     external_metadata: None,
+    client_certificate_subject: None,
 });
 
 pub const SUPPORT_USER_NAME: &str = "mz_support";
 pub static SUPPORT_USER: LazyLock<User> = LazyLock::new(|| User {
     name: SUPPORT_USER_NAME.into(),
     external_metadata: None,
+    client_certificate_subject: None,
 });
 
 pub const ANALYTICS_USER_NAME: &str = "mz_analytics";
 pub static ANALYTICS_USER: LazyLock<User> = LazyLock::new(|| User {
     name: ANALYTICS_USER_NAME.into(),
     external_metadata: None,
+    client_certificate_subject: None,
 });
 
 pub static INTERNAL_USER_NAMES: LazyLock<BTreeSet<String>> = LazyLock::new(|| {
@@ -54,6 +57,7 @@ pub static INTERNAL_USER_NAME_TO_DEFAULT_CLUSTER: LazyLock<BTreeMap<String, Stri
 pub static HTTP_DEFAULT_USER: LazyLock<User> = LazyLock::new(|| User {
     name: "anonymous_http_user".into(),
     external_metadata: None,
+    client_certificate_subject: None,
 });
 
 /// Identifies a user.
@@ -63,6 +67,9 @@ pub struct User {
     pub name: String,
     /// Metadata about this user in an external system.
     pub external_metadata: Option<ExternalUserMetadata>,
+    /// The subject of the TLS client certificate that authenticated this user,
+    /// if the user authenticated with a client certificate.
+    pub client_certificate_subject: Option<String>,
 }
 
 impl PartialEq for User {
@@ -120,6 +127,7 @@ pub enum UserKind {
 pub const MZ_SYSTEM_ROLE_ID: RoleId = RoleId::System(1);
 pub const MZ_SUPPORT_ROLE_ID: RoleId = RoleId::System(2);
 pub const MZ_ANALYTICS_ROLE_ID: RoleId = RoleId::System(3);
+pub const MZ_EXTERNAL_ROLE_SYNC_ROLE_ID: RoleId = RoleId::System(4);
 pub const MZ_MONITOR_ROLE_ID: RoleId = RoleId::Predefined(1);
 pub const MZ_MONITOR_REDACTED_ROLE_ID: RoleId = RoleId::Predefined(2);
 
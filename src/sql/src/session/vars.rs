@@ -1186,6 +1186,7 @@ impl SystemVars {
                 &TIMEZONE,
                 &TRANSACTION_ISOLATION,
                 &MAX_QUERY_RESULT_SIZE,
+                &NETWORK_POLICY,
             ]
             .into_iter()
             .map(|var| (UncasedStr::new(var.name()), var))
@@ -1243,6 +1244,7 @@ impl SystemVars {
             &STORAGE_SHRINK_UPSERT_UNUSED_BUFFERS_BY_RATIO,
             &STORAGE_RECORD_SOURCE_SINK_NAMESPACED_ERRORS,
             &PERSIST_FAST_PATH_LIMIT,
+            &BOUNDED_TOP_K_BUFFER,
             &METRICS_RETENTION,
             &UNSAFE_MOCK_AUDIT_EVENT_TIMESTAMP,
             &ENABLE_RBAC_CHECKS,
@@ -1312,6 +1314,10 @@ impl SystemVars {
             &STATEMENT_LOGGING_DEFAULT_SAMPLE_RATE,
             &STATEMENT_LOGGING_TARGET_DATA_RATE,
             &STATEMENT_LOGGING_MAX_DATA_CREDIT,
+            &AUDIT_LOG_RETENTION_PERIOD,
+            &RETENTION_CHECK_INTERVAL,
+            &AUDIT_LOG_LOGINS,
+            &AUDIT_LOG_SELECT_OBJECTS,
             &OPTIMIZER_STATS_TIMEOUT,
             &OPTIMIZER_ONESHOT_STATS_TIMEOUT,
             &PRIVATELINK_STATUS_UPDATE_QUOTA_PER_MINUTE,
@@ -1845,6 +1851,11 @@ impl SystemVars {
         *self.expect_value(&PERSIST_FAST_PATH_LIMIT)
     }
 
+    /// Returns the `bounded_top_k_buffer` configuration parameter.
+    pub fn bounded_top_k_buffer(&self) -> usize {
+        *self.expect_value(&BOUNDED_TOP_K_BUFFER)
+    }
+
     /// Returns the `pg_source_connect_timeout` configuration parameter.
     pub fn pg_source_connect_timeout(&self) -> Duration {
         *self.expect_value(&PG_SOURCE_CONNECT_TIMEOUT)
@@ -2072,6 +2083,12 @@ impl SystemVars {
             .clone()
     }
 
+    /// Returns the system default for the [`NETWORK_POLICY`] session variable,
+    /// which applies to roles that do not set their own.
+    pub fn default_network_policy_name(&self) -> String {
+        self.expect_value::<String>(&NETWORK_POLICY).to_owned()
+    }
+
     /// Returns the `superuser_reserved_connections` configuration parameter.
     pub fn superuser_reserved_connections(&self) -> u32 {
         *self.expect_value(&SUPERUSER_RESERVED_CONNECTIONS)
@@ -2241,6 +2258,27 @@ impl SystemVars {
         *self.expect_value(&STATEMENT_LOGGING_DEFAULT_SAMPLE_RATE)
     }
 
+    /// Returns the `audit_log_retention_period` configuration parameter.
+    pub fn audit_log_retention_period(&self) -> Duration {
+        *self.expect_value(&AUDIT_LOG_RETENTION_PERIOD)
+    }
+
+    /// Returns the `retention_check_interval` configuration parameter.
+    pub fn retention_check_interval(&self) -> Duration {
+        *self.expect_value(&RETENTION_CHECK_INTERVAL)
+    }
+
+    /// Returns the `audit_log_logins` configuration parameter.
+    pub fn audit_log_logins(&self) -> bool {
+        *self.expect_value(&AUDIT_LOG_LOGINS)
+    }
+
+    /// Returns the `audit_log_select_objects` configuration parameter.
+    pub fn audit_log_select_objects(&self) -> &[AuditedObject] {
+        self.expect_value::<Vec<AuditedObject>>(&AUDIT_LOG_SELECT_OBJECTS)
+            .as_slice()
+    }
+
     /// Returns the `optimizer_stats_timeout` configuration parameter.
     pub fn optimizer_stats_timeout(&self) -> Duration {
         *self.expect_value(&OPTIMIZER_STATS_TIMEOUT)
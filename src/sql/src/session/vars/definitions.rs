@@ -45,8 +45,8 @@ use crate::session::vars::constraints::{
 use crate::session::vars::errors::VarError;
 use crate::session::vars::polyfill::{lazy_value, value, LazyValueFn};
 use crate::session::vars::value::{
-    ClientEncoding, ClientSeverity, Failpoints, IntervalStyle, IsolationLevel, TimeZone, Value,
-    DEFAULT_DATE_STYLE,
+    AuditedObject, ClientEncoding, ClientSeverity, Failpoints, IntervalStyle, IsolationLevel,
+    TimeZone, Value, DEFAULT_DATE_STYLE,
 };
 use crate::session::vars::{FeatureFlag, Var, VarInput, VarParseError};
 use crate::{DEFAULT_SCHEMA, WEBHOOK_CONCURRENCY_LIMIT};
@@ -637,6 +637,14 @@ pub static PERSIST_FAST_PATH_LIMIT: VarDefinition = VarDefinition::new(
     false,
 );
 
+pub static BOUNDED_TOP_K_BUFFER: VarDefinition = VarDefinition::new(
+    "bounded_top_k_buffer",
+    value!(usize; 64),
+    "The number of rows beyond `OFFSET + LIMIT` that bounded TopK operators retain per group; \
+    larger values make recomputation of a group from its input less likely (Materialize).",
+    false,
+);
+
 /// Controls `mz_adapter::coord::timestamp_oracle::postgres_oracle::DynamicConfig::pg_connection_pool_max_size`.
 pub static PG_TIMESTAMP_ORACLE_CONNECTION_POOL_MAX_SIZE: VarDefinition = VarDefinition::new(
     "pg_timestamp_oracle_connection_pool_max_size",
@@ -1406,6 +1414,36 @@ pub static STATEMENT_LOGGING_DEFAULT_SAMPLE_RATE: VarDefinition = VarDefinition:
 )
 .with_constraint(&NUMERIC_BOUNDED_0_1_INCLUSIVE);
 
+pub static AUDIT_LOG_RETENTION_PERIOD: VarDefinition = VarDefinition::new(
+    "audit_log_retention_period",
+    value!(Duration; Duration::ZERO),
+    "How long events are retained in the audit log before they are pruned, or 0 to retain them \
+        indefinitely (Materialize).",
+    true,
+);
+
+pub static RETENTION_CHECK_INTERVAL: VarDefinition = VarDefinition::new(
+    "retention_check_interval",
+    value!(Duration; Duration::from_secs(60)),
+    "How often tables with a RETENTION policy are checked for expired rows (Materialize).",
+    true,
+);
+
+pub static AUDIT_LOG_LOGINS: VarDefinition = VarDefinition::new(
+    "audit_log_logins",
+    value!(bool; false),
+    "Whether to record successful and failed logins in the audit log (Materialize).",
+    true,
+);
+
+pub static AUDIT_LOG_SELECT_OBJECTS: VarDefinition = VarDefinition::new(
+    "audit_log_select_objects",
+    value!(Vec<AuditedObject>; Vec::new()),
+    "The fully qualified names of the objects whose reads are recorded in the audit log, each \
+        optionally followed by a colon and the fraction of reads to record (Materialize).",
+    true,
+);
+
 pub static AUTO_ROUTE_CATALOG_QUERIES: VarDefinition = VarDefinition::new(
     "auto_route_catalog_queries",
     value!(bool; true),
@@ -1510,6 +1548,13 @@ pub static DEFAULT_NETWORK_POLICY_ALLOW_LIST: VarDefinition = VarDefinition::new
     true,
 );
 
+pub static NETWORK_POLICY: VarDefinition = VarDefinition::new_lazy(
+    "network_policy",
+    lazy_value!(String; || String::new()),
+    "Sets the network policy that connections are validated against. If empty, connections are validated against `default_network_policy_allow_list` (Materialize).",
+    true,
+);
+
 pub static ENABLE_CREATE_TABLE_FROM_SOURCE: VarDefinition = VarDefinition::new(
     "enable_create_table_from_source",
     value!(bool; false),
@@ -2154,6 +2199,12 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_bounded_top_k,
+        desc: "TopK operators that retain a bounded number of rows per group",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_reduce_unnest_list_fusion,
         desc: "Enables fusing `Reduce` with `FlatMap UnnestList` for better window function performance",
@@ -2184,6 +2235,54 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_row_level_security,
+        desc: "CREATE POLICY and ENABLE ROW LEVEL SECURITY",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_column_level_security,
+        desc: "GRANT on columns and SET MASK",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_resource_groups,
+        desc: "CREATE RESOURCE GROUP",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_external_secrets,
+        desc: "CREATE SECRET ... FROM VAULT",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_table_retention,
+        desc: "RETENTION for tables",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_create_table_clone,
+        desc: "CREATE TABLE ... CLONE",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_read_write_transactions,
+        desc: "transactions that read and then write tables",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_persisted_indexes,
+        desc: "CREATE INDEX ... WITH (PERSISTED)",
+        default: false,
+        enable_for_item_parsing: true,
+    },
 );
 
 impl From<&super::SystemVars> for OptimizerFeatures {
@@ -2199,6 +2298,8 @@ impl From<&super::SystemVars> for OptimizerFeatures {
             enable_value_window_function_fusion: vars.enable_value_window_function_fusion(),
             enable_reduce_unnest_list_fusion: vars.enable_reduce_unnest_list_fusion(),
             enable_window_aggregation_fusion: vars.enable_window_aggregation_fusion(),
+            enable_bounded_top_k: vars.enable_bounded_top_k(),
+            bounded_top_k_buffer: vars.bounded_top_k_buffer(),
             persist_fast_path_limit: vars.persist_fast_path_limit(),
             reoptimize_imported_views: false,
         }
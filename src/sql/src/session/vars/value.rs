@@ -547,6 +547,87 @@ impl Value for Vec<SerializableDirective> {
     }
 }
 
+/// An object whose reads are recorded in the audit log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditedObject {
+    /// The fully qualified name of the object, e.g. `materialize.public.t`.
+    pub name: String,
+    /// The fraction of reads of the object to record, in the range `[0, 1]`.
+    pub sample_rate: f64,
+}
+
+impl AuditedObject {
+    fn parse(s: &str) -> Result<AuditedObject, String> {
+        let (name, sample_rate) = match s.rsplit_once(':') {
+            Some((name, rate)) => {
+                let rate = rate
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid sample rate {}", rate.trim()))?;
+                if !(0.0..=1.0).contains(&rate) {
+                    return Err(format!("sample rate {rate} is not between 0 and 1"));
+                }
+                (name.trim(), rate)
+            }
+            None => (s, 1.0),
+        };
+        if name.split('.').count() != 3 || name.split('.').any(|part| part.is_empty()) {
+            return Err(format!(
+                "{name} is not a fully qualified object name of the form database.schema.name"
+            ));
+        }
+        Ok(AuditedObject {
+            name: name.to_string(),
+            sample_rate,
+        })
+    }
+}
+
+impl fmt::Display for AuditedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if self.sample_rate != 1.0 {
+            write!(f, ":{}", self.sample_rate)?;
+        }
+        Ok(())
+    }
+}
+
+impl Value for Vec<AuditedObject> {
+    fn type_name() -> Cow<'static, str>
+    where
+        Self: Sized,
+    {
+        "audited object list".into()
+    }
+
+    fn parse(input: VarInput<'_>) -> Result<Self, VarParseError>
+    where
+        Self: Sized,
+    {
+        let values = input.to_vec();
+        values
+            .iter()
+            .flat_map(|v| v.split(','))
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(AuditedObject::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|reason| VarParseError::InvalidParameterValue {
+                invalid_values: values,
+                reason,
+            })
+    }
+
+    fn box_clone(&self) -> Box<dyn Value> {
+        Box::new(self.clone())
+    }
+
+    fn format(&self) -> String {
+        self.iter().map(|object| object.to_string()).join(", ")
+    }
+}
+
 // This unorthodox design lets us escape complex errors from value parsing.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Failpoints;
@@ -1169,6 +1250,51 @@ mod tests {
 
     use super::*;
 
+    #[mz_ore::test]
+    fn test_value_audited_objects() {
+        let objects = Vec::<AuditedObject>::parse(VarInput::Flat(
+            "materialize.public.t, materialize.public.u:0.25",
+        ))
+        .expect("valid audited objects");
+        assert_eq!(
+            objects,
+            vec![
+                AuditedObject {
+                    name: "materialize.public.t".into(),
+                    sample_rate: 1.0,
+                },
+                AuditedObject {
+                    name: "materialize.public.u".into(),
+                    sample_rate: 0.25,
+                },
+            ]
+        );
+        assert_eq!(
+            objects.format(),
+            "materialize.public.t, materialize.public.u:0.25"
+        );
+
+        let objects = Vec::<AuditedObject>::parse(VarInput::SqlSet(&[
+            "materialize.public.t".into(),
+            "materialize.public.u:0".into(),
+        ]))
+        .expect("valid audited objects");
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[1].sample_rate, 0.0);
+
+        assert_eq!(
+            Vec::<AuditedObject>::parse(VarInput::Flat("")).expect("empty list"),
+            vec![]
+        );
+        assert_err!(Vec::<AuditedObject>::parse(VarInput::Flat("public.t")));
+        assert_err!(Vec::<AuditedObject>::parse(VarInput::Flat(
+            "materialize.public.t:2"
+        )));
+        assert_err!(Vec::<AuditedObject>::parse(VarInput::Flat(
+            "materialize.public.t:often"
+        )));
+    }
+
     #[mz_ore::test]
     fn test_value_duration() {
         fn inner(t: &'static str, e: Duration, expected_format: Option<&'static str>) {
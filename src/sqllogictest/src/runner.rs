@@ -1057,6 +1057,9 @@ impl<'a> RunnerInner<'a> {
                     secrets_reader_local_file_dir: Some(secrets_dir),
                     secrets_reader_kubernetes_context: None,
                     secrets_reader_aws_prefix: None,
+                    secrets_reader_vault_address: None,
+                    secrets_reader_vault_mount: None,
+                    secrets_reader_vault_token_file: None,
                 },
                 connection_context,
             },
@@ -1064,6 +1067,7 @@ impl<'a> RunnerInner<'a> {
             cloud_resource_controller: None,
             tls: None,
             frontegg: None,
+            oidc: None,
             cors_allowed_origin: AllowOrigin::list([]),
             unsafe_mode: true,
             all_features: false,
@@ -1092,6 +1096,7 @@ impl<'a> RunnerInner<'a> {
             storage_usage_retention_period: None,
             segment_api_key: None,
             segment_client_side: false,
+            audit_log_export_path: None,
             egress_addresses: vec![],
             aws_account_id: None,
             aws_privatelink_availability_zones: None,
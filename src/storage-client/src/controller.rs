@@ -48,6 +48,7 @@ use mz_storage_types::sources::{
     GenericSourceConnection, IngestionDescription, SourceData, SourceDesc, SourceExportDataConfig,
     SourceExportDetails, Timeline,
 };
+use mz_storage_types::stats::RelationPartStats;
 use serde::{Deserialize, Serialize};
 use timely::progress::Timestamp as TimelyTimestamp;
 use timely::progress::{Antichain, Timestamp};
@@ -88,6 +89,7 @@ pub enum IntrospectionType {
     // Collections written by the compute controller.
     ComputeDependencies,
     ComputeOperatorHydrationStatus,
+    ComputeDataflowMemoryLimitStatus,
     ComputeMaterializedViewRefreshes,
     ComputeErrorCounts,
     ComputeHydrationTimes,
@@ -258,6 +260,10 @@ pub trait StorageTxn<T> {
 
 pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
 
+/// Decides, from its statistics, whether a part of a collection may contain
+/// updates of interest. See [`StorageController::snapshot_filtered`].
+pub type PartFilter = Box<dyn for<'a> Fn(&RelationPartStats<'a>) -> bool + Send + Sync>;
+
 /// A predicate for a `Row` filter.
 pub type RowPredicate = Box<dyn Fn(&Row) -> bool + Send + Sync>;
 
@@ -427,6 +433,25 @@ pub trait StorageController: Debug {
         migrated_storage_collections: &BTreeSet<GlobalId>,
     ) -> Result<(), StorageError<Self::Timestamp>>;
 
+    /// Makes the shard of the collection `id`, which must not have been
+    /// created yet, a fork of the shard of the collection `source`, so that it
+    /// starts out with the contents of `source` without copying them.
+    ///
+    /// The fork's upper is not advanced beyond `upper_bound`, so it may only
+    /// have a prefix of the contents of `source`, or none at all. Returns the
+    /// fork's upper.
+    ///
+    /// This must be called before `id` is created with
+    /// [`Self::create_collections`], with `storage_metadata` that already
+    /// contains its shard.
+    async fn fork_collection(
+        &mut self,
+        storage_metadata: &StorageMetadata,
+        source: GlobalId,
+        id: GlobalId,
+        upper_bound: Self::Timestamp,
+    ) -> Result<Antichain<Self::Timestamp>, StorageError<Self::Timestamp>>;
+
     /// Check that the ingestion associated with `id` can use the provided
     /// [`SourceDesc`].
     ///
@@ -565,6 +590,25 @@ pub trait StorageController: Debug {
         StorageError<Self::Timestamp>,
     >;
 
+    /// Like [`StorageController::append_table`], but the append is conditional
+    /// on none of the tables in `read_set` having been changed after the
+    /// timestamp they are mapped to.
+    ///
+    /// This is used to commit transactions that read tables before writing.
+    /// If the condition does not hold, none of `commands` are applied and the
+    /// oneshot returns [`StorageError::ReadConflict`] with the offending
+    /// table. The tables are still advanced to `advance_to`.
+    fn append_table_validated(
+        &mut self,
+        write_ts: Self::Timestamp,
+        advance_to: Self::Timestamp,
+        commands: Vec<(GlobalId, Vec<TimestamplessUpdate>)>,
+        read_set: BTreeMap<GlobalId, Self::Timestamp>,
+    ) -> Result<
+        tokio::sync::oneshot::Receiver<Result<(), StorageError<Self::Timestamp>>>,
+        StorageError<Self::Timestamp>,
+    >;
+
     /// Returns a [`MonotonicAppender`] which is a channel that can be used to monotonically
     /// append to the specified [`GlobalId`].
     fn monotonic_appender(
@@ -591,6 +635,19 @@ pub trait StorageController: Debug {
         as_of: Self::Timestamp,
     ) -> BoxFuture<Result<Vec<(Row, Diff)>, StorageError<Self::Timestamp>>>;
 
+    /// Returns the snapshot of the contents of the local input named `id` at
+    /// `as_of`, skipping the parts of the collection for which `filter` does
+    /// not hold.
+    ///
+    /// Parts without statistics are always read. All updates in the parts
+    /// that are read are returned, so callers must still filter the result.
+    fn snapshot_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Self::Timestamp,
+        filter: PartFilter,
+    ) -> BoxFuture<Result<Vec<(Row, Diff)>, StorageError<Self::Timestamp>>>;
+
     /// Returns the snapshot of the contents of the local input named `id` at
     /// the largest readable `as_of`.
     async fn snapshot_latest(
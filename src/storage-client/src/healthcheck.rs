@@ -49,6 +49,10 @@ pub static MZ_SESSION_HISTORY_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
             ScalarType::String.nullable(false),
         )
         .with_column("authenticated_user", ScalarType::String.nullable(false))
+        .with_column(
+            "client_certificate_subject",
+            ScalarType::String.nullable(true),
+        )
         .finish()
 });
 
@@ -675,6 +675,7 @@ where
 
             IntrospectionType::ComputeDependencies
             | IntrospectionType::ComputeOperatorHydrationStatus
+            | IntrospectionType::ComputeDataflowMemoryLimitStatus
             | IntrospectionType::ComputeMaterializedViewRefreshes
             | IntrospectionType::ComputeErrorCounts
             | IntrospectionType::ComputeHydrationTimes => {
@@ -1105,6 +1106,7 @@ where
             | Some(introspection_type @ IntrospectionType::StorageSinkStatistics)
             | Some(introspection_type @ IntrospectionType::ComputeDependencies)
             | Some(introspection_type @ IntrospectionType::ComputeOperatorHydrationStatus)
+            | Some(introspection_type @ IntrospectionType::ComputeDataflowMemoryLimitStatus)
             | Some(introspection_type @ IntrospectionType::ComputeMaterializedViewRefreshes)
             | Some(introspection_type @ IntrospectionType::ComputeErrorCounts)
             | Some(introspection_type @ IntrospectionType::ComputeHydrationTimes) => {
@@ -1292,6 +1294,7 @@ where
             | introspection_type @ IntrospectionType::StorageSinkStatistics
             | introspection_type @ IntrospectionType::ComputeDependencies
             | introspection_type @ IntrospectionType::ComputeOperatorHydrationStatus
+            | introspection_type @ IntrospectionType::ComputeDataflowMemoryLimitStatus
             | introspection_type @ IntrospectionType::ComputeMaterializedViewRefreshes
             | introspection_type @ IntrospectionType::ComputeErrorCounts
             | introspection_type @ IntrospectionType::ComputeHydrationTimes => {
@@ -36,7 +36,8 @@ use mz_ore::task::AbortOnDropHandle;
 use mz_ore::{assert_none, instrument, soft_panic_or_log};
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::USE_CRITICAL_SINCE_SNAPSHOT;
-use mz_persist_client::read::ReadHandle;
+use mz_persist_client::error::InvalidUsage;
+use mz_persist_client::read::{LazyPartStats, ReadHandle};
 use mz_persist_client::schema::CaESchema;
 use mz_persist_client::stats::{SnapshotPartsStats, SnapshotStats};
 use mz_persist_client::write::WriteHandle;
@@ -54,7 +55,7 @@ use mz_storage_client::client::{
 };
 use mz_storage_client::controller::{
     BoxFuture, CollectionDescription, DataSource, ExportDescription, ExportState,
-    IntrospectionType, MonotonicAppender, PersistEpoch, Response, SnapshotCursor,
+    IntrospectionType, MonotonicAppender, PartFilter, PersistEpoch, Response, SnapshotCursor,
     StorageController, StorageMetadata, StorageTxn, StorageWriteOp,
 };
 use mz_storage_client::healthcheck::{
@@ -79,6 +80,7 @@ use mz_storage_types::sources::{
     GenericSourceConnection, IngestionDescription, SourceConnection, SourceData, SourceDesc,
     SourceExport, SourceExportDataConfig,
 };
+use mz_storage_types::stats::RelationPartStats;
 use mz_storage_types::AlterCompatible;
 use mz_txn_wal::metrics::Metrics as TxnMetrics;
 use mz_txn_wal::txn_read::TxnsRead;
@@ -876,6 +878,51 @@ where
         Ok(())
     }
 
+    #[instrument(name = "storage::fork_collection")]
+    async fn fork_collection(
+        &mut self,
+        storage_metadata: &StorageMetadata,
+        source: GlobalId,
+        id: GlobalId,
+        upper_bound: Self::Timestamp,
+    ) -> Result<Antichain<Self::Timestamp>, StorageError<Self::Timestamp>> {
+        if self.collections.contains_key(&id) {
+            return Err(StorageError::SourceIdReused(id));
+        }
+        let source_shard = self.collection(source)?.collection_metadata.data_shard;
+        let shard = storage_metadata.get_collection_shard::<T>(id)?;
+
+        let persist_client = self
+            .persist
+            .open(self.persist_location.clone())
+            .await
+            .unwrap();
+        let diagnostics = Diagnostics {
+            shard_name: id.to_string(),
+            handle_purpose: format!("fork of {} for {}", source, id),
+        };
+        let upper = persist_client
+            .fork_shard::<SourceData, (), T, Diff>(
+                source_shard,
+                shard,
+                &Antichain::from_elem(upper_bound),
+                diagnostics,
+            )
+            .await
+            .map_err(|err| match err {
+                InvalidUsage::ForkTargetInUse { shard_id } => {
+                    StorageError::PersistShardAlreadyInUse(shard_id)
+                }
+                err => StorageError::InvalidUsage(err.to_string()),
+            })?;
+        debug!(
+            "forked {} ({}) into {} ({}) up to {:?}",
+            source, source_shard, id, shard, upper
+        );
+
+        Ok(upper)
+    }
+
     fn check_alter_ingestion_source_desc(
         &mut self,
         ingestion_id: GlobalId,
@@ -1605,6 +1652,20 @@ where
     ) -> Result<
         tokio::sync::oneshot::Receiver<Result<(), StorageError<Self::Timestamp>>>,
         StorageError<Self::Timestamp>,
+    > {
+        self.append_table_validated(write_ts, advance_to, commands, BTreeMap::new())
+    }
+
+    #[instrument(level = "debug")]
+    fn append_table_validated(
+        &mut self,
+        write_ts: Self::Timestamp,
+        advance_to: Self::Timestamp,
+        commands: Vec<(GlobalId, Vec<TimestamplessUpdate>)>,
+        read_set: BTreeMap<GlobalId, Self::Timestamp>,
+    ) -> Result<
+        tokio::sync::oneshot::Receiver<Result<(), StorageError<Self::Timestamp>>>,
+        StorageError<Self::Timestamp>,
     > {
         if self.read_only {
             // While in read only mode, ONLY collections that have been migrated
@@ -1628,7 +1689,7 @@ where
 
         Ok(self
             .persist_table_worker
-            .append(write_ts, advance_to, commands))
+            .append(write_ts, advance_to, commands, read_set))
     }
 
     fn monotonic_appender(
@@ -1670,6 +1731,22 @@ where
         )
     }
 
+    fn snapshot_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Self::Timestamp,
+        filter: PartFilter,
+    ) -> BoxFuture<Result<Vec<(Row, Diff)>, StorageError<Self::Timestamp>>> {
+        snapshot_filtered(
+            id,
+            as_of,
+            filter,
+            &self.storage_collections,
+            &self.txns_read,
+            &self.persist,
+        )
+    }
+
     async fn snapshot_latest(
         &self,
         id: GlobalId,
@@ -3399,6 +3476,7 @@ impl From<&IntrospectionType> for CollectionManagerKind {
             | IntrospectionType::StorageSinkStatistics
             | IntrospectionType::ComputeDependencies
             | IntrospectionType::ComputeOperatorHydrationStatus
+            | IntrospectionType::ComputeDataflowMemoryLimitStatus
             | IntrospectionType::ComputeMaterializedViewRefreshes
             | IntrospectionType::ComputeErrorCounts
             | IntrospectionType::ComputeHydrationTimes => CollectionManagerKind::Differential,
@@ -3525,6 +3603,69 @@ where
     .boxed()
 }
 
+/// Like [`snapshot`], but only reads the parts of the collection for which
+/// `filter` holds, as determined by their statistics.
+pub(crate) fn snapshot_filtered<T>(
+    id: GlobalId,
+    as_of: T,
+    filter: PartFilter,
+    storage_collections: &Arc<dyn StorageCollections<Timestamp = T> + Send + Sync>,
+    txns_read: &TxnsRead<T>,
+    persist: &Arc<PersistClientCache>,
+) -> BoxFuture<Result<Vec<(Row, Diff)>, StorageError<T>>>
+where
+    T: Codec64 + From<EpochMillis> + TimestampManipulation,
+{
+    let metadata = match storage_collections.collection_metadata(id) {
+        Ok(metadata) => metadata,
+        Err(e) => return async { Err(e) }.boxed(),
+    };
+    let txns_read = metadata.txns_shard.as_ref().map(|txns_id| {
+        assert_eq!(txns_id, txns_read.txns_id());
+        txns_read.clone()
+    });
+    let persist = Arc::clone(persist);
+    async move {
+        let mut read_handle = read_handle_for_snapshot(&persist, id, &metadata).await?;
+        let name = id.to_string();
+        let metrics = Arc::clone(persist.metrics());
+        let desc = metadata.relation_desc.clone();
+        let should_fetch_part = move |stats: Option<&LazyPartStats>| {
+            let Some(stats) = stats else { return true };
+            let stats = stats.decode();
+            let stats = RelationPartStats::new(&name, &metrics.pushdown.part_stats, &desc, &stats);
+            filter(&stats)
+        };
+        let cursor = match txns_read {
+            None => {
+                read_handle
+                    .snapshot_cursor(Antichain::from_elem(as_of), should_fetch_part)
+                    .await
+            }
+            Some(txns_read) => {
+                // See the comments in `snapshot` for why reads go through txn-wal.
+                txns_read.update_gt(as_of.clone()).await;
+                let data_snapshot = txns_read
+                    .data_snapshot(metadata.data_shard, as_of.clone())
+                    .await;
+                data_snapshot
+                    .snapshot_cursor(&mut read_handle, should_fetch_part)
+                    .await
+            }
+        };
+        let mut cursor = cursor.map_err(|_| StorageError::ReadBeforeSince(id))?;
+        let mut snapshot = Vec::new();
+        while let Some(updates) = cursor.next().await {
+            for ((data, _), _, diff) in updates {
+                let row = data.expect("invalid protobuf data").0?;
+                snapshot.push((row, diff));
+            }
+        }
+        Ok(snapshot)
+    }
+    .boxed()
+}
+
 async fn read_handle_for_snapshot<T>(
     persist: &PersistClientCache,
     id: GlobalId,
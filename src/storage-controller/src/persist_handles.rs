@@ -28,6 +28,7 @@ use mz_repr::{Diff, GlobalId, TimestampManipulation};
 use mz_storage_client::client::{TimestamplessUpdate, Update};
 use mz_storage_types::controller::{InvalidUpper, TxnsCodecRow};
 use mz_storage_types::sources::SourceData;
+use mz_txn_wal::txn_write::TxnCommitError;
 use mz_txn_wal::txns::{Tidy, TxnsHandle};
 use timely::order::TotalOrder;
 use timely::progress::{Antichain, Timestamp};
@@ -75,6 +76,9 @@ enum PersistTableWriteCmd<T: Timestamp + Lattice + Codec64> {
         write_ts: T,
         advance_to: T,
         updates: Vec<(GlobalId, Vec<TimestamplessUpdate>)>,
+        /// Tables that must not have changed since the mapped timestamp for
+        /// `updates` to be applied.
+        read_set: BTreeMap<GlobalId, T>,
         tx: tokio::sync::oneshot::Sender<Result<(), StorageError<T>>>,
     },
     Shutdown,
@@ -224,6 +228,7 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> PersistTableWrite
         write_ts: T,
         advance_to: T,
         updates: Vec<(GlobalId, Vec<TimestamplessUpdate>)>,
+        read_set: BTreeMap<GlobalId, T>,
     ) -> tokio::sync::oneshot::Receiver<Result<(), StorageError<T>>> {
         let (tx, rx) = tokio::sync::oneshot::channel();
         if updates.is_empty() {
@@ -235,6 +240,7 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> PersistTableWrite
                 write_ts,
                 advance_to,
                 updates,
+                read_set,
                 tx,
             });
             rx
@@ -301,9 +307,10 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
                     write_ts,
                     advance_to,
                     updates,
+                    read_set,
                     tx,
                 } => {
-                    self.append(write_ts, advance_to, updates, tx)
+                    self.append(write_ts, advance_to, updates, read_set, tx)
                         .instrument(span)
                         .await
                 }
@@ -381,12 +388,14 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
         write_ts: T,
         advance_to: T,
         updates: Vec<(GlobalId, Vec<TimestamplessUpdate>)>,
+        read_set: BTreeMap<GlobalId, T>,
         tx: tokio::sync::oneshot::Sender<Result<(), StorageError<T>>>,
     ) {
         debug!(
-            "tables append timestamp={:?} advance_to={:?} len={} ids={:?}{}",
+            "tables append timestamp={:?} advance_to={:?} read_set={:?} len={} ids={:?}{}",
             write_ts,
             advance_to,
+            read_set,
             updates.iter().flat_map(|(_, x)| x).count(),
             updates
                 .iter()
@@ -410,24 +419,53 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
             mz_persist_types::StepForward::step_forward(&write_ts)
         );
 
+        // A table that was dropped after it was read has certainly changed, so
+        // don't bother staging anything in that case.
+        let mut read_conflict = read_set
+            .keys()
+            .find(|id| !self.write_handles.contains_key(id))
+            .copied();
         let mut txn = self.txns.begin();
-        for (id, updates) in updates {
-            let Some(data_id) = self.write_handles.get(&id) else {
-                // HACK: When creating a table we get an append that includes it
-                // before it's been registered. When this happens there are no
-                // updates, so it's ~fine to ignore it.
-                assert!(updates.is_empty(), "{}: {:?}", id, updates);
-                continue;
-            };
-            for update in updates {
-                let () = txn
-                    .write(data_id, SourceData(Ok(update.row)), (), update.diff)
-                    .await;
+        if read_conflict.is_none() {
+            for (id, read_ts) in read_set {
+                txn.read(&self.write_handles[&id], read_ts);
+            }
+            for (id, updates) in updates {
+                let Some(data_id) = self.write_handles.get(&id) else {
+                    // HACK: When creating a table we get an append that includes it
+                    // before it's been registered. When this happens there are no
+                    // updates, so it's ~fine to ignore it.
+                    assert!(updates.is_empty(), "{}: {:?}", id, updates);
+                    continue;
+                };
+                for update in updates {
+                    let () = txn
+                        .write(data_id, SourceData(Ok(update.row)), (), update.diff)
+                        .await;
+                }
             }
         }
         // Sneak in any txns shard tidying from previous commits.
         txn.tidy(std::mem::take(&mut self.tidy));
-        let txn_res = txn.commit_at(&mut self.txns, write_ts.clone()).await;
+        let mut txn_res = txn
+            .commit_validated_at(&mut self.txns, write_ts.clone())
+            .await;
+        if let Err(TxnCommitError::ReadConflict(data_id)) = txn_res {
+            let id = self
+                .write_handles
+                .iter()
+                .find_map(|(id, shard_id)| (*shard_id == data_id).then_some(*id))
+                .expect("read data shard is registered");
+            read_conflict = Some(id);
+            // None of the updates can be written, but the tables still need to
+            // be advanced, so commit an empty txn in its place.
+            let mut empty_txn = self.txns.begin();
+            empty_txn.tidy(txn.take_tidy());
+            txn_res = empty_txn
+                .commit_validated_at(&mut self.txns, write_ts.clone())
+                .await;
+            txn = empty_txn;
+        }
         let response = match txn_res {
             Ok(apply) => {
                 // TODO: Do the applying in a background task. This will be a
@@ -441,9 +479,18 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
                 // just wrote).
                 let () = self.txns.compact_to(write_ts).await;
 
-                Ok(())
+                match read_conflict {
+                    Some(id) => {
+                        debug!("tables append at {:?} read conflict on {}", write_ts, id);
+                        Err(StorageError::ReadConflict(id))
+                    }
+                    None => Ok(()),
+                }
             }
-            Err(current) => {
+            Err(TxnCommitError::ReadConflict(_)) => {
+                unreachable!("txn without reads cannot conflict")
+            }
+            Err(TxnCommitError::InvalidUpper(current)) => {
                 self.tidy.merge(txn.take_tidy());
                 debug!(
                     "unable to commit txn at {:?} current={:?}",
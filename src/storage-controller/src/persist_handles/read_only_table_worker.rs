@@ -175,8 +175,16 @@ where
                 write_ts,
                 advance_to,
                 updates,
+                read_set,
                 tx,
             } => {
+                // Only migrated builtin tables are written in read-only mode,
+                // and those are never written by user transactions.
+                assert!(
+                    read_set.is_empty(),
+                    "unexpected read set in read-only mode: {:?}",
+                    read_set
+                );
                 let mut ids = BTreeSet::new();
                 for (id, updates_no_ts) in updates {
                     ids.insert(id);
@@ -223,6 +223,9 @@ pub enum StorageError<T> {
     /// We are in read-only mode and were asked to do a something that requires
     /// writing.
     ReadOnly,
+    /// A table that an append was conditional on was written after the time it
+    /// was read at.
+    ReadConflict(GlobalId),
 }
 
 impl<T: Debug + Display + 'static> Error for StorageError<T> {
@@ -250,6 +253,7 @@ impl<T: Debug + Display + 'static> Error for StorageError<T> {
             Self::RtrDropFailure(_) => None,
             Self::Generic(err) => err.source(),
             Self::ReadOnly => None,
+            Self::ReadConflict(_) => None,
         }
     }
 }
@@ -337,6 +341,9 @@ impl<T: fmt::Display + 'static> fmt::Display for StorageError<T> {
             ),
             Self::Generic(err) => std::fmt::Display::fmt(err, f),
             Self::ReadOnly => write!(f, "cannot write in read-only mode"),
+            Self::ReadConflict(id) => {
+                write!(f, "collection {id} was written after it was read")
+            }
         }
     }
 }
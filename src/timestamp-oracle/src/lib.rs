@@ -14,11 +14,20 @@
 //! reported completed write timestamps, and strictly less than all subsequently
 //! emitted write timestamps.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{EpochMillis, NowFn};
+use mz_ore::url::SensitiveUrl;
+
+use crate::metrics::Metrics;
+use crate::persist_oracle::{PersistTimestampOracle, PersistTimestampOracleConfig};
+use crate::postgres_oracle::{PostgresTimestampOracle, PostgresTimestampOracleConfig};
 
 pub mod batching_oracle;
 pub mod metrics;
+pub mod persist_oracle;
 pub mod postgres_oracle;
 pub mod retry;
 
@@ -62,6 +71,84 @@ pub trait TimestampOracle<T>: std::fmt::Debug {
     async fn apply_write(&self, lower_bound: T);
 }
 
+/// Configuration of one of the [`TimestampOracle`] implementations, as selected
+/// by the scheme of a timestamp oracle URL.
+#[derive(Clone, Debug)]
+pub enum TimestampOracleConfig {
+    /// A [`PostgresTimestampOracle`], for Postgres/CRDB URLs.
+    Postgres(PostgresTimestampOracleConfig),
+    /// A [`PersistTimestampOracle`], for URLs that start with
+    /// [`persist_oracle::URL_SCHEME_PREFIX`].
+    Persist(PersistTimestampOracleConfig),
+}
+
+impl TimestampOracleConfig {
+    /// Returns the configuration of the [`TimestampOracle`] implementation
+    /// selected by `url`.
+    pub async fn from_url(
+        url: &SensitiveUrl,
+        metrics_registry: &MetricsRegistry,
+    ) -> Result<Self, anyhow::Error> {
+        if url.as_str().starts_with(persist_oracle::URL_SCHEME_PREFIX) {
+            let config = PersistTimestampOracleConfig::from_url(url, metrics_registry).await?;
+            Ok(TimestampOracleConfig::Persist(config))
+        } else {
+            let config = PostgresTimestampOracleConfig::new(url, metrics_registry);
+            Ok(TimestampOracleConfig::Postgres(config))
+        }
+    }
+
+    /// Returns the metrics of the configured oracle.
+    pub fn metrics(&self) -> &Arc<Metrics> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => &config.metrics,
+            TimestampOracleConfig::Persist(config) => &config.metrics,
+        }
+    }
+
+    /// Returns the configuration that Postgres/CRDB connection parameters
+    /// should be applied to, if the configured oracle uses Postgres/CRDB.
+    pub fn pg_config(&self) -> Option<&PostgresTimestampOracleConfig> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => Some(config),
+            TimestampOracleConfig::Persist(config) => config.pg_config.as_ref(),
+        }
+    }
+
+    /// Opens the configured [`TimestampOracle`] for the timeline named
+    /// `timeline`. See [`PostgresTimestampOracle::open`].
+    pub async fn open(
+        self,
+        timeline: String,
+        initially: mz_repr::Timestamp,
+        next: NowFn,
+        read_only: bool,
+    ) -> Arc<dyn TimestampOracle<mz_repr::Timestamp> + Send + Sync> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => Arc::new(
+                PostgresTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+            TimestampOracleConfig::Persist(config) => Arc::new(
+                PersistTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+        }
+    }
+
+    /// Returns all known timelines and their current greatest timestamp.
+    pub async fn get_all_timelines(
+        &self,
+    ) -> Result<Vec<(String, mz_repr::Timestamp)>, anyhow::Error> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => {
+                PostgresTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
+            TimestampOracleConfig::Persist(config) => {
+                PersistTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
+        }
+    }
+}
+
 /// A [`NowFn`] that is generic over the timestamp.
 ///
 /// The oracle operations work in terms of [`mz_repr::Timestamp`] and we could
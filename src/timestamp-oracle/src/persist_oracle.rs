@@ -0,0 +1,511 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A timestamp oracle backed by persist's [`Consensus`] for
+//! persistence/durability.
+//!
+//! The read and write timestamps of a timeline are stored together in a
+//! single [`Consensus`] key, and every oracle operation that changes them is a
+//! compare-and-set against the latest version. This makes all operations
+//! linearizable, like with the [`PostgresTimestampOracle`], but works with any
+//! [`Consensus`] implementation.
+//!
+//! [`PostgresTimestampOracle`]: crate::postgres_oracle::PostgresTimestampOracle
+
+use std::sync::Arc;
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
+use mz_ore::instrument;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::url::SensitiveUrl;
+use mz_persist::cfg::ConsensusConfig;
+use mz_persist::location::{CaSResult, Consensus, SeqNo, VersionedData};
+use mz_repr::Timestamp;
+use tracing::{debug, info, warn};
+
+use crate::metrics::Metrics;
+use crate::postgres_oracle::{retry_fallible, PostgresTimestampOracleConfig};
+use crate::WriteTimestamp;
+use crate::{GenericNowFn, TimestampOracle};
+
+/// The [`Consensus`] key prefix under which timelines are stored.
+const KEY_PREFIX: &str = "timestamp_oracle/";
+
+/// The prefix of timestamp oracle URLs that select the
+/// [`PersistTimestampOracle`], e.g. `persist+file:///path/to/consensus`. The
+/// rest of the URL is that of the [`Consensus`] to store timelines in.
+pub const URL_SCHEME_PREFIX: &str = "persist+";
+
+/// How often, in terms of [`SeqNo`]s, to truncate the history of a timeline's
+/// [`Consensus`] key. Only the latest version is ever read.
+const TRUNCATE_INTERVAL: u64 = 128;
+
+/// A [`TimestampOracle`] backed by persist's [`Consensus`].
+#[derive(Debug)]
+pub struct PersistTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp>,
+{
+    timeline: String,
+    key: String,
+    next: N,
+    consensus: Arc<dyn Consensus>,
+    metrics: Arc<Metrics>,
+    /// A read-only timestamp oracle is NOT allowed to do operations that change
+    /// the backing [`Consensus`] state.
+    read_only: bool,
+}
+
+/// Configuration to connect to a [`Consensus`]-backed implementation of
+/// [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub struct PersistTimestampOracleConfig {
+    consensus: Arc<dyn Consensus>,
+    pub metrics: Arc<Metrics>,
+
+    /// The tuning of the connection pool, if `consensus` is backed by
+    /// Postgres/CRDB and was opened by [`Self::from_url`].
+    pub pg_config: Option<PostgresTimestampOracleConfig>,
+}
+
+impl PersistTimestampOracleConfig {
+    /// Returns a new instance of [`PersistTimestampOracleConfig`] that stores
+    /// timelines in `consensus`.
+    pub fn new(consensus: Arc<dyn Consensus>, metrics_registry: &MetricsRegistry) -> Self {
+        let metrics = Arc::new(Metrics::new(metrics_registry));
+
+        PersistTimestampOracleConfig {
+            consensus,
+            metrics,
+            pg_config: None,
+        }
+    }
+
+    /// Returns a new instance of [`PersistTimestampOracleConfig`] for a
+    /// timestamp oracle URL that starts with [`URL_SCHEME_PREFIX`], which
+    /// stores timelines in the [`Consensus`] named by the rest of `url`.
+    pub async fn from_url(
+        url: &SensitiveUrl,
+        metrics_registry: &MetricsRegistry,
+    ) -> Result<Self, anyhow::Error> {
+        let consensus_url = url
+            .as_str()
+            .strip_prefix(URL_SCHEME_PREFIX)
+            .ok_or_else(|| anyhow!("timestamp oracle url must start with {URL_SCHEME_PREFIX}"))?;
+        let consensus_url = SensitiveUrl::from_str(consensus_url)?;
+        let metrics = Arc::new(Metrics::new(metrics_registry));
+
+        // A Postgres/CRDB-backed Consensus is tuned with the same parameters as
+        // the Postgres oracle, so that they can be updated the same way.
+        let pg_config =
+            PostgresTimestampOracleConfig::with_metrics(&consensus_url, Arc::clone(&metrics));
+        let consensus = ConsensusConfig::try_from(
+            &consensus_url,
+            Box::new(pg_config.clone()),
+            metrics.postgres_client.clone(),
+        )?
+        .open()
+        .await?;
+
+        Ok(PersistTimestampOracleConfig {
+            consensus,
+            metrics,
+            pg_config: Some(pg_config),
+        })
+    }
+}
+
+/// The read and write timestamps of a timeline, as stored in [`Consensus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimelineState {
+    read_ts: Timestamp,
+    write_ts: Timestamp,
+}
+
+impl TimelineState {
+    /// Encodes as the little-endian `read_ts` followed by the little-endian
+    /// `write_ts`.
+    fn encode(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(16);
+        buf.extend_from_slice(&u64::from(self.read_ts).to_le_bytes());
+        buf.extend_from_slice(&u64::from(self.write_ts).to_le_bytes());
+        Bytes::from(buf)
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, anyhow::Error> {
+        let (Some(read_ts), Some(write_ts)) = (buf.get(0..8), buf.get(8..16)) else {
+            return Err(anyhow!("invalid timeline state of {} bytes", buf.len()));
+        };
+        let ts = |x: &[u8]| Timestamp::from(u64::from_le_bytes(x.try_into().expect("8 bytes")));
+        Ok(TimelineState {
+            read_ts: ts(read_ts),
+            write_ts: ts(write_ts),
+        })
+    }
+}
+
+impl<N> PersistTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    /// Open a [`Consensus`]-backed [`TimestampOracle`] instance with `config`,
+    /// for the timeline named `timeline`. `next` generates new timestamps when
+    /// invoked. Timestamps that are returned are made durable and will never
+    /// retract.
+    pub async fn open(
+        config: PersistTimestampOracleConfig,
+        timeline: String,
+        initially: Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Self {
+        info!(config = ?config, "opening PersistTimestampOracle");
+
+        let oracle = PersistTimestampOracle {
+            key: format!("{}{}", KEY_PREFIX, timeline),
+            timeline,
+            next,
+            consensus: Arc::clone(&config.consensus),
+            metrics: Arc::clone(&config.metrics),
+            read_only,
+        };
+
+        // Create the state for our timeline, if it doesn't exist, so that the
+        // other operations can expect it to be present.
+        let fallible = || async {
+            if oracle.consensus.head(&oracle.key).await?.is_some() {
+                return Ok(());
+            }
+            let initial = TimelineState {
+                read_ts: initially,
+                write_ts: initially,
+            };
+            let new = VersionedData {
+                seqno: SeqNo::minimum(),
+                data: initial.encode(),
+            };
+            // If this fails, someone else concurrently created the state.
+            let _ = oracle
+                .consensus
+                .compare_and_set(&oracle.key, None, new)
+                .await?;
+            Result::<_, anyhow::Error>::Ok(())
+        };
+        retry_fallible(&config.metrics.retries.open, fallible).await;
+
+        // Forward timestamps to what we're given from outside. Remember, the
+        // above will only create the state at the initial timestamp if it
+        // didn't exist before.
+        if !read_only {
+            TimestampOracle::apply_write(&oracle, initially).await;
+        }
+
+        oracle
+    }
+
+    /// Returns all known timelines and their current greatest timestamp.
+    pub async fn get_all_timelines(
+        config: PersistTimestampOracleConfig,
+    ) -> Result<Vec<(String, Timestamp)>, anyhow::Error> {
+        let fallible = || async {
+            let mut keys = config.consensus.list_keys();
+            let mut result = Vec::new();
+            while let Some(key) = keys.next().await {
+                let key = key?;
+                let Some(timeline) = key.strip_prefix(KEY_PREFIX) else {
+                    continue;
+                };
+                let Some(current) = config.consensus.head(&key).await? else {
+                    continue;
+                };
+                let state = TimelineState::decode(&current.data)?;
+                result.push((
+                    timeline.to_string(),
+                    std::cmp::max(state.read_ts, state.write_ts),
+                ));
+            }
+            Result::<_, anyhow::Error>::Ok(result)
+        };
+
+        let metrics = &config.metrics.retries.get_all_timelines;
+
+        let result = retry_fallible(metrics, fallible).await;
+
+        Ok(result)
+    }
+
+    /// Returns the current state of the timeline and its [`SeqNo`].
+    async fn head(&self) -> Result<(SeqNo, TimelineState), anyhow::Error> {
+        let Some(current) = self.consensus.head(&self.key).await? else {
+            panic!("state for timeline {} has been removed", self.timeline);
+        };
+        let state = TimelineState::decode(&current.data)?;
+        Ok((current.seqno, state))
+    }
+
+    /// Repeatedly applies `update_fn` to the current state of the timeline
+    /// until it's successfully compare-and-set, and returns the resulting
+    /// state. If `update_fn` returns `None`, the state is left as is.
+    async fn update<F>(&self, mut update_fn: F) -> Result<TimelineState, anyhow::Error>
+    where
+        F: FnMut(TimelineState) -> Option<TimelineState>,
+    {
+        loop {
+            let (seqno, current) = self.head().await?;
+            let Some(new) = update_fn(current) else {
+                return Ok(current);
+            };
+            let new_seqno = seqno.next();
+            let data = VersionedData {
+                seqno: new_seqno,
+                data: new.encode(),
+            };
+            match self
+                .consensus
+                .compare_and_set(&self.key, Some(seqno), data)
+                .await?
+            {
+                CaSResult::Committed => {
+                    if new_seqno.0 % TRUNCATE_INTERVAL == 0 {
+                        // Failing to truncate only leaves behind history that
+                        // the next successful truncation will clean up.
+                        if let Err(err) = self.consensus.truncate(&self.key, new_seqno).await {
+                            warn!(timeline = ?self.timeline, "failed to truncate: {}", err);
+                        }
+                    }
+                    return Ok(new);
+                }
+                CaSResult::ExpectationMismatch => continue,
+            }
+        }
+    }
+
+    #[mz_ore::instrument(name = "oracle::write_ts")]
+    async fn fallible_write_ts(&self) -> Result<WriteTimestamp<Timestamp>, anyhow::Error> {
+        if self.read_only {
+            panic!("attempting write_ts in read-only mode");
+        }
+
+        let proposed_next_ts = self.next.now();
+
+        let state = self
+            .update(|current| {
+                Some(TimelineState {
+                    read_ts: current.read_ts,
+                    write_ts: std::cmp::max(current.write_ts.step_forward(), proposed_next_ts),
+                })
+            })
+            .await?;
+        let write_ts = state.write_ts;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            proposed_next_ts = ?proposed_next_ts,
+            "returning from write_ts()");
+
+        let advance_to = write_ts.step_forward();
+
+        Ok(WriteTimestamp {
+            timestamp: write_ts,
+            advance_to,
+        })
+    }
+
+    #[mz_ore::instrument(name = "oracle::peek_write_ts")]
+    async fn fallible_peek_write_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.head().await?;
+        let write_ts = state.write_ts;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from peek_write_ts()");
+
+        Ok(write_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::read_ts")]
+    async fn fallible_read_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.head().await?;
+        let read_ts = state.read_ts;
+
+        debug!(
+            timeline = ?self.timeline,
+            read_ts = ?read_ts,
+            "returning from read_ts()");
+
+        Ok(read_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::apply_write")]
+    async fn fallible_apply_write(&self, write_ts: Timestamp) -> Result<(), anyhow::Error> {
+        if self.read_only {
+            panic!("attempting apply_write in read-only mode");
+        }
+
+        let _ = self
+            .update(|current| {
+                if current.read_ts >= write_ts && current.write_ts >= write_ts {
+                    return None;
+                }
+                Some(TimelineState {
+                    read_ts: std::cmp::max(current.read_ts, write_ts),
+                    write_ts: std::cmp::max(current.write_ts, write_ts),
+                })
+            })
+            .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from apply_write()");
+
+        Ok(())
+    }
+}
+
+// A wrapper around the `fallible_` methods that adds operation metrics and
+// retries.
+#[async_trait]
+impl<N> TimestampOracle<Timestamp> for PersistTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    #[instrument]
+    async fn write_ts(&self) -> WriteTimestamp<Timestamp> {
+        let metrics = &self.metrics.retries.write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .write_ts
+                .run_op(|| self.fallible_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn peek_write_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.peek_write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .peek_write_ts
+                .run_op(|| self.fallible_peek_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn read_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.read_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .read_ts
+                .run_op(|| self.fallible_read_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn apply_write(&self, write_ts: Timestamp) {
+        let metrics = &self.metrics.retries.apply_write;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .apply_write
+                .run_op(|| self.fallible_apply_write(write_ts))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::now::NowFn;
+    use mz_persist::mem::MemConsensus;
+
+    use crate::TimestampOracleConfig;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn test_persist_timestamp_oracle() -> Result<(), anyhow::Error> {
+        let config = PersistTimestampOracleConfig::new(
+            Arc::new(MemConsensus::default()),
+            &MetricsRegistry::new(),
+        );
+
+        crate::tests::timestamp_oracle_impl_test(|timeline, now_fn, initial_ts| {
+            let oracle = PersistTimestampOracle::open(
+                config.clone(),
+                timeline,
+                initial_ts,
+                now_fn,
+                false, /* read-only */
+            );
+
+            async {
+                let arced_oracle: Arc<dyn TimestampOracle<Timestamp> + Send + Sync> =
+                    Arc::new(oracle.await);
+
+                arced_oracle
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn test_persist_timestamp_oracle_from_url() -> Result<(), anyhow::Error> {
+        let url = SensitiveUrl::from_str("persist+mem://")?;
+        let config = TimestampOracleConfig::from_url(&url, &MetricsRegistry::new()).await?;
+        assert!(matches!(config, TimestampOracleConfig::Persist(_)));
+
+        let oracle = config
+            .clone()
+            .open(
+                "test".to_string(),
+                Timestamp::from(5u64),
+                NowFn::from(|| 0),
+                false, /* read-only */
+            )
+            .await;
+        oracle.apply_write(Timestamp::from(10u64)).await;
+        assert_eq!(
+            config.get_all_timelines().await?,
+            vec![("test".to_string(), Timestamp::from(10u64))]
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn timeline_state_roundtrip() {
+        let state = TimelineState {
+            read_ts: Timestamp::from(3u64),
+            write_ts: Timestamp::MAX,
+        };
+        assert_eq!(TimelineState::decode(&state.encode()).unwrap(), state);
+        assert!(TimelineState::decode(&[0; 15]).is_err());
+    }
+}
@@ -111,7 +111,12 @@ impl PostgresTimestampOracleConfig {
     /// Returns a new instance of [`PostgresTimestampOracleConfig`] with default tuning.
     pub fn new(url: &SensitiveUrl, metrics_registry: &MetricsRegistry) -> Self {
         let metrics = Arc::new(Metrics::new(metrics_registry));
+        Self::with_metrics(url, metrics)
+    }
 
+    /// Returns a new instance of [`PostgresTimestampOracleConfig`] with default
+    /// tuning that reports to the given, already registered, `metrics`.
+    pub(crate) fn with_metrics(url: &SensitiveUrl, metrics: Arc<Metrics>) -> Self {
         let dynamic = DynamicConfig::default();
 
         PostgresTimestampOracleConfig {
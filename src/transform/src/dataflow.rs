@@ -417,9 +417,12 @@ pub fn optimize_dataflow_monotonic(
     ctx: &mut TransformCtx,
 ) -> Result<(), TransformError> {
     let mut monotonic_ids = BTreeSet::new();
-    for (source_id, (_source, is_monotonic)) in dataflow.source_imports.iter() {
+    for (source_id, (source, is_monotonic)) in dataflow.source_imports.iter() {
         if *is_monotonic {
-            monotonic_ids.insert(source_id.clone());
+            match &source.arguments.persisted_index {
+                Some(index_desc) => monotonic_ids.insert(index_desc.on_id.clone()),
+                None => monotonic_ids.insert(source_id.clone()),
+            };
         }
     }
     for (
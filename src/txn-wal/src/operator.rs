@@ -534,7 +534,9 @@ pub struct TxnsContext {
 }
 
 impl TxnsContext {
-    async fn get_or_init<T, C>(&self, client: &PersistClient, txns_id: ShardId) -> TxnsRead<T>
+    /// Returns the process global [`TxnsRead`] for the given txns shard, starting it if
+    /// necessary.
+    pub async fn get_or_init<T, C>(&self, client: &PersistClient, txns_id: ShardId) -> TxnsRead<T>
     where
         T: Timestamp + Lattice + Codec64 + TotalOrder + StepForward,
         C: TxnsCodec + 'static,
@@ -214,6 +214,36 @@ impl<T: Timestamp + Lattice + TotalOrder + StepForward + Codec64> TxnsCacheState
             .collect()
     }
 
+    /// Returns whether the contents of a data shard may have changed at some
+    /// time after `ts` and before the current progress.
+    ///
+    /// A data shard is considered changed if it was written, registered, or
+    /// forgotten after `ts`. This errs on the side of reporting a change when
+    /// the cache cannot know for sure, i.e. when `ts` is before the time the
+    /// cache was initialized at or when the data shard has never been
+    /// registered.
+    pub fn data_changed_after(&self, data_id: &ShardId, ts: &T) -> bool {
+        self.assert_only_data_id(data_id);
+        if ts < &self.init_ts {
+            return true;
+        }
+        let Some(data_times) = self.datas.get(data_id) else {
+            return true;
+        };
+        // The latest write and registration are always retained by
+        // `compact_data_times`, which is all we need here.
+        let last_reg = data_times.last_reg();
+        data_times
+            .writes
+            .back()
+            .map_or(false, |write_ts| write_ts > ts)
+            || &last_reg.register_ts > ts
+            || last_reg
+                .forget_ts
+                .as_ref()
+                .map_or(false, |forget_ts| forget_ts > ts)
+    }
+
     /// Returns a token exchangeable for a snapshot of a data shard.
     ///
     /// A data shard might be definite at times past the physical upper because
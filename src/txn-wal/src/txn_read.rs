@@ -22,7 +22,9 @@ use mz_ore::instrument;
 use mz_ore::task::AbortOnDropHandle;
 use mz_persist_client::cfg::USE_CRITICAL_SINCE_TXN;
 use mz_persist_client::critical::SinceHandle;
-use mz_persist_client::read::{Cursor, LazyPartStats, ListenEvent, ReadHandle, Since, Subscribe};
+use mz_persist_client::read::{
+    Cursor, LazyPartStats, Listen, ListenEvent, ReadHandle, Since, Subscribe,
+};
 use mz_persist_client::stats::{SnapshotPartsStats, SnapshotStats};
 use mz_persist_client::write::WriteHandle;
 use mz_persist_client::{Diagnostics, PersistClient, ShardId};
@@ -154,6 +156,25 @@ impl<T: Timestamp + Lattice + TotalOrder + Codec64> DataSnapshot<T> {
             .await
     }
 
+    /// See [ReadHandle::listen].
+    ///
+    /// The returned listen is guaranteed to progress past `self.as_of`, so it can be used to
+    /// read the changes of the data shard in an interval ending at `self.as_of`.
+    pub async fn listen<K, V, D>(
+        &self,
+        data_read: ReadHandle<K, V, T, D>,
+        as_of: Antichain<T>,
+    ) -> Result<Listen<K, V, T, D>, Since<T>>
+    where
+        K: Debug + Codec + Ord,
+        V: Debug + Codec + Ord,
+        D: Debug + Semigroup + Ord + Codec64 + Send + Sync,
+    {
+        let data_write = WriteHandle::from_read(&data_read, "unblock_read");
+        self.unblock_read(data_write).await;
+        data_read.listen(as_of).await
+    }
+
     /// See [ReadHandle::snapshot_cursor].
     pub async fn snapshot_cursor<K, V, D>(
         &self,
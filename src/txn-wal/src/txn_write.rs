@@ -60,9 +60,21 @@ impl<K, V, T, D> Default for TxnWrite<K, V, T, D> {
 #[derive(Debug)]
 pub struct Txn<K, V, T, D> {
     pub(crate) writes: BTreeMap<ShardId, TxnWrite<K, V, T, D>>,
+    reads: BTreeMap<ShardId, T>,
     tidy: Tidy,
 }
 
+/// An error returned by [Txn::commit_validated_at].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxnCommitError<T> {
+    /// The txn can no longer be committed at the requested timestamp. Contains
+    /// the least commit-able timestamp.
+    InvalidUpper(T),
+    /// A data shard read by the txn was changed after the timestamp at which it
+    /// was read.
+    ReadConflict(ShardId),
+}
+
 impl<K, V, T, D> Txn<K, V, T, D>
 where
     K: Debug + Codec,
@@ -73,6 +85,7 @@ where
     pub(crate) fn new() -> Self {
         Txn {
             writes: BTreeMap::default(),
+            reads: BTreeMap::default(),
             tidy: Tidy::default(),
         }
     }
@@ -92,6 +105,21 @@ where
             .push((key, val, diff))
     }
 
+    /// Record that the in-progress txn read `data_id` as of `read_ts`.
+    ///
+    /// [Self::commit_validated_at] only commits the txn if `data_id` has not
+    /// been changed at any time after `read_ts`. If the same data shard is read
+    /// multiple times, the earliest read timestamp is the one validated.
+    pub fn read(&mut self, data_id: &ShardId, read_ts: T) {
+        let ts = self
+            .reads
+            .entry(*data_id)
+            .or_insert_with(|| read_ts.clone());
+        if read_ts < *ts {
+            *ts = read_ts;
+        }
+    }
+
     /// Commit this transaction at `commit_ts`.
     ///
     /// This either atomically commits all staged writes or, if that's no longer
@@ -105,12 +133,49 @@ where
     /// correctness nor liveness require this followup be done.
     ///
     /// Panics if any involved data shards were not registered before commit ts.
-    #[instrument(level = "debug", fields(ts = ?commit_ts))]
+    /// Panics if any reads were recorded with [Self::read]; those txns must be
+    /// committed with [Self::commit_validated_at].
     pub async fn commit_at<O, C>(
         &mut self,
         handle: &mut TxnsHandle<K, V, T, D, O, C>,
         commit_ts: T,
     ) -> Result<TxnApply<T>, T>
+    where
+        O: Opaque + Debug + Codec64,
+        C: TxnsCodec,
+    {
+        assert!(
+            self.reads.is_empty(),
+            "txn with reads must be committed with commit_validated_at"
+        );
+        self.commit_validated_at(handle, commit_ts)
+            .await
+            .map_err(|err| match err {
+                TxnCommitError::InvalidUpper(upper) => upper,
+                TxnCommitError::ReadConflict(data_id) => {
+                    unreachable!("txn without reads conflicted on {}", data_id)
+                }
+            })
+    }
+
+    /// Commit this transaction at `commit_ts`, if none of the data shards it
+    /// read have changed since they were read.
+    ///
+    /// This is [Self::commit_at] with the additional guarantee that the staged
+    /// writes are committed only if no data shard recorded with [Self::read]
+    /// was changed at any time in `(read_ts, commit_ts]`. The validation is
+    /// performed against the same view of the txns shard that the commit
+    /// itself is conditional on, so no concurrent commit can slip in between
+    /// the two. Otherwise, a [TxnCommitError::ReadConflict] is returned and
+    /// nothing is committed.
+    ///
+    /// Panics if any involved data shards were not registered before commit ts.
+    #[instrument(level = "debug", fields(ts = ?commit_ts))]
+    pub async fn commit_validated_at<O, C>(
+        &mut self,
+        handle: &mut TxnsHandle<K, V, T, D, O, C>,
+        commit_ts: T,
+    ) -> Result<TxnApply<T>, TxnCommitError<T>>
     where
         O: Opaque + Debug + Codec64,
         C: TxnsCodec,
@@ -135,7 +200,22 @@ where
                         "commit_at {:?} mismatch current={:?}",
                         commit_ts, txns_upper
                     );
-                    return Err(txns_upper);
+                    return Err(TxnCommitError::InvalidUpper(txns_upper));
+                }
+                // Validate that nothing we read has changed since we read it.
+                // The cache is up to date through `txns_upper` and the CaA
+                // below only succeeds if nothing else is committed before
+                // `commit_ts`, so this covers everything up to the commit.
+                for (data_id, read_ts) in self.reads.iter() {
+                    if handle.txns_cache.data_changed_after(data_id, read_ts) {
+                        debug!(
+                            "commit_at {:?} read conflict on {:.9} read_ts={:?}",
+                            commit_ts,
+                            data_id.to_string(),
+                            read_ts
+                        );
+                        return Err(TxnCommitError::ReadConflict(*data_id));
+                    }
                 }
                 // Validate that the involved data shards are all registered.
                 for (data_id, _) in self.writes.iter() {
@@ -316,11 +396,14 @@ where
         .await
     }
 
-    /// Merges the staged writes in the other txn into this one.
+    /// Merges the staged writes and reads in the other txn into this one.
     pub fn merge(&mut self, other: Self) {
         for (data_id, writes) in other.writes {
             self.writes.entry(data_id).or_default().merge(writes);
         }
+        for (data_id, read_ts) in other.reads {
+            self.read(&data_id, read_ts);
+        }
         self.tidy.merge(other.tidy);
     }
 
@@ -423,6 +506,63 @@ mod tests {
         assert_eq!(actual_d1, expected_d1);
     }
 
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn commit_validated_at() {
+        let client = PersistClient::new_for_tests().await;
+        let mut txns = TxnsHandle::expect_open(client.clone()).await;
+        let mut cache = TxnsCache::expect_open(0, &txns).await;
+        let d0 = txns.expect_register(1).await;
+        let d1 = txns.expect_register(2).await;
+
+        let mut txn = txns.begin();
+        txn.write(&d0, "0".into(), (), 1).await;
+        txn.commit_at(&mut txns, 3).await.unwrap();
+
+        // A txn that read d0 at or after its latest write can commit.
+        let mut txn = txns.begin();
+        txn.read(&d0, 3);
+        txn.write(&d1, "A".into(), (), 1).await;
+        txn.commit_validated_at(&mut txns, 4).await.unwrap();
+
+        // A txn that read d0 before its latest write cannot, and none of its
+        // writes are committed. Reading the same shard again at a later time
+        // doesn't paper over the earlier read.
+        let mut txn = txns.begin();
+        txn.read(&d0, 2);
+        txn.read(&d0, 3);
+        txn.write(&d1, "B".into(), (), 1).await;
+        assert_eq!(
+            txn.commit_validated_at(&mut txns, 5).await,
+            Err(TxnCommitError::ReadConflict(d0))
+        );
+
+        // The write to d1 at 4 doesn't conflict with a txn that only read d0,
+        // and a txn may write a data shard that it read.
+        let mut txn = txns.begin();
+        txn.read(&d0, 3);
+        txn.write(&d0, "1".into(), (), 1).await;
+        txn.commit_validated_at(&mut txns, 5).await.unwrap();
+        txns.apply_le(&5).await;
+
+        let expected_d0 = vec!["0".to_owned(), "1".to_owned()];
+        let actual_d0 = cache.expect_snapshot(&client, d0, 5).await;
+        assert_eq!(actual_d0, expected_d0);
+
+        let expected_d1 = vec!["A".to_owned()];
+        let actual_d1 = cache.expect_snapshot(&client, d1, 5).await;
+        assert_eq!(actual_d1, expected_d1);
+
+        // Forgetting a data shard also counts as a change.
+        txns.forget(6, [d1]).await.unwrap();
+        let mut txn = txns.begin();
+        txn.read(&d1, 5);
+        assert_eq!(
+            txn.commit_validated_at(&mut txns, 7).await,
+            Err(TxnCommitError::ReadConflict(d1))
+        );
+    }
+
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
     async fn apply_and_tidy() {
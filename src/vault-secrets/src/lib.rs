@@ -0,0 +1,178 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Reads external secrets from the KV version 2 secrets engine of a HashiCorp
+//! Vault server.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context};
+use async_trait::async_trait;
+use mz_secrets::external::ExternalSecretsReader;
+use url::Url;
+
+/// An [`ExternalSecretsReader`] for HashiCorp Vault.
+///
+/// Secret paths start with the mount of a KV version 2 secrets engine, e.g.
+/// `kv/payments/db` names the secret `payments/db` in the engine mounted at
+/// `kv`. Only secrets in the engine mounted at the configured mount can be
+/// read.
+#[derive(Debug)]
+pub struct VaultSecretsReader {
+    client: reqwest::Client,
+    address: Url,
+    mount: String,
+    token_file: PathBuf,
+}
+
+impl VaultSecretsReader {
+    /// Creates a reader for the secrets in the KV engine mounted at `mount` of
+    /// the Vault server at `address`.
+    ///
+    /// Requests are authenticated with the token stored in `token_file`. The
+    /// file is read anew for every request, so that tokens renewed by a Vault
+    /// agent are picked up.
+    pub fn new(address: Url, mount: String, token_file: PathBuf) -> Self {
+        VaultSecretsReader {
+            client: reqwest::Client::new(),
+            address,
+            mount: mount.trim_matches('/').into(),
+            token_file,
+        }
+    }
+}
+
+#[async_trait]
+impl ExternalSecretsReader for VaultSecretsReader {
+    async fn read(&self, path: &str, key: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let url = secret_url(&self.address, &self.mount, path)?;
+        let token = tokio::fs::read_to_string(&self.token_file)
+            .await
+            .with_context(|| format!("reading Vault token from {}", self.token_file.display()))?;
+        let response = self
+            .client
+            .get(url)
+            .header("X-Vault-Token", token.trim())
+            .send()
+            .await
+            .with_context(|| format!("reading Vault secret {path}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            bail!("reading Vault secret {path}: server responded with {status}");
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .with_context(|| format!("decoding Vault secret {path}"))?;
+        secret_value(&body, path, key)
+    }
+}
+
+/// Returns the URL of the KV version 2 API that reads the secret at `path`,
+/// which must be in the engine mounted at `allowed_mount`.
+///
+/// Path segments are percent-encoded, and empty, `.` and `..` segments are
+/// rejected, so that a path can't address any other Vault API.
+fn secret_url(address: &Url, allowed_mount: &str, path: &str) -> Result<Url, anyhow::Error> {
+    let path = path.trim_matches('/');
+    let segments: Vec<_> = path.split('/').collect();
+    if segments
+        .iter()
+        .any(|segment| matches!(*segment, "" | "." | ".."))
+    {
+        bail!("invalid Vault secret path {path}");
+    }
+    let mount: Vec<_> = allowed_mount.split('/').collect();
+    let Some(secret) = segments.strip_prefix(mount.as_slice()) else {
+        bail!("Vault secret path {path} is not in the {allowed_mount} mount");
+    };
+    if secret.is_empty() {
+        bail!("Vault secret path {path} names the {allowed_mount} mount, not a secret");
+    }
+
+    let mut url = address.clone();
+    url.path_segments_mut()
+        .map_err(|()| anyhow!("invalid Vault address {address}"))?
+        .pop_if_empty()
+        .push("v1")
+        .extend(mount)
+        .push("data")
+        .extend(secret);
+    Ok(url)
+}
+
+/// Extracts the value of `key` from the response to a KV version 2 read.
+fn secret_value(body: &serde_json::Value, path: &str, key: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let value = body
+        .pointer("/data/data")
+        .and_then(|data| data.get(key))
+        .ok_or_else(|| anyhow!("Vault secret {path} has no key {key}"))?;
+    match value {
+        serde_json::Value::String(value) => Ok(value.clone().into_bytes()),
+        _ => bail!("Vault secret {path} has a non-string value for key {key}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use url::Url;
+
+    use crate::{secret_url, secret_value};
+
+    #[mz_ore::test]
+    fn test_secret_url() {
+        let address = Url::parse("https://vault.example.com:8200/").unwrap();
+        assert_eq!(
+            secret_url(&address, "kv", "kv/payments/db")
+                .unwrap()
+                .as_str(),
+            "https://vault.example.com:8200/v1/kv/data/payments/db"
+        );
+        assert_eq!(
+            secret_url(&address, "secret", "/secret/db/")
+                .unwrap()
+                .as_str(),
+            "https://vault.example.com:8200/v1/secret/data/db"
+        );
+        assert_eq!(
+            secret_url(&address, "kv", "kv/a b?c#d").unwrap().as_str(),
+            "https://vault.example.com:8200/v1/kv/data/a%20b%3Fc%23d"
+        );
+        assert_eq!(
+            secret_url(&address, "team/kv", "team/kv/db")
+                .unwrap()
+                .as_str(),
+            "https://vault.example.com:8200/v1/team/kv/data/db"
+        );
+        assert!(secret_url(&address, "kv", "kv").is_err());
+
+        // Paths can't escape the configured mount.
+        assert!(secret_url(&address, "kv", "other/db").is_err());
+        assert!(secret_url(&address, "kv", "kv/../../sys/policy").is_err());
+        assert!(secret_url(&address, "kv", "kv/./db").is_err());
+        assert!(secret_url(&address, "kv", "kv//db").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_secret_value() {
+        let body = json!({
+            "data": {
+                "data": { "password": "hunter2", "port": 5432 },
+                "metadata": { "version": 3 },
+            },
+        });
+        assert_eq!(
+            secret_value(&body, "kv/db", "password").unwrap(),
+            b"hunter2"
+        );
+        assert!(secret_value(&body, "kv/db", "port").is_err());
+        assert!(secret_value(&body, "kv/db", "username").is_err());
+    }
+}